@@ -0,0 +1,593 @@
+use dc_core::call_graph::{CallEdge, CallGraph, CallNode};
+use dc_core::discovery::find_source_files;
+use dc_core::models::NodeId;
+use petgraph::graph::NodeIndex;
+use rustpython_parser::ast;
+use rustpython_parser::{parse, Mode};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+/// Route metadata key holding the service (app) a route belongs to
+pub const SERVICE_METADATA_KEY: &str = "service";
+
+/// Route metadata key holding the middleware stack a route goes through
+/// (comma-separated, outermost first)
+pub const MIDDLEWARE_METADATA_KEY: &str = "middleware";
+
+/// Directories that never contain application code
+const SKIPPED_DIRS: &[&str] = &["__pycache__", "node_modules", "venv", "site-packages"];
+
+/// FastAPI application instance (`app = FastAPI()`) found in the source tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FastApiApp {
+    /// Service name used to namespace the app's routes
+    pub service: String,
+    /// Module that instantiates the app
+    pub file: PathBuf,
+    /// Variable the app is assigned to
+    pub variable: String,
+}
+
+/// Finds all module-level `FastAPI()` instantiations under `root`
+///
+/// The service name is the directory of the app module (`services/users/main.py` -> `users`),
+/// or the module name for apps at the root. Apps whose directory names collide are named by
+/// their full relative directory instead. With `respect_gitignore`, modules ignored by
+/// `.gitignore` are skipped.
+pub fn discover_apps(root: &Path, respect_gitignore: bool) -> Vec<FastApiApp> {
+    let files = find_source_files(root, &["py"], respect_gitignore)
+        .into_iter()
+        .filter(|file| !in_skipped_dir(root, file));
+
+    let mut apps = Vec::new();
+    for file in files {
+        let Ok(source) = std::fs::read_to_string(&file) else {
+            continue;
+        };
+        if !source.contains("FastAPI(") {
+            continue;
+        }
+        let Ok(ast::Mod::Module(module)) =
+            parse(&source, Mode::Module, file.to_string_lossy().as_ref())
+        else {
+            debug!(file = ?file, "Skipping unparsable module during app discovery");
+            continue;
+        };
+
+        for stmt in &module.body {
+            if let Some(variable) = fastapi_app_variable(stmt) {
+                apps.push(FastApiApp {
+                    service: service_name(root, &file, false),
+                    file: file.clone(),
+                    variable,
+                });
+            }
+        }
+    }
+
+    // Disambiguate services sharing a directory name (`a/api/main.py`, `b/api/main.py`)
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for app in &apps {
+        *counts.entry(app.service.clone()).or_default() += 1;
+    }
+    for app in &mut apps {
+        if counts[&app.service] > 1 {
+            app.service = service_name(root, &app.file, true);
+        }
+    }
+
+    apps
+}
+
+/// Tags every route of an app graph with its service name
+pub fn namespace_routes(graph: &mut CallGraph, service: &str) {
+    for node in graph.node_weights_mut() {
+        if let CallNode::Route { metadata, .. } = node {
+            metadata.insert(SERVICE_METADATA_KEY.to_string(), service.to_string());
+        }
+    }
+}
+
+/// Routes per service (`"GET /users"` entries), for the per-service route inventory
+///
+/// Routes without a service are listed under an empty name. Routes going through
+/// middleware list their stack: `"GET /users [CORSMiddleware, add_timing]"`.
+pub fn route_inventory(graph: &CallGraph) -> BTreeMap<String, Vec<String>> {
+    let mut inventory: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for node in graph.node_weights() {
+        if let CallNode::Route {
+            path,
+            method,
+            metadata,
+            ..
+        } = node
+        {
+            let service = metadata
+                .get(SERVICE_METADATA_KEY)
+                .cloned()
+                .unwrap_or_default();
+            let mut entry = format!("{} {}", format!("{:?}", method).to_uppercase(), path);
+            if let Some(stack) = metadata
+                .get(MIDDLEWARE_METADATA_KEY)
+                .filter(|stack| !stack.is_empty())
+            {
+                entry.push_str(&format!(" [{}]", stack.replace(',', ", ")));
+            }
+            inventory.entry(service).or_default().push(entry);
+        }
+    }
+    for routes in inventory.values_mut() {
+        routes.sort();
+        routes.dedup();
+    }
+    inventory
+}
+
+/// Middleware registered on the app of `entry`, outermost first
+///
+/// Handles `app.add_middleware(CORSMiddleware, ...)`, `@app.middleware("http")` functions
+/// and `FastAPI(middleware=[Middleware(GZipMiddleware)])`, at module level or inside an
+/// app factory function. Like Starlette, the last middleware added is the outermost one.
+pub fn discover_middleware(entry: &Path) -> Vec<String> {
+    let Ok(source) = std::fs::read_to_string(entry) else {
+        return Vec::new();
+    };
+    let Ok(ast::Mod::Module(module)) =
+        parse(&source, Mode::Module, entry.to_string_lossy().as_ref())
+    else {
+        return Vec::new();
+    };
+
+    let mut stack = Vec::new();
+    collect_middleware(&module.body, &mut stack);
+    stack
+}
+
+/// Tags every route of an app graph with the app's middleware stack
+pub fn attach_middleware(graph: &mut CallGraph, middleware: &[String]) {
+    if middleware.is_empty() {
+        return;
+    }
+    let stack = middleware.join(",");
+    for node in graph.node_weights_mut() {
+        if let CallNode::Route { metadata, .. } = node {
+            metadata.insert(MIDDLEWARE_METADATA_KEY.to_string(), stack.clone());
+        }
+    }
+}
+
+fn collect_middleware(body: &[ast::Stmt], stack: &mut Vec<String>) {
+    for stmt in body {
+        match stmt {
+            ast::Stmt::FunctionDef(ast::StmtFunctionDef {
+                name,
+                decorator_list,
+                body,
+                ..
+            })
+            | ast::Stmt::AsyncFunctionDef(ast::StmtAsyncFunctionDef {
+                name,
+                decorator_list,
+                body,
+                ..
+            }) => {
+                let is_middleware = decorator_list.iter().any(|decorator| {
+                    let ast::Expr::Call(call) = decorator else {
+                        return false;
+                    };
+                    matches!(
+                        call.func.as_ref(),
+                        ast::Expr::Attribute(attr) if attr.attr.as_str() == "middleware"
+                    )
+                });
+                if is_middleware {
+                    stack.insert(0, name.to_string());
+                } else {
+                    collect_middleware(body, stack);
+                }
+            }
+            ast::Stmt::Expr(expr_stmt) => {
+                let ast::Expr::Call(call) = expr_stmt.value.as_ref() else {
+                    continue;
+                };
+                let ast::Expr::Attribute(func) = call.func.as_ref() else {
+                    continue;
+                };
+                if func.attr.as_str() == "add_middleware" {
+                    if let Some(name) = call.args.first().and_then(expr_name) {
+                        stack.insert(0, name);
+                    }
+                }
+            }
+            ast::Stmt::Assign(_) | ast::Stmt::AnnAssign(_) => {
+                if let Some(call) = fastapi_app_call(stmt) {
+                    stack.extend(constructor_middleware(call));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Middleware classes of `FastAPI(middleware=[Middleware(X, ...), ...])`, in list order
+fn constructor_middleware(call: &ast::ExprCall) -> Vec<String> {
+    let Some(ast::Expr::List(list)) = call
+        .keywords
+        .iter()
+        .find(|keyword| {
+            keyword
+                .arg
+                .as_ref()
+                .is_some_and(|arg| arg.as_str() == "middleware")
+        })
+        .map(|keyword| &keyword.value)
+    else {
+        return Vec::new();
+    };
+    list.elts
+        .iter()
+        .filter_map(|element| match element {
+            ast::Expr::Call(middleware) => middleware.args.first().and_then(expr_name),
+            _ => None,
+        })
+        .collect()
+}
+
+/// `CORSMiddleware` for `CORSMiddleware` and `starlette.middleware.cors.CORSMiddleware`
+fn expr_name(expr: &ast::Expr) -> Option<String> {
+    match expr {
+        ast::Expr::Name(name) => Some(name.id.to_string()),
+        ast::Expr::Attribute(attr) => Some(attr.attr.to_string()),
+        _ => None,
+    }
+}
+
+/// Appends `source` to `target`, shifting all node references by the current node count
+pub(crate) fn append_graph(target: &mut CallGraph, source: CallGraph) {
+    let offset = target.node_count();
+    let shift = |id: &mut NodeId| *id = NodeId::from(NodeIndex::new(id.index() + offset));
+
+    let (nodes, edges) = source.into_nodes_edges();
+    for node in nodes {
+        let mut weight = node.weight;
+        match &mut weight {
+            CallNode::Route { handler, .. } => shift(handler),
+            CallNode::Class { methods, .. } => methods.iter_mut().for_each(shift),
+            CallNode::Method { class, .. } => shift(class),
+            _ => {}
+        }
+        target.add_node(weight);
+    }
+    for edge in edges {
+        let (source, target_node) = (edge.source().index(), edge.target().index());
+        let mut weight = edge.weight;
+        match &mut weight {
+            CallEdge::Import { from, to, .. }
+            | CallEdge::Return { from, to, .. }
+            | CallEdge::DataFlow { from, to, .. }
+            | CallEdge::Relationship { from, to, .. } => {
+                shift(from);
+                shift(to);
+            }
+            CallEdge::Call { caller, callee, .. } => {
+                shift(caller);
+                shift(callee);
+            }
+        }
+        target.add_edge(
+            NodeIndex::new(source + offset),
+            NodeIndex::new(target_node + offset),
+            weight,
+        );
+    }
+}
+
+/// Modules providing the routers passed to `include_router(...)` in `entry`
+///
+/// Handles `from shared.health import router` + `include_router(router)` and
+/// `from routers import users` + `include_router(users.router)`. Modules are resolved from
+/// `import_root` first, then from the entry's directory.
+pub fn included_router_modules(entry: &Path, import_root: &Path) -> Vec<PathBuf> {
+    let Ok(source) = std::fs::read_to_string(entry) else {
+        return Vec::new();
+    };
+    let Ok(ast::Mod::Module(module)) =
+        parse(&source, Mode::Module, entry.to_string_lossy().as_ref())
+    else {
+        return Vec::new();
+    };
+
+    // Imported name -> dotted module path the name may live in (or be)
+    let mut imported: HashMap<String, Vec<String>> = HashMap::new();
+    for stmt in &module.body {
+        match stmt {
+            ast::Stmt::ImportFrom(import_from) => {
+                let Some(base) = import_from.module.as_ref() else {
+                    continue;
+                };
+                for alias in &import_from.names {
+                    let local = alias.asname.as_ref().unwrap_or(&alias.name).to_string();
+                    imported.insert(
+                        local,
+                        vec![format!("{}.{}", base, alias.name), base.to_string()],
+                    );
+                }
+            }
+            ast::Stmt::Import(import) => {
+                for alias in &import.names {
+                    let local = alias.asname.as_ref().unwrap_or(&alias.name).to_string();
+                    imported.insert(local, vec![alias.name.to_string()]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let entry_dir = entry.parent().unwrap_or(import_root);
+    let mut modules = Vec::new();
+    for stmt in &module.body {
+        let ast::Stmt::Expr(expr_stmt) = stmt else {
+            continue;
+        };
+        let ast::Expr::Call(call) = expr_stmt.value.as_ref() else {
+            continue;
+        };
+        let ast::Expr::Attribute(func) = call.func.as_ref() else {
+            continue;
+        };
+        if func.attr.as_str() != "include_router" {
+            continue;
+        }
+        let router_name = match call.args.first() {
+            Some(ast::Expr::Name(name)) => name.id.to_string(),
+            Some(ast::Expr::Attribute(attr)) => match attr.value.as_ref() {
+                ast::Expr::Name(name) => name.id.to_string(),
+                _ => continue,
+            },
+            _ => continue,
+        };
+
+        let candidates = imported.get(&router_name).cloned().unwrap_or_default();
+        let resolved = candidates.iter().find_map(|dotted| {
+            [import_root, entry_dir]
+                .iter()
+                .find_map(|root| resolve_module_file(root, dotted))
+        });
+        if let Some(file) = resolved {
+            if !modules.contains(&file) {
+                modules.push(file);
+            }
+        }
+    }
+
+    modules
+}
+
+fn resolve_module_file(root: &Path, dotted: &str) -> Option<PathBuf> {
+    let path = root.join(dotted.replace('.', std::path::MAIN_SEPARATOR_STR));
+    let file = path.with_extension("py");
+    if file.is_file() {
+        return Some(file);
+    }
+    let package = path.join("__init__.py");
+    package.is_file().then_some(package)
+}
+
+/// Returns `app` for `app = FastAPI(...)` / `app: FastAPI = fastapi.FastAPI(...)`
+fn fastapi_app_variable(stmt: &ast::Stmt) -> Option<String> {
+    let target = match stmt {
+        ast::Stmt::Assign(assign) => assign.targets.first()?,
+        ast::Stmt::AnnAssign(ann_assign) => ann_assign.target.as_ref(),
+        _ => return None,
+    };
+    let ast::Expr::Name(name) = target else {
+        return None;
+    };
+    fastapi_app_call(stmt).map(|_| name.id.to_string())
+}
+
+/// The `FastAPI(...)` call assigned by `stmt`, if any
+fn fastapi_app_call(stmt: &ast::Stmt) -> Option<&ast::ExprCall> {
+    let value = match stmt {
+        ast::Stmt::Assign(assign) => assign.value.as_ref(),
+        ast::Stmt::AnnAssign(ann_assign) => ann_assign.value.as_deref()?,
+        _ => return None,
+    };
+    let ast::Expr::Call(call) = value else {
+        return None;
+    };
+    let is_fastapi = match call.func.as_ref() {
+        ast::Expr::Name(func) => func.id.as_str() == "FastAPI",
+        ast::Expr::Attribute(attr) => attr.attr.as_str() == "FastAPI",
+        _ => false,
+    };
+    is_fastapi.then_some(call)
+}
+
+fn service_name(root: &Path, file: &Path, full_path: bool) -> String {
+    let relative_dir = file
+        .parent()
+        .and_then(|dir| dir.strip_prefix(root).ok())
+        .filter(|dir| !dir.as_os_str().is_empty());
+
+    match relative_dir {
+        Some(dir) if full_path => dir
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<_>>()
+            .join("/"),
+        Some(dir) => dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        None => file
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+/// Whether a file is inside a hidden or never-application directory below `root`
+pub(crate) fn in_skipped_dir(root: &Path, file: &Path) -> bool {
+    let relative_dir = file.strip_prefix(root).ok().and_then(Path::parent);
+    relative_dir.is_some_and(|dir| {
+        dir.components().any(|component| {
+            let name = component.as_os_str().to_string_lossy();
+            name.starts_with('.') || SKIPPED_DIRS.contains(&name.as_ref())
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let full_path = root.join(path);
+        fs::create_dir_all(full_path.parent().unwrap()).unwrap();
+        fs::write(full_path, content).unwrap();
+    }
+
+    #[test]
+    fn test_discover_apps_names_services_by_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        write(
+            root,
+            "users/main.py",
+            "from fastapi import FastAPI\napp = FastAPI()\n",
+        );
+        write(
+            root,
+            "billing/main.py",
+            "import fastapi\napi = fastapi.FastAPI(title='billing')\n",
+        );
+        write(
+            root,
+            "gateway.py",
+            "from fastapi import FastAPI\napp: FastAPI = FastAPI()\n",
+        );
+        write(
+            root,
+            "shared/routes.py",
+            "from fastapi import APIRouter\nrouter = APIRouter()\n",
+        );
+        write(root, "venv/lib/fastapi_app.py", "app = FastAPI()\n");
+
+        let apps = discover_apps(root, true);
+        let services: Vec<_> = apps.iter().map(|a| a.service.as_str()).collect();
+        assert_eq!(services, vec!["billing", "gateway", "users"]);
+        assert_eq!(apps[0].variable, "api");
+    }
+
+    #[test]
+    fn test_discover_apps_disambiguates_colliding_service_names() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        write(
+            root,
+            "a/api/main.py",
+            "from fastapi import FastAPI\napp = FastAPI()\n",
+        );
+        write(
+            root,
+            "b/api/main.py",
+            "from fastapi import FastAPI\napp = FastAPI()\n",
+        );
+
+        let services: Vec<_> = discover_apps(root, true)
+            .into_iter()
+            .map(|a| a.service)
+            .collect();
+        assert_eq!(services, vec!["a/api", "b/api"]);
+    }
+
+    #[test]
+    fn test_discover_apps_skips_gitignored_modules() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        write(root, ".gitignore", "build/\n");
+        write(
+            root,
+            "users/main.py",
+            "from fastapi import FastAPI\napp = FastAPI()\n",
+        );
+        write(
+            root,
+            "build/users/main.py",
+            "from fastapi import FastAPI\napp = FastAPI()\n",
+        );
+
+        let services: Vec<_> = discover_apps(root, true)
+            .into_iter()
+            .map(|a| a.service)
+            .collect();
+        assert_eq!(services, vec!["users"]);
+
+        let services: Vec<_> = discover_apps(root, false)
+            .into_iter()
+            .map(|a| a.service)
+            .collect();
+        assert_eq!(services, vec!["build/users", "users"]);
+    }
+
+    #[test]
+    fn test_discover_middleware_orders_stack_outermost_first() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        write(
+            root,
+            "main.py",
+            r#"from fastapi import FastAPI
+from starlette.middleware import Middleware
+from starlette.middleware.gzip import GZipMiddleware
+from fastapi.middleware.cors import CORSMiddleware
+
+app = FastAPI(middleware=[Middleware(GZipMiddleware)])
+app.add_middleware(CORSMiddleware, allow_origins=["*"])
+
+@app.middleware("http")
+async def add_timing(request, call_next):
+    return await call_next(request)
+"#,
+        );
+
+        assert_eq!(
+            discover_middleware(&root.join("main.py")),
+            vec!["add_timing", "CORSMiddleware", "GZipMiddleware"]
+        );
+    }
+
+    #[test]
+    fn test_discover_middleware_in_app_factory() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        write(
+            root,
+            "main.py",
+            "import fastapi
+
+def create_app():
+    app = fastapi.FastAPI()
+    app.add_middleware(fastapi.middleware.cors.CORSMiddleware)
+    return app
+",
+        );
+        write(
+            root,
+            "plain.py",
+            "from fastapi import FastAPI
+app = FastAPI()
+",
+        );
+
+        assert_eq!(
+            discover_middleware(&root.join("main.py")),
+            vec!["CORSMiddleware"]
+        );
+        assert!(discover_middleware(&root.join("plain.py")).is_empty());
+    }
+}
@@ -1,17 +1,32 @@
+use crate::apps::{
+    append_graph, attach_middleware, discover_apps, discover_middleware, in_skipped_dir,
+    included_router_modules, namespace_routes, route_inventory, FastApiApp,
+};
 use crate::dynamic_routes::{DynamicRoutesAnalyzer, DynamicRoutesConfig};
 use crate::pydantic::PydanticExtractor;
 use anyhow::Result;
-use dc_core::call_graph::{CallGraph, CallGraphBuilder, CallNode, HttpMethod};
-use dc_core::models::{Location, NodeId};
-use dc_core::openapi::{OpenAPILinker, OpenAPIParser, OpenAPISchema};
+use dc_core::call_graph::{CallGraph, CallGraphBuilder, CallNode, HttpMethod, RoutePattern};
+use dc_core::discovery::find_source_files;
+use dc_core::models::{AnalysisWarning, Diagnostic, Location, NodeId, TypeCompatibility};
+use dc_core::openapi::{FetchOptions, OpenAPILinker, OpenAPIParser, OpenAPISchema};
 use std::path::{Path, PathBuf};
-use tracing::{debug, warn};
+use std::time::Instant;
+use tracing::{debug, info, warn};
 
 /// Call graph builder for FastAPI application
 pub struct FastApiCallGraphBuilder {
-    core_builder: CallGraphBuilder,
     app_path: PathBuf,
+    strict_imports: bool,
+    strict_parse: bool,
+    external_modules: Vec<String>,
+    max_depth: Option<usize>,
+    deadline: Option<Instant>,
+    transformation_methods: Vec<String>,
+    type_compatibility: TypeCompatibility,
+    app_objects: Vec<String>,
+    route_patterns: Vec<RoutePattern>,
     verbose: bool,
+    respect_gitignore: bool,
     openapi_schema: Option<OpenAPISchema>,
     openapi_linker: Option<OpenAPILinker>,
     dynamic_routes_config: Option<DynamicRoutesConfig>,
@@ -20,11 +35,19 @@ pub struct FastApiCallGraphBuilder {
 impl FastApiCallGraphBuilder {
     /// Creates a new builder
     pub fn new(app_path: PathBuf) -> Self {
-        let extractor = Box::new(PydanticExtractor::new());
         Self {
-            core_builder: CallGraphBuilder::new().with_schema_extractor(extractor),
             app_path,
+            strict_imports: false,
+            strict_parse: false,
+            external_modules: Vec::new(),
+            max_depth: None,
+            deadline: None,
+            transformation_methods: Vec::new(),
+            type_compatibility: TypeCompatibility::default(),
+            app_objects: Vec::new(),
+            route_patterns: Vec::new(),
             verbose: false,
+            respect_gitignore: true,
             openapi_schema: None,
             openapi_linker: None,
             dynamic_routes_config: None,
@@ -33,40 +56,105 @@ impl FastApiCallGraphBuilder {
 
     /// Enables or disables strict import resolution in the underlying core builder
     pub fn with_strict_imports(mut self, strict_imports: bool) -> Self {
-        self.core_builder = self.core_builder.with_strict_imports(strict_imports);
+        self.strict_imports = strict_imports;
+        self
+    }
+
+    /// Fails on the first Python file with a syntax error instead of skipping it
+    pub fn with_strict_parse(mut self, strict_parse: bool) -> Self {
+        self.strict_parse = strict_parse;
+        self
+    }
+
+    /// Sets whether modules ignored by `.gitignore` are skipped when looking for the apps of
+    /// a directory `app_path` (default: `true`)
+    pub fn with_respect_gitignore(mut self, respect_gitignore: bool) -> Self {
+        self.respect_gitignore = respect_gitignore;
+        self
+    }
+
+    /// Sets module prefixes known to be external: their uninstalled imports are skipped
+    /// without an analysis warning
+    pub fn with_external_modules(mut self, external_modules: Vec<String>) -> Self {
+        self.external_modules = external_modules;
         self
     }
 
     /// Sets the maximum recursion depth
     pub fn with_max_depth(mut self, max_depth: Option<usize>) -> Self {
-        self.core_builder = self.core_builder.with_max_depth(max_depth);
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Stops reading files once `deadline` has passed, leaving the graph partial
+    /// (see `CallGraphBuilder::with_deadline`)
+    pub fn with_deadline(mut self, deadline: Option<Instant>) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Sets additional method names treated as data transformations (e.g. `to_response`)
+    pub fn with_transformation_methods(mut self, methods: Vec<String>) -> Self {
+        self.transformation_methods = methods;
+        self
+    }
+
+    /// Sets the type compatibility matrix used when linking Pydantic and ORM models
+    pub fn with_type_compatibility(mut self, type_compatibility: TypeCompatibility) -> Self {
+        self.type_compatibility = type_compatibility;
+        self
+    }
+
+    /// Sets the app/router variables whose decorators declare routes
+    /// (e.g. `application` for `application = FastAPI()` in `server.py`)
+    ///
+    /// When empty, common names such as `app` and `router` are recognized.
+    pub fn with_app_objects(mut self, app_objects: Vec<String>) -> Self {
+        self.app_objects = app_objects;
+        self
+    }
+
+    /// Registers project-specific route decorators recognized alongside the FastAPI ones
+    pub fn with_route_patterns(mut self, route_patterns: Vec<RoutePattern>) -> Self {
+        self.route_patterns = route_patterns;
         self
     }
 
     /// Sets the verbose flag for debug output
     pub fn with_verbose(mut self, verbose: bool) -> Self {
         self.verbose = verbose;
-        self.core_builder = self.core_builder.with_verbose(verbose);
         self
     }
 
-    /// Sets the OpenAPI schema path
+    /// Sets the OpenAPI schema path (a file or an `http(s)://` URL)
     /// If provided, the builder will use OpenAPI schema to enhance route detection
-    pub fn with_openapi_schema(mut self, openapi_path: Option<PathBuf>) -> Self {
-        if let Some(path) = openapi_path {
-            if let Ok(schema) = OpenAPIParser::parse_file(&path) {
-                debug!(
-                    openapi_path = ?path,
-                    "Loaded OpenAPI schema"
-                );
-                self.openapi_schema = Some(schema.clone());
-                self.openapi_linker = Some(OpenAPILinker::new(schema));
-            } else {
-                warn!(
-                    openapi_path = ?path,
-                    "Failed to parse OpenAPI schema"
-                );
+    pub fn with_openapi_schema(self, openapi_path: Option<PathBuf>) -> Self {
+        let schema = openapi_path.and_then(|path| {
+            match OpenAPIParser::parse_source(&path.to_string_lossy(), &FetchOptions::default()) {
+                Ok(schema) => {
+                    debug!(
+                        openapi_path = ?path,
+                        "Loaded OpenAPI schema"
+                    );
+                    Some(schema)
+                }
+                Err(_) => {
+                    warn!(
+                        openapi_path = ?path,
+                        "Failed to parse OpenAPI schema"
+                    );
+                    None
+                }
             }
+        });
+        self.with_openapi_spec(schema)
+    }
+
+    /// Sets an already parsed OpenAPI schema (e.g. fetched once from a URL)
+    pub fn with_openapi_spec(mut self, schema: Option<OpenAPISchema>) -> Self {
+        if let Some(schema) = schema {
+            self.openapi_schema = Some(schema.clone());
+            self.openapi_linker = Some(OpenAPILinker::new(schema));
         }
         self
     }
@@ -78,65 +166,239 @@ impl FastApiCallGraphBuilder {
     }
 
     /// Builds graph for FastAPI application
-    /// Consumes self, as it calls into_graph() on core_builder
+    ///
+    /// When `app_path` is a directory hosting several FastAPI apps (one per service),
+    /// each app is built separately and its routes are namespaced by service.
     pub fn build_graph(self) -> Result<CallGraph> {
-        // Determine project root
-        let project_root = Self::find_project_root(&self.app_path);
+        self.build_graph_with_diagnostics().map(|(graph, _)| graph)
+    }
+
+    /// Builds the graph and returns the problems found on the way
+    /// (files skipped because of syntax errors, ...)
+    pub fn build_graph_with_diagnostics(self) -> Result<(CallGraph, Vec<Diagnostic>)> {
+        self.build_graph_with_warnings()
+            .map(|(graph, diagnostics, _)| (graph, diagnostics))
+    }
+
+    /// Builds the graph and returns the problems found on the way along with what the
+    /// analysis couldn't follow (unresolved imports, skipped modules, ...)
+    pub fn build_graph_with_warnings(
+        self,
+    ) -> Result<(CallGraph, Vec<Diagnostic>, Vec<AnalysisWarning>)> {
+        if self.app_path.is_dir() {
+            let apps = discover_apps(&self.app_path, self.respect_gitignore);
+            if apps.len() > 1 {
+                return self.build_multi_app_graph(&apps);
+            }
+        }
+
+        let entry_point = self.single_entry_point()?;
+        let (mut graph, diagnostics, warnings) = self.build_app_graph(&entry_point, None)?;
+        self.enhance_routes_with_openapi(&mut graph);
+
+        Ok((graph, diagnostics, warnings))
+    }
+
+    /// Files the graph is built from: each app of a directory holding several FastAPI apps,
+    /// or the single entry point
+    pub fn entry_points(&self) -> Result<Vec<PathBuf>> {
+        if self.app_path.is_dir() {
+            let apps = discover_apps(&self.app_path, self.respect_gitignore);
+            if apps.len() > 1 {
+                return Ok(apps.into_iter().map(|app| app.file).collect());
+            }
+        }
+        Ok(vec![self.single_entry_point()?])
+    }
 
-        // Find entry point
-        let entry_point = if self.app_path.exists() && self.app_path.is_file() {
-            // If app_path points to specific file, use it
+    /// Python files under the project root, which imports of the entry points resolve to
+    ///
+    /// Hidden directories, virtualenvs and caches are left out.
+    pub fn source_files(&self) -> Vec<PathBuf> {
+        let root = if self.app_path.is_dir() {
             self.app_path.clone()
         } else {
-            // Otherwise search for standard entry point
-            self.core_builder.find_entry_point(&project_root)?
+            Self::find_project_root(&self.app_path)
         };
+        find_source_files(&root, &["py"], self.respect_gitignore)
+            .into_iter()
+            .filter(|file| !in_skipped_dir(&root, file))
+            .collect()
+    }
+
+    /// Entry point of a single-app project: `app_path` itself when it's a file, otherwise
+    /// the standard entry point found under the project root
+    fn single_entry_point(&self) -> Result<PathBuf> {
+        if self.app_path.exists() && self.app_path.is_file() {
+            Ok(self.app_path.clone())
+        } else {
+            let project_root = Self::find_project_root(&self.app_path);
+            self.new_core_builder().find_entry_point(&project_root)
+        }
+    }
+
+    /// Builds one graph per app and merges them, tagging routes with their service
+    ///
+    /// A router included by several apps is built once per app, so its routes are
+    /// listed under every service that serves them.
+    fn build_multi_app_graph(
+        &self,
+        apps: &[FastApiApp],
+    ) -> Result<(CallGraph, Vec<Diagnostic>, Vec<AnalysisWarning>)> {
+        let mut graph = CallGraph::new();
+        let mut diagnostics: Vec<Diagnostic> = Vec::new();
+        let mut warnings: Vec<AnalysisWarning> = Vec::new();
+
+        for app in apps {
+            // Apps not reached before the deadline are left out of the graph
+            if self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+            {
+                break;
+            }
+            debug!(
+                service = %app.service,
+                file = ?app.file,
+                app_variable = %app.variable,
+                "Building FastAPI service"
+            );
+            let (mut app_graph, app_diagnostics, app_warnings) =
+                self.build_app_graph(&app.file, Some(&self.app_path))?;
+            namespace_routes(&mut app_graph, &app.service);
+            append_graph(&mut graph, app_graph);
+            // Files shared between services are reported once
+            for diagnostic in app_diagnostics {
+                if !diagnostics.contains(&diagnostic) {
+                    diagnostics.push(diagnostic);
+                }
+            }
+            for warning in app_warnings {
+                if !warnings.contains(&warning) {
+                    warnings.push(warning);
+                }
+            }
+        }
 
+        for (service, routes) in route_inventory(&graph) {
+            info!(
+                service = %service,
+                route_count = routes.len(),
+                routes = ?routes,
+                "FastAPI service route inventory"
+            );
+        }
+
+        self.enhance_routes_with_openapi(&mut graph);
+
+        Ok((graph, diagnostics, warnings))
+    }
+
+    /// Creates a core builder with this builder's settings
+    fn new_core_builder(&self) -> CallGraphBuilder {
+        CallGraphBuilder::new()
+            .with_schema_extractor(Box::new(PydanticExtractor::new()))
+            .with_strict_imports(self.strict_imports)
+            .with_strict_parse(self.strict_parse)
+            .with_external_modules(self.external_modules.clone())
+            .with_max_depth(self.max_depth)
+            .with_deadline(self.deadline)
+            .with_transformation_methods(self.transformation_methods.clone())
+            .with_type_compatibility(self.type_compatibility.clone())
+            .with_route_objects(self.app_objects.clone())
+            .with_route_patterns(self.route_patterns.clone())
+            .with_verbose(self.verbose)
+    }
+
+    /// Builds the graph of a single app starting from its entry point
+    ///
+    /// `import_root` overrides the directory imports are resolved from (the monorepo root
+    /// for multi-app projects, so routers shared between services resolve).
+    fn build_app_graph(
+        &self,
+        entry_point: &Path,
+        import_root: Option<&Path>,
+    ) -> Result<(CallGraph, Vec<Diagnostic>, Vec<AnalysisWarning>)> {
         // Build call graph from entry point
         // CallGraphBuilder will automatically handle:
         // - Imports
         // - Functions and classes
         // - Function calls
         // - FastAPI decorators (@app.get, @app.post, etc.)
-        let mut core_builder = self.core_builder;
-        core_builder.build_from_entry(&entry_point)?;
+        let mut core_builder = self.new_core_builder();
+        if let Some(root) = import_root {
+            core_builder = core_builder.with_project_root(root.to_path_buf());
+        }
+        core_builder.build_from_entry(entry_point)?;
+
+        // Routers mounted with `include_router` may live in modules the entry never calls into
+        let router_root = import_root
+            .map(Path::to_path_buf)
+            .or_else(|| entry_point.parent().map(Path::to_path_buf))
+            .unwrap_or_default();
+        for router_module in included_router_modules(entry_point, &router_root) {
+            if let Err(err) = core_builder.build_from_entry(&router_module) {
+                warn!(
+                    router_module = ?router_module,
+                    error = %err,
+                    "Failed to build graph for included router module"
+                );
+            }
+        }
 
-        // Store verbose and openapi_linker before moving self
-        let verbose = self.verbose;
-        let openapi_linker = self.openapi_linker;
+        // Imported modules are built best-effort, so strict parsing is enforced here
+        let diagnostics = core_builder.diagnostics().to_vec();
+        let warnings = core_builder.analysis_warnings().to_vec();
+        if self.strict_parse {
+            if let Some(diagnostic) = diagnostics.first() {
+                anyhow::bail!("{}", diagnostic.message);
+            }
+        }
 
         // Analyze dynamic routes (fastapi_users, etc.)
         let mut dynamic_analyzer =
             DynamicRoutesAnalyzer::new().with_config(self.dynamic_routes_config.clone());
 
         // Get the graph (before dynamic routes processing)
-        let mut graph =
-            if let Ok(dynamic_endpoints) = dynamic_analyzer.analyze_main_file(&entry_point) {
-                if !dynamic_endpoints.is_empty() {
-                    debug!(
-                        endpoint_count = dynamic_endpoints.len(),
-                        entry_point = ?entry_point,
-                        "Found dynamic endpoints"
-                    );
+        let mut graph = if let Ok(dynamic_endpoints) =
+            dynamic_analyzer.analyze_main_file(entry_point)
+        {
+            if !dynamic_endpoints.is_empty() {
+                debug!(
+                    endpoint_count = dynamic_endpoints.len(),
+                    entry_point = ?entry_point,
+                    "Found dynamic endpoints"
+                );
 
-                    // Create virtual route nodes in the graph
-                    let mut graph = core_builder.into_graph();
-                    dynamic_analyzer.create_virtual_routes(
-                        &mut graph,
-                        &dynamic_endpoints,
-                        &entry_point,
-                    );
-                    graph
-                } else {
-                    core_builder.into_graph()
-                }
+                // Create virtual route nodes in the graph
+                let mut graph = core_builder.into_graph();
+                dynamic_analyzer.create_virtual_routes(&mut graph, &dynamic_endpoints, entry_point);
+                graph
             } else {
                 core_builder.into_graph()
-            };
+            }
+        } else {
+            core_builder.into_graph()
+        };
 
-        // Enhance routes with OpenAPI information
-        if let Some(linker) = openapi_linker {
-            if let Err(err) = Self::enhance_routes_with_openapi_static(&linker, &mut graph, verbose)
+        // Middleware is registered on the app, so it applies to every route it serves
+        let middleware = discover_middleware(entry_point);
+        if !middleware.is_empty() {
+            debug!(
+                entry_point = ?entry_point,
+                middleware = ?middleware,
+                "Found app middleware"
+            );
+        }
+        attach_middleware(&mut graph, &middleware);
+
+        Ok((graph, diagnostics, warnings))
+    }
+
+    /// Enhances routes with OpenAPI information when a schema is configured
+    fn enhance_routes_with_openapi(&self, graph: &mut CallGraph) {
+        if let Some(linker) = &self.openapi_linker {
+            if let Err(err) = Self::enhance_routes_with_openapi_static(linker, graph, self.verbose)
             {
                 warn!(
                     error = %err,
@@ -144,8 +406,6 @@ impl FastApiCallGraphBuilder {
                 );
             }
         }
-
-        Ok(graph)
     }
 
     /// Finds project root by going up from app_path and searching for project markers
@@ -197,6 +457,15 @@ impl FastApiCallGraphBuilder {
                 }
             })
             .collect();
+        // Routes declared with `include_in_schema=False` are intentionally undocumented
+        let excluded_routes: Vec<(String, HttpMethod)> = graph
+            .node_weights()
+            .filter(|node| node.is_excluded_from_schema())
+            .filter_map(|node| match node {
+                CallNode::Route { path, method, .. } => Some((path.clone(), *method)),
+                _ => None,
+            })
+            .collect();
 
         debug!(
             route_count = route_nodes.len(),
@@ -205,6 +474,14 @@ impl FastApiCallGraphBuilder {
 
         // For each route, find corresponding OpenAPI endpoint and enrich
         for (node_id, path, method) in &route_nodes {
+            if excluded_routes.contains(&(path.clone(), *method)) {
+                debug!(
+                    http_method = ?method,
+                    route_path = %path,
+                    "Route excluded from OpenAPI schema (include_in_schema=False)"
+                );
+                continue;
+            }
             if let Some(endpoint) = linker.match_route_to_endpoint(path, *method) {
                 debug!(
                     http_method = ?method,
@@ -281,7 +558,8 @@ impl FastApiCallGraphBuilder {
             .iter()
             .map(|(_, path, method)| (path.clone(), *method))
             .collect();
-        let (missing_in_openapi, missing_in_code) = linker.validate_routes(&discovered_routes);
+        let (mut missing_in_openapi, missing_in_code) = linker.validate_routes(&discovered_routes);
+        missing_in_openapi.retain(|route| !excluded_routes.contains(route));
 
         if verbose {
             if !missing_in_openapi.is_empty() {
@@ -359,6 +637,7 @@ impl FastApiCallGraphBuilder {
                     },
                     request_schema: None,
                     response_schema: None,
+                    metadata: std::collections::HashMap::new(),
                 });
 
                 debug!(
@@ -418,4 +697,140 @@ mod tests {
         // Should return parent of app_path
         assert_eq!(found_root, app_path.parent().unwrap());
     }
+
+    #[test]
+    fn test_build_graph_namespaces_routes_of_multiple_apps() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let write = |path: &str, content: &str| {
+            let full_path = root.join(path);
+            fs::create_dir_all(full_path.parent().unwrap()).unwrap();
+            fs::write(full_path, content).unwrap();
+        };
+        write(
+            "shared/health.py",
+            "from fastapi import APIRouter\n\nrouter = APIRouter()\n\n@router.get(\"/health\")\ndef health():\n    return {}\n",
+        );
+        write(
+            "users/main.py",
+            "from fastapi import FastAPI\nfrom shared.health import router\n\napp = FastAPI()\napp.include_router(router)\n\n@app.get(\"/items\")\ndef list_users():\n    return []\n",
+        );
+        write(
+            "orders/main.py",
+            "from fastapi import FastAPI\nfrom shared.health import router\n\napp = FastAPI()\napp.include_router(router)\n\n@app.get(\"/items\")\ndef list_orders():\n    return []\n",
+        );
+
+        let graph = FastApiCallGraphBuilder::new(root.to_path_buf())
+            .build_graph()
+            .unwrap();
+        let inventory = route_inventory(&graph);
+
+        assert_eq!(
+            inventory.keys().collect::<Vec<_>>(),
+            vec!["orders", "users"]
+        );
+        for routes in inventory.values() {
+            assert!(routes.contains(&"GET /items".to_string()), "{:?}", routes);
+            assert!(routes.contains(&"GET /health".to_string()), "{:?}", routes);
+        }
+
+        let tracker = dc_core::data_flow::DataFlowTracker::new(&graph);
+        let chains = dc_core::analyzers::ChainBuilder::new(&graph, &tracker)
+            .find_all_chains()
+            .unwrap();
+        assert!(chains.iter().any(|c| c.name == "[users] GET /items"));
+        assert!(chains.iter().any(|c| c.name == "[orders] GET /items"));
+    }
+
+    #[test]
+    fn test_build_graph_attaches_middleware_stack_to_routes() {
+        let temp_dir = TempDir::new().unwrap();
+        let main_path = temp_dir.path().join("main.py");
+        fs::write(
+            &main_path,
+            "from fastapi import FastAPI\nfrom fastapi.middleware.cors import CORSMiddleware\n\napp = FastAPI()\napp.add_middleware(CORSMiddleware)\n\n@app.middleware(\"http\")\nasync def add_timing(request, call_next):\n    return await call_next(request)\n\n@app.get(\"/items\")\ndef list_items():\n    return []\n",
+        )
+        .unwrap();
+
+        let graph = FastApiCallGraphBuilder::new(main_path)
+            .build_graph()
+            .unwrap();
+        let inventory = route_inventory(&graph);
+
+        assert_eq!(
+            inventory[""],
+            vec!["GET /items [add_timing, CORSMiddleware]".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_routes_excluded_from_schema_are_not_compared_with_openapi() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("main.py"),
+            "from fastapi import FastAPI\n\napp = FastAPI()\n\n@app.get(\"/health\", include_in_schema=False)\ndef health():\n    return {}\n\n@app.get(\"/items\")\ndef list_items():\n    return []\n",
+        )
+        .unwrap();
+        let spec = serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "demo", "version": "1.0.0" },
+            "paths": {
+                "/health": { "get": { "responses": { "200": {
+                    "description": "OK",
+                    "content": { "application/json": {
+                        "schema": { "$ref": "#/components/schemas/Health" }
+                    } }
+                } } } },
+                "/items": { "get": { "responses": { "200": {
+                    "description": "OK",
+                    "content": { "application/json": {
+                        "schema": { "$ref": "#/components/schemas/ItemList" }
+                    } }
+                } } } }
+            },
+            "components": { "schemas": {
+                "Health": { "type": "object", "properties": { "ok": { "type": "boolean" } } },
+                "ItemList": { "type": "object", "properties": { "total": { "type": "integer" } } }
+            } }
+        });
+        let spec_path = root.join("openapi.json");
+        fs::write(&spec_path, spec.to_string()).unwrap();
+
+        let graph = FastApiCallGraphBuilder::new(root.join("main.py"))
+            .with_openapi_schema(Some(spec_path))
+            .build_graph()
+            .unwrap();
+
+        let return_schema = |route_path: &str| {
+            let handler = graph
+                .node_weights()
+                .find_map(|node| match node {
+                    CallNode::Route { path, handler, .. } if path == route_path => {
+                        assert_eq!(node.is_excluded_from_schema(), route_path == "/health");
+                        Some(*handler)
+                    }
+                    _ => None,
+                })
+                .unwrap_or_else(|| panic!("route {} should stay in the graph", route_path));
+            match graph.node_weight(handler.0) {
+                Some(CallNode::Function { return_type, .. }) => return_type
+                    .as_ref()
+                    .and_then(|rt| rt.schema_ref.as_ref())
+                    .map(|schema| schema.name.clone()),
+                _ => None,
+            }
+        };
+
+        assert_eq!(return_schema("/items").as_deref(), Some("ItemList"));
+        assert_eq!(return_schema("/health"), None);
+        // The documented /health endpoint is not turned into a second, virtual route
+        assert_eq!(
+            graph
+                .node_weights()
+                .filter(|node| matches!(node, CallNode::Route { path, .. } if path == "/health"))
+                .count(),
+            1
+        );
+    }
 }
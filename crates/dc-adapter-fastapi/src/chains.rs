@@ -0,0 +1,136 @@
+use crate::call_graph::FastApiCallGraphBuilder;
+use anyhow::Result;
+use dc_core::analyzers::ChainBuilder;
+use dc_core::call_graph::{CallGraph, CallNode};
+use dc_core::data_flow::DataFlowTracker;
+use dc_core::models::{ChainDirection, DataChain, TransformationType};
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// Data chain of a FastAPI route, as handed to Python callers
+#[derive(Debug, Clone, Serialize)]
+pub struct ChainSummary {
+    /// Chain identifier (e.g., "chain-3", "chain-reverse-3")
+    pub id: String,
+    /// Human-readable chain name (e.g., "POST /items")
+    pub name: String,
+    /// Direction of the data flow
+    pub direction: ChainDirection,
+    /// Route the chain starts or ends at (e.g., "POST /items")
+    pub route: Option<String>,
+    /// Request body schema of the route
+    pub request_schema: Option<String>,
+    /// Response schema of the route
+    pub response_schema: Option<String>,
+    /// Transformations applied along the chain, in link order
+    pub transformations: Vec<TransformationType>,
+}
+
+/// Builds the call graph of a FastAPI application and extracts its data chains
+///
+/// Mirrors what `dc-verifier check` does for a `fastapi` adapter: one forward and one
+/// reverse chain per route.
+pub fn extract_chain_summaries(
+    app_path: impl Into<PathBuf>,
+    max_depth: Option<usize>,
+    strict_imports: bool,
+) -> Result<Vec<ChainSummary>> {
+    let graph = FastApiCallGraphBuilder::new(app_path.into())
+        .with_strict_imports(strict_imports)
+        .with_max_depth(max_depth)
+        .build_graph()?;
+    let tracker = DataFlowTracker::new(&graph);
+    let chains = ChainBuilder::new(&graph, &tracker).find_all_chains()?;
+    Ok(chains
+        .iter()
+        .map(|chain| summarize_chain(&graph, chain))
+        .collect())
+}
+
+fn summarize_chain(graph: &CallGraph, chain: &DataChain) -> ChainSummary {
+    let route = chain
+        .links
+        .iter()
+        .find_map(|link| match graph.node_weight(*link.node_id) {
+            Some(CallNode::Route {
+                path,
+                method,
+                request_schema,
+                response_schema,
+                ..
+            }) => Some((path, method, request_schema, response_schema)),
+            _ => None,
+        });
+    let (route, request_schema, response_schema) = match route {
+        Some((path, method, request_schema, response_schema)) => (
+            Some(format!(
+                "{} {}",
+                format!("{:?}", method).to_uppercase(),
+                path
+            )),
+            request_schema.as_ref().map(|schema| schema.name.clone()),
+            response_schema.as_ref().map(|schema| schema.name.clone()),
+        ),
+        None => (None, None, None),
+    };
+
+    ChainSummary {
+        id: chain.id.clone(),
+        name: chain.name.clone(),
+        direction: chain.direction,
+        route,
+        request_schema,
+        response_schema,
+        transformations: chain
+            .links
+            .iter()
+            .filter_map(|link| link.transformation.clone())
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_extract_chain_summaries_reports_route_schemas() {
+        let temp_dir = TempDir::new().unwrap();
+        let main = temp_dir.path().join("main.py");
+        fs::write(
+            &main,
+            r#"from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class ItemIn(BaseModel):
+    name: str
+
+class ItemOut(BaseModel):
+    id: int
+    name: str
+
+@app.post("/items", response_model=ItemOut)
+def create_item(item: ItemIn) -> ItemOut:
+    return ItemOut(id=1, name=item.name)
+"#,
+        )
+        .unwrap();
+
+        let summaries = extract_chain_summaries(&main, None, false).unwrap();
+
+        let forward = summaries
+            .iter()
+            .find(|summary| summary.direction == ChainDirection::FrontendToBackend)
+            .expect("forward chain for the route");
+        assert_eq!(forward.route.as_deref(), Some("POST /items"));
+        assert_eq!(forward.request_schema.as_deref(), Some("ItemIn"));
+        assert_eq!(forward.response_schema.as_deref(), Some("ItemOut"));
+        assert!(summaries
+            .iter()
+            .any(|summary| summary.direction == ChainDirection::BackendToFrontend));
+    }
+}
@@ -741,6 +741,7 @@ impl DynamicRoutesAnalyzer {
                 location: location.clone(),
                 request_schema: None,
                 response_schema: None,
+                metadata: HashMap::new(),
             }));
 
             // Add edge from route to handler
@@ -1,13 +1,16 @@
-use dc_core::models::DataChain;
 use pyo3::prelude::*;
 
+mod apps;
 mod call_graph;
+mod chains;
 mod dynamic_routes;
 mod extractor;
 mod pydantic;
 mod utils;
 
+pub use apps::*;
 pub use call_graph::*;
+pub use chains::*;
 pub use dynamic_routes::*;
 pub use extractor::*;
 pub use pydantic::*;
@@ -21,7 +24,6 @@ fn dc_adapter_fastapi(_py: Python, m: Bound<'_, PyModule>) -> PyResult<()> {
 
 /// Adapter for FastAPI applications
 #[pyclass]
-#[allow(dead_code)]
 pub struct FastApiAdapter {
     app_path: String,
 }
@@ -34,10 +36,21 @@ impl FastApiAdapter {
     }
 
     /// Extracts data chains from FastAPI application
-    /// Returns JSON string with data chains
-    /// Note: Chain extraction is currently handled by the CLI, not through this Python interface
-    fn extract_chains(&self, py: Python) -> PyResult<Py<PyAny>> {
-        let chains: Vec<DataChain> = Vec::new();
+    /// Returns a list of dicts with the route, its request/response schemas and transformations
+    #[pyo3(signature = (max_depth=None, strict_imports=false))]
+    fn extract_chains(
+        &self,
+        py: Python,
+        max_depth: Option<usize>,
+        strict_imports: bool,
+    ) -> PyResult<Py<PyAny>> {
+        let chains =
+            extract_chain_summaries(&self.app_path, max_depth, strict_imports).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Failed to extract chains: {}",
+                    e
+                ))
+            })?;
 
         // Serialize to JSON and return as Python object
         let json_str = serde_json::to_string(&chains).map_err(|e| {
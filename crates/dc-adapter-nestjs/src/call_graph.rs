@@ -3,9 +3,11 @@ use crate::dto::DTOExtractor;
 use crate::extractor::ParameterExtractor;
 use anyhow::Result;
 use dc_core::call_graph::CallGraph;
+use dc_core::discovery::find_source_files;
 use dc_core::parsers::TypeScriptParser;
 use dc_typescript::TypeScriptCallGraphBuilder;
 use std::path::{Path, PathBuf};
+use std::time::Instant;
 use tracing::debug;
 
 /// Builder for NestJS call graph
@@ -13,6 +15,8 @@ pub struct NestJSCallGraphBuilder {
     typescript_builder: TypeScriptCallGraphBuilder,
     src_paths: Vec<PathBuf>,
     verbose: bool,
+    respect_gitignore: bool,
+    deadline: Option<Instant>,
 }
 
 impl NestJSCallGraphBuilder {
@@ -22,6 +26,8 @@ impl NestJSCallGraphBuilder {
             typescript_builder: TypeScriptCallGraphBuilder::new(src_paths.clone()),
             src_paths,
             verbose: false,
+            respect_gitignore: true,
+            deadline: None,
         }
     }
 
@@ -31,6 +37,16 @@ impl NestJSCallGraphBuilder {
         self
     }
 
+    /// Sets whether files ignored by `.gitignore` are skipped when walking `src_paths`
+    /// (default: `true`)
+    pub fn with_respect_gitignore(mut self, respect_gitignore: bool) -> Self {
+        self.typescript_builder = self
+            .typescript_builder
+            .with_respect_gitignore(respect_gitignore);
+        self.respect_gitignore = respect_gitignore;
+        self
+    }
+
     /// Sets max recursion depth
     pub fn with_max_depth(mut self, max_depth: Option<usize>) -> Self {
         if let Some(depth) = max_depth {
@@ -39,6 +55,13 @@ impl NestJSCallGraphBuilder {
         self
     }
 
+    /// Stops reading files once `deadline` has passed, leaving the graph partial
+    pub fn with_deadline(mut self, deadline: Option<Instant>) -> Self {
+        self.typescript_builder = self.typescript_builder.with_deadline(deadline);
+        self.deadline = deadline;
+        self
+    }
+
     /// Builds the call graph
     pub fn build_graph(self) -> Result<CallGraph> {
         // 1. Build base graph through TypeScriptCallGraphBuilder
@@ -51,7 +74,11 @@ impl NestJSCallGraphBuilder {
         // 2. Find all TypeScript files
         let mut files = Vec::new();
         for src_path in &self.src_paths {
-            Self::find_ts_files(src_path, &mut files)?;
+            files.extend(find_source_files(
+                src_path,
+                &["ts", "tsx"],
+                self.respect_gitignore,
+            ));
         }
 
         if self.verbose {
@@ -83,6 +110,12 @@ impl NestJSCallGraphBuilder {
             NestJSDecoratorProcessor::new(graph).with_parameter_extractor(parameter_extractor);
 
         for file in files {
+            if self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+            {
+                break;
+            }
             if let Err(err) =
                 Self::process_file_decorators(&parser, &mut decorator_processor, &file)
             {
@@ -119,25 +152,4 @@ impl NestJSCallGraphBuilder {
         processor.process_decorators(decorators)?;
         Ok(())
     }
-
-    /// Recursively finds all TypeScript files in a directory or adds a single file
-    fn find_ts_files(dir: &PathBuf, files: &mut Vec<PathBuf>) -> Result<()> {
-        if dir.is_file() {
-            if let Some(ext) = dir.extension() {
-                if ext == "ts" || ext == "tsx" {
-                    files.push(dir.clone());
-                }
-            }
-            return Ok(());
-        }
-
-        if dir.is_dir() {
-            for entry in std::fs::read_dir(dir)? {
-                let path = entry?.path();
-                Self::find_ts_files(&path, files)?;
-            }
-        }
-
-        Ok(())
-    }
 }
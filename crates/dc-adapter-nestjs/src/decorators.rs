@@ -2,7 +2,7 @@ use crate::extractor::ParameterExtractor;
 use anyhow::Result;
 use dc_core::call_graph::HttpMethod;
 use dc_core::call_graph::{CallEdge, CallGraph, CallNode};
-use dc_core::models::{Location, NodeId};
+use dc_core::models::{BaseType, Constraint, Location, NodeId, TypeInfo};
 use dc_core::parsers::{DecoratorTarget, TypeScriptDecorator};
 use std::collections::HashMap;
 use tracing::debug;
@@ -60,9 +60,16 @@ impl NestJSDecoratorProcessor {
                     let key = (class.clone(), method.clone(), parameter.clone());
                     parameter_decorators.insert(key, decorator);
                 }
+                // DTO field validators are read by the DTO extractor
+                DecoratorTarget::Property { .. } => {}
             }
         }
 
+        // Built-in pipes parse path and query parameters before they reach the handler
+        for ((class_name, method_name, parameter), decorator) in &parameter_decorators {
+            self.apply_parameter_pipes(class_name, method_name, parameter, decorator);
+        }
+
         // Process controller decorators
         for (class_name, decorators) in &class_decorators {
             for decorator in decorators {
@@ -119,14 +126,21 @@ impl NestJSDecoratorProcessor {
                         };
 
                         // Create Route node
-                        let route_node_id = NodeId::from(self.graph.add_node(CallNode::Route {
-                            path: route_info.path.clone(),
-                            method: route_info.method,
-                            handler: route_info.handler,
-                            location: route_info.location.clone(),
-                            request_schema: None,
-                            response_schema: None,
-                        }));
+                        let route_node_id = NodeId::from(
+                            self.graph.add_node(CallNode::Route {
+                                path: route_info.path.clone(),
+                                method: route_info.method,
+                                handler: route_info.handler,
+                                location: route_info.location.clone(),
+                                request_schema: request_type
+                                    .as_ref()
+                                    .and_then(|type_info| type_info.schema_ref.clone()),
+                                response_schema: response_type
+                                    .as_ref()
+                                    .and_then(|type_info| type_info.schema_ref.clone()),
+                                metadata: HashMap::new(),
+                            }),
+                        );
 
                         // Create edge from Route to handler
                         self.graph.add_edge(
@@ -265,6 +279,44 @@ impl NestJSDecoratorProcessor {
         }
     }
 
+    /// Refines the type of a `@Param`/`@Query` parameter parsed by a built-in pipe
+    ///
+    /// `@Param('id', ParseIntPipe) id: number` receives an integer (requests with `id=abc` are
+    /// rejected), so the parameter is typed `Integer` rather than the declared `number`.
+    fn apply_parameter_pipes(
+        &mut self,
+        class_name: &str,
+        method_name: &str,
+        parameter: &str,
+        decorator: &TypeScriptDecorator,
+    ) {
+        if !matches!(decorator.name.as_str(), "Param" | "Query") {
+            return;
+        }
+        // The first argument is the parameter name; pipes follow it
+        let pipes: Vec<&str> = decorator
+            .arguments
+            .iter()
+            .skip(1)
+            .map(String::as_str)
+            .filter(|argument| pipe_name(argument).is_some())
+            .collect();
+        if pipes.is_empty() {
+            return;
+        }
+        let Some(method_node) = self.find_method_node(class_name, method_name) else {
+            return;
+        };
+        if let Some(CallNode::Method { parameters, .. }) = self.graph.node_weight_mut(method_node.0)
+        {
+            if let Some(param) = parameters.iter_mut().find(|p| p.name == parameter) {
+                for pipe in pipes {
+                    apply_pipe(&mut param.type_info, pipe);
+                }
+            }
+        }
+    }
+
     /// Extracts HTTP method from decorator name
     fn extract_http_method(decorator_name: &str) -> Option<HttpMethod> {
         match decorator_name {
@@ -341,6 +393,44 @@ impl NestJSDecoratorProcessor {
     }
 }
 
+/// Pattern a value parsed by `ParseUUIDPipe` matches
+pub const UUID_PATTERN: &str =
+    "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$";
+
+/// Name of a built-in parsing pipe used as a decorator argument
+/// (`ParseIntPipe`, `new ParseUUIDPipe({ version: '4' })`)
+fn pipe_name(argument: &str) -> Option<&str> {
+    let argument = argument.trim();
+    let argument = argument
+        .strip_prefix("new ")
+        .unwrap_or(argument)
+        .trim_start();
+    let name = argument.split('(').next()?.trim();
+    let name = name.rsplit('.').next()?;
+    matches!(
+        name,
+        "ParseIntPipe" | "ParseFloatPipe" | "ParseBoolPipe" | "ParseUUIDPipe"
+    )
+    .then_some(name)
+}
+
+/// Narrows `type_info` to the value a built-in pipe produces
+pub fn apply_pipe(type_info: &mut TypeInfo, pipe: &str) {
+    match pipe_name(pipe) {
+        Some("ParseIntPipe") => type_info.base_type = BaseType::Integer,
+        Some("ParseFloatPipe") => type_info.base_type = BaseType::Number,
+        Some("ParseBoolPipe") => type_info.base_type = BaseType::Boolean,
+        Some("ParseUUIDPipe") => {
+            type_info.base_type = BaseType::String;
+            let pattern = Constraint::Pattern(UUID_PATTERN.to_string());
+            if !type_info.constraints.contains(&pattern) {
+                type_info.constraints.push(pattern);
+            }
+        }
+        _ => {}
+    }
+}
+
 /// Information about a route
 #[allow(dead_code)] // Will be used in implementation
 pub struct RouteInfo {
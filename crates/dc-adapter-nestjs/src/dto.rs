@@ -1,13 +1,18 @@
+use crate::decorators::UUID_PATTERN;
 use anyhow::Result;
-use dc_core::models::{Location, SchemaReference, SchemaType};
-use dc_core::parsers::{FunctionOrClass, TypeScriptDecorator, TypeScriptParser};
-use std::collections::HashMap;
+use dc_core::models::{Constraint, ConstraintValue, SchemaReference};
+use dc_core::parsers::{
+    DecoratorTarget, TypeScriptDecorator, TypeScriptParser, FIELD_CONSTRAINTS_METADATA_KEY,
+};
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 /// Extractor for DTO classes with class-validator decorators
 pub struct DTOExtractor {
     parser: TypeScriptParser,
     dto_classes: HashMap<String, SchemaReference>,
+    /// Interfaces, type aliases and Zod schemas by name (several when the name is reused)
+    declared_schemas: HashMap<String, Vec<SchemaReference>>,
 }
 
 impl Default for DTOExtractor {
@@ -22,6 +27,7 @@ impl DTOExtractor {
         Self {
             parser: TypeScriptParser::new(),
             dto_classes: HashMap::new(),
+            declared_schemas: HashMap::new(),
         }
     }
 
@@ -36,42 +42,61 @@ impl DTOExtractor {
             self.parser
                 .extract_decorators(&module, &file_path_str, &converter, &source);
 
-        // 3. Extract functions and classes
-        let functions_and_classes =
-            self.parser
-                .extract_functions_and_classes(&module, &file_path_str, &converter);
+        // 3. Extract classes with their typed properties
+        let class_schemas = self
+            .parser
+            .extract_class_schemas(&module, &file_path_str, &converter);
+
+        // Plain type declarations and classes may be used as request bodies too
+        // (`@Body() user: CreateUser`)
+        let declared = self
+            .parser
+            .extract_typescript_schemas(&module, &file_path_str, &converter)
+            .into_iter()
+            .chain(class_schemas.iter().cloned())
+            .chain(
+                self.parser
+                    .extract_zod_schemas(&module, &file_path_str, &converter),
+            );
+        for schema in declared {
+            self.declared_schemas
+                .entry(schema.name.clone())
+                .or_default()
+                .push(schema);
+        }
 
         let mut dto_schemas = Vec::new();
 
-        // 4. For each class, check if it has class-validator decorators
-        for item in functions_and_classes {
-            if let FunctionOrClass::Class { name, line, .. } = item {
-                // Check if class has class-validator decorators on properties
-                // This is a simplified check - in full implementation we'd parse class properties
-                if self.has_class_validator_decorators(&decorators, &name) {
-                    // Extract DTO fields (simplified - would need to parse class properties from AST)
-                    let fields = self.extract_dto_fields_simple(&name, &decorators);
-
-                    let schema = SchemaReference {
-                        name: name.clone(),
-                        schema_type: SchemaType::TypeScript,
-                        location: Location {
-                            file: file_path_str.clone(),
-                            line,
-                            column: None,
-                        },
-                        metadata: {
-                            let mut meta = HashMap::new();
-                            meta.insert("fields".to_string(), serde_json::to_string(&fields)?);
-                            meta.insert("dto_type".to_string(), "class-validator".to_string());
-                            meta
-                        },
-                    };
-
-                    self.dto_classes.insert(name, schema.clone());
-                    dto_schemas.push(schema);
+        // 4. Classes with class-validator decorators on their properties are DTOs
+        for mut schema in class_schemas {
+            if !self.has_class_validator_decorators(&decorators, &schema.name) {
+                continue;
+            }
+
+            let rules = self.extract_validation_rules(&decorators, &schema.name);
+            if let Some(fields) = schema.metadata.get_mut("fields") {
+                *fields = Self::apply_optional_validators(fields, &rules);
+            }
+            let mut constraints: BTreeMap<&String, Vec<Constraint>> = BTreeMap::new();
+            for (property, rules) in &rules {
+                let property_constraints: Vec<Constraint> =
+                    rules.iter().flat_map(ValidationRule::constraints).collect();
+                if !property_constraints.is_empty() {
+                    constraints.insert(property, property_constraints);
                 }
             }
+            if !constraints.is_empty() {
+                schema.metadata.insert(
+                    FIELD_CONSTRAINTS_METADATA_KEY.to_string(),
+                    serde_json::to_string(&constraints)?,
+                );
+            }
+            schema
+                .metadata
+                .insert("dto_type".to_string(), "class-validator".to_string());
+
+            self.dto_classes.insert(schema.name.clone(), schema.clone());
+            dto_schemas.push(schema);
         }
 
         Ok(dto_schemas)
@@ -90,10 +115,18 @@ impl DTOExtractor {
             "IsEmail",
             "IsOptional",
             "IsNumber",
+            "IsInt",
             "Min",
             "Max",
             "Length",
+            "MinLength",
+            "MaxLength",
+            "Matches",
+            "IsUUID",
+            "IsUrl",
             "IsArray",
+            "ArrayMinSize",
+            "ArrayMaxSize",
             "IsObject",
             "IsBoolean",
             "IsDate",
@@ -103,51 +136,35 @@ impl DTOExtractor {
         ];
 
         decorators.iter().any(|d| {
-            matches!(&d.target, dc_core::parsers::DecoratorTarget::Parameter { class, .. } if class == class_name)
+            matches!(&d.target, DecoratorTarget::Property { class, .. } if class == class_name)
                 && validator_names.iter().any(|&name| d.name == name)
         })
     }
 
-    /// Extracts DTO fields (simplified version)
-    /// Full implementation would parse class properties from SWC AST
-    fn extract_dto_fields_simple(
-        &self,
-        _class_name: &str,
-        decorators: &[TypeScriptDecorator],
-    ) -> Vec<serde_json::Value> {
-        // Simplified extraction - in full implementation would parse class properties
-        // For now, return empty vector - will be enhanced when parsing class properties
-        let mut fields = Vec::new();
-
-        // Group decorators by parameter (property)
-        let mut param_decorators: HashMap<String, Vec<&TypeScriptDecorator>> = HashMap::new();
-        for decorator in decorators {
-            if let dc_core::parsers::DecoratorTarget::Parameter {
-                class, parameter, ..
-            } = &decorator.target
-            {
-                if class == _class_name {
-                    param_decorators
-                        .entry(parameter.clone())
-                        .or_default()
-                        .push(decorator);
-                }
-            }
-        }
-
-        // Create field info for each parameter with decorators
-        for (param_name, decorators) in param_decorators {
-            let validation: Vec<String> = decorators.iter().map(|d| d.name.clone()).collect();
-            let optional = decorators.iter().any(|d| d.name == "IsOptional");
-
-            fields.push(serde_json::json!({
-                "name": param_name,
-                "validation": validation,
-                "optional": optional,
-            }));
-        }
-
+    /// Marks properties as optional or required by their validators: a property validated
+    /// without `@IsOptional()` is required even when declared `name?: T`
+    fn apply_optional_validators(
+        fields: &str,
+        rules: &BTreeMap<String, Vec<ValidationRule>>,
+    ) -> String {
         fields
+            .split(',')
+            .map(|field| {
+                let name = field.split(':').next().unwrap_or_default();
+                match (rules.get(name), field.rsplit_once(':')) {
+                    (Some(rules), Some((declaration, "optional" | "required"))) => {
+                        let optional = rules.iter().any(|rule| rule.decorator == "IsOptional");
+                        format!(
+                            "{}:{}",
+                            declaration,
+                            if optional { "optional" } else { "required" }
+                        )
+                    }
+                    _ => field.to_string(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",")
     }
 
     /// Gets a DTO schema by class name
@@ -155,12 +172,38 @@ impl DTOExtractor {
         self.dto_classes.get(class_name)
     }
 
-    /// Extracts validation rules from decorators
-    #[allow(dead_code)] // Will be used in implementation
-    fn extract_validation_rules(&self, _decorators: &[TypeScriptDecorator]) -> Vec<ValidationRule> {
-        // Convert decorators to validation rules
-        // TODO: Implement
-        Vec::new()
+    /// Schema of a type name: its DTO class, or else the only class, interface, type alias
+    /// or Zod schema declared with that name
+    pub fn get_schema(&self, type_name: &str) -> Option<&SchemaReference> {
+        self.get_dto_schema(type_name).or_else(|| {
+            match self.declared_schemas.get(type_name).map(Vec::as_slice) {
+                Some([schema]) => Some(schema),
+                _ => None,
+            }
+        })
+    }
+
+    /// Extracts the validation rules of a class from its property decorators, by property
+    fn extract_validation_rules(
+        &self,
+        decorators: &[TypeScriptDecorator],
+        class_name: &str,
+    ) -> BTreeMap<String, Vec<ValidationRule>> {
+        let mut rules: BTreeMap<String, Vec<ValidationRule>> = BTreeMap::new();
+        for decorator in decorators {
+            if let DecoratorTarget::Property { class, property } = &decorator.target {
+                if class == class_name {
+                    rules
+                        .entry(property.clone())
+                        .or_default()
+                        .push(ValidationRule {
+                            decorator: decorator.name.clone(),
+                            arguments: decorator.arguments.clone(),
+                        });
+                }
+            }
+        }
+        rules
     }
 }
 
@@ -174,8 +217,46 @@ pub struct DTOField {
 }
 
 /// Validation rule from class-validator decorator
-#[allow(dead_code)] // Will be used in implementation
 pub struct ValidationRule {
     pub decorator: String, // "IsString", "IsEmail", "Min", "Max", etc.
     pub arguments: Vec<String>,
 }
+
+impl ValidationRule {
+    /// Constraints the rule puts on the value: lengths and array sizes as integer bounds,
+    /// numeric bounds as float bounds (like Pydantic `Field` constraints)
+    pub fn constraints(&self) -> Vec<Constraint> {
+        let integer = |index: usize| -> Option<ConstraintValue> {
+            let value = self.arguments.get(index)?.parse::<f64>().ok()?;
+            Some(ConstraintValue::Integer(value as i64))
+        };
+        let float = |index: usize| -> Option<ConstraintValue> {
+            Some(ConstraintValue::Float(
+                self.arguments.get(index)?.parse().ok()?,
+            ))
+        };
+        match self.decorator.as_str() {
+            "IsEmail" => vec![Constraint::Email],
+            "IsUrl" => vec![Constraint::Url],
+            "IsUUID" => vec![Constraint::Pattern(UUID_PATTERN.to_string())],
+            "Matches" => self
+                .arguments
+                .first()
+                .map(|pattern| Constraint::Pattern(pattern.clone()))
+                .into_iter()
+                .collect(),
+            "MinLength" | "ArrayMinSize" => integer(0).map(Constraint::Min).into_iter().collect(),
+            "MaxLength" | "ArrayMaxSize" => integer(0).map(Constraint::Max).into_iter().collect(),
+            "Length" => integer(0)
+                .map(Constraint::Min)
+                .into_iter()
+                .chain(integer(1).map(Constraint::Max))
+                .collect(),
+            "Min" => float(0).map(Constraint::Min).into_iter().collect(),
+            "Max" => float(0).map(Constraint::Max).into_iter().collect(),
+            "IsPositive" => vec![Constraint::ExclusiveMin(ConstraintValue::Float(0.0))],
+            "IsNegative" => vec![Constraint::ExclusiveMax(ConstraintValue::Float(0.0))],
+            _ => Vec::new(),
+        }
+    }
+}
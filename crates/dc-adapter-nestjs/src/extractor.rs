@@ -1,7 +1,8 @@
 use anyhow::Result;
 use dc_core::call_graph::{CallGraph, CallNode, Parameter};
-use dc_core::models::{NodeId, TypeInfo};
-use dc_core::parsers::TypeScriptDecorator;
+use dc_core::models::{BaseType, NodeId, SchemaReference, SchemaType, TypeInfo};
+use dc_core::parsers::{DecoratorTarget, TypeScriptDecorator};
+use std::collections::HashMap;
 use std::path::Path;
 
 /// Extractor for route parameters
@@ -34,42 +35,40 @@ impl ParameterExtractor {
         &mut self,
         graph: &CallGraph,
         method_node: NodeId,
-        _method_decorators: &[&TypeScriptDecorator],
+        method_decorators: &[&TypeScriptDecorator],
         parameter_decorators: &[&TypeScriptDecorator],
         parameters: &[Parameter],
     ) -> Result<(Option<TypeInfo>, Option<TypeInfo>)> {
-        // 1. Find @Body() parameter → request type
-        let request_type = if let Some(body_param_idx) =
-            self.find_body_parameter(parameters, parameter_decorators)
-        {
-            if let Some(body_param) = parameters.get(body_param_idx) {
-                let mut type_info = body_param.type_info.clone();
-
-                // If type is a DTO class, try to resolve it
-                if let Some(schema_ref) = &type_info.schema_ref {
-                    if let Some(dto_extractor) = &self.dto_extractor {
-                        if let Some(dto_schema) = dto_extractor.get_dto_schema(&schema_ref.name) {
-                            // Update type_info with DTO schema reference
-                            type_info.schema_ref = Some(dto_schema.clone());
-                        }
-                    }
-                }
-
-                Some(type_info)
-            } else {
-                None
-            }
-        } else {
-            None
-        };
+        // 1. Find @Body() parameter → request type, or the @Body('field') properties
+        let request_type = self
+            .find_body_parameter(parameters, parameter_decorators)
+            .and_then(|body_param_idx| parameters.get(body_param_idx))
+            .map(|body_param| self.resolve_schema(body_param.type_info.clone()))
+            .or_else(|| {
+                Self::body_properties_type(method_decorators, parameter_decorators, parameters)
+            });
 
         // 2. Extract return type from method node → response type
-        let response_type = self.extract_method_return_type(graph, method_node)?;
+        let response_type = self
+            .extract_method_return_type(graph, method_node)?
+            .map(|type_info| self.resolve_schema(type_info));
 
         Ok((request_type, response_type))
     }
 
-    /// Finds @Body() parameter
+    /// Points a type referencing a DTO class or a declared type at its declaration
+    fn resolve_schema(&self, mut type_info: TypeInfo) -> TypeInfo {
+        if let (Some(schema_ref), Some(dto_extractor)) =
+            (&type_info.schema_ref, &self.dto_extractor)
+        {
+            if let Some(schema) = dto_extractor.get_schema(&schema_ref.name) {
+                type_info.schema_ref = Some(schema.clone());
+            }
+        }
+        type_info
+    }
+
+    /// Finds @Body() parameter (the whole body, not a `@Body('field')` property)
     fn find_body_parameter(
         &self,
         parameters: &[Parameter],
@@ -77,7 +76,7 @@ impl ParameterExtractor {
     ) -> Option<usize> {
         // Find parameter with @Body() decorator
         for decorator in parameter_decorators {
-            if decorator.name == "Body" {
+            if decorator.name == "Body" && body_property(decorator).is_none() {
                 if let dc_core::parsers::DecoratorTarget::Parameter { parameter, .. } =
                     &decorator.target
                 {
@@ -89,6 +88,52 @@ impl ParameterExtractor {
         None
     }
 
+    /// Request body of a handler reading single properties (`@Body('email') email: string`):
+    /// an object with one field per property, in parameter order
+    fn body_properties_type(
+        method_decorators: &[&TypeScriptDecorator],
+        parameter_decorators: &[&TypeScriptDecorator],
+        parameters: &[Parameter],
+    ) -> Option<TypeInfo> {
+        let mut target = None;
+        let fields: Vec<String> = parameters
+            .iter()
+            .filter_map(|parameter| {
+                let decorator = parameter_decorators.iter().find(|decorator| {
+                    decorator.name == "Body"
+                        && matches!(&decorator.target, DecoratorTarget::Parameter { parameter: name, .. } if *name == parameter.name)
+                })?;
+                let property = body_property(decorator)?;
+                target = Some(&decorator.target);
+                Some(format!(
+                    "{}:{}:{}",
+                    property,
+                    ts_type_name(&parameter.type_info),
+                    if parameter.optional || parameter.type_info.optional {
+                        "optional"
+                    } else {
+                        "required"
+                    }
+                ))
+            })
+            .collect();
+        let Some(DecoratorTarget::Parameter { class, method, .. }) = target else {
+            return None;
+        };
+
+        Some(TypeInfo {
+            base_type: BaseType::Object,
+            schema_ref: Some(SchemaReference {
+                name: format!("{}.{}.body", class, method),
+                schema_type: SchemaType::TypeScript,
+                location: method_decorators.first()?.location.clone(),
+                metadata: HashMap::from([("fields".to_string(), fields.join(","))]),
+            }),
+            constraints: Vec::new(),
+            optional: false,
+        })
+    }
+
     /// Extracts return type from method node
     fn extract_method_return_type(
         &self,
@@ -120,3 +165,31 @@ impl ParameterExtractor {
         Ok(None)
     }
 }
+
+/// Property a `@Body('email')` decorator reads; None for `@Body()` and `@Body(ValidationPipe)`
+fn body_property(decorator: &TypeScriptDecorator) -> Option<&str> {
+    let argument = decorator.arguments.first()?;
+    (!argument.ends_with("Pipe")
+        && argument
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$'))
+    .then_some(argument.as_str())
+}
+
+/// TypeScript spelling of a parameter type, for the fields of a synthesized body schema
+fn ts_type_name(type_info: &TypeInfo) -> String {
+    if let Some(schema_ref) = &type_info.schema_ref {
+        return schema_ref.name.clone();
+    }
+    match type_info.base_type {
+        BaseType::String => "string",
+        BaseType::Number | BaseType::Integer => "number",
+        BaseType::Boolean => "boolean",
+        BaseType::Object => "object",
+        BaseType::Array => "unknown[]",
+        BaseType::Null => "null",
+        BaseType::Any => "any",
+        BaseType::Unknown => "unknown",
+    }
+    .to_string()
+}
@@ -0,0 +1,76 @@
+use std::fs;
+
+use dc_adapter_nestjs::NestJSCallGraphBuilder;
+use dc_core::call_graph::CallNode;
+
+#[test]
+fn body_parameters_set_the_route_request_schema() {
+    let source = r#"
+import { Body, Controller, Post, ValidationPipe } from "@nestjs/common";
+import { IsEmail, IsString } from "class-validator";
+
+export class CreateUserDto {
+  @IsString()
+  name: string;
+
+  @IsEmail()
+  email: string;
+}
+
+export interface RenameUser {
+  name: string;
+}
+
+@Controller("users")
+export class UsersController {
+  @Post()
+  create(@Body() dto: CreateUserDto) {
+    return dto;
+  }
+
+  @Post(":id/rename")
+  rename(@Body(new ValidationPipe()) body: RenameUser) {
+    return body;
+  }
+
+  @Post(":id/email")
+  email(@Body("email") email: string) {
+    return email;
+  }
+}
+"#;
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    fs::write(tmp_dir.path().join("users.controller.ts"), source).expect("failed to write file");
+    let graph = NestJSCallGraphBuilder::new(vec![tmp_dir.path().to_path_buf()])
+        .build_graph()
+        .expect("failed to build call graph");
+    let request_schema = |route_path: &str| {
+        graph
+            .node_weights()
+            .find_map(|node| match node {
+                CallNode::Route {
+                    path,
+                    request_schema,
+                    ..
+                } if path == route_path => Some(request_schema.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("route {} not found", route_path))
+            .unwrap_or_else(|| panic!("route {} has no request schema", route_path))
+    };
+
+    let create = request_schema("/users");
+    assert_eq!(create.name, "CreateUserDto");
+    assert_eq!(create.location.line, 5);
+    assert_eq!(
+        create.metadata["fields"],
+        "name:string:required,email:string:required"
+    );
+
+    assert_eq!(request_schema("/users/:id/rename").name, "RenameUser");
+
+    // `@Body('email')` reads one property of the body
+    let email = request_schema("/users/:id/email");
+    assert_eq!(email.name, "UsersController.email.body");
+    assert_eq!(email.metadata["fields"], "email:string:required");
+}
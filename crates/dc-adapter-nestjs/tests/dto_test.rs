@@ -0,0 +1,91 @@
+use std::fs;
+
+use dc_adapter_nestjs::{NestJSCallGraphBuilder, UUID_PATTERN};
+use dc_core::analyzers::SchemaParser;
+use dc_core::call_graph::CallNode;
+use dc_core::models::{Constraint, ConstraintValue};
+
+#[test]
+fn class_validator_decorators_become_field_constraints() {
+    let source = r#"
+import { Body, Controller, Post } from "@nestjs/common";
+import { IsEmail, IsInt, IsOptional, IsString, IsUUID, Matches, Max, MaxLength, Min, MinLength } from "class-validator";
+
+export class CreateUserDto {
+  @IsString()
+  @MinLength(3)
+  @MaxLength(20)
+  @Matches(/^[a-z]+$/)
+  name: string;
+
+  @IsEmail()
+  email: string;
+
+  @IsOptional()
+  @IsInt()
+  @Min(0)
+  @Max(120)
+  age: number;
+
+  @IsUUID()
+  teamId?: string;
+
+  nickname?: string;
+}
+
+@Controller("users")
+export class UsersController {
+  @Post()
+  create(@Body() dto: CreateUserDto) {
+    return dto;
+  }
+}
+"#;
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    fs::write(tmp_dir.path().join("users.controller.ts"), source).expect("failed to write file");
+    let graph = NestJSCallGraphBuilder::new(vec![tmp_dir.path().to_path_buf()])
+        .build_graph()
+        .expect("failed to build call graph");
+    let schema = graph
+        .node_weights()
+        .find_map(|node| match node {
+            CallNode::Route { request_schema, .. } => request_schema.clone(),
+            _ => None,
+        })
+        .expect("route with a request schema");
+
+    assert_eq!(schema.name, "CreateUserDto");
+    assert_eq!(schema.metadata["dto_type"], "class-validator");
+    // `@IsOptional()` decides optionality of validated properties
+    assert_eq!(
+        schema.metadata["fields"],
+        "name:string:required,email:string:required,age:number:optional,\
+         teamId:string:required,nickname:string:optional"
+    );
+
+    let parsed = SchemaParser::parse(&schema).expect("DTO schema parses");
+    let constraints = |field: &str| parsed.properties[field].constraints.clone();
+    assert_eq!(
+        constraints("name"),
+        [
+            Constraint::Min(ConstraintValue::Integer(3)),
+            Constraint::Max(ConstraintValue::Integer(20)),
+            Constraint::Pattern("^[a-z]+$".to_string()),
+        ]
+    );
+    assert_eq!(constraints("email"), [Constraint::Email]);
+    assert_eq!(
+        constraints("age"),
+        [
+            Constraint::Min(ConstraintValue::Float(0.0)),
+            Constraint::Max(ConstraintValue::Float(120.0)),
+        ]
+    );
+    assert_eq!(
+        constraints("teamId"),
+        [Constraint::Pattern(UUID_PATTERN.to_string())]
+    );
+    assert!(constraints("nickname").is_empty());
+    assert!(!parsed.required.contains(&"age".to_string()));
+    assert!(parsed.required.contains(&"teamId".to_string()));
+}
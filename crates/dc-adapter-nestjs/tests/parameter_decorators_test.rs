@@ -0,0 +1,112 @@
+use std::fs;
+
+use dc_adapter_nestjs::NestJSCallGraphBuilder;
+use dc_core::call_graph::{CallGraph, CallNode, HttpMethod};
+use dc_core::models::BaseType;
+
+fn build_graph(source: &str) -> CallGraph {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    fs::write(tmp_dir.path().join("orders.controller.ts"), source).expect("failed to write file");
+    NestJSCallGraphBuilder::new(vec![tmp_dir.path().to_path_buf()])
+        .build_graph()
+        .expect("failed to build call graph")
+}
+
+#[test]
+fn multi_line_handler_signatures_read_decorators_from_the_ast() {
+    let source = r#"
+import { Body, Controller, Get, HttpStatus, Param, ParseIntPipe, Post, Put, Query, Req } from "@nestjs/common";
+import { IsInt, IsString } from "class-validator";
+
+export class CreateOrderDto {
+  @IsString()
+  title: string;
+}
+
+export class UpdateOrderDto {
+  @IsInt()
+  quantity: number;
+}
+
+@Controller("orders")
+export class OrdersController {
+  constructor(
+    private readonly ordersService: OrdersService,
+    private readonly auditService: AuditService,
+  ) {}
+
+  @Post()
+  create(
+    // Was @Body(new ValidationPipe({ whitelist: true })) before
+    @Body()
+    dto: CreateOrderDto,
+    @Req() request: Request,
+  ) {
+    return this.ordersService.create(dto);
+  }
+
+  @Get()
+  list(
+    @Query("status") // a filter, not a @Body("status") field
+    status: string,
+  ) {
+    return this.ordersService.list(status);
+  }
+
+  @Put(":id")
+  update(
+    @Param(
+      "id",
+      new ParseIntPipe({ errorHttpStatusCode: HttpStatus.NOT_ACCEPTABLE }),
+    )
+    id: number,
+    @Body() dto: UpdateOrderDto,
+    audit: boolean,
+  ) {
+    return this.ordersService.update(id, dto);
+  }
+}
+"#;
+    let graph = build_graph(source);
+    let request_schema = |route_method: HttpMethod, route_path: &str| {
+        graph
+            .node_weights()
+            .find_map(|node| match node {
+                CallNode::Route {
+                    path,
+                    method,
+                    request_schema,
+                    ..
+                } if path == route_path && *method == route_method => Some(request_schema.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("route {} not found", route_path))
+    };
+
+    assert_eq!(
+        request_schema(HttpMethod::Post, "/orders").map(|schema| schema.name),
+        Some("CreateOrderDto".to_string())
+    );
+    assert_eq!(
+        request_schema(HttpMethod::Put, "/orders/:id").map(|schema| schema.name),
+        Some("UpdateOrderDto".to_string())
+    );
+    // Decorator-like text in comments doesn't decorate the parameter
+    assert_eq!(request_schema(HttpMethod::Get, "/orders"), None);
+
+    // The pipe in the multi-line `@Param(...)` call parses the path parameter
+    let update_parameters = graph
+        .node_weights()
+        .find_map(|node| match node {
+            CallNode::Method {
+                name, parameters, ..
+            } if name == "update" => Some(parameters.clone()),
+            _ => None,
+        })
+        .expect("update method");
+    let id = update_parameters
+        .iter()
+        .find(|parameter| parameter.name == "id")
+        .expect("id parameter");
+    assert_eq!(id.type_info.base_type, BaseType::Integer);
+}
@@ -0,0 +1,78 @@
+use std::fs;
+
+use dc_adapter_nestjs::{NestJSCallGraphBuilder, UUID_PATTERN};
+use dc_core::call_graph::{CallNode, Parameter};
+use dc_core::models::{BaseType, Constraint};
+
+fn method_parameters(source: &str, method_name: &str) -> Vec<Parameter> {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    fs::write(tmp_dir.path().join("users.controller.ts"), source).expect("failed to write file");
+
+    let graph = NestJSCallGraphBuilder::new(vec![tmp_dir.path().to_path_buf()])
+        .build_graph()
+        .expect("failed to build call graph");
+    graph
+        .node_weights()
+        .find_map(|node| match node {
+            CallNode::Method {
+                name, parameters, ..
+            } if name == method_name => Some(parameters.clone()),
+            _ => None,
+        })
+        .expect("method node")
+}
+
+#[test]
+fn parsing_pipes_refine_parameter_types() {
+    let source = r#"
+import { Body, Controller, Get, Param, ParseBoolPipe, ParseIntPipe, ParseUUIDPipe, Put, Query } from "@nestjs/common";
+
+@Controller("users")
+export class UsersController {
+  @Put(":id")
+  update(@Body() dto: UpdateUserDto, @Param("id", ParseIntPipe) id: number) {
+    return dto;
+  }
+
+  @Get(":uuid")
+  find(
+    @Param("uuid", new ParseUUIDPipe({ version: "4" })) uuid: string,
+    @Query("active", ParseBoolPipe) active: string,
+    @Query("page") page: string,
+  ) {
+    return uuid;
+  }
+}
+"#;
+
+    let update = method_parameters(source, "update");
+    let id = update
+        .iter()
+        .find(|p| p.name == "id")
+        .expect("id parameter");
+    assert_eq!(id.type_info.base_type, BaseType::Integer);
+
+    let find = method_parameters(source, "find");
+    let types: Vec<(&str, BaseType, &[Constraint])> = find
+        .iter()
+        .map(|p| {
+            (
+                p.name.as_str(),
+                p.type_info.base_type,
+                p.type_info.constraints.as_slice(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        types,
+        vec![
+            (
+                "uuid",
+                BaseType::String,
+                &[Constraint::Pattern(UUID_PATTERN.to_string())][..]
+            ),
+            ("active", BaseType::Boolean, &[][..]),
+            ("page", BaseType::String, &[][..]),
+        ]
+    );
+}
@@ -1,23 +1,149 @@
 use crate::config::{Config, DynamicRoutesConfig, EndpointConfig, RouterGeneratorConfig};
-use crate::reporters::{JsonReporter, MarkdownReporter};
-use crate::ReportFormat;
-use anyhow::Result;
+use crate::git::changed_files_since;
+use crate::reporters::{
+    deduplicate_diagnostics, rewrite_report_paths, FindingCounts, JsonReporter, JunitReporter,
+    MarkdownReporter, SarifReporter, SummaryReporter,
+};
+use crate::{FailOn, PathStyle, ReportFormat};
+use anyhow::{Context, Result};
 use dc_adapter_fastapi::{
     DynamicRoutesConfig as AdapterDynamicRoutesConfig, EndpointConfig as AdapterEndpointConfig,
     FastApiCallGraphBuilder, RouterGeneratorConfig as AdapterRouterGeneratorConfig,
 };
 use dc_adapter_nestjs::NestJSCallGraphBuilder;
-use dc_core::analyzers::{ChainBuilder, ContractChecker};
+use dc_core::analyzers::{
+    deprecated_field_usages, inconsistent_pagination, is_shared_contract, operation_id_issues,
+    pagination_call_mismatches, query_coercion_issues, shared_schema_issues, stale_examples,
+    unresolved_frontend_types, ChainBuilder, ContractChecker, DocumentedRoute, EndpointAnalyzer,
+    EndpointIssue,
+};
+use dc_core::call_graph::{from_json, to_json, CallGraph};
 use dc_core::data_flow::DataFlowTracker;
-use dc_core::models::Severity;
-use dc_core::openapi::{OpenAPILinker, OpenAPIParser};
+use dc_core::fixes::{fix_missing_response_models, SourceEdit};
+use dc_core::models::{
+    AnalysisWarning, AnalysisWarningKind, Contract, DataChain, Diagnostic, Location,
+};
+use dc_core::openapi::{FetchOptions, OpenAPILinker, OpenAPIParser, OpenAPISchema};
+use dc_core::parsers::PythonParser;
 use dc_typescript::TypeScriptCallGraphBuilder;
-use indicatif::{ProgressBar, ProgressStyle};
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 use tracing::{error, warn};
 
+/// Exit code of a check that ran out of time, distinct from the exit code 1 of a check
+/// that failed
+pub const TIMED_OUT_EXIT_CODE: i32 = 124;
+
+/// A check reached its `--timeout`
+///
+/// Returned once the report is written: it holds what was analyzed before the deadline,
+/// and every step cut short is listed as a `timed-out` analysis warning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnalysisTimedOut {
+    /// The timeout that was reached
+    pub timeout: Duration,
+}
+
+impl fmt::Display for AnalysisTimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Analysis timed out after {}s; the report holds partial results",
+            self.timeout.as_secs_f64()
+        )
+    }
+}
+
+impl std::error::Error for AnalysisTimedOut {}
+
+/// Exit code of a check with findings at its `--fail-on` level
+pub const VERIFICATION_FAILED_EXIT_CODE: i32 = 1;
+
+/// A check found errors (or warnings, with `--fail-on warning`)
+///
+/// Returned once the report is written and the summary printed, so CI jobs fail with
+/// the verdict whatever the output mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerificationFailed {
+    /// Findings of the check
+    pub counts: FindingCounts,
+}
+
+impl fmt::Display for VerificationFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Verification failed: {} error(s), {} warning(s)",
+            self.counts.errors, self.counts.warnings
+        )
+    }
+}
+
+impl std::error::Error for VerificationFailed {}
+
+/// Options for the `check` command
+#[derive(Debug, Clone, Default)]
+pub struct CheckOptions {
+    /// Enable verbose debug output
+    pub verbose: bool,
+    /// Print only aggregate counts and the verdict to the console
+    pub summary_only: bool,
+    /// Lowest level of findings that fails the check (see [`VerificationFailed`])
+    pub fail_on: FailOn,
+    /// Fail on the first file with a syntax error instead of skipping it
+    pub strict_parse: bool,
+    /// Report frontend types that can't be resolved to a schema instead of skipping them
+    pub strict_types: bool,
+    /// FastAPI app/router variables declaring routes (overrides the adapters' `app_objects`)
+    pub app_objects: Vec<String>,
+    /// Apply safe source fixes (missing `response_model=`) before checking
+    pub fix: bool,
+    /// Accept invalid TLS certificates when fetching the OpenAPI spec from a URL
+    pub insecure: bool,
+    /// Report only issues in files changed since this git ref (the whole project is still
+    /// analyzed)
+    pub since: Option<String>,
+    /// How file paths are rendered in reports (overrides `[output].paths`)
+    pub paths: Option<PathStyle>,
+    /// Root relative report paths are rendered against (overrides `[output].paths_root`)
+    pub paths_root: Option<String>,
+    /// Analyze files ignored by `.gitignore` too
+    pub no_gitignore: bool,
+    /// Print what would be analyzed instead of analyzing it
+    pub dry_run: bool,
+    /// Stop analyzing after this long and report partial results (see [`AnalysisTimedOut`])
+    pub timeout: Option<Duration>,
+    /// Directory to write each adapter's call graph to, as JSON
+    pub emit_graph: Option<String>,
+    /// Directory to read each adapter's call graph from instead of parsing the sources
+    pub graph: Option<String>,
+}
+
 /// Executes data chain verification
 pub fn execute_check(config_path: &str, format: ReportFormat, verbose: bool) -> Result<()> {
+    execute_check_with_options(
+        config_path,
+        Some(format),
+        &CheckOptions {
+            verbose,
+            ..Default::default()
+        },
+    )
+}
+
+/// Executes data chain verification with the full set of check options
+///
+/// `format` comes from the command line; when `None`, `[output].format` from the config is used.
+pub fn execute_check_with_options(
+    config_path: &str,
+    format: Option<ReportFormat>,
+    options: &CheckOptions,
+) -> Result<()> {
+    let verbose = options.verbose;
     // 1. Load configuration
     // Determine base path from config file location
     let config_file_path = Path::new(config_path);
@@ -27,18 +153,57 @@ pub fn execute_check(config_path: &str, format: ReportFormat, verbose: bool) ->
     // 2. Auto-fill missing OpenAPI paths
     config.auto_fill_openapi(config_path);
 
-    // 2. Parse global OpenAPI schema if specified
-    let _global_openapi = config.openapi_path.as_ref().and_then(|path| {
-        OpenAPIParser::parse_file(std::path::Path::new(path))
-            .map_err(|e| {
-                warn!(
-                    path = %path,
-                    error = %e,
-                    "Failed to parse global OpenAPI schema"
-                );
-            })
-            .ok()
-    });
+    // CLI flags take precedence over the config file
+    let format = format.unwrap_or_else(|| config.report_format());
+
+    if options.dry_run {
+        print!("{}", render_dry_run(config_path, &config, format, options)?);
+        return Ok(());
+    }
+
+    // The deadline is checked between files and analysis steps: what isn't reached by then
+    // is left out of the report, and each step cut short is recorded as an analysis warning
+    let deadline = options.timeout.map(|timeout| Instant::now() + timeout);
+    let expired = || deadline.is_some_and(|deadline| Instant::now() >= deadline);
+    let timed_out = |message: String| {
+        AnalysisWarning::new(
+            AnalysisWarningKind::TimedOut,
+            Location {
+                file: config_path.to_string(),
+                line: 1,
+                column: None,
+            },
+            message,
+        )
+    };
+
+    // Everything is still analyzed, so schemas the changed files depend on are resolved;
+    // only the report is scoped to the changed files
+    let changed_files = match &options.since {
+        Some(git_ref) => {
+            let repo_dir = base_path
+                .filter(|path| !path.as_os_str().is_empty())
+                .unwrap_or_else(|| Path::new("."));
+            let files = changed_files_since(repo_dir, git_ref)?;
+            if !options.summary_only {
+                print!("{}", render_changed_files(git_ref, &files));
+            }
+            Some(files.iter().map(canonical_file).collect())
+        }
+        None => None,
+    };
+
+    // Fixes rewrite the sources, so they run before the graphs used for the report are built
+    if options.fix {
+        let edits = apply_fixes(&config, options)?;
+        if !options.summary_only {
+            print!("{}", render_fix_summary(&edits));
+        }
+    }
+
+    // 2. Load OpenAPI specs once per source (a remote spec is fetched only once)
+    let fetch_options = config.fetch_options(options.insecure);
+    let mut openapi_specs: HashMap<String, Option<OpenAPISchema>> = HashMap::new();
 
     // 3. Initialize adapters and build graphs
     let mut all_chains = Vec::new();
@@ -51,22 +216,44 @@ pub fn execute_check(config_path: &str, format: ReportFormat, verbose: bool) ->
             .expect("Failed to create progress bar template")
             .progress_chars("#>-"),
     );
+    if options.summary_only {
+        pb.set_draw_target(ProgressDrawTarget::hidden());
+    }
     pb.set_message("Building graphs...");
 
     let mut skipped_adapters = Vec::new();
+    // Route nodes of backend graphs and frontend call nodes, for endpoint matching
+    let mut backend_routes = Vec::new();
+    let mut frontend_calls = Vec::new();
+    // Ranges of `all_chains` built by frontend adapters
+    let mut frontend_chains = Vec::new();
+    // Problems found while building graphs (skipped unparseable files, ...)
+    let mut diagnostics = Vec::new();
+    // What the analysis couldn't follow (unresolved imports, skipped modules, ...)
+    let mut analysis_warnings: Vec<AnalysisWarning> = Vec::new();
 
     for (idx, adapter_config) in config.adapters.iter().enumerate() {
+        if expired() {
+            analysis_warnings.push(timed_out(format!(
+                "Adapter {} ({}) was not analyzed",
+                idx + 1,
+                adapter_config.adapter_type
+            )));
+            pb.inc(1);
+            continue;
+        }
         pb.set_message(format!(
             "Processing adapter {} ({})...",
             idx + 1,
             adapter_config.adapter_type
         ));
-        // Determine OpenAPI path for this adapter
-        let openapi_path = adapter_config
+        // Determine OpenAPI spec for this adapter (adapter-specific or global)
+        let openapi_source = adapter_config
             .openapi_path
             .as_ref()
-            .or(config.openapi_path.as_ref())
-            .map(PathBuf::from);
+            .or(config.openapi_path.as_ref());
+        let openapi_spec = openapi_source
+            .and_then(|source| load_openapi_spec(&mut openapi_specs, source, &fetch_options));
 
         match adapter_config.adapter_type.as_str() {
             "fastapi" => {
@@ -79,8 +266,21 @@ pub fn execute_check(config_path: &str, format: ReportFormat, verbose: bool) ->
                 // Build call graph for FastAPI
                 let mut builder = FastApiCallGraphBuilder::new(app_path)
                     .with_strict_imports(config.strict_imports.unwrap_or(false))
+                    .with_external_modules(config.external_modules.clone().unwrap_or_default())
+                    .with_strict_parse(options.strict_parse)
+                    .with_app_objects(if options.app_objects.is_empty() {
+                        adapter_config.app_objects.clone().unwrap_or_default()
+                    } else {
+                        options.app_objects.clone()
+                    })
+                    .with_transformation_methods(
+                        config.transformation_methods.clone().unwrap_or_default(),
+                    )
+                    .with_type_compatibility(config.type_compatibility())
+                    .with_respect_gitignore(!options.no_gitignore)
                     .with_verbose(verbose)
-                    .with_openapi_schema(openapi_path);
+                    .with_deadline(deadline)
+                    .with_openapi_spec(openapi_spec.clone());
                 // Set max recursion depth from config
                 if let Some(max_depth) = config.max_recursion_depth {
                     builder = builder.with_max_depth(Some(max_depth));
@@ -91,7 +291,21 @@ pub fn execute_check(config_path: &str, format: ReportFormat, verbose: bool) ->
                     .as_ref()
                     .map(convert_dynamic_routes_config);
                 builder = builder.with_dynamic_routes_config(adapter_dynamic_routes);
-                let graph = builder.build_graph()?;
+                let (graph, graph_diagnostics, graph_warnings) =
+                    match load_graph(options, idx, &adapter_config.adapter_type)? {
+                        Some(graph) => (graph, Vec::new(), Vec::new()),
+                        None => builder.build_graph_with_warnings()?,
+                    };
+                emit_graph(options, idx, &adapter_config.adapter_type, &graph)?;
+                backend_routes.extend(EndpointAnalyzer::endpoints_in_graph(&graph));
+                diagnostics.extend(graph_diagnostics);
+                analysis_warnings.extend(graph_warnings);
+                diagnostics.extend(operation_id_issues(
+                    &DocumentedRoute::in_graph(&graph),
+                    openapi_source
+                        .map(String::as_str)
+                        .zip(openapi_spec.as_ref()),
+                ));
 
                 // Create DataFlowTracker and ChainBuilder
                 let tracker = DataFlowTracker::new(&graph);
@@ -108,42 +322,29 @@ pub fn execute_check(config_path: &str, format: ReportFormat, verbose: bool) ->
                     .ok_or_else(|| anyhow::anyhow!("TypeScript adapter requires src_paths"))?;
                 let src_paths: Vec<PathBuf> = src_paths.iter().map(PathBuf::from).collect();
 
-                // Resolve OpenAPI path for this adapter (can be adapter-specific or global)
-                let openapi_path = adapter_config
-                    .openapi_path
-                    .as_ref()
-                    .or(config.openapi_path.as_ref())
-                    .map(PathBuf::from);
-
                 // Build optional OpenAPILinker for Zod → Pydantic chains
-                let ts_openapi_linker = if let Some(ref path) = openapi_path {
-                    match OpenAPIParser::parse_file(path) {
-                        Ok(schema) => Some(OpenAPILinker::new(schema)),
-                        Err(e) => {
-                            warn!(
-                                path = ?path,
-                                error = %e,
-                                "Failed to parse OpenAPI schema for TypeScript adapter"
-                            );
-                            None
-                        }
-                    }
-                } else {
-                    None
-                };
+                let ts_openapi_linker = openapi_spec.clone().map(OpenAPILinker::new);
 
                 // Build call graph for TypeScript
                 let builder = TypeScriptCallGraphBuilder::new(src_paths)
                     .with_max_depth(config.max_recursion_depth)
+                    .with_respect_gitignore(!options.no_gitignore)
                     .with_verbose(verbose)
-                    .with_openapi_schema(openapi_path);
-                let graph = builder.build_graph()?;
+                    .with_deadline(deadline)
+                    .with_openapi_spec(openapi_spec);
+                let graph = match load_graph(options, idx, &adapter_config.adapter_type)? {
+                    Some(graph) => graph,
+                    None => builder.build_graph()?,
+                };
+                emit_graph(options, idx, &adapter_config.adapter_type, &graph)?;
+                frontend_calls.extend(EndpointAnalyzer::endpoints_in_graph(&graph));
 
                 // Create DataFlowTracker and ChainBuilder
                 let tracker = DataFlowTracker::new(&graph);
                 let chain_builder = ChainBuilder::new(&graph, &tracker);
 
                 // Find all standard chains
+                let first_chain = all_chains.len();
                 let chains = chain_builder.find_all_chains()?;
                 all_chains.extend(chains);
 
@@ -152,6 +353,7 @@ pub fn execute_check(config_path: &str, format: ReportFormat, verbose: bool) ->
                     let zod_chains = chain_builder.find_zod_to_pydantic_chains(Some(linker))?;
                     all_chains.extend(zod_chains);
                 }
+                frontend_chains.push(first_chain..all_chains.len());
             }
             "nestjs" => {
                 let src_paths = adapter_config
@@ -161,11 +363,48 @@ pub fn execute_check(config_path: &str, format: ReportFormat, verbose: bool) ->
                 let src_paths: Vec<PathBuf> = src_paths.iter().map(PathBuf::from).collect();
 
                 // Build call graph for NestJS
-                let mut builder = NestJSCallGraphBuilder::new(src_paths).with_verbose(verbose);
+                let mut builder = NestJSCallGraphBuilder::new(src_paths)
+                    .with_respect_gitignore(!options.no_gitignore)
+                    .with_verbose(verbose)
+                    .with_deadline(deadline);
                 if let Some(max_depth) = config.max_recursion_depth {
                     builder = builder.with_max_depth(Some(max_depth));
                 }
-                let graph = builder.build_graph()?;
+                let graph = match load_graph(options, idx, &adapter_config.adapter_type)? {
+                    Some(graph) => graph,
+                    None => builder.build_graph()?,
+                };
+                emit_graph(options, idx, &adapter_config.adapter_type, &graph)?;
+                backend_routes.extend(EndpointAnalyzer::endpoints_in_graph(&graph));
+
+                // Create DataFlowTracker and ChainBuilder
+                let tracker = DataFlowTracker::new(&graph);
+                let chain_builder = ChainBuilder::new(&graph, &tracker);
+
+                // Find all chains
+                let chains = chain_builder.find_all_chains()?;
+                all_chains.extend(chains);
+            }
+            "express" => {
+                let src_paths = adapter_config
+                    .src_paths
+                    .as_ref()
+                    .ok_or_else(|| anyhow::anyhow!("Express adapter requires src_paths"))?;
+                let src_paths: Vec<PathBuf> = src_paths.iter().map(PathBuf::from).collect();
+
+                // Build call graph for Express routes
+                let builder = TypeScriptCallGraphBuilder::new(src_paths)
+                    .with_express_routes(true)
+                    .with_max_depth(config.max_recursion_depth)
+                    .with_respect_gitignore(!options.no_gitignore)
+                    .with_verbose(verbose)
+                    .with_deadline(deadline);
+                let graph = match load_graph(options, idx, &adapter_config.adapter_type)? {
+                    Some(graph) => graph,
+                    None => builder.build_graph()?,
+                };
+                emit_graph(options, idx, &adapter_config.adapter_type, &graph)?;
+                backend_routes.extend(EndpointAnalyzer::endpoints_in_graph(&graph));
 
                 // Create DataFlowTracker and ChainBuilder
                 let tracker = DataFlowTracker::new(&graph);
@@ -184,6 +423,13 @@ pub fn execute_check(config_path: &str, format: ReportFormat, verbose: bool) ->
                 skipped_adapters.push(adapter_type);
             }
         }
+        if expired() {
+            analysis_warnings.push(timed_out(format!(
+                "Adapter {} ({}) was partly analyzed: files not reached in time were skipped",
+                idx + 1,
+                adapter_config.adapter_type
+            )));
+        }
         pb.inc(1);
     }
 
@@ -206,54 +452,482 @@ pub fn execute_check(config_path: &str, format: ReportFormat, verbose: bool) ->
             .unwrap()
             .progress_chars("#>-"),
     );
+    if options.summary_only {
+        pb.set_draw_target(ProgressDrawTarget::hidden());
+    }
     pb.set_message("Checking contracts...");
 
-    let checker = ContractChecker::new();
-    for chain in &mut all_chains {
+    let checker = ContractChecker::with_type_compatibility(config.type_compatibility());
+    let chain_count = all_chains.len();
+    for (checked, chain) in all_chains.iter_mut().enumerate() {
+        if expired() {
+            analysis_warnings.push(timed_out(format!(
+                "{} of {} chain(s) were not checked",
+                chain_count - checked,
+                chain_count
+            )));
+            break;
+        }
         for contract in &mut chain.contracts {
+            // A schema shared by both links is authoritative: there is nothing to compare
+            if is_shared_contract(contract) {
+                continue;
+            }
             let mismatches = checker.check_contract(contract);
             contract.mismatches = mismatches.clone();
 
             // Determine severity based on Mismatch types
-            contract.severity = if mismatches
-                .iter()
-                .any(|m| matches!(m.mismatch_type, dc_core::models::MismatchType::TypeMismatch))
-            {
-                Severity::Critical
-            } else if !mismatches.is_empty() {
-                Severity::Warning
-            } else {
-                Severity::Info
-            };
+            contract.severity = Contract::severity_of(&mismatches);
         }
         pb.inc(1);
     }
 
     pb.finish_with_message("Contracts checked");
 
+    // The same issue is often reached through several routes: report it once
+    deduplicate_diagnostics(&mut all_chains);
+
+    // Paginated routes should all return one envelope
+    diagnostics.extend(inconsistent_pagination(&backend_routes));
+    // Every frontend call should reach exactly one backend route
+    let mut endpoint_issues = if backend_routes.is_empty() || frontend_calls.is_empty() {
+        Vec::new()
+    } else if expired() {
+        analysis_warnings.push(timed_out(
+            "Frontend calls were not matched to backend routes".to_string(),
+        ));
+        Vec::new()
+    } else {
+        let analyzer = EndpointAnalyzer::new(backend_routes);
+        // Deprecated routes and fields are reported, not enforced
+        diagnostics.extend(analyzer.deprecated_calls(&frontend_calls));
+        // Calls and routes importing one schema module must agree on it
+        diagnostics.extend(shared_schema_issues(&analyzer, &frontend_calls));
+        // Headers the backend requires must be set by the calls reaching it
+        diagnostics.extend(analyzer.missing_headers(&frontend_calls));
+        // Bodies must be sent in the media type the route reads
+        diagnostics.extend(analyzer.media_type_mismatches(&frontend_calls));
+        // Calls must only read the pagination fields their route returns
+        diagnostics.extend(pagination_call_mismatches(&analyzer, &frontend_calls));
+        // Query values must coerce to the types their route validates
+        diagnostics.extend(query_coercion_issues(&analyzer, &frontend_calls));
+        if config.strict_trailing_slash.unwrap_or(false) {
+            diagnostics.extend(analyzer.trailing_slash_redirects(&frontend_calls));
+        }
+        analyzer.check_calls(&frontend_calls)
+    };
+    diagnostics.extend(deprecated_field_usages(&all_chains));
+    diagnostics.extend(stale_examples(&all_chains));
+    // Frontend types the rules couldn't see into are skipped silently unless asked for
+    if options.strict_types {
+        diagnostics.extend(unresolved_frontend_types(
+            frontend_chains
+                .iter()
+                .flat_map(|range| &all_chains[range.clone()]),
+        ));
+    }
+    if let Some(changed_files) = &changed_files {
+        retain_changed(
+            changed_files,
+            &mut all_chains,
+            &mut endpoint_issues,
+            &mut diagnostics,
+        );
+    }
+    // Paths are rewritten last: the steps above read the files they point to
+    let path_style = options.paths.or(config.output.paths);
+    let paths_root = match options
+        .paths_root
+        .as_ref()
+        .or(config.output.paths_root.as_ref())
+    {
+        Some(root) => PathBuf::from(root),
+        None => base_path
+            .filter(|path| !path.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."))
+            .to_path_buf(),
+    };
+    if let Some(style) = path_style {
+        let outside_root = rewrite_report_paths(
+            style,
+            &paths_root,
+            &mut all_chains,
+            &mut endpoint_issues,
+            &mut diagnostics,
+            &mut analysis_warnings,
+        );
+        if !outside_root.is_empty() && !options.summary_only {
+            print!("{}", render_outside_root(&paths_root, &outside_root));
+        }
+    }
+    for issue in &endpoint_issues {
+        warn!(
+            file = %issue.call.location.file,
+            line = issue.call.location.line,
+            "{}",
+            issue.message
+        );
+    }
+
     // 4. Generate report
     let pb = ProgressBar::new_spinner();
+    if options.summary_only {
+        pb.set_draw_target(ProgressDrawTarget::hidden());
+    }
     pb.set_message("Generating report...");
     pb.enable_steady_tick(std::time::Duration::from_millis(100));
     match format {
         ReportFormat::Json => {
-            JsonReporter.generate(&all_chains, &config.output.path)?;
+            JsonReporter.generate_report(
+                &all_chains,
+                &endpoint_issues,
+                &diagnostics,
+                &analysis_warnings,
+                &config.output.path,
+            )?;
+        }
+        ReportFormat::Junit => {
+            JunitReporter.generate_report(
+                &all_chains,
+                &endpoint_issues,
+                &diagnostics,
+                &analysis_warnings,
+                &config.output.path,
+            )?;
+        }
+        ReportFormat::Sarif => {
+            SarifReporter.generate_report(
+                &all_chains,
+                &endpoint_issues,
+                &diagnostics,
+                &analysis_warnings,
+                &config.output.path,
+            )?;
         }
         ReportFormat::Markdown => {
-            MarkdownReporter.generate(&all_chains, &config.output.path)?;
+            MarkdownReporter.generate_report_with_source_root(
+                &all_chains,
+                &endpoint_issues,
+                &diagnostics,
+                &analysis_warnings,
+                (path_style == Some(PathStyle::Relative)).then_some(paths_root.as_path()),
+                &config.output.path,
+            )?;
         }
     }
 
     pb.finish_with_message("Report generated");
 
-    println!(
-        "Verification completed. Report saved to {}",
-        config.output.path
-    );
+    if options.summary_only {
+        print!(
+            "{}",
+            SummaryReporter.render_report(
+                &all_chains,
+                &endpoint_issues,
+                &diagnostics,
+                &analysis_warnings,
+                options.fail_on
+            )
+        );
+    } else {
+        println!(
+            "Verification completed. Report saved to {}",
+            config.output.path
+        );
+    }
 
+    if let Some(timeout) = options.timeout {
+        if analysis_warnings
+            .iter()
+            .any(|warning| warning.kind == AnalysisWarningKind::TimedOut)
+        {
+            return Err(AnalysisTimedOut { timeout }.into());
+        }
+    }
+    let counts = FindingCounts::of(&all_chains, &endpoint_issues, &diagnostics);
+    if !counts.passes(options.fail_on) {
+        return Err(VerificationFailed { counts }.into());
+    }
     Ok(())
 }
 
+/// Parses (or fetches, for a URL) the OpenAPI spec of `source`, once per source
+///
+/// Failures are logged and cached as `None`, so a broken spec is reported only once.
+fn load_openapi_spec(
+    specs: &mut HashMap<String, Option<OpenAPISchema>>,
+    source: &str,
+    fetch_options: &FetchOptions,
+) -> Option<OpenAPISchema> {
+    specs
+        .entry(source.to_string())
+        .or_insert_with(|| {
+            OpenAPIParser::parse_source(source, fetch_options)
+                .map_err(|e| {
+                    warn!(
+                        source = %source,
+                        error = %e,
+                        "Failed to load OpenAPI schema"
+                    );
+                })
+                .ok()
+        })
+        .clone()
+}
+
+/// Applies the safe source fixes to the Python sources of every FastAPI adapter
+fn apply_fixes(config: &Config, options: &CheckOptions) -> Result<Vec<SourceEdit>> {
+    let mut edits = Vec::new();
+    for adapter_config in &config.adapters {
+        if adapter_config.adapter_type != "fastapi" {
+            continue;
+        }
+        let Some(app_path) = adapter_config.app_path.as_ref() else {
+            continue;
+        };
+        let app_objects = if options.app_objects.is_empty() {
+            adapter_config.app_objects.clone().unwrap_or_default()
+        } else {
+            options.app_objects.clone()
+        };
+        let (graph, _) = FastApiCallGraphBuilder::new(PathBuf::from(app_path))
+            .with_strict_imports(config.strict_imports.unwrap_or(false))
+            .with_external_modules(config.external_modules.clone().unwrap_or_default())
+            .with_app_objects(app_objects.clone())
+            .with_max_depth(config.max_recursion_depth)
+            .with_respect_gitignore(!options.no_gitignore)
+            .build_graph_with_diagnostics()?;
+
+        let mut parser = PythonParser::new();
+        parser.set_route_objects(app_objects);
+        edits.extend(fix_missing_response_models(&graph, &parser)?);
+    }
+    Ok(edits)
+}
+
+/// One line per applied fix, preceded by their count
+fn render_fix_summary(edits: &[SourceEdit]) -> String {
+    let mut summary = format!("Applied {} fix(es)\n", edits.len());
+    for edit in edits {
+        summary.push_str(&format!(
+            "  {}:{}: {}\n",
+            edit.location.file, edit.location.line, edit.description
+        ));
+    }
+    summary
+}
+
+/// What a check would analyze, for `--dry-run`
+///
+/// Lists the resolved config, then each adapter's source roots, OpenAPI spec, entry points
+/// and source files, and ends with the file count per category. Paths under the config
+/// file's directory are shown relative to it.
+fn render_dry_run(
+    config_path: &str,
+    config: &Config,
+    format: ReportFormat,
+    options: &CheckOptions,
+) -> Result<String> {
+    let base = Path::new(config_path)
+        .parent()
+        .filter(|path| !path.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    let base = canonical_file(base);
+    let show = |path: &Path| {
+        let path = canonical_file(path);
+        path.strip_prefix(&base)
+            .unwrap_or(&path)
+            .display()
+            .to_string()
+    };
+    let list = |out: &mut String, title: &str, paths: &[PathBuf]| {
+        out.push_str(&format!("  {} ({}):\n", title, paths.len()));
+        for path in paths {
+            out.push_str(&format!("    {}\n", show(path)));
+        }
+    };
+
+    let mut out = format!(
+        "Dry run: nothing is analyzed\nConfig: {}\nProject: {}\nReport: {} -> {}\n",
+        Path::new(config_path).display(),
+        config.project_name,
+        format!("{:?}", format).to_lowercase(),
+        show(Path::new(&config.output.path))
+    );
+
+    let (mut backend_files, mut frontend_files) = (0, 0);
+    for (idx, adapter_config) in config.adapters.iter().enumerate() {
+        let adapter_type = adapter_config.adapter_type.as_str();
+        let category = match adapter_type {
+            "fastapi" | "nestjs" | "express" => "backend",
+            "typescript" => "frontend",
+            _ => {
+                out.push_str(&format!(
+                    "\nAdapter {}: {} (unknown type, skipped)\n",
+                    idx + 1,
+                    adapter_type
+                ));
+                continue;
+            }
+        };
+        out.push_str(&format!(
+            "\nAdapter {}: {} ({})\n",
+            idx + 1,
+            adapter_type,
+            category
+        ));
+        let openapi_source = adapter_config
+            .openapi_path
+            .as_ref()
+            .or(config.openapi_path.as_ref());
+        out.push_str(&format!(
+            "  OpenAPI spec: {}\n",
+            openapi_source
+                .map(|source| {
+                    if OpenAPIParser::is_url(source) {
+                        source.clone()
+                    } else {
+                        show(Path::new(source))
+                    }
+                })
+                .unwrap_or_else(|| "none".to_string())
+        ));
+
+        let files = if adapter_type == "fastapi" {
+            let app_path = adapter_config
+                .app_path
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("FastAPI adapter requires app_path"))?;
+            out.push_str(&format!("  App: {}\n", show(Path::new(app_path))));
+            let builder = FastApiCallGraphBuilder::new(PathBuf::from(app_path))
+                .with_respect_gitignore(!options.no_gitignore);
+            list(&mut out, "Entry points", &builder.entry_points()?);
+            builder.source_files()
+        } else {
+            let src_paths: Vec<PathBuf> = adapter_config
+                .src_paths
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("{} adapter requires src_paths", adapter_type))?
+                .iter()
+                .map(PathBuf::from)
+                .collect();
+            let roots: Vec<String> = src_paths.iter().map(|path| show(path)).collect();
+            out.push_str(&format!("  Source roots: {}\n", roots.join(", ")));
+            TypeScriptCallGraphBuilder::new(src_paths)
+                .with_respect_gitignore(!options.no_gitignore)
+                .source_files()
+        };
+        list(&mut out, "Files", &files);
+        match category {
+            "backend" => backend_files += files.len(),
+            _ => frontend_files += files.len(),
+        }
+    }
+
+    out.push_str(&format!(
+        "\nFiles: {} backend, {} frontend\n",
+        backend_files, frontend_files
+    ));
+    Ok(out)
+}
+
+/// Files selected by `--since`, one per line, preceded by their count
+///
+/// The whole project is still analyzed; only the report is limited to these files.
+fn render_changed_files(git_ref: &str, files: &[PathBuf]) -> String {
+    let mut selection = format!(
+        "Reporting issues in {} file(s) changed since {} (the whole project is analyzed)\n",
+        files.len(),
+        git_ref
+    );
+    for file in files {
+        selection.push_str(&format!("  {}\n", file.display()));
+    }
+    selection
+}
+
+fn render_outside_root(root: &Path, files: &[String]) -> String {
+    let mut note = format!(
+        "Note: {} file(s) outside {} are reported with absolute paths:\n",
+        files.len(),
+        root.display()
+    );
+    for file in files {
+        note.push_str(&format!("  {}\n", file));
+    }
+    note
+}
+
+/// Drops chains, endpoint issues and diagnostics that don't touch any of `changed_files`
+///
+/// A chain is kept when one of its links or contract schemas is declared in a changed file,
+/// an endpoint issue when its call or one of the candidate routes is.
+fn retain_changed(
+    changed_files: &HashSet<PathBuf>,
+    chains: &mut Vec<DataChain>,
+    endpoint_issues: &mut Vec<EndpointIssue>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let is_changed = |location: &Location| changed_files.contains(&canonical_file(&location.file));
+    chains.retain(|chain| {
+        chain.links.iter().any(|link| is_changed(&link.location))
+            || chain.contracts.iter().any(|contract| {
+                is_changed(&contract.from_schema.location)
+                    || is_changed(&contract.to_schema.location)
+            })
+    });
+    endpoint_issues.retain(|issue| {
+        is_changed(&issue.call.location)
+            || issue
+                .candidates
+                .iter()
+                .any(|route| is_changed(&route.location))
+    });
+    diagnostics.retain(|diagnostic| is_changed(&diagnostic.location));
+}
+
+/// File holding the serialized call graph of the adapter at `idx` (`fastapi_0.json`)
+fn graph_file(dir: &str, idx: usize, adapter_type: &str) -> PathBuf {
+    Path::new(dir).join(format!("{}_{}.json", adapter_type, idx))
+}
+
+/// Call graph of the adapter at `idx` read from `--graph`, when given
+///
+/// A loaded graph carries no build diagnostics or analysis warnings: those are only
+/// reported by the run that parsed the sources.
+fn load_graph(options: &CheckOptions, idx: usize, adapter_type: &str) -> Result<Option<CallGraph>> {
+    let Some(dir) = &options.graph else {
+        return Ok(None);
+    };
+    let path = graph_file(dir, idx, adapter_type);
+    let json = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read call graph {}", path.display()))?;
+    let graph = from_json(&json)
+        .with_context(|| format!("Failed to load call graph {}", path.display()))?;
+    Ok(Some(graph))
+}
+
+/// Writes the call graph of the adapter at `idx` under `--emit-graph`, when given
+fn emit_graph(
+    options: &CheckOptions,
+    idx: usize,
+    adapter_type: &str,
+    graph: &CallGraph,
+) -> Result<()> {
+    let Some(dir) = &options.emit_graph else {
+        return Ok(());
+    };
+    let path = graph_file(dir, idx, adapter_type);
+    fs::create_dir_all(dir)?;
+    fs::write(&path, to_json(graph)?)
+        .with_context(|| format!("Failed to write call graph {}", path.display()))
+}
+
+fn canonical_file(file: impl AsRef<Path>) -> PathBuf {
+    let file = file.as_ref();
+    file.canonicalize().unwrap_or_else(|_| file.to_path_buf())
+}
+
 /// Converts CLI config types to adapter config types
 fn convert_dynamic_routes_config(config: &DynamicRoutesConfig) -> AdapterDynamicRoutesConfig {
     AdapterDynamicRoutesConfig {
@@ -0,0 +1,76 @@
+use anyhow::Result;
+use dc_core::models::{RuleInfo, Severity, RULES};
+use serde::Serialize;
+
+/// One rule of the `--list-rules` JSON output
+#[derive(Debug, Serialize)]
+struct RuleListEntry {
+    id: &'static str,
+    default_severity: Severity,
+    title: &'static str,
+    docs_url: String,
+}
+
+/// Prints what a rule checks, why it matters and how to fix a violation
+pub fn execute_explain(rule_id: &str) -> Result<()> {
+    print!("{}", render_explanation(rule_id)?);
+    Ok(())
+}
+
+/// Renders the explanation of a rule; unknown ids list the known ones
+pub fn render_explanation(rule_id: &str) -> Result<String> {
+    let Some(rule) = RuleInfo::find(rule_id) else {
+        let known: Vec<&str> = RULES.iter().map(|rule| rule.id).collect();
+        anyhow::bail!(
+            "Unknown rule '{}'. Known rules: {}",
+            rule_id,
+            known.join(", ")
+        );
+    };
+
+    Ok(format!(
+        "{} — {}\n\n{}\n\nWhy it matters:\n  {}\n\nExample fix:\n  {}\n\nDocs: {}\n",
+        rule.id,
+        rule.title,
+        rule.description,
+        rule.rationale,
+        rule.example_fix,
+        rule.docs_url()
+    ))
+}
+
+/// Prints every rule with its default severity and one-line summary
+pub fn execute_list_rules(json: bool) -> Result<()> {
+    print!("{}", render_rule_list(json)?);
+    Ok(())
+}
+
+/// Renders the rule list as aligned text columns, or as a JSON array when `json` is set
+pub fn render_rule_list(json: bool) -> Result<String> {
+    if json {
+        let entries: Vec<RuleListEntry> = RULES
+            .iter()
+            .map(|rule| RuleListEntry {
+                id: rule.id,
+                default_severity: rule.default_severity,
+                title: rule.title,
+                docs_url: rule.docs_url(),
+            })
+            .collect();
+        return Ok(serde_json::to_string_pretty(&entries)? + "\n");
+    }
+
+    let width = RULES.iter().map(|rule| rule.id.len()).max().unwrap_or(0);
+    let mut text = String::new();
+    for rule in RULES {
+        let severity = format!("{:?}", rule.default_severity).to_lowercase();
+        text.push_str(&format!(
+            "{:<width$}  {:<8}  {}\n",
+            rule.id,
+            severity,
+            rule.title,
+            width = width
+        ));
+    }
+    Ok(text)
+}
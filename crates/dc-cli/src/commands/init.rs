@@ -30,6 +30,11 @@ src_paths = ["frontend/src"]
 # src_paths = ["backend/src"]
 # # openapi_path = "backend/openapi.json"  # Optional override
 
+# Example Express adapter configuration:
+# [[adapters]]
+# type = "express"
+# src_paths = ["server/src"]
+
 [rules]
 type_mismatch = "critical"
 missing_field = "warning"
@@ -1,3 +1,7 @@
 pub mod check;
+pub mod explain;
 pub mod init;
+pub mod orm_links;
+pub mod schema_graph;
+pub mod schemas;
 pub mod visualize;
@@ -0,0 +1,117 @@
+use crate::commands::schemas::collect_project_schemas;
+use crate::ManifestFormat;
+use anyhow::Result;
+use dc_core::models::{Location, SchemaReference, SchemaType};
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+/// One Pydantic model of the ORM link table
+#[derive(Debug, Clone, Serialize)]
+pub struct OrmLinkEntry {
+    /// Pydantic model name
+    pub pydantic_model: String,
+    /// Where the Pydantic model is declared
+    pub location: Location,
+    /// Linked SQLAlchemy model (`None` when no model matched)
+    pub orm_model: Option<String>,
+    /// Where the linked model is declared (`file:line`)
+    pub orm_location: Option<String>,
+    /// How the link was found: `exact`, `base-name` or `field-based`
+    pub strategy: Option<String>,
+    /// Match score between 0 and 1
+    pub score: Option<f64>,
+}
+
+/// Prints (or saves to `output`) the Pydantic ↔ SQLAlchemy links of the project
+pub fn execute_orm_links(
+    config_path: &str,
+    format: ManifestFormat,
+    output: Option<&str>,
+) -> Result<()> {
+    let table = render_link_table(&build_link_table(config_path)?, format)?;
+    match output {
+        Some(path) => {
+            if let Some(parent) = Path::new(path).parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, table)?;
+        }
+        None => print!("{}", table),
+    }
+    Ok(())
+}
+
+/// Lists every linked Pydantic model and every `from_attributes` model left unlinked,
+/// sorted by name
+pub fn build_link_table(config_path: &str) -> Result<Vec<OrmLinkEntry>> {
+    let mut entries: Vec<OrmLinkEntry> = collect_project_schemas(config_path)?
+        .iter()
+        .filter_map(link_entry)
+        .collect();
+    entries.sort_by(|a, b| {
+        (&a.pydantic_model, &a.location.file, a.location.line).cmp(&(
+            &b.pydantic_model,
+            &b.location.file,
+            b.location.line,
+        ))
+    });
+    Ok(entries)
+}
+
+/// Renders the link table as JSON or as a Markdown table
+pub fn render_link_table(entries: &[OrmLinkEntry], format: ManifestFormat) -> Result<String> {
+    match format {
+        ManifestFormat::Json => Ok(serde_json::to_string_pretty(entries)? + "\n"),
+        ManifestFormat::Markdown => {
+            let mut md = String::from("# ORM Links\n\n");
+            md.push_str("| Pydantic model | Location | ORM model | Strategy | Score |\n");
+            md.push_str("|----------------|----------|-----------|----------|-------|\n");
+            for entry in entries {
+                let orm_model = match (&entry.orm_model, &entry.orm_location) {
+                    (Some(name), Some(location)) => format!("{} ({})", name, location),
+                    (Some(name), None) => name.clone(),
+                    _ => "⚠️ not linked".to_string(),
+                };
+                md.push_str(&format!(
+                    "| {} | {}:{} | {} | {} | {} |\n",
+                    entry.pydantic_model,
+                    entry.location.file,
+                    entry.location.line,
+                    orm_model,
+                    entry.strategy.as_deref().unwrap_or("-"),
+                    entry
+                        .score
+                        .map(|score| format!("{:.2}", score))
+                        .unwrap_or_else(|| "-".to_string())
+                ));
+            }
+            Ok(md)
+        }
+    }
+}
+
+fn link_entry(schema: &SchemaReference) -> Option<OrmLinkEntry> {
+    if schema.schema_type != SchemaType::Pydantic {
+        return None;
+    }
+    let orm_model = schema.metadata.get("sqlalchemy_model").cloned();
+    let from_attributes = schema
+        .metadata
+        .get("from_attributes")
+        .is_some_and(|value| value.eq_ignore_ascii_case("true"));
+    if orm_model.is_none() && !from_attributes {
+        return None;
+    }
+    Some(OrmLinkEntry {
+        pydantic_model: schema.name.clone(),
+        location: schema.location.clone(),
+        orm_model,
+        orm_location: schema.metadata.get("sqlalchemy_location").cloned(),
+        strategy: schema.metadata.get("sqlalchemy_match_strategy").cloned(),
+        score: schema
+            .metadata
+            .get("sqlalchemy_match_score")
+            .and_then(|score| score.parse().ok()),
+    })
+}
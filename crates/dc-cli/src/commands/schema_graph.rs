@@ -0,0 +1,29 @@
+use crate::commands::schemas::collect_project_schemas;
+use crate::GraphFormat;
+use anyhow::Result;
+use dc_core::analyzers::SchemaDependencyGraph;
+use std::fs;
+use std::path::Path;
+
+/// Prints (or saves to `output`) which schema references which through its fields
+pub fn execute_schema_graph(
+    config_path: &str,
+    format: GraphFormat,
+    output: Option<&str>,
+) -> Result<()> {
+    let graph = SchemaDependencyGraph::build(&collect_project_schemas(config_path)?);
+    let rendered = match format {
+        GraphFormat::Dot => graph.to_dot(),
+        GraphFormat::Json => serde_json::to_string_pretty(&graph)? + "\n",
+    };
+    match output {
+        Some(path) => {
+            if let Some(parent) = Path::new(path).parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, rendered)?;
+        }
+        None => print!("{}", rendered),
+    }
+    Ok(())
+}
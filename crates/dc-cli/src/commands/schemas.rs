@@ -0,0 +1,269 @@
+use crate::config::Config;
+use crate::ManifestFormat;
+use anyhow::Result;
+use dc_adapter_fastapi::FastApiCallGraphBuilder;
+use dc_adapter_nestjs::NestJSCallGraphBuilder;
+use dc_core::analyzers::SchemaParser;
+use dc_core::call_graph::{collect_schemas, CallGraph};
+use dc_core::models::{Location, PydanticFieldInfo, SchemaReference, SchemaType};
+use dc_typescript::TypeScriptCallGraphBuilder;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::error;
+
+/// One schema of the manifest
+#[derive(Debug, Clone, Serialize)]
+pub struct SchemaEntry {
+    /// Schema name (class, interface or variable name)
+    pub name: String,
+    /// Kind of schema (Pydantic, OrmModel, TypeScript, Zod, ...)
+    pub schema_type: SchemaType,
+    /// Where the schema is declared
+    pub location: Location,
+    /// Hash of the schema's fields, unchanged when only its location changes (see
+    /// [`SchemaReference::content_hash`])
+    pub content_hash: String,
+    /// Fields ordered by name (empty when the shape is unknown)
+    pub fields: Vec<SchemaFieldEntry>,
+    /// Schemas on the other side of an ORM ↔ Pydantic link
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub orm_links: Vec<String>,
+    /// Full JSON schema, when it was extracted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json_schema: Option<serde_json::Value>,
+}
+
+/// One field of a manifest schema
+#[derive(Debug, Clone, Serialize)]
+pub struct SchemaFieldEntry {
+    /// Field name
+    pub name: String,
+    /// Declared field type
+    #[serde(rename = "type")]
+    pub field_type: String,
+    /// Whether the field may be omitted
+    pub optional: bool,
+}
+
+/// Prints (or saves to `output`) the manifest of all schemas detected by the configured adapters
+pub fn execute_schemas(
+    config_path: &str,
+    format: ManifestFormat,
+    output: Option<&str>,
+) -> Result<()> {
+    let manifest = render_manifest(&build_manifest(config_path)?, format)?;
+    match output {
+        Some(path) => {
+            if let Some(parent) = Path::new(path).parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, manifest)?;
+        }
+        None => print!("{}", manifest),
+    }
+    Ok(())
+}
+
+/// Builds the graphs of all adapters and lists their schemas, sorted by name
+pub fn build_manifest(config_path: &str) -> Result<Vec<SchemaEntry>> {
+    let schemas = collect_project_schemas(config_path)?;
+    let mut entries: Vec<SchemaEntry> = schemas
+        .iter()
+        .map(|schema| schema_entry(schema, &schemas))
+        .collect();
+    entries.sort_by(|a, b| {
+        (&a.name, &a.location.file, a.location.line).cmp(&(
+            &b.name,
+            &b.location.file,
+            b.location.line,
+        ))
+    });
+    Ok(entries)
+}
+
+/// Builds the graphs of all adapters and collects their schemas, each once
+pub fn collect_project_schemas(config_path: &str) -> Result<Vec<SchemaReference>> {
+    let config_file_path = Path::new(config_path);
+    let base_path = config_file_path.parent();
+    let config = Config::load(config_path, base_path)?;
+
+    let mut schemas: Vec<SchemaReference> = Vec::new();
+    for adapter_config in &config.adapters {
+        let graph = match adapter_config.adapter_type.as_str() {
+            "fastapi" => {
+                let app_path = adapter_config
+                    .app_path
+                    .as_ref()
+                    .ok_or_else(|| anyhow::anyhow!("FastAPI adapter requires app_path"))?;
+                FastApiCallGraphBuilder::new(PathBuf::from(app_path))
+                    .with_strict_imports(config.strict_imports.unwrap_or(false))
+                    .with_external_modules(config.external_modules.clone().unwrap_or_default())
+                    .with_transformation_methods(
+                        config.transformation_methods.clone().unwrap_or_default(),
+                    )
+                    .with_type_compatibility(config.type_compatibility())
+                    .with_app_objects(adapter_config.app_objects.clone().unwrap_or_default())
+                    .with_max_depth(config.max_recursion_depth)
+                    .build_graph()?
+            }
+            "typescript" => {
+                let src_paths = adapter_config
+                    .src_paths
+                    .as_ref()
+                    .ok_or_else(|| anyhow::anyhow!("TypeScript adapter requires src_paths"))?;
+                TypeScriptCallGraphBuilder::new(src_paths.iter().map(PathBuf::from).collect())
+                    .with_max_depth(config.max_recursion_depth)
+                    .build_graph()?
+            }
+            "nestjs" => {
+                let src_paths = adapter_config
+                    .src_paths
+                    .as_ref()
+                    .ok_or_else(|| anyhow::anyhow!("NestJS adapter requires src_paths"))?;
+                NestJSCallGraphBuilder::new(src_paths.iter().map(PathBuf::from).collect())
+                    .with_max_depth(config.max_recursion_depth)
+                    .build_graph()?
+            }
+            "express" => {
+                let src_paths = adapter_config
+                    .src_paths
+                    .as_ref()
+                    .ok_or_else(|| anyhow::anyhow!("Express adapter requires src_paths"))?;
+                TypeScriptCallGraphBuilder::new(src_paths.iter().map(PathBuf::from).collect())
+                    .with_express_routes(true)
+                    .with_max_depth(config.max_recursion_depth)
+                    .build_graph()?
+            }
+            _ => {
+                error!(
+                    adapter_type = %adapter_config.adapter_type,
+                    "Unknown adapter type"
+                );
+                continue;
+            }
+        };
+        merge_schemas(&mut schemas, &graph);
+    }
+    Ok(schemas)
+}
+
+/// Renders the manifest as JSON or as a Markdown table
+pub fn render_manifest(entries: &[SchemaEntry], format: ManifestFormat) -> Result<String> {
+    match format {
+        ManifestFormat::Json => Ok(serde_json::to_string_pretty(entries)? + "\n"),
+        ManifestFormat::Markdown => {
+            let mut md = String::from("# Schemas\n\n");
+            md.push_str("| Name | Type | Location | Fields | ORM links |\n");
+            md.push_str("|------|------|----------|--------|-----------|\n");
+            for entry in entries {
+                let fields: Vec<String> = entry
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            "{}{}: {}",
+                            field.name,
+                            if field.optional { "?" } else { "" },
+                            field.field_type
+                        )
+                    })
+                    .collect();
+                md.push_str(&format!(
+                    "| {} | {:?} | {}:{} | {} | {} |\n",
+                    entry.name,
+                    entry.schema_type,
+                    entry.location.file,
+                    entry.location.line,
+                    fields.join(", ").replace('|', "\\|"),
+                    entry.orm_links.join(", ")
+                ));
+            }
+            Ok(md)
+        }
+    }
+}
+
+/// Adds the schemas of a graph, skipping ones already collected from another adapter
+fn merge_schemas(schemas: &mut Vec<SchemaReference>, graph: &CallGraph) {
+    for schema in collect_schemas(graph) {
+        let known = schemas.iter().any(|known| {
+            known.schema_type == schema.schema_type
+                && known.name == schema.name
+                && known.location == schema.location
+        });
+        if !known {
+            schemas.push(schema);
+        }
+    }
+}
+
+/// Declared fields of a schema
+///
+/// Pydantic fields are read from their own metadata, which knows which fields have
+/// defaults; other schemas go through `SchemaParser`.
+fn schema_fields(schema: &SchemaReference) -> Vec<SchemaFieldEntry> {
+    let pydantic_fields = (schema.schema_type == SchemaType::Pydantic)
+        .then(|| schema.metadata.get("fields"))
+        .flatten()
+        .and_then(|json| serde_json::from_str::<Vec<PydanticFieldInfo>>(json).ok());
+    let mut fields: Vec<SchemaFieldEntry> = match pydantic_fields {
+        Some(pydantic_fields) => pydantic_fields
+            .into_iter()
+            .map(|field| SchemaFieldEntry {
+                optional: !field.is_required(),
+                name: field.name,
+                field_type: field.type_name,
+            })
+            .collect(),
+        None => SchemaParser::parse(schema)
+            .map(|parsed| {
+                parsed
+                    .properties
+                    .into_iter()
+                    .map(|(name, info)| SchemaFieldEntry {
+                        name,
+                        field_type: info.field_type,
+                        optional: info.optional,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+    };
+    fields.sort_by(|a, b| a.name.cmp(&b.name));
+    fields
+}
+
+fn schema_entry(schema: &SchemaReference, all: &[SchemaReference]) -> SchemaEntry {
+    let fields = schema_fields(schema);
+
+    // Pydantic models record the ORM model they were linked to; ORM models list them back
+    let mut orm_links: Vec<String> = match schema.schema_type {
+        SchemaType::Pydantic => schema.metadata.get("sqlalchemy_model").cloned(),
+        _ => None,
+    }
+    .into_iter()
+    .collect();
+    if schema.schema_type == SchemaType::OrmModel {
+        orm_links.extend(
+            all.iter()
+                .filter(|other| other.metadata.get("sqlalchemy_model") == Some(&schema.name))
+                .map(|other| other.name.clone()),
+        );
+        orm_links.sort();
+        orm_links.dedup();
+    }
+
+    SchemaEntry {
+        name: schema.name.clone(),
+        schema_type: schema.schema_type,
+        location: schema.location.clone(),
+        content_hash: schema.content_hash(),
+        fields,
+        orm_links,
+        json_schema: schema
+            .metadata
+            .get("json_schema")
+            .and_then(|json| serde_json::from_str(json).ok()),
+    }
+}
@@ -4,7 +4,7 @@ use dc_adapter_fastapi::{
     DynamicRoutesConfig as AdapterDynamicRoutesConfig, EndpointConfig as AdapterEndpointConfig,
     FastApiCallGraphBuilder, RouterGeneratorConfig as AdapterRouterGeneratorConfig,
 };
-use dc_core::call_graph::{CallEdge, CallGraph, CallNode};
+use dc_core::call_graph::to_dot;
 use dc_typescript::TypeScriptCallGraphBuilder;
 use indicatif::{ProgressBar, ProgressStyle};
 use std::fs;
@@ -12,7 +12,10 @@ use std::path::{Path, PathBuf};
 use tracing::error;
 
 /// Visualizes call graphs (optional function)
-pub fn execute_visualize(config_path: &str) -> Result<()> {
+///
+/// Writes one DOT file per adapter under `output` (a directory, or a `.dot` file) when given,
+/// else under the configured output path.
+pub fn execute_visualize(config_path: &str, output: Option<&str>) -> Result<()> {
     let config_file_path = Path::new(config_path);
     let base_path = config_file_path.parent();
     let config = Config::load(config_path, base_path)?;
@@ -44,7 +47,13 @@ pub fn execute_visualize(config_path: &str) -> Result<()> {
                 let app_path = PathBuf::from(app_path);
 
                 let mut builder = FastApiCallGraphBuilder::new(app_path)
-                    .with_strict_imports(config.strict_imports.unwrap_or(false));
+                    .with_strict_imports(config.strict_imports.unwrap_or(false))
+                    .with_external_modules(config.external_modules.clone().unwrap_or_default())
+                    .with_transformation_methods(
+                        config.transformation_methods.clone().unwrap_or_default(),
+                    )
+                    .with_type_compatibility(config.type_compatibility())
+                    .with_app_objects(adapter_config.app_objects.clone().unwrap_or_default());
                 // Convert and set dynamic routes config
                 let adapter_dynamic_routes = config
                     .dynamic_routes
@@ -92,11 +101,12 @@ pub fn execute_visualize(config_path: &str) -> Result<()> {
     let adapter_count = config.adapters.len();
     for (adapter_name, graph) in all_graphs {
         pb.set_message(format!("Generating DOT for {}...", adapter_name));
-        let dot_content = generate_dot(&graph, &adapter_name)?;
+        let dot_content = to_dot(&graph);
 
         // Determine output path
-        let output_path = if config.output.path.ends_with(".dot") {
-            let base_path = PathBuf::from(&config.output.path);
+        let output_root = output.unwrap_or(&config.output.path);
+        let output_path = if output_root.ends_with(".dot") {
+            let base_path = PathBuf::from(output_root);
             if adapter_count > 1 {
                 let stem = base_path
                     .file_stem()
@@ -113,7 +123,7 @@ pub fn execute_visualize(config_path: &str) -> Result<()> {
                 base_path
             }
         } else {
-            PathBuf::from(&config.output.path).join(format!("{}.dot", adapter_name))
+            PathBuf::from(output_root).join(format!("{}.dot", adapter_name))
         };
 
         // Create directory if needed
@@ -133,139 +143,6 @@ pub fn execute_visualize(config_path: &str) -> Result<()> {
     Ok(())
 }
 
-/// Generates DOT format from graph
-fn generate_dot(graph: &CallGraph, graph_name: &str) -> Result<String> {
-    let mut dot = String::new();
-
-    // DOT header
-    dot.push_str(&format!("digraph {} {{\n", graph_name.replace("-", "_")));
-    dot.push_str("  rankdir=LR;\n");
-    dot.push_str("  node [shape=box];\n\n");
-
-    // Create mapping of node indices to string identifiers
-    let mut node_map = std::collections::HashMap::new();
-    let mut node_counter = 0;
-
-    // Add nodes
-    for node_idx in graph.node_indices() {
-        if let Some(node) = graph.node_weight(node_idx) {
-            let node_id = format!("node_{}", node_counter);
-            node_map.insert(node_idx, node_id.clone());
-            node_counter += 1;
-
-            let label = format_node_label(node);
-            // Escape special characters for DOT
-            let escaped_label = escape_dot_string(&label);
-            dot.push_str(&format!("  {} [label=\"{}\"];\n", node_id, escaped_label));
-        }
-    }
-
-    dot.push('\n');
-
-    // Add edges
-    for edge_idx in graph.edge_indices() {
-        if let Some((source, target)) = graph.edge_endpoints(edge_idx) {
-            if let (Some(source_id), Some(target_id), Some(edge)) = (
-                node_map.get(&source),
-                node_map.get(&target),
-                graph.edge_weight(edge_idx),
-            ) {
-                let edge_label = format_edge_label(edge);
-                let escaped_label = escape_dot_string(&edge_label);
-
-                // Apply style attributes for DataFlow edges
-                if matches!(edge, CallEdge::DataFlow { .. }) {
-                    dot.push_str(&format!(
-                        "  {} -> {} [label=\"{}\", style=dashed, color=purple];\n",
-                        source_id, target_id, escaped_label
-                    ));
-                } else {
-                    dot.push_str(&format!(
-                        "  {} -> {} [label=\"{}\"];\n",
-                        source_id, target_id, escaped_label
-                    ));
-                }
-            }
-        }
-    }
-
-    dot.push_str("}\n");
-    Ok(dot)
-}
-
-/// Formats node label for DOT
-fn format_node_label(node: &CallNode) -> String {
-    match node {
-        CallNode::Module { path } => {
-            format!(
-                "Module: {}",
-                path.file_name().unwrap_or_default().to_string_lossy()
-            )
-        }
-        CallNode::Function { name, line, .. } => {
-            format!("Function: {}\n(line {})", name, line)
-        }
-        CallNode::Class { name, .. } => {
-            format!("Class: {}", name)
-        }
-        CallNode::Method { name, .. } => {
-            format!("Method: {}", name)
-        }
-        CallNode::Route { path, method, .. } => {
-            let method_str = match method {
-                dc_core::call_graph::HttpMethod::Get => "GET",
-                dc_core::call_graph::HttpMethod::Post => "POST",
-                dc_core::call_graph::HttpMethod::Put => "PUT",
-                dc_core::call_graph::HttpMethod::Patch => "PATCH",
-                dc_core::call_graph::HttpMethod::Delete => "DELETE",
-                dc_core::call_graph::HttpMethod::Options => "OPTIONS",
-                dc_core::call_graph::HttpMethod::Head => "HEAD",
-            };
-            format!("Route: {} {}", method_str, path)
-        }
-        CallNode::Schema { schema } => {
-            format!("Schema: {} ({:?})", schema.name, schema.schema_type)
-        }
-    }
-}
-
-/// Formats edge label for DOT
-fn format_edge_label(edge: &CallEdge) -> String {
-    match edge {
-        CallEdge::DataFlow {
-            from_schema,
-            to_schema,
-            ..
-        } => {
-            format!("{} → {} [{}]", from_schema.name, to_schema.name, "DataFlow")
-        }
-        CallEdge::Import { import_path, .. } => {
-            format!("import: {}", import_path)
-        }
-        CallEdge::Call {
-            argument_mapping, ..
-        } => {
-            if argument_mapping.is_empty() {
-                "calls".to_string()
-            } else {
-                format!("calls ({} args)", argument_mapping.len())
-            }
-        }
-        CallEdge::Return { return_value, .. } => {
-            format!("returns: {}", return_value)
-        }
-    }
-}
-
-/// Escapes special characters for DOT
-fn escape_dot_string(s: &str) -> String {
-    s.replace("\\", "\\\\")
-        .replace("\"", "\\\"")
-        .replace("\n", "\\n")
-        .replace("\r", "\\r")
-        .replace("\t", "\\t")
-}
-
 /// Converts CLI config types to adapter config types
 fn convert_dynamic_routes_config(config: &DynamicRoutesConfig) -> AdapterDynamicRoutesConfig {
     AdapterDynamicRoutesConfig {
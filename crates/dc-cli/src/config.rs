@@ -1,7 +1,16 @@
+use crate::{PathStyle, ReportFormat};
 use anyhow::{Context, Result};
+use dc_core::models::TypeCompatibility;
+use dc_core::openapi::{FetchOptions, OpenAPIParser};
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::info;
+
+/// Config file names looked up during discovery, in priority order within a directory
+pub const CONFIG_FILE_NAMES: &[&str] = &["dc-verifier.toml", "dcverifier.toml", ".dcverifier.toml"];
 
 /// Project configuration
 #[derive(Debug, Deserialize)]
@@ -14,12 +23,27 @@ pub struct Config {
     pub output: OutputConfig,
     /// Maximum recursion depth for graph building (None = unlimited)
     pub max_recursion_depth: Option<usize>,
-    /// Global OpenAPI schema path (optional, can be overridden per adapter)
+    /// Global OpenAPI schema path or `http(s)://` URL (optional, can be overridden per adapter)
     pub openapi_path: Option<String>,
+    /// Accept invalid TLS certificates when fetching the OpenAPI spec from a URL
+    pub openapi_insecure: Option<bool>,
+    /// Timeout in seconds for fetching the OpenAPI spec from a URL
+    pub openapi_timeout_secs: Option<u64>,
     /// Configuration for dynamic route generators
     pub dynamic_routes: Option<DynamicRoutesConfig>,
     /// Strict import resolution: fail on unresolved imports (if true)
     pub strict_imports: Option<bool>,
+    /// Module prefixes known to be external (`fastapi`, `sqlalchemy`, company libraries):
+    /// uninstalled imports of them are skipped without an analysis warning
+    pub external_modules: Option<Vec<String>>,
+    /// Report frontend calls that only reach their route through a trailing-slash redirect
+    /// (by default `/users` and `/users/` are equivalent)
+    pub strict_trailing_slash: Option<bool>,
+    /// Additional method names treated as data transformations (e.g. `to_response`)
+    pub transformation_methods: Option<Vec<String>>,
+    /// Project-specific type equivalences added to the default compatibility matrix
+    /// (`[type_compatibility]` table, e.g. `Money = ["string", "str"]`)
+    pub type_compatibility: Option<BTreeMap<String, Vec<String>>>,
 }
 
 /// Adapter configuration
@@ -28,8 +52,10 @@ pub struct AdapterConfig {
     #[serde(rename = "type")]
     pub adapter_type: String,
     pub app_path: Option<String>,
+    /// FastAPI app/router variables whose decorators declare routes (default: common names)
+    pub app_objects: Option<Vec<String>>,
     pub src_paths: Option<Vec<String>>,
-    /// OpenAPI schema path (optional, overrides global openapi_path if set)
+    /// OpenAPI schema path or URL (optional, overrides global openapi_path if set)
     pub openapi_path: Option<String>,
 }
 
@@ -48,6 +74,11 @@ pub struct RulesConfig {
 pub struct OutputConfig {
     pub format: String,
     pub path: String,
+    /// How file paths are rendered in reports (`relative` or `absolute`); unset keeps the
+    /// paths as they were parsed
+    pub paths: Option<PathStyle>,
+    /// Root relative paths are rendered against (default: the config file's directory)
+    pub paths_root: Option<String>,
 }
 
 /// Configuration for dynamic route generators
@@ -87,6 +118,66 @@ pub struct EndpointConfig {
 }
 
 impl Config {
+    /// Resolves which config file to use
+    ///
+    /// An explicit `--config` path always wins. Otherwise the nearest config file
+    /// (see [`CONFIG_FILE_NAMES`]) is discovered walking up from the current directory.
+    pub fn locate(explicit: Option<&str>) -> Result<PathBuf> {
+        if let Some(path) = explicit {
+            return Ok(PathBuf::from(path));
+        }
+
+        let cwd = std::env::current_dir().context("Failed to determine current directory")?;
+        Self::discover(&cwd).ok_or_else(|| {
+            anyhow::anyhow!(
+                "No configuration file found in {} or its parent directories (looked for {}). \
+                 Pass --config <path> or run `dc-verifier init`",
+                cwd.display(),
+                CONFIG_FILE_NAMES.join(", ")
+            )
+        })
+    }
+
+    /// Finds the nearest config file in `start` or any of its ancestors
+    pub fn discover(start: &Path) -> Option<PathBuf> {
+        start.ancestors().find_map(|dir| {
+            CONFIG_FILE_NAMES
+                .iter()
+                .map(|name| dir.join(name))
+                .find(|candidate| candidate.is_file())
+        })
+    }
+
+    /// Default type compatibility matrix merged with the `[type_compatibility]` table
+    pub fn type_compatibility(&self) -> TypeCompatibility {
+        TypeCompatibility::new()
+            .with_equivalences(self.type_compatibility.clone().unwrap_or_default())
+    }
+
+    /// Options for fetching an OpenAPI spec given as a URL
+    ///
+    /// `insecure` comes from the command line and is combined with `openapi_insecure`.
+    pub fn fetch_options(&self, insecure: bool) -> FetchOptions {
+        let defaults = FetchOptions::default();
+        FetchOptions {
+            timeout: self
+                .openapi_timeout_secs
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.timeout),
+            insecure: insecure || self.openapi_insecure.unwrap_or(false),
+        }
+    }
+
+    /// Report format from `[output].format`, used when `--format` is not given
+    pub fn report_format(&self) -> ReportFormat {
+        match self.output.format.as_str() {
+            "json" => ReportFormat::Json,
+            "junit" => ReportFormat::Junit,
+            "sarif" => ReportFormat::Sarif,
+            _ => ReportFormat::Markdown,
+        }
+    }
+
     /// Loads configuration from a file
     ///
     /// # Arguments
@@ -108,6 +199,7 @@ impl Config {
         config.resolve_relative_paths(base)?;
 
         config.validate()?;
+        info!(path = %path, "Loaded configuration file");
         Ok(config)
     }
 
@@ -167,10 +259,18 @@ impl Config {
                         }
                     }
                 }
-                "nestjs" => {
-                    // For NestJS, src_paths is required
+                "nestjs" | "express" => {
+                    // For NestJS and Express, src_paths is required
                     let src_paths = adapter.src_paths.as_ref().ok_or_else(|| {
-                        anyhow::anyhow!("Adapter {}: NestJS adapter requires src_paths", idx)
+                        anyhow::anyhow!(
+                            "Adapter {}: {} adapter requires src_paths",
+                            idx,
+                            if adapter.adapter_type == "nestjs" {
+                                "NestJS"
+                            } else {
+                                "Express"
+                            }
+                        )
                     })?;
                     if src_paths.is_empty() {
                         anyhow::bail!("Adapter {}: src_paths cannot be empty", idx);
@@ -189,7 +289,7 @@ impl Config {
                 }
                 _ => {
                     anyhow::bail!(
-                        "Adapter {}: Unknown adapter type: {}. Supported types: fastapi, typescript, nestjs",
+                        "Adapter {}: Unknown adapter type: {}. Supported types: fastapi, typescript, nestjs, express",
                         idx,
                         adapter.adapter_type
                     );
@@ -199,10 +299,10 @@ impl Config {
 
         // Validate output format
         match self.output.format.as_str() {
-            "markdown" | "json" => {}
+            "markdown" | "json" | "junit" | "sarif" => {}
             _ => {
                 anyhow::bail!(
-                    "Invalid output format: {}. Supported formats: markdown, json",
+                    "Invalid output format: {}. Supported formats: markdown, json, junit, sarif",
                     self.output.format
                 );
             }
@@ -228,6 +328,45 @@ impl Config {
             }
         }
 
+        if self.openapi_timeout_secs == Some(0) {
+            anyhow::bail!("openapi_timeout_secs must be greater than 0");
+        }
+
+        // Validate type compatibility table
+        for (type_name, equivalents) in self.type_compatibility.iter().flatten() {
+            Self::validate_type_name(type_name, "type_compatibility key")?;
+            if equivalents.is_empty() {
+                anyhow::bail!(
+                    "type_compatibility.{}: list of equivalent types cannot be empty",
+                    type_name
+                );
+            }
+            for equivalent in equivalents {
+                Self::validate_type_name(equivalent, &format!("type_compatibility.{}", type_name))?;
+                if equivalent.eq_ignore_ascii_case(type_name) {
+                    anyhow::bail!(
+                        "type_compatibility.{}: a type cannot be declared equivalent to itself",
+                        type_name
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// A type name must be a single non-empty token (`Money`, `str`, `Decimal`)
+    fn validate_type_name(type_name: &str, context: &str) -> Result<()> {
+        if type_name.trim().is_empty() {
+            anyhow::bail!("{}: type name cannot be empty", context);
+        }
+        if type_name.chars().any(char::is_whitespace) {
+            anyhow::bail!(
+                "{}: invalid type name '{}' (whitespace is not allowed)",
+                context,
+                type_name
+            );
+        }
         Ok(())
     }
 
@@ -250,7 +389,7 @@ impl Config {
 
         // Resolve global openapi_path if present
         if let Some(ref openapi_path) = self.openapi_path {
-            if !Path::new(openapi_path).is_absolute() {
+            if !Path::new(openapi_path).is_absolute() && !OpenAPIParser::is_url(openapi_path) {
                 let joined = base.join(openapi_path);
                 let resolved = if joined.exists() {
                     joined.canonicalize().with_context(|| {
@@ -280,7 +419,7 @@ impl Config {
                 }
             }
 
-            // Resolve src_paths for TypeScript/NestJS
+            // Resolve src_paths for TypeScript/NestJS/Express
             if let Some(ref src_paths) = adapter.src_paths {
                 let mut resolved_paths = Vec::new();
                 for src_path in src_paths {
@@ -303,7 +442,7 @@ impl Config {
 
             // Resolve adapter-specific openapi_path
             if let Some(ref openapi_path) = adapter.openapi_path {
-                if !Path::new(openapi_path).is_absolute() {
+                if !Path::new(openapi_path).is_absolute() && !OpenAPIParser::is_url(openapi_path) {
                     let joined = base.join(openapi_path);
                     let resolved = if joined.exists() {
                         joined.canonicalize().with_context(|| {
@@ -317,6 +456,13 @@ impl Config {
             }
         }
 
+        // Resolve the root of relative report paths
+        if let Some(ref paths_root) = self.output.paths_root {
+            if !Path::new(paths_root).is_absolute() {
+                self.output.paths_root = Some(base.join(paths_root).to_string_lossy().to_string());
+            }
+        }
+
         // Resolve output path
         if !Path::new(&self.output.path).is_absolute() {
             let resolved = base.join(&self.output.path);
@@ -327,7 +473,12 @@ impl Config {
     }
 
     /// Validates that a path exists and is a readable file
+    ///
+    /// URLs are only checked when fetched.
     fn validate_openapi_path(path_str: &str, context: &str) -> Result<()> {
+        if OpenAPIParser::is_url(path_str) {
+            return Ok(());
+        }
         let path = Path::new(path_str);
         if !path.exists() {
             anyhow::bail!("{} does not exist: {}", context, path_str);
@@ -0,0 +1,55 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Files changed since `git_ref` in the repository containing `dir`
+///
+/// Covers commits after the ref, staged and unstaged changes, and untracked files that
+/// aren't ignored. Deleted files are left out. Paths are absolute and sorted.
+pub fn changed_files_since(dir: &Path, git_ref: &str) -> Result<Vec<PathBuf>> {
+    let root = PathBuf::from(git(dir, &["rev-parse", "--show-toplevel"])?.trim());
+
+    let commit = format!("{}^{{commit}}", git_ref);
+    if git(dir, &["rev-parse", "--verify", "--quiet", &commit]).is_err() {
+        anyhow::bail!(
+            "Git ref '{}' does not exist in the repository at {}",
+            git_ref,
+            root.display()
+        );
+    }
+
+    let changed = git(
+        &root,
+        &["diff", "--name-only", "--diff-filter=d", git_ref, "--"],
+    )?;
+    let untracked = git(&root, &["ls-files", "--others", "--exclude-standard"])?;
+
+    let mut files: Vec<PathBuf> = changed
+        .lines()
+        .chain(untracked.lines())
+        .filter(|line| !line.is_empty())
+        .map(|line| root.join(line))
+        .collect();
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+/// Runs git in `dir` and returns its stdout
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .context("Failed to run git; is it installed?")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed in {}: {}",
+            args.join(" "),
+            dir.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
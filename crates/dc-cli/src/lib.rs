@@ -1,9 +1,50 @@
 pub mod commands;
 pub mod config;
+pub mod git;
 pub mod reporters;
 
 #[derive(clap::ValueEnum, Clone, Copy, Debug)]
 pub enum ReportFormat {
     Markdown,
     Json,
+    /// JUnit XML, for CI test reporters
+    Junit,
+    /// SARIF 2.1.0, for code scanning tools
+    Sarif,
+}
+
+/// Output format of the listing commands (`schemas`, `orm-links`)
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum ManifestFormat {
+    Json,
+    Markdown,
+}
+
+/// Lowest level of findings that fails `check` (exit code 1)
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FailOn {
+    /// Error-level findings: critical contracts and diagnostics, files that failed to parse
+    #[default]
+    Error,
+    /// Warning-level findings too, including unmatched and ambiguous frontend calls
+    Warning,
+    /// Never fail on findings
+    Never,
+}
+
+/// How file paths are rendered in reports
+#[derive(clap::ValueEnum, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PathStyle {
+    /// Relative to the project root (or `[output].paths_root`)
+    Relative,
+    /// Absolute, canonicalized
+    Absolute,
+}
+
+/// Output format of the schema dependency graph
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum GraphFormat {
+    Dot,
+    Json,
 }
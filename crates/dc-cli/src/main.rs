@@ -1,9 +1,14 @@
 use anyhow::Result;
 use clap::Parser;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use dc_cli::commands;
-use dc_cli::ReportFormat;
+use dc_cli::commands::check::{
+    AnalysisTimedOut, VerificationFailed, TIMED_OUT_EXIT_CODE, VERIFICATION_FAILED_EXIT_CODE,
+};
+use dc_cli::config::Config;
+use dc_cli::{FailOn, GraphFormat, ManifestFormat, PathStyle, ReportFormat};
 use dc_core::logging::init_from_args;
 
 #[derive(Parser)]
@@ -16,6 +21,9 @@ struct Cli {
     /// Path to log file
     #[arg(long, global = true)]
     log_file: Option<PathBuf>,
+    /// Only print errors to the console (ignores RUST_LOG; --log-file still gets full logs)
+    #[arg(short, long, global = true)]
+    quiet: bool,
     #[command(subcommand)]
     command: Commands,
 }
@@ -24,15 +32,79 @@ struct Cli {
 enum Commands {
     /// Check data chains
     Check {
-        /// Path to configuration file
-        #[arg(short, long, default_value = "dc-verifier.toml")]
-        config: String,
-        /// Report format (markdown or json)
-        #[arg(short, long, value_enum, default_value_t = ReportFormat::Markdown)]
-        format: ReportFormat,
+        /// Path to configuration file (default: nearest dc-verifier.toml, dcverifier.toml
+        /// or .dcverifier.toml in the current directory or its parents)
+        #[arg(short, long)]
+        config: Option<String>,
+        /// Report format (markdown, json, junit or sarif); overrides `[output].format` from the config
+        #[arg(short, long, value_enum)]
+        format: Option<ReportFormat>,
         /// Enable verbose debug output
         #[arg(short, long)]
         verbose: bool,
+        /// Print only aggregate counts and the pass/fail verdict
+        #[arg(long)]
+        summary_only: bool,
+        /// Exit with code 1 when the check finds errors, also warnings, or never
+        #[arg(long, value_enum, default_value = "error")]
+        fail_on: FailOn,
+        /// Abort on the first Python file with a syntax error instead of skipping it
+        #[arg(long)]
+        strict_parse: bool,
+        /// Report frontend types that can't be resolved to a schema instead of skipping them
+        #[arg(long)]
+        strict_types: bool,
+        /// FastAPI app/router variable declaring routes (repeatable); overrides `app_objects`
+        #[arg(long = "app-object", value_name = "NAME")]
+        app_objects: Vec<String>,
+        /// Rewrite sources with safe fixes (adds missing `response_model=`) before checking
+        #[arg(long)]
+        fix: bool,
+        /// Accept invalid TLS certificates when fetching the OpenAPI spec from a URL
+        #[arg(long)]
+        insecure: bool,
+        /// Report only issues in files changed since this git ref (e.g. origin/main); the
+        /// whole project is still analyzed, so this filters the report without speeding up
+        /// the check
+        #[arg(long, value_name = "GIT_REF")]
+        since: Option<String>,
+        /// Render report file paths relative to the project root or absolute; overrides
+        /// `[output].paths`
+        #[arg(long, value_enum)]
+        paths: Option<PathStyle>,
+        /// Root relative report paths are rendered against (default: the config file's
+        /// directory); overrides `[output].paths_root`
+        #[arg(long, value_name = "DIR")]
+        paths_root: Option<String>,
+        /// Also analyze files ignored by `.gitignore`
+        #[arg(long)]
+        no_gitignore: bool,
+        /// Print the resolved config and the files each adapter would analyze, then exit
+        #[arg(long)]
+        dry_run: bool,
+        /// Stop analyzing after this many seconds, write the partial report and exit with
+        /// code 124
+        #[arg(long, value_name = "SECONDS")]
+        timeout: Option<u64>,
+        /// Write each adapter's call graph as JSON to this directory (`fastapi_0.json`, ...)
+        #[arg(long, value_name = "DIR")]
+        emit_graph: Option<String>,
+        /// Read each adapter's call graph from a directory written by `--emit-graph` instead
+        /// of parsing the sources
+        #[arg(long, value_name = "DIR", conflicts_with = "fix")]
+        graph: Option<String>,
+    },
+    /// Explain what a rule checks and how to fix its diagnostics
+    Explain {
+        /// Rule identifier as shown in reports (e.g. type-mismatch, parse-error)
+        #[arg(required_unless_present = "list_rules")]
+        rule_id: Option<String>,
+        /// List every rule with its default severity and summary instead
+        #[arg(long, conflicts_with = "rule_id")]
+        list_rules: bool,
+        /// Print the rule list as JSON
+        #[arg(long, requires = "list_rules")]
+        json: bool,
     },
     /// Create configuration file
     Init {
@@ -40,11 +112,50 @@ enum Commands {
         #[arg(default_value = "dc-verifier.toml")]
         path: String,
     },
+    /// List all detected schemas with their locations, fields and ORM links
+    Schemas {
+        /// Path to configuration file (discovered like in `check` when omitted)
+        #[arg(short, long)]
+        config: Option<String>,
+        /// Manifest format
+        #[arg(short, long, value_enum, default_value = "json")]
+        format: ManifestFormat,
+        /// Write the manifest to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// List Pydantic ↔ SQLAlchemy model links with the match strategy and score
+    OrmLinks {
+        /// Path to configuration file (discovered like in `check` when omitted)
+        #[arg(short, long)]
+        config: Option<String>,
+        /// Table format
+        #[arg(short, long, value_enum, default_value = "markdown")]
+        format: ManifestFormat,
+        /// Write the table to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Show which schema references which through its field types
+    SchemaGraph {
+        /// Path to configuration file (discovered like in `check` when omitted)
+        #[arg(short, long)]
+        config: Option<String>,
+        /// Graph format
+        #[arg(short, long, value_enum, default_value = "dot")]
+        format: GraphFormat,
+        /// Write the graph to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+    },
     /// Visualize data chain graphs
     Visualize {
-        /// Path to configuration file
-        #[arg(short, long, default_value = "dc-verifier.toml")]
-        config: String,
+        /// Path to configuration file (discovered like in `check` when omitted)
+        #[arg(short, long)]
+        config: Option<String>,
+        /// Write the DOT files to this directory, or to this `.dot` file
+        #[arg(short, long)]
+        output: Option<String>,
     },
 }
 
@@ -58,21 +169,120 @@ fn main() -> Result<()> {
     };
 
     // Initialize logging before everything else
-    init_from_args(cli.log_level, cli.log_file, verbose)?;
+    init_from_args(cli.log_level, cli.log_file, verbose, cli.quiet)?;
 
     match cli.command {
         Commands::Check {
             config,
             format,
             verbose,
+            summary_only,
+            fail_on,
+            strict_parse,
+            strict_types,
+            app_objects,
+            fix,
+            insecure,
+            since,
+            paths,
+            paths_root,
+            no_gitignore,
+            dry_run,
+            timeout,
+            emit_graph,
+            graph,
         } => {
-            commands::check::execute_check(&config, format, verbose)?;
+            let options = commands::check::CheckOptions {
+                verbose,
+                summary_only,
+                fail_on,
+                strict_parse,
+                strict_types,
+                app_objects,
+                fix,
+                insecure,
+                since,
+                paths,
+                paths_root,
+                no_gitignore,
+                dry_run,
+                timeout: timeout.map(Duration::from_secs),
+                emit_graph,
+                graph,
+            };
+            let config_path = Config::locate(config.as_deref())?;
+            if let Err(err) = commands::check::execute_check_with_options(
+                &config_path.to_string_lossy(),
+                format,
+                &options,
+            ) {
+                if err.downcast_ref::<AnalysisTimedOut>().is_some() {
+                    eprintln!("Warning: {}", err);
+                    std::process::exit(TIMED_OUT_EXIT_CODE);
+                }
+                if err.downcast_ref::<VerificationFailed>().is_some() {
+                    // The summary already printed the FAIL verdict
+                    if !summary_only {
+                        eprintln!("{}", err);
+                    }
+                    std::process::exit(VERIFICATION_FAILED_EXIT_CODE);
+                }
+                return Err(err);
+            }
         }
+        Commands::Explain {
+            rule_id,
+            list_rules,
+            json,
+        } => match rule_id {
+            Some(rule_id) if !list_rules => commands::explain::execute_explain(&rule_id)?,
+            _ => commands::explain::execute_list_rules(json)?,
+        },
         Commands::Init { path } => {
             commands::init::execute_init(&path)?;
         }
-        Commands::Visualize { config } => {
-            commands::visualize::execute_visualize(&config)?;
+        Commands::Schemas {
+            config,
+            format,
+            output,
+        } => {
+            let config_path = Config::locate(config.as_deref())?;
+            commands::schemas::execute_schemas(
+                &config_path.to_string_lossy(),
+                format,
+                output.as_deref(),
+            )?;
+        }
+        Commands::OrmLinks {
+            config,
+            format,
+            output,
+        } => {
+            let config_path = Config::locate(config.as_deref())?;
+            commands::orm_links::execute_orm_links(
+                &config_path.to_string_lossy(),
+                format,
+                output.as_deref(),
+            )?;
+        }
+        Commands::SchemaGraph {
+            config,
+            format,
+            output,
+        } => {
+            let config_path = Config::locate(config.as_deref())?;
+            commands::schema_graph::execute_schema_graph(
+                &config_path.to_string_lossy(),
+                format,
+                output.as_deref(),
+            )?;
+        }
+        Commands::Visualize { config, output } => {
+            let config_path = Config::locate(config.as_deref())?;
+            commands::visualize::execute_visualize(
+                &config_path.to_string_lossy(),
+                output.as_deref(),
+            )?;
         }
     }
 
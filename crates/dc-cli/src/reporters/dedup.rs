@@ -0,0 +1,51 @@
+use dc_core::models::{Contract, DataChain};
+use std::collections::HashMap;
+
+/// Owned form of `Mismatch::dedup_key`
+type DiagnosticKey = (&'static str, String, usize, Option<usize>, String, String);
+
+/// Collapses identical diagnostics reported by several chains or contracts
+///
+/// Diagnostics are identical when rule id, file, line, column, field path and message
+/// all match. The first occurrence is kept and its `occurrences` counter absorbs the
+/// duplicates, which are removed from later contracts, whose severity is then recomputed
+/// from the mismatches they keep. Diagnostics at the same location but with different
+/// field paths stay separate.
+pub fn deduplicate_diagnostics(chains: &mut [DataChain]) {
+    let mut first_seen: HashMap<DiagnosticKey, (usize, usize, usize)> = HashMap::new();
+    let mut duplicates: Vec<((usize, usize, usize), usize)> = Vec::new();
+
+    for (chain_idx, chain) in chains.iter_mut().enumerate() {
+        for (contract_idx, contract) in chain.contracts.iter_mut().enumerate() {
+            let mut kept = Vec::with_capacity(contract.mismatches.len());
+            let mut removed = false;
+            for mismatch in contract.mismatches.drain(..) {
+                let (rule_id, file, line, column, path, message) = mismatch.dedup_key();
+                let key = (
+                    rule_id,
+                    file.to_string(),
+                    line,
+                    column,
+                    path.to_string(),
+                    message.to_string(),
+                );
+                if let Some(position) = first_seen.get(&key) {
+                    duplicates.push((*position, mismatch.occurrences));
+                    removed = true;
+                } else {
+                    first_seen.insert(key, (chain_idx, contract_idx, kept.len()));
+                    kept.push(mismatch);
+                }
+            }
+            if removed {
+                contract.severity = Contract::severity_of(&kept);
+            }
+            contract.mismatches = kept;
+        }
+    }
+
+    for ((chain_idx, contract_idx, mismatch_idx), occurrences) in duplicates {
+        chains[chain_idx].contracts[contract_idx].mismatches[mismatch_idx].occurrences +=
+            occurrences;
+    }
+}
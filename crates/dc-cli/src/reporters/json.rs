@@ -1,6 +1,10 @@
 use anyhow::Result;
-use dc_core::models::{ChainType, DataChain, SchemaReference, SchemaType, Severity};
-use std::collections::HashMap;
+use dc_core::analyzers::EndpointIssue;
+use dc_core::models::{
+    rule_docs_url, AnalysisWarning, ChainType, DataChain, Diagnostic, SchemaReference, SchemaType,
+    Severity,
+};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::Path;
 
@@ -10,20 +14,66 @@ pub struct JsonReporter;
 impl JsonReporter {
     /// Generates a JSON report
     pub fn generate(&self, chains: &[DataChain], output_path: &str) -> Result<()> {
+        self.generate_report(chains, &[], &[], &[], output_path)
+    }
+
+    /// Generates a JSON report including frontend calls that don't match exactly one route
+    /// (`endpoint_issues`), analysis diagnostics (`diagnostics`) and what the analysis
+    /// couldn't follow (`analysis_warnings`), each present only when there are any
+    pub fn generate_report(
+        &self,
+        chains: &[DataChain],
+        endpoint_issues: &[EndpointIssue],
+        diagnostics: &[Diagnostic],
+        analysis_warnings: &[AnalysisWarning],
+        output_path: &str,
+    ) -> Result<()> {
         let summary = Self::build_summary(chains);
 
-        let report = serde_json::json!({
+        let mut report = serde_json::json!({
             "version": "1.0.0",
             "timestamp": chrono::Utc::now().to_rfc3339(),
             "summary": summary,
             "chains": chains,
         });
+        if !endpoint_issues.is_empty() {
+            report["summary"]["endpoint_issues"] = endpoint_issues.len().into();
+            report["endpoint_issues"] = serde_json::to_value(endpoint_issues)?;
+        }
+        if !diagnostics.is_empty() {
+            report["summary"]["diagnostics"] = diagnostics.len().into();
+            report["diagnostics"] = serde_json::to_value(diagnostics)?;
+        }
+        if !analysis_warnings.is_empty() {
+            report["summary"]["analysis_warnings"] = analysis_warnings.len().into();
+            report["analysis_warnings"] = serde_json::to_value(analysis_warnings)?;
+        }
+        let rule_docs = Self::rule_docs(chains, diagnostics);
+        if !rule_docs.is_empty() {
+            report["rule_docs"] = serde_json::to_value(rule_docs)?;
+        }
 
         let json_string = serde_json::to_string_pretty(&report)?;
         fs::write(Path::new(output_path), json_string)?;
         Ok(())
     }
 
+    /// Documentation links of the rules reported in `chains` and `diagnostics`
+    fn rule_docs(chains: &[DataChain], diagnostics: &[Diagnostic]) -> BTreeMap<String, String> {
+        chains
+            .iter()
+            .flat_map(|chain| &chain.contracts)
+            .flat_map(|contract| &contract.mismatches)
+            .map(|mismatch| mismatch.rule_id())
+            .chain(
+                diagnostics
+                    .iter()
+                    .map(|diagnostic| diagnostic.rule_id.as_str()),
+            )
+            .filter_map(|rule_id| Some((rule_id.to_string(), rule_docs_url(rule_id)?)))
+            .collect()
+    }
+
     fn build_summary(chains: &[DataChain]) -> serde_json::Value {
         let total_chains = chains.len();
 
@@ -61,6 +111,7 @@ impl JsonReporter {
                 SchemaType::OpenAPI => "openapi",
                 SchemaType::JsonSchema => "json_schema",
                 SchemaType::OrmModel => "orm_model",
+                SchemaType::Enum => "enum",
             };
             *schemas_by_type.entry(key.to_string()).or_insert(0) += 1;
         }
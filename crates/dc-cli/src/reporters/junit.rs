@@ -0,0 +1,291 @@
+use anyhow::Result;
+use dc_core::analyzers::{EndpointIssue, EndpointIssueKind};
+use dc_core::models::{
+    AnalysisWarning, DataChain, Diagnostic, Location, Severity, PARSE_ERROR_RULE,
+};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// JUnit XML report generator, for CI systems rendering test results
+///
+/// Every data chain, frontend call issue and diagnostic becomes a `<testcase>`, grouped
+/// into one `<testsuite>` per source file. Contract mismatches of critical or warning
+/// contracts, endpoint issues and warning/critical diagnostics are `<failure>`s; files that
+/// couldn't be parsed are `<error>`s. Code the analysis couldn't follow (analysis warnings)
+/// is reported as `<skipped>` test cases: contracts through it were not checked.
+pub struct JunitReporter;
+
+/// Outcome of one test case
+enum Outcome {
+    Failure,
+    Error,
+    Skipped,
+}
+
+/// One problem reported in a test case
+struct Problem {
+    outcome: Outcome,
+    /// Rule id (the `type` attribute)
+    rule_id: String,
+    message: String,
+    location: Location,
+}
+
+struct TestCase {
+    name: String,
+    classname: String,
+    problems: Vec<Problem>,
+}
+
+impl TestCase {
+    /// Error when any problem is an error, failure when any is a failure, skipped when
+    /// all are skipped, None when passed
+    fn status(&self) -> Option<&Outcome> {
+        let mut outcomes = self.problems.iter().map(|problem| &problem.outcome);
+        outcomes
+            .clone()
+            .find(|outcome| matches!(outcome, Outcome::Error))
+            .or_else(|| {
+                outcomes
+                    .clone()
+                    .find(|outcome| matches!(outcome, Outcome::Failure))
+            })
+            .or_else(|| outcomes.next())
+    }
+}
+
+impl JunitReporter {
+    /// Generates a JUnit XML report
+    pub fn generate_report(
+        &self,
+        chains: &[DataChain],
+        endpoint_issues: &[EndpointIssue],
+        diagnostics: &[Diagnostic],
+        analysis_warnings: &[AnalysisWarning],
+        output_path: &str,
+    ) -> Result<()> {
+        let xml = self.render_report(chains, endpoint_issues, diagnostics, analysis_warnings);
+        fs::write(Path::new(output_path), xml)?;
+        Ok(())
+    }
+
+    /// Renders the JUnit XML document
+    pub fn render_report(
+        &self,
+        chains: &[DataChain],
+        endpoint_issues: &[EndpointIssue],
+        diagnostics: &[Diagnostic],
+        analysis_warnings: &[AnalysisWarning],
+    ) -> String {
+        let mut suites: BTreeMap<String, Vec<TestCase>> = BTreeMap::new();
+        for chain in chains {
+            let file = chain
+                .links
+                .first()
+                .map(|link| link.location.file.clone())
+                .unwrap_or_default();
+            suites
+                .entry(file)
+                .or_default()
+                .push(Self::chain_case(chain));
+        }
+        for issue in endpoint_issues {
+            suites
+                .entry(issue.call.location.file.clone())
+                .or_default()
+                .push(Self::endpoint_issue_case(issue));
+        }
+        for diagnostic in diagnostics {
+            suites
+                .entry(diagnostic.location.file.clone())
+                .or_default()
+                .push(Self::diagnostic_case(diagnostic));
+        }
+        for warning in analysis_warnings {
+            suites
+                .entry(warning.location.file.clone())
+                .or_default()
+                .push(Self::analysis_warning_case(warning));
+        }
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuites name=\"dc-verifier\" {}>\n",
+            Self::counts(suites.values().flatten())
+        ));
+        for (file, cases) in &suites {
+            xml.push_str(&format!(
+                "  <testsuite name=\"{}\" {}>\n",
+                escape(file),
+                Self::counts(cases)
+            ));
+            for case in cases {
+                Self::write_case(&mut xml, case);
+            }
+            xml.push_str("  </testsuite>\n");
+        }
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+
+    /// `tests`, `failures`, `errors` and `skipped` attributes; a case with an error counts
+    /// as an error only
+    fn counts<'a>(cases: impl IntoIterator<Item = &'a TestCase>) -> String {
+        let (mut tests, mut failures, mut errors, mut skipped) = (0, 0, 0, 0);
+        for case in cases {
+            tests += 1;
+            match case.status() {
+                Some(Outcome::Failure) => failures += 1,
+                Some(Outcome::Error) => errors += 1,
+                Some(Outcome::Skipped) => skipped += 1,
+                None => {}
+            }
+        }
+        format!(
+            "tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\"",
+            tests, failures, errors, skipped
+        )
+    }
+
+    fn write_case(xml: &mut String, case: &TestCase) {
+        let open = format!(
+            "    <testcase name=\"{}\" classname=\"{}\"",
+            escape(&case.name),
+            escape(&case.classname)
+        );
+        xml.push_str(&open);
+        if case.problems.is_empty() {
+            xml.push_str("/>\n");
+            return;
+        }
+        xml.push_str(">\n");
+        for problem in &case.problems {
+            let tag = match problem.outcome {
+                Outcome::Failure => "failure",
+                Outcome::Error => "error",
+                Outcome::Skipped => {
+                    xml.push_str(&format!(
+                        "      <skipped message=\"{}\"/>\n",
+                        escape(&problem.message)
+                    ));
+                    continue;
+                }
+            };
+            xml.push_str(&format!(
+                "      <{} type=\"{}\" message=\"{}\">{}:{}: {}</{}>\n",
+                tag,
+                escape(&problem.rule_id),
+                escape(&problem.message),
+                escape(&problem.location.file),
+                problem.location.line,
+                escape(&problem.message),
+                tag
+            ));
+        }
+        xml.push_str("    </testcase>\n");
+    }
+
+    fn chain_case(chain: &DataChain) -> TestCase {
+        let problems = chain
+            .contracts
+            .iter()
+            .filter(|contract| contract.severity != Severity::Info)
+            .flat_map(|contract| &contract.mismatches)
+            .map(|mismatch| Problem {
+                outcome: Outcome::Failure,
+                rule_id: mismatch.rule_id().to_string(),
+                message: mismatch.message.clone(),
+                location: mismatch.location.clone(),
+            })
+            .collect();
+        TestCase {
+            name: chain.name.clone(),
+            classname: chain.id.clone(),
+            problems,
+        }
+    }
+
+    fn endpoint_issue_case(issue: &EndpointIssue) -> TestCase {
+        let rule_id = match issue.kind {
+            EndpointIssueKind::Unmatched => "unmatched-endpoint",
+            EndpointIssueKind::Ambiguous => "ambiguous-endpoint",
+        };
+        TestCase {
+            name: format!(
+                "{} {}",
+                format!("{:?}", issue.call.method).to_uppercase(),
+                issue.call.path
+            ),
+            classname: "endpoints".to_string(),
+            problems: vec![Problem {
+                outcome: Outcome::Failure,
+                rule_id: rule_id.to_string(),
+                message: issue.message.clone(),
+                location: issue.call.location.clone(),
+            }],
+        }
+    }
+
+    fn diagnostic_case(diagnostic: &Diagnostic) -> TestCase {
+        let outcome = if diagnostic.rule_id == PARSE_ERROR_RULE {
+            Some(Outcome::Error)
+        } else if diagnostic.severity != Severity::Info {
+            Some(Outcome::Failure)
+        } else {
+            None
+        };
+        TestCase {
+            name: format!(
+                "{} {}:{}",
+                diagnostic.rule_id, diagnostic.location.file, diagnostic.location.line
+            ),
+            classname: "diagnostics".to_string(),
+            problems: outcome
+                .map(|outcome| Problem {
+                    outcome,
+                    rule_id: diagnostic.rule_id.clone(),
+                    message: diagnostic.message.clone(),
+                    location: diagnostic.location.clone(),
+                })
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    fn analysis_warning_case(warning: &AnalysisWarning) -> TestCase {
+        TestCase {
+            name: format!(
+                "{} {}:{}",
+                warning.kind.id(),
+                warning.location.file,
+                warning.location.line
+            ),
+            classname: "analysis-warnings".to_string(),
+            problems: vec![Problem {
+                outcome: Outcome::Skipped,
+                rule_id: warning.kind.id().to_string(),
+                message: warning.message.clone(),
+                location: warning.location.clone(),
+            }],
+        }
+    }
+}
+
+/// Escapes text for XML attributes and content, dropping characters XML 1.0 forbids
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if (c as u32) < 0x20 || c == '\u{FFFE}' || c == '\u{FFFF}' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -1,5 +1,10 @@
 use anyhow::Result;
-use dc_core::models::{ChainType, DataChain, LinkType, MismatchType, SchemaType, Severity};
+use dc_core::analyzers::{EndpointIssue, EndpointIssueKind};
+use dc_core::models::{
+    rule_docs_url, AnalysisWarning, ChainType, DataChain, Diagnostic, LinkType, MismatchType,
+    SchemaType, Severity,
+};
+use dc_core::snippet::SnippetResolver;
 use std::fs;
 use std::path::Path;
 
@@ -9,6 +14,41 @@ pub struct MarkdownReporter;
 impl MarkdownReporter {
     /// Generates report in .chain_verification_report.md format
     pub fn generate(&self, chains: &[DataChain], output_path: &str) -> Result<()> {
+        self.generate_report(chains, &[], &[], &[], output_path)
+    }
+
+    /// Generates the report including frontend calls that don't match exactly one route,
+    /// analysis diagnostics (e.g. files skipped because of syntax errors) and what the
+    /// analysis couldn't follow (e.g. unresolved imports)
+    pub fn generate_report(
+        &self,
+        chains: &[DataChain],
+        endpoint_issues: &[EndpointIssue],
+        diagnostics: &[Diagnostic],
+        analysis_warnings: &[AnalysisWarning],
+        output_path: &str,
+    ) -> Result<()> {
+        self.generate_report_with_source_root(
+            chains,
+            endpoint_issues,
+            diagnostics,
+            analysis_warnings,
+            None,
+            output_path,
+        )
+    }
+
+    /// Generates the report for locations rewritten relative to `source_root`, which source
+    /// snippets are read from
+    pub fn generate_report_with_source_root(
+        &self,
+        chains: &[DataChain],
+        endpoint_issues: &[EndpointIssue],
+        diagnostics: &[Diagnostic],
+        analysis_warnings: &[AnalysisWarning],
+        source_root: Option<&Path>,
+        output_path: &str,
+    ) -> Result<()> {
         let mut report = String::new();
 
         // Header
@@ -91,9 +131,13 @@ impl MarkdownReporter {
             0.0
         };
         report.push_str(&format!(
-            "- **Safe Chains Coverage**: {:.1}%\n\n",
+            "- **Safe Chains Coverage**: {:.1}%\n",
             coverage_percentage
         ));
+        if let Some(incomplete) = AnalysisWarning::summarize(analysis_warnings) {
+            report.push_str(&format!("- **Analysis Incomplete**: {}\n", incomplete));
+        }
+        report.push('\n');
 
         // Add schema summary section
         let schemas = Self::collect_all_schemas(chains);
@@ -231,6 +275,17 @@ impl MarkdownReporter {
                             "  - Location: {}:{}\n",
                             mismatch.location.file, mismatch.location.line
                         ));
+                        if mismatch.occurrences > 1 {
+                            report
+                                .push_str(&format!("  - Occurrences: {}\n", mismatch.occurrences));
+                        }
+                        if let Some(url) = rule_docs_url(mismatch.rule_id()) {
+                            report.push_str(&format!(
+                                "  - Rule: [`{}`]({})\n",
+                                mismatch.rule_id(),
+                                url
+                            ));
+                        }
                         report.push('\n');
                     }
                 }
@@ -257,6 +312,18 @@ impl MarkdownReporter {
             report.push_str("---\n\n");
         }
 
+        if !endpoint_issues.is_empty() {
+            report.push_str(&Self::format_endpoint_issues(endpoint_issues));
+        }
+
+        if !diagnostics.is_empty() {
+            report.push_str(&Self::format_diagnostics(diagnostics, source_root));
+        }
+
+        if !analysis_warnings.is_empty() {
+            report.push_str(&Self::format_analysis_warnings(analysis_warnings));
+        }
+
         // Recommendations section
         report.push_str("## Recommendations\n\n");
         let recommendations = Self::generate_recommendations(chains);
@@ -341,6 +408,91 @@ impl MarkdownReporter {
         Ok(())
     }
 
+    /// Lists frontend calls matching no backend route or several routes
+    fn format_endpoint_issues(endpoint_issues: &[EndpointIssue]) -> String {
+        let mut section = String::from("## Frontend Calls Without a Single Route\n\n");
+        for issue in endpoint_issues {
+            let label = match issue.kind {
+                EndpointIssueKind::Unmatched => "🔴 **Unmatched**",
+                EndpointIssueKind::Ambiguous => "🟡 **Ambiguous**",
+            };
+            section.push_str(&format!("- {}: {}\n", label, issue.message));
+            section.push_str(&format!(
+                "  - Call: {}:{}\n",
+                issue.call.location.file, issue.call.location.line
+            ));
+            for candidate in &issue.candidates {
+                section.push_str(&format!(
+                    "  - Candidate: `{:?} {}` at {}:{}\n",
+                    candidate.method,
+                    candidate.path,
+                    candidate.location.file,
+                    candidate.location.line
+                ));
+            }
+        }
+        section.push_str("\n---\n\n");
+        section
+    }
+
+    /// Lists problems of the analysis itself, which may hide contract issues
+    ///
+    /// Each diagnostic shows its source line with the offending code underlined, when the
+    /// file can be read.
+    fn format_diagnostics(diagnostics: &[Diagnostic], source_root: Option<&Path>) -> String {
+        let mut section = String::from("## Analysis Diagnostics\n\n");
+        let mut snippets = SnippetResolver::new();
+        if let Some(source_root) = source_root {
+            snippets = snippets.with_base_dir(source_root);
+        }
+        for diagnostic in diagnostics {
+            let icon = if diagnostic.severity == Severity::Info {
+                "ℹ️"
+            } else {
+                "⚠️"
+            };
+            let rule = match rule_docs_url(&diagnostic.rule_id) {
+                Some(url) => format!("[`{}`]({})", diagnostic.rule_id, url),
+                None => format!("`{}`", diagnostic.rule_id),
+            };
+            section.push_str(&format!("- {} {}: {}\n", icon, rule, diagnostic.message));
+            section.push_str(&format!(
+                "  - Location: {}:{}\n",
+                diagnostic.location.file, diagnostic.location.line
+            ));
+            if let Some(snippet) = snippets.resolve(&diagnostic.location) {
+                section.push_str("\n  ```text\n");
+                for line in snippet.to_string().lines() {
+                    section.push_str(&format!("  {}\n", line));
+                }
+                section.push_str("  ```\n\n");
+            }
+        }
+        section.push_str("\n---\n\n");
+        section
+    }
+
+    /// Lists the code the analysis couldn't follow: contracts through it were not checked,
+    /// which is a limitation of the tool rather than a finding
+    fn format_analysis_warnings(analysis_warnings: &[AnalysisWarning]) -> String {
+        let mut section = String::from("## Analysis Incompleteness\n\n");
+        section.push_str(
+            "Contracts through the code below were not checked; results may miss issues there.\n\n",
+        );
+        for (kind, count) in AnalysisWarning::counts(analysis_warnings) {
+            section.push_str(&format!("### {}\n\n", kind.describe(count)));
+            for warning in analysis_warnings.iter().filter(|w| w.kind == kind) {
+                section.push_str(&format!(
+                    "- {} ({}:{})\n",
+                    warning.message, warning.location.file, warning.location.line
+                ));
+            }
+            section.push('\n');
+        }
+        section.push_str("---\n\n");
+        section
+    }
+
     /// Builds a human-readable description of the data path
     fn build_path_description(links: &[dc_core::models::Link]) -> String {
         let mut parts = Vec::new();
@@ -525,6 +677,7 @@ impl MarkdownReporter {
             SchemaType::OpenAPI => "OpenAPI Schema",
             SchemaType::JsonSchema => "JSON Schema",
             SchemaType::OrmModel => "ORM Model",
+            SchemaType::Enum => "Enum",
         }
     }
 
@@ -573,6 +726,24 @@ impl MarkdownReporter {
                                 mismatch.location.line
                             )
                         }
+                        MismatchType::ResponseOnlyField => {
+                            format!(
+                                "Stop sending the response-only field `{}` in chain '{}' at {}:{}",
+                                mismatch.path,
+                                chain.name,
+                                mismatch.location.file,
+                                mismatch.location.line
+                            )
+                        }
+                        MismatchType::DefaultMismatch => {
+                            format!(
+                                "Use the same default for field `{}` in chain '{}' at {}:{}",
+                                mismatch.path,
+                                chain.name,
+                                mismatch.location.file,
+                                mismatch.location.line
+                            )
+                        }
                         MismatchType::UnnormalizedData => {
                             format!(
                                 "Normalize data format for field `{}` in chain '{}' at {}:{}",
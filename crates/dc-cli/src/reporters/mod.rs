@@ -1,5 +1,15 @@
+pub mod dedup;
 pub mod json;
+pub mod junit;
 pub mod markdown;
+pub mod paths;
+pub mod sarif;
+pub mod summary;
 
+pub use dedup::deduplicate_diagnostics;
 pub use json::JsonReporter;
+pub use junit::JunitReporter;
 pub use markdown::*;
+pub use paths::rewrite_report_paths;
+pub use sarif::SarifReporter;
+pub use summary::{FindingCounts, SummaryReporter};
@@ -0,0 +1,90 @@
+use crate::PathStyle;
+use dc_core::analyzers::EndpointIssue;
+use dc_core::models::{AnalysisWarning, DataChain, Diagnostic, Location};
+use std::collections::BTreeSet;
+use std::path::{Component, Path, PathBuf};
+
+/// Rewrites every `Location.file` of the report in the given style
+///
+/// Relative paths are rendered against `root` with `/` separators, so reports of the same
+/// project are identical on every machine. Files outside `root` can't be made relative and
+/// keep their absolute path; they are returned, sorted, so the caller can point them out.
+pub fn rewrite_report_paths(
+    style: PathStyle,
+    root: &Path,
+    chains: &mut [DataChain],
+    endpoint_issues: &mut [EndpointIssue],
+    diagnostics: &mut [Diagnostic],
+    analysis_warnings: &mut [AnalysisWarning],
+) -> Vec<String> {
+    let root = absolute_path(root);
+    let mut outside_root = BTreeSet::new();
+    let mut rewrite = |location: &mut Location| {
+        if location.file.is_empty() {
+            return;
+        }
+        let absolute = absolute_path(Path::new(&location.file));
+        location.file = match style {
+            PathStyle::Absolute => absolute.to_string_lossy().to_string(),
+            PathStyle::Relative => match absolute.strip_prefix(&root) {
+                Ok(relative) => relative_display(relative),
+                Err(_) => {
+                    let file = absolute.to_string_lossy().to_string();
+                    outside_root.insert(file.clone());
+                    file
+                }
+            },
+        };
+    };
+
+    for chain in chains.iter_mut() {
+        for link in &mut chain.links {
+            rewrite(&mut link.location);
+            rewrite(&mut link.schema_ref.location);
+        }
+        for contract in &mut chain.contracts {
+            rewrite(&mut contract.from_schema.location);
+            rewrite(&mut contract.to_schema.location);
+            for mismatch in &mut contract.mismatches {
+                rewrite(&mut mismatch.location);
+            }
+        }
+    }
+    for issue in endpoint_issues.iter_mut() {
+        rewrite(&mut issue.call.location);
+        for candidate in &mut issue.candidates {
+            rewrite(&mut candidate.location);
+        }
+    }
+    for diagnostic in diagnostics.iter_mut() {
+        rewrite(&mut diagnostic.location);
+    }
+    for warning in analysis_warnings.iter_mut() {
+        rewrite(&mut warning.location);
+    }
+
+    outside_root.into_iter().collect()
+}
+
+/// Canonical form of `path`, or the path joined to the current directory when it doesn't
+/// exist
+fn absolute_path(path: &Path) -> PathBuf {
+    path.canonicalize()
+        .or_else(|_| std::path::absolute(path))
+        .unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn relative_display(path: &Path) -> String {
+    let parts: Vec<_> = path
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part.to_string_lossy()),
+            _ => None,
+        })
+        .collect();
+    if parts.is_empty() {
+        ".".to_string()
+    } else {
+        parts.join("/")
+    }
+}
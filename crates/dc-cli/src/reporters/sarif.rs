@@ -0,0 +1,198 @@
+use anyhow::Result;
+use dc_core::analyzers::{EndpointIssue, EndpointIssueKind};
+use dc_core::models::{
+    rule_docs_url, AnalysisWarning, AnalysisWarningKind, DataChain, Diagnostic, Location, RuleInfo,
+    Severity, PARSE_ERROR_RULE,
+};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+
+/// SARIF 2.1.0 schema the report declares in `$schema`
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// SARIF 2.1.0 report generator, for code scanning tools (e.g. GitHub code scanning)
+///
+/// Every contract mismatch of a critical or warning contract, endpoint issue and
+/// warning/critical diagnostic becomes a `result` pointing at its source location; each
+/// reported rule is listed once in `tool.driver.rules`. Code the analysis couldn't follow
+/// (analysis warnings) is reported as `toolExecutionNotifications` of the run's invocation.
+pub struct SarifReporter;
+
+/// One finding of the report
+struct Finding {
+    rule_id: String,
+    /// `error` or `warning`
+    level: &'static str,
+    message: String,
+    location: Location,
+}
+
+impl SarifReporter {
+    /// Generates a SARIF report
+    pub fn generate_report(
+        &self,
+        chains: &[DataChain],
+        endpoint_issues: &[EndpointIssue],
+        diagnostics: &[Diagnostic],
+        analysis_warnings: &[AnalysisWarning],
+        output_path: &str,
+    ) -> Result<()> {
+        let sarif = self.render_report(chains, endpoint_issues, diagnostics, analysis_warnings)?;
+        fs::write(Path::new(output_path), sarif)?;
+        Ok(())
+    }
+
+    /// Renders the SARIF log as pretty-printed JSON
+    pub fn render_report(
+        &self,
+        chains: &[DataChain],
+        endpoint_issues: &[EndpointIssue],
+        diagnostics: &[Diagnostic],
+        analysis_warnings: &[AnalysisWarning],
+    ) -> Result<String> {
+        let findings = Self::findings(chains, endpoint_issues, diagnostics);
+
+        let mut rule_ids: Vec<&str> = Vec::new();
+        let mut results = Vec::with_capacity(findings.len());
+        for finding in &findings {
+            let rule_index = match rule_ids.iter().position(|id| *id == finding.rule_id) {
+                Some(index) => index,
+                None => {
+                    rule_ids.push(&finding.rule_id);
+                    rule_ids.len() - 1
+                }
+            };
+            results.push(json!({
+                "ruleId": finding.rule_id,
+                "ruleIndex": rule_index,
+                "level": finding.level,
+                "message": { "text": finding.message },
+                "locations": [Self::location(&finding.location)],
+            }));
+        }
+        let rules: Vec<Value> = rule_ids.iter().map(|id| Self::rule(id)).collect();
+
+        let log = json!({
+            "$schema": SARIF_SCHEMA,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "dc-verifier",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    }
+                },
+                "invocations": [Self::invocation(analysis_warnings)],
+                "results": results,
+            }],
+        });
+        Ok(serde_json::to_string_pretty(&log)? + "\n")
+    }
+
+    fn findings(
+        chains: &[DataChain],
+        endpoint_issues: &[EndpointIssue],
+        diagnostics: &[Diagnostic],
+    ) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for contract in chains.iter().flat_map(|chain| &chain.contracts) {
+            let Some(level) = Self::level(contract.severity) else {
+                continue;
+            };
+            findings.extend(contract.mismatches.iter().map(|mismatch| Finding {
+                rule_id: mismatch.rule_id().to_string(),
+                level,
+                message: mismatch.message.clone(),
+                location: mismatch.location.clone(),
+            }));
+        }
+        for issue in endpoint_issues {
+            let rule_id = match issue.kind {
+                EndpointIssueKind::Unmatched => "unmatched-endpoint",
+                EndpointIssueKind::Ambiguous => "ambiguous-endpoint",
+            };
+            findings.push(Finding {
+                rule_id: rule_id.to_string(),
+                level: "warning",
+                message: issue.message.clone(),
+                location: issue.call.location.clone(),
+            });
+        }
+        for diagnostic in diagnostics {
+            let level = if diagnostic.rule_id == PARSE_ERROR_RULE {
+                Some("error")
+            } else {
+                Self::level(diagnostic.severity)
+            };
+            if let Some(level) = level {
+                findings.push(Finding {
+                    rule_id: diagnostic.rule_id.clone(),
+                    level,
+                    message: diagnostic.message.clone(),
+                    location: diagnostic.location.clone(),
+                });
+            }
+        }
+        findings
+    }
+
+    /// `invocation` of the run: analysis warnings are its notifications, and a timed out
+    /// analysis did not execute successfully
+    fn invocation(analysis_warnings: &[AnalysisWarning]) -> Value {
+        let notifications: Vec<Value> = analysis_warnings
+            .iter()
+            .map(|warning| {
+                json!({
+                    "level": "warning",
+                    "message": { "text": warning.message },
+                    "descriptor": { "id": warning.kind.id() },
+                    "locations": [Self::location(&warning.location)],
+                })
+            })
+            .collect();
+        json!({
+            "executionSuccessful": !analysis_warnings
+                .iter()
+                .any(|warning| warning.kind == AnalysisWarningKind::TimedOut),
+            "toolExecutionNotifications": notifications,
+        })
+    }
+
+    /// SARIF level of a severity; info findings are not reported
+    fn level(severity: Severity) -> Option<&'static str> {
+        match severity {
+            Severity::Critical => Some("error"),
+            Severity::Warning => Some("warning"),
+            Severity::Info => None,
+        }
+    }
+
+    /// `reportingDescriptor` of a rule, described from the rule catalog when known
+    fn rule(rule_id: &str) -> Value {
+        let mut rule = json!({ "id": rule_id });
+        if let Some(info) = RuleInfo::find(rule_id) {
+            rule["shortDescription"] = json!({ "text": info.title });
+            rule["fullDescription"] = json!({ "text": info.description });
+        }
+        if let Some(url) = rule_docs_url(rule_id) {
+            rule["helpUri"] = url.into();
+        }
+        rule
+    }
+
+    /// `location` with the file as a forward-slash URI and a 1-based region
+    fn location(location: &Location) -> Value {
+        let mut region = json!({ "startLine": location.line.max(1) });
+        if let Some(column) = location.column {
+            region["startColumn"] = column.max(1).into();
+        }
+        json!({
+            "physicalLocation": {
+                "artifactLocation": { "uri": location.file.replace('\\', "/") },
+                "region": region,
+            }
+        })
+    }
+}
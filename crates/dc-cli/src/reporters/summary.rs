@@ -0,0 +1,165 @@
+use crate::FailOn;
+use dc_core::analyzers::{EndpointIssue, EndpointIssueKind};
+use dc_core::models::{
+    AnalysisWarning, DataChain, Diagnostic, Severity, DEPRECATED_USAGE_RULE, PARSE_ERROR_RULE,
+    UNRESOLVED_TYPE_RULE,
+};
+use std::collections::HashSet;
+
+/// Console summary reporter: aggregate counts and the overall verdict only
+pub struct SummaryReporter;
+
+/// Findings of a check, by level
+///
+/// Levels are the ones SARIF reports use: critical contracts and diagnostics and files
+/// that failed to parse are errors, unmatched and ambiguous frontend calls are warnings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FindingCounts {
+    pub errors: usize,
+    pub warnings: usize,
+    pub info: usize,
+}
+
+impl FindingCounts {
+    /// Counts contracts, endpoint issues and diagnostics by level
+    pub fn of(
+        chains: &[DataChain],
+        endpoint_issues: &[EndpointIssue],
+        diagnostics: &[Diagnostic],
+    ) -> Self {
+        let mut counts = Self {
+            warnings: endpoint_issues.len(),
+            ..Self::default()
+        };
+        let contracts = chains.iter().flat_map(|c| &c.contracts).map(|c| c.severity);
+        let diagnostics = diagnostics.iter().map(|diagnostic| {
+            if diagnostic.rule_id == PARSE_ERROR_RULE {
+                Severity::Critical
+            } else {
+                diagnostic.severity
+            }
+        });
+        for severity in contracts.chain(diagnostics) {
+            match severity {
+                Severity::Critical => counts.errors += 1,
+                Severity::Warning => counts.warnings += 1,
+                Severity::Info => counts.info += 1,
+            }
+        }
+        counts
+    }
+
+    /// Whether these findings pass a check run with `fail_on`
+    pub fn passes(&self, fail_on: FailOn) -> bool {
+        match fail_on {
+            FailOn::Error => self.errors == 0,
+            FailOn::Warning => self.errors == 0 && self.warnings == 0,
+            FailOn::Never => true,
+        }
+    }
+}
+
+impl SummaryReporter {
+    /// Renders the summary block printed by `check --summary-only`
+    pub fn render(&self, chains: &[DataChain]) -> String {
+        self.render_report(chains, &[], &[], &[], FailOn::default())
+    }
+
+    /// Renders the summary, adding unmatched/ambiguous frontend call counts, skipped files
+    /// and what the analysis couldn't follow when present
+    ///
+    /// The verdict is FAIL when a finding reaches the `fail_on` level.
+    pub fn render_report(
+        &self,
+        chains: &[DataChain],
+        endpoint_issues: &[EndpointIssue],
+        diagnostics: &[Diagnostic],
+        analysis_warnings: &[AnalysisWarning],
+        fail_on: FailOn,
+    ) -> String {
+        let counts = FindingCounts::of(chains, endpoint_issues, diagnostics);
+
+        let mut summary = String::new();
+        summary.push_str(&format!("Chains: {}\n", chains.len()));
+        summary.push_str(&format!("Routes checked: {}\n", Self::count_routes(chains)));
+        summary.push_str(&format!(
+            "Schemas linked: {}\n",
+            Self::count_schemas(chains)
+        ));
+        summary.push_str(&format!(
+            "Errors: {}, Warnings: {}, Info: {}\n",
+            counts.errors, counts.warnings, counts.info
+        ));
+        if !endpoint_issues.is_empty() {
+            let unmatched = endpoint_issues
+                .iter()
+                .filter(|issue| issue.kind == EndpointIssueKind::Unmatched)
+                .count();
+            summary.push_str(&format!(
+                "Frontend calls: {} unmatched, {} ambiguous\n",
+                unmatched,
+                endpoint_issues.len() - unmatched
+            ));
+        }
+        let unparsed = diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.rule_id == PARSE_ERROR_RULE)
+            .count();
+        if unparsed > 0 {
+            summary.push_str(&format!("Files skipped (parse errors): {}\n", unparsed));
+        }
+        let deprecated = diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.rule_id == DEPRECATED_USAGE_RULE)
+            .count();
+        if deprecated > 0 {
+            summary.push_str(&format!("Deprecated API usages: {}\n", deprecated));
+        }
+        let unresolved = diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.rule_id == UNRESOLVED_TYPE_RULE)
+            .count();
+        if unresolved > 0 {
+            summary.push_str(&format!("Unresolved frontend types: {}\n", unresolved));
+        }
+        if let Some(incomplete) = AnalysisWarning::summarize(analysis_warnings) {
+            summary.push_str(&format!("Analysis incomplete: {}\n", incomplete));
+        }
+        summary.push_str(&format!(
+            "Result: {}\n",
+            if counts.passes(fail_on) {
+                "PASS"
+            } else {
+                "FAIL"
+            }
+        ));
+        summary
+    }
+
+    /// Counts unique route links (the chain builder prefixes their ids with `route-`)
+    fn count_routes(chains: &[DataChain]) -> usize {
+        chains
+            .iter()
+            .flat_map(|c| &c.links)
+            .filter(|link| link.id.starts_with("route-"))
+            .map(|link| link.id.as_str())
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Counts unique schemas participating in chains
+    fn count_schemas(chains: &[DataChain]) -> usize {
+        chains
+            .iter()
+            .flat_map(|c| &c.links)
+            .map(|link| {
+                (
+                    link.schema_ref.name.as_str(),
+                    link.schema_ref.location.file.as_str(),
+                    link.schema_ref.location.line,
+                )
+            })
+            .collect::<HashSet<_>>()
+            .len()
+    }
+}
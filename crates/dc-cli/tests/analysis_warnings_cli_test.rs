@@ -0,0 +1,157 @@
+use std::fs;
+
+use anyhow::Result;
+
+use dc_cli::commands::check::execute_check;
+use dc_cli::ReportFormat;
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+fn project(format: &str) -> tempfile::TempDir {
+    create_temp_project(&[
+        (
+            "backend/main.py",
+            r#"
+import os
+from fastapi import FastAPI
+from pydantic import BaseModel
+from routers.gone import helper
+
+app = FastAPI()
+
+class Item(BaseModel):
+    name: str
+
+@app.post("/items", response_model=Item)
+def create_item(item: Item) -> Item:
+    return item
+"#,
+        ),
+        ("backend/routers/__init__.py", ""),
+        (
+            "dc-verifier.toml",
+            &format!(
+                r#"project_name = "demo"
+
+[output]
+format = "{}"
+path = "report.out"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+"#,
+                format
+            ),
+        ),
+    ])
+}
+
+#[test]
+fn unresolved_project_imports_are_reported_as_analysis_warnings() -> Result<()> {
+    let project = project("json");
+    let config_path = project.path().join("dc-verifier.toml");
+    execute_check(config_path.to_str().unwrap(), ReportFormat::Json, false)?;
+
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.path().join("report.out"))?)?;
+    // Standard library and installed packages aren't analysis gaps
+    let warnings = report["analysis_warnings"]
+        .as_array()
+        .expect("unresolved imports should be reported");
+    assert_eq!(warnings.len(), 1, "{:#}", report["analysis_warnings"]);
+    assert_eq!(warnings[0]["kind"], "unresolved-import");
+    assert_eq!(warnings[0]["location"]["line"], 5);
+    assert!(warnings[0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("routers.gone"));
+    assert_eq!(report["summary"]["analysis_warnings"], 1);
+    // Tool limitations are not findings
+    assert!(report.get("diagnostics").is_none());
+    Ok(())
+}
+
+#[test]
+fn markdown_report_lists_analysis_incompleteness() -> Result<()> {
+    let project = project("markdown");
+    let config_path = project.path().join("dc-verifier.toml");
+    execute_check(config_path.to_str().unwrap(), ReportFormat::Markdown, false)?;
+
+    let report = fs::read_to_string(project.path().join("report.out"))?;
+    assert!(
+        report.contains("- **Analysis Incomplete**: 1 import(s) unresolved\n"),
+        "{}",
+        report
+    );
+    assert!(report.contains("## Analysis Incompleteness"), "{}", report);
+    assert!(
+        report.contains("- Module 'routers.gone' was not found in the project ("),
+        "{}",
+        report
+    );
+    Ok(())
+}
+
+#[test]
+fn external_modules_are_not_reported_as_missing_dependencies() -> Result<()> {
+    let project = create_temp_project(&[
+        (
+            "backend/main.py",
+            r#"
+from acme_auth import tokens
+import redis
+from fastapi import FastAPI
+
+app = FastAPI()
+
+@app.get("/health")
+def health():
+    return {}
+"#,
+        ),
+        ("backend/requirements.txt", "acme_auth==2.1\nredis>=5\n"),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+external_modules = ["acme_auth"]
+
+[output]
+format = "json"
+path = "report.out"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+"#,
+        ),
+    ]);
+    let config_path = project.path().join("dc-verifier.toml");
+    execute_check(config_path.to_str().unwrap(), ReportFormat::Json, false)?;
+
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.path().join("report.out"))?)?;
+    let warnings = report["analysis_warnings"]
+        .as_array()
+        .expect("the missing dependency should be reported");
+    let messages: Vec<&str> = warnings
+        .iter()
+        .map(|warning| warning["message"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        messages,
+        ["Module 'redis' is not installed (Install with: pip install redis)"]
+    );
+    assert_eq!(warnings[0]["kind"], "external-dependency");
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use std::fs;
+
+use dc_cli::config::Config;
+use dc_cli::ReportFormat;
+
+const MINIMAL_CONFIG: &str = r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "typescript"
+src_paths = ["src"]
+"#;
+
+#[test]
+fn discovery_walks_up_and_prefers_nearest_config() {
+    let root = tempfile::tempdir().expect("failed to create temp dir");
+    let nested = root.path().join("packages/web/src");
+    fs::create_dir_all(&nested).unwrap();
+
+    assert_eq!(Config::discover(&nested), None);
+
+    fs::write(root.path().join(".dcverifier.toml"), MINIMAL_CONFIG).unwrap();
+    assert_eq!(
+        Config::discover(&nested),
+        Some(root.path().join(".dcverifier.toml"))
+    );
+
+    let package_dir = root.path().join("packages/web");
+    fs::write(package_dir.join("dcverifier.toml"), MINIMAL_CONFIG).unwrap();
+    assert_eq!(
+        Config::discover(&nested),
+        Some(package_dir.join("dcverifier.toml"))
+    );
+
+    // Within one directory the historical file name wins
+    fs::write(package_dir.join("dc-verifier.toml"), MINIMAL_CONFIG).unwrap();
+    assert_eq!(
+        Config::discover(&nested),
+        Some(package_dir.join("dc-verifier.toml"))
+    );
+}
+
+#[test]
+fn explicit_config_path_overrides_discovery() {
+    let path = Config::locate(Some("custom/verifier.toml")).unwrap();
+    assert_eq!(path, std::path::PathBuf::from("custom/verifier.toml"));
+}
+
+#[test]
+fn report_format_falls_back_to_config_output_format() {
+    let root = tempfile::tempdir().expect("failed to create temp dir");
+    fs::create_dir_all(root.path().join("src")).unwrap();
+    let config_path = root.path().join("dcverifier.toml");
+    fs::write(&config_path, MINIMAL_CONFIG).unwrap();
+
+    let config = Config::load(config_path.to_str().unwrap(), None).unwrap();
+    assert!(matches!(config.report_format(), ReportFormat::Json));
+}
@@ -0,0 +1,94 @@
+use std::fs;
+use std::process::Command;
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+#[test]
+fn dry_run_lists_what_would_be_analyzed() {
+    let project = create_temp_project(&[
+        (
+            "backend/main.py",
+            "from fastapi import FastAPI\n\napp = FastAPI()\n",
+        ),
+        ("backend/models.py", "from pydantic import BaseModel\n"),
+        ("backend/requirements.txt", "fastapi\n"),
+        (
+            "frontend/src/api.ts",
+            "export const ping = () => fetch('/ping');\n",
+        ),
+        ("frontend/src/generated/client.ts", "export {};\n"),
+        ("frontend/.gitignore", "src/generated/\n"),
+        ("openapi.json", "{}"),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+openapi_path = "openapi.json"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+
+[[adapters]]
+type = "typescript"
+src_paths = ["frontend/src"]
+"#,
+        ),
+    ]);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_dc-verifier"))
+        .current_dir(project.path())
+        .arg("--quiet")
+        .arg("check")
+        .arg("--dry-run")
+        .output()
+        .expect("failed to run dc-verifier");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let plan = &stdout[stdout.find("Project:").expect("dry run should be printed")..];
+    assert_eq!(
+        plan,
+        "Project: demo
+Report: json -> report.json
+
+Adapter 1: fastapi (backend)
+  OpenAPI spec: openapi.json
+  App: backend/main.py
+  Entry points (1):
+    backend/main.py
+  Files (2):
+    backend/main.py
+    backend/models.py
+
+Adapter 2: typescript (frontend)
+  OpenAPI spec: openapi.json
+  Source roots: frontend/src
+  Files (1):
+    frontend/src/api.ts
+
+Files: 2 backend, 1 frontend
+"
+    );
+    assert!(
+        !project.path().join("report.json").exists(),
+        "a dry run must not write the report"
+    );
+}
@@ -0,0 +1,103 @@
+use std::fs;
+
+use anyhow::Result;
+
+use dc_cli::commands::check::execute_check;
+use dc_cli::ReportFormat;
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+#[test]
+fn frontend_calls_without_a_single_route_are_reported() -> Result<()> {
+    let project = create_temp_project(&[
+        (
+            "backend/main.py",
+            r#"
+from fastapi import FastAPI
+
+app = FastAPI()
+
+@app.get("/users")
+def list_users():
+    return []
+
+@app.get("/users/me")
+def current_user():
+    return {}
+
+@app.get("/users/{user_id}")
+def get_user(user_id: int):
+    return {}
+"#,
+        ),
+        (
+            "frontend/src/api.ts",
+            r#"
+export async function load(id: string) {
+  await fetch("/users/");
+  await fetch("/users/me");
+  await fetch("/userz");
+  await fetch(`/users/${id}`);
+}
+"#,
+        ),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+
+[[adapters]]
+type = "typescript"
+src_paths = ["frontend/src"]
+"#,
+        ),
+    ]);
+
+    let config_path = project.path().join("dc-verifier.toml");
+    execute_check(config_path.to_str().unwrap(), ReportFormat::Json, false)?;
+
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.path().join("report.json"))?)?;
+    let issues = report["endpoint_issues"]
+        .as_array()
+        .expect("endpoint issues should be reported");
+    let summary: Vec<(&str, &str)> = issues
+        .iter()
+        .map(|issue| {
+            (
+                issue["kind"].as_str().unwrap(),
+                issue["message"].as_str().unwrap(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        summary,
+        vec![
+            ("Unmatched", "No backend route serves GET /userz"),
+            (
+                "Ambiguous",
+                "GET /users/{} matches 2 backend routes: /users/me, /users/{user_id}"
+            ),
+        ]
+    );
+    assert_eq!(report["summary"]["endpoint_issues"], 2);
+    Ok(())
+}
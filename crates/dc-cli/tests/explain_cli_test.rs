@@ -0,0 +1,50 @@
+use dc_cli::commands::explain::{render_explanation, render_rule_list};
+
+#[test]
+fn explain_prints_rule_description_and_docs_link() {
+    let text = render_explanation("type-mismatch").expect("rule should be known");
+
+    assert!(text.starts_with("type_mismatch — "), "{}", text);
+    assert!(text.contains("Why it matters:"), "{}", text);
+    assert!(text.contains("Example fix:"), "{}", text);
+    assert!(text.contains("docs/rules.md#type_mismatch"), "{}", text);
+}
+
+#[test]
+fn explain_unknown_rule_lists_known_ones() {
+    let err = render_explanation("no-such-rule").unwrap_err().to_string();
+
+    assert!(err.contains("Unknown rule 'no-such-rule'"), "{}", err);
+    assert!(err.contains("parse-error"), "{}", err);
+    assert!(err.contains("missing_field"), "{}", err);
+}
+
+#[test]
+fn list_rules_prints_every_rule_with_its_default_severity() {
+    let text = render_rule_list(false).expect("rule list should render");
+
+    let missing_field = text
+        .lines()
+        .find(|line| line.starts_with("missing_field "))
+        .expect("missing_field should be listed");
+    assert!(missing_field.contains(" warning "), "{}", missing_field);
+    assert!(
+        missing_field.ends_with("Required field is not provided"),
+        "{}",
+        missing_field
+    );
+    assert!(text
+        .lines()
+        .any(|line| line.starts_with("stale-example ") && line.contains(" info ")));
+
+    let json: serde_json::Value =
+        serde_json::from_str(&render_rule_list(true).unwrap()).expect("valid JSON");
+    let rules = json.as_array().unwrap();
+    assert_eq!(rules.len(), text.lines().count());
+    assert_eq!(rules[0]["id"], "type_mismatch");
+    assert_eq!(rules[0]["default_severity"], "Critical");
+    assert!(rules[0]["docs_url"]
+        .as_str()
+        .unwrap()
+        .ends_with("docs/rules.md#type_mismatch"));
+}
@@ -0,0 +1,89 @@
+use std::fs;
+
+use anyhow::Result;
+
+use dc_cli::commands::check::{execute_check_with_options, CheckOptions};
+use dc_cli::ReportFormat;
+
+const MAIN_PY: &str = r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class Item(BaseModel):
+    name: str
+
+@app.get("/items/{item_id}")
+def read_item(item_id: int) -> Item:
+    return Item(name="a")
+
+@app.get("/items", response_model=list[Item])
+def list_items() -> list[Item]:
+    return []
+
+@app.get("/health")
+def health() -> dict:
+    return {"status": "ok"}
+"#;
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+#[test]
+fn fix_adds_missing_response_model_once() -> Result<()> {
+    let project = create_temp_project(&[
+        ("backend/main.py", MAIN_PY),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+"#,
+        ),
+    ]);
+    let config_path = project.path().join("dc-verifier.toml");
+    let main_py = project.path().join("backend/main.py");
+    let options = CheckOptions {
+        fix: true,
+        ..Default::default()
+    };
+
+    execute_check_with_options(
+        config_path.to_str().unwrap(),
+        Some(ReportFormat::Json),
+        &options,
+    )?;
+    let fixed = fs::read_to_string(&main_py)?;
+    assert_eq!(
+        fixed,
+        MAIN_PY.replace(
+            r#"@app.get("/items/{item_id}")"#,
+            r#"@app.get("/items/{item_id}", response_model=Item)"#
+        )
+    );
+
+    // Fixes are idempotent
+    execute_check_with_options(
+        config_path.to_str().unwrap(),
+        Some(ReportFormat::Json),
+        &options,
+    )?;
+    assert_eq!(fs::read_to_string(&main_py)?, fixed);
+    Ok(())
+}
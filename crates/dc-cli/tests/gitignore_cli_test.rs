@@ -0,0 +1,106 @@
+use std::fs;
+
+use anyhow::Result;
+
+use dc_cli::commands::check::{execute_check_with_options, CheckOptions};
+use dc_cli::ReportFormat;
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+/// Messages of the endpoint issues reported with the given options
+fn endpoint_issues(project: &tempfile::TempDir, options: &CheckOptions) -> Result<Vec<String>> {
+    let config_path = project.path().join("dc-verifier.toml");
+    execute_check_with_options(
+        config_path.to_str().unwrap(),
+        Some(ReportFormat::Json),
+        options,
+    )?;
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.path().join("report.json"))?)?;
+    // The report leaves out an empty issue list
+    Ok(report["endpoint_issues"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|issue| issue["message"].as_str().unwrap().to_string())
+        .collect())
+}
+
+#[test]
+fn gitignored_files_are_only_analyzed_with_no_gitignore() -> Result<()> {
+    let project = create_temp_project(&[
+        (
+            "backend/main.py",
+            r#"
+from fastapi import FastAPI
+
+app = FastAPI()
+
+@app.get("/users")
+def list_users():
+    return []
+"#,
+        ),
+        ("frontend/.gitignore", "src/generated/\n"),
+        (
+            "frontend/src/api.ts",
+            r#"
+export async function load() {
+  await fetch("/users");
+}
+"#,
+        ),
+        (
+            "frontend/src/generated/client.ts",
+            r#"
+export async function legacy() {
+  await fetch("/legacy");
+}
+"#,
+        ),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+
+[[adapters]]
+type = "typescript"
+src_paths = ["frontend/src"]
+"#,
+        ),
+    ]);
+
+    let options = CheckOptions {
+        summary_only: true,
+        ..Default::default()
+    };
+    assert!(endpoint_issues(&project, &options)?.is_empty());
+
+    let options = CheckOptions {
+        summary_only: true,
+        no_gitignore: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        endpoint_issues(&project, &options)?,
+        ["No backend route serves GET /legacy"]
+    );
+    Ok(())
+}
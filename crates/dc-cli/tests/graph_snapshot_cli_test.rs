@@ -0,0 +1,88 @@
+use std::fs;
+
+use dc_cli::commands::check::{execute_check_with_options, CheckOptions};
+use dc_cli::ReportFormat;
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+fn report_chains(project: &tempfile::TempDir) -> serde_json::Value {
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.path().join("report.json")).unwrap())
+            .unwrap();
+    report["chains"].clone()
+}
+
+#[test]
+fn emitted_graphs_are_checked_without_parsing_the_sources() {
+    let project = create_temp_project(&[
+        (
+            "backend/main.py",
+            r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class Item(BaseModel):
+    name: str
+
+@app.post("/items", response_model=Item)
+def create_item(item: Item) -> Item:
+    return item
+"#,
+        ),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+"#,
+        ),
+    ]);
+    let config_path = project.path().join("dc-verifier.toml");
+    let graph_dir = project.path().join("graphs");
+
+    execute_check_with_options(
+        config_path.to_str().unwrap(),
+        Some(ReportFormat::Json),
+        &CheckOptions {
+            summary_only: true,
+            emit_graph: Some(graph_dir.to_string_lossy().to_string()),
+            ..Default::default()
+        },
+    )
+    .expect("check should succeed");
+    assert!(graph_dir.join("fastapi_0.json").is_file());
+    let parsed = report_chains(&project);
+    assert!(!parsed.as_array().unwrap().is_empty());
+
+    // The sources no longer declare the route: only the emitted graph does
+    fs::write(project.path().join("backend/main.py"), "").unwrap();
+    execute_check_with_options(
+        config_path.to_str().unwrap(),
+        Some(ReportFormat::Json),
+        &CheckOptions {
+            summary_only: true,
+            graph: Some(graph_dir.to_string_lossy().to_string()),
+            ..Default::default()
+        },
+    )
+    .expect("check from the emitted graph should succeed");
+    assert_eq!(report_chains(&project), parsed);
+}
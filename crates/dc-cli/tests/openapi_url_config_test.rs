@@ -0,0 +1,64 @@
+use std::fs;
+use std::time::Duration;
+
+use dc_cli::config::Config;
+
+fn load_with_globals(globals: &str) -> anyhow::Result<Config> {
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    fs::create_dir_all(dir.path().join("src")).unwrap();
+    let config_path = dir.path().join("dc-verifier.toml");
+    fs::write(
+        &config_path,
+        format!(
+            r#"project_name = "demo"
+{globals}
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "typescript"
+src_paths = ["src"]
+openapi_path = "https://internal.example/openapi.json"
+"#
+        ),
+    )
+    .unwrap();
+    Config::load(config_path.to_str().unwrap(), None)
+}
+
+#[test]
+fn url_openapi_paths_are_kept_as_is() {
+    let config = load_with_globals(r#"openapi_path = "http://localhost:8000/openapi.json""#)
+        .expect("URL openapi_path should not be checked on disk");
+
+    assert_eq!(
+        config.openapi_path.as_deref(),
+        Some("http://localhost:8000/openapi.json")
+    );
+    assert_eq!(
+        config.adapters[0].openapi_path.as_deref(),
+        Some("https://internal.example/openapi.json")
+    );
+}
+
+#[test]
+fn fetch_options_combine_config_and_cli() {
+    let config = load_with_globals("openapi_timeout_secs = 5").expect("config should load");
+    let options = config.fetch_options(false);
+    assert_eq!(options.timeout, Duration::from_secs(5));
+    assert!(!options.insecure);
+    assert!(config.fetch_options(true).insecure);
+
+    let config = load_with_globals("openapi_insecure = true").expect("config should load");
+    assert!(config.fetch_options(false).insecure);
+}
+
+#[test]
+fn zero_timeout_is_rejected() {
+    let err = load_with_globals("openapi_timeout_secs = 0")
+        .expect_err("zero timeout should be rejected")
+        .to_string();
+    assert!(err.contains("openapi_timeout_secs"), "{}", err);
+}
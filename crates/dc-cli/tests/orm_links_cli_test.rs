@@ -0,0 +1,217 @@
+use std::fs;
+
+use anyhow::Result;
+
+use dc_cli::commands::orm_links::{build_link_table, execute_orm_links};
+use dc_cli::ManifestFormat;
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+fn project() -> tempfile::TempDir {
+    create_temp_project(&[
+        (
+            "backend/main.py",
+            r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+from sqlalchemy.orm import Mapped, declarative_base, mapped_column
+
+Base = declarative_base()
+app = FastAPI()
+
+class Item(Base):
+    __tablename__ = "items"
+    id: Mapped[int] = mapped_column(primary_key=True)
+    name: Mapped[str]
+
+class ItemRead(BaseModel):
+    model_config = {"from_attributes": True}
+    id: int
+    name: str
+
+class AuditEntry(BaseModel):
+    model_config = {"from_attributes": True}
+    actor: str
+    action: str
+
+class ItemCreate(BaseModel):
+    name: str
+
+@app.post("/items", response_model=ItemRead)
+def create_item(item: ItemCreate) -> ItemRead:
+    return ItemRead(id=1, name=item.name)
+
+@app.get("/audit", response_model=AuditEntry)
+def read_audit() -> AuditEntry:
+    return AuditEntry(actor="admin", action="login")
+"#,
+        ),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+"#,
+        ),
+    ])
+}
+
+#[test]
+fn link_table_lists_linked_and_unlinked_models() -> Result<()> {
+    let project = project();
+    let config_path = project.path().join("dc-verifier.toml");
+
+    let table = build_link_table(config_path.to_str().unwrap())?;
+
+    let names: Vec<&str> = table
+        .iter()
+        .map(|entry| entry.pydantic_model.as_str())
+        .collect();
+    // Models without `from_attributes` and without a link are left out
+    assert_eq!(names, vec!["AuditEntry", "ItemRead"]);
+    assert_eq!(table[0].orm_model, None);
+    assert_eq!(table[0].strategy, None);
+    assert_eq!(table[1].orm_model.as_deref(), Some("Item"));
+    assert_eq!(table[1].strategy.as_deref(), Some("base-name"));
+    assert_eq!(table[1].score, Some(0.9));
+    assert!(table[1]
+        .orm_location
+        .as_ref()
+        .unwrap()
+        .ends_with("main.py:9"));
+    Ok(())
+}
+
+#[test]
+fn link_table_renders_markdown() -> Result<()> {
+    let project = project();
+    let config_path = project.path().join("dc-verifier.toml");
+    let output = project.path().join("out/orm-links.md");
+
+    execute_orm_links(
+        config_path.to_str().unwrap(),
+        ManifestFormat::Markdown,
+        Some(output.to_str().unwrap()),
+    )?;
+
+    let table = fs::read_to_string(&output)?;
+    assert!(table.starts_with("# ORM Links\n\n| Pydantic model |"));
+    let audit_row = table
+        .lines()
+        .find(|line| line.starts_with("| AuditEntry |"))
+        .expect("unlinked model row");
+    assert!(
+        audit_row.ends_with("| ⚠️ not linked | - | - |"),
+        "{}",
+        audit_row
+    );
+    let item_row = table
+        .lines()
+        .find(|line| line.starts_with("| ItemRead |"))
+        .expect("linked model row");
+    assert!(item_row.ends_with("| base-name | 0.90 |"), "{}", item_row);
+    Ok(())
+}
+
+#[test]
+fn relationship_fields_take_part_in_field_based_links() -> Result<()> {
+    let project = create_temp_project(&[
+        (
+            "backend/main.py",
+            r#"
+from typing import List, Optional
+from fastapi import FastAPI
+from pydantic import BaseModel
+from sqlalchemy import ForeignKey
+from sqlalchemy.orm import Mapped, declarative_base, mapped_column, relationship
+
+Base = declarative_base()
+app = FastAPI()
+
+class Author(Base):
+    __tablename__ = "authors"
+    id: Mapped[int] = mapped_column(primary_key=True)
+    name: Mapped[str]
+    books: Mapped[list["Book"]] = relationship(back_populates="author")
+
+class Book(Base):
+    __tablename__ = "books"
+    id: Mapped[int] = mapped_column(primary_key=True)
+    author_id: Mapped[int] = mapped_column(ForeignKey("authors.id"))
+    author: Mapped[Optional["Author"]] = relationship(back_populates="books")
+    reviews = relationship("Review")
+
+class BookSummary(BaseModel):
+    id: int
+
+class WriterProfile(BaseModel):
+    model_config = {"from_attributes": True}
+    id: int
+    name: str
+    books: List[BookSummary]
+
+@app.get("/writers/{writer_id}", response_model=WriterProfile)
+def read_writer(writer_id: int) -> WriterProfile:
+    return WriterProfile(id=writer_id, name="", books=[])
+"#,
+        ),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+"#,
+        ),
+    ]);
+    let config_path = project.path().join("dc-verifier.toml");
+
+    let table = build_link_table(config_path.to_str().unwrap())?;
+
+    let writer = table
+        .iter()
+        .find(|entry| entry.pydantic_model == "WriterProfile")
+        .expect("WriterProfile row");
+    assert_eq!(writer.orm_model.as_deref(), Some("Author"));
+    assert_eq!(writer.strategy.as_deref(), Some("field-based"));
+    assert_eq!(writer.score, Some(1.0));
+    Ok(())
+}
+
+#[test]
+fn report_only_formats_are_rejected_by_argument_parsing() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_dc-verifier"))
+        .args(["orm-links", "--format", "junit"])
+        .output()
+        .expect("failed to run dc-verifier");
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("invalid value 'junit'"), "{}", stderr);
+    assert!(
+        stderr.contains("[possible values: json, markdown]"),
+        "{}",
+        stderr
+    );
+}
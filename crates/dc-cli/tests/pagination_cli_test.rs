@@ -0,0 +1,106 @@
+use std::fs;
+
+use anyhow::Result;
+
+use dc_cli::commands::check::execute_check;
+use dc_cli::ReportFormat;
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+#[test]
+fn inconsistent_pagination_envelopes_are_reported() -> Result<()> {
+    let project = create_temp_project(&[
+        (
+            "backend/main.py",
+            r#"
+from typing import Generic, TypeVar
+
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+T = TypeVar("T")
+
+app = FastAPI()
+
+class Page(BaseModel, Generic[T]):
+    items: list[T]
+    total: int
+    page: int
+    size: int
+
+class User(BaseModel):
+    id: int
+    name: str
+
+class Team(BaseModel):
+    id: int
+    title: str
+
+class InvoiceList(BaseModel):
+    results: list[Team]
+    count: int
+
+@app.get("/users", response_model=Page[User])
+def list_users():
+    return []
+
+@app.get("/teams", response_model=Page[Team])
+def list_teams():
+    return []
+
+@app.get("/invoices", response_model=InvoiceList)
+def list_invoices():
+    return []
+
+@app.get("/users/{user_id}", response_model=User)
+def get_user(user_id: int):
+    return {}
+"#,
+        ),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+"#,
+        ),
+    ]);
+
+    let config_path = project.path().join("dc-verifier.toml");
+    execute_check(config_path.to_str().unwrap(), ReportFormat::Json, false)?;
+
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.path().join("report.json"))?)?;
+    let messages: Vec<&str> = report["diagnostics"]
+        .as_array()
+        .expect("diagnostics should be reported")
+        .iter()
+        .filter(|diagnostic| diagnostic["rule_id"] == "pagination-shape")
+        .map(|diagnostic| diagnostic["message"].as_str().unwrap())
+        .collect();
+
+    assert_eq!(
+        messages,
+        vec![
+            "GET /invoices returns the pagination envelope {count, results}, while 2 other \
+             paginated route(s) return {items, page, size, total}"
+        ]
+    );
+    Ok(())
+}
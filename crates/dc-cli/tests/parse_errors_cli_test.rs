@@ -0,0 +1,131 @@
+use std::fs;
+
+use anyhow::Result;
+
+use dc_cli::commands::check::{
+    execute_check, execute_check_with_options, CheckOptions, VerificationFailed,
+};
+use dc_cli::{FailOn, ReportFormat};
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+fn project_with_broken_module() -> tempfile::TempDir {
+    create_temp_project(&[
+        (
+            "backend/main.py",
+            r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+from routers import legacy
+
+app = FastAPI()
+app.include_router(legacy.router)
+
+class Item(BaseModel):
+    name: str
+
+@app.post("/items", response_model=Item)
+def create_item(item: Item) -> Item:
+    return item
+"#,
+        ),
+        ("backend/routers/legacy.py", "print 'python 2 only'\n"),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+"#,
+        ),
+    ])
+}
+
+#[test]
+fn unparseable_file_is_reported_and_skipped() -> Result<()> {
+    let project = project_with_broken_module();
+    let config_path = project.path().join("dc-verifier.toml");
+    // The report is still written, and the skipped file fails the check
+    let err = execute_check(config_path.to_str().unwrap(), ReportFormat::Json, false)
+        .expect_err("a parse error is an error-level finding");
+    assert!(
+        err.downcast_ref::<VerificationFailed>().is_some(),
+        "{}",
+        err
+    );
+
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.path().join("report.json"))?)?;
+    let diagnostics = report["diagnostics"]
+        .as_array()
+        .expect("parse errors should be reported");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0]["rule_id"], "parse-error");
+    assert!(diagnostics[0]["location"]["file"]
+        .as_str()
+        .unwrap()
+        .ends_with("legacy.py"));
+    assert_eq!(report["summary"]["diagnostics"], 1);
+    assert!(report["summary"]["total_chains"].as_u64().unwrap() > 0);
+    Ok(())
+}
+
+#[test]
+fn strict_parse_fails_on_unparseable_file() {
+    let project = project_with_broken_module();
+    let config_path = project.path().join("dc-verifier.toml");
+    let result = execute_check_with_options(
+        config_path.to_str().unwrap(),
+        Some(ReportFormat::Json),
+        &CheckOptions {
+            strict_parse: true,
+            ..Default::default()
+        },
+    );
+
+    let err = result.expect_err("strict parsing should abort the run");
+    assert!(format!("{:#}", err).contains("legacy.py"));
+}
+
+#[test]
+fn markdown_report_shows_the_source_line_of_diagnostics() -> Result<()> {
+    let project = project_with_broken_module();
+    let config_path = project.path().join("dc-verifier.toml");
+    execute_check_with_options(
+        config_path.to_str().unwrap(),
+        Some(ReportFormat::Markdown),
+        &CheckOptions {
+            fail_on: FailOn::Never,
+            ..Default::default()
+        },
+    )?;
+
+    let report = fs::read_to_string(project.path().join("report.json"))?;
+    assert!(report.contains("## Analysis Diagnostics"), "{}", report);
+    assert!(
+        report.contains("  1 | print 'python 2 only'\n"),
+        "{}",
+        report
+    );
+    assert!(
+        report.contains("    |       ^^^^^^^^^^^^^^^\n"),
+        "{}",
+        report
+    );
+    Ok(())
+}
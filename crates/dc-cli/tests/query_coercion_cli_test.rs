@@ -0,0 +1,88 @@
+use std::fs;
+
+use anyhow::Result;
+
+use dc_cli::commands::check::execute_check;
+use dc_cli::ReportFormat;
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+#[test]
+fn query_values_the_route_cannot_coerce_are_reported() -> Result<()> {
+    let project = create_temp_project(&[
+        (
+            "backend/main.py",
+            r#"
+from fastapi import FastAPI, Query
+
+app = FastAPI()
+
+@app.get("/users")
+def list_users(page: int = 1, active: bool = Query(False, strict=True), q: str = ""):
+    return []
+"#,
+        ),
+        (
+            "frontend/src/api.ts",
+            r#"
+import axios from "axios";
+
+export async function load(term: string) {
+  await fetch("/users?page=first&q=x");
+  await fetch("/users?page=2");
+  await axios.get("/users", { params: { active: true, q: term } });
+}
+"#,
+        ),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+
+[[adapters]]
+type = "typescript"
+src_paths = ["frontend/src"]
+"#,
+        ),
+    ]);
+
+    let config_path = project.path().join("dc-verifier.toml");
+    execute_check(config_path.to_str().unwrap(), ReportFormat::Json, false)?;
+
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.path().join("report.json"))?)?;
+    let messages: Vec<&str> = report["diagnostics"]
+        .as_array()
+        .expect("diagnostics should be reported")
+        .iter()
+        .filter(|diagnostic| diagnostic["rule_id"] == "query-coercion")
+        .map(|diagnostic| diagnostic["message"].as_str().unwrap())
+        .collect();
+
+    assert_eq!(
+        messages,
+        vec![
+            "Call to GET /users sends `page=first`, which the route can't coerce to `int`",
+            "Call to GET /users sends the query parameter `active` as a string, which the \
+             route's strict `bool` rejects",
+        ]
+    );
+    Ok(())
+}
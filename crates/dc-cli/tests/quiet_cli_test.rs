@@ -0,0 +1,62 @@
+use std::fs;
+use std::process::Command;
+
+fn write_project(dir: &std::path::Path) {
+    fs::create_dir_all(dir.join("src")).expect("failed to create src dir");
+    fs::write(
+        dir.join("src/api.ts"),
+        "export const ping = () => fetch('/ping');\n",
+    )
+    .expect("failed to write source");
+    fs::write(
+        dir.join("dc-verifier.toml"),
+        r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "typescript"
+src_paths = ["src"]
+"#,
+    )
+    .expect("failed to write config");
+}
+
+#[test]
+fn quiet_suppresses_console_logs_but_keeps_log_file() {
+    let project = tempfile::tempdir().expect("failed to create temp dir");
+    write_project(project.path());
+    let log_file = project.path().join("logs/dcv.log");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_dc-verifier"))
+        .current_dir(project.path())
+        .env("RUST_LOG", "debug")
+        .arg("--quiet")
+        .arg("--log-file")
+        .arg(&log_file)
+        .arg("check")
+        .output()
+        .expect("failed to run dc-verifier");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stdout.contains("Verification completed"),
+        "report output must be kept, got: {stdout}"
+    );
+    for level in ["INFO", "DEBUG", "WARN"] {
+        assert!(
+            !stdout.contains(level) && !stderr.contains(level),
+            "{level} logs must not reach the console in quiet mode"
+        );
+    }
+
+    let logged = fs::read_to_string(&log_file).expect("log file should be written");
+    assert!(
+        logged.contains("Loaded configuration file"),
+        "log file must keep the configured level, got: {logged}"
+    );
+}
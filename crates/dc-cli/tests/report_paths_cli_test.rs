@@ -0,0 +1,157 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+
+use dc_cli::commands::check::{execute_check_with_options, CheckOptions};
+use dc_cli::{FailOn, PathStyle, ReportFormat};
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+fn project(output: &str) -> tempfile::TempDir {
+    create_temp_project(&[
+        (
+            "backend/main.py",
+            r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+from routers import legacy
+
+app = FastAPI()
+app.include_router(legacy.router)
+
+class Item(BaseModel):
+    name: str
+
+@app.post("/items", response_model=Item)
+def create_item(item: Item) -> Item:
+    return item
+"#,
+        ),
+        ("backend/routers/legacy.py", "print 'python 2 only'\n"),
+        (
+            "dc-verifier.toml",
+            &format!(
+                r#"project_name = "demo"
+
+[output]
+{}
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+"#,
+                output
+            ),
+        ),
+    ])
+}
+
+/// Runs a check that doesn't fail on the project's parse error
+fn check(project: &Path, format: ReportFormat, options: &CheckOptions) -> Result<()> {
+    let config_path = project.join("dc-verifier.toml");
+    let options = CheckOptions {
+        fail_on: FailOn::Never,
+        ..options.clone()
+    };
+    execute_check_with_options(config_path.to_str().unwrap(), Some(format), &options)
+}
+
+fn report(project: &Path) -> Result<serde_json::Value> {
+    Ok(serde_json::from_str(&fs::read_to_string(
+        project.join("report.json"),
+    )?)?)
+}
+
+fn link_files(report: &serde_json::Value) -> Vec<String> {
+    report["chains"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|chain| chain["links"].as_array().unwrap())
+        .map(|link| link["location"]["file"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn paths_are_relative_to_the_project_root() -> Result<()> {
+    let project = project("format = \"markdown\"\npath = \"report.md\"\npaths = \"relative\"");
+
+    check(
+        project.path(),
+        ReportFormat::Markdown,
+        &CheckOptions::default(),
+    )?;
+
+    let report = fs::read_to_string(project.path().join("report.md"))?;
+    assert!(
+        report.contains("  - Location: backend/routers/legacy.py:1\n"),
+        "{}",
+        report
+    );
+    // Source snippets are still read from the rewritten paths
+    assert!(report.contains("print 'python 2 only'"), "{}", report);
+    assert!(!report.contains("Location: /"), "{}", report);
+    Ok(())
+}
+
+#[test]
+fn command_line_overrides_the_path_style() -> Result<()> {
+    let project = project("format = \"json\"\npath = \"report.json\"\npaths = \"relative\"");
+
+    check(
+        project.path(),
+        ReportFormat::Json,
+        &CheckOptions {
+            paths: Some(PathStyle::Absolute),
+            ..Default::default()
+        },
+    )?;
+
+    let report = report(project.path())?;
+    let file = report["diagnostics"][0]["location"]["file"]
+        .as_str()
+        .unwrap();
+    assert!(Path::new(file).is_absolute(), "{}", file);
+    assert!(file.ends_with("legacy.py"));
+    Ok(())
+}
+
+#[test]
+fn files_outside_the_root_keep_absolute_paths() -> Result<()> {
+    let project = project("format = \"json\"\npath = \"report.json\"");
+    let root = project.path().join("backend/routers");
+
+    check(
+        project.path(),
+        ReportFormat::Json,
+        &CheckOptions {
+            paths: Some(PathStyle::Relative),
+            paths_root: Some(root.to_string_lossy().to_string()),
+            ..Default::default()
+        },
+    )?;
+
+    let report = report(project.path())?;
+    assert_eq!(report["diagnostics"][0]["location"]["file"], "legacy.py");
+    let files = link_files(&report);
+    assert!(!files.is_empty());
+    assert!(
+        files
+            .iter()
+            .all(|file| Path::new(file).is_absolute() && file.ends_with("main.py")),
+        "{:?}",
+        files
+    );
+    Ok(())
+}
@@ -2,7 +2,7 @@ use std::fs;
 
 use anyhow::Result;
 
-use dc_cli::reporters::{JsonReporter, MarkdownReporter};
+use dc_cli::reporters::{deduplicate_diagnostics, JsonReporter, MarkdownReporter};
 use dc_core::call_graph::{graph::CallGraph, CallNode};
 use dc_core::models::{
     BaseType, ChainDirection, ChainType, Contract, DataChain, Link, LinkType, Location, Mismatch,
@@ -89,6 +89,7 @@ fn build_regression_chains() -> Vec<DataChain> {
         location: loc("frontend/schemas/item.ts", 8),
         message: "Field `discount` has mismatched type between Zod and OpenAPI".to_string(),
         severity_level: SeverityLevel::High,
+        occurrences: 1,
     };
 
     let frontend_contract = Contract {
@@ -146,6 +147,7 @@ fn build_regression_chains() -> Vec<DataChain> {
         location: loc("backend/models.py", 20),
         message: "Field `title` is missing in ORM model".to_string(),
         severity_level: SeverityLevel::Critical,
+        occurrences: 1,
     };
 
     let backend_contract = Contract {
@@ -214,6 +216,16 @@ fn json_reporter_summary_is_stable_for_regression_chains() -> Result<()> {
     assert_eq!(by_type.get("pydantic").and_then(|v| v.as_u64()), Some(1));
     assert_eq!(by_type.get("orm_model").and_then(|v| v.as_u64()), Some(1));
 
+    // Reported rules are linked to their documentation
+    let rule_docs = json
+        .get("rule_docs")
+        .and_then(|v| v.as_object())
+        .expect("rule_docs must list the reported rules");
+    assert!(rule_docs
+        .get("type_mismatch")
+        .and_then(|v| v.as_str())
+        .is_some_and(|url| url.ends_with("docs/rules.md#type_mismatch")));
+
     Ok(())
 }
 
@@ -257,6 +269,56 @@ fn markdown_reporter_contains_mismatch_sections_for_regression_chains() -> Resul
         content.contains("ORM Model"),
         "markdown report should display ORM schema type label"
     );
+    assert!(
+        content.contains("  - Rule: [`type_mismatch`](https://github.com/Sett11/dc-verifier/blob/main/docs/rules.md#type_mismatch)"),
+        "markdown report should link each mismatch to its rule docs"
+    );
 
     Ok(())
 }
+
+#[test]
+fn identical_diagnostics_are_reported_once_with_occurrence_count() {
+    let mut chains = build_regression_chains();
+
+    // Same frontend issue reached through a second route
+    let mut repeated = chains[0].clone();
+    repeated.id = "chain-frontend-repeated".to_string();
+    // A different field at the same location must survive deduplication
+    let mut other_field = repeated.contracts[0].mismatches[0].clone();
+    other_field.path = "price".to_string();
+    repeated.contracts[0].mismatches.push(other_field);
+    chains.push(repeated);
+
+    deduplicate_diagnostics(&mut chains);
+
+    let first = &chains[0].contracts[0].mismatches;
+    assert_eq!(first.len(), 1);
+    assert_eq!(first[0].occurrences, 2);
+
+    let repeated = &chains[2].contracts[0].mismatches;
+    assert_eq!(repeated.len(), 1);
+    assert_eq!(repeated[0].path, "price");
+    assert_eq!(repeated[0].occurrences, 1);
+
+    // Unrelated diagnostics are untouched
+    assert_eq!(chains[1].contracts[0].mismatches[0].occurrences, 1);
+}
+
+#[test]
+fn contracts_left_without_mismatches_by_deduplication_are_no_longer_failing() {
+    let mut chains = build_regression_chains();
+    assert_eq!(chains[1].contracts[0].severity, Severity::Critical);
+
+    // The backend issue reached again through another route: its only mismatch is a duplicate
+    let mut repeated = chains[1].clone();
+    repeated.id = "chain-backend-repeated".to_string();
+    chains.push(repeated);
+
+    deduplicate_diagnostics(&mut chains);
+
+    assert_eq!(chains[1].contracts[0].severity, Severity::Critical);
+    let repeated = &chains[2].contracts[0];
+    assert!(repeated.mismatches.is_empty());
+    assert_eq!(repeated.severity, Severity::Info);
+}
@@ -1,10 +1,14 @@
 use std::fs;
 
 use anyhow::Result;
-use dc_cli::reporters::{JsonReporter, MarkdownReporter};
+use dc_cli::reporters::{
+    JsonReporter, JunitReporter, MarkdownReporter, SarifReporter, SummaryReporter,
+};
+use dc_cli::FailOn;
 use dc_core::models::{
-    ChainDirection, ChainType, Contract, DataChain, Link, LinkType, Location, NodeId,
-    SchemaReference, SchemaType, Severity,
+    AnalysisWarning, AnalysisWarningKind, BaseType, ChainDirection, ChainType, Contract, DataChain,
+    Diagnostic, Link, LinkType, Location, Mismatch, MismatchType, NodeId, SchemaReference,
+    SchemaType, Severity, SeverityLevel, TypeInfo,
 };
 
 fn dummy_location() -> Location {
@@ -117,3 +121,462 @@ fn markdown_reporter_includes_statistics_and_coverage() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn summary_reporter_prints_counts_and_verdict() {
+    let mut chains = vec![build_dummy_chain()];
+
+    let summary = SummaryReporter.render(&chains);
+    assert!(summary.contains("Chains: 1"));
+    assert!(summary.contains("Schemas linked: 2"));
+    assert!(summary.contains("Errors: 0, Warnings: 0, Info: 1"));
+    assert!(summary.contains("Result: PASS"));
+    assert!(
+        !summary.contains("Dummy Chain"),
+        "summary must not list individual chains"
+    );
+
+    chains[0].contracts[0].severity = Severity::Critical;
+    let summary = SummaryReporter.render(&chains);
+    assert!(summary.contains("Errors: 1, Warnings: 0, Info: 0"));
+    assert!(summary.contains("Result: FAIL"));
+}
+
+#[test]
+fn summary_reporter_verdict_counts_diagnostics_at_the_fail_on_level() {
+    let chains = vec![build_dummy_chain()];
+
+    let unparsed = vec![Diagnostic::parse_error(dummy_location(), "invalid syntax")];
+    let summary = SummaryReporter.render_report(&chains, &[], &unparsed, &[], FailOn::Error);
+    assert!(
+        summary.contains("Errors: 1, Warnings: 0, Info: 1"),
+        "{}",
+        summary
+    );
+    assert!(summary.contains("Result: FAIL"));
+
+    let missing_header = vec![Diagnostic::missing_header(
+        dummy_location(),
+        "Header 'X-Token' is required",
+    )];
+    let render =
+        |fail_on| SummaryReporter.render_report(&chains, &[], &missing_header, &[], fail_on);
+    assert!(render(FailOn::Error).contains("Errors: 0, Warnings: 1, Info: 1"));
+    assert!(render(FailOn::Error).contains("Result: PASS"));
+    assert!(render(FailOn::Warning).contains("Result: FAIL"));
+    assert!(render(FailOn::Never).contains("Result: PASS"));
+}
+
+#[test]
+fn summary_reporter_counts_analysis_warnings_by_kind() {
+    let chains = vec![build_dummy_chain()];
+    let warnings = vec![
+        AnalysisWarning::new(
+            AnalysisWarningKind::UnresolvedImport,
+            dummy_location(),
+            "Module 'app.gone' was not found in the project",
+        ),
+        AnalysisWarning::new(
+            AnalysisWarningKind::UnresolvedImport,
+            dummy_location(),
+            "Module '.missing' was not found in the project",
+        ),
+        AnalysisWarning::new(
+            AnalysisWarningKind::ModuleSkipped,
+            dummy_location(),
+            "Module was not analyzed: maximum depth exceeded",
+        ),
+    ];
+
+    let summary = SummaryReporter.render_report(&chains, &[], &[], &warnings, FailOn::Error);
+    assert!(
+        summary.contains("Analysis incomplete: 2 import(s) unresolved, 1 module(s) not analyzed\n"),
+        "{}",
+        summary
+    );
+    assert!(!SummaryReporter
+        .render(&chains)
+        .contains("Analysis incomplete"));
+}
+
+#[test]
+fn junit_reporter_maps_chains_and_diagnostics_to_test_cases() {
+    let passing = build_dummy_chain();
+    let mut failing = build_dummy_chain();
+    failing.id = "chain-2".to_string();
+    failing.name = "Create <Order>".to_string();
+    failing.contracts[0].severity = Severity::Critical;
+    let type_info = |base_type| TypeInfo {
+        base_type,
+        schema_ref: None,
+        constraints: Vec::new(),
+        optional: false,
+    };
+    failing.contracts[0].mismatches.push(Mismatch {
+        mismatch_type: MismatchType::TypeMismatch,
+        path: "price".to_string(),
+        expected: type_info(BaseType::Number),
+        actual: type_info(BaseType::String),
+        location: dummy_location(),
+        message: "Type mismatch: \"price\" is number & string".to_string(),
+        severity_level: SeverityLevel::High,
+        occurrences: 1,
+    });
+    let diagnostics = vec![Diagnostic::parse_error(
+        Location {
+            file: "broken.py".to_string(),
+            line: 3,
+            column: Some(1),
+        },
+        "unexpected token",
+    )];
+
+    let xml = JunitReporter.render_report(&[passing, failing], &[], &diagnostics, &[]);
+
+    assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"));
+    assert!(
+        xml.contains("<testsuites name=\"dc-verifier\" tests=\"3\" failures=\"1\" errors=\"1\" skipped=\"0\">"),
+        "{}",
+        xml
+    );
+    assert!(xml.contains(
+        "<testsuite name=\"broken.py\" tests=\"1\" failures=\"0\" errors=\"1\" skipped=\"0\">"
+    ));
+    assert!(xml.contains(
+        "<testsuite name=\"dummy.py\" tests=\"2\" failures=\"1\" errors=\"0\" skipped=\"0\">"
+    ));
+    assert!(xml.contains("<testcase name=\"Dummy Chain\" classname=\"chain-1\"/>"));
+    assert!(xml.contains("<testcase name=\"Create &lt;Order&gt;\" classname=\"chain-2\">"));
+    assert!(xml.contains(
+        "<failure type=\"type_mismatch\" message=\"Type mismatch: &quot;price&quot; is number &amp; string\">dummy.py:1: "
+    ));
+    assert!(xml.contains("<error type=\"parse-error\""));
+}
+
+/// The definitions of the SARIF 2.1.0 schema (sarif-schema-2.1.0.json) for the objects the
+/// SARIF reporter emits
+fn sarif_schema() -> serde_json::Value {
+    serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "type": "object",
+        "additionalProperties": false,
+        "properties": {
+            "$schema": { "type": "string", "format": "uri" },
+            "version": { "enum": ["2.1.0"] },
+            "runs": { "type": ["array", "null"], "items": { "$ref": "#/definitions/run" } }
+        },
+        "required": ["version", "runs"],
+        "definitions": {
+            "run": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "tool": { "$ref": "#/definitions/tool" },
+                    "invocations": {
+                        "type": "array",
+                        "items": { "$ref": "#/definitions/invocation" }
+                    },
+                    "results": { "type": ["array", "null"], "items": { "$ref": "#/definitions/result" } }
+                },
+                "required": ["tool"]
+            },
+            "tool": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": { "driver": { "$ref": "#/definitions/toolComponent" } },
+                "required": ["driver"]
+            },
+            "toolComponent": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "name": { "type": "string" },
+                    "version": { "type": "string" },
+                    "informationUri": { "type": "string", "format": "uri" },
+                    "rules": {
+                        "type": "array",
+                        "uniqueItems": true,
+                        "items": { "$ref": "#/definitions/reportingDescriptor" }
+                    }
+                },
+                "required": ["name"]
+            },
+            "invocation": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "executionSuccessful": { "type": "boolean" },
+                    "toolExecutionNotifications": {
+                        "type": "array",
+                        "items": { "$ref": "#/definitions/notification" }
+                    }
+                },
+                "required": ["executionSuccessful"]
+            },
+            "notification": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "locations": {
+                        "type": "array",
+                        "items": { "$ref": "#/definitions/location" }
+                    },
+                    "message": { "$ref": "#/definitions/message" },
+                    "level": { "enum": ["none", "note", "warning", "error"] },
+                    "descriptor": { "$ref": "#/definitions/reportingDescriptorReference" }
+                },
+                "required": ["message"]
+            },
+            "reportingDescriptorReference": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "id": { "type": "string" },
+                    "index": { "type": "integer", "minimum": -1 }
+                },
+                "anyOf": [{ "required": ["index"] }, { "required": ["id"] }]
+            },
+            "reportingDescriptor": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "id": { "type": "string" },
+                    "shortDescription": { "$ref": "#/definitions/multiformatMessageString" },
+                    "fullDescription": { "$ref": "#/definitions/multiformatMessageString" },
+                    "helpUri": { "type": "string", "format": "uri" }
+                },
+                "required": ["id"]
+            },
+            "multiformatMessageString": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "text": { "type": "string" },
+                    "markdown": { "type": "string" }
+                },
+                "required": ["text"]
+            },
+            "message": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "text": { "type": "string" },
+                    "id": { "type": "string" }
+                },
+                "anyOf": [{ "required": ["text"] }, { "required": ["id"] }]
+            },
+            "result": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "ruleId": { "type": "string" },
+                    "ruleIndex": { "type": "integer", "minimum": -1 },
+                    "level": { "enum": ["none", "note", "warning", "error"] },
+                    "message": { "$ref": "#/definitions/message" },
+                    "locations": {
+                        "type": "array",
+                        "items": { "$ref": "#/definitions/location" }
+                    }
+                },
+                "required": ["message"]
+            },
+            "location": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "physicalLocation": { "$ref": "#/definitions/physicalLocation" }
+                }
+            },
+            "physicalLocation": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "artifactLocation": { "$ref": "#/definitions/artifactLocation" },
+                    "region": { "$ref": "#/definitions/region" }
+                },
+                "anyOf": [{ "required": ["artifactLocation"] }]
+            },
+            "artifactLocation": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "uri": { "type": "string", "format": "uri-reference" },
+                    "uriBaseId": { "type": "string" }
+                }
+            },
+            "region": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "startLine": { "type": "integer", "minimum": 1 },
+                    "startColumn": { "type": "integer", "minimum": 1 },
+                    "endLine": { "type": "integer", "minimum": 1 },
+                    "endColumn": { "type": "integer", "minimum": 1 }
+                }
+            }
+        }
+    })
+}
+
+#[test]
+fn sarif_reporter_emits_one_result_per_violation() -> Result<()> {
+    let mut failing = build_dummy_chain();
+    failing.contracts[0].severity = Severity::Critical;
+    let type_info = |base_type| TypeInfo {
+        base_type,
+        schema_ref: None,
+        constraints: Vec::new(),
+        optional: false,
+    };
+    let mismatch = |path: &str, line| Mismatch {
+        mismatch_type: MismatchType::TypeMismatch,
+        path: path.to_string(),
+        expected: type_info(BaseType::Number),
+        actual: type_info(BaseType::String),
+        location: Location {
+            file: "src\\api\\orders.py".to_string(),
+            line,
+            column: Some(5),
+        },
+        message: format!("Type mismatch: \"{}\" is number & string", path),
+        severity_level: SeverityLevel::High,
+        occurrences: 1,
+    };
+    failing.contracts[0]
+        .mismatches
+        .extend([mismatch("price", 12), mismatch("quantity", 13)]);
+    let diagnostics = vec![Diagnostic::parse_error(
+        Location {
+            file: "broken.py".to_string(),
+            line: 3,
+            column: None,
+        },
+        "unexpected token",
+    )];
+
+    let sarif =
+        SarifReporter.render_report(&[build_dummy_chain(), failing], &[], &diagnostics, &[])?;
+    let log: serde_json::Value = serde_json::from_str(&sarif)?;
+
+    let schema = jsonschema::JSONSchema::compile(&sarif_schema()).expect("valid SARIF schema");
+    if let Err(errors) = schema.validate(&log) {
+        let errors: Vec<String> = errors.map(|error| error.to_string()).collect();
+        panic!(
+            "SARIF report doesn't match the schema: {:?}\n{}",
+            errors, sarif
+        );
+    }
+    assert_eq!(log["version"], "2.1.0");
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "dc-verifier");
+    let rule_ids: Vec<&str> = run["tool"]["driver"]["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|rule| rule["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(rule_ids, ["type_mismatch", "parse-error"]);
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0]["ruleId"], "type_mismatch");
+    assert_eq!(results[0]["ruleIndex"], 0);
+    assert_eq!(results[0]["level"], "error");
+    assert_eq!(
+        results[0]["message"]["text"],
+        "Type mismatch: \"price\" is number & string"
+    );
+    assert_eq!(
+        results[0]["locations"][0]["physicalLocation"],
+        serde_json::json!({
+            "artifactLocation": { "uri": "src/api/orders.py" },
+            "region": { "startLine": 12, "startColumn": 5 }
+        })
+    );
+    assert_eq!(results[1]["ruleIndex"], 0);
+    assert_eq!(results[2]["ruleId"], "parse-error");
+    assert_eq!(results[2]["ruleIndex"], 1);
+    assert_eq!(results[2]["level"], "error");
+    Ok(())
+}
+
+fn unresolved_import_warning() -> AnalysisWarning {
+    AnalysisWarning::new(
+        AnalysisWarningKind::UnresolvedImport,
+        Location {
+            file: "app/routes.py".to_string(),
+            line: 4,
+            column: None,
+        },
+        "Module 'app.missing' imported here was not found",
+    )
+}
+
+#[test]
+fn junit_reporter_reports_analysis_warnings_as_skipped_cases() {
+    let xml = JunitReporter.render_report(
+        &[build_dummy_chain()],
+        &[],
+        &[],
+        &[unresolved_import_warning()],
+    );
+
+    assert!(
+        xml.contains(
+            "<testsuites name=\"dc-verifier\" tests=\"2\" failures=\"0\" errors=\"0\" skipped=\"1\">"
+        ),
+        "{}",
+        xml
+    );
+    assert!(xml.contains(
+        "<testsuite name=\"app/routes.py\" tests=\"1\" failures=\"0\" errors=\"0\" skipped=\"1\">"
+    ));
+    assert!(xml.contains(
+        "<testcase name=\"unresolved-import app/routes.py:4\" classname=\"analysis-warnings\">\n      <skipped message=\"Module &apos;app.missing&apos; imported here was not found\"/>\n    </testcase>"
+    ), "{}", xml);
+}
+
+#[test]
+fn sarif_reporter_emits_analysis_warnings_as_notifications() -> Result<()> {
+    let timed_out = AnalysisWarning::new(
+        AnalysisWarningKind::TimedOut,
+        dummy_location(),
+        "2 of 5 chain(s) were not checked",
+    );
+
+    let sarif = SarifReporter.render_report(
+        &[build_dummy_chain()],
+        &[],
+        &[],
+        &[unresolved_import_warning()],
+    )?;
+    let log: serde_json::Value = serde_json::from_str(&sarif)?;
+
+    let schema = jsonschema::JSONSchema::compile(&sarif_schema()).expect("valid SARIF schema");
+    assert!(schema.is_valid(&log), "{}", sarif);
+    let invocation = &log["runs"][0]["invocations"][0];
+    assert_eq!(invocation["executionSuccessful"], true);
+    assert_eq!(
+        invocation["toolExecutionNotifications"],
+        serde_json::json!([{
+            "level": "warning",
+            "message": { "text": "Module 'app.missing' imported here was not found" },
+            "descriptor": { "id": "unresolved-import" },
+            "locations": [{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": "app/routes.py" },
+                    "region": { "startLine": 4 }
+                }
+            }]
+        }])
+    );
+    assert!(log["runs"][0]["results"].as_array().unwrap().is_empty());
+
+    let sarif = SarifReporter.render_report(&[], &[], &[], &[timed_out])?;
+    let log: serde_json::Value = serde_json::from_str(&sarif)?;
+    assert_eq!(
+        log["runs"][0]["invocations"][0]["executionSuccessful"],
+        false
+    );
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use std::fs;
+
+use anyhow::Result;
+
+use dc_cli::commands::schema_graph::execute_schema_graph;
+use dc_cli::GraphFormat;
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+#[test]
+fn schema_graph_follows_field_types() -> Result<()> {
+    let project = create_temp_project(&[
+        (
+            "backend/main.py",
+            r#"
+from typing import Optional
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class Category(BaseModel):
+    name: str
+    parent: Optional["Category"] = None
+
+class OrderItem(BaseModel):
+    sku: str
+    category: Category
+
+class Order(BaseModel):
+    items: list[OrderItem]
+
+@app.post("/orders", response_model=Order)
+def create_order(order: Order) -> Order:
+    return order
+
+@app.get("/categories/{name}", response_model=Category)
+def get_category(name: str) -> Category:
+    return Category(name=name)
+
+@app.get("/items/{sku}", response_model=OrderItem)
+def get_item(sku: str) -> OrderItem:
+    return OrderItem(sku=sku, category=Category(name="misc"))
+"#,
+        ),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+"#,
+        ),
+    ]);
+    let config_path = project.path().join("dc-verifier.toml");
+    let output = project.path().join("out/schemas.json");
+
+    execute_schema_graph(
+        config_path.to_str().unwrap(),
+        GraphFormat::Json,
+        Some(output.to_str().unwrap()),
+    )?;
+
+    let graph: serde_json::Value = serde_json::from_str(&fs::read_to_string(&output)?)?;
+    let edges: Vec<(&str, &str, &str)> = graph["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|edge| {
+            (
+                edge["from"].as_str().unwrap(),
+                edge["to"].as_str().unwrap(),
+                edge["field"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        edges,
+        vec![
+            ("Category", "Category", "parent"),
+            ("Order", "OrderItem", "items"),
+            ("OrderItem", "Category", "category"),
+        ]
+    );
+    assert_eq!(graph["nodes"][0]["schema_type"], "Pydantic");
+
+    let dot = project.path().join("out/schemas.dot");
+    execute_schema_graph(
+        config_path.to_str().unwrap(),
+        GraphFormat::Dot,
+        Some(dot.to_str().unwrap()),
+    )?;
+    assert!(fs::read_to_string(&dot)?.contains("\"Order\" -> \"OrderItem\" [label=\"items\"];"));
+    Ok(())
+}
@@ -0,0 +1,199 @@
+use std::fs;
+
+use anyhow::Result;
+
+use dc_cli::commands::schemas::{build_manifest, execute_schemas};
+use dc_cli::ManifestFormat;
+use dc_core::models::SchemaType;
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+fn project() -> tempfile::TempDir {
+    create_temp_project(&[
+        (
+            "backend/main.py",
+            r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+from sqlalchemy.orm import Mapped, declarative_base, mapped_column
+
+Base = declarative_base()
+app = FastAPI()
+
+class Item(Base):
+    __tablename__ = "items"
+    id: Mapped[int] = mapped_column(primary_key=True)
+    name: Mapped[str]
+
+class ItemRead(BaseModel):
+    model_config = {"from_attributes": True}
+    id: int
+    name: str
+
+class ItemCreate(BaseModel):
+    name: str
+    note: str | None = None
+
+@app.post("/items", response_model=ItemRead)
+def create_item(item: ItemCreate) -> ItemRead:
+    return ItemRead(id=1, name=item.name)
+"#,
+        ),
+        (
+            "frontend/src/api.ts",
+            r#"
+export interface ItemForm {
+    name: string;
+}
+
+export async function createItem(form: ItemForm): Promise<void> {
+    await fetch("/items", { method: "POST", body: JSON.stringify(form) });
+}
+"#,
+        ),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+
+[[adapters]]
+type = "typescript"
+src_paths = ["frontend/src"]
+"#,
+        ),
+    ])
+}
+
+#[test]
+fn manifest_lists_schemas_sorted_by_name_with_orm_links() -> Result<()> {
+    let project = project();
+    let config_path = project.path().join("dc-verifier.toml");
+    let manifest = build_manifest(config_path.to_str().unwrap())?;
+
+    let names: Vec<(&str, SchemaType)> = manifest
+        .iter()
+        .map(|entry| (entry.name.as_str(), entry.schema_type))
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            ("Item", SchemaType::OrmModel),
+            ("ItemCreate", SchemaType::Pydantic),
+            ("ItemForm", SchemaType::TypeScript),
+            ("ItemRead", SchemaType::Pydantic),
+        ]
+    );
+
+    let entry = |name: &str| manifest.iter().find(|entry| entry.name == name).unwrap();
+    assert_eq!(entry("Item").orm_links, vec!["ItemRead"]);
+    assert_eq!(entry("ItemRead").orm_links, vec!["Item"]);
+    assert!(entry("ItemCreate").orm_links.is_empty());
+    assert!(entry("ItemRead").location.file.ends_with("main.py"));
+    assert_eq!(entry("ItemRead").location.line, 14);
+
+    let fields: Vec<(&str, &str, bool)> = entry("ItemCreate")
+        .fields
+        .iter()
+        .map(|field| {
+            (
+                field.name.as_str(),
+                field.field_type.as_str(),
+                field.optional,
+            )
+        })
+        .collect();
+    assert_eq!(fields, vec![("name", "str", false), ("note", "str", true)]);
+    Ok(())
+}
+
+#[test]
+fn manifest_is_written_as_json() -> Result<()> {
+    let project = project();
+    let config_path = project.path().join("dc-verifier.toml");
+    let output = project.path().join("out/schemas.json");
+    execute_schemas(
+        config_path.to_str().unwrap(),
+        ManifestFormat::Json,
+        Some(output.to_str().unwrap()),
+    )?;
+
+    let manifest: serde_json::Value = serde_json::from_str(&fs::read_to_string(&output)?)?;
+    let entries = manifest.as_array().expect("manifest should be an array");
+    assert_eq!(entries.len(), 4);
+    assert_eq!(entries[0]["name"], "Item");
+    assert_eq!(entries[0]["schema_type"], "OrmModel");
+    assert_eq!(entries[0]["fields"][0]["name"], "id");
+    assert_eq!(entries[2]["fields"][0]["type"], "string");
+    // Absent links and JSON schemas are omitted
+    assert!(entries[1].get("orm_links").is_none());
+    assert!(entries[1].get("json_schema").is_none());
+    assert_eq!(entries[1]["content_hash"].as_str().map(str::len), Some(64));
+    Ok(())
+}
+
+#[test]
+fn content_hashes_ignore_where_schemas_are_declared() -> Result<()> {
+    let project = project();
+    let config_path = project.path().join("dc-verifier.toml");
+    let hashes = || -> Result<Vec<(String, String)>> {
+        Ok(build_manifest(config_path.to_str().unwrap())?
+            .into_iter()
+            .map(|entry| (entry.name, entry.content_hash))
+            .collect())
+    };
+    let before = hashes()?;
+
+    // Every schema moves down; only `ItemRead` changes
+    let main = project.path().join("backend/main.py");
+    let source = fs::read_to_string(&main)?
+        .replacen("\n", "\n# Items\n\n", 1)
+        .replace(
+            "    id: int\n    name: str",
+            "    id: int\n    name: str\n    tags: list[str]",
+        );
+    fs::write(&main, source)?;
+    let after = hashes()?;
+
+    let changed: Vec<&str> = before
+        .iter()
+        .zip(&after)
+        .filter(|(old, new)| old != new)
+        .map(|(old, _)| old.0.as_str())
+        .collect();
+    assert_eq!(changed, vec!["ItemRead"]);
+    Ok(())
+}
+
+#[test]
+fn report_only_formats_are_rejected_by_argument_parsing() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_dc-verifier"))
+        .args(["schemas", "--format", "sarif"])
+        .output()
+        .expect("failed to run dc-verifier");
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("invalid value 'sarif'"), "{}", stderr);
+    assert!(
+        stderr.contains("[possible values: json, markdown]"),
+        "{}",
+        stderr
+    );
+}
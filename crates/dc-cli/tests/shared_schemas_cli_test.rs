@@ -0,0 +1,138 @@
+use std::fs;
+
+use anyhow::Result;
+
+use dc_cli::commands::check::{execute_check_with_options, CheckOptions};
+use dc_cli::ReportFormat;
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+#[test]
+fn routes_and_calls_must_agree_on_shared_schemas() -> Result<()> {
+    let project = create_temp_project(&[
+        (
+            "shared/user.ts",
+            r#"
+export interface CreateUser {
+  name: string;
+  email: string;
+}
+
+export interface CreateTeam {
+  title: string;
+}
+"#,
+        ),
+        (
+            "backend/src/users.controller.ts",
+            r#"
+import { Body, Controller, Post } from "@nestjs/common";
+import { CreateUser } from "../../shared/user";
+
+export interface LegacyTeam {
+  title: string;
+}
+
+export interface InviteUser {
+  email: string;
+}
+
+@Controller("users")
+export class UsersController {
+  @Post()
+  create(@Body() user: CreateUser) {
+    return user;
+  }
+
+  @Post("teams")
+  createTeam(@Body() team: LegacyTeam) {
+    return team;
+  }
+
+  @Post("invites")
+  invite(@Body() invite: InviteUser) {
+    return invite;
+  }
+}
+"#,
+        ),
+        (
+            "frontend/src/api.ts",
+            r#"
+import { CreateUser, CreateTeam } from "../../shared/user";
+
+interface InviteUser {
+  email: string;
+  role: string;
+}
+
+export async function createUser(user: CreateUser) {
+  await fetch("/users", { method: "POST", body: JSON.stringify(user) });
+}
+
+export async function createTeam(team: CreateTeam) {
+  await fetch("/users/teams", { method: "POST", body: JSON.stringify(team) });
+}
+
+export async function invite(invite: InviteUser) {
+  await fetch("/users/invites", { method: "POST", body: JSON.stringify(invite) });
+}
+"#,
+        ),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "nestjs"
+src_paths = ["backend/src", "shared"]
+
+[[adapters]]
+type = "typescript"
+src_paths = ["frontend/src", "shared"]
+"#,
+        ),
+    ]);
+
+    let config_path = project.path().join("dc-verifier.toml");
+    execute_check_with_options(
+        config_path.to_str().unwrap(),
+        Some(ReportFormat::Json),
+        &CheckOptions::default(),
+    )?;
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.path().join("report.json"))?)?;
+    let messages: Vec<&str> = report["diagnostics"]
+        .as_array()
+        .expect("shared schema issues should be reported")
+        .iter()
+        .filter(|diagnostic| diagnostic["rule_id"] == "shared-schema")
+        .map(|diagnostic| diagnostic["message"].as_str().unwrap())
+        .collect();
+
+    // `CreateUser` is the same declaration on both sides: nothing to report
+    assert_eq!(messages.len(), 2, "{:?}", messages);
+    assert!(messages[0].starts_with(
+        "POST /users/teams request schema 'CreateTeam' is shared with the backend, \
+         but the route uses 'LegacyTeam'"
+    ));
+    assert!(messages[1].starts_with(
+        "POST /users/invites request schema 'InviteUser' diverges from the backend definition"
+    ));
+    assert!(messages[1].ends_with("field 'role' is only on the frontend"));
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::Result;
+
+use dc_cli::commands::check::{execute_check_with_options, CheckOptions};
+use dc_cli::{FailOn, ReportFormat};
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .status()
+        .expect("failed to run git");
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+/// A committed project with two unparseable router modules
+fn committed_project() -> tempfile::TempDir {
+    let project = create_temp_project(&[
+        (
+            "backend/main.py",
+            r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+from routers import legacy, reports
+
+app = FastAPI()
+app.include_router(legacy.router)
+app.include_router(reports.router)
+
+class Item(BaseModel):
+    name: str
+
+@app.post("/items", response_model=Item)
+def create_item(item: Item) -> Item:
+    return item
+"#,
+        ),
+        ("backend/routers/legacy.py", "print 'python 2 only'\n"),
+        ("backend/routers/reports.py", "print 'python 2 only'\n"),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+"#,
+        ),
+    ]);
+    git(project.path(), &["init", "--quiet"]);
+    git(project.path(), &["add", "-A"]);
+    git(project.path(), &["commit", "--quiet", "-m", "initial"]);
+    project
+}
+
+fn since(git_ref: &str) -> CheckOptions {
+    CheckOptions {
+        since: Some(git_ref.to_string()),
+        fail_on: FailOn::Never,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn only_issues_in_changed_files_are_reported() -> Result<()> {
+    let project = committed_project();
+    fs::write(
+        project.path().join("backend/routers/reports.py"),
+        "print 'still python 2'\n",
+    )?;
+    let config_path = project.path().join("dc-verifier.toml");
+
+    execute_check_with_options(
+        config_path.to_str().unwrap(),
+        Some(ReportFormat::Json),
+        &since("HEAD"),
+    )?;
+
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.path().join("report.json"))?)?;
+    let diagnostics = report["diagnostics"].as_array().unwrap();
+    assert_eq!(diagnostics.len(), 1, "{:?}", diagnostics);
+    assert!(diagnostics[0]["location"]["file"]
+        .as_str()
+        .unwrap()
+        .ends_with("reports.py"));
+    // The chains of the unchanged main module are out of scope
+    assert_eq!(report["summary"]["total_chains"], 0);
+    Ok(())
+}
+
+#[test]
+fn unknown_git_ref_is_an_error() {
+    let project = committed_project();
+    let config_path = project.path().join("dc-verifier.toml");
+
+    let err = execute_check_with_options(
+        config_path.to_str().unwrap(),
+        Some(ReportFormat::Json),
+        &since("no-such-branch"),
+    )
+    .expect_err("an unknown ref should fail");
+
+    assert!(
+        err.to_string()
+            .contains("Git ref 'no-such-branch' does not exist"),
+        "{}",
+        err
+    );
+}
@@ -0,0 +1,85 @@
+use std::fs;
+
+use anyhow::Result;
+
+use dc_cli::commands::check::{execute_check_with_options, CheckOptions};
+use dc_cli::ReportFormat;
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+fn project_with_unresolved_type() -> tempfile::TempDir {
+    create_temp_project(&[
+        (
+            "frontend/src/api.ts",
+            r#"
+import axios from "axios";
+import { UserCreate, User } from "@shared/types";
+
+export async function createUser(user: UserCreate): Promise<User> {
+  const response = await axios.post<User>("/api/users", user);
+  return response.data;
+}
+"#,
+        ),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "typescript"
+src_paths = ["frontend/src"]
+"#,
+        ),
+    ])
+}
+
+fn run_check(project: &tempfile::TempDir, strict_types: bool) -> Result<serde_json::Value> {
+    let config_path = project.path().join("dc-verifier.toml");
+    execute_check_with_options(
+        config_path.to_str().unwrap(),
+        Some(ReportFormat::Json),
+        &CheckOptions {
+            strict_types,
+            ..Default::default()
+        },
+    )?;
+    let report = fs::read_to_string(project.path().join("report.json"))?;
+    Ok(serde_json::from_str(&report)?)
+}
+
+#[test]
+fn unresolved_frontend_types_are_reported_only_in_strict_mode() -> Result<()> {
+    let project = project_with_unresolved_type();
+
+    let report = run_check(&project, false)?;
+    assert!(report["diagnostics"]
+        .as_array()
+        .is_none_or(|d| d.is_empty()));
+
+    let report = run_check(&project, true)?;
+    let diagnostics = report["diagnostics"]
+        .as_array()
+        .expect("diagnostics should be reported");
+    assert_eq!(diagnostics.len(), 1, "{:#?}", diagnostics);
+    assert_eq!(diagnostics[0]["rule_id"], "unresolved-type");
+    assert_eq!(diagnostics[0]["severity"], "Warning");
+    assert!(diagnostics[0]["location"]["file"]
+        .as_str()
+        .unwrap()
+        .ends_with("api.ts"));
+    Ok(())
+}
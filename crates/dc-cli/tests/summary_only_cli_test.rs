@@ -0,0 +1,161 @@
+use std::fs;
+use std::process::Command;
+
+use dc_cli::commands::check::VERIFICATION_FAILED_EXIT_CODE;
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+fn project(record_total: &str) -> tempfile::TempDir {
+    create_temp_project(&[
+        (
+            "backend/main.py",
+            &format!(
+                r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class OrderCreate(BaseModel):
+    total: str
+
+class OrderRecord(BaseModel):
+    total: {record_total}
+
+def save_order(record: OrderRecord) -> OrderRecord:
+    return record
+
+@app.post("/orders", response_model=OrderRecord)
+def create_order(order: OrderCreate) -> OrderRecord:
+    return save_order(order)
+"#
+            ),
+        ),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+"#,
+        ),
+    ])
+}
+
+fn run_check(project: &tempfile::TempDir, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_dc-verifier"))
+        .current_dir(project.path())
+        .arg("--quiet")
+        .arg("check")
+        .args(args)
+        .output()
+        .expect("failed to run dc-verifier")
+}
+
+fn run_summary_only(project: &tempfile::TempDir, extra_args: &[&str]) -> std::process::Output {
+    let mut args = vec!["--summary-only"];
+    args.extend_from_slice(extra_args);
+    run_check(project, &args)
+}
+
+#[test]
+fn failed_summary_verdict_exits_nonzero() {
+    // `str` is passed on to a model expecting a `float`: a critical type mismatch
+    let project = project("float");
+
+    let output = run_summary_only(&project, &[]);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Result: FAIL"), "stdout: {}", stdout);
+    assert_eq!(output.status.code(), Some(VERIFICATION_FAILED_EXIT_CODE));
+    assert!(project.path().join("report.json").exists());
+}
+
+#[test]
+fn passed_summary_verdict_exits_zero() {
+    let project = project("str");
+
+    let output = run_summary_only(&project, &[]);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Result: PASS"), "stdout: {}", stdout);
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn fix_summary_is_not_printed_with_summary_only() {
+    let project = project("str");
+    fs::write(
+        project.path().join("backend/main.py"),
+        r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class Item(BaseModel):
+    name: str
+
+@app.post("/items")
+def create_item(item: Item) -> Item:
+    return item
+"#,
+    )
+    .unwrap();
+
+    let output = run_summary_only(&project, &["--fix"]);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        fs::read_to_string(project.path().join("backend/main.py"))
+            .unwrap()
+            .contains("response_model=Item"),
+        "the fix should still be applied"
+    );
+    assert!(stdout.starts_with("Chains: "), "stdout: {}", stdout);
+}
+
+#[test]
+fn full_report_run_uses_the_same_exit_code() {
+    let project = project("float");
+
+    let output = run_check(&project, &[]);
+
+    assert_eq!(output.status.code(), Some(VERIFICATION_FAILED_EXIT_CODE));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Verification failed: 1 error(s)"),
+        "stderr: {}",
+        stderr
+    );
+}
+
+#[test]
+fn fail_on_never_exits_zero_with_errors() {
+    let project = project("float");
+
+    let output = run_summary_only(&project, &["--fail-on", "never"]);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Result: PASS"), "stdout: {}", stdout);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        run_check(&project, &["--fail-on", "never"]).status.code(),
+        Some(0)
+    );
+}
@@ -0,0 +1,155 @@
+use std::fs;
+use std::process::Command;
+use std::time::Duration;
+
+use dc_cli::commands::check::{
+    execute_check_with_options, AnalysisTimedOut, CheckOptions, TIMED_OUT_EXIT_CODE,
+};
+use dc_cli::ReportFormat;
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+fn project() -> tempfile::TempDir {
+    create_temp_project(&[
+        (
+            "backend/main.py",
+            r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class Item(BaseModel):
+    name: str
+
+@app.post("/items", response_model=Item)
+def create_item(item: Item) -> Item:
+    return item
+"#,
+        ),
+        (
+            "frontend/src/api.ts",
+            "export const createItem = () => fetch('/items', { method: 'POST' });\n",
+        ),
+        (
+            "dc-verifier.toml",
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "fastapi"
+app_path = "backend/main.py"
+
+[[adapters]]
+type = "typescript"
+src_paths = ["frontend/src"]
+"#,
+        ),
+    ])
+}
+
+fn timed_out_messages(report: &serde_json::Value) -> Vec<String> {
+    report["analysis_warnings"]
+        .as_array()
+        .map(|warnings| {
+            warnings
+                .iter()
+                .filter(|warning| warning["kind"] == "timed-out")
+                .map(|warning| warning["message"].as_str().unwrap().to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn expired_timeout_writes_a_partial_report_and_fails_with_timed_out() {
+    let project = project();
+    let config_path = project.path().join("dc-verifier.toml");
+    let err = execute_check_with_options(
+        config_path.to_str().unwrap(),
+        Some(ReportFormat::Json),
+        &CheckOptions {
+            summary_only: true,
+            timeout: Some(Duration::ZERO),
+            ..Default::default()
+        },
+    )
+    .expect_err("an expired timeout should fail the check");
+    assert_eq!(
+        err.downcast_ref::<AnalysisTimedOut>(),
+        Some(&AnalysisTimedOut {
+            timeout: Duration::ZERO
+        })
+    );
+
+    let report: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(project.path().join("report.json"))
+            .expect("the partial report should be written"),
+    )
+    .unwrap();
+    assert_eq!(
+        timed_out_messages(&report),
+        vec![
+            "Adapter 1 (fastapi) was not analyzed",
+            "Adapter 2 (typescript) was not analyzed",
+        ]
+    );
+}
+
+#[test]
+fn checks_finishing_in_time_are_not_affected_by_the_timeout() {
+    let project = project();
+    let config_path = project.path().join("dc-verifier.toml");
+    execute_check_with_options(
+        config_path.to_str().unwrap(),
+        Some(ReportFormat::Json),
+        &CheckOptions {
+            summary_only: true,
+            timeout: Some(Duration::from_secs(600)),
+            ..Default::default()
+        },
+    )
+    .expect("the check should finish in time");
+
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.path().join("report.json")).unwrap())
+            .unwrap();
+    assert!(timed_out_messages(&report).is_empty());
+}
+
+#[test]
+fn timed_out_check_exits_with_its_own_code() {
+    let project = project();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_dc-verifier"))
+        .current_dir(project.path())
+        .arg("--quiet")
+        .arg("check")
+        .arg("--summary-only")
+        .arg("--timeout")
+        .arg("0")
+        .output()
+        .expect("failed to run dc-verifier");
+
+    assert_eq!(output.status.code(), Some(TIMED_OUT_EXIT_CODE));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Analysis timed out after 0s; the report holds partial results"),
+        "stderr: {}",
+        stderr
+    );
+    assert!(project.path().join("report.json").exists());
+}
@@ -0,0 +1,55 @@
+use std::fs;
+
+use dc_cli::config::Config;
+
+fn load_with_table(table: &str) -> anyhow::Result<Config> {
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    fs::create_dir_all(dir.path().join("src")).unwrap();
+    let config_path = dir.path().join("dc-verifier.toml");
+    fs::write(
+        &config_path,
+        format!(
+            r#"project_name = "demo"
+
+[output]
+format = "json"
+path = "report.json"
+
+[[adapters]]
+type = "typescript"
+src_paths = ["src"]
+
+[type_compatibility]
+{table}
+"#
+        ),
+    )
+    .unwrap();
+    Config::load(config_path.to_str().unwrap(), None)
+}
+
+#[test]
+fn custom_equivalences_are_merged_with_defaults() {
+    let config = load_with_table(r#"Money = ["string", "str"]"#).expect("config should load");
+    let compatibility = config.type_compatibility();
+
+    assert!(compatibility.declared_equivalent("Money", "string"));
+    assert!(compatibility.declared_equivalent("str", "Money"));
+    assert!(compatibility.declared_equivalent("Integer", "int"));
+}
+
+#[test]
+fn invalid_tables_are_rejected_at_load_time() {
+    for (table, expected) in [
+        ("Money = []", "list of equivalent types cannot be empty"),
+        (r#"Money = ["big decimal"]"#, "whitespace is not allowed"),
+        (
+            r#"Money = ["money"]"#,
+            "cannot be declared equivalent to itself",
+        ),
+        (r#"Money = [""]"#, "type name cannot be empty"),
+    ] {
+        let err = load_with_table(table).expect_err(table).to_string();
+        assert!(err.contains(expected), "{}: {}", table, err);
+    }
+}
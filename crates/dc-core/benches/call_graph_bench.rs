@@ -24,6 +24,7 @@ fn build_trivial_graph() -> CallGraph {
         },
         request_schema: None,
         response_schema: None,
+        metadata: std::collections::HashMap::new(),
     });
     // Reverse edge is not required, but we return index
     // so benchmark has something to measure.
@@ -0,0 +1,94 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use dc_core::call_graph::CallGraphBuilder;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Writes a FastAPI project of `modules` router modules, each declaring three models
+/// (two Pydantic, one ORM) and two routes, all included from `main.py`
+///
+/// Returns the project's Python files, entry point first.
+fn synthetic_project(root: &Path, modules: usize) -> Vec<PathBuf> {
+    let mut main = String::from("from fastapi import FastAPI\n");
+    for i in 0..modules {
+        main.push_str(&format!(
+            "from routers.module_{i} import router as router_{i}\n"
+        ));
+    }
+    main.push_str("\napp = FastAPI()\n");
+    for i in 0..modules {
+        main.push_str(&format!("app.include_router(router_{i})\n"));
+    }
+    fs::create_dir_all(root.join("routers")).unwrap();
+    fs::write(root.join("main.py"), main).unwrap();
+    fs::write(root.join("routers/__init__.py"), "").unwrap();
+
+    for i in 0..modules {
+        let module = format!(
+            r#"
+from fastapi import APIRouter
+from pydantic import BaseModel
+from sqlalchemy.orm import Mapped, declarative_base, mapped_column
+
+Base = declarative_base()
+router = APIRouter()
+
+class Item{i}(Base):
+    __tablename__ = "items_{i}"
+    id: Mapped[int] = mapped_column(primary_key=True)
+    name: Mapped[str]
+
+class Item{i}Create(BaseModel):
+    name: str
+
+class Item{i}Read(Item{i}Create):
+    id: int
+
+@router.post("/items_{i}", response_model=Item{i}Read)
+def create_item_{i}(item: Item{i}Create) -> Item{i}Read:
+    return Item{i}Read(id=1, name=item.name)
+
+@router.get("/items_{i}/{{item_id}}", response_model=Item{i}Read)
+def get_item_{i}(item_id: int) -> Item{i}Read:
+    return Item{i}Read(id=item_id, name="item")
+"#
+        );
+        fs::write(root.join(format!("routers/module_{i}.py")), module).unwrap();
+    }
+
+    std::iter::once(root.join("main.py"))
+        .chain((0..modules).map(|i| root.join(format!("routers/module_{i}.py"))))
+        .collect()
+}
+
+/// Streams the project files through one builder
+fn build(files: &[PathBuf]) -> CallGraphBuilder {
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_files(files).unwrap();
+    builder
+}
+
+fn bench_project_graph(c: &mut Criterion) {
+    let mut group = c.benchmark_group("build_from_files");
+    for modules in [10, 50] {
+        let project = tempfile::tempdir().unwrap();
+        let files = synthetic_project(project.path(), modules);
+
+        // Every file is parsed once, however many classes and passes read it (class nodes
+        // used to re-read their file up to three times each)
+        let builder = build(&files);
+        eprintln!(
+            "{} files, {} classes: {} parses",
+            files.len(),
+            modules * 3,
+            builder.parse_count()
+        );
+
+        group.bench_with_input(BenchmarkId::from_parameter(modules), &files, |b, files| {
+            b.iter(|| black_box(build(files).into_graph()));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_project_graph);
+criterion_main!(benches);
@@ -1,9 +1,10 @@
+use crate::analyzers::populates_by_name;
 use crate::call_graph::{CallGraph, CallNode, Parameter};
 use crate::data_flow::DataFlowTracker;
 use crate::models::{
-    BaseType, ChainDirection, ChainType, Contract, DataChain, FieldMismatch, Link, LinkType,
-    Location, NodeId, PydanticFieldInfo, SchemaReference, SchemaType, Severity, TransformationType,
-    TypeInfo, ZodField, ZodUsage,
+    BaseType, ChainDirection, ChainType, Contract, DataChain, FieldConstraint, FieldMismatch, Link,
+    LinkType, Location, NodeId, PydanticFieldInfo, SchemaReference, SchemaType, Severity,
+    TransformationType, TypeInfo, ZodField, ZodUsage,
 };
 use crate::openapi::OpenAPILinker;
 use anyhow::{anyhow, bail, Result};
@@ -328,7 +329,11 @@ impl<'a> ChainBuilder<'a> {
             } = pydantic_node
             {
                 let pydantic_fields = Self::extract_pydantic_fields(pydantic_schema_ref);
-                let mismatches = Self::compare_zod_pydantic_fields(&zod_fields, &pydantic_fields);
+                let mismatches = Self::compare_zod_pydantic_fields(
+                    &zod_fields,
+                    &pydantic_fields,
+                    populates_by_name(pydantic_schema_ref),
+                );
 
                 let contracts = self.create_contracts_with_mismatches(&links, mismatches.clone());
 
@@ -537,6 +542,8 @@ impl<'a> ChainBuilder<'a> {
                 FromOrm | FromAttributes | OrmToPydantic => (Some(OrmModel), Some(Pydantic)),
                 ToDict | ToJson | Serialize => (Some(Pydantic), None),
                 PydanticToOrm => (Some(Pydantic), Some(OrmModel)),
+                // Project-specific conversions carry no expectations about either side
+                Custom(_) => (None, None),
             };
 
         let mut reasons = Vec::new();
@@ -597,6 +604,7 @@ impl<'a> ChainBuilder<'a> {
                 reasons.join("; ")
             ),
             severity_level: crate::models::SeverityLevel::Medium,
+            occurrences: 1,
         })
     }
 
@@ -834,19 +842,29 @@ impl<'a> ChainBuilder<'a> {
     }
 
     /// Compares Zod fields with Pydantic fields and returns list of mismatches.
+    ///
+    /// With `populate_by_name`, aliased Pydantic fields may also be sent under their Python
+    /// name.
     fn compare_zod_pydantic_fields(
         zod_fields: &[ZodField],
         pydantic_fields: &[PydanticFieldInfo],
+        populate_by_name: bool,
     ) -> Vec<FieldMismatch> {
         let mut mismatches = Vec::new();
 
-        // Create maps for quick lookup
+        // Create maps for quick lookup; the Zod schema sends the request, so Pydantic
+        // fields go by the name they are read from
         let zod_map: HashMap<&str, &ZodField> =
             zod_fields.iter().map(|f| (f.name.as_str(), f)).collect();
-        let pydantic_map: HashMap<&str, &PydanticFieldInfo> = pydantic_fields
+        let mut pydantic_map: HashMap<&str, &PydanticFieldInfo> = pydantic_fields
             .iter()
-            .map(|f| (f.name.as_str(), f))
+            .map(|f| (f.input_name(), f))
             .collect();
+        if populate_by_name {
+            for field in pydantic_fields {
+                pydantic_map.entry(field.name.as_str()).or_insert(field);
+            }
+        }
 
         // Check all Zod fields
         for zod_field in zod_fields {
@@ -855,7 +873,8 @@ impl<'a> ChainBuilder<'a> {
                 if !Self::types_compatible_zod_pydantic(
                     &zod_field.type_name,
                     &pydantic_field.type_name,
-                ) {
+                ) && !Self::lazy_ref_compatible(zod_field, &pydantic_field.type_name)
+                {
                     mismatches.push(FieldMismatch {
                         field_name: zod_field.name.clone(),
                         zod_type: zod_field.type_name.clone(),
@@ -864,8 +883,9 @@ impl<'a> ChainBuilder<'a> {
                     });
                 }
 
-                // Check optionality
-                if zod_field.optional != pydantic_field.optional {
+                // Check optionality: a Pydantic field with a default may be omitted too
+                let pydantic_optional = !pydantic_field.is_required();
+                if zod_field.optional != pydantic_optional {
                     mismatches.push(FieldMismatch {
                         field_name: zod_field.name.clone(),
                         zod_type: format!(
@@ -874,11 +894,63 @@ impl<'a> ChainBuilder<'a> {
                         ),
                         pydantic_type: format!(
                             "{} (optional: {})",
-                            pydantic_field.type_name, pydantic_field.optional
+                            pydantic_field.type_name, pydantic_optional
                         ),
                         reason: "Optionality mismatch".to_string(),
                     });
                 }
+
+                // Check array item count bounds (an unbounded side counts as a difference)
+                if zod_field.type_name == "array"
+                    && Self::types_compatible_zod_pydantic("array", &pydantic_field.type_name)
+                {
+                    let pydantic_bounds = Self::pydantic_array_bounds(pydantic_field);
+                    if (zod_field.min_items, zod_field.max_items) != pydantic_bounds {
+                        mismatches.push(FieldMismatch {
+                            field_name: zod_field.name.clone(),
+                            zod_type: format!(
+                                "array (items: {:?}..{:?})",
+                                zod_field.min_items, zod_field.max_items
+                            ),
+                            pydantic_type: format!(
+                                "{} (items: {:?}..{:?})",
+                                pydantic_field.type_name, pydantic_bounds.0, pydantic_bounds.1
+                            ),
+                            reason: "Array length mismatch".to_string(),
+                        });
+                    }
+
+                    // `null` is not an empty list: Pydantic rejects it unless the list is
+                    // `Optional`
+                    if zod_field.nullable && !pydantic_field.nullable {
+                        mismatches.push(FieldMismatch {
+                            field_name: zod_field.name.clone(),
+                            zod_type: "array (nullable)".to_string(),
+                            pydantic_type: format!("{} (not nullable)", pydantic_field.type_name),
+                            reason: "Nullability mismatch".to_string(),
+                        });
+                    }
+
+                    if let (Some(zod_values), Some(pydantic_values)) =
+                        (&zod_field.item_values, &pydantic_field.item_values)
+                    {
+                        if zod_values
+                            .iter()
+                            .any(|value| !pydantic_values.contains(value))
+                        {
+                            mismatches.push(FieldMismatch {
+                                field_name: zod_field.name.clone(),
+                                zod_type: format!("array (items: {})", zod_values.join(" | ")),
+                                pydantic_type: format!(
+                                    "{} (items: {})",
+                                    pydantic_field.type_name,
+                                    pydantic_values.join(" | ")
+                                ),
+                                reason: "Array item values mismatch".to_string(),
+                            });
+                        }
+                    }
+                }
             } else {
                 // Field exists in Zod but not in Pydantic
                 mismatches.push(FieldMismatch {
@@ -892,9 +964,11 @@ impl<'a> ChainBuilder<'a> {
 
         // Fields that exist in Pydantic but not in Zod
         for pydantic_field in pydantic_fields {
-            if !zod_map.contains_key(pydantic_field.name.as_str()) {
+            let sent = zod_map.contains_key(pydantic_field.input_name())
+                || (populate_by_name && zod_map.contains_key(pydantic_field.name.as_str()));
+            if !sent {
                 mismatches.push(FieldMismatch {
-                    field_name: pydantic_field.name.clone(),
+                    field_name: pydantic_field.input_name().to_string(),
                     zod_type: "missing".to_string(),
                     pydantic_type: pydantic_field.type_name.clone(),
                     reason: "Field missing in Zod".to_string(),
@@ -905,6 +979,40 @@ impl<'a> ChainBuilder<'a> {
         mismatches
     }
 
+    /// Item count bounds of a Pydantic list field (`conlist(...)`, `Field(min_length=..)`)
+    fn pydantic_array_bounds(field: &PydanticFieldInfo) -> (Option<usize>, Option<usize>) {
+        let mut bounds = (None, None);
+        for constraint in &field.constraints {
+            match constraint {
+                FieldConstraint::MinLength(min) => bounds.0 = Some(*min),
+                FieldConstraint::MaxLength(max) => bounds.1 = Some(*max),
+                _ => {}
+            }
+        }
+        bounds
+    }
+
+    /// Checks if a `z.lazy(() => Schema)` field points to the model used by the Pydantic field
+    /// (`Node`, `Optional[Node]`, `List[Node]`), ignoring common `Schema`/`Zod` suffixes.
+    fn lazy_ref_compatible(zod_field: &ZodField, pydantic_type: &str) -> bool {
+        let Some(schema_ref) = &zod_field.schema_ref else {
+            return false;
+        };
+        let lower = schema_ref.to_lowercase();
+        let model = lower
+            .trim_end_matches("schema")
+            .trim_end_matches("zod")
+            .trim_end_matches('_');
+        if model.is_empty() {
+            return false;
+        }
+
+        pydantic_type
+            .to_lowercase()
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .any(|part| part == model)
+    }
+
     /// Checks if Zod type is compatible with Pydantic type.
     fn types_compatible_zod_pydantic(zod_type: &str, pydantic_type: &str) -> bool {
         let zod_normalized = zod_type.to_lowercase();
@@ -1126,9 +1234,18 @@ impl<'a> ChainBuilder<'a> {
             .ok_or_else(|| anyhow!("Node not found: {:?}", start))?;
 
         Ok(match node {
-            CallNode::Route { path, method, .. } => {
+            CallNode::Route {
+                path,
+                method,
+                metadata,
+                ..
+            } => {
                 let method_str = format!("{:?}", method).to_uppercase();
-                format!("{} {}", method_str, path)
+                // Routes of multi-app projects are namespaced by their service
+                match metadata.get("service") {
+                    Some(service) => format!("[{}] {} {}", service, method_str, path),
+                    None => format!("{} {}", method_str, path),
+                }
             }
             CallNode::Function { name, .. } => format!("Function {}", name),
             CallNode::Class { name, .. } => format!("Class {}", name),
@@ -0,0 +1,137 @@
+use crate::analyzers::{method_name, EndpointAnalyzer, HttpEndpoint};
+use crate::models::Diagnostic;
+
+/// Warnings for frontend calls sending query parameter values their backend route rejects
+///
+/// Query values are strings: Pydantic coerces them to the `int`, `float` or `bool` a route
+/// reads in lax mode, when they look like one (`"2"`, `"yes"`). A literal value that
+/// doesn't is reported, and so is any value sent for a parameter validated in strict mode.
+/// Values computed at runtime are only checked against strict parameters.
+pub fn query_coercion_issues(
+    analyzer: &EndpointAnalyzer,
+    calls: &[HttpEndpoint],
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for call in calls {
+        if call.sent_query.is_empty() {
+            continue;
+        }
+        let Some(route) = analyzer.resolve_call(call) else {
+            continue;
+        };
+        for parameter in &route.query_parameters {
+            let Some((_, value)) = call
+                .sent_query
+                .iter()
+                .find(|(name, _)| *name == parameter.name)
+            else {
+                continue;
+            };
+            let type_name = parameter.param_type.python_name();
+            let problem = match value {
+                _ if parameter.strict => format!(
+                    "sends the query parameter `{}` as a string, which the route's strict `{}` \
+                     rejects",
+                    parameter.name, type_name
+                ),
+                Some(value) if !parameter.param_type.accepts(value) => format!(
+                    "sends `{}={}`, which the route can't coerce to `{}`",
+                    parameter.name, value, type_name
+                ),
+                _ => continue,
+            };
+            diagnostics.push(Diagnostic::query_coercion(
+                call.location.clone(),
+                format!(
+                    "Call to {} {} {}",
+                    method_name(route.method),
+                    route.path,
+                    problem
+                ),
+            ));
+        }
+    }
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::call_graph::HttpMethod;
+    use crate::models::{Location, QueryParamType, QueryParameter};
+
+    fn endpoint(path: &str, file: &str) -> HttpEndpoint {
+        HttpEndpoint {
+            method: HttpMethod::Get,
+            path: path.to_string(),
+            location: Location {
+                file: file.to_string(),
+                line: 1,
+                column: None,
+            },
+            deprecated: false,
+            request_schema: None,
+            response_schema: None,
+            response_envelope: None,
+            query_parameters: Vec::new(),
+            sent_query: Vec::new(),
+            required_headers: Vec::new(),
+            sent_headers: None,
+            media_type: None,
+        }
+    }
+
+    fn parameter(name: &str, param_type: QueryParamType, strict: bool) -> QueryParameter {
+        QueryParameter {
+            name: name.to_string(),
+            param_type,
+            strict,
+        }
+    }
+
+    #[test]
+    fn test_values_that_do_not_coerce_are_reported() {
+        let mut route = endpoint("/users", "main.py");
+        route.query_parameters = vec![
+            parameter("page", QueryParamType::Integer, false),
+            parameter("active", QueryParamType::Boolean, false),
+        ];
+        let analyzer = EndpointAnalyzer::new(vec![route]);
+        let mut call = endpoint("\"/users\"", "web/users.ts");
+        call.sent_query = vec![
+            ("page".to_string(), Some("first".to_string())),
+            ("active".to_string(), Some("yes".to_string())),
+            ("limit".to_string(), Some("ten".to_string())),
+        ];
+
+        let diagnostics = query_coercion_issues(&analyzer, &[call]);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].location.file, "web/users.ts");
+        assert_eq!(
+            diagnostics[0].message,
+            "Call to GET /users sends `page=first`, which the route can't coerce to `int`"
+        );
+    }
+
+    #[test]
+    fn test_strict_parameters_reject_any_sent_value() {
+        let mut route = endpoint("/users", "main.py");
+        route.query_parameters = vec![
+            parameter("page", QueryParamType::Integer, false),
+            parameter("active", QueryParamType::Boolean, true),
+        ];
+        let analyzer = EndpointAnalyzer::new(vec![route]);
+        let mut call = endpoint("\"/users\"", "web/users.ts");
+        call.sent_query = vec![("page".to_string(), None), ("active".to_string(), None)];
+
+        let diagnostics = query_coercion_issues(&analyzer, &[call]);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Call to GET /users sends the query parameter `active` as a string, which the \
+             route's strict `bool` rejects"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::analyzers::ContractRule;
-use crate::models::{Contract, Mismatch};
+use crate::models::{Contract, Mismatch, MismatchType, SeverityLevel, TypeCompatibility};
+use crate::parsers::PRE_VALIDATOR_METADATA_KEY;
 
 /// Contract checker - applies rules to contracts
 pub struct ContractChecker {
@@ -9,12 +10,27 @@ pub struct ContractChecker {
 impl ContractChecker {
     /// Creates a new checker with default rules
     pub fn new() -> Self {
+        Self::with_type_compatibility(TypeCompatibility::default())
+    }
+
+    /// Creates a checker with default rules whose type comparison consults the given
+    /// compatibility matrix
+    pub fn with_type_compatibility(type_compatibility: TypeCompatibility) -> Self {
         let mut checker = Self { rules: Vec::new() };
 
         // Add default rules
-        checker.add_rule(Box::new(crate::analyzers::TypeMismatchRule));
+        checker.add_rule(Box::new(crate::analyzers::TypeMismatchRule::new(
+            type_compatibility,
+        )));
         checker.add_rule(Box::new(crate::analyzers::MissingFieldRule));
+        checker.add_rule(Box::new(crate::analyzers::ResponseOnlyFieldRule));
+        checker.add_rule(Box::new(crate::analyzers::ExtraFieldRule));
+        checker.add_rule(Box::new(crate::analyzers::FieldAliasRule));
+        checker.add_rule(Box::new(crate::analyzers::DiscriminatedUnionRule));
         checker.add_rule(Box::new(crate::analyzers::UnnormalizedDataRule));
+        checker.add_rule(Box::new(crate::analyzers::ArrayLengthRule));
+        checker.add_rule(Box::new(crate::analyzers::ArrayItemsRule));
+        checker.add_rule(Box::new(crate::analyzers::DefaultValueRule));
         checker.add_rule(Box::new(crate::analyzers::MissingSchemaRule));
 
         checker
@@ -34,6 +50,28 @@ impl ContractChecker {
             all_mismatches.extend(mismatches);
         }
 
+        // A model reshaping its raw input before validation may accept payloads that
+        // don't match its fields: missing and extra fields are only informational
+        if contract
+            .to_schema
+            .metadata
+            .get(PRE_VALIDATOR_METADATA_KEY)
+            .is_some_and(|value| value == "true")
+        {
+            for mismatch in &mut all_mismatches {
+                if matches!(
+                    mismatch.mismatch_type,
+                    MismatchType::MissingField | MismatchType::ExtraField
+                ) {
+                    mismatch.severity_level = SeverityLevel::Low;
+                    mismatch.message.push_str(&format!(
+                        " ('{}' transforms its input before validation)",
+                        contract.to_schema.name
+                    ));
+                }
+            }
+        }
+
         all_mismatches
     }
 
@@ -0,0 +1,314 @@
+use crate::analyzers::SchemaParser;
+use crate::models::{Location, PydanticFieldInfo, SchemaReference, SchemaType};
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+/// A schema of the dependency graph
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SchemaNode {
+    /// Node identifier: the schema name, qualified with its location when the name is declared
+    /// more than once (`User (web/types.ts:3)`)
+    pub id: String,
+    /// Schema name
+    pub name: String,
+    /// Kind of schema
+    pub schema_type: SchemaType,
+    /// Where the schema is declared
+    pub location: Location,
+}
+
+/// `from` references `to` through one of its fields
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct SchemaDependency {
+    /// Id of the referencing schema
+    pub from: String,
+    /// Id of the referenced schema
+    pub to: String,
+    /// Field of `from` whose type names `to`
+    pub field: String,
+}
+
+/// Which schema references which, through field types
+///
+/// Unlike the call graph this only follows type relationships: `Order.items: list[Item]`
+/// makes `Order` depend on `Item`. Cycles (`Category.parent: Category | None`) are kept as
+/// edges; traversals visit every schema once.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SchemaDependencyGraph {
+    /// Schemas, sorted by id
+    pub nodes: Vec<SchemaNode>,
+    /// References between schemas, sorted
+    pub edges: Vec<SchemaDependency>,
+}
+
+impl SchemaDependencyGraph {
+    /// Builds the graph from every field type of `schemas` naming another schema
+    ///
+    /// A type name resolves to a schema of the same kind first, then to one of the same side
+    /// (Pydantic/ORM models on the backend, TypeScript/Zod on the frontend).
+    pub fn build(schemas: &[SchemaReference]) -> Self {
+        let mut unique: Vec<&SchemaReference> = Vec::new();
+        for schema in schemas {
+            let known = unique.iter().any(|known| {
+                known.name == schema.name
+                    && known.schema_type == schema.schema_type
+                    && known.location == schema.location
+            });
+            if !known {
+                unique.push(schema);
+            }
+        }
+
+        let mut name_counts: HashMap<&str, usize> = HashMap::new();
+        for schema in &unique {
+            *name_counts.entry(schema.name.as_str()).or_default() += 1;
+        }
+        let node_id = |schema: &SchemaReference| {
+            if name_counts[schema.name.as_str()] > 1 {
+                format!(
+                    "{} ({}:{})",
+                    schema.name, schema.location.file, schema.location.line
+                )
+            } else {
+                schema.name.clone()
+            }
+        };
+
+        let mut edges = BTreeSet::new();
+        for schema in &unique {
+            for (field, type_name) in field_types(schema) {
+                for referenced in type_names(&type_name) {
+                    let Some(target) = resolve(referenced, schema, &unique) else {
+                        continue;
+                    };
+                    edges.insert(SchemaDependency {
+                        from: node_id(schema),
+                        to: node_id(target),
+                        field: field.clone(),
+                    });
+                }
+            }
+        }
+
+        let mut nodes: Vec<SchemaNode> = unique
+            .iter()
+            .map(|schema| SchemaNode {
+                id: node_id(schema),
+                name: schema.name.clone(),
+                schema_type: schema.schema_type,
+                location: schema.location.clone(),
+            })
+            .collect();
+        nodes.sort_by(|a, b| a.id.cmp(&b.id));
+
+        Self {
+            nodes,
+            edges: edges.into_iter().collect(),
+        }
+    }
+
+    /// Schemas referenced by `id`, directly or through other schemas, sorted
+    pub fn dependencies(&self, id: &str) -> Vec<&str> {
+        self.reachable(id, |edge| (edge.from.as_str(), edge.to.as_str()))
+    }
+
+    /// Schemas referencing `id`, directly or through other schemas, sorted: what a change to
+    /// `id` may break
+    pub fn dependents(&self, id: &str) -> Vec<&str> {
+        self.reachable(id, |edge| (edge.to.as_str(), edge.from.as_str()))
+    }
+
+    /// Renders the graph in Graphviz DOT format
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph schemas {\n");
+        dot.push_str("  rankdir=LR;\n");
+        dot.push_str("  node [shape=box];\n\n");
+        for node in &self.nodes {
+            dot.push_str(&format!(
+                "  \"{}\" [label=\"{}\\n{:?}\"];\n",
+                escape_dot(&node.id),
+                escape_dot(&node.name),
+                node.schema_type
+            ));
+        }
+        dot.push('\n');
+        for edge in &self.edges {
+            dot.push_str(&format!(
+                "  \"{}\" -> \"{}\" [label=\"{}\"];\n",
+                escape_dot(&edge.from),
+                escape_dot(&edge.to),
+                escape_dot(&edge.field)
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Breadth-first walk over `step(edge) = (from, to)` pairs, visiting each schema once
+    fn reachable<'a>(
+        &'a self,
+        id: &str,
+        step: impl Fn(&'a SchemaDependency) -> (&'a str, &'a str),
+    ) -> Vec<&'a str> {
+        let mut seen: HashSet<&str> = HashSet::new();
+        let mut queue: VecDeque<&str> = VecDeque::new();
+        let mut reached = BTreeSet::new();
+        queue.push_back(id);
+        while let Some(current) = queue.pop_front() {
+            if !seen.insert(current) {
+                continue;
+            }
+            for edge in &self.edges {
+                let (from, to) = step(edge);
+                if from == current {
+                    reached.insert(to);
+                    queue.push_back(to);
+                }
+            }
+        }
+        reached.into_iter().collect()
+    }
+}
+
+/// Declared field names and type names of a schema
+///
+/// Pydantic metadata keeps the element type of containers apart (`list[Item]` has
+/// `inner_type` `Item`), so both are returned.
+fn field_types(schema: &SchemaReference) -> Vec<(String, String)> {
+    let pydantic_fields = (schema.schema_type == SchemaType::Pydantic)
+        .then(|| schema.metadata.get("fields"))
+        .flatten()
+        .and_then(|json| serde_json::from_str::<Vec<PydanticFieldInfo>>(json).ok());
+    if let Some(fields) = pydantic_fields {
+        return fields
+            .into_iter()
+            .map(|field| {
+                let type_name = match &field.inner_type {
+                    Some(inner) => format!("{}[{}]", field.type_name, inner),
+                    None => field.type_name,
+                };
+                (field.name, type_name)
+            })
+            .collect();
+    }
+    SchemaParser::parse(schema)
+        .map(|parsed| {
+            parsed
+                .properties
+                .into_iter()
+                .map(|(name, info)| (name, info.field_type))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Identifiers of a type expression (`list[models.Item] | None` -> `list`, `Item`, `None`)
+fn type_names(type_name: &str) -> impl Iterator<Item = &str> {
+    type_name
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+        .filter_map(|token| token.rsplit('.').next())
+        .filter(|token| !token.is_empty())
+}
+
+/// Schema named `name` that a field of `schema` refers to
+fn resolve<'a>(
+    name: &str,
+    schema: &SchemaReference,
+    schemas: &[&'a SchemaReference],
+) -> Option<&'a SchemaReference> {
+    let candidates = || schemas.iter().copied().filter(move |c| c.name == name);
+    candidates()
+        .find(|candidate| candidate.schema_type == schema.schema_type)
+        .or_else(|| {
+            candidates().find(|candidate| side(candidate.schema_type) == side(schema.schema_type))
+        })
+}
+
+/// Backend (0), frontend (1) or API description (2) schemas
+fn side(schema_type: SchemaType) -> u8 {
+    match schema_type {
+        SchemaType::Pydantic | SchemaType::OrmModel | SchemaType::Enum => 0,
+        SchemaType::TypeScript | SchemaType::Zod => 1,
+        SchemaType::OpenAPI | SchemaType::JsonSchema => 2,
+    }
+}
+
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema(name: &str, schema_type: SchemaType, file: &str, fields: &str) -> SchemaReference {
+        SchemaReference {
+            name: name.to_string(),
+            schema_type,
+            location: Location {
+                file: file.to_string(),
+                line: 1,
+                column: None,
+            },
+            metadata: HashMap::from([("fields".to_string(), fields.to_string())]),
+        }
+    }
+
+    #[test]
+    fn test_references_are_resolved_on_the_same_side() {
+        let schemas = vec![
+            schema(
+                "Order",
+                SchemaType::TypeScript,
+                "web/order.ts",
+                "items:Item[],owner:User",
+            ),
+            schema("Item", SchemaType::TypeScript, "web/item.ts", "name:string"),
+            schema("User", SchemaType::Zod, "web/user.ts", "name:string"),
+            schema("User", SchemaType::OpenAPI, "openapi.json", ""),
+        ];
+
+        let graph = SchemaDependencyGraph::build(&schemas);
+
+        let edges: Vec<(&str, &str, &str)> = graph
+            .edges
+            .iter()
+            .map(|e| (e.from.as_str(), e.to.as_str(), e.field.as_str()))
+            .collect();
+        assert_eq!(
+            edges,
+            vec![
+                ("Order", "Item", "items"),
+                ("Order", "User (web/user.ts:1)", "owner"),
+            ]
+        );
+        assert_eq!(graph.nodes.len(), 4);
+    }
+
+    #[test]
+    fn test_cycles_are_traversed_once() {
+        let schemas = vec![
+            schema(
+                "Category",
+                SchemaType::TypeScript,
+                "a.ts",
+                "parent:Category,tags:Tag[]",
+            ),
+            schema("Tag", SchemaType::TypeScript, "a.ts", "category:Category"),
+            schema(
+                "Product",
+                SchemaType::TypeScript,
+                "a.ts",
+                "category:Category",
+            ),
+        ];
+
+        let graph = SchemaDependencyGraph::build(&schemas);
+
+        assert_eq!(graph.dependencies("Category"), vec!["Category", "Tag"]);
+        assert_eq!(graph.dependents("Tag"), vec!["Category", "Product", "Tag"]);
+        assert!(graph
+            .to_dot()
+            .contains("  \"Category\" -> \"Category\" [label=\"parent\"];\n"));
+    }
+}
@@ -0,0 +1,156 @@
+use crate::analyzers::SchemaParser;
+use crate::models::{Contract, DataChain, Diagnostic, SchemaReference, SchemaType};
+use std::collections::HashSet;
+
+/// Info diagnostics for deprecated fields a frontend schema still uses
+///
+/// Looks at contracts between a frontend schema (Zod/TypeScript) and a backend schema and
+/// reports every field both sides declare while either side marks it deprecated. Each
+/// (frontend schema, backend schema, field) is reported once, at the frontend schema.
+pub fn deprecated_field_usages(chains: &[DataChain]) -> Vec<Diagnostic> {
+    let mut seen = HashSet::new();
+    let mut diagnostics = Vec::new();
+
+    for contract in chains.iter().flat_map(|chain| &chain.contracts) {
+        let Some((frontend, backend)) = frontend_and_backend(contract) else {
+            continue;
+        };
+        let Ok(frontend_schema) = SchemaParser::parse(frontend) else {
+            continue;
+        };
+        let Ok(backend_schema) = SchemaParser::parse(backend) else {
+            continue;
+        };
+
+        let mut fields: Vec<_> = frontend_schema.properties.iter().collect();
+        fields.sort_by(|a, b| a.0.cmp(b.0));
+        for (field_name, frontend_field) in fields {
+            let Some(backend_field) = backend_schema.properties.get(field_name) else {
+                continue;
+            };
+            if !frontend_field.deprecated && !backend_field.deprecated {
+                continue;
+            }
+            let key = (
+                frontend.name.clone(),
+                backend.name.clone(),
+                field_name.clone(),
+            );
+            if !seen.insert(key) {
+                continue;
+            }
+            let owner = if backend_field.deprecated {
+                &backend.name
+            } else {
+                &frontend.name
+            };
+            diagnostics.push(Diagnostic::deprecated_usage(
+                frontend.location.clone(),
+                format!(
+                    "Field '{}' is deprecated in '{}' but still used between '{}' and '{}'",
+                    field_name, owner, frontend.name, backend.name
+                ),
+            ));
+        }
+    }
+
+    diagnostics
+}
+
+/// Frontend and backend schema of a contract crossing the API boundary
+fn frontend_and_backend(contract: &Contract) -> Option<(&SchemaReference, &SchemaReference)> {
+    let is_frontend =
+        |schema_type: SchemaType| matches!(schema_type, SchemaType::Zod | SchemaType::TypeScript);
+    match (
+        is_frontend(contract.from_schema.schema_type),
+        is_frontend(contract.to_schema.schema_type),
+    ) {
+        (true, false) => Some((&contract.from_schema, &contract.to_schema)),
+        (false, true) => Some((&contract.to_schema, &contract.from_schema)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ChainDirection, ChainType, Location, Severity};
+    use std::collections::HashMap;
+
+    fn schema(name: &str, schema_type: SchemaType, key: &str, value: &str) -> SchemaReference {
+        SchemaReference {
+            name: name.to_string(),
+            schema_type,
+            location: Location {
+                file: format!("{}.src", name),
+                line: 3,
+                column: None,
+            },
+            metadata: HashMap::from([(key.to_string(), value.to_string())]),
+        }
+    }
+
+    fn chain(from_schema: SchemaReference, to_schema: SchemaReference) -> DataChain {
+        DataChain {
+            id: "users".to_string(),
+            name: "users".to_string(),
+            links: Vec::new(),
+            contracts: vec![Contract {
+                from_link_id: "frontend".to_string(),
+                to_link_id: "backend".to_string(),
+                from_schema,
+                to_schema,
+                mismatches: Vec::new(),
+                severity: Severity::Info,
+            }],
+            direction: ChainDirection::FrontendToBackend,
+            chain_type: ChainType::Full,
+        }
+    }
+
+    #[test]
+    fn test_deprecated_fields_used_by_frontend_are_reported_once() {
+        let form = schema(
+            "UserForm",
+            SchemaType::TypeScript,
+            "fields",
+            "name:string:required,email:string:required",
+        );
+        let backend = schema(
+            "UserCreate",
+            SchemaType::OpenAPI,
+            "json_schema",
+            r#"{"type": "object", "properties": {
+                "name": {"type": "string", "deprecated": true},
+                "email": {"type": "string"},
+                "nickname": {"type": "string", "deprecated": true}
+            }}"#,
+        );
+        let chains = vec![
+            chain(form.clone(), backend.clone()),
+            chain(form.clone(), backend),
+        ];
+
+        let diagnostics = deprecated_field_usages(&chains);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Info);
+        assert_eq!(diagnostics[0].location, form.location);
+        assert_eq!(
+            diagnostics[0].message,
+            "Field 'name' is deprecated in 'UserCreate' but still used between 'UserForm' and 'UserCreate'"
+        );
+    }
+
+    #[test]
+    fn test_backend_only_contracts_are_ignored() {
+        let request = schema(
+            "UserCreate",
+            SchemaType::OpenAPI,
+            "json_schema",
+            r#"{"type": "object", "properties": {"name": {"type": "string", "deprecated": true}}}"#,
+        );
+
+        assert!(deprecated_field_usages(&[chain(request.clone(), request)]).is_empty());
+    }
+}
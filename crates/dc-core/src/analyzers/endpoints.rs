@@ -0,0 +1,850 @@
+use crate::analyzers::pagination_envelope;
+use crate::analyzers::route_matcher::{RouteMatch, RouteMatcher, TemplateMatcher};
+use crate::call_graph::{
+    CallGraph, CallNode, HttpMethod, QUERY_PARAMETERS_METADATA_KEY,
+    REQUEST_MEDIA_TYPE_METADATA_KEY, REQUIRED_HEADERS_METADATA_KEY, RESPONSE_ENVELOPE_METADATA_KEY,
+    SENT_HEADERS_METADATA_KEY, SENT_MEDIA_TYPE_METADATA_KEY, SENT_QUERY_METADATA_KEY,
+};
+use crate::models::{Diagnostic, Location, NodeId, QueryParameter, SchemaReference};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use tracing::debug;
+
+/// Route metadata key marking frontend calls that don't go through a REST route
+/// (`trpc`, `graphql`, `server_action`); their paths are synthesized and never checked
+pub const TRANSPORT_METADATA_KEY: &str = "transport";
+
+/// HTTP endpoint on one side of the API boundary: a backend route or a frontend call
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpEndpoint {
+    pub method: HttpMethod,
+    /// Path as written in code (`/users/{user_id}`, `"/users"`, `` `/users/${id}` ``)
+    pub path: String,
+    pub location: Location,
+    /// Backend route declared `deprecated=True` (always false for frontend calls)
+    #[serde(default)]
+    pub deprecated: bool,
+    /// Request body schema (route schema, or the handler's first schema parameter).
+    /// Not serialized: reports identify endpoints by method, path and location
+    #[serde(skip)]
+    pub request_schema: Option<SchemaReference>,
+    /// Response schema (route schema, or the handler's return type)
+    #[serde(skip)]
+    pub response_schema: Option<SchemaReference>,
+    /// Backend route: headers it requires, lower-cased (empty for frontend calls)
+    #[serde(skip)]
+    pub required_headers: Vec<String>,
+    /// Frontend call: headers it sets, lower-cased; `None` when they can't be determined
+    /// statically (and always for backend routes)
+    #[serde(skip)]
+    pub sent_headers: Option<Vec<String>>,
+    /// Backend route: media type it reads the request body in. Frontend call: media type of
+    /// the body it sends. `None` when unknown
+    #[serde(skip)]
+    pub media_type: Option<String>,
+    /// Fields of the paginated envelope of the response, sorted: the generic wrapper of a
+    /// backend route (`Page[Item]`), or a response schema that is itself a page (see
+    /// [`pagination_envelope`](crate::analyzers::pagination_envelope)). `None` when the
+    /// response isn't paginated
+    #[serde(skip)]
+    pub response_envelope: Option<Vec<String>>,
+    /// Backend route: its `int`/`float`/`bool` query parameters (empty for frontend calls)
+    #[serde(skip)]
+    pub query_parameters: Vec<QueryParameter>,
+    /// Frontend call: query parameters it sends in its URL or a literal `params` object,
+    /// with their value (`None` when computed at runtime)
+    #[serde(skip)]
+    pub sent_query: Vec<(String, Option<String>)>,
+}
+
+/// Frontend call that doesn't resolve to exactly one backend route
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointIssue {
+    pub kind: EndpointIssueKind,
+    /// The frontend call
+    pub call: HttpEndpoint,
+    /// Ambiguous: every route the call may reach.
+    /// Unmatched: routes serving the same path with other methods (if any)
+    pub candidates: Vec<HttpEndpoint>,
+    pub message: String,
+}
+
+/// Kind of endpoint issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EndpointIssueKind {
+    /// No backend route serves the call (typo or removed endpoint)
+    Unmatched,
+    /// Several backend routes may serve the call
+    Ambiguous,
+}
+
+/// Checks that every frontend HTTP call matches exactly one backend route
+///
+/// Paths are compared by a [`RouteMatcher`], by default as normalized templates
+/// ([`TemplateMatcher`]): literal segments must be equal, a backend parameter accepts any
+/// segment, and a dynamic frontend segment (`${id}`) may hit either a parameter or a literal.
+/// When one call matches several routes through literal segments only, the most specific
+/// route wins (`/users/me` over `/users/{id}`), as in FastAPI/NestJS routing; a dynamic
+/// segment that could reach a literal route keeps the call ambiguous.
+///
+/// A route declared both with and without a trailing slash (`/users`, `/users/`) serves
+/// each call through the variant spelled like the call; a call reaching only the other
+/// variant still resolves, as FastAPI redirects it (see [`Self::trailing_slash_redirects`]).
+pub struct EndpointAnalyzer {
+    /// Backend routes with their normalized paths
+    routes: Vec<(HttpEndpoint, String)>,
+    /// Decides which routes a call may reach
+    matcher: Box<dyn RouteMatcher>,
+}
+
+impl EndpointAnalyzer {
+    /// Creates an analyzer over the given backend routes
+    ///
+    /// Routes whose path can't be normalized are ignored; duplicates (same method,
+    /// normalized path and trailing slash) are kept once.
+    pub fn new(routes: Vec<HttpEndpoint>) -> Self {
+        let mut seen = HashSet::new();
+        let routes = routes
+            .into_iter()
+            .filter_map(|route| {
+                let normalized = normalize_route_path(&route.path)?;
+                seen.insert((
+                    route.method,
+                    normalized.clone(),
+                    has_trailing_slash(&route.path),
+                ))
+                .then_some((route, normalized))
+            })
+            .collect();
+        Self {
+            routes,
+            matcher: Box::new(TemplateMatcher),
+        }
+    }
+
+    /// Matches calls to routes with a custom strategy instead of [`TemplateMatcher`]
+    pub fn with_matcher(mut self, matcher: impl RouteMatcher + 'static) -> Self {
+        self.matcher = Box::new(matcher);
+        self
+    }
+
+    /// Route and frontend call endpoints of a graph (`Route` nodes)
+    ///
+    /// Nodes carrying `transport` metadata (tRPC, GraphQL, server actions) are skipped.
+    pub fn endpoints_in_graph(graph: &CallGraph) -> Vec<HttpEndpoint> {
+        graph
+            .node_weights()
+            .filter_map(|node| match node {
+                CallNode::Route {
+                    path,
+                    method,
+                    handler,
+                    location,
+                    request_schema,
+                    response_schema,
+                    metadata,
+                } if !metadata.contains_key(TRANSPORT_METADATA_KEY) => {
+                    let (handler_request, handler_response) = handler_schemas(graph, *handler);
+                    let response_schema = response_schema.clone().or(handler_response);
+                    let metadata_list = |key: &str| {
+                        metadata.get(key).map(|list| {
+                            list.split(',')
+                                .filter(|item| !item.is_empty())
+                                .map(str::to_string)
+                                .collect::<Vec<_>>()
+                        })
+                    };
+                    // Generic wrappers were resolved by the builder, page models are read here
+                    let response_envelope = match metadata_list(RESPONSE_ENVELOPE_METADATA_KEY) {
+                        Some(mut fields) => {
+                            fields.sort();
+                            Some(fields)
+                        }
+                        None => response_schema.as_ref().and_then(pagination_envelope),
+                    };
+                    Some(HttpEndpoint {
+                        method: *method,
+                        path: path.clone(),
+                        location: location.clone(),
+                        deprecated: node.is_deprecated(),
+                        request_schema: request_schema.clone().or(handler_request),
+                        response_schema,
+                        required_headers: metadata_list(REQUIRED_HEADERS_METADATA_KEY)
+                            .unwrap_or_default(),
+                        sent_headers: metadata_list(SENT_HEADERS_METADATA_KEY),
+                        media_type: metadata
+                            .get(REQUEST_MEDIA_TYPE_METADATA_KEY)
+                            .or(metadata.get(SENT_MEDIA_TYPE_METADATA_KEY))
+                            .cloned(),
+                        response_envelope,
+                        query_parameters: metadata
+                            .get(QUERY_PARAMETERS_METADATA_KEY)
+                            .and_then(|json| serde_json::from_str(json).ok())
+                            .unwrap_or_default(),
+                        sent_query: sent_query(path, metadata.get(SENT_QUERY_METADATA_KEY)),
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Backend routes the analyzer matches calls against
+    pub fn routes(&self) -> impl Iterator<Item = &HttpEndpoint> {
+        self.routes.iter().map(|(route, _)| route)
+    }
+
+    /// Checks all calls, returning one issue per call that is unmatched or ambiguous
+    pub fn check_calls(&self, calls: &[HttpEndpoint]) -> Vec<EndpointIssue> {
+        calls
+            .iter()
+            .filter_map(|call| self.check_call(call))
+            .collect()
+    }
+
+    /// Checks a single frontend call; calls whose URL can't be resolved statically are skipped
+    pub fn check_call(&self, call: &HttpEndpoint) -> Option<EndpointIssue> {
+        let Some(normalized) = normalize_route_path(&call.path) else {
+            debug!(path = %call.path, "Skipping frontend call with unresolvable URL");
+            return None;
+        };
+        let (matches, other_methods): (Vec<_>, Vec<_>) = self
+            .matching_routes(call)
+            .into_iter()
+            .partition(|(route, _)| route.method == call.method);
+        let other_methods: Vec<HttpEndpoint> = other_methods
+            .into_iter()
+            .map(|(route, _)| route.clone())
+            .collect();
+
+        let method = method_name(call.method);
+        if matches.is_empty() {
+            let message = if other_methods.is_empty() {
+                format!("No backend route serves {} {}", method, normalized)
+            } else {
+                let methods: Vec<_> = other_methods
+                    .iter()
+                    .map(|route| method_name(route.method))
+                    .collect();
+                format!(
+                    "No backend route serves {} {} (path is served for {})",
+                    method,
+                    normalized,
+                    methods.join(", ")
+                )
+            };
+            return Some(EndpointIssue {
+                kind: EndpointIssueKind::Unmatched,
+                call: call.clone(),
+                candidates: other_methods,
+                message,
+            });
+        }
+
+        if matches.len() == 1 || Self::resolves_to_most_specific(&matches) {
+            return None;
+        }
+
+        let mut candidates: Vec<HttpEndpoint> = matches
+            .into_iter()
+            .map(|(route, _)| route.clone())
+            .collect();
+        candidates.sort_by(|a, b| a.path.cmp(&b.path));
+        let paths: Vec<_> = candidates.iter().map(|route| route.path.as_str()).collect();
+        Some(EndpointIssue {
+            kind: EndpointIssueKind::Ambiguous,
+            call: call.clone(),
+            message: format!(
+                "{} {} matches {} backend routes: {}",
+                method,
+                normalized,
+                candidates.len(),
+                paths.join(", ")
+            ),
+            candidates,
+        })
+    }
+
+    /// Backend route a frontend call resolves to, if exactly one serves it
+    pub fn resolve_call(&self, call: &HttpEndpoint) -> Option<&HttpEndpoint> {
+        normalize_route_path(&call.path)?;
+        let matches: Vec<_> = self
+            .matching_routes(call)
+            .into_iter()
+            .filter(|(route, _)| route.method == call.method)
+            .collect();
+        if matches.len() == 1 {
+            return Some(matches[0].0);
+        }
+        if !Self::resolves_to_most_specific(&matches) {
+            return None;
+        }
+        matches
+            .into_iter()
+            .max_by_key(|(_, m)| m.literals)
+            .map(|(route, _)| route)
+    }
+
+    /// Info diagnostics for frontend calls that resolve to a deprecated backend route
+    ///
+    /// Reported at the call site, so teams can track which callers still need migrating.
+    pub fn deprecated_calls(&self, calls: &[HttpEndpoint]) -> Vec<Diagnostic> {
+        calls
+            .iter()
+            .filter_map(|call| {
+                let route = self.resolve_call(call).filter(|route| route.deprecated)?;
+                Some(Diagnostic::deprecated_usage(
+                    call.location.clone(),
+                    format!(
+                        "{} {} calls a deprecated backend route ({}:{})",
+                        method_name(call.method),
+                        normalize_route_path(&call.path)?,
+                        route.location.file,
+                        route.location.line
+                    ),
+                ))
+            })
+            .collect()
+    }
+
+    /// Diagnostics for frontend calls that don't send a header their backend route requires
+    ///
+    /// Header names are compared case-insensitively. Calls whose headers can't be determined
+    /// statically (a `headers` variable or spread, an API client that may add defaults) are
+    /// skipped.
+    pub fn missing_headers(&self, calls: &[HttpEndpoint]) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        for call in calls {
+            let Some(sent) = &call.sent_headers else {
+                continue;
+            };
+            let Some(route) = self.resolve_call(call) else {
+                continue;
+            };
+            let Some(path) = normalize_route_path(&call.path) else {
+                continue;
+            };
+            for header in &route.required_headers {
+                if sent.contains(header) {
+                    continue;
+                }
+                diagnostics.push(Diagnostic::missing_header(
+                    call.location.clone(),
+                    format!(
+                        "{} {} doesn't send the `{}` header required by the backend route ({}:{})",
+                        method_name(call.method),
+                        path,
+                        header,
+                        route.location.file,
+                        route.location.line
+                    ),
+                ));
+            }
+        }
+        diagnostics
+    }
+
+    /// Diagnostics for frontend calls sending a body in a media type their backend route
+    /// doesn't read (JSON to a `Form(...)` route, a form to a JSON route)
+    ///
+    /// Form routes accept `multipart/form-data` too, and JSON routes any `+json` type.
+    /// Calls or routes whose media type is unknown are skipped.
+    pub fn media_type_mismatches(&self, calls: &[HttpEndpoint]) -> Vec<Diagnostic> {
+        calls
+            .iter()
+            .filter_map(|call| {
+                let sent = call.media_type.as_deref()?;
+                let route = self.resolve_call(call)?;
+                let expected = route.media_type.as_deref()?;
+                if media_type_accepted(expected, sent) {
+                    return None;
+                }
+                Some(Diagnostic::content_type(
+                    call.location.clone(),
+                    format!(
+                        "{} {} sends `{}` but the backend route reads `{}` ({}:{})",
+                        method_name(call.method),
+                        normalize_route_path(&call.path)?,
+                        sent,
+                        expected,
+                        route.location.file,
+                        route.location.line
+                    ),
+                ))
+            })
+            .collect()
+    }
+
+    /// Warning diagnostics for frontend calls that only reach their route through a
+    /// trailing-slash redirect (`/users` calling a route declared as `/users/`, or the
+    /// reverse)
+    ///
+    /// Trailing slashes are equivalent by default; this check is run in strict mode
+    /// (`strict_trailing_slash = true`), since the redirect costs a round trip and drops
+    /// the request body or credentials with some clients.
+    pub fn trailing_slash_redirects(&self, calls: &[HttpEndpoint]) -> Vec<Diagnostic> {
+        calls
+            .iter()
+            .filter_map(|call| {
+                let route = self.resolve_call(call)?;
+                if has_trailing_slash(&call.path) == has_trailing_slash(&route.path) {
+                    return None;
+                }
+                Some(Diagnostic::trailing_slash(
+                    call.location.clone(),
+                    format!(
+                        "{} {} reaches {} only through a trailing-slash redirect ({}:{})",
+                        method_name(call.method),
+                        route_path_part(&call.path)?,
+                        route.path,
+                        route.location.file,
+                        route.location.line
+                    ),
+                ))
+            })
+            .collect()
+    }
+
+    /// Routes of any method the call may reach
+    ///
+    /// Of a route declared with and without a trailing slash, only the variant spelled like
+    /// the call is kept.
+    fn matching_routes(&self, call: &HttpEndpoint) -> Vec<(&HttpEndpoint, RouteMatch)> {
+        let trailing_slash = has_trailing_slash(&call.path);
+        let matches: Vec<_> = self
+            .routes
+            .iter()
+            .filter_map(|(route, normalized)| {
+                self.matcher
+                    .match_route(&call.path, &route.path)
+                    .map(|m| (route, normalized, m))
+            })
+            .collect();
+        matches
+            .iter()
+            .filter(|(route, normalized, _)| {
+                has_trailing_slash(&route.path) == trailing_slash
+                    || !matches.iter().any(|(other, other_normalized, _)| {
+                        other.method == route.method
+                            && other_normalized == normalized
+                            && has_trailing_slash(&other.path) == trailing_slash
+                    })
+            })
+            .map(|(route, _, m)| (*route, *m))
+            .collect()
+    }
+
+    /// Several matches resolve to one route when no dynamic call segment lands on a literal
+    /// route segment and a single route has the most literal segments
+    fn resolves_to_most_specific(matches: &[(&HttpEndpoint, RouteMatch)]) -> bool {
+        if matches.iter().any(|(_, m)| !m.certain) {
+            return false;
+        }
+        let best = matches.iter().map(|(_, m)| m.literals).max().unwrap_or(0);
+        matches.iter().filter(|(_, m)| m.literals == best).count() == 1
+    }
+}
+
+/// Normalizes a route path or frontend URL into a path template
+///
+/// Strips quotes, scheme/host, a leading base-URL placeholder (`${API_URL}/users`), the query
+/// string and empty segments (so trailing slashes are ignored). Parameters in any syntax
+/// (`{id}`, `:id`, `<id>`, `${id}`, `user-${id}`) become `{}`; catch-all parameters
+/// (`{path:path}`, `*`) become `{*}`. Returns None when the result isn't an absolute path
+/// (e.g. a URL held in a variable).
+pub fn normalize_route_path(path: &str) -> Option<String> {
+    let segments: Vec<&str> = route_path_part(path)?
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            if segment == "*" || segment.ends_with(":path}") {
+                "{*}"
+            } else if segment.contains('{')
+                || segment.starts_with(':')
+                || (segment.starts_with('<') && segment.ends_with('>'))
+            {
+                "{}"
+            } else {
+                segment
+            }
+        })
+        .collect();
+    Some(format!("/{}", segments.join("/")))
+}
+
+/// Whether a route path or frontend URL ends with a slash (`/users/`, `"/users/?page=2"`);
+/// the root path `/` doesn't
+///
+/// [`normalize_route_path`] ignores trailing slashes, so matching stays lenient; this tells
+/// the variants apart for the strict trailing-slash check.
+pub fn has_trailing_slash(path: &str) -> bool {
+    route_path_part(path).is_some_and(|path| path.len() > 1 && path.ends_with('/'))
+}
+
+/// Path part of a route path or URL: quotes, scheme/host, base-URL placeholders, query
+/// string and fragment stripped. None when it isn't an absolute path.
+pub(crate) fn route_path_part(path: &str) -> Option<&str> {
+    let mut path = path
+        .trim()
+        .trim_matches(|c| c == '"' || c == '\'' || c == '`');
+
+    if let Some(scheme_end) = path.find("://") {
+        let after_scheme = &path[scheme_end + 3..];
+        path = after_scheme
+            .find('/')
+            .map(|idx| &after_scheme[idx..])
+            .unwrap_or("/");
+    }
+    while let Some(rest) = path.strip_prefix("${") {
+        path = &rest[rest.find('}')? + 1..];
+    }
+    if let Some(end) = path.find(['?', '#']) {
+        path = &path[..end];
+    }
+    path.starts_with('/').then_some(path)
+}
+
+/// Query parameters a frontend call sends: those of its URL's query string
+/// (`?page=2&q=${term}`), then those of its literal `params` object (`sent_query` metadata)
+///
+/// Values interpolated at runtime are `None`; so are names without a value.
+fn sent_query(path: &str, params: Option<&String>) -> Vec<(String, Option<String>)> {
+    let url = path
+        .trim()
+        .trim_matches(|c| c == '"' || c == '\'' || c == '`');
+    let query = url
+        .split_once('?')
+        .map(|(_, query)| query.split('#').next().unwrap_or(query))
+        .unwrap_or("");
+    let mut sent: Vec<(String, Option<String>)> = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .filter_map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            if name.is_empty() || name.contains("${") {
+                return None;
+            }
+            let value = (!value.is_empty() && !value.contains("${")).then(|| value.to_string());
+            Some((name.to_string(), value))
+        })
+        .collect();
+    if let Some(params) = params.and_then(|json| {
+        serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(json).ok()
+    }) {
+        sent.extend(params.into_iter().map(|(name, value)| {
+            let value = value.as_str().map(str::to_string);
+            (name, value)
+        }));
+    }
+    sent
+}
+
+/// Schemas of a route handler: its first parameter typed with a schema and its return type
+fn handler_schemas(
+    graph: &CallGraph,
+    handler: NodeId,
+) -> (Option<SchemaReference>, Option<SchemaReference>) {
+    let (parameters, return_type) = match graph.node_weight(handler.0) {
+        Some(CallNode::Function {
+            parameters,
+            return_type,
+            ..
+        })
+        | Some(CallNode::Method {
+            parameters,
+            return_type,
+            ..
+        }) => (parameters, return_type),
+        _ => return (None, None),
+    };
+    let request = parameters
+        .iter()
+        .find_map(|parameter| parameter.type_info.schema_ref.clone());
+    let response = return_type
+        .as_ref()
+        .and_then(|type_info| type_info.schema_ref.clone());
+    (request, response)
+}
+
+pub(crate) fn method_name(method: HttpMethod) -> String {
+    format!("{:?}", method).to_uppercase()
+}
+
+/// Whether a route reading bodies of media type `expected` accepts one sent as `sent`
+/// (parameters such as `; charset=utf-8` are ignored)
+fn media_type_accepted(expected: &str, sent: &str) -> bool {
+    let essence = |media_type: &str| {
+        media_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase()
+    };
+    let (expected, sent) = (essence(expected), essence(sent));
+    match expected.as_str() {
+        "application/json" => sent == "application/json" || sent.ends_with("+json"),
+        "application/x-www-form-urlencoded" => {
+            sent == "application/x-www-form-urlencoded" || sent == "multipart/form-data"
+        }
+        _ => sent == expected,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoint(method: HttpMethod, path: &str) -> HttpEndpoint {
+        HttpEndpoint {
+            method,
+            path: path.to_string(),
+            location: Location {
+                file: "test".to_string(),
+                line: 1,
+                column: None,
+            },
+            deprecated: false,
+            request_schema: None,
+            response_schema: None,
+            required_headers: Vec::new(),
+            sent_headers: None,
+            media_type: None,
+            response_envelope: None,
+            query_parameters: Vec::new(),
+            sent_query: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_normalize_route_path() {
+        assert_eq!(
+            normalize_route_path("/users/{user_id}/").as_deref(),
+            Some("/users/{}")
+        );
+        assert_eq!(
+            normalize_route_path("\"/users?page=2\"").as_deref(),
+            Some("/users")
+        );
+        assert_eq!(
+            normalize_route_path("`${API_URL}/users/${user.id}/posts`").as_deref(),
+            Some("/users/{}/posts")
+        );
+        assert_eq!(
+            normalize_route_path("https://api.example.com/items/:id").as_deref(),
+            Some("/items/{}")
+        );
+        assert_eq!(
+            normalize_route_path("/files/{file_path:path}").as_deref(),
+            Some("/files/{*}")
+        );
+        assert_eq!(normalize_route_path("url"), None);
+    }
+
+    #[test]
+    fn test_calls_match_exactly_one_route() {
+        let analyzer = EndpointAnalyzer::new(vec![
+            endpoint(HttpMethod::Get, "/users"),
+            endpoint(HttpMethod::Get, "/users/{user_id}"),
+            endpoint(HttpMethod::Get, "/users/me"),
+            endpoint(HttpMethod::Get, "/files/{path:path}"),
+        ]);
+
+        for call in [
+            endpoint(HttpMethod::Get, "\"/users/\""),
+            endpoint(HttpMethod::Get, "`/users/${id}/`"),
+            endpoint(HttpMethod::Get, "\"/users/me\""),
+            endpoint(HttpMethod::Get, "\"/users/42\""),
+            endpoint(HttpMethod::Get, "\"/files/a/b.txt\""),
+            endpoint(HttpMethod::Get, "expr"),
+        ] {
+            let issue = analyzer.check_call(&call);
+            if call.path.contains("${id}") {
+                // `/users/${id}` may hit `/users/me` as well
+                assert_eq!(issue.unwrap().kind, EndpointIssueKind::Ambiguous);
+            } else {
+                assert!(issue.is_none(), "{} should match one route", call.path);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unmatched_calls_report_other_methods() {
+        let analyzer = EndpointAnalyzer::new(vec![
+            endpoint(HttpMethod::Get, "/items"),
+            endpoint(HttpMethod::Get, "/items/"),
+        ]);
+
+        let typo = analyzer
+            .check_call(&endpoint(HttpMethod::Get, "\"/itmes\""))
+            .unwrap();
+        assert_eq!(typo.kind, EndpointIssueKind::Unmatched);
+        assert!(typo.candidates.is_empty());
+
+        let wrong_method = analyzer
+            .check_call(&endpoint(HttpMethod::Post, "\"/items\""))
+            .unwrap();
+        assert_eq!(wrong_method.kind, EndpointIssueKind::Unmatched);
+        assert_eq!(wrong_method.candidates.len(), 1);
+        assert_eq!(
+            wrong_method.message,
+            "No backend route serves POST /items (path is served for GET)"
+        );
+    }
+
+    #[test]
+    fn test_ambiguous_call_reports_candidate_set() {
+        let analyzer = EndpointAnalyzer::new(vec![
+            endpoint(HttpMethod::Get, "/orgs/{org}/members"),
+            endpoint(HttpMethod::Get, "/orgs/{org}/{section}"),
+            endpoint(HttpMethod::Get, "/orgs/public/members"),
+        ]);
+
+        let issue = analyzer
+            .check_call(&endpoint(HttpMethod::Get, "`/orgs/${org}/members`"))
+            .unwrap();
+        assert_eq!(issue.kind, EndpointIssueKind::Ambiguous);
+        let paths: Vec<_> = issue.candidates.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/orgs/public/members",
+                "/orgs/{org}/members",
+                "/orgs/{org}/{section}"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calls_to_deprecated_routes_are_reported() {
+        let mut legacy = endpoint(HttpMethod::Get, "/users/{user_id}");
+        legacy.deprecated = true;
+        let analyzer = EndpointAnalyzer::new(vec![
+            legacy,
+            endpoint(HttpMethod::Get, "/users/me"),
+            endpoint(HttpMethod::Get, "/users"),
+        ]);
+
+        let diagnostics = analyzer.deprecated_calls(&[
+            endpoint(HttpMethod::Get, "\"/users/42\""),
+            endpoint(HttpMethod::Get, "\"/users/me\""),
+            endpoint(HttpMethod::Get, "\"/users\""),
+            // Ambiguous calls don't resolve to a single route
+            endpoint(HttpMethod::Get, "`/users/${id}`"),
+        ]);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, crate::models::Severity::Info);
+        assert_eq!(
+            diagnostics[0].message,
+            "GET /users/42 calls a deprecated backend route (test:1)"
+        );
+    }
+
+    #[test]
+    fn test_calls_missing_required_headers_are_reported() {
+        let mut secured = endpoint(HttpMethod::Post, "/orders");
+        secured.required_headers = vec!["x-api-key".to_string(), "x-tenant".to_string()];
+        let analyzer = EndpointAnalyzer::new(vec![secured]);
+
+        let call = |sent: Option<&[&str]>| {
+            let mut call = endpoint(HttpMethod::Post, "\"/orders\"");
+            call.sent_headers =
+                sent.map(|sent| sent.iter().map(|header| header.to_string()).collect());
+            call
+        };
+        let diagnostics = analyzer.missing_headers(&[
+            call(Some(&["x-api-key", "x-tenant"])),
+            call(Some(&["x-api-key"])),
+            // Headers that can't be determined statically are not checked
+            call(None),
+        ]);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule_id, crate::models::MISSING_HEADER_RULE);
+        assert_eq!(
+            diagnostics[0].message,
+            "POST /orders doesn't send the `x-tenant` header required by the backend route (test:1)"
+        );
+    }
+
+    #[test]
+    fn test_trailing_slash_variants() {
+        assert!(has_trailing_slash("\"/users/?page=2\""));
+        assert!(!has_trailing_slash("`${API_URL}/users`"));
+        assert!(!has_trailing_slash("/"));
+
+        let analyzer = EndpointAnalyzer::new(vec![
+            endpoint(HttpMethod::Get, "/users/"),
+            endpoint(HttpMethod::Get, "/items"),
+            endpoint(HttpMethod::Get, "/items/"),
+        ]);
+        let calls = [
+            endpoint(HttpMethod::Get, "\"/users\""),
+            endpoint(HttpMethod::Get, "\"/users/\""),
+            // Both variants are declared: each call reaches its own
+            endpoint(HttpMethod::Get, "\"/items\""),
+            endpoint(HttpMethod::Get, "\"/items/\""),
+        ];
+
+        assert!(analyzer.check_calls(&calls).is_empty());
+        assert_eq!(analyzer.resolve_call(&calls[3]).unwrap().path, "/items/");
+        let diagnostics = analyzer.trailing_slash_redirects(&calls);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "GET /users reaches /users/ only through a trailing-slash redirect (test:1)"
+        );
+    }
+
+    #[test]
+    fn test_custom_matcher_replaces_template_matching() {
+        let routes = vec![
+            endpoint(HttpMethod::Get, "/users/{user_id}"),
+            endpoint(HttpMethod::Get, "/users/me"),
+        ];
+        let call = endpoint(HttpMethod::Get, "\"/users/me\"");
+        let dynamic_call = endpoint(HttpMethod::Get, "`/users/${id}`");
+
+        let analyzer = EndpointAnalyzer::new(routes).with_matcher(crate::analyzers::ExactMatcher);
+
+        assert_eq!(analyzer.resolve_call(&call).unwrap().path, "/users/me");
+        let issue = analyzer.check_call(&dynamic_call).unwrap();
+        assert_eq!(issue.kind, EndpointIssueKind::Unmatched);
+    }
+
+    #[test]
+    fn test_bodies_sent_in_another_media_type_are_reported() {
+        let route = |path: &str, media_type: &str| {
+            let mut route = endpoint(HttpMethod::Post, path);
+            route.media_type = Some(media_type.to_string());
+            route
+        };
+        let analyzer = EndpointAnalyzer::new(vec![
+            route("/login", "application/x-www-form-urlencoded"),
+            route("/items", "application/json"),
+        ]);
+
+        let call = |path: &str, media_type: Option<&str>| {
+            let mut call = endpoint(HttpMethod::Post, path);
+            call.media_type = media_type.map(str::to_string);
+            call
+        };
+        let diagnostics = analyzer.media_type_mismatches(&[
+            call("\"/login\"", Some("application/json")),
+            call("\"/login\"", Some("multipart/form-data")),
+            call("\"/items\"", Some("application/json; charset=utf-8")),
+            call("\"/items\"", Some("application/merge-patch+json")),
+            // Bodies of unknown encoding are not checked
+            call("\"/items\"", None),
+        ]);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule_id, crate::models::CONTENT_TYPE_RULE);
+        assert_eq!(
+            diagnostics[0].message,
+            "POST /login sends `application/json` but the backend route reads \
+             `application/x-www-form-urlencoded` (test:1)"
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use crate::analyzers::{FieldInfo, SchemaParser};
+use crate::models::{BaseType, Constraint, ConstraintValue, DataChain, Diagnostic};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Info diagnostics for documented examples that no longer fit their field
+///
+/// Looks at every schema of the chains' contracts and checks the literal examples of each
+/// field (`Field(examples=[...])`, OpenAPI `example`/`examples`) against the field's current
+/// type, length/value bounds and enum values. Catches examples left behind by a type change
+/// (`"abc"` documented for a field that is now an integer). Each (schema, field, example) is
+/// reported once, at the schema.
+pub fn stale_examples(chains: &[DataChain]) -> Vec<Diagnostic> {
+    let mut seen = HashSet::new();
+    let mut diagnostics = Vec::new();
+
+    let schemas = chains
+        .iter()
+        .flat_map(|chain| &chain.contracts)
+        .flat_map(|contract| [&contract.from_schema, &contract.to_schema]);
+    for schema in schemas {
+        let key = (
+            schema.name.clone(),
+            schema.location.file.clone(),
+            schema.location.line,
+        );
+        if !seen.insert(key) {
+            continue;
+        }
+        let Ok(parsed) = SchemaParser::parse(schema) else {
+            continue;
+        };
+
+        let mut fields: Vec<_> = parsed.properties.iter().collect();
+        fields.sort_by(|a, b| a.0.cmp(b.0));
+        for (field_name, field) in fields {
+            for example in &field.examples {
+                if let Some(problem) = example_problem(field, example) {
+                    diagnostics.push(Diagnostic::stale_example(
+                        schema.location.clone(),
+                        format!(
+                            "Example {} of field '{}' in '{}' {}",
+                            example, field_name, schema.name, problem
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+
+    diagnostics
+}
+
+/// Why `example` doesn't validate against `field`, if it doesn't
+fn example_problem(field: &FieldInfo, example: &Value) -> Option<String> {
+    if example.is_null() {
+        return (!field.optional).then(|| "is null but the field is required".to_string());
+    }
+
+    let type_matches = match field.base_type {
+        BaseType::String => example.is_string(),
+        BaseType::Integer => example.is_i64() || example.is_u64(),
+        BaseType::Number => example.is_number(),
+        BaseType::Boolean => example.is_boolean(),
+        BaseType::Array => example.is_array(),
+        BaseType::Object => example.is_object(),
+        // Nothing to check against
+        BaseType::Null | BaseType::Any | BaseType::Unknown => return None,
+    };
+    if !type_matches {
+        return Some(format!(
+            "is not a valid {}",
+            format!("{:?}", field.base_type).to_lowercase()
+        ));
+    }
+
+    // Integer bounds limit lengths (strings, arrays), float bounds limit values
+    let length = match example {
+        Value::String(value) => Some(value.chars().count() as f64),
+        Value::Array(items) => Some(items.len() as f64),
+        _ => None,
+    };
+    let value = example.as_f64();
+    for constraint in &field.constraints {
+        let problem = match constraint {
+            Constraint::Min(ConstraintValue::Integer(min)) => length
+                .filter(|length| *length < *min as f64)
+                .map(|_| format!("is shorter than the minimum length {}", min)),
+            Constraint::Max(ConstraintValue::Integer(max)) => length
+                .filter(|length| *length > *max as f64)
+                .map(|_| format!("is longer than the maximum length {}", max)),
+            Constraint::Min(ConstraintValue::Float(min)) => value
+                .filter(|value| value < min)
+                .map(|_| format!("is below the minimum {}", min)),
+            Constraint::Max(ConstraintValue::Float(max)) => value
+                .filter(|value| value > max)
+                .map(|_| format!("is above the maximum {}", max)),
+            Constraint::ExclusiveMin(bound) => {
+                let min = constraint_number(bound);
+                value
+                    .filter(|value| *value <= min)
+                    .map(|_| format!("is not above the exclusive minimum {}", min))
+            }
+            Constraint::ExclusiveMax(bound) => {
+                let max = constraint_number(bound);
+                value
+                    .filter(|value| *value >= max)
+                    .map(|_| format!("is not below the exclusive maximum {}", max))
+            }
+            Constraint::Enum(values) => example
+                .as_str()
+                .filter(|example| !values.iter().any(|value| value == example))
+                .map(|_| format!("is not one of {}", values.join(", "))),
+            Constraint::Pattern(_) | Constraint::Email | Constraint::Url => None,
+        };
+        if problem.is_some() {
+            return problem;
+        }
+    }
+    None
+}
+
+fn constraint_number(value: &ConstraintValue) -> f64 {
+    match value {
+        ConstraintValue::Integer(value) => *value as f64,
+        ConstraintValue::Float(value) => *value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        ChainDirection, ChainType, Contract, Location, SchemaReference, SchemaType, Severity,
+    };
+    use std::collections::HashMap;
+
+    fn chain(schema: SchemaReference) -> DataChain {
+        DataChain {
+            id: "items".to_string(),
+            name: "items".to_string(),
+            links: Vec::new(),
+            contracts: vec![Contract {
+                from_link_id: "route".to_string(),
+                to_link_id: "model".to_string(),
+                from_schema: schema.clone(),
+                to_schema: schema,
+                mismatches: Vec::new(),
+                severity: Severity::Info,
+            }],
+            direction: ChainDirection::FrontendToBackend,
+            chain_type: ChainType::Full,
+        }
+    }
+
+    #[test]
+    fn test_examples_violating_type_or_constraints_are_reported_once() {
+        let spec = SchemaReference {
+            name: "ItemCreate".to_string(),
+            schema_type: SchemaType::OpenAPI,
+            location: Location {
+                file: "openapi.json".to_string(),
+                line: 1,
+                column: None,
+            },
+            metadata: HashMap::from([(
+                "json_schema".to_string(),
+                r#"{"type": "object", "required": ["quantity"], "properties": {
+                    "quantity": {"type": "integer", "minimum": 1, "examples": [3, "three", 0]},
+                    "name": {"type": "string", "maxLength": 4, "example": "widget"},
+                    "status": {"type": "string", "enum": ["new", "sold"], "example": "new"},
+                    "note": {"type": "string", "examples": [null]}
+                }}"#
+                .to_string(),
+            )]),
+        };
+
+        let diagnostics = stale_examples(&[chain(spec.clone()), chain(spec)]);
+        let messages: Vec<&str> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+
+        assert_eq!(
+            messages,
+            [
+                "Example \"widget\" of field 'name' in 'ItemCreate' is longer than the maximum length 4",
+                "Example \"three\" of field 'quantity' in 'ItemCreate' is not a valid integer",
+                "Example 0 of field 'quantity' in 'ItemCreate' is below the minimum 1",
+            ]
+        );
+        assert!(diagnostics.iter().all(|d| d.severity == Severity::Info));
+    }
+}
@@ -0,0 +1,332 @@
+use crate::analyzers::SchemaParser;
+use crate::call_graph::{CallEdge, CallGraph, CallNode};
+use crate::models::{NodeId, SchemaReference, TransformationType};
+use petgraph::visit::EdgeRef;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// One `DataFlow` edge a field passed through
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LineageHop {
+    /// Schema the field arrives in
+    pub schema: String,
+    /// Transformation applied on the edge
+    pub transformation: Option<TransformationType>,
+}
+
+/// Where a field ends up at the end of a data flow path
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LineageOutcome {
+    /// The field reaches `field` of `schema`, which has no further data flows
+    Sink {
+        /// Last schema on the path
+        schema: String,
+        /// Field name in that schema
+        field: String,
+    },
+    /// `schema` has no such field: the transformation into it drops the field
+    Dropped {
+        /// Schema lacking the field
+        schema: String,
+    },
+}
+
+/// Lineage of one field of the starting schema along one data flow path
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldLineage {
+    /// Field of the starting schema
+    pub source_field: String,
+    /// Schemas the field passes through, in order (the last one holds the outcome)
+    pub hops: Vec<LineageHop>,
+    /// Sink field or the schema that dropped it
+    pub outcome: LineageOutcome,
+}
+
+/// Field-level lineage over `CallEdge::DataFlow` edges
+///
+/// Fields are matched by name. A schema whose fields are unknown (plain `dict`/JSON data)
+/// keeps every field; a schema with known fields drops the ones it doesn't declare.
+/// Nodes already on the current path are not revisited, so bidirectional
+/// Pydantic ↔ ORM edges don't loop.
+pub struct LineageAnalyzer<'a> {
+    graph: &'a CallGraph,
+}
+
+/// State of following one field from the starting schema
+struct FieldWalk<'f> {
+    source_field: &'f str,
+    hops: Vec<LineageHop>,
+    on_path: Vec<NodeId>,
+    lineage: &'f mut Vec<FieldLineage>,
+}
+
+impl<'a> LineageAnalyzer<'a> {
+    /// Creates an analyzer over the graph
+    pub fn new(graph: &'a CallGraph) -> Self {
+        Self { graph }
+    }
+
+    /// Computes where each field of the schema at `start` flows
+    ///
+    /// `start` is a schema node or a model class with outgoing `DataFlow` edges.
+    /// Returns one entry per field and path end, ordered by field name.
+    pub fn field_lineage(&self, start: NodeId) -> Vec<FieldLineage> {
+        let Some(schema) = self.node_schema(start) else {
+            return Vec::new();
+        };
+        let Some(fields) = Self::schema_fields(&schema) else {
+            return Vec::new();
+        };
+
+        let mut lineage = Vec::new();
+        for field in &fields {
+            let mut walk = FieldWalk {
+                source_field: field,
+                hops: Vec::new(),
+                on_path: vec![start],
+                lineage: &mut lineage,
+            };
+            self.follow(start, &schema.name, field, &mut walk);
+        }
+        lineage
+    }
+
+    /// Follows `field` (as named in `schema_name`) out of `node`
+    fn follow(&self, node: NodeId, schema_name: &str, field: &str, walk: &mut FieldWalk) {
+        let mut continued = false;
+
+        for edge in self.graph.edges(node.0) {
+            let CallEdge::DataFlow {
+                to,
+                to_schema,
+                transformation,
+                ..
+            } = edge.weight()
+            else {
+                continue;
+            };
+            // Self-loops are transformations inside a function, not flows between schemas
+            if edge.target() == node.0 || walk.on_path.contains(to) {
+                continue;
+            }
+            continued = true;
+
+            walk.hops.push(LineageHop {
+                schema: to_schema.name.clone(),
+                transformation: transformation.clone(),
+            });
+            match Self::schema_fields(to_schema) {
+                Some(fields) if !fields.contains(field) => {
+                    walk.lineage.push(FieldLineage {
+                        source_field: walk.source_field.to_string(),
+                        hops: walk.hops.clone(),
+                        outcome: LineageOutcome::Dropped {
+                            schema: to_schema.name.clone(),
+                        },
+                    });
+                }
+                _ => {
+                    walk.on_path.push(*to);
+                    self.follow(*to, &to_schema.name, field, walk);
+                    walk.on_path.pop();
+                }
+            }
+            walk.hops.pop();
+        }
+
+        if !continued {
+            walk.lineage.push(FieldLineage {
+                source_field: walk.source_field.to_string(),
+                hops: walk.hops.clone(),
+                outcome: LineageOutcome::Sink {
+                    schema: schema_name.to_string(),
+                    field: field.to_string(),
+                },
+            });
+        }
+    }
+
+    /// Schema of a schema node, or the source schema of a node's outgoing data flows
+    fn node_schema(&self, node: NodeId) -> Option<SchemaReference> {
+        if let Some(CallNode::Schema { schema }) = self.graph.node_weight(node.0) {
+            return Some(schema.clone());
+        }
+        self.graph
+            .edges(node.0)
+            .find_map(|edge| match edge.weight() {
+                CallEdge::DataFlow { from_schema, .. } if edge.target() != node.0 => {
+                    Some(from_schema.clone())
+                }
+                _ => None,
+            })
+    }
+
+    /// Field names of a schema, `None` when its shape is unknown
+    fn schema_fields(schema: &SchemaReference) -> Option<BTreeSet<String>> {
+        let fields: BTreeSet<String> = SchemaParser::parse(schema)
+            .ok()?
+            .properties
+            .into_keys()
+            .collect();
+        (!fields.is_empty()).then_some(fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Location, SchemaType};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn schema(name: &str, schema_type: SchemaType, fields: &str) -> SchemaReference {
+        let mut metadata = HashMap::new();
+        if !fields.is_empty() {
+            metadata.insert("fields".to_string(), fields.to_string());
+        }
+        SchemaReference {
+            name: name.to_string(),
+            schema_type,
+            location: Location {
+                file: "models.py".to_string(),
+                line: 1,
+                column: None,
+            },
+            metadata,
+        }
+    }
+
+    fn class(graph: &mut CallGraph, name: &str) -> NodeId {
+        NodeId::from(graph.add_node(CallNode::Class {
+            name: name.to_string(),
+            file: PathBuf::from("models.py"),
+            methods: Vec::new(),
+        }))
+    }
+
+    fn data_flow(
+        graph: &mut CallGraph,
+        (from, from_schema): (NodeId, &SchemaReference),
+        (to, to_schema): (NodeId, &SchemaReference),
+        transformation: TransformationType,
+    ) {
+        graph.add_edge(
+            from.0,
+            to.0,
+            CallEdge::DataFlow {
+                from,
+                to,
+                from_schema: from_schema.clone(),
+                to_schema: Box::new(to_schema.clone()),
+                location: from_schema.location.clone(),
+                transformation: Some(transformation),
+            },
+        );
+    }
+
+    #[test]
+    fn test_fields_flow_to_sinks_or_are_dropped() {
+        let mut graph = CallGraph::new();
+        let user_create = schema(
+            "UserCreate",
+            SchemaType::Pydantic,
+            "email:str,password:str,nickname:str",
+        );
+        let user_orm = schema(
+            "User",
+            SchemaType::OrmModel,
+            r#"[{"name":"id","type_name":"Integer","nullable":false},{"name":"email","type_name":"String","nullable":false},{"name":"password","type_name":"String","nullable":false}]"#,
+        );
+        let payload = schema("Json", SchemaType::JsonSchema, "");
+        let create = class(&mut graph, "UserCreate");
+        let orm = class(&mut graph, "User");
+        let json = NodeId::from(graph.add_node(CallNode::Schema {
+            schema: payload.clone(),
+        }));
+
+        data_flow(
+            &mut graph,
+            (create, &user_create),
+            (orm, &user_orm),
+            TransformationType::PydanticToOrm,
+        );
+        // The reverse ORM → Pydantic edge must not loop back into the start
+        data_flow(
+            &mut graph,
+            (orm, &user_orm),
+            (create, &user_create),
+            TransformationType::OrmToPydantic,
+        );
+        data_flow(
+            &mut graph,
+            (orm, &user_orm),
+            (json, &payload),
+            TransformationType::ToJson,
+        );
+
+        let lineage = LineageAnalyzer::new(&graph).field_lineage(create);
+
+        let outcomes: Vec<(&str, &LineageOutcome)> = lineage
+            .iter()
+            .map(|entry| (entry.source_field.as_str(), &entry.outcome))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                (
+                    "email",
+                    &LineageOutcome::Sink {
+                        schema: "Json".to_string(),
+                        field: "email".to_string(),
+                    }
+                ),
+                (
+                    "nickname",
+                    &LineageOutcome::Dropped {
+                        schema: "User".to_string(),
+                    }
+                ),
+                (
+                    "password",
+                    &LineageOutcome::Sink {
+                        schema: "Json".to_string(),
+                        field: "password".to_string(),
+                    }
+                ),
+            ]
+        );
+        assert_eq!(
+            lineage[0].hops,
+            vec![
+                LineageHop {
+                    schema: "User".to_string(),
+                    transformation: Some(TransformationType::PydanticToOrm),
+                },
+                LineageHop {
+                    schema: "Json".to_string(),
+                    transformation: Some(TransformationType::ToJson),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_schema_without_data_flows_is_its_own_sink() {
+        let mut graph = CallGraph::new();
+        let item = NodeId::from(graph.add_node(CallNode::Schema {
+            schema: schema("Item", SchemaType::Pydantic, "name:str"),
+        }));
+
+        let lineage = LineageAnalyzer::new(&graph).field_lineage(item);
+
+        assert_eq!(lineage.len(), 1);
+        assert!(lineage[0].hops.is_empty());
+        assert_eq!(
+            lineage[0].outcome,
+            LineageOutcome::Sink {
+                schema: "Item".to_string(),
+                field: "name".to_string(),
+            }
+        );
+    }
+}
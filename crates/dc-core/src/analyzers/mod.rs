@@ -1,9 +1,31 @@
 pub mod chain;
+pub mod coercion;
 pub mod contract;
+pub mod dependencies;
+pub mod deprecation;
+pub mod endpoints;
+pub mod examples;
+pub mod lineage;
+pub mod operation_ids;
+pub mod pagination;
+pub mod route_matcher;
 pub mod rules;
 pub mod schema_parser;
+pub mod shared;
+pub mod unresolved;
 
 pub use chain::*;
+pub use coercion::*;
 pub use contract::*;
+pub use dependencies::*;
+pub use deprecation::*;
+pub use endpoints::*;
+pub use examples::*;
+pub use lineage::*;
+pub use operation_ids::*;
+pub use pagination::*;
+pub use route_matcher::*;
 pub use rules::*;
 pub use schema_parser::*;
+pub use shared::*;
+pub use unresolved::*;
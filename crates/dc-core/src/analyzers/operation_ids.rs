@@ -0,0 +1,282 @@
+use crate::analyzers::{method_name, normalize_route_path};
+use crate::call_graph::{CallGraph, CallNode, HttpMethod, OPERATION_ID_METADATA_KEY};
+use crate::models::{Diagnostic, Location};
+use crate::openapi::{OpenAPIEndpoint, OpenAPIParser, OpenAPISchema};
+use std::collections::BTreeMap;
+
+/// Backend route that appears in the OpenAPI schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentedRoute {
+    pub method: HttpMethod,
+    /// Path as written in code
+    pub path: String,
+    pub location: Location,
+    /// Explicit `operation_id=` of the route decorator
+    pub operation_id: Option<String>,
+}
+
+impl DocumentedRoute {
+    /// Routes of `graph`, leaving out those declared with `include_in_schema=False`
+    pub fn in_graph(graph: &CallGraph) -> Vec<Self> {
+        graph
+            .node_weights()
+            .filter(|node| !node.is_excluded_from_schema())
+            .filter_map(|node| match node {
+                CallNode::Route {
+                    path,
+                    method,
+                    location,
+                    metadata,
+                    ..
+                } => Some(Self {
+                    method: *method,
+                    path: path.clone(),
+                    location: location.clone(),
+                    operation_id: metadata.get(OPERATION_ID_METADATA_KEY).cloned(),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn describe(&self) -> String {
+        format!("{} {}", method_name(self.method), self.path)
+    }
+
+    /// Whether the route serves the OpenAPI operation's method and path
+    fn serves(&self, operation: &OpenAPIEndpoint) -> bool {
+        method_name(self.method).eq_ignore_ascii_case(&operation.method)
+            && normalize_route_path(&self.path).is_some()
+            && normalize_route_path(&self.path) == normalize_route_path(&operation.path)
+    }
+}
+
+/// Warnings for OpenAPI operation IDs that are not unique or don't map to a handler
+///
+/// Generated clients name their methods after operation IDs, so they must be unique and stay
+/// in sync with the code. Reported:
+/// - an `operation_id` declared by several routes, at every route after the first one;
+/// - an `operationId` used by several operations of the spec;
+/// - an `operation_id` declared by a route that the spec doesn't document, or documents under
+///   another ID, at the route;
+/// - an `operationId` of the spec that no route declares and whose method and path no route
+///   serves (an orphaned operation), at `spec_source`.
+///
+/// Routes without an explicit `operation_id` get a generated one, so they are only matched
+/// against the spec by method and path.
+pub fn operation_id_issues(
+    routes: &[DocumentedRoute],
+    spec: Option<(&str, &OpenAPISchema)>,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    let mut declared: BTreeMap<&str, Vec<&DocumentedRoute>> = BTreeMap::new();
+    for route in routes {
+        if let Some(operation_id) = &route.operation_id {
+            declared
+                .entry(operation_id.as_str())
+                .or_default()
+                .push(route);
+        }
+    }
+    for (operation_id, routes) in &declared {
+        let first = routes[0];
+        for route in &routes[1..] {
+            diagnostics.push(Diagnostic::operation_id(
+                route.location.clone(),
+                format!(
+                    "operation_id '{}' of {} is already used by {} ({}:{})",
+                    operation_id,
+                    route.describe(),
+                    first.describe(),
+                    first.location.file,
+                    first.location.line
+                ),
+            ));
+        }
+    }
+
+    let Some((spec_source, spec)) = spec else {
+        return diagnostics;
+    };
+    // The spec has no line information; its issues point at the start of the file
+    let spec_location = Location {
+        file: spec_source.to_string(),
+        line: 1,
+        column: None,
+    };
+    let mut operations = OpenAPIParser::extract_endpoints(spec);
+    operations.sort_by(|a, b| (&a.path, &a.method).cmp(&(&b.path, &b.method)));
+
+    let mut documented: BTreeMap<&str, Vec<&OpenAPIEndpoint>> = BTreeMap::new();
+    for operation in &operations {
+        if let Some(operation_id) = &operation.operation_id {
+            documented
+                .entry(operation_id.as_str())
+                .or_default()
+                .push(operation);
+        }
+    }
+    for (operation_id, operations) in &documented {
+        if operations.len() > 1 {
+            let described: Vec<String> = operations
+                .iter()
+                .map(|operation| describe_operation(operation))
+                .collect();
+            diagnostics.push(Diagnostic::operation_id(
+                spec_location.clone(),
+                format!(
+                    "OpenAPI operationId '{}' is used by several operations: {}",
+                    operation_id,
+                    described.join(", ")
+                ),
+            ));
+        }
+    }
+
+    for route in routes {
+        let Some(operation_id) = &route.operation_id else {
+            continue;
+        };
+        if documented.contains_key(operation_id.as_str()) {
+            continue;
+        }
+        let documented_as = operations
+            .iter()
+            .find(|operation| route.serves(operation))
+            .and_then(|operation| operation.operation_id.as_deref());
+        let message = match documented_as {
+            Some(other) => format!(
+                "{} declares operation_id '{}', but the OpenAPI schema documents it as '{}'",
+                route.describe(),
+                operation_id,
+                other
+            ),
+            None => format!(
+                "operation_id '{}' of {} is missing from the OpenAPI schema",
+                operation_id,
+                route.describe()
+            ),
+        };
+        diagnostics.push(Diagnostic::operation_id(route.location.clone(), message));
+    }
+
+    for (operation_id, operations) in &documented {
+        if declared.contains_key(operation_id) {
+            continue;
+        }
+        for operation in operations {
+            if routes.iter().any(|route| route.serves(operation)) {
+                continue;
+            }
+            diagnostics.push(Diagnostic::operation_id(
+                spec_location.clone(),
+                format!(
+                    "OpenAPI operationId '{}' ({}) doesn't map to any route",
+                    operation_id,
+                    describe_operation(operation)
+                ),
+            ));
+        }
+    }
+
+    diagnostics
+}
+
+fn describe_operation(operation: &OpenAPIEndpoint) -> String {
+    format!("{} {}", operation.method.to_uppercase(), operation.path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(
+        method: HttpMethod,
+        path: &str,
+        line: usize,
+        operation_id: Option<&str>,
+    ) -> DocumentedRoute {
+        DocumentedRoute {
+            method,
+            path: path.to_string(),
+            location: Location {
+                file: "app/main.py".to_string(),
+                line,
+                column: None,
+            },
+            operation_id: operation_id.map(str::to_string),
+        }
+    }
+
+    fn spec() -> OpenAPISchema {
+        OpenAPIParser::parse_str(
+            r#"{
+                "openapi": "3.0.0",
+                "info": {"title": "api", "version": "1"},
+                "paths": {
+                    "/users": {
+                        "get": {"operationId": "listUsers", "responses": {}},
+                        "post": {"operationId": "createUser", "responses": {}}
+                    },
+                    "/users/{user_id}": {
+                        "get": {"operationId": "read_user_users__user_id__get", "responses": {}},
+                        "delete": {"operationId": "deleteUser", "responses": {}}
+                    },
+                    "/teams": {
+                        "get": {"operationId": "listUsers", "responses": {}}
+                    }
+                }
+            }"#,
+        )
+        .expect("valid spec")
+    }
+
+    #[test]
+    fn test_duplicate_operation_ids_are_reported() {
+        let routes = vec![
+            route(HttpMethod::Get, "/users", 3, Some("listUsers")),
+            route(HttpMethod::Get, "/admins", 9, Some("listUsers")),
+            route(HttpMethod::Get, "/health", 12, None),
+        ];
+
+        let diagnostics = operation_id_issues(&routes, None);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].location.line, 9);
+        assert_eq!(
+            diagnostics[0].message,
+            "operation_id 'listUsers' of GET /admins is already used by GET /users \
+             (app/main.py:3)"
+        );
+    }
+
+    #[test]
+    fn test_spec_operation_ids_are_matched_to_routes() {
+        let routes = vec![
+            route(HttpMethod::Get, "/users", 3, Some("listUsers")),
+            route(HttpMethod::Post, "/users", 6, Some("addUser")),
+            route(HttpMethod::Get, "/users/{id}", 9, None),
+            route(HttpMethod::Put, "/users/{id}", 12, Some("updateUser")),
+        ];
+        let spec = spec();
+
+        let diagnostics = operation_id_issues(&routes, Some(("openapi.json", &spec)));
+
+        let messages: Vec<_> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "OpenAPI operationId 'listUsers' is used by several operations: GET /teams, \
+                 GET /users",
+                "POST /users declares operation_id 'addUser', but the OpenAPI schema documents \
+                 it as 'createUser'",
+                "operation_id 'updateUser' of PUT /users/{id} is missing from the OpenAPI schema",
+                "OpenAPI operationId 'deleteUser' (DELETE /users/{user_id}) doesn't map to any \
+                 route",
+            ]
+        );
+        assert_eq!(diagnostics[0].location.file, "openapi.json");
+        assert_eq!(diagnostics[1].location.line, 6);
+    }
+}
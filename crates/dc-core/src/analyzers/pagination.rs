@@ -0,0 +1,205 @@
+use crate::analyzers::{method_name, EndpointAnalyzer, HttpEndpoint, SchemaParser};
+use crate::models::{BaseType, Diagnostic, SchemaReference};
+use std::collections::BTreeMap;
+
+/// Field names marking a schema with a list field as a page of results
+const PAGINATION_FIELDS: [&str; 16] = [
+    "total",
+    "count",
+    "total_count",
+    "totalCount",
+    "page",
+    "pages",
+    "size",
+    "page_size",
+    "pageSize",
+    "limit",
+    "offset",
+    "next",
+    "previous",
+    "cursor",
+    "next_cursor",
+    "nextCursor",
+];
+
+/// Sorted field names of a schema that is a page of results: it has a list field and a
+/// pagination field (`total`, `page`, `size`, `limit`, `next`, `cursor`, ...)
+pub fn pagination_envelope(schema: &SchemaReference) -> Option<Vec<String>> {
+    let parsed = SchemaParser::parse(schema).ok()?;
+    let has_list = parsed
+        .properties
+        .values()
+        .any(|field| field.base_type == BaseType::Array);
+    let has_pagination = parsed
+        .properties
+        .keys()
+        .any(|name| PAGINATION_FIELDS.contains(&name.as_str()));
+    if !has_list || !has_pagination {
+        return None;
+    }
+    let mut fields: Vec<String> = parsed.properties.into_keys().collect();
+    fields.sort();
+    Some(fields)
+}
+
+/// Warnings for paginated routes whose envelope differs from the other paginated routes
+///
+/// The envelope most paginated routes return (the first one in field order on a tie) is
+/// the convention; every route returning another one is reported.
+pub fn inconsistent_pagination<'a>(
+    routes: impl IntoIterator<Item = &'a HttpEndpoint>,
+) -> Vec<Diagnostic> {
+    let mut by_envelope: BTreeMap<&[String], Vec<&HttpEndpoint>> = BTreeMap::new();
+    for route in routes {
+        if let Some(envelope) = &route.response_envelope {
+            by_envelope.entry(envelope).or_default().push(route);
+        }
+    }
+    let Some((convention, followers)) = by_envelope
+        .iter()
+        .rev()
+        .max_by_key(|(_, routes)| routes.len())
+        .map(|(envelope, routes)| (*envelope, routes.len()))
+    else {
+        return Vec::new();
+    };
+
+    let mut diagnostics = Vec::new();
+    for (envelope, routes) in &by_envelope {
+        if *envelope == convention {
+            continue;
+        }
+        for route in routes {
+            diagnostics.push(Diagnostic::pagination_shape(
+                route.location.clone(),
+                format!(
+                    "{} {} returns the pagination envelope {{{}}}, while {} other paginated \
+                     route(s) return {{{}}}",
+                    method_name(route.method),
+                    route.path,
+                    envelope.join(", "),
+                    followers,
+                    convention.join(", ")
+                ),
+            ));
+        }
+    }
+    diagnostics
+}
+
+/// Warnings for frontend calls reading pagination fields their paginated route doesn't return
+///
+/// Only calls whose response type is itself a page are compared; fields of the envelope the
+/// call ignores are fine.
+pub fn pagination_call_mismatches(
+    analyzer: &EndpointAnalyzer,
+    calls: &[HttpEndpoint],
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for call in calls {
+        let Some(expected) = &call.response_envelope else {
+            continue;
+        };
+        let Some(route) = analyzer.resolve_call(call) else {
+            continue;
+        };
+        let Some(envelope) = &route.response_envelope else {
+            continue;
+        };
+        let missing: Vec<&str> = expected
+            .iter()
+            .filter(|field| !envelope.contains(field))
+            .map(String::as_str)
+            .collect();
+        if missing.is_empty() {
+            continue;
+        }
+        diagnostics.push(Diagnostic::pagination_shape(
+            call.location.clone(),
+            format!(
+                "Call to {} {} reads pagination field(s) {{{}}} that the route's envelope \
+                 {{{}}} doesn't return",
+                method_name(route.method),
+                route.path,
+                missing.join(", "),
+                envelope.join(", ")
+            ),
+        ));
+    }
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::call_graph::HttpMethod;
+    use crate::models::{Location, SchemaType};
+    use std::collections::HashMap;
+
+    fn endpoint(path: &str, file: &str, fields: &str) -> HttpEndpoint {
+        let schema = SchemaReference {
+            name: "Page".to_string(),
+            schema_type: SchemaType::TypeScript,
+            location: Location {
+                file: file.to_string(),
+                line: 1,
+                column: None,
+            },
+            metadata: HashMap::from([("fields".to_string(), fields.to_string())]),
+        };
+        HttpEndpoint {
+            method: HttpMethod::Get,
+            path: path.to_string(),
+            location: schema.location.clone(),
+            deprecated: false,
+            request_schema: None,
+            response_envelope: pagination_envelope(&schema),
+            query_parameters: Vec::new(),
+            sent_query: Vec::new(),
+            response_schema: Some(schema),
+            required_headers: Vec::new(),
+            sent_headers: None,
+            media_type: None,
+        }
+    }
+
+    #[test]
+    fn test_pages_need_a_list_and_a_pagination_field() {
+        let page = endpoint("/users", "api.ts", "items:array,total:number");
+        assert_eq!(
+            page.response_envelope,
+            Some(vec!["items".to_string(), "total".to_string()])
+        );
+        assert_eq!(
+            endpoint("/users", "api.ts", "items:array,owner:string").response_envelope,
+            None
+        );
+        assert_eq!(
+            endpoint("/users", "api.ts", "total:number").response_envelope,
+            None
+        );
+    }
+
+    #[test]
+    fn test_calls_reading_missing_pagination_fields_are_reported() {
+        let analyzer = EndpointAnalyzer::new(vec![endpoint(
+            "/users",
+            "main.py",
+            "items:array,total:number,page:number",
+        )]);
+        let calls = [
+            endpoint("\"/users\"", "web/users.ts", "items:array,total:number"),
+            endpoint("\"/users\"", "web/admin.ts", "items:array,next:string"),
+        ];
+
+        let diagnostics = pagination_call_mismatches(&analyzer, &calls);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].location.file, "web/admin.ts");
+        assert_eq!(
+            diagnostics[0].message,
+            "Call to GET /users reads pagination field(s) {next} that the route's envelope \
+             {items, page, total} doesn't return"
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use crate::analyzers::endpoints::{normalize_route_path, route_path_part};
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Strategy deciding whether a frontend call path reaches a backend route path
+///
+/// [`EndpointAnalyzer`](crate::analyzers::EndpointAnalyzer) asks the matcher about every
+/// route and resolves, or reports as ambiguous, the calls from the returned confidences.
+/// Paths are passed as written in code (`"/users/42"`, `` `/users/${id}` ``,
+/// `/users/{user_id}`).
+pub trait RouteMatcher: Send + Sync {
+    /// How `call_path` matches `route_path`, or None when the call can't reach the route
+    fn match_route(&self, call_path: &str, route_path: &str) -> Option<RouteMatch>;
+}
+
+/// Confidence of a call matching a route
+///
+/// When a call matches several routes of its method, the route with the most literal
+/// segments wins if every match is certain and no other route has as many; otherwise the
+/// call is reported as ambiguous.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RouteMatch {
+    /// Literal route segments the call spells out (specificity of the match)
+    pub literals: usize,
+    /// False when the call may also not reach the route (e.g. a dynamic call segment
+    /// landing on a literal route segment)
+    pub certain: bool,
+}
+
+/// Matches normalized path templates (the default)
+///
+/// Literal segments must be equal, a route parameter accepts any segment, a catch-all
+/// parameter (`{path:path}`, `*`) one or more segments, and a dynamic call segment (`${id}`)
+/// may hit either a parameter or a literal (an uncertain match). See
+/// [`normalize_route_path`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TemplateMatcher;
+
+impl RouteMatcher for TemplateMatcher {
+    fn match_route(&self, call_path: &str, route_path: &str) -> Option<RouteMatch> {
+        let (call, route) = (
+            normalize_route_path(call_path)?,
+            normalize_route_path(route_path)?,
+        );
+        let (call, route) = (split_segments(&call), split_segments(&route));
+        let mut result = RouteMatch {
+            literals: 0,
+            certain: true,
+        };
+        for (idx, route_segment) in route.iter().enumerate() {
+            if *route_segment == "{*}" {
+                // Catch-all consumes at least one remaining segment
+                return (call.len() > idx).then_some(result);
+            }
+            let call_segment = call.get(idx)?;
+            match (*call_segment, *route_segment) {
+                (_, "{}") => {}
+                ("{}" | "{*}", _) => result.certain = false,
+                (literal, route_literal) if literal == route_literal => result.literals += 1,
+                _ => return None,
+            }
+        }
+        (call.len() == route.len()).then_some(result)
+    }
+}
+
+/// Matches only calls spelling the route path exactly (`"/users/me"` for `/users/me`)
+///
+/// Quotes, host, query string and trailing slashes are ignored; calls with dynamic segments
+/// never reach parameterized routes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExactMatcher;
+
+impl RouteMatcher for ExactMatcher {
+    fn match_route(&self, call_path: &str, route_path: &str) -> Option<RouteMatch> {
+        let call = route_path_part(call_path)?.trim_end_matches('/');
+        let route = route_path_part(route_path)?.trim_end_matches('/');
+        (call == route).then(|| RouteMatch {
+            literals: split_segments(route).len(),
+            certain: true,
+        })
+    }
+}
+
+/// Matches calls against route regexes
+///
+/// Each route path is compiled into an anchored regex: parameters (`{id}`, `:id`, `<id>`)
+/// match one segment and catch-alls (`{path:path}`, `*`) any rest of the path. Routes with
+/// unusual shapes (optional segments, versioned prefixes) get a custom regex through
+/// [`Self::with_pattern`]. Dynamic call segments (`${id}`) only match parameters, so
+/// matches are always certain.
+#[derive(Debug, Default)]
+pub struct RegexMatcher {
+    /// Custom regexes by route path
+    patterns: HashMap<String, Regex>,
+    /// Regexes compiled from route paths so far
+    compiled: Mutex<HashMap<String, Option<Regex>>>,
+}
+
+impl RegexMatcher {
+    /// Creates a matcher compiling every route path into its regex
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches calls to `route_path` with `pattern` instead of the regex compiled from the
+    /// path (e.g. `^/files(/[^/]+)?$` for a route serving an optional segment)
+    ///
+    /// The pattern is matched against the normalized call path (see
+    /// [`normalize_route_path`]), where dynamic segments read `{}`.
+    pub fn with_pattern(mut self, route_path: &str, pattern: &str) -> Result<Self, regex::Error> {
+        self.patterns
+            .insert(route_path.to_string(), Regex::new(pattern)?);
+        Ok(self)
+    }
+
+    /// Anchored regex of a route path; None when the path can't be normalized
+    fn route_regex(route_path: &str) -> Option<Regex> {
+        let normalized = normalize_route_path(route_path)?;
+        let segments: Vec<String> = split_segments(&normalized)
+            .into_iter()
+            .map(|segment| match segment {
+                "{}" => "/[^/]+".to_string(),
+                "{*}" => "/.+".to_string(),
+                literal => format!("/{}", regex::escape(literal)),
+            })
+            .collect();
+        Regex::new(&format!("^{}/?$", segments.concat())).ok()
+    }
+}
+
+impl RouteMatcher for RegexMatcher {
+    fn match_route(&self, call_path: &str, route_path: &str) -> Option<RouteMatch> {
+        // Dynamic call segments become `{}`, which parameters match and literals don't
+        let call = normalize_route_path(call_path)?;
+        let matched = match self.patterns.get(route_path) {
+            Some(pattern) => pattern.is_match(&call),
+            None => {
+                let mut compiled = self.compiled.lock().ok()?;
+                compiled
+                    .entry(route_path.to_string())
+                    .or_insert_with(|| Self::route_regex(route_path))
+                    .as_ref()?
+                    .is_match(&call)
+            }
+        };
+        matched.then(|| RouteMatch {
+            literals: split_segments(&call)
+                .into_iter()
+                .filter(|segment| !segment.starts_with('{'))
+                .count(),
+            certain: true,
+        })
+    }
+}
+
+fn split_segments(path: &str) -> Vec<&str> {
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_matcher_requires_the_same_path() {
+        let matcher = ExactMatcher;
+        assert_eq!(
+            matcher.match_route("\"/users/me/\"", "/users/me"),
+            Some(RouteMatch {
+                literals: 2,
+                certain: true
+            })
+        );
+        assert_eq!(matcher.match_route("`/users/${id}`", "/users/{id}"), None);
+    }
+
+    #[test]
+    fn test_regex_matcher_uses_custom_patterns() {
+        let matcher = RegexMatcher::new()
+            .with_pattern("/files/{rest}", r"^/files(/[^/]+)?$")
+            .unwrap();
+
+        assert!(matcher.match_route("\"/files\"", "/files/{rest}").is_some());
+        assert!(matcher
+            .match_route("\"/files/a\"", "/files/{rest}")
+            .is_some());
+        assert!(matcher
+            .match_route("`/users/${id}`", "/users/{user_id}")
+            .is_some());
+        assert!(matcher.match_route("`/users/${id}`", "/users/me").is_none());
+        assert!(matcher
+            .match_route("\"/static/css/app.css\"", "/static/{path:path}")
+            .is_some());
+    }
+}
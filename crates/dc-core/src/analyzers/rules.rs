@@ -1,5 +1,8 @@
-use crate::analyzers::schema_parser::SchemaParser;
-use crate::models::{BaseType, Contract, Mismatch, MismatchType, SeverityLevel, TypeInfo};
+use crate::analyzers::schema_parser::{populates_by_name, FieldInfo, SchemaParser, SchemaRole};
+use crate::models::{
+    BaseType, Constraint, ConstraintValue, Contract, DiscriminatedUnion, Mismatch, MismatchType,
+    PydanticFieldInfo, SchemaReference, SchemaType, SeverityLevel, TypeCompatibility, TypeInfo,
+};
 
 /// Trait for contract checking rules
 pub trait ContractRule: Send + Sync {
@@ -11,17 +14,33 @@ pub trait ContractRule: Send + Sync {
 }
 
 /// Type mismatch checking rule
-pub struct TypeMismatchRule;
+///
+/// Fields whose declared type names are equivalent in the compatibility matrix
+/// (e.g. a custom `Money` ↔ `string`) are not reported.
+#[derive(Default)]
+pub struct TypeMismatchRule {
+    type_compatibility: TypeCompatibility,
+}
+
+impl TypeMismatchRule {
+    /// Creates the rule with a custom compatibility matrix
+    pub fn new(type_compatibility: TypeCompatibility) -> Self {
+        Self { type_compatibility }
+    }
+}
 
 impl ContractRule for TypeMismatchRule {
     fn check(&self, contract: &Contract) -> Vec<Mismatch> {
         let mut mismatches = Vec::new();
 
         // Parse schemas
-        let Ok(from_schema) = SchemaParser::parse(&contract.from_schema) else {
+        let Ok(from_schema) =
+            SchemaParser::parse_sent_to(&contract.from_schema, &contract.to_schema)
+        else {
             return mismatches;
         };
-        let Ok(to_schema) = SchemaParser::parse(&contract.to_schema) else {
+        let Ok(to_schema) = SchemaParser::parse_as(&contract.to_schema, SchemaRole::Receiver)
+        else {
             return mismatches;
         };
 
@@ -29,7 +48,11 @@ impl ContractRule for TypeMismatchRule {
         for (field_name, from_field) in &from_schema.properties {
             if let Some(to_field) = to_schema.properties.get(field_name) {
                 // Check type mismatch
-                if from_field.base_type != to_field.base_type {
+                if from_field.base_type != to_field.base_type
+                    && !self
+                        .type_compatibility
+                        .declared_equivalent(&from_field.field_type, &to_field.field_type)
+                {
                     mismatches.push(Mismatch {
                         mismatch_type: MismatchType::TypeMismatch,
                         path: field_name.clone(),
@@ -51,6 +74,7 @@ impl ContractRule for TypeMismatchRule {
                             field_name, from_field.base_type, to_field.base_type
                         ),
                         severity_level: SeverityLevel::High,
+                        occurrences: 1,
                     });
                 }
             }
@@ -72,18 +96,32 @@ impl ContractRule for MissingFieldRule {
         let mut mismatches = Vec::new();
 
         // Parse schemas
-        let Ok(from_schema) = SchemaParser::parse(&contract.from_schema) else {
+        let Ok(from_schema) =
+            SchemaParser::parse_sent_to(&contract.from_schema, &contract.to_schema)
+        else {
             return mismatches;
         };
-        let Ok(to_schema) = SchemaParser::parse(&contract.to_schema) else {
+        let Ok(to_schema) = SchemaParser::parse_as(&contract.to_schema, SchemaRole::Receiver)
+        else {
             return mismatches;
         };
 
+        // Python names of aliased fields are reported by `FieldAliasRule`
+        let renamed: Vec<String> =
+            FieldAliasRule::renamed_fields(&contract.from_schema, SchemaRole::Sender)
+                .into_iter()
+                .map(|(attribute, _)| attribute)
+                .collect();
+
         // Check required fields in target schema
         for required_field in &to_schema.required {
+            let to_field = to_schema.properties.get(required_field);
+            // Response-only fields are generated by the server, senders must not provide them
+            if to_field.is_some_and(|f| f.response_only) || renamed.contains(required_field) {
+                continue;
+            }
             if !from_schema.properties.contains_key(required_field) {
                 // Field is missing in source schema
-                let to_field = to_schema.properties.get(required_field);
                 mismatches.push(Mismatch {
                     mismatch_type: MismatchType::MissingField,
                     path: required_field.clone(),
@@ -105,6 +143,7 @@ impl ContractRule for MissingFieldRule {
                         required_field
                     ),
                     severity_level: SeverityLevel::High,
+                    occurrences: 1,
                 });
             }
         }
@@ -115,8 +154,10 @@ impl ContractRule for MissingFieldRule {
             // Additional check: ensure field is not already in required list
             // This prevents duplicate mismatches for the same field
             if !to_field.optional
+                && !to_field.response_only
                 && !from_schema.properties.contains_key(field_name)
                 && !to_schema.required.contains(field_name)
+                && !renamed.contains(field_name)
             {
                 // Add to required if not already there
                 mismatches.push(Mismatch {
@@ -137,6 +178,7 @@ impl ContractRule for MissingFieldRule {
                     location: contract.from_schema.location.clone(),
                     message: format!("Missing required field '{}' in source schema", field_name),
                     severity_level: SeverityLevel::High,
+                    occurrences: 1,
                 });
             }
         }
@@ -149,6 +191,471 @@ impl ContractRule for MissingFieldRule {
     }
 }
 
+/// Response-only field checking rule
+///
+/// Flags frontend schemas (Zod/TypeScript) that send a field the backend marks as
+/// response-only (`readOnly` in OpenAPI, `Field(frozen=True)`, Read-only half of a Create/Read split).
+pub struct ResponseOnlyFieldRule;
+
+impl ContractRule for ResponseOnlyFieldRule {
+    fn check(&self, contract: &Contract) -> Vec<Mismatch> {
+        let mut mismatches = Vec::new();
+
+        if !matches!(
+            contract.from_schema.schema_type,
+            SchemaType::Zod | SchemaType::TypeScript
+        ) {
+            return mismatches;
+        }
+
+        // Parse schemas
+        let Ok(from_schema) =
+            SchemaParser::parse_sent_to(&contract.from_schema, &contract.to_schema)
+        else {
+            return mismatches;
+        };
+        let Ok(to_schema) = SchemaParser::parse_as(&contract.to_schema, SchemaRole::Receiver)
+        else {
+            return mismatches;
+        };
+
+        for (field_name, from_field) in &from_schema.properties {
+            let Some(to_field) = to_schema.properties.get(field_name) else {
+                continue;
+            };
+            if !to_field.response_only {
+                continue;
+            }
+
+            mismatches.push(Mismatch {
+                mismatch_type: MismatchType::ResponseOnlyField,
+                path: field_name.clone(),
+                expected: TypeInfo {
+                    base_type: BaseType::Unknown,
+                    schema_ref: None,
+                    constraints: Vec::new(),
+                    optional: true,
+                },
+                actual: TypeInfo {
+                    base_type: from_field.base_type,
+                    schema_ref: None,
+                    constraints: from_field.constraints.clone(),
+                    optional: from_field.optional,
+                },
+                location: contract.from_schema.location.clone(),
+                message: format!(
+                    "Field '{}' is response-only in '{}' and should not be sent by the client",
+                    field_name, contract.to_schema.name
+                ),
+                severity_level: SeverityLevel::Medium,
+                occurrences: 1,
+            });
+        }
+
+        mismatches
+    }
+
+    fn name(&self) -> &str {
+        "response_only_field"
+    }
+}
+
+/// Unknown field checking rule
+///
+/// Flags fields a frontend schema (Zod/TypeScript) sends that the receiving Pydantic model
+/// doesn't declare. Models with `extra="forbid"` (v2 `model_config`/`ConfigDict`, v1
+/// `class Config`) reject such requests with a 422, so the field is reported as high severity;
+/// with an explicit `allow`/`ignore` the field is accepted or dropped and the mismatch is low
+/// severity. Models that don't configure `extra` are not checked.
+pub struct ExtraFieldRule;
+
+impl ExtraFieldRule {
+    /// `extra` setting of a Pydantic model (`forbid`, `allow`, `ignore`), if configured
+    fn extra_setting(schema: &SchemaReference) -> Option<&str> {
+        let value = schema.metadata.get("model_config.extra")?;
+        // "forbid", Extra.forbid, "Extra.forbid"
+        Some(
+            value
+                .trim_matches(['"', '\''])
+                .rsplit('.')
+                .next()
+                .unwrap_or(value),
+        )
+    }
+}
+
+impl ContractRule for ExtraFieldRule {
+    fn check(&self, contract: &Contract) -> Vec<Mismatch> {
+        let mut mismatches = Vec::new();
+
+        if !matches!(
+            contract.from_schema.schema_type,
+            SchemaType::Zod | SchemaType::TypeScript
+        ) || contract.to_schema.schema_type != SchemaType::Pydantic
+        {
+            return mismatches;
+        }
+        let (severity_level, consequence) = match Self::extra_setting(&contract.to_schema) {
+            Some("forbid") => (SeverityLevel::High, "requests with it are rejected"),
+            Some("allow") => (SeverityLevel::Low, "it is accepted as an extra field"),
+            Some("ignore") => (SeverityLevel::Low, "it is silently dropped"),
+            _ => return mismatches,
+        };
+
+        // Parse schemas
+        let Ok(from_schema) =
+            SchemaParser::parse_sent_to(&contract.from_schema, &contract.to_schema)
+        else {
+            return mismatches;
+        };
+        let Ok(to_schema) = SchemaParser::parse_as(&contract.to_schema, SchemaRole::Receiver)
+        else {
+            return mismatches;
+        };
+        if to_schema.properties.is_empty() {
+            return mismatches;
+        }
+
+        // Python names of aliased fields are reported by `FieldAliasRule`
+        let renamed: Vec<String> =
+            FieldAliasRule::renamed_fields(&contract.to_schema, SchemaRole::Receiver)
+                .into_iter()
+                .map(|(attribute, _)| attribute)
+                .collect();
+        let mut field_names: Vec<&String> = from_schema
+            .properties
+            .keys()
+            .filter(|name| !to_schema.properties.contains_key(*name) && !renamed.contains(name))
+            .collect();
+        field_names.sort();
+        for field_name in field_names {
+            let from_field = &from_schema.properties[field_name];
+            mismatches.push(Mismatch {
+                mismatch_type: MismatchType::ExtraField,
+                path: field_name.clone(),
+                expected: TypeInfo {
+                    base_type: BaseType::Unknown,
+                    schema_ref: None,
+                    constraints: Vec::new(),
+                    optional: true,
+                },
+                actual: TypeInfo {
+                    base_type: from_field.base_type,
+                    schema_ref: None,
+                    constraints: from_field.constraints.clone(),
+                    optional: from_field.optional,
+                },
+                location: contract.from_schema.location.clone(),
+                message: format!(
+                    "Field '{}' is not declared in '{}' (extra=\"{}\"): {}",
+                    field_name,
+                    contract.to_schema.name,
+                    Self::extra_setting(&contract.to_schema).unwrap_or_default(),
+                    consequence
+                ),
+                severity_level,
+                occurrences: 1,
+            });
+        }
+
+        mismatches
+    }
+
+    fn name(&self) -> &str {
+        "extra_field"
+    }
+}
+
+/// Aliased field checking rule
+///
+/// Flags frontend schemas (Zod/TypeScript) that use the Python attribute name of a Pydantic
+/// field renamed on the wire with `Field(alias=...)` (or `validation_alias` for requests,
+/// `serialization_alias` for responses). A request field under the attribute name is not
+/// read by the model, unless it sets `populate_by_name`; a response field under it is never
+/// sent.
+pub struct FieldAliasRule;
+
+impl FieldAliasRule {
+    /// `(attribute name, wire name)` of the fields a Pydantic model renames in the given role
+    ///
+    /// A receiving model configured with `populate_by_name` reads fields under either name,
+    /// so it renames none.
+    pub fn renamed_fields(schema: &SchemaReference, role: SchemaRole) -> Vec<(String, String)> {
+        if schema.schema_type != SchemaType::Pydantic
+            || (role == SchemaRole::Receiver && populates_by_name(schema))
+        {
+            return Vec::new();
+        }
+        let fields: Vec<PydanticFieldInfo> = schema
+            .metadata
+            .get("fields")
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+        fields
+            .iter()
+            .filter_map(|field| {
+                let wire_name = match role {
+                    SchemaRole::Sender => field.output_name(),
+                    SchemaRole::Receiver => field.input_name(),
+                };
+                (wire_name != field.name).then(|| (field.name.clone(), wire_name.to_string()))
+            })
+            .collect()
+    }
+}
+
+impl ContractRule for FieldAliasRule {
+    fn check(&self, contract: &Contract) -> Vec<Mismatch> {
+        let mut mismatches = Vec::new();
+
+        let is_frontend = |schema: &SchemaReference| {
+            matches!(schema.schema_type, SchemaType::Zod | SchemaType::TypeScript)
+        };
+        let (model, frontend, role) = match (
+            contract.from_schema.schema_type,
+            contract.to_schema.schema_type,
+        ) {
+            (_, SchemaType::Pydantic) if is_frontend(&contract.from_schema) => (
+                &contract.to_schema,
+                &contract.from_schema,
+                SchemaRole::Receiver,
+            ),
+            (SchemaType::Pydantic, _) if is_frontend(&contract.to_schema) => (
+                &contract.from_schema,
+                &contract.to_schema,
+                SchemaRole::Sender,
+            ),
+            _ => return mismatches,
+        };
+
+        let renamed = Self::renamed_fields(model, role);
+        if renamed.is_empty() {
+            return mismatches;
+        }
+        let Ok(frontend_schema) = SchemaParser::parse(frontend) else {
+            return mismatches;
+        };
+        let Ok(model_schema) = SchemaParser::parse_as(model, role) else {
+            return mismatches;
+        };
+
+        for (attribute, wire_name) in renamed {
+            let Some(frontend_field) = frontend_schema.properties.get(&attribute) else {
+                continue;
+            };
+            if frontend_schema.properties.contains_key(&wire_name) {
+                continue;
+            }
+            let model_field = model_schema.properties.get(&wire_name);
+            let (mismatch_type, message) = match role {
+                SchemaRole::Receiver => (
+                    MismatchType::ExtraField,
+                    format!(
+                        "Field '{}' is sent under its Python name; '{}' reads it as '{}'",
+                        attribute, model.name, wire_name
+                    ),
+                ),
+                SchemaRole::Sender => (
+                    MismatchType::MissingField,
+                    format!(
+                        "Field '{}' is read under its Python name; '{}' sends it as '{}'",
+                        attribute, model.name, wire_name
+                    ),
+                ),
+            };
+            mismatches.push(Mismatch {
+                mismatch_type,
+                path: attribute,
+                expected: TypeInfo {
+                    base_type: model_field
+                        .map(|f| f.base_type)
+                        .unwrap_or(BaseType::Unknown),
+                    schema_ref: None,
+                    constraints: model_field
+                        .map(|f| f.constraints.clone())
+                        .unwrap_or_default(),
+                    optional: model_field.is_none_or(|f| f.optional),
+                },
+                actual: TypeInfo {
+                    base_type: frontend_field.base_type,
+                    schema_ref: None,
+                    constraints: frontend_field.constraints.clone(),
+                    optional: frontend_field.optional,
+                },
+                location: frontend.location.clone(),
+                message,
+                severity_level: SeverityLevel::High,
+                occurrences: 1,
+            });
+        }
+
+        mismatches
+    }
+
+    fn name(&self) -> &str {
+        "field_alias"
+    }
+}
+
+/// Discriminated union checking rule
+///
+/// Compares tagged unions member by member: both sides must use the same discriminator field
+/// (by wire name, so a Pydantic tag field aliased to the frontend's name matches) and every tag
+/// one side produces must be accepted by the other. Members are matched by tag value, not by
+/// schema name. Nested unions are compared recursively.
+pub struct DiscriminatedUnionRule;
+
+impl DiscriminatedUnionRule {
+    /// Union declared by the schema itself (`type Pet = Cat | Dog`)
+    fn schema_union(schema: &SchemaReference) -> Option<DiscriminatedUnion> {
+        schema
+            .metadata
+            .get("discriminated_union")
+            .and_then(|json| serde_json::from_str(json).ok())
+    }
+
+    fn compare(
+        contract: &Contract,
+        path: &str,
+        from: &DiscriminatedUnion,
+        to: &DiscriminatedUnion,
+        mismatches: &mut Vec<Mismatch>,
+    ) {
+        let union_type = || TypeInfo {
+            base_type: BaseType::Object,
+            schema_ref: None,
+            constraints: Vec::new(),
+            optional: false,
+        };
+        let mut push = |mismatch_type, message, severity_level| {
+            mismatches.push(Mismatch {
+                mismatch_type,
+                path: path.to_string(),
+                expected: union_type(),
+                actual: union_type(),
+                location: contract.from_schema.location.clone(),
+                message,
+                severity_level,
+                occurrences: 1,
+            })
+        };
+
+        if from.wire_discriminator() != to.wire_discriminator() {
+            push(
+                MismatchType::TypeMismatch,
+                format!(
+                    "Union '{}' is discriminated by '{}' in '{}' but by '{}' in '{}'",
+                    path,
+                    from.wire_discriminator(),
+                    contract.from_schema.name,
+                    to.wire_discriminator(),
+                    contract.to_schema.name
+                ),
+                SeverityLevel::Critical,
+            );
+            return;
+        }
+
+        // Members with unresolved tags cannot be told apart reliably, only tagged ones are reported
+        for member in &from.members {
+            if let (Some(tag), None) = (&member.tag, to.counterpart(member)) {
+                push(
+                    MismatchType::TypeMismatch,
+                    format!(
+                        "Union member '{}' ({} = \"{}\") of '{}' in '{}' is not accepted by '{}'",
+                        member.schema,
+                        from.wire_discriminator(),
+                        tag,
+                        path,
+                        contract.from_schema.name,
+                        contract.to_schema.name
+                    ),
+                    SeverityLevel::High,
+                );
+            }
+        }
+        for member in &to.members {
+            if let (Some(tag), None) = (&member.tag, from.counterpart(member)) {
+                push(
+                    MismatchType::MissingField,
+                    format!(
+                        "Union member '{}' ({} = \"{}\") of '{}' in '{}' is missing in '{}'",
+                        member.schema,
+                        to.wire_discriminator(),
+                        tag,
+                        path,
+                        contract.to_schema.name,
+                        contract.from_schema.name
+                    ),
+                    SeverityLevel::Medium,
+                );
+            }
+        }
+
+        for member in &from.members {
+            let Some(counterpart) = to.counterpart(member) else {
+                continue;
+            };
+            if let (Some(from_nested), Some(to_nested)) = (&member.nested, &counterpart.nested) {
+                let nested_path = format!(
+                    "{}[{}]",
+                    path,
+                    member.tag.as_deref().unwrap_or(&member.schema)
+                );
+                Self::compare(contract, &nested_path, from_nested, to_nested, mismatches);
+            }
+        }
+    }
+}
+
+impl ContractRule for DiscriminatedUnionRule {
+    fn check(&self, contract: &Contract) -> Vec<Mismatch> {
+        let mut mismatches = Vec::new();
+
+        if let (Some(from), Some(to)) = (
+            Self::schema_union(&contract.from_schema),
+            Self::schema_union(&contract.to_schema),
+        ) {
+            Self::compare(
+                contract,
+                &contract.to_schema.name,
+                &from,
+                &to,
+                &mut mismatches,
+            );
+        }
+
+        // Parse schemas
+        let Ok(from_schema) =
+            SchemaParser::parse_sent_to(&contract.from_schema, &contract.to_schema)
+        else {
+            return mismatches;
+        };
+        let Ok(to_schema) = SchemaParser::parse_as(&contract.to_schema, SchemaRole::Receiver)
+        else {
+            return mismatches;
+        };
+
+        for (field_name, from_field) in &from_schema.properties {
+            let Some(to_field) = to_schema.properties.get(field_name) else {
+                continue;
+            };
+            if let (Some(from), Some(to)) = (
+                &from_field.discriminated_union,
+                &to_field.discriminated_union,
+            ) {
+                Self::compare(contract, field_name, from, to, &mut mismatches);
+            }
+        }
+
+        mismatches
+    }
+
+    fn name(&self) -> &str {
+        "discriminated_union"
+    }
+}
+
 /// Unnormalized data checking rule
 pub struct UnnormalizedDataRule;
 
@@ -157,10 +664,13 @@ impl ContractRule for UnnormalizedDataRule {
         let mut mismatches = Vec::new();
 
         // Parse schemas
-        let Ok(from_schema) = SchemaParser::parse(&contract.from_schema) else {
+        let Ok(from_schema) =
+            SchemaParser::parse_sent_to(&contract.from_schema, &contract.to_schema)
+        else {
             return mismatches;
         };
-        let Ok(to_schema) = SchemaParser::parse(&contract.to_schema) else {
+        let Ok(to_schema) = SchemaParser::parse_as(&contract.to_schema, SchemaRole::Receiver)
+        else {
             return mismatches;
         };
 
@@ -201,6 +711,7 @@ impl ContractRule for UnnormalizedDataRule {
                             field_name
                         ),
                         severity_level: SeverityLevel::Medium,
+                        occurrences: 1,
                     });
                 }
 
@@ -237,6 +748,7 @@ impl ContractRule for UnnormalizedDataRule {
                             field_name
                         ),
                         severity_level: SeverityLevel::Medium,
+                        occurrences: 1,
                     });
                 }
             }
@@ -250,6 +762,326 @@ impl ContractRule for UnnormalizedDataRule {
     }
 }
 
+/// Array length checking rule
+///
+/// Compares item count bounds of array fields (`minItems`/`maxItems`, `conlist(min_length=..)`,
+/// `z.array(...).min(n)`). A side without bounds accepts any number of items, so a bound on
+/// only one side is reported too. Divergence is High when the source may send item counts
+/// the target rejects, Low when the source is merely stricter.
+pub struct ArrayLengthRule;
+
+impl ArrayLengthRule {
+    /// (min, max) item count of an array field
+    fn bounds(constraints: &[Constraint]) -> (Option<i64>, Option<i64>) {
+        let mut bounds = (None, None);
+        for constraint in constraints {
+            match constraint {
+                Constraint::Min(ConstraintValue::Integer(min)) => bounds.0 = Some(*min),
+                Constraint::Max(ConstraintValue::Integer(max)) => bounds.1 = Some(*max),
+                _ => {}
+            }
+        }
+        bounds
+    }
+
+    fn describe(bounds: (Option<i64>, Option<i64>)) -> String {
+        match bounds {
+            (None, None) => "any number of items".to_string(),
+            (Some(min), None) => format!("at least {} item(s)", min),
+            (None, Some(max)) => format!("at most {} item(s)", max),
+            (Some(min), Some(max)) if min == max => format!("exactly {} item(s)", min),
+            (Some(min), Some(max)) => format!("{} to {} items", min, max),
+        }
+    }
+}
+
+impl ContractRule for ArrayLengthRule {
+    fn check(&self, contract: &Contract) -> Vec<Mismatch> {
+        let mut mismatches = Vec::new();
+
+        // Parse schemas
+        let Ok(from_schema) =
+            SchemaParser::parse_sent_to(&contract.from_schema, &contract.to_schema)
+        else {
+            return mismatches;
+        };
+        let Ok(to_schema) = SchemaParser::parse_as(&contract.to_schema, SchemaRole::Receiver)
+        else {
+            return mismatches;
+        };
+
+        let mut field_names: Vec<&String> = from_schema.properties.keys().collect();
+        field_names.sort();
+
+        for field_name in field_names {
+            let from_field = &from_schema.properties[field_name];
+            let Some(to_field) = to_schema.properties.get(field_name) else {
+                continue;
+            };
+            if from_field.base_type != BaseType::Array || to_field.base_type != BaseType::Array {
+                continue;
+            }
+
+            let from_bounds = Self::bounds(&from_field.constraints);
+            let to_bounds = Self::bounds(&to_field.constraints);
+            if from_bounds == to_bounds {
+                continue;
+            }
+
+            // The source may produce item counts the target rejects
+            let too_few = from_bounds.0.unwrap_or(0) < to_bounds.0.unwrap_or(0);
+            let too_many = match (from_bounds.1, to_bounds.1) {
+                (_, None) => false,
+                (None, Some(_)) => true,
+                (Some(from_max), Some(to_max)) => from_max > to_max,
+            };
+
+            mismatches.push(Mismatch {
+                mismatch_type: MismatchType::ValidationMismatch,
+                path: field_name.clone(),
+                expected: TypeInfo {
+                    base_type: to_field.base_type,
+                    schema_ref: None,
+                    constraints: to_field.constraints.clone(),
+                    optional: to_field.optional,
+                },
+                actual: TypeInfo {
+                    base_type: from_field.base_type,
+                    schema_ref: None,
+                    constraints: from_field.constraints.clone(),
+                    optional: from_field.optional,
+                },
+                location: contract.from_schema.location.clone(),
+                message: format!(
+                    "Array field '{}' allows {} in '{}' but {} in '{}'",
+                    field_name,
+                    Self::describe(from_bounds),
+                    contract.from_schema.name,
+                    Self::describe(to_bounds),
+                    contract.to_schema.name
+                ),
+                severity_level: if too_few || too_many {
+                    SeverityLevel::High
+                } else {
+                    SeverityLevel::Low
+                },
+                occurrences: 1,
+            });
+        }
+
+        mismatches
+    }
+
+    fn name(&self) -> &str {
+        "array_length"
+    }
+}
+
+/// Array items checking rule
+///
+/// Compares the facets of array fields the base type doesn't carry, each reported on its
+/// own: nullability (`list[Status] | None`, `Status[] | null`, `.nullable()`) and the values
+/// items are restricted to (`list[Literal[...]]`, `z.array(z.enum([...]))`, `items.enum`).
+/// An empty array is not `null`: a source that may send `null` to a target that only
+/// accepts an array is reported even when the target defaults to `[]`. Fields that are an
+/// array on one side only are type mismatches.
+pub struct ArrayItemsRule;
+
+impl ArrayItemsRule {
+    fn mismatch(
+        contract: &Contract,
+        field_name: &str,
+        from_field: &FieldInfo,
+        to_field: &FieldInfo,
+        message: String,
+    ) -> Mismatch {
+        Mismatch {
+            mismatch_type: MismatchType::ValidationMismatch,
+            path: field_name.to_string(),
+            expected: TypeInfo {
+                base_type: to_field.base_type,
+                schema_ref: None,
+                constraints: to_field.constraints.clone(),
+                optional: to_field.optional,
+            },
+            actual: TypeInfo {
+                base_type: from_field.base_type,
+                schema_ref: None,
+                constraints: from_field.constraints.clone(),
+                optional: from_field.optional,
+            },
+            location: contract.from_schema.location.clone(),
+            message,
+            severity_level: SeverityLevel::High,
+            occurrences: 1,
+        }
+    }
+}
+
+impl ContractRule for ArrayItemsRule {
+    fn check(&self, contract: &Contract) -> Vec<Mismatch> {
+        let mut mismatches = Vec::new();
+
+        // Parse schemas
+        let Ok(from_schema) =
+            SchemaParser::parse_sent_to(&contract.from_schema, &contract.to_schema)
+        else {
+            return mismatches;
+        };
+        let Ok(to_schema) = SchemaParser::parse_as(&contract.to_schema, SchemaRole::Receiver)
+        else {
+            return mismatches;
+        };
+
+        let mut field_names: Vec<&String> = from_schema.properties.keys().collect();
+        field_names.sort();
+
+        for field_name in field_names {
+            let from_field = &from_schema.properties[field_name];
+            let Some(to_field) = to_schema.properties.get(field_name) else {
+                continue;
+            };
+            if from_field.base_type != BaseType::Array || to_field.base_type != BaseType::Array {
+                continue;
+            }
+
+            if from_field.nullable && !to_field.nullable {
+                mismatches.push(Self::mismatch(
+                    contract,
+                    field_name,
+                    from_field,
+                    to_field,
+                    format!(
+                        "Array field '{}' may be null in '{}' but '{}' only accepts an array (send [] instead of null)",
+                        field_name, contract.from_schema.name, contract.to_schema.name
+                    ),
+                ));
+            }
+
+            if let (Some(from_values), Some(to_values)) =
+                (&from_field.item_values, &to_field.item_values)
+            {
+                let rejected: Vec<String> = from_values
+                    .iter()
+                    .filter(|value| !to_values.contains(value))
+                    .map(|value| format!("'{}'", value))
+                    .collect();
+                if !rejected.is_empty() {
+                    let accepted: Vec<String> = to_values
+                        .iter()
+                        .map(|value| format!("'{}'", value))
+                        .collect();
+                    mismatches.push(Self::mismatch(
+                        contract,
+                        field_name,
+                        from_field,
+                        to_field,
+                        format!(
+                            "Items of array field '{}' may be {} in '{}', which '{}' doesn't accept (accepted: {})",
+                            field_name,
+                            rejected.join(", "),
+                            contract.from_schema.name,
+                            contract.to_schema.name,
+                            accepted.join(", ")
+                        ),
+                    ));
+                }
+            }
+        }
+
+        mismatches
+    }
+
+    fn name(&self) -> &str {
+        "array_items"
+    }
+}
+
+/// Default value checking rule
+///
+/// Compares the literal defaults a field has on both sides (`Field(default=10)`,
+/// `z.number().default(10)`, JSON Schema `default`). When they differ, an omitted value means
+/// something else to each side (the form pre-fills 10, the API applies 20), which no type check
+/// catches. Fields defaulted on one side only, or with computed defaults, are not compared.
+pub struct DefaultValueRule;
+
+impl DefaultValueRule {
+    /// Whether two literal defaults are the same value (`10` and `10.0` are)
+    fn same_default(a: &serde_json::Value, b: &serde_json::Value) -> bool {
+        match (a.as_f64(), b.as_f64()) {
+            (Some(a), Some(b)) => a == b,
+            _ => a == b,
+        }
+    }
+}
+
+impl ContractRule for DefaultValueRule {
+    fn check(&self, contract: &Contract) -> Vec<Mismatch> {
+        let mut mismatches = Vec::new();
+
+        // Parse schemas
+        let Ok(from_schema) =
+            SchemaParser::parse_sent_to(&contract.from_schema, &contract.to_schema)
+        else {
+            return mismatches;
+        };
+        let Ok(to_schema) = SchemaParser::parse_as(&contract.to_schema, SchemaRole::Receiver)
+        else {
+            return mismatches;
+        };
+
+        let mut field_names: Vec<&String> = from_schema.properties.keys().collect();
+        field_names.sort();
+        for field_name in field_names {
+            let from_field = &from_schema.properties[field_name];
+            let Some(to_field) = to_schema.properties.get(field_name) else {
+                continue;
+            };
+            let (Some(from_default), Some(to_default)) =
+                (&from_field.default_value, &to_field.default_value)
+            else {
+                continue;
+            };
+            if Self::same_default(from_default, to_default) {
+                continue;
+            }
+            mismatches.push(Mismatch {
+                mismatch_type: MismatchType::DefaultMismatch,
+                path: field_name.clone(),
+                expected: TypeInfo {
+                    base_type: from_field.base_type,
+                    schema_ref: None,
+                    constraints: from_field.constraints.clone(),
+                    optional: from_field.optional,
+                },
+                actual: TypeInfo {
+                    base_type: to_field.base_type,
+                    schema_ref: None,
+                    constraints: to_field.constraints.clone(),
+                    optional: to_field.optional,
+                },
+                location: contract.to_schema.location.clone(),
+                message: format!(
+                    "Default of field '{}' differs: {} in '{}', {} in '{}'",
+                    field_name,
+                    from_default,
+                    contract.from_schema.name,
+                    to_default,
+                    contract.to_schema.name
+                ),
+                severity_level: SeverityLevel::Medium,
+                occurrences: 1,
+            });
+        }
+
+        mismatches
+    }
+
+    fn name(&self) -> &str {
+        "default_value"
+    }
+}
+
 /// Missing schema checking rule
 pub struct MissingSchemaRule;
 
@@ -281,6 +1113,7 @@ impl ContractRule for MissingSchemaRule {
                     contract.from_schema.name
                 ),
                 severity_level: SeverityLevel::Critical,
+                occurrences: 1,
             });
         }
 
@@ -308,6 +1141,7 @@ impl ContractRule for MissingSchemaRule {
                     contract.to_schema.name
                 ),
                 severity_level: SeverityLevel::High,
+                occurrences: 1,
             });
         }
 
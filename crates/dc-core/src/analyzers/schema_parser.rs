@@ -1,4 +1,6 @@
-use crate::models::{BaseType, Constraint, ConstraintValue, SchemaReference, SchemaType};
+use crate::models::{
+    BaseType, Constraint, ConstraintValue, DiscriminatedUnion, SchemaReference, SchemaType,
+};
 use anyhow::Result;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -31,6 +33,50 @@ pub struct FieldInfo {
     pub constraints: Vec<Constraint>,
     /// Nested schema (for objects)
     pub nested_schema: Option<Box<JsonSchema>>,
+    /// Server-generated field that only appears in responses (`readOnly`, frozen Pydantic field)
+    pub response_only: bool,
+    /// Tagged union carried by the field (discriminator and members)
+    pub discriminated_union: Option<DiscriminatedUnion>,
+    /// Field is deprecated (`Field(deprecated=True)`, `@deprecated` JSDoc, `deprecated: true`)
+    pub deprecated: bool,
+    /// Documented example values (`Field(examples=[...])`, OpenAPI `example`/`examples`)
+    pub examples: Vec<Value>,
+    /// Literal default (`Field(default=10)`, `.default(10)`, JSON Schema `default`)
+    pub default_value: Option<Value>,
+    /// Field accepts `null` (`Optional[T]`, `.nullable()`, `T | null`, `anyOf` with `null`),
+    /// which is not the same as an empty array or an omitted field
+    pub nullable: bool,
+    /// Values the items of an array field are restricted to (`list[Literal["a", "b"]]`,
+    /// `z.array(z.enum([...]))`, `items.enum`), None when unrestricted or unknown
+    pub item_values: Option<Vec<String>>,
+}
+
+/// Side of a contract a schema is parsed for
+///
+/// Pydantic fields are keyed by their wire name, which differs between the two sides when
+/// `validation_alias` and `serialization_alias` are set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaRole {
+    /// The schema sends the data (a response model): fields go by their serialization name
+    Sender,
+    /// The schema receives the data (a request model): fields go by their validation name
+    Receiver,
+}
+
+/// Whether a Pydantic model reads aliased fields under their Python name too
+///
+/// Set with `populate_by_name=True` (or `validate_by_name=True`, Pydantic 2.11) in
+/// `model_config`, or `allow_population_by_field_name = True` in a v1 `class Config`,
+/// possibly inherited from a base model.
+pub fn populates_by_name(schema: &SchemaReference) -> bool {
+    [
+        "model_config.populate_by_name",
+        "model_config.validate_by_name",
+        "model_config.allow_population_by_field_name",
+    ]
+    .iter()
+    .filter_map(|key| schema.metadata.get(*key))
+    .any(|value| value == "True" || value == "true")
 }
 
 /// Schema parser from SchemaReference
@@ -38,19 +84,106 @@ pub struct SchemaParser;
 
 impl SchemaParser {
     /// Parses SchemaReference into JsonSchema
+    ///
+    /// Pydantic fields are keyed by their `alias`, or by the attribute name.
     pub fn parse(schema_ref: &SchemaReference) -> Result<JsonSchema> {
+        Self::parse_schema(schema_ref, None)
+    }
+
+    /// Parses the schema of one side of a contract, keying Pydantic fields by the name they
+    /// have on the wire in that direction
+    pub fn parse_as(schema_ref: &SchemaReference, role: SchemaRole) -> Result<JsonSchema> {
+        Self::parse_schema(schema_ref, Some(role))
+    }
+
+    /// Parses the sending side of a contract, as `receiver` reads it
+    ///
+    /// A Pydantic receiver configured with `populate_by_name` (see [`populates_by_name`])
+    /// also reads aliased fields under their Python name: such a field sent under that name
+    /// is keyed by the receiver's wire name instead.
+    pub fn parse_sent_to(
+        sender: &SchemaReference,
+        receiver: &SchemaReference,
+    ) -> Result<JsonSchema> {
+        let mut schema = Self::parse_as(sender, SchemaRole::Sender)?;
+        if receiver.schema_type != SchemaType::Pydantic || !populates_by_name(receiver) {
+            return Ok(schema);
+        }
+        let fields: Vec<crate::models::PydanticFieldInfo> = receiver
+            .metadata
+            .get("fields")
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+        for field in &fields {
+            let wire_name = field.input_name();
+            if wire_name == field.name || schema.properties.contains_key(wire_name) {
+                continue;
+            }
+            if let Some(sent) = schema.properties.remove(&field.name) {
+                schema.properties.insert(wire_name.to_string(), sent);
+                for required in schema.required.iter_mut() {
+                    if *required == field.name {
+                        *required = wire_name.to_string();
+                    }
+                }
+            }
+        }
+        Ok(schema)
+    }
+
+    fn parse_schema(schema_ref: &SchemaReference, role: Option<SchemaRole>) -> Result<JsonSchema> {
         match schema_ref.schema_type {
-            SchemaType::Pydantic => Self::parse_pydantic(schema_ref),
+            SchemaType::Pydantic => Self::parse_pydantic(schema_ref, role),
             SchemaType::Zod => Self::parse_zod(schema_ref),
             SchemaType::TypeScript => Self::parse_typescript(schema_ref),
             SchemaType::OpenAPI => Self::parse_openapi(schema_ref),
             SchemaType::JsonSchema => Self::parse_json_schema(schema_ref),
             SchemaType::OrmModel => Self::parse_orm_model(schema_ref),
+            SchemaType::Enum => Ok(Self::parse_enum(schema_ref)),
+        }
+    }
+
+    /// Parses an enum class: a string (or number) restricted to its member values
+    fn parse_enum(schema_ref: &SchemaReference) -> JsonSchema {
+        let values: Vec<serde_json::Value> = schema_ref
+            .metadata
+            .get(crate::parsers::ENUM_VARIANTS_METADATA_KEY)
+            .and_then(|json| serde_json::from_str::<Vec<serde_json::Value>>(json).ok())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|variant| variant.get("value").cloned())
+            .filter(|value| !value.is_null())
+            .collect();
+        let schema_type = if !values.is_empty() && values.iter().all(|value| value.is_number()) {
+            "number"
+        } else {
+            "string"
+        };
+        let values: Vec<String> = values
+            .iter()
+            .map(|value| match value {
+                serde_json::Value::String(value) => value.clone(),
+                other => other.to_string(),
+            })
+            .collect();
+        JsonSchema {
+            schema_type: schema_type.to_string(),
+            properties: HashMap::new(),
+            required: Vec::new(),
+            items: None,
+            constraints: if values.is_empty() {
+                Vec::new()
+            } else {
+                vec![Constraint::Enum(values)]
+            },
         }
     }
 
     /// Parses Pydantic schema
-    fn parse_pydantic(schema_ref: &SchemaReference) -> Result<JsonSchema> {
+    fn parse_pydantic(
+        schema_ref: &SchemaReference,
+        role: Option<SchemaRole>,
+    ) -> Result<JsonSchema> {
         // Check if full JSON schema is in metadata
         if let Some(json_schema_str) = schema_ref.metadata.get("json_schema") {
             // Use full JSON schema
@@ -86,19 +219,32 @@ impl SchemaParser {
                 serde_json::from_str::<Vec<crate::models::PydanticFieldInfo>>(fields_str)
             {
                 for field in fields {
+                    let wire_name = match role {
+                        Some(SchemaRole::Sender) => field.output_name(),
+                        Some(SchemaRole::Receiver) => field.input_name(),
+                        None => field.alias.as_deref().unwrap_or(&field.name),
+                    }
+                    .to_string();
                     // Convert PydanticFieldInfo to FieldInfo for JsonSchema
                     let field_info = FieldInfo {
                         field_type: field.type_name.clone(),
                         base_type: Self::map_pydantic_type_to_base_type(&field.type_name),
-                        optional: field.optional,
+                        optional: !field.is_required(),
                         constraints: field
                             .constraints
                             .iter()
                             .map(Self::convert_field_constraint)
                             .collect(),
                         nested_schema: None, // Can be enhanced later
+                        response_only: field.response_only,
+                        discriminated_union: field.discriminated_union,
+                        deprecated: field.deprecated,
+                        examples: field.examples,
+                        default_value: field.default_literal,
+                        nullable: field.nullable,
+                        item_values: field.item_values,
                     };
-                    properties.insert(field.name.clone(), field_info);
+                    properties.insert(wire_name, field_info);
                 }
             } else {
                 // Fallback: parse old string format
@@ -128,6 +274,13 @@ impl SchemaParser {
                                 optional: true, // By default fields are optional
                                 constraints: Vec::new(),
                                 nested_schema: None,
+                                response_only: false,
+                                discriminated_union: None,
+                                deprecated: false,
+                                examples: Vec::new(),
+                                default_value: None,
+                                nullable: false,
+                                item_values: None,
                             },
                         );
                     }
@@ -168,8 +321,62 @@ impl SchemaParser {
 
     /// Parses Zod schema
     fn parse_zod(schema_ref: &SchemaReference) -> Result<JsonSchema> {
-        // Similar to Pydantic
-        Self::parse_pydantic(schema_ref)
+        let zod_fields = schema_ref
+            .metadata
+            .get("fields")
+            .and_then(|fields| serde_json::from_str::<Vec<crate::models::ZodField>>(fields).ok());
+        let Some(zod_fields) = zod_fields else {
+            // Similar to Pydantic
+            return Self::parse_pydantic(schema_ref, None);
+        };
+
+        // Tagged unions held by fields (JSON map: field name -> union)
+        let mut unions: HashMap<String, DiscriminatedUnion> = schema_ref
+            .metadata
+            .get("discriminated_unions")
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+
+        let mut properties = HashMap::new();
+        let mut required = Vec::new();
+        for field in zod_fields {
+            let mut constraints = Vec::new();
+            if let Some(min) = field.min_items {
+                constraints.push(Constraint::Min(ConstraintValue::Integer(min as i64)));
+            }
+            if let Some(max) = field.max_items {
+                constraints.push(Constraint::Max(ConstraintValue::Integer(max as i64)));
+            }
+            if !field.optional {
+                required.push(field.name.clone());
+            }
+            let discriminated_union = unions.remove(&field.name);
+            properties.insert(
+                field.name,
+                FieldInfo {
+                    base_type: Self::base_type_from_string(&field.type_name),
+                    field_type: field.type_name,
+                    optional: field.optional,
+                    constraints,
+                    nested_schema: None,
+                    response_only: false,
+                    discriminated_union,
+                    deprecated: false,
+                    examples: Vec::new(),
+                    default_value: field.default_value,
+                    nullable: field.nullable,
+                    item_values: field.item_values,
+                },
+            );
+        }
+
+        Ok(JsonSchema {
+            schema_type: "object".to_string(),
+            properties,
+            required,
+            items: None,
+            constraints: Vec::new(),
+        })
     }
 
     /// Parses TypeScript schema
@@ -191,6 +398,27 @@ impl SchemaParser {
         let mut properties = HashMap::new();
         let mut required = Vec::new();
 
+        // Tagged unions referenced by fields (JSON map: field name -> union)
+        let mut unions: HashMap<String, DiscriminatedUnion> = schema_ref
+            .metadata
+            .get("discriminated_unions")
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+
+        // Validation constraints of fields (JSON map: field name -> constraints)
+        let mut field_constraints: HashMap<String, Vec<Constraint>> = schema_ref
+            .metadata
+            .get(crate::parsers::FIELD_CONSTRAINTS_METADATA_KEY)
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+
+        // Fields documented with `@deprecated` (comma-separated names)
+        let deprecated_fields: Vec<&str> = schema_ref
+            .metadata
+            .get("deprecated_fields")
+            .map(|names| names.split(',').map(str::trim).collect())
+            .unwrap_or_default();
+
         if let Some(fields_str) = schema_ref.metadata.get("fields") {
             for field in fields_str.split(',') {
                 let field = field.trim();
@@ -209,13 +437,20 @@ impl SchemaParser {
                         .unwrap_or(false);
 
                     if !name.is_empty() && !field_type.is_empty() {
-                        let base_type = Self::base_type_from_string(&field_type);
+                        let (base_type, nullable) = Self::typescript_type(&field_type);
                         let field_info = FieldInfo {
                             field_type,
                             base_type,
                             optional,
-                            constraints: Vec::new(),
+                            constraints: field_constraints.remove(&name).unwrap_or_default(),
                             nested_schema: None,
+                            response_only: false,
+                            discriminated_union: unions.remove(&name),
+                            deprecated: deprecated_fields.contains(&name.as_str()),
+                            examples: Vec::new(),
+                            default_value: None,
+                            nullable,
+                            item_values: None,
                         };
                         properties.insert(name.clone(), field_info);
 
@@ -271,18 +506,31 @@ impl SchemaParser {
             {
                 for field in fields {
                     // Convert SQLAlchemyField to FieldInfo for JsonSchema
-                    let base_type = Self::map_sqlalchemy_type_to_base_type(&field.type_name);
+                    let base_type = match (&field.related_model, field.many) {
+                        (Some(_), true) => BaseType::Array,
+                        (Some(_), false) => BaseType::Object,
+                        (None, _) => Self::map_sqlalchemy_type_to_base_type(&field.type_name),
+                    };
+                    // Relationships are loaded on access, so models may leave them out
+                    let optional = field.nullable || field.related_model.is_some();
                     let field_info = FieldInfo {
                         field_type: field.type_name.clone(),
                         base_type,
-                        optional: field.nullable,
+                        optional,
                         constraints: Vec::new(),
                         nested_schema: None,
+                        response_only: false,
+                        discriminated_union: None,
+                        deprecated: false,
+                        examples: Vec::new(),
+                        default_value: None,
+                        nullable: field.nullable,
+                        item_values: None,
                     };
                     properties.insert(field.name.clone(), field_info);
 
                     // If field is not nullable, it's required
-                    if !field.nullable {
+                    if !optional {
                         required.push(field.name);
                     }
                 }
@@ -366,12 +614,25 @@ impl SchemaParser {
         if let Some(max) = json_value.get("maximum").and_then(|v| v.as_f64()) {
             constraints.push(Constraint::Max(ConstraintValue::Float(max)));
         }
+        // Numeric form of OpenAPI 3.1 / Pydantic v2 (`gt=`, `lt=`)
+        if let Some(min) = json_value.get("exclusiveMinimum").and_then(|v| v.as_f64()) {
+            constraints.push(Constraint::ExclusiveMin(ConstraintValue::Float(min)));
+        }
+        if let Some(max) = json_value.get("exclusiveMaximum").and_then(|v| v.as_f64()) {
+            constraints.push(Constraint::ExclusiveMax(ConstraintValue::Float(max)));
+        }
         if let Some(min_len) = json_value.get("minLength").and_then(|v| v.as_u64()) {
             constraints.push(Constraint::Min(ConstraintValue::Integer(min_len as i64)));
         }
         if let Some(max_len) = json_value.get("maxLength").and_then(|v| v.as_u64()) {
             constraints.push(Constraint::Max(ConstraintValue::Integer(max_len as i64)));
         }
+        if let Some(min_items) = json_value.get("minItems").and_then(|v| v.as_u64()) {
+            constraints.push(Constraint::Min(ConstraintValue::Integer(min_items as i64)));
+        }
+        if let Some(max_items) = json_value.get("maxItems").and_then(|v| v.as_u64()) {
+            constraints.push(Constraint::Max(ConstraintValue::Integer(max_items as i64)));
+        }
         if let Some(pattern) = json_value.get("pattern").and_then(|v| v.as_str()) {
             constraints.push(Constraint::Pattern(pattern.to_string()));
         }
@@ -409,6 +670,8 @@ impl SchemaParser {
 
     /// Parses property from JSON Schema
     fn parse_property(prop_value: &Value) -> Result<FieldInfo> {
+        let (prop_value, nullable) = Self::unwrap_nullable(prop_value);
+        let prop_value = &prop_value;
         let field_type = prop_value
             .get("type")
             .and_then(|v| v.as_str())
@@ -426,12 +689,25 @@ impl SchemaParser {
         if let Some(max) = prop_value.get("maximum").and_then(|v| v.as_f64()) {
             constraints.push(Constraint::Max(ConstraintValue::Float(max)));
         }
+        // Numeric form of OpenAPI 3.1 / Pydantic v2 (`gt=`, `lt=`)
+        if let Some(min) = prop_value.get("exclusiveMinimum").and_then(|v| v.as_f64()) {
+            constraints.push(Constraint::ExclusiveMin(ConstraintValue::Float(min)));
+        }
+        if let Some(max) = prop_value.get("exclusiveMaximum").and_then(|v| v.as_f64()) {
+            constraints.push(Constraint::ExclusiveMax(ConstraintValue::Float(max)));
+        }
         if let Some(min_len) = prop_value.get("minLength").and_then(|v| v.as_u64()) {
             constraints.push(Constraint::Min(ConstraintValue::Integer(min_len as i64)));
         }
         if let Some(max_len) = prop_value.get("maxLength").and_then(|v| v.as_u64()) {
             constraints.push(Constraint::Max(ConstraintValue::Integer(max_len as i64)));
         }
+        if let Some(min_items) = prop_value.get("minItems").and_then(|v| v.as_u64()) {
+            constraints.push(Constraint::Min(ConstraintValue::Integer(min_items as i64)));
+        }
+        if let Some(max_items) = prop_value.get("maxItems").and_then(|v| v.as_u64()) {
+            constraints.push(Constraint::Max(ConstraintValue::Integer(max_items as i64)));
+        }
         if let Some(pattern) = prop_value.get("pattern").and_then(|v| v.as_str()) {
             constraints.push(Constraint::Pattern(pattern.to_string()));
         }
@@ -443,15 +719,119 @@ impl SchemaParser {
             None
         };
 
+        let response_only = prop_value
+            .get("readOnly")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let deprecated = prop_value
+            .get("deprecated")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        // OpenAPI 3.0 `example` and JSON Schema `examples`
+        let mut examples: Vec<Value> = prop_value.get("example").cloned().into_iter().collect();
+        if let Some(Value::Array(values)) = prop_value.get("examples") {
+            examples.extend(values.iter().cloned());
+        }
+
         Ok(FieldInfo {
             field_type,
             base_type,
             optional: true, // Will be set later based on required
             constraints,
             nested_schema,
+            response_only,
+            discriminated_union: None,
+            deprecated,
+            examples,
+            default_value: prop_value.get("default").cloned(),
+            nullable,
+            item_values: prop_value
+                .get("items")
+                .and_then(|items| items.get("enum"))
+                .and_then(Value::as_array)
+                .and_then(|values| {
+                    values
+                        .iter()
+                        .map(|value| value.as_str().map(str::to_string))
+                        .collect()
+                }),
         })
     }
 
+    /// The non-null schema of a nullable property, and whether it is nullable
+    ///
+    /// Reads `anyOf`/`oneOf` with a `{"type": "null"}` branch (Pydantic v2 `Optional[T]`),
+    /// `"type": [T, "null"]` and OpenAPI 3.0 `"nullable": true`. A single remaining branch
+    /// is merged with the property's own keywords (`default`, `description`, ...).
+    fn unwrap_nullable(prop_value: &Value) -> (Value, bool) {
+        let mut value = prop_value.clone();
+        let mut nullable = value
+            .get("nullable")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        if let Some(Value::Array(types)) = value.get("type") {
+            let non_null: Vec<Value> = types
+                .iter()
+                .filter(|t| t.as_str() != Some("null"))
+                .cloned()
+                .collect();
+            if non_null.len() < types.len() {
+                nullable = true;
+                if let [single] = non_null.as_slice() {
+                    value["type"] = single.clone();
+                }
+            }
+        }
+
+        for key in ["anyOf", "oneOf"] {
+            let Some(Value::Array(branches)) = value.get(key) else {
+                continue;
+            };
+            let non_null: Vec<&Value> = branches
+                .iter()
+                .filter(|branch| branch.get("type").and_then(Value::as_str) != Some("null"))
+                .collect();
+            if non_null.len() == branches.len() {
+                continue;
+            }
+            nullable = true;
+            if let [single] = non_null.as_slice() {
+                let mut merged = (*single).clone();
+                if let (Value::Object(merged), Value::Object(outer)) = (&mut merged, &value) {
+                    for (name, outer_value) in outer {
+                        if name != key {
+                            merged
+                                .entry(name.clone())
+                                .or_insert_with(|| outer_value.clone());
+                        }
+                    }
+                }
+                value = merged;
+            }
+            break;
+        }
+
+        (value, nullable)
+    }
+
+    /// Base type and nullability of a TypeScript field type (`Status[] | null` is a
+    /// nullable array)
+    fn typescript_type(type_str: &str) -> (BaseType, bool) {
+        let members: Vec<&str> = type_str.split('|').map(str::trim).collect();
+        let nullable = members.contains(&"null");
+        let non_null: Vec<&str> = members
+            .into_iter()
+            .filter(|member| !matches!(*member, "null" | "undefined"))
+            .collect();
+        let base_type = match non_null.as_slice() {
+            [member] if member.ends_with("[]") || member.starts_with("Array<") => BaseType::Array,
+            [member] => Self::base_type_from_string(member),
+            _ => Self::base_type_from_string(type_str),
+        };
+        (base_type, nullable)
+    }
+
     /// Converts string type to BaseType
     fn base_type_from_string(type_str: &str) -> BaseType {
         match type_str.to_lowercase().as_str() {
@@ -505,6 +885,12 @@ impl SchemaParser {
             crate::models::FieldConstraint::MaxValue(val) => {
                 Constraint::Max(ConstraintValue::Float(*val))
             }
+            crate::models::FieldConstraint::ExclusiveMinValue(val) => {
+                Constraint::ExclusiveMin(ConstraintValue::Float(*val))
+            }
+            crate::models::FieldConstraint::ExclusiveMaxValue(val) => {
+                Constraint::ExclusiveMax(ConstraintValue::Float(*val))
+            }
             crate::models::FieldConstraint::Pattern(pattern) => {
                 Constraint::Pattern(pattern.clone())
             }
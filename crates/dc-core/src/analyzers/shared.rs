@@ -0,0 +1,268 @@
+use crate::analyzers::{method_name, normalize_route_path, EndpointAnalyzer, HttpEndpoint};
+use crate::analyzers::{JsonSchema, SchemaParser};
+use crate::models::{Contract, Diagnostic, SchemaReference, SchemaType};
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Whether two schema references point at the same declaration (same name, file and line)
+///
+/// Files are compared after canonicalization, so a schema module imported by two adapters
+/// with differently written `src_paths` is still recognized as one declaration.
+pub fn same_declaration(a: &SchemaReference, b: &SchemaReference) -> bool {
+    a.name == b.name
+        && a.location.line == b.location.line
+        && canonical_file(&a.location.file) == canonical_file(&b.location.file)
+}
+
+/// Whether both links of a contract use one shared TypeScript/Zod declaration
+///
+/// Such a contract holds by construction, so its mismatch checks are skipped.
+pub fn is_shared_contract(contract: &Contract) -> bool {
+    matches!(
+        contract.from_schema.schema_type,
+        SchemaType::TypeScript | SchemaType::Zod
+    ) && contract.from_schema.schema_type == contract.to_schema.schema_type
+        && same_declaration(&contract.from_schema, &contract.to_schema)
+}
+
+/// Warnings for frontend calls that don't agree with their backend route on a shared schema
+///
+/// In full-stack TypeScript projects the frontend and the backend often import the same
+/// schema module. For every call resolving to exactly one route, the request and response
+/// schemas of both sides are compared by declaration:
+/// - the same declaration on both sides is shared and authoritative, nothing is compared;
+/// - a schema of the same name declared twice whose fields differ is a copy that diverged
+///   from the shared definition;
+/// - a frontend schema declared in a module the backend takes its route schemas from, while
+///   the route uses another schema (or no request body), means the route bypasses the
+///   shared definition.
+pub fn shared_schema_issues(
+    analyzer: &EndpointAnalyzer,
+    calls: &[HttpEndpoint],
+) -> Vec<Diagnostic> {
+    let shared_files: HashSet<PathBuf> = analyzer
+        .routes()
+        .flat_map(|route| [&route.request_schema, &route.response_schema])
+        .flatten()
+        .map(|schema| canonical_file(&schema.location.file))
+        .collect();
+
+    let mut diagnostics = Vec::new();
+    for call in calls {
+        let Some(route) = analyzer.resolve_call(call) else {
+            continue;
+        };
+        let Some(path) = normalize_route_path(&call.path) else {
+            continue;
+        };
+        let sides = [
+            ("request", &call.request_schema, &route.request_schema),
+            ("response", &call.response_schema, &route.response_schema),
+        ];
+        for (side, frontend, backend) in sides {
+            let Some(frontend) = frontend else {
+                continue;
+            };
+            let problem = match backend {
+                Some(backend) if same_declaration(frontend, backend) => None,
+                Some(backend) if frontend.name == backend.name => {
+                    let differences = field_differences(frontend, backend);
+                    (!differences.is_empty()).then(|| {
+                        format!(
+                            "diverges from the backend definition ({}:{}): {}",
+                            backend.location.file,
+                            backend.location.line,
+                            differences.join("; ")
+                        )
+                    })
+                }
+                _ if !shared_files.contains(&canonical_file(&frontend.location.file)) => None,
+                Some(backend) => Some(format!(
+                    "is shared with the backend, but the route uses '{}' ({}:{})",
+                    backend.name, backend.location.file, backend.location.line
+                )),
+                // Handlers often leave the response type to inference
+                None if side == "response" => None,
+                None => Some(
+                    "is shared with the backend, but the route declares no request body"
+                        .to_string(),
+                ),
+            };
+            let Some(problem) = problem else {
+                continue;
+            };
+            diagnostics.push(Diagnostic::shared_schema(
+                call.location.clone(),
+                format!(
+                    "{} {} {} schema '{}' {}",
+                    method_name(call.method),
+                    path,
+                    side,
+                    frontend.name,
+                    problem
+                ),
+            ));
+        }
+    }
+    diagnostics
+}
+
+/// Differences between two declarations of a schema, by field name
+///
+/// Empty when either side can't be parsed or has no known fields.
+fn field_differences(frontend: &SchemaReference, backend: &SchemaReference) -> Vec<String> {
+    let parse = |schema| SchemaParser::parse(schema).ok().filter(has_fields);
+    let (Some(frontend), Some(backend)) = (parse(frontend), parse(backend)) else {
+        return Vec::new();
+    };
+
+    let names: BTreeSet<&String> = frontend
+        .properties
+        .keys()
+        .chain(backend.properties.keys())
+        .collect();
+    names
+        .into_iter()
+        .filter_map(
+            |name| match (frontend.properties.get(name), backend.properties.get(name)) {
+                (Some(_), None) => Some(format!("field '{}' is only on the frontend", name)),
+                (None, Some(_)) => Some(format!("field '{}' is only on the backend", name)),
+                (Some(f), Some(b)) if f.field_type != b.field_type => Some(format!(
+                    "field '{}' is `{}` on the frontend but `{}` on the backend",
+                    name, f.field_type, b.field_type
+                )),
+                (Some(f), Some(b)) if f.optional != b.optional => Some(format!(
+                    "field '{}' is {} on the frontend but {} on the backend",
+                    name,
+                    requiredness(f.optional),
+                    requiredness(b.optional)
+                )),
+                _ => None,
+            },
+        )
+        .collect()
+}
+
+fn has_fields(schema: &JsonSchema) -> bool {
+    !schema.properties.is_empty()
+}
+
+fn requiredness(optional: bool) -> &'static str {
+    if optional {
+        "optional"
+    } else {
+        "required"
+    }
+}
+
+fn canonical_file(file: &str) -> PathBuf {
+    Path::new(file)
+        .canonicalize()
+        .unwrap_or_else(|_| PathBuf::from(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::call_graph::HttpMethod;
+    use crate::models::{Location, SchemaType, Severity};
+    use std::collections::HashMap;
+
+    fn schema(name: &str, file: &str, fields: &str) -> SchemaReference {
+        SchemaReference {
+            name: name.to_string(),
+            schema_type: SchemaType::TypeScript,
+            location: Location {
+                file: file.to_string(),
+                line: 3,
+                column: None,
+            },
+            metadata: HashMap::from([("fields".to_string(), fields.to_string())]),
+        }
+    }
+
+    fn endpoint(path: &str, request_schema: Option<SchemaReference>) -> HttpEndpoint {
+        HttpEndpoint {
+            method: HttpMethod::Post,
+            path: path.to_string(),
+            location: Location {
+                file: "web/api.ts".to_string(),
+                line: 7,
+                column: None,
+            },
+            deprecated: false,
+            request_schema,
+            response_schema: None,
+            required_headers: Vec::new(),
+            sent_headers: None,
+            media_type: None,
+            response_envelope: None,
+            query_parameters: Vec::new(),
+            sent_query: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_shared_declaration_is_not_compared() {
+        let shared = schema("CreateUser", "shared/user.ts", "name:string");
+        let mut copy = shared.clone();
+        // Same declaration, fields parsed differently by the two adapters
+        copy.metadata
+            .insert("fields".to_string(), "name:string,age:number".to_string());
+        let analyzer = EndpointAnalyzer::new(vec![endpoint("/users", Some(copy))]);
+
+        let diagnostics = shared_schema_issues(&analyzer, &[endpoint("\"/users\"", Some(shared))]);
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_diverged_copy_is_reported() {
+        let backend = schema("CreateUser", "api/user.dto.ts", "name:string,age:number");
+        let frontend = schema("CreateUser", "web/types.ts", "name:string:optional");
+        let analyzer = EndpointAnalyzer::new(vec![endpoint("/users", Some(backend))]);
+
+        let diagnostics =
+            shared_schema_issues(&analyzer, &[endpoint("\"/users\"", Some(frontend))]);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(
+            diagnostics[0].message,
+            "POST /users request schema 'CreateUser' diverges from the backend definition \
+             (api/user.dto.ts:3): field 'age' is only on the backend; field 'name' is \
+             optional on the frontend but required on the backend"
+        );
+    }
+
+    #[test]
+    fn test_route_bypassing_shared_schema_is_reported() {
+        let shared = schema("CreateUser", "shared/user.ts", "name:string");
+        let legacy = schema("LegacyUser", "api/legacy.ts", "name:string");
+        let analyzer = EndpointAnalyzer::new(vec![
+            endpoint("/users", Some(legacy)),
+            endpoint("/admins", Some(shared.clone())),
+            endpoint("/teams", None),
+        ]);
+
+        let diagnostics = shared_schema_issues(
+            &analyzer,
+            &[
+                endpoint("\"/users\"", Some(shared.clone())),
+                endpoint("\"/teams\"", Some(shared.clone())),
+                endpoint("\"/admins\"", Some(shared)),
+            ],
+        );
+
+        let messages: Vec<_> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "POST /users request schema 'CreateUser' is shared with the backend, but the \
+                 route uses 'LegacyUser' (api/legacy.ts:3)",
+                "POST /teams request schema 'CreateUser' is shared with the backend, but the \
+                 route declares no request body",
+            ]
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use crate::analyzers::SchemaParser;
+use crate::models::{DataChain, Diagnostic, SchemaReference, SchemaType};
+use std::collections::HashSet;
+
+/// Warning diagnostics for frontend types the contract comparison could not see into
+///
+/// Takes the chains of frontend adapters. A schema of their contracts is unresolved when it
+/// is not a backend schema (Pydantic, ORM, OpenAPI) and has no fields to compare: an
+/// interface that wasn't found ends up as a field-less `Object`/`Unknown` schema, and every
+/// rule silently skips it. Schemas already reported as `missing_schema` and tagged unions are
+/// left out. Each schema is reported once, at its location.
+pub fn unresolved_frontend_types<'a>(
+    chains: impl IntoIterator<Item = &'a DataChain>,
+) -> Vec<Diagnostic> {
+    let mut seen = HashSet::new();
+    let mut diagnostics = Vec::new();
+
+    for contract in chains.into_iter().flat_map(|chain| &chain.contracts) {
+        for schema in [&contract.from_schema, &contract.to_schema] {
+            if !is_unresolved(schema) {
+                continue;
+            }
+            let key = (
+                schema.name.clone(),
+                schema.location.file.clone(),
+                schema.location.line,
+            );
+            if !seen.insert(key) {
+                continue;
+            }
+            diagnostics.push(Diagnostic::unresolved_type(
+                schema.location.clone(),
+                format!(
+                    "Type '{}' could not be resolved to a schema; contracts using it are not checked",
+                    schema.name
+                ),
+            ));
+        }
+    }
+
+    diagnostics
+}
+
+fn is_unresolved(schema: &SchemaReference) -> bool {
+    if matches!(
+        schema.schema_type,
+        SchemaType::Pydantic | SchemaType::OrmModel | SchemaType::OpenAPI | SchemaType::Enum
+    ) || schema.metadata.contains_key("missing_schema")
+        || schema.metadata.contains_key("discriminated_union")
+    {
+        return false;
+    }
+    SchemaParser::parse(schema).map_or(true, |parsed| parsed.properties.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ChainDirection, ChainType, Contract, Location, Severity};
+    use std::collections::HashMap;
+
+    fn schema(name: &str, schema_type: SchemaType, metadata: &[(&str, &str)]) -> SchemaReference {
+        SchemaReference {
+            name: name.to_string(),
+            schema_type,
+            location: Location {
+                file: format!("{}.src", name),
+                line: 3,
+                column: None,
+            },
+            metadata: metadata
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<HashMap<_, _>>(),
+        }
+    }
+
+    fn chain(from_schema: SchemaReference, to_schema: SchemaReference) -> DataChain {
+        DataChain {
+            id: "users".to_string(),
+            name: "users".to_string(),
+            links: Vec::new(),
+            contracts: vec![Contract {
+                from_link_id: "frontend".to_string(),
+                to_link_id: "backend".to_string(),
+                from_schema,
+                to_schema,
+                mismatches: Vec::new(),
+                severity: Severity::Info,
+            }],
+            direction: ChainDirection::FrontendToBackend,
+            chain_type: ChainType::Full,
+        }
+    }
+
+    #[test]
+    fn test_fieldless_frontend_schemas_are_reported_once() {
+        let unknown = schema("Object", SchemaType::JsonSchema, &[("base_type", "Object")]);
+        let backend = schema("UserCreate", SchemaType::Pydantic, &[]);
+        let chains = vec![
+            chain(unknown.clone(), backend.clone()),
+            chain(unknown.clone(), backend),
+        ];
+
+        let diagnostics = unresolved_frontend_types(&chains);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(diagnostics[0].location, unknown.location);
+        assert_eq!(
+            diagnostics[0].message,
+            "Type 'Object' could not be resolved to a schema; contracts using it are not checked"
+        );
+    }
+
+    #[test]
+    fn test_resolved_and_already_reported_schemas_are_skipped() {
+        let form = schema(
+            "UserForm",
+            SchemaType::TypeScript,
+            &[("fields", "name:string:required")],
+        );
+        let untyped = schema(
+            "payload",
+            SchemaType::JsonSchema,
+            &[("missing_schema", "true")],
+        );
+        let backend = schema("UserCreate", SchemaType::Pydantic, &[]);
+
+        assert!(unresolved_frontend_types(&[
+            chain(form, backend.clone()),
+            chain(untyped, backend),
+        ])
+        .is_empty());
+    }
+}
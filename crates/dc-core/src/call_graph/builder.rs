@@ -1,16 +1,46 @@
 use anyhow::{Context, Result};
+use rayon::prelude::*;
 use rustpython_parser::ast::Ranged;
-use rustpython_parser::{ast, parse, Mode};
-use std::collections::{HashMap, HashSet};
+use rustpython_parser::{ast, parse, Mode, ParseError};
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
 use tracing::{debug, warn};
 
-use crate::call_graph::decorator::Decorator;
+use crate::call_graph::decorator::{Decorator, RoutePattern};
 use crate::call_graph::extractor::PydanticSchemaExtractor;
-use crate::call_graph::{CallEdge, CallGraph, CallNode, HttpMethod, Parameter};
-use crate::models::{BaseType, Location, NodeId, SchemaReference, SchemaType, TypeInfo};
-use crate::parsers::{Call, Import, LocationConverter, PythonParser};
+use crate::call_graph::file_analysis::FileAnalysis;
+use crate::call_graph::{CallEdge, CallGraph, CallNode, HttpMethod, Parameter, RelationshipKind};
+use crate::models::{
+    status_constant_code, AnalysisWarning, AnalysisWarningKind, BaseType, Diagnostic, Location,
+    NodeId, QueryParameter, SchemaReference, SchemaType, TypeCompatibility, TypeInfo,
+};
+use crate::parsers::{Call, Import, LocationConverter, PythonParser, FORWARD_REFS_METADATA_KEY};
+
+/// Model metadata key recording where forward references resolved to (`B=models/b.py:3`)
+pub const RESOLVED_FORWARD_REFS_METADATA_KEY: &str = "resolved_forward_refs";
+
+/// Schema metadata key listing the members of the `User | Admin` union a type was picked
+/// from (`User,Admin`)
+pub const UNION_ALTERNATIVES_METADATA_KEY: &str = "union_alternatives";
+
+/// A Python file read and parsed once per build, shared by every pass over it
+pub struct ParsedModule {
+    /// Module AST
+    pub ast: ast::Mod,
+    /// Byte offset to line/column conversion for the file's source
+    pub converter: LocationConverter,
+}
+
+/// A file with a syntax error, parsed ahead of `build_from_entry`
+struct ParseFailure {
+    error: ParseError,
+    /// Byte offset to line/column conversion for the file's source
+    converter: LocationConverter,
+}
 
 /// Call graph builder - main class for creating call graphs from code
 pub struct CallGraphBuilder {
@@ -28,8 +58,16 @@ pub struct CallGraphBuilder {
     function_nodes: HashMap<String, NodeId>,
     /// Cache of Pydantic models (class name -> SchemaReference)
     pydantic_models: HashMap<String, SchemaReference>,
+    /// Pydantic models by class name, one per declaring file, to resolve names several
+    /// modules declare (see `pydantic_model_in`)
+    pydantic_model_variants: HashMap<String, Vec<SchemaReference>>,
+    /// References to ambiguous model names found so far (model name, referencing file),
+    /// reported as diagnostics when the build ends
+    ambiguous_model_refs: RefCell<BTreeSet<(String, String)>>,
     /// Cache of ORM models (class name -> SchemaReference)
     orm_models: HashMap<String, SchemaReference>,
+    /// Cache of enum classes (class name -> SchemaReference)
+    enum_models: HashMap<String, SchemaReference>,
     /// Optional Pydantic schema extractor for JSON schema extraction
     schema_extractor: Option<Box<dyn PydanticSchemaExtractor>>,
     /// Project root
@@ -38,13 +76,57 @@ pub struct CallGraphBuilder {
     max_depth: Option<usize>,
     /// Current recursion depth
     current_depth: usize,
+    /// Time after which no more files are read (None = no limit)
+    deadline: Option<Instant>,
+    /// Files were skipped because the deadline passed
+    timed_out: bool,
     /// Enable verbose debug output
     verbose: bool,
     /// Strict import resolution: fail on unresolved imports when true
     strict_imports: bool,
+    /// Strict parsing: fail on the first file with a syntax error when true
+    strict_parse: bool,
+    /// Module prefixes known to be external, whose unresolved imports aren't reported
+    external_modules: Vec<String>,
+    /// Problems found while building (e.g. skipped unparseable files)
+    diagnostics: Vec<Diagnostic>,
+    /// What the build couldn't follow (unresolved imports, skipped modules, ...)
+    analysis_warnings: Vec<AnalysisWarning>,
     /// Import information: file path -> (imported name -> module path)
     /// Stores which names are imported from which modules in each file
     file_imports: HashMap<PathBuf, HashMap<String, String>>,
+    /// String constants of each Python file (see `PythonParser::extract_string_constants`),
+    /// for route paths given as constants
+    string_constants: HashMap<PathBuf, HashMap<String, String>>,
+    /// Type aliases visible in each Python file, declared there or imported from another
+    /// module (see `PythonParser::extract_type_aliases`)
+    type_aliases: HashMap<PathBuf, HashMap<String, ast::Expr>>,
+    /// Additional method names treated as data transformations (`to_response`, ...)
+    transformation_methods: HashSet<String>,
+    /// Type names considered compatible when linking Pydantic and ORM models
+    type_compatibility: TypeCompatibility,
+    /// Classes returned by functions without a return annotation (see
+    /// `PythonParser::returned_model_names`), used to infer route response schemas
+    returned_models: HashMap<NodeId, Vec<String>>,
+    /// Headers functions declare as required `Header(...)` parameters (see
+    /// `PythonParser::required_header_names`)
+    required_headers: HashMap<NodeId, Vec<String>>,
+    /// Typed query parameters of functions (see `PythonParser::typed_query_parameters`)
+    query_parameters: HashMap<NodeId, Vec<QueryParameter>>,
+    /// Media types functions read form parameters in (see
+    /// `PythonParser::request_media_type`)
+    request_media_types: HashMap<NodeId, &'static str>,
+    /// Parsed files (`None` when unreadable or unparseable), so each file is parsed once
+    parsed_modules: HashMap<PathBuf, Option<Arc<ParsedModule>>>,
+    /// Syntax errors of files parsed in parallel, reported by `parse_entry` without
+    /// parsing the file again
+    parse_failures: HashMap<PathBuf, ParseFailure>,
+    /// Number of files read and parsed so far
+    parse_count: usize,
+    /// Only the first entry point is read: imports aren't followed (see `analyze_file`)
+    single_file: bool,
+    /// Maximum number of threads parsing files in parallel (None = one per core)
+    threads: Option<usize>,
 }
 
 impl CallGraphBuilder {
@@ -69,14 +151,36 @@ impl CallGraphBuilder {
             module_nodes: HashMap::new(),
             function_nodes: HashMap::new(),
             pydantic_models: HashMap::new(),
+            pydantic_model_variants: HashMap::new(),
+            ambiguous_model_refs: RefCell::new(BTreeSet::new()),
             orm_models: HashMap::new(),
+            enum_models: HashMap::new(),
             schema_extractor: None,
             project_root: None,
             max_depth: None,
             current_depth: 0,
+            deadline: None,
+            timed_out: false,
             verbose: false,
             strict_imports,
+            strict_parse: false,
+            external_modules: Vec::new(),
+            diagnostics: Vec::new(),
+            analysis_warnings: Vec::new(),
             file_imports: HashMap::new(),
+            string_constants: HashMap::new(),
+            type_aliases: HashMap::new(),
+            transformation_methods: HashSet::new(),
+            type_compatibility: TypeCompatibility::default(),
+            returned_models: HashMap::new(),
+            required_headers: HashMap::new(),
+            query_parameters: HashMap::new(),
+            request_media_types: HashMap::new(),
+            parsed_modules: HashMap::new(),
+            parse_failures: HashMap::new(),
+            parse_count: 0,
+            single_file: false,
+            threads: None,
         }
     }
 
@@ -98,12 +202,88 @@ impl CallGraphBuilder {
         self
     }
 
+    /// Sets strict parsing mode
+    ///
+    /// By default a file with a syntax error is skipped and reported as a `parse-error`
+    /// diagnostic; in strict mode `build_from_entry` fails on it instead.
+    pub fn with_strict_parse(mut self, strict_parse: bool) -> Self {
+        self.strict_parse = strict_parse;
+        self
+    }
+
+    /// Sets module prefixes known to be external (`fastapi`, `sqlalchemy`, `acme_auth`)
+    ///
+    /// An uninstalled dependency matching one (the module itself or a submodule, e.g.
+    /// `sqlalchemy.orm` for `sqlalchemy`) is skipped silently, also in strict mode, instead of
+    /// being reported as an `external-dependency` analysis warning.
+    pub fn with_external_modules(mut self, external_modules: Vec<String>) -> Self {
+        self.external_modules = external_modules;
+        self
+    }
+
+    /// Sets the app/router variables whose decorators declare routes
+    /// (e.g. `application` for `application = FastAPI()`)
+    ///
+    /// Replaces the name heuristics (`app`, `router`, `api_router`, ...) when non-empty.
+    pub fn with_route_objects(mut self, route_objects: Vec<String>) -> Self {
+        self.parser.set_route_objects(route_objects);
+        self
+    }
+
+    /// Registers project-specific route decorators (`@endpoints.fetch("/users")`)
+    ///
+    /// The built-in FastAPI patterns stay recognized; a matching pattern's method mapping
+    /// decides the HTTP method of the route.
+    pub fn with_route_patterns(mut self, route_patterns: Vec<RoutePattern>) -> Self {
+        self.parser.set_route_patterns(route_patterns);
+        self
+    }
+
+    /// Sets the root imports are resolved from (defaults to the entry point's directory)
+    pub fn with_project_root(mut self, project_root: PathBuf) -> Self {
+        self.project_root = Some(project_root);
+        self
+    }
+
     /// Sets the maximum recursion depth
     pub fn with_max_depth(mut self, max_depth: Option<usize>) -> Self {
         self.max_depth = max_depth;
         self
     }
 
+    /// Stops reading files once `deadline` has passed (None = no limit)
+    ///
+    /// The deadline is checked at file boundaries: files not reached by then are skipped,
+    /// so the graph holds what was built so far. See [`Self::timed_out`].
+    pub fn with_deadline(mut self, deadline: Option<Instant>) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Sets additional method names recorded as `TransformationType::Custom` data flows
+    /// regardless of the receiver (e.g. a project's `to_response()` convention)
+    pub fn with_transformation_methods(mut self, methods: Vec<String>) -> Self {
+        self.transformation_methods = methods.into_iter().collect();
+        self
+    }
+
+    /// Sets the type compatibility matrix used when linking Pydantic and ORM models
+    /// (defaults plus project-specific equivalences)
+    pub fn with_type_compatibility(mut self, type_compatibility: TypeCompatibility) -> Self {
+        self.type_compatibility = type_compatibility;
+        self
+    }
+
+    /// Bounds the number of threads `build_from_files` parses files on (defaults to one per
+    /// core)
+    ///
+    /// The graph doesn't depend on it: files are only parsed in parallel, and added to the
+    /// graph in the order they are given.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = Some(threads.max(1));
+        self
+    }
+
     /// Finds the entry point (main.py, app.py) in the project
     pub fn find_entry_point(&self, project_root: &Path) -> Result<PathBuf> {
         let candidates = ["main.py", "app.py", "__main__.py"];
@@ -125,6 +305,16 @@ impl CallGraphBuilder {
         if self.processed_files.contains(&normalized_entry) {
             return Ok(()); // Already processed
         }
+        if self.single_file && !self.entry_points.is_empty() {
+            return Ok(());
+        }
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            self.timed_out = true;
+            return Ok(());
+        }
 
         // Check recursion depth limit
         if let Some(max_depth) = self.max_depth {
@@ -143,32 +333,248 @@ impl CallGraphBuilder {
             }
         }
 
-        let source = fs::read_to_string(&normalized_entry)
-            .with_context(|| format!("Failed to read {:?}", normalized_entry))?;
-        let ast = parse(
-            &source,
-            Mode::Module,
-            normalized_entry.to_string_lossy().as_ref(),
-        )
-        .with_context(|| format!("Failed to parse {:?}", normalized_entry))?;
-
-        // Create LocationConverter for accurate byte offset conversion
-        let converter = LocationConverter::new(source);
+        let module = match self
+            .parsed_modules
+            .get(&normalized_entry)
+            .cloned()
+            .flatten()
+        {
+            Some(module) => Some(module),
+            None => match self.parse_entry(&normalized_entry) {
+                Ok(module) => module,
+                Err(err) => {
+                    self.current_depth -= 1;
+                    return Err(err);
+                }
+            },
+        };
+        let Some(module) = module else {
+            self.current_depth -= 1;
+            return Ok(());
+        };
+        let (ast, converter) = (&module.ast, &module.converter);
 
         let module_node = self.get_or_create_module_node(&normalized_entry)?;
 
         self.processed_files.insert(normalized_entry.clone());
         self.entry_points.push(normalized_entry.clone());
 
-        self.process_imports(&ast, module_node, &normalized_entry, &converter)?;
-        self.extract_functions_and_classes(&ast, &normalized_entry, &converter)?;
-        self.process_calls(&ast, module_node, &normalized_entry, &converter)?;
-        self.process_decorators(&ast, &normalized_entry, &converter)?;
+        self.process_imports(ast, module_node, &normalized_entry, converter)?;
+        self.collect_type_aliases(ast, &normalized_entry);
+        self.extract_functions_and_classes(ast, &normalized_entry, converter)?;
+        self.process_calls(ast, module_node, &normalized_entry, converter)?;
+        self.process_decorators(ast, &normalized_entry, converter)?;
 
         self.current_depth -= 1;
+        if self.current_depth == 0 {
+            self.report_ambiguous_models();
+        }
+        Ok(())
+    }
+
+    /// Builds the graph from several files, each treated as an entry point
+    ///
+    /// Files already reached through imports of earlier ones are skipped, and every file is
+    /// read and parsed once for all passes over it, so a stream of project files can be fed
+    /// to one builder. The files are parsed in parallel (see [`Self::with_threads`]), then
+    /// added to the graph one by one in the given order, so the graph is the same whatever
+    /// the number of threads.
+    pub fn build_from_files<I>(&mut self, files: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<Path>,
+    {
+        let files: Vec<PathBuf> = files
+            .into_iter()
+            .map(|file| Self::normalize_path(file.as_ref()))
+            .collect();
+        if !self.single_file {
+            self.parse_in_parallel(&files)?;
+        }
+        for file in &files {
+            self.build_from_entry(file)?;
+        }
         Ok(())
     }
 
+    /// Reads and parses the files not parsed yet on a pool of `threads` threads, caching
+    /// the modules
+    ///
+    /// Files that can't be read are left to `build_from_entry`, which reports them; syntax
+    /// errors are kept for it to report.
+    fn parse_in_parallel(&mut self, files: &[PathBuf]) -> Result<()> {
+        let mut pending: Vec<&PathBuf> = files
+            .iter()
+            .filter(|file| !self.parsed_modules.contains_key(*file))
+            .collect();
+        pending.sort();
+        pending.dedup();
+        if pending.len() < 2 {
+            return Ok(());
+        }
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(self.threads.unwrap_or(0))
+            .build()
+            .context("Failed to start the parser threads")?;
+        let parsed: Vec<(&PathBuf, Result<Arc<ParsedModule>, ParseFailure>)> = pool.install(|| {
+            pending
+                .par_iter()
+                .filter_map(|file| {
+                    let source = fs::read_to_string(file).ok()?;
+                    let parsed = parse(&source, Mode::Module, file.to_string_lossy().as_ref());
+                    let converter = LocationConverter::new(source);
+                    let module = match parsed {
+                        Ok(ast) => Ok(Arc::new(ParsedModule { ast, converter })),
+                        Err(error) => Err(ParseFailure { error, converter }),
+                    };
+                    Some((*file, module))
+                })
+                .collect()
+        });
+
+        for (file, module) in parsed {
+            self.parse_count += 1;
+            match module {
+                Ok(module) => {
+                    self.parsed_modules.insert(file.clone(), Some(module));
+                }
+                Err(failure) => {
+                    self.parsed_modules.insert(file.clone(), None);
+                    self.parse_failures.insert(file.clone(), failure);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Number of times a source file was read and parsed during the build
+    pub fn parse_count(&self) -> usize {
+        self.parse_count
+    }
+
+    /// Extracts the routes, schemas and calls of one file, for fast editor feedback
+    ///
+    /// Unlike [`Self::build_from_entry`], only `file` is read and parsed: imports aren't
+    /// followed, so route schemas imported from other modules are listed as unresolved
+    /// instead of being resolved to their fields. Use a fresh builder for each file.
+    pub fn analyze_file(&mut self, file: &Path) -> Result<FileAnalysis> {
+        self.single_file = true;
+        let normalized = Self::normalize_path(file);
+        self.build_from_entry(&normalized)?;
+
+        let module = self.parsed_modules.get(&normalized).cloned().flatten();
+        let calls = match &module {
+            Some(module) => self.parser.extract_calls(
+                &module.ast,
+                &normalized.to_string_lossy(),
+                &module.converter,
+            ),
+            None => Vec::new(),
+        };
+        let mut schemas: Vec<SchemaReference> = self
+            .pydantic_model_variants
+            .values()
+            .flatten()
+            .chain(self.orm_models.values())
+            .filter(|schema| Self::normalize_path(Path::new(&schema.location.file)) == normalized)
+            .cloned()
+            .collect();
+        schemas.sort_by_key(|schema| schema.location.line);
+
+        Ok(FileAnalysis::new(
+            &self.graph,
+            module.as_ref().map(|module| &module.ast),
+            schemas,
+            calls,
+            self.file_imports.get(&normalized),
+            self.diagnostics.clone(),
+        ))
+    }
+
+    /// Parsed module of a file, read and parsed on first use
+    ///
+    /// `None` when the file can't be read or has a syntax error (entry points report the
+    /// latter as a diagnostic, see `parse_entry`).
+    fn parsed_module(&mut self, file_path: &Path) -> Option<Arc<ParsedModule>> {
+        let normalized = Self::normalize_path(file_path);
+        if let Some(module) = self.parsed_modules.get(&normalized) {
+            return module.clone();
+        }
+        if self.single_file {
+            return None;
+        }
+        let module = fs::read_to_string(&normalized).ok().and_then(|source| {
+            self.parse_count += 1;
+            let ast = parse(&source, Mode::Module, normalized.to_string_lossy().as_ref()).ok()?;
+            Some(Arc::new(ParsedModule {
+                ast,
+                converter: LocationConverter::new(source),
+            }))
+        });
+        self.parsed_modules.insert(normalized, module.clone());
+        module
+    }
+
+    /// Reads and parses an entry point, caching the result
+    ///
+    /// A syntax error is recorded as a `parse-error` diagnostic and yields `Ok(None)`, or an
+    /// error with `strict_parse`.
+    fn parse_entry(&mut self, normalized_entry: &Path) -> Result<Option<Arc<ParsedModule>>> {
+        let (parsed, converter) = match self.parse_failures.remove(normalized_entry) {
+            Some(failure) => (Err(failure.error), failure.converter),
+            None => {
+                let source = fs::read_to_string(normalized_entry)
+                    .with_context(|| format!("Failed to read {:?}", normalized_entry))?;
+                self.parse_count += 1;
+                let parsed = parse(
+                    &source,
+                    Mode::Module,
+                    normalized_entry.to_string_lossy().as_ref(),
+                );
+                // Create LocationConverter for accurate byte offset conversion
+                (parsed, LocationConverter::new(source))
+            }
+        };
+
+        let ast = match parsed {
+            Ok(ast) => ast,
+            Err(err) => {
+                let (line, column) = converter.byte_offset_to_location(err.offset.to_usize());
+                let diagnostic = Diagnostic::parse_error(
+                    Location {
+                        file: normalized_entry.to_string_lossy().to_string(),
+                        line,
+                        column: Some(column),
+                    },
+                    &err.error,
+                );
+                if self.strict_parse {
+                    self.diagnostics.push(diagnostic);
+                    return Err(err)
+                        .with_context(|| format!("Failed to parse {:?}", normalized_entry));
+                }
+                warn!(
+                    file = ?normalized_entry,
+                    line,
+                    error = %err.error,
+                    "Skipping file that could not be parsed"
+                );
+                // Don't retry the file when other modules import it
+                self.processed_files.insert(normalized_entry.to_path_buf());
+                self.parsed_modules
+                    .insert(normalized_entry.to_path_buf(), None);
+                self.diagnostics.push(diagnostic);
+                return Ok(None);
+            }
+        };
+
+        let module = Arc::new(ParsedModule { ast, converter });
+        self.parsed_modules
+            .insert(normalized_entry.to_path_buf(), Some(module.clone()));
+        Ok(Some(module))
+    }
+
     /// Resolves import according to strict_imports configuration.
     /// In non-strict mode, unresolved imports are logged (if verbose) and treated as no-op (Ok(None)).
     /// In strict mode, unresolved imports result in an error.
@@ -209,9 +615,26 @@ impl CallGraphBuilder {
                         current_file = ?current_file,
                         "Import could not be resolved, treated as local/missing"
                     );
+                    if self.is_missing_project_module(&import.path, current_file) {
+                        self.record_analysis_warning(AnalysisWarning::new(
+                            AnalysisWarningKind::UnresolvedImport,
+                            import.location.clone(),
+                            format!("Module '{}' was not found in the project", import.path),
+                        ));
+                    }
                     Ok(None)
                 }
             }
+            Err(ImportError::ExternalDependency { module, .. })
+                if self.is_known_external(&module) =>
+            {
+                debug!(
+                    import_path = %import.path,
+                    module = %module,
+                    "Known external module not installed, skipping import"
+                );
+                Ok(None)
+            }
             Err(ImportError::ExternalDependency { module, suggestion }) => {
                 let msg = format!(
                     "External dependency not resolved: {} ({}).",
@@ -227,29 +650,52 @@ impl CallGraphBuilder {
                         suggestion = %suggestion,
                         "External dependency not resolved, skipping import"
                     );
+                    self.record_analysis_warning(AnalysisWarning::new(
+                        AnalysisWarningKind::ExternalDependency,
+                        import.location.clone(),
+                        format!("Module '{}' is not installed ({})", module, suggestion),
+                    ));
                     Ok(None)
                 }
             }
-            Err(ImportError::ResolutionFailed { import, reason }) => {
+            Err(ImportError::ResolutionFailed {
+                import: unresolved,
+                reason,
+            }) => {
                 let msg = format!(
                     "Failed to resolve import '{}' from {:?}: {}",
-                    import, current_file, reason
+                    unresolved, current_file, reason
                 );
                 if self.strict_imports {
                     anyhow::bail!("[STRICT IMPORTS] {}", msg);
                 } else {
                     warn!(
-                        import = %import,
+                        import = %unresolved,
                         current_file = ?current_file,
                         reason = %reason,
                         "Failed to resolve import, continuing"
                     );
+                    self.record_analysis_warning(AnalysisWarning::new(
+                        AnalysisWarningKind::UnresolvedImport,
+                        import.location.clone(),
+                        format!("Failed to resolve import '{}': {}", unresolved, reason),
+                    ));
                     Ok(None)
                 }
             }
         }
     }
 
+    /// Whether a module is one of the configured external modules or a submodule of one
+    fn is_known_external(&self, module: &str) -> bool {
+        self.external_modules.iter().any(|prefix| {
+            module == prefix
+                || module
+                    .strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.starts_with('.'))
+        })
+    }
+
     /// Processes an import: adds a node and an edge
     pub fn process_import(
         &mut self,
@@ -285,6 +731,7 @@ impl CallGraphBuilder {
                 error = %err,
                 "Failed to recursively build graph for imported module"
             );
+            self.record_skipped_module(&import_path, &err);
         }
 
         // Extract and cache Pydantic models from imported file
@@ -353,6 +800,11 @@ impl CallGraphBuilder {
             );
         }
 
+        // Background tasks run after the response, but still as part of the handler
+        if let Some(task_call) = background_task_call(call) {
+            return self.process_call(caller, &task_call, current_file);
+        }
+
         let Some(callee_node) = self.find_function_node(&call.name, current_file) else {
             // Function not found, return caller without creating edge
             return Ok(caller);
@@ -385,7 +837,9 @@ impl CallGraphBuilder {
         if let Some(file) = self.node_file_path(callee_node) {
             let normalized = Self::normalize_path(&file);
             if !self.processed_files.contains(&normalized) {
-                let _ = self.build_from_entry(&normalized);
+                if let Err(err) = self.build_from_entry(&normalized) {
+                    self.record_skipped_module(&normalized, &err);
+                }
             }
         }
 
@@ -402,7 +856,12 @@ impl CallGraphBuilder {
             );
             return Ok(());
         }
+        self.process_route(decorator, current_file)
+    }
 
+    /// Adds the routes a route decorator (or an equivalent route registration call)
+    /// declares, wired to its handler
+    fn process_route(&mut self, decorator: &Decorator, current_file: &Path) -> Result<()> {
         let handler_name = match &decorator.target_function {
             Some(name) => name,
             None => {
@@ -462,13 +921,11 @@ impl CallGraphBuilder {
             return Ok(());
         };
 
-        let http_method = self
-            .extract_http_method(&decorator.name)
-            .unwrap_or(HttpMethod::Get);
+        let http_methods = self.route_http_methods(decorator);
         let route_path = decorator
             .arguments
             .first()
-            .cloned()
+            .map(|argument| self.resolve_route_path(argument, current_file))
             .unwrap_or_else(|| "/".to_string());
 
         let mut location = decorator.location.clone();
@@ -506,7 +963,7 @@ impl CallGraphBuilder {
                                             .trim()
                                             .to_string();
                                         if let Some(pydantic_model) =
-                                            self.pydantic_models.get(&simple_name)
+                                            self.pydantic_model_in(&simple_name, current_file)
                                         {
                                             return Some(pydantic_model.clone());
                                         }
@@ -530,7 +987,8 @@ impl CallGraphBuilder {
                                     .unwrap_or(&schema_ref.name)
                                     .trim()
                                     .to_string();
-                                if let Some(pydantic_model) = self.pydantic_models.get(&simple_name)
+                                if let Some(pydantic_model) =
+                                    self.pydantic_model_in(&simple_name, current_file)
                                 {
                                     return Some(pydantic_model.clone());
                                 }
@@ -552,7 +1010,7 @@ impl CallGraphBuilder {
                                 };
 
                                 if let Some(pydantic_model) =
-                                    self.pydantic_models.get(&param_name_capitalized)
+                                    self.pydantic_model_in(&param_name_capitalized, current_file)
                                 {
                                     return Some(pydantic_model.clone());
                                 }
@@ -611,18 +1069,23 @@ impl CallGraphBuilder {
                                 error = %err,
                                 "Failed to enrich schema"
                             );
+                            let warning = Self::schema_enrichment_warning(model, &err);
+                            self.record_analysis_warning(warning);
                         }
                     }
                 }
             }
 
             // Apply response_model to handler node's return_type
+            let response_model = self
+                .pydantic_model_in(&response_model_name, current_file)
+                .cloned();
             if let Some(handler_node) = self.graph.node_weight_mut(handler_node.0) {
                 // Get the Pydantic model schema reference
-                if let Some(pydantic_model) = self.pydantic_models.get(&response_model_name) {
+                if let Some(pydantic_model) = response_model {
                     let return_type = Some(TypeInfo {
                         base_type: BaseType::Object,
-                        schema_ref: Some(pydantic_model.clone()),
+                        schema_ref: Some(pydantic_model),
                         constraints: Vec::new(),
                         optional: false,
                     });
@@ -714,7 +1177,7 @@ impl CallGraphBuilder {
         if let Some(ref req_schema) = request_body_schema {
             debug!(
                 schema_name = %req_schema.name,
-                http_method = ?http_method,
+                http_methods = ?http_methods,
                 route_path = %route_path,
                 "Found request body schema for route"
             );
@@ -734,43 +1197,259 @@ impl CallGraphBuilder {
                 .to_string();
 
             // Get schema from cache
-            self.pydantic_models.get(&response_model_name).cloned()
+            self.pydantic_model_in(&response_model_name, current_file)
+                .cloned()
         } else {
             // Try to get from handler's return_type
             handler_returns_data.and_then(|rt| rt.schema_ref.clone())
         };
 
-        let route_node = NodeId::from(self.graph.add_node(CallNode::Route {
-            path: route_path.clone(),
-            method: http_method,
-            handler: handler_node,
-            location: location.clone(),
-            request_schema: request_body_schema.clone(),
-            response_schema: response_model_schema.clone(),
-        }));
+        // `response_class=HTMLResponse` and the like don't send JSON: there's no model to
+        // compare their response with
+        let response_class = decorator
+            .keyword_arguments
+            .get("response_class")
+            .map(|value| value.trim());
+        let response_media_type =
+            response_class.and_then(PythonParser::non_json_response_media_type);
+
+        // Unannotated handlers: infer the response from the models their `return`s build
+        let mut returned_schemas = Vec::new();
+        let response_model_schema = match response_model_schema {
+            _ if response_media_type.is_some() => None,
+            Some(schema) => Some(schema),
+            None => {
+                returned_schemas = self.inferred_response_schemas(handler_node, current_file);
+                returned_schemas.first().cloned()
+            }
+        };
 
-        self.graph.add_edge(
-            route_node.0,
-            handler_node.0,
-            CallEdge::Call {
-                caller: route_node,
-                callee: handler_node,
-                argument_mapping: Vec::new(),
-                location,
-            },
-        );
+        // Undocumented routes stay in the graph but are not compared with the OpenAPI schema
+        let mut metadata = HashMap::new();
+        if decorator
+            .keyword_arguments
+            .get("include_in_schema")
+            .is_some_and(|value| value.trim().eq_ignore_ascii_case("false"))
+        {
+            metadata.insert(
+                crate::call_graph::INCLUDE_IN_SCHEMA_METADATA_KEY.to_string(),
+                "false".to_string(),
+            );
+        }
+        // Deprecated routes still serve requests; frontend calls to them are reported as info
+        if decorator
+            .keyword_arguments
+            .get("deprecated")
+            .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"))
+        {
+            metadata.insert(
+                crate::call_graph::DEPRECATED_METADATA_KEY.to_string(),
+                "true".to_string(),
+            );
+        }
+        if let Some(operation_id) = decorator
+            .keyword_arguments
+            .get("operation_id")
+            .map(|value| value.trim().trim_matches(|c| c == '"' || c == '\''))
+            .filter(|value| !value.is_empty())
+        {
+            metadata.insert(
+                crate::call_graph::OPERATION_ID_METADATA_KEY.to_string(),
+                operation_id.to_string(),
+            );
+        }
 
-        debug!(
-            http_method = ?http_method,
-            route_path = %route_path,
-            handler_node_index = handler_node.0.index(),
-            file_path = ?current_file,
-            "Created route node"
-        );
+        // `status_code=status.HTTP_201_CREATED` is resolved to its code; a custom constant
+        // is only recorded by name
+        if let Some(status_code) = decorator
+            .keyword_arguments
+            .get("status_code")
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+        {
+            let code = match status_code.parse::<u16>() {
+                Ok(code) => Some(code),
+                Err(_) => {
+                    metadata.insert(
+                        crate::call_graph::STATUS_CONSTANT_METADATA_KEY.to_string(),
+                        status_code.to_string(),
+                    );
+                    status_constant_code(status_code)
+                }
+            };
+            if let Some(code) = code {
+                metadata.insert(
+                    crate::call_graph::STATUS_CODE_METADATA_KEY.to_string(),
+                    code.to_string(),
+                );
+            }
+        }
+
+        if let Some(headers) = self.required_headers.get(&handler_node) {
+            metadata.insert(
+                crate::call_graph::REQUIRED_HEADERS_METADATA_KEY.to_string(),
+                headers.join(","),
+            );
+        }
+
+        // Parameters named in the path are path parameters, whatever their declaration
+        let query_parameters: Vec<&QueryParameter> = self
+            .query_parameters
+            .get(&handler_node)
+            .into_iter()
+            .flatten()
+            .filter(|parameter| {
+                !route_path.contains(&format!("{{{}}}", parameter.name))
+                    && !route_path.contains(&format!("{{{}:", parameter.name))
+            })
+            .collect();
+        if !query_parameters.is_empty() {
+            if let Ok(json) = serde_json::to_string(&query_parameters) {
+                metadata.insert(
+                    crate::call_graph::QUERY_PARAMETERS_METADATA_KEY.to_string(),
+                    json,
+                );
+            }
+        }
+
+        // Form parameters decide the body encoding, a body schema alone means JSON
+        let request_media_type = self
+            .request_media_types
+            .get(&handler_node)
+            .copied()
+            .or(request_body_schema.as_ref().map(|_| "application/json"));
+        if let Some(media_type) = request_media_type {
+            metadata.insert(
+                crate::call_graph::REQUEST_MEDIA_TYPE_METADATA_KEY.to_string(),
+                media_type.to_string(),
+            );
+        }
+
+        if let Some(media_type) = response_media_type {
+            metadata.insert(
+                crate::call_graph::RESPONSE_MEDIA_TYPE_METADATA_KEY.to_string(),
+                media_type.to_string(),
+            );
+        }
+        if response_class.is_some_and(|class| class.rsplit('.').next() == Some("StreamingResponse"))
+        {
+            metadata.insert(
+                crate::call_graph::STREAMING_RESPONSE_METADATA_KEY.to_string(),
+                "true".to_string(),
+            );
+        }
+
+        if let Some(envelope) = response_model_type
+            .as_deref()
+            .and_then(|response_model| self.response_envelope(response_model, current_file))
+        {
+            metadata.insert(
+                crate::call_graph::RESPONSE_ENVELOPE_METADATA_KEY.to_string(),
+                envelope.join(","),
+            );
+        }
+
+        if returned_schemas.len() > 1 {
+            let names: Vec<&str> = returned_schemas
+                .iter()
+                .map(|schema| schema.name.as_str())
+                .collect();
+            metadata.insert(
+                crate::call_graph::RETURNED_SCHEMAS_METADATA_KEY.to_string(),
+                names.join(","),
+            );
+        }
+
+        // `@app.route(path, methods=["GET", "POST"])` declares one route per method
+        for http_method in http_methods {
+            let route_node = NodeId::from(self.graph.add_node(CallNode::Route {
+                path: route_path.clone(),
+                method: http_method,
+                handler: handler_node,
+                location: location.clone(),
+                request_schema: request_body_schema.clone(),
+                response_schema: response_model_schema.clone(),
+                metadata: metadata.clone(),
+            }));
+
+            self.graph.add_edge(
+                route_node.0,
+                handler_node.0,
+                CallEdge::Call {
+                    caller: route_node,
+                    callee: handler_node,
+                    argument_mapping: Vec::new(),
+                    location: location.clone(),
+                },
+            );
+
+            debug!(
+                http_method = ?http_method,
+                route_path = %route_path,
+                handler_node_index = handler_node.0.index(),
+                file_path = ?current_file,
+                "Created route node"
+            );
+        }
 
         Ok(())
     }
 
+    /// Adds a `Relationship` edge between ORM model classes for every `relationship()` field
+    /// whose target model is known (`orders: Mapped[list["Order"]]`, `relationship("Order")`)
+    pub fn link_orm_relationships(&mut self) {
+        let mut models: Vec<&SchemaReference> = self.orm_models.values().collect();
+        models.sort_by(|a, b| a.name.cmp(&b.name));
+        let mut links = Vec::new();
+        for model in models {
+            let fields: Vec<crate::models::SQLAlchemyField> = model
+                .metadata
+                .get("fields")
+                .and_then(|json| serde_json::from_str(json).ok())
+                .unwrap_or_default();
+            for field in fields {
+                let Some(related) = field.related_model.as_deref() else {
+                    continue;
+                };
+                let related = related.rsplit('.').next().unwrap_or(related);
+                if !self.orm_models.contains_key(related) {
+                    continue;
+                }
+                let (Some(from), Some(to)) = (
+                    self.find_class_node_by_name(&model.name),
+                    self.find_class_node_by_name(related),
+                ) else {
+                    continue;
+                };
+                let kind = if field.many {
+                    RelationshipKind::OneToMany
+                } else {
+                    RelationshipKind::ManyToOne
+                };
+                links.push((from, to, field.name, kind));
+            }
+        }
+
+        for (from, to, field, kind) in links {
+            let linked = self.graph.edges_connecting(*from, *to).any(|edge| {
+                matches!(edge.weight(), CallEdge::Relationship { field: existing, .. } if *existing == field)
+            });
+            if linked {
+                continue;
+            }
+            self.graph.add_edge(
+                *from,
+                *to,
+                CallEdge::Relationship {
+                    from,
+                    to,
+                    field,
+                    kind,
+                },
+            );
+        }
+    }
+
     /// Links Pydantic models with SQLAlchemy models based on from_attributes
     /// This should be called after the graph is built to ensure all classes are available
     pub fn link_pydantic_to_sqlalchemy(&mut self) {
@@ -824,10 +1503,11 @@ impl CallGraphBuilder {
                 .trim_end_matches("Model")
                 .to_string();
 
-            let mut best_match: Option<(String, SchemaReference, f64)> = None;
+            let mut best_match: Option<(String, SchemaReference, f64, &str)> = None;
 
             for (sql_name, sql_schema) in &sqlalchemy_models {
                 let mut match_score = 0.0;
+                let mut strategy = "exact";
 
                 // Strategy 1: Exact name match
                 if sql_name == &pydantic_name {
@@ -836,6 +1516,7 @@ impl CallGraphBuilder {
                 // Strategy 2: Base name match (remove suffixes)
                 else if sql_name == &base_name {
                     match_score = 0.9;
+                    strategy = "base-name";
                 }
                 // Strategy 3: Field-based matching
                 else if !pydantic_fields.is_empty() {
@@ -849,23 +1530,25 @@ impl CallGraphBuilder {
                     let field_match = self.match_fields(&pydantic_fields, &sql_fields);
                     if field_match > 0.7 {
                         match_score = field_match;
+                        strategy = "field-based";
                     }
                 }
 
                 // Update best match if this is better
                 if match_score > 0.0 {
-                    if let Some((_, _, best_score)) = &best_match {
+                    let candidate = (sql_name.clone(), sql_schema.clone(), match_score, strategy);
+                    if let Some((_, _, best_score, _)) = &best_match {
                         if match_score > *best_score {
-                            best_match = Some((sql_name.clone(), sql_schema.clone(), match_score));
+                            best_match = Some(candidate);
                         }
                     } else {
-                        best_match = Some((sql_name.clone(), sql_schema.clone(), match_score));
+                        best_match = Some(candidate);
                     }
                 }
             }
 
             // Use best match if found
-            if let Some((sql_name, sql_schema, match_score)) = best_match {
+            if let Some((sql_name, sql_schema, match_score, strategy)) = best_match {
                 // Find the SQLAlchemy node in the graph for metadata
                 let sql_node_id = self.graph.node_indices().find_map(|idx| {
                     if let Some(CallNode::Class { name, .. }) = self.graph.node_weight(idx) {
@@ -892,11 +1575,15 @@ impl CallGraphBuilder {
                         format!("{}", node_id.0.index()),
                     );
                 }
-                // Also store match score for debugging
+                // Also store how the link was found, for auditing borderline links
                 pydantic_model.metadata.insert(
                     "sqlalchemy_match_score".to_string(),
                     format!("{:.2}", match_score),
                 );
+                pydantic_model.metadata.insert(
+                    "sqlalchemy_match_strategy".to_string(),
+                    strategy.to_string(),
+                );
 
                 debug!(
                     pydantic_name = %pydantic_name,
@@ -1032,16 +1719,175 @@ impl CallGraphBuilder {
 
     /// Gets the built graph
     pub fn into_graph(mut self) -> CallGraph {
+        // Forward refs may pull models from other modules, which then take part in linking
+        self.resolve_forward_refs();
         // Link Pydantic models to SQLAlchemy models before returning the graph
         self.link_pydantic_to_sqlalchemy();
+        self.link_orm_relationships();
         self.graph
     }
 
+    /// Second pass over the Pydantic cache: resolves string forward references
+    /// (`children: list["Node"]`, `owner: "users.User"`) once every model is known
+    ///
+    /// Models declared in other modules are loaded through the import map of the referencing
+    /// model's file (`if TYPE_CHECKING:` imports included). Each resolved reference is recorded
+    /// in the model's `resolved_forward_refs` metadata as `name=file:line`; unresolved ones are
+    /// only logged, since they may name non-model types.
+    pub fn resolve_forward_refs(&mut self) {
+        // Models loaded while resolving may carry forward references of their own
+        let mut visited: HashSet<String> = HashSet::new();
+        loop {
+            let mut pending: Vec<(String, PathBuf, Vec<String>)> = self
+                .pydantic_models
+                .values()
+                .filter(|model| !visited.contains(&model.name))
+                .filter_map(|model| {
+                    let refs = model.metadata.get(FORWARD_REFS_METADATA_KEY)?;
+                    Some((
+                        model.name.clone(),
+                        PathBuf::from(&model.location.file),
+                        refs.split(',').map(str::to_string).collect(),
+                    ))
+                })
+                .collect();
+            if pending.is_empty() {
+                return;
+            }
+            pending.sort();
+            for (model_name, model_file, refs) in pending {
+                visited.insert(model_name.clone());
+                self.resolve_model_forward_refs(&model_name, &model_file, &refs);
+            }
+        }
+    }
+
+    fn resolve_model_forward_refs(&mut self, model_name: &str, model_file: &Path, refs: &[String]) {
+        let mut resolved = Vec::new();
+        for reference in refs {
+            let simple_name = reference.rsplit('.').next().unwrap_or(reference);
+            if !self.pydantic_models.contains_key(simple_name) {
+                self.ensure_file_imports(model_file);
+                if let Err(err) = self.resolve_schema_from_imports(simple_name, model_file) {
+                    debug!(
+                        model_name = %model_name,
+                        forward_ref = %reference,
+                        error = %err,
+                        "Failed to resolve forward reference from imports"
+                    );
+                }
+            }
+            match self.pydantic_models.get(simple_name) {
+                Some(target) => resolved.push(format!(
+                    "{}={}:{}",
+                    reference, target.location.file, target.location.line
+                )),
+                None => debug!(
+                    model_name = %model_name,
+                    forward_ref = %reference,
+                    "Unresolved forward reference"
+                ),
+            }
+        }
+        if resolved.is_empty() {
+            return;
+        }
+        if let Some(model) = self.pydantic_models.get_mut(model_name) {
+            model.metadata.insert(
+                RESOLVED_FORWARD_REFS_METADATA_KEY.to_string(),
+                resolved.join(","),
+            );
+            let model = model.clone();
+            self.refresh_graph_schema(&model);
+        }
+    }
+
+    /// Copies the metadata of a cached schema into the graph nodes already holding it
+    fn refresh_graph_schema(&mut self, schema: &SchemaReference) {
+        let same = |known: &SchemaReference| {
+            known.schema_type == schema.schema_type
+                && known.name == schema.name
+                && known.location == schema.location
+        };
+        let mut refresh = |known: &mut SchemaReference| {
+            if same(known) {
+                known.metadata = schema.metadata.clone();
+            }
+        };
+        for node in self.graph.node_weights_mut() {
+            match node {
+                CallNode::Schema { schema } => refresh(schema),
+                CallNode::Route {
+                    request_schema,
+                    response_schema,
+                    ..
+                } => {
+                    request_schema.iter_mut().for_each(&mut refresh);
+                    response_schema.iter_mut().for_each(&mut refresh);
+                }
+                CallNode::Function {
+                    parameters,
+                    return_type,
+                    ..
+                }
+                | CallNode::Method {
+                    parameters,
+                    return_type,
+                    ..
+                } => {
+                    let types = parameters
+                        .iter_mut()
+                        .map(|param| &mut param.type_info)
+                        .chain(return_type.iter_mut());
+                    for type_info in types {
+                        type_info.schema_ref.iter_mut().for_each(&mut refresh);
+                    }
+                }
+                CallNode::Module { .. } | CallNode::Class { .. } => {}
+            }
+        }
+    }
+
+    /// Records the import map of a file that was only read for its models
+    fn ensure_file_imports(&mut self, file_path: &Path) {
+        let normalized_file = Self::normalize_path(file_path);
+        if self.file_imports.contains_key(&normalized_file) {
+            return;
+        }
+        let Some(module) = self.parsed_module(file_path) else {
+            return;
+        };
+        let imports = self.parser.extract_imports(
+            &module.ast,
+            &file_path.to_string_lossy(),
+            &module.converter,
+        );
+        self.file_imports
+            .insert(normalized_file, Self::import_map(&imports));
+    }
+
     /// Gets a reference to the graph
     pub fn graph(&self) -> &CallGraph {
         &self.graph
     }
 
+    /// Problems found while building (files skipped because of syntax errors, ...)
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// What the build couldn't follow (unresolved imports, skipped modules, ...), so
+    /// contracts through it were not checked
+    pub fn analysis_warnings(&self) -> &[AnalysisWarning] {
+        &self.analysis_warnings
+    }
+
+    /// Whether files were skipped because the deadline passed (see [`Self::with_deadline`]),
+    /// leaving the graph partial
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
     /// Gets a mutable reference to the graph
     pub fn graph_mut(&mut self) -> &mut CallGraph {
         &mut self.graph
@@ -1067,11 +1913,9 @@ impl CallGraphBuilder {
             .parser
             .extract_imports(module_ast, &file_path_str, converter);
 
-        // Store import information for schema resolution
-        let normalized_file = Self::normalize_path(file_path);
-        let mut file_imports_map = HashMap::new();
-
-        for import in &imports {
+        // A single file is analyzed without the modules it imports
+        let followed: &[Import] = if self.single_file { &[] } else { &imports };
+        for import in followed {
             if let Err(err) = self.process_import(module_node, import, file_path) {
                 warn!(
                     import_path = %import.path,
@@ -1080,8 +1924,91 @@ impl CallGraphBuilder {
                     "Failed to process import"
                 );
             }
+        }
+
+        // Store import information for schema resolution
+        let mut file_imports_map = Self::import_map(&imports);
+        self.expand_star_imports(&imports, file_path, &mut file_imports_map);
+        if !file_imports_map.is_empty() {
+            if !self.single_file {
+                self.load_shadowed_models(&file_imports_map, file_path);
+            }
+            self.file_imports
+                .insert(Self::normalize_path(file_path), file_imports_map);
+        }
+
+        Ok(())
+    }
+
+    /// Maps the names star imports (`from .schemas import *`) bring into a file to their
+    /// module, and caches the models of that module so the file's annotations can use them
+    ///
+    /// Names are taken from the module's `__all__` when it declares one (see
+    /// `PythonParser::star_import_names`); names imported explicitly keep their module.
+    fn expand_star_imports(
+        &mut self,
+        imports: &[Import],
+        file_path: &Path,
+        file_imports_map: &mut HashMap<String, String>,
+    ) {
+        file_imports_map.remove("*");
+        for import in imports
+            .iter()
+            .filter(|import| import.names.iter().any(|name| name == "*"))
+        {
+            let Ok(module_file) = self.resolve_import_path(&import.path, file_path) else {
+                continue;
+            };
+            if Self::normalize_path(&module_file) == Self::normalize_path(file_path) {
+                continue;
+            }
+            let Some(module) = self.parsed_module(&module_file) else {
+                continue;
+            };
+            for name in self.parser.star_import_names(&module.ast) {
+                file_imports_map
+                    .entry(name)
+                    .or_insert_with(|| import.path.clone());
+            }
+            if let Err(err) = self.extract_and_cache_pydantic_models(&module_file) {
+                debug!(
+                    module_file = ?module_file,
+                    error = %err,
+                    "Failed to extract star-imported models"
+                );
+            }
+        }
+    }
 
-            // Store import mapping: imported name -> module path
+    /// Caches the models a file imports under a name already cached from another module,
+    /// so its references resolve to the imported ones (see `pydantic_model_in`)
+    fn load_shadowed_models(&mut self, imports: &HashMap<String, String>, file_path: &Path) {
+        for (name, module) in imports {
+            let Some(cached) = self.pydantic_models.get(name) else {
+                continue;
+            };
+            let Ok(module_file) = self.resolve_import_path(module, file_path) else {
+                continue;
+            };
+            if Self::normalize_path(Path::new(&cached.location.file))
+                == Self::normalize_path(&module_file)
+            {
+                continue;
+            }
+            if let Err(err) = self.extract_and_cache_pydantic_models(&module_file) {
+                debug!(
+                    module_file = ?module_file,
+                    error = %err,
+                    "Failed to extract shadowed models"
+                );
+            }
+        }
+    }
+
+    /// Maps imported names to their module paths
+    fn import_map(imports: &[Import]) -> HashMap<String, String> {
+        let mut file_imports_map = HashMap::new();
+        for import in imports {
             if !import.names.is_empty() {
                 // from module import name1, name2
                 for name in &import.names {
@@ -1094,13 +2021,7 @@ impl CallGraphBuilder {
                 file_imports_map.insert(import.path.clone(), import.path.clone());
             }
         }
-
-        // Store the import map after processing all imports
-        if !file_imports_map.is_empty() {
-            self.file_imports.insert(normalized_file, file_imports_map);
-        }
-
-        Ok(())
+        file_imports_map
     }
 
     fn extract_functions_and_classes(
@@ -1198,7 +2119,7 @@ impl CallGraphBuilder {
         let return_type = func_def
             .returns
             .as_ref()
-            .map(|ret_ann| self.resolve_type_annotation(ret_ann, file_path, line));
+            .map(|ret_ann| self.resolve_return_annotation(ret_ann, file_path, line));
 
         let node_id = NodeId::from(self.graph.add_node(CallNode::Function {
             name: func_def.name.to_string(),
@@ -1210,6 +2131,16 @@ impl CallGraphBuilder {
 
         let key = Self::function_key(file_path, &func_def.name);
         self.function_nodes.insert(key, node_id);
+        if func_def
+            .returns
+            .as_deref()
+            .is_none_or(|returns| self.parser.is_response_class_annotation(returns))
+        {
+            self.record_returned_models(node_id, &func_def.body);
+        }
+        self.record_required_headers(node_id, &func_def.args);
+        self.record_query_parameters(node_id, &func_def.args);
+        self.record_request_media_type(node_id, &func_def.args);
 
         Ok(node_id)
     }
@@ -1230,7 +2161,7 @@ impl CallGraphBuilder {
         let return_type = func_def
             .returns
             .as_ref()
-            .map(|ret_ann| self.resolve_type_annotation(ret_ann, file_path, line));
+            .map(|ret_ann| self.resolve_return_annotation(ret_ann, file_path, line));
 
         let node_id = NodeId::from(self.graph.add_node(CallNode::Function {
             name: func_def.name.to_string(),
@@ -1242,6 +2173,16 @@ impl CallGraphBuilder {
 
         let key = Self::function_key(file_path, &func_def.name);
         self.function_nodes.insert(key, node_id);
+        if func_def
+            .returns
+            .as_deref()
+            .is_none_or(|returns| self.parser.is_response_class_annotation(returns))
+        {
+            self.record_returned_models(node_id, &func_def.body);
+        }
+        self.record_required_headers(node_id, &func_def.args);
+        self.record_query_parameters(node_id, &func_def.args);
+        self.record_request_media_type(node_id, &func_def.args);
 
         Ok(node_id)
     }
@@ -1270,7 +2211,7 @@ impl CallGraphBuilder {
         let return_type = func_def
             .returns
             .as_ref()
-            .map(|ret_ann| self.resolve_type_annotation(ret_ann, file_path, line));
+            .map(|ret_ann| self.resolve_return_annotation(ret_ann, file_path, line));
 
         let node_id = NodeId::from(self.graph.add_node(CallNode::Method {
             name: func_def.name.to_string(),
@@ -1281,6 +2222,16 @@ impl CallGraphBuilder {
 
         let key = Self::function_key(file_path, &format!("{}.{}", class_name, func_def.name));
         self.function_nodes.insert(key, node_id);
+        if func_def
+            .returns
+            .as_deref()
+            .is_none_or(|returns| self.parser.is_response_class_annotation(returns))
+        {
+            self.record_returned_models(node_id, &func_def.body);
+        }
+        self.record_required_headers(node_id, &func_def.args);
+        self.record_query_parameters(node_id, &func_def.args);
+        self.record_request_media_type(node_id, &func_def.args);
 
         Ok(node_id)
     }
@@ -1308,7 +2259,7 @@ impl CallGraphBuilder {
         let return_type = func_def
             .returns
             .as_ref()
-            .map(|ret_ann| self.resolve_type_annotation(ret_ann, file_path, line));
+            .map(|ret_ann| self.resolve_return_annotation(ret_ann, file_path, line));
 
         let node_id = NodeId::from(self.graph.add_node(CallNode::Method {
             name: func_def.name.to_string(),
@@ -1319,10 +2270,80 @@ impl CallGraphBuilder {
 
         let key = Self::function_key(file_path, &format!("{}.{}", class_name, func_def.name));
         self.function_nodes.insert(key, node_id);
+        if func_def
+            .returns
+            .as_deref()
+            .is_none_or(|returns| self.parser.is_response_class_annotation(returns))
+        {
+            self.record_returned_models(node_id, &func_def.body);
+        }
+        self.record_required_headers(node_id, &func_def.args);
+        self.record_query_parameters(node_id, &func_def.args);
+        self.record_request_media_type(node_id, &func_def.args);
 
         Ok(node_id)
     }
 
+    /// Remembers the classes an unannotated function returns
+    fn record_returned_models(&mut self, node_id: NodeId, body: &[ast::Stmt]) {
+        let names = self.parser.returned_model_names(body);
+        if !names.is_empty() {
+            self.returned_models.insert(node_id, names);
+        }
+    }
+
+    /// Remembers the headers a function requires
+    fn record_required_headers(&mut self, node_id: NodeId, args: &ast::Arguments) {
+        let headers = self.parser.required_header_names(args);
+        if !headers.is_empty() {
+            self.required_headers.insert(node_id, headers);
+        }
+    }
+
+    /// Remembers the `int`/`float`/`bool` query parameters a function reads
+    fn record_query_parameters(&mut self, node_id: NodeId, args: &ast::Arguments) {
+        let parameters = self.parser.typed_query_parameters(args);
+        if !parameters.is_empty() {
+            self.query_parameters.insert(node_id, parameters);
+        }
+    }
+
+    /// Remembers the media type a function reads form parameters in
+    fn record_request_media_type(&mut self, node_id: NodeId, args: &ast::Arguments) {
+        if let Some(media_type) = self.parser.request_media_type(args) {
+            self.request_media_types.insert(node_id, media_type);
+        }
+    }
+
+    /// Pydantic models returned by an unannotated handler, in source order
+    fn inferred_response_schemas(
+        &mut self,
+        handler_node: NodeId,
+        current_file: &Path,
+    ) -> Vec<SchemaReference> {
+        let names = self
+            .returned_models
+            .get(&handler_node)
+            .cloned()
+            .unwrap_or_default();
+        let mut schemas = Vec::new();
+        for name in names {
+            if !self.pydantic_models.contains_key(&name) {
+                if let Err(err) = self.resolve_schema_from_imports(&name, current_file) {
+                    debug!(
+                        schema_name = %name,
+                        error = %err,
+                        "Failed to resolve returned model from imports"
+                    );
+                }
+            }
+            if let Some(model) = self.pydantic_models.get(&name) {
+                schemas.push(model.clone());
+            }
+        }
+        schemas
+    }
+
     fn add_class_node(
         &mut self,
         class_def: &ast::StmtClassDef,
@@ -1337,71 +2358,66 @@ impl CallGraphBuilder {
         //   2) рекурсивное наследование от уже известной Pydantic‑модели (например, ItemCreate(ItemBase))
         //   3) рекурсивная проверка базовых классов в текущем файле
 
-        // Read AST to check base classes in current file
-        let file_ast = if let Ok(source) = std::fs::read_to_string(file_path) {
-            rustpython_parser::parse(
-                &source,
-                rustpython_parser::Mode::Module,
-                file_path.to_string_lossy().as_ref(),
-            )
-            .ok()
-        } else {
-            None
-        };
+        // AST of the current file, to check base classes declared in it
+        let file_module = self.parsed_module(file_path);
+        let file_ast = file_module.as_ref().map(|module| &module.ast);
 
-        let is_pydantic = self.is_pydantic_model_recursive(class_def, file_ast.as_ref(), file_path);
+        let is_pydantic = self.is_pydantic_model_recursive(class_def, file_ast, file_path);
 
         if is_pydantic {
             let range = class_def.range();
             let (line, column) = converter.byte_offset_to_location(range.start().into());
 
             // Extract Pydantic models from file to get full metadata
-            // Read the file to extract models properly
-            if let Ok(source) = std::fs::read_to_string(file_path) {
-                if let Ok(ast) = rustpython_parser::parse(
-                    &source,
-                    rustpython_parser::Mode::Module,
-                    file_path.to_string_lossy().as_ref(),
-                ) {
-                    let models = self.parser.extract_pydantic_models(
-                        &ast,
-                        &file_path.to_string_lossy(),
-                        converter,
-                    );
+            if let Some(ast) = file_ast {
+                let models = self.parser.extract_pydantic_models(
+                    ast,
+                    &file_path.to_string_lossy(),
+                    converter,
+                );
 
-                    if let Some(model) = models.iter().find(|m| m.name == class_name) {
-                        let mut model = model.clone();
-
-                        // Enrich with JSON schema if extractor is available
-                        if let Some(ref extractor) = self.schema_extractor {
-                            if let Err(err) = extractor.enrich_schema(&mut model) {
-                                debug!(
-                                    class_name = %class_name,
-                                    error = %err,
-                                    "Failed to enrich schema"
-                                );
-                            }
-                        }
+                if let Some(model) = models.iter().find(|m| m.name == class_name) {
+                    let mut model = model.clone();
 
-                        self.pydantic_models.insert(class_name.clone(), model);
-                    } else {
-                        // Fallback: create basic schema reference
-                        let schema_ref = SchemaReference {
-                            name: class_name.clone(),
-                            schema_type: SchemaType::Pydantic,
-                            location: Location {
-                                file: file_path.to_string_lossy().to_string(),
-                                line,
-                                column: Some(column),
-                            },
-                            metadata: HashMap::new(),
-                        };
-                        self.pydantic_models.insert(class_name.clone(), schema_ref);
+                    // Enrich with JSON schema if extractor is available
+                    if let Some(ref extractor) = self.schema_extractor {
+                        if let Err(err) = extractor.enrich_schema(&mut model) {
+                            debug!(
+                                class_name = %class_name,
+                                error = %err,
+                                "Failed to enrich schema"
+                            );
+                            let warning = Self::schema_enrichment_warning(&model, &err);
+                            self.record_analysis_warning(warning);
+                        }
                     }
+
+                    self.cache_pydantic_model(model);
+                } else {
+                    // Fallback: create basic schema reference
+                    let schema_ref = SchemaReference {
+                        name: class_name.clone(),
+                        schema_type: SchemaType::Pydantic,
+                        location: Location {
+                            file: file_path.to_string_lossy().to_string(),
+                            line,
+                            column: Some(column),
+                        },
+                        metadata: HashMap::new(),
+                    };
+                    self.cache_pydantic_model(schema_ref);
                 }
             }
         }
 
+        // Enum classes type query/path parameters and fields with their member values
+        if self.parser.is_enum_class(class_def) {
+            let schema =
+                self.parser
+                    .enum_schema(class_def, &file_path.to_string_lossy(), converter);
+            self.enum_models.insert(class_name.clone(), schema);
+        }
+
         // Check if this is an ORM model and cache it
         if let Ok(Some(orm_model)) =
             self.extract_and_cache_orm_model(class_def, file_path, converter)
@@ -1426,7 +2442,7 @@ impl CallGraphBuilder {
         Ok(node_id)
     }
 
-    /// Extracts and caches all Pydantic models from a file
+    /// Extracts and caches all Pydantic models (and enum classes) from a file
     /// This should be called before resolving type annotations to ensure models are available
     fn extract_and_cache_pydantic_models(&mut self, file_path: &Path) -> Result<()> {
         // 1. Check if file was already processed
@@ -1440,15 +2456,17 @@ impl CallGraphBuilder {
             return Ok(());
         }
 
-        // 3. Read and parse file
-        let source = fs::read_to_string(file_path)?;
-        let ast = parse(&source, Mode::Module, file_path.to_string_lossy().as_ref())?;
-        let converter = LocationConverter::new(source);
+        // 3. Read and parse file (once per build)
+        let Some(module) = self.parsed_module(file_path) else {
+            anyhow::bail!("Failed to read or parse {:?}", file_path);
+        };
 
         // 4. Extract all Pydantic models
-        let models =
-            self.parser
-                .extract_pydantic_models(&ast, &file_path.to_string_lossy(), &converter);
+        let models = self.parser.extract_pydantic_models(
+            &module.ast,
+            &file_path.to_string_lossy(),
+            &module.converter,
+        );
 
         // 5. Add all models to cache (with JSON schema enrichment)
         for mut model in models {
@@ -1460,14 +2478,149 @@ impl CallGraphBuilder {
                         error = %err,
                         "Failed to enrich schema"
                     );
+                    let warning = Self::schema_enrichment_warning(&model, &err);
+                    self.record_analysis_warning(warning);
                 }
             }
-            self.pydantic_models.insert(model.name.clone(), model);
+            self.cache_pydantic_model(model);
+        }
+
+        for schema in
+            self.parser
+                .extract_enums(&module.ast, &file_path.to_string_lossy(), &module.converter)
+        {
+            self.enum_models.insert(schema.name.clone(), schema);
         }
 
         Ok(())
     }
 
+    /// Caches a Pydantic model, keeping the models of the same name other files declare
+    fn cache_pydantic_model(&mut self, model: SchemaReference) {
+        let file = Self::normalize_path(Path::new(&model.location.file));
+        let variants = self
+            .pydantic_model_variants
+            .entry(model.name.clone())
+            .or_default();
+        variants.retain(|known| Self::normalize_path(Path::new(&known.location.file)) != file);
+        variants.push(model.clone());
+        if variants.len() > 1 {
+            debug!(
+                model_name = %model.name,
+                files = ?variants.iter().map(|v| &v.location.file).collect::<Vec<_>>(),
+                "Pydantic model name declared in several files"
+            );
+        }
+        self.pydantic_models.insert(model.name.clone(), model);
+    }
+
+    /// Cached Pydantic model `name` as referenced from `file`
+    ///
+    /// When several files declare models of that name with different fields, the one
+    /// declared in `file` wins, then the one `file` imports the name from. Otherwise the
+    /// reference is ambiguous: the last cached model is returned and the reference is
+    /// reported once the build ends.
+    /// Response names of the fields of the generic model a `response_model` wraps its model
+    /// in (`Page[Item]` gives the fields of `Page`); None for containers (`list[Item]`,
+    /// `Optional[Item]`), plain models and wrappers that aren't project models
+    fn response_envelope(&self, response_model: &str, file: &Path) -> Option<Vec<String>> {
+        const CONTAINERS: [&str; 14] = [
+            "list",
+            "List",
+            "Sequence",
+            "set",
+            "Set",
+            "tuple",
+            "Tuple",
+            "dict",
+            "Dict",
+            "Optional",
+            "Union",
+            "Annotated",
+            "Iterable",
+            "Literal",
+        ];
+        let (wrapper, _) = response_model.trim().split_once('[')?;
+        let wrapper = wrapper.rsplit('.').next().unwrap_or(wrapper).trim();
+        if CONTAINERS.contains(&wrapper) {
+            return None;
+        }
+        let fields: Vec<crate::models::PydanticFieldInfo> = serde_json::from_str(
+            self.pydantic_model_in(wrapper, file)?
+                .metadata
+                .get("fields")?,
+        )
+        .ok()?;
+        Some(
+            fields
+                .iter()
+                .map(|field| field.output_name().to_string())
+                .collect(),
+        )
+    }
+
+    fn pydantic_model_in(&self, name: &str, file: &Path) -> Option<&SchemaReference> {
+        let model = self.pydantic_models.get(name)?;
+        let Some(variants) = self
+            .pydantic_model_variants
+            .get(name)
+            .filter(|variants| Self::have_different_fields(variants))
+        else {
+            return Some(model);
+        };
+        let file = Self::normalize_path(file);
+        let declared_in = |declaring_file: &Path| {
+            variants.iter().find(|variant| {
+                Self::normalize_path(Path::new(&variant.location.file)) == declaring_file
+            })
+        };
+        if let Some(variant) = declared_in(&file) {
+            return Some(variant);
+        }
+        let imported_from = self
+            .file_imports
+            .get(&file)
+            .and_then(|imports| imports.get(name))
+            .and_then(|module| self.resolve_import_path(module, &file).ok());
+        if let Some(variant) = imported_from.and_then(|module_file| declared_in(&module_file)) {
+            return Some(variant);
+        }
+        self.ambiguous_model_refs
+            .borrow_mut()
+            .insert((name.to_string(), file.to_string_lossy().to_string()));
+        Some(model)
+    }
+
+    fn have_different_fields(variants: &[SchemaReference]) -> bool {
+        variants
+            .iter()
+            .any(|variant| variant.metadata.get("fields") != variants[0].metadata.get("fields"))
+    }
+
+    /// Turns the ambiguous model references found so far into diagnostics
+    fn report_ambiguous_models(&mut self) {
+        for (name, file) in std::mem::take(self.ambiguous_model_refs.get_mut()) {
+            let mut files: Vec<&str> = self.pydantic_model_variants[&name]
+                .iter()
+                .map(|variant| variant.location.file.as_str())
+                .collect();
+            files.sort_unstable();
+            self.diagnostics.push(Diagnostic::ambiguous_model(
+                Location {
+                    file,
+                    line: 1,
+                    column: None,
+                },
+                format!(
+                    "`{}` is declared with different fields in {}; import it from one of \
+                     them to pick the model",
+                    name,
+                    files.join(", ")
+                ),
+            ));
+        }
+    }
+
     fn process_calls(
         &mut self,
         module_ast: &ast::Mod,
@@ -1479,7 +2632,33 @@ impl CallGraphBuilder {
         let calls = self
             .parser
             .extract_calls(module_ast, &file_path_str, converter);
+        // Route paths may be given as constants (`@app.get(ROUTES.USERS)`, `Route(USERS, ...)`)
+        self.string_constants.insert(
+            Self::normalize_path(file_path),
+            self.parser.extract_string_constants(module_ast),
+        );
+        let mount_prefixes = self.parser.mount_prefixes(module_ast, converter);
+
         for call in calls {
+            // Routes registered by calls: `Route(...)`, `app.add_route(...)`
+            if let Some(mut registration) = self.parser.route_registration(&call) {
+                let path = self.resolve_route_path(&registration.arguments[0], file_path);
+                let prefix = call
+                    .location
+                    .column
+                    .and_then(|column| mount_prefixes.get(&(call.location.line, column)));
+                registration.arguments[0] =
+                    format!("{}{}", prefix.map(String::as_str).unwrap_or_default(), path);
+                if let Err(err) = self.process_route(&registration, file_path) {
+                    debug!(
+                        call_name = %call.name,
+                        file_path = ?file_path,
+                        error = %err,
+                        "Failed to process route registration"
+                    );
+                }
+            }
+
             let caller_node = match &call.caller {
                 Some(caller_name) => self.find_function_node(caller_name, file_path),
                 None => Some(module_node),
@@ -1525,16 +2704,128 @@ impl CallGraphBuilder {
                 "Extracted decorator"
             );
 
-            if let Err(err) = self.process_decorator(&decorator, file_path) {
-                debug!(
-                    decorator_name = %decorator.name,
-                    file_path = ?file_path,
-                    error = %err,
-                    "Failed to process decorator"
-                );
+            if let Err(err) = self.process_decorator(&decorator, file_path) {
+                debug!(
+                    decorator_name = %decorator.name,
+                    file_path = ?file_path,
+                    error = %err,
+                    "Failed to process decorator"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Route path of a decorator argument naming a constant (`ROUTES.USERS`) or an f-string
+    /// over constants (`{API_PREFIX}/users/{user_id}`)
+    ///
+    /// Constants are looked up in the current file, then in the module the name is imported
+    /// from. Names that aren't constants are kept as written (path parameters stay `{name}`).
+    fn resolve_route_path(&mut self, argument: &str, current_file: &Path) -> String {
+        if is_dotted_identifier(argument) {
+            return self
+                .string_constant(argument, current_file)
+                .unwrap_or_else(|| argument.to_string());
+        }
+
+        let mut path = String::new();
+        let mut rest = argument;
+        while let Some(start) = rest.find('{') {
+            let Some(end) = rest[start..].find('}').map(|offset| start + offset) else {
+                break;
+            };
+            path.push_str(&rest[..start]);
+            let name = &rest[start + 1..end];
+            let value = is_dotted_identifier(name)
+                .then(|| self.string_constant(name, current_file))
+                .flatten();
+            path.push_str(value.as_deref().unwrap_or(&rest[start..=end]));
+            rest = &rest[end + 1..];
+        }
+        path.push_str(rest);
+        path
+    }
+
+    /// Value of a string constant declared in a file or imported into it
+    /// (`from app.routes import ROUTES` makes `ROUTES.USERS` visible)
+    fn string_constant(&mut self, name: &str, current_file: &Path) -> Option<String> {
+        let normalized_file = Self::normalize_path(current_file);
+        if let Some(value) = self
+            .string_constants
+            .get(&normalized_file)
+            .and_then(|constants| constants.get(name))
+        {
+            return Some(value.clone());
+        }
+
+        let imported_name = name.split('.').next().unwrap_or(name);
+        let module_path = self
+            .file_imports
+            .get(&normalized_file)?
+            .get(imported_name)?
+            .clone();
+        let module_file =
+            Self::normalize_path(&self.resolve_import_path(&module_path, current_file).ok()?);
+        if !self.string_constants.contains_key(&module_file) {
+            let module = self.parsed_module(&module_file)?;
+            let constants = self.parser.extract_string_constants(&module.ast);
+            self.string_constants.insert(module_file.clone(), constants);
+        }
+        self.string_constants.get(&module_file)?.get(name).cloned()
+    }
+
+    /// Records the type aliases of a file: its own, then the ones it imports
+    /// (`from app.types import UserId`)
+    fn collect_type_aliases(&mut self, module_ast: &ast::Mod, file_path: &Path) {
+        let mut aliases = self.parser.extract_type_aliases(module_ast);
+        let imported: Vec<(String, String)> = self
+            .file_imports
+            .get(&Self::normalize_path(file_path))
+            .map(|imports| {
+                imports
+                    .iter()
+                    .filter(|(name, _)| !aliases.contains_key(*name))
+                    .map(|(name, module)| (name.clone(), module.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        for (name, module_path) in imported {
+            let Ok(module_file) = self.resolve_import_path(&module_path, file_path) else {
+                continue;
+            };
+            let module_file = Self::normalize_path(&module_file);
+            if module_file == Self::normalize_path(file_path) {
+                continue;
+            }
+            let Some(module) = self.parsed_module(&module_file) else {
+                continue;
+            };
+            if let Some(underlying) = self.parser.extract_type_aliases(&module.ast).remove(&name) {
+                aliases.insert(name, underlying);
             }
         }
-        Ok(())
+        if !aliases.is_empty() {
+            self.type_aliases
+                .insert(Self::normalize_path(file_path), aliases);
+        }
+    }
+
+    /// Underlying type of an annotation naming a type alias, following aliases of aliases
+    fn resolve_type_alias(&self, annotation: &ast::Expr, file_path: &Path) -> Option<ast::Expr> {
+        // Bounded, so cyclic aliases in broken code can't loop forever
+        const MAX_ALIAS_DEPTH: usize = 8;
+        let aliases = self.type_aliases.get(&Self::normalize_path(file_path))?;
+        let mut resolved: Option<&ast::Expr> = None;
+        for _ in 0..MAX_ALIAS_DEPTH {
+            let ast::Expr::Name(name) = resolved.unwrap_or(annotation) else {
+                break;
+            };
+            match aliases.get(name.id.as_str()) {
+                Some(underlying) => resolved = Some(underlying),
+                None => break,
+            }
+        }
+        resolved.cloned()
     }
 
     fn convert_parameters(
@@ -1581,7 +2872,11 @@ impl CallGraphBuilder {
         file_path: &Path,
         line: usize,
     ) -> Parameter {
-        let optional = arg.default.is_some();
+        // `Query(...)` without a default and `Path(...)` still mark the parameter as required
+        let optional = arg
+            .default
+            .as_deref()
+            .is_some_and(|expr| !self.is_required_param_marker(expr));
         let default_value = arg.default.as_deref().map(|expr| {
             // Extract text representation of the default expression
             match expr {
@@ -1598,7 +2893,7 @@ impl CallGraphBuilder {
         });
 
         // Extract type annotation if present
-        let type_info = if let Some(annotation) = &arg.def.annotation {
+        let mut type_info = if let Some(annotation) = &arg.def.annotation {
             self.resolve_type_annotation(annotation, file_path, line)
         } else {
             TypeInfo {
@@ -1609,6 +2904,13 @@ impl CallGraphBuilder {
             }
         };
 
+        // Validation declared through the default value: `q: str = Query(min_length=3)`
+        if let Some(default) = arg.default.as_deref() {
+            type_info
+                .constraints
+                .extend(self.extract_param_constraints(default));
+        }
+
         Parameter {
             name: arg.def.arg.to_string(),
             type_info,
@@ -1714,6 +3016,118 @@ impl CallGraphBuilder {
         None
     }
 
+    /// Extracts validation constraints from FastAPI `Query(...)` / `Path(...)` / `Body(...)`
+    /// and Pydantic `Field(...)` calls
+    ///
+    /// Supports `min_length`/`max_length`, `ge`/`gt`/`le`/`lt` and `regex`/`pattern`;
+    /// values other than literals are skipped.
+    fn extract_param_constraints(&self, expr: &ast::Expr) -> Vec<crate::models::Constraint> {
+        use crate::models::{Constraint, ConstraintValue};
+
+        let mut constraints = Vec::new();
+
+        let ast::Expr::Call(call) = expr else {
+            return constraints;
+        };
+        if !matches!(
+            self.extract_annotation_type_name(expr).as_deref(),
+            Some("Query") | Some("Path") | Some("Body") | Some("Field")
+        ) {
+            return constraints;
+        }
+
+        for kw in &call.keywords {
+            let Some(arg_name) = &kw.arg else {
+                continue;
+            };
+            match arg_name.as_str() {
+                "min_length" | "max_length" => {
+                    if let Some(ConstraintValue::Integer(value)) = Self::constraint_value(&kw.value)
+                    {
+                        let value = ConstraintValue::Integer(value);
+                        constraints.push(if arg_name.as_str() == "min_length" {
+                            Constraint::Min(value)
+                        } else {
+                            Constraint::Max(value)
+                        });
+                    }
+                }
+                "ge" | "gt" | "le" | "lt" => {
+                    if let Some(value) = Self::constraint_value(&kw.value) {
+                        constraints.push(match arg_name.as_str() {
+                            "ge" => Constraint::Min(value),
+                            "gt" => Constraint::ExclusiveMin(value),
+                            "le" => Constraint::Max(value),
+                            _ => Constraint::ExclusiveMax(value),
+                        });
+                    }
+                }
+                "regex" | "pattern" => {
+                    if let ast::Expr::Constant(constant) = &kw.value {
+                        if let ast::Constant::Str(pattern) = &constant.value {
+                            constraints.push(Constraint::Pattern(pattern.clone()));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        constraints
+    }
+
+    /// Converts a numeric literal (optionally negated) into a ConstraintValue
+    fn constraint_value(expr: &ast::Expr) -> Option<crate::models::ConstraintValue> {
+        use crate::models::ConstraintValue;
+
+        match expr {
+            ast::Expr::Constant(constant) => match &constant.value {
+                ast::Constant::Int(i) => i.to_string().parse().ok().map(ConstraintValue::Integer),
+                ast::Constant::Float(f) => Some(ConstraintValue::Float(*f)),
+                _ => None,
+            },
+            ast::Expr::UnaryOp(unary) if matches!(unary.op, ast::UnaryOp::USub) => {
+                match Self::constraint_value(&unary.operand)? {
+                    ConstraintValue::Integer(i) => Some(ConstraintValue::Integer(-i)),
+                    ConstraintValue::Float(f) => Some(ConstraintValue::Float(-f)),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks if a default is a `Query(...)` / `Path(...)` call leaving the parameter required
+    ///
+    /// Path parameters are always required. A `Query` is required unless it is given a
+    /// default: `Query(min_length=3)`, `Query(...)` and `Query(default=...)` are required,
+    /// `Query(1)`, `Query(default=1)` and `Query(default_factory=list)` are not.
+    fn is_required_param_marker(&self, expr: &ast::Expr) -> bool {
+        let ast::Expr::Call(call) = expr else {
+            return false;
+        };
+        match self.extract_annotation_type_name(expr).as_deref() {
+            Some("Path") => true,
+            Some("Query") => {
+                let mut default = call.args.first();
+                for kw in &call.keywords {
+                    match kw.arg.as_ref().map(|arg| arg.as_str()) {
+                        Some("default") => default = Some(&kw.value),
+                        Some("default_factory") => return false,
+                        _ => {}
+                    }
+                }
+                match default {
+                    None => true,
+                    Some(ast::Expr::Constant(constant)) => {
+                        matches!(constant.value, ast::Constant::Ellipsis)
+                    }
+                    Some(_) => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
     /// Extracts inner type from Annotated[T, Body()] string representation
     /// Annotated[ItemCreate, Body()] -> ItemCreate
     /// Handles nested types like Annotated[List[str], Body()] correctly
@@ -1816,6 +3230,34 @@ impl CallGraphBuilder {
         false
     }
 
+    /// Resolves a handler's return annotation to TypeInfo
+    ///
+    /// Like `resolve_type_annotation` (`Annotated[User, ...]` is unwrapped to `User`), but a
+    /// list of models (`list[User]`, `Annotated[list[User], ...]`) keeps its array base type
+    /// and takes the element model as schema, the same way `response_model=list[User]` does.
+    fn resolve_return_annotation(
+        &self,
+        annotation: &ast::Expr,
+        file_path: &Path,
+        line: usize,
+    ) -> TypeInfo {
+        let mut type_info = self.resolve_type_annotation(annotation, file_path, line);
+        if type_info.schema_ref.is_none() && type_info.base_type == BaseType::Array {
+            let mut annotation = annotation;
+            let mut unwrapped;
+            while let Some((inner, _)) = self.extract_annotated_type(annotation) {
+                unwrapped = inner;
+                annotation = unwrapped.as_ref();
+            }
+            let model_name = self
+                .parser
+                .extract_base_model_from_response_model(&self.parser.expr_to_string(annotation));
+            let model_name = model_name.rsplit('.').next().unwrap_or(&model_name).trim();
+            type_info.schema_ref = self.pydantic_model_in(model_name, file_path).cloned();
+        }
+        type_info
+    }
+
     /// Resolves a type annotation to TypeInfo, checking if it's a Pydantic model
     fn resolve_type_annotation(
         &self,
@@ -1823,10 +3265,32 @@ impl CallGraphBuilder {
         file_path: &Path,
         line: usize,
     ) -> TypeInfo {
+        // `UserId = NewType("UserId", int)` is resolved as `int`
+        let underlying = self.resolve_type_alias(annotation, file_path);
+        let annotation = underlying.as_ref().unwrap_or(annotation);
+
         // First, check if it's Annotated[T, ...]
         if let Some((inner_type_expr, _annotation_type)) = self.extract_annotated_type(annotation) {
             // Recursively resolve the inner type
-            return self.resolve_type_annotation(inner_type_expr.as_ref(), file_path, line);
+            let mut type_info =
+                self.resolve_type_annotation(inner_type_expr.as_ref(), file_path, line);
+            // Annotated[str, Query(max_length=50)] carries constraints in its metadata
+            if let ast::Expr::Subscript(sub) = annotation {
+                if let ast::Expr::Tuple(tuple) = sub.slice.as_ref() {
+                    for metadata in tuple.elts.iter().skip(1) {
+                        type_info
+                            .constraints
+                            .extend(self.extract_param_constraints(metadata));
+                    }
+                }
+            }
+            return type_info;
+        }
+
+        // PEP 604 unions: `T | None` is `Optional[T]`
+        if matches!(annotation, ast::Expr::BinOp(bin_op) if matches!(bin_op.op, ast::Operator::BitOr))
+        {
+            return self.resolve_union_annotation(annotation, file_path, line);
         }
 
         // Continue with existing logic for non-Annotated types
@@ -1914,7 +3378,7 @@ impl CallGraphBuilder {
                 },
                 metadata,
             })
-        } else if let Some(schema) = self.pydantic_models.get(actual_type_name) {
+        } else if let Some(schema) = self.pydantic_model_in(actual_type_name, file_path) {
             Some(schema.clone())
         } else {
             // Try to resolve the type through various strategies:
@@ -1930,7 +3394,7 @@ impl CallGraphBuilder {
             };
 
             // First try simple name
-            if let Some(schema) = self.pydantic_models.get(simple_name) {
+            if let Some(schema) = self.pydantic_model_in(simple_name, file_path) {
                 Some(schema.clone())
             } else {
                 // Try to find by exact match in cache (case-insensitive)
@@ -1946,6 +3410,32 @@ impl CallGraphBuilder {
             }
         };
 
+        // Enum classes restrict a string or number to their member values
+        let enum_schema = if schema_ref.is_none() {
+            let simple_name = actual_type_name
+                .rsplit('.')
+                .next()
+                .unwrap_or(actual_type_name);
+            self.enum_models.get(simple_name).cloned()
+        } else {
+            None
+        };
+        if let Some(enum_schema) = enum_schema {
+            let enum_type = crate::analyzers::SchemaParser::parse(&enum_schema).ok();
+            let base_type = match enum_type.as_ref().map(|schema| schema.schema_type.as_str()) {
+                Some("number") => BaseType::Number,
+                _ => BaseType::String,
+            };
+            return TypeInfo {
+                base_type,
+                schema_ref: Some(enum_schema),
+                constraints: enum_type
+                    .map(|schema| schema.constraints)
+                    .unwrap_or_default(),
+                optional: is_optional,
+            };
+        }
+
         // Determine base type
         let base_type = if schema_ref.is_some() {
             BaseType::Object
@@ -1969,6 +3459,72 @@ impl CallGraphBuilder {
         }
     }
 
+    /// Resolves a `X | Y` union annotation
+    ///
+    /// `None` members make the type optional. Of several other members the first one with a
+    /// schema is picked, and all of them are listed in its `union_alternatives` metadata.
+    fn resolve_union_annotation(
+        &self,
+        annotation: &ast::Expr,
+        file_path: &Path,
+        line: usize,
+    ) -> TypeInfo {
+        let operands = self.parser.union_operands(annotation);
+        let members: Vec<&ast::Expr> = operands
+            .iter()
+            .copied()
+            .filter(|member| self.parser.expr_to_string(member) != "None")
+            .collect();
+        let has_none = members.len() < operands.len();
+
+        let resolved: Vec<TypeInfo> = members
+            .iter()
+            .map(|member| self.resolve_type_annotation(member, file_path, line))
+            .collect();
+        let has_schema = |type_info: &&TypeInfo, documented: bool| {
+            type_info.schema_ref.as_ref().is_some_and(|schema| {
+                !documented || !schema.metadata.contains_key("missing_schema")
+            })
+        };
+        let picked = resolved
+            .iter()
+            .find(|type_info| has_schema(type_info, true))
+            .or_else(|| {
+                resolved
+                    .iter()
+                    .find(|type_info| has_schema(type_info, false))
+            })
+            .or(resolved.first());
+        let mut type_info = picked.cloned().unwrap_or(TypeInfo {
+            base_type: BaseType::Unknown,
+            schema_ref: None,
+            constraints: Vec::new(),
+            optional: false,
+        });
+
+        if members.len() > 1 {
+            let alternatives: Vec<String> = members
+                .iter()
+                .zip(&resolved)
+                .map(|(member, member_type)| match &member_type.schema_ref {
+                    Some(schema) if !schema.metadata.contains_key("missing_schema") => {
+                        schema.name.clone()
+                    }
+                    _ => self.parser.expr_to_string(member),
+                })
+                .collect();
+            if let Some(schema) = type_info.schema_ref.as_mut() {
+                schema.metadata.insert(
+                    UNION_ALTERNATIVES_METADATA_KEY.to_string(),
+                    alternatives.join(","),
+                );
+            }
+        }
+
+        type_info.optional |= has_none;
+        type_info
+    }
+
     fn get_or_create_module_node(&mut self, path: &Path) -> Result<NodeId> {
         let normalized = Self::normalize_path(path);
         if let Some(node) = self.module_nodes.get(&normalized) {
@@ -2083,7 +3639,7 @@ impl CallGraphBuilder {
 
         // 3. Fallback: select first deterministically (sorted by key)
         let mut sorted_matches = matches.clone();
-        sorted_matches.sort_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b));
+        sorted_matches.sort_by_key(|(key, _)| *key);
         if let Some((key, node)) = sorted_matches.first() {
             debug!(
                 function_name = %name,
@@ -2159,6 +3715,57 @@ impl CallGraphBuilder {
         }
     }
 
+    /// Whether an import that resolves neither locally nor to a dependency names a project
+    /// module: a relative import, or a submodule of a package found in the project
+    ///
+    /// Other imports (`os`, `fastapi` without a requirements file) are the standard library
+    /// or installed packages, which aren't followed anyway.
+    fn is_missing_project_module(&self, import_path: &str, current_file: &Path) -> bool {
+        if self.resolve_import_path(import_path, current_file).is_ok() {
+            return false;
+        }
+        let module = import_path.trim_start_matches('.');
+        if module.len() != import_path.len() {
+            return !module.is_empty();
+        }
+        match module.split_once('.') {
+            Some((package, _)) => self.resolve_import_path(package, current_file).is_ok(),
+            None => false,
+        }
+    }
+
+    /// Records an analysis warning once
+    fn record_analysis_warning(&mut self, warning: AnalysisWarning) {
+        if !self.analysis_warnings.contains(&warning) {
+            self.analysis_warnings.push(warning);
+        }
+    }
+
+    /// Records a module whose graph could not be built (e.g. past the maximum depth)
+    fn record_skipped_module(&mut self, module: &Path, err: &anyhow::Error) {
+        self.record_analysis_warning(AnalysisWarning::new(
+            AnalysisWarningKind::ModuleSkipped,
+            Location {
+                file: module.to_string_lossy().to_string(),
+                line: 1,
+                column: None,
+            },
+            format!("Module was not analyzed: {}", err),
+        ));
+    }
+
+    /// Warning for a model whose JSON schema could not be extracted
+    fn schema_enrichment_warning(model: &SchemaReference, err: &anyhow::Error) -> AnalysisWarning {
+        AnalysisWarning::new(
+            AnalysisWarningKind::SchemaEnrichment,
+            model.location.clone(),
+            format!(
+                "Failed to extract the JSON schema of '{}': {}",
+                model.name, err
+            ),
+        )
+    }
+
     fn resolve_import_path(&self, import_path: &str, current_file: &Path) -> Result<PathBuf> {
         let normalized_current = Self::normalize_path(current_file);
         let base_dir = normalized_current
@@ -2469,50 +4076,54 @@ impl CallGraphBuilder {
     }
 
     fn is_route_decorator(&self, name: &str) -> bool {
-        // Check for common FastAPI route patterns
-        // 1. Direct app/router access: app.get, router.post, etc.
-        if name.starts_with("app.") || name.starts_with("router.") {
-            return true;
-        }
+        self.parser.is_route_decorator(name)
+    }
 
-        // 2. Contains .route: api_router.route, main_router.route
-        if name.contains(".route") {
-            return true;
+    fn extract_http_method(&self, decorator_name: &str) -> Option<HttpMethod> {
+        if let Some(method) = self.parser.route_pattern_method(decorator_name) {
+            return Some(method);
         }
+        let method_part = decorator_name.split('.').nth(1)?;
+        method_part.parse().ok()
+    }
 
-        // 3. Common router variable names with HTTP methods
-        let router_names = [
-            "api_router",
-            "api",
-            "main_router",
-            "main",
-            "fastapi_router",
-            "fastapi",
-            "app_router",
-            "web_router",
-            "r",
-            "rt",
-            "router_instance",
-        ];
-
-        for router_name in &router_names {
-            if name.starts_with(&format!("{}.", router_name)) {
-                // Check if last component equals an HTTP method
-                let http_methods = ["get", "post", "put", "patch", "delete", "head", "options"];
-                if let Some(last_component) = name.split('.').next_back() {
-                    if http_methods.contains(&last_component) {
-                        return true;
-                    }
+    /// HTTP methods of the routes a decorator declares
+    ///
+    /// Flask's `.route` (FastAPI's `.api_route`, and the `Route(...)`/`add_route(...)`
+    /// registrations) list them in `methods=[...]`, GET when absent; the other decorators
+    /// name their method.
+    fn route_http_methods(&self, decorator: &Decorator) -> Vec<HttpMethod> {
+        let member = decorator.name.rsplit('.').next().unwrap_or_default();
+        if matches!(
+            member,
+            "route" | "api_route" | "Route" | "add_route" | "add_api_route"
+        ) {
+            let methods: Vec<HttpMethod> = decorator
+                .keyword_arguments
+                .get("methods")
+                .map(|methods| {
+                    methods
+                        .trim()
+                        .trim_start_matches(['[', '(', '{'])
+                        .trim_end_matches([']', ')', '}'])
+                        .split(',')
+                        .filter_map(|method| method.trim().parse().ok())
+                        .collect()
+                })
+                .unwrap_or_default();
+            let mut unique = Vec::new();
+            for method in methods {
+                if !unique.contains(&method) {
+                    unique.push(method);
                 }
             }
+            if !unique.is_empty() {
+                return unique;
+            }
         }
-
-        false
-    }
-
-    fn extract_http_method(&self, decorator_name: &str) -> Option<HttpMethod> {
-        let method_part = decorator_name.split('.').nth(1)?;
-        method_part.parse().ok()
+        vec![self
+            .extract_http_method(&decorator.name)
+            .unwrap_or(HttpMethod::Get)]
     }
 
     /// Checks if the specified decorator is in the decorator list
@@ -2570,7 +4181,9 @@ impl CallGraphBuilder {
                     "parse_raw",
                 ];
 
-                if transformation_methods.contains(&method) {
+                if transformation_methods.contains(&method)
+                    || self.is_custom_transformation(&model_name, method)
+                {
                     return Some((method.to_string(), model_name));
                 }
             }
@@ -2610,10 +4223,23 @@ impl CallGraphBuilder {
             "model_dump_json" | "json" => Some(ToJson),
             "model_serialize" => Some(Serialize),
 
+            _ if self.transformation_methods.contains(method) || is_conversion_shaped(method) => {
+                Some(Custom(method.to_string()))
+            }
             _ => None,
         }
     }
 
+    /// Checks whether `receiver.method(...)` is a project-specific transformation:
+    /// a configured method name, or a conversion-shaped name (`to_*`, `from_*`, `as_*`)
+    /// called on a known Pydantic / ORM model
+    fn is_custom_transformation(&self, receiver: &str, method: &str) -> bool {
+        self.transformation_methods.contains(method)
+            || (is_conversion_shaped(method)
+                && (self.pydantic_models.contains_key(receiver)
+                    || self.orm_models.contains_key(receiver)))
+    }
+
     /// Processes a Pydantic transformation call:
     /// - determines source/target schemas
     /// - creates a DataFlow edge with concrete TransformationType
@@ -2642,6 +4268,7 @@ impl CallGraphBuilder {
         let to_schema = self
             .pydantic_models
             .get(&model_name)
+            .or_else(|| self.orm_models.get(&model_name))
             .cloned()
             .unwrap_or_else(|| SchemaReference {
                 name: model_name.clone(),
@@ -2680,6 +4307,13 @@ impl CallGraphBuilder {
             crate::models::TransformationType::ToJson
             | crate::models::TransformationType::Serialize
             | crate::models::TransformationType::PydanticToOrm => to_schema.clone(),
+            // The other side of a project-specific conversion is not known statically
+            crate::models::TransformationType::Custom(_) => SchemaReference {
+                name: "Data".to_string(),
+                schema_type: SchemaType::JsonSchema,
+                location: call.location.clone(),
+                metadata: HashMap::new(),
+            },
         };
 
         let (from_schema, to_schema) = match transformation_type {
@@ -2696,6 +4330,13 @@ impl CallGraphBuilder {
             | crate::models::TransformationType::ToJson
             | crate::models::TransformationType::Serialize
             | crate::models::TransformationType::PydanticToOrm => (to_schema.clone(), from_schema),
+            // `Model.from_*` builds the model, anything else converts the model into data
+            crate::models::TransformationType::Custom(ref method)
+                if method.starts_with("from_") =>
+            {
+                (from_schema, to_schema)
+            }
+            crate::models::TransformationType::Custom(_) => (to_schema.clone(), from_schema),
         };
 
         // Create dedicated DataFlow edge between abstract "data" and concrete Pydantic model
@@ -2880,19 +4521,11 @@ impl CallGraphBuilder {
     ) -> Result<Option<SchemaReference>> {
         let class_name = class_def.name.to_string();
 
-        // Read AST to check if this is a SQLAlchemy model
-        let file_ast = if let Ok(source) = std::fs::read_to_string(file_path) {
-            rustpython_parser::parse(
-                &source,
-                rustpython_parser::Mode::Module,
-                file_path.to_string_lossy().as_ref(),
-            )
-            .ok()
-        } else {
-            None
-        };
+        // Check the file's AST for a SQLAlchemy model
+        let file_module = self.parsed_module(file_path);
 
-        let is_orm = if let Some(ast) = &file_ast {
+        let is_orm = if let Some(module) = &file_module {
+            let ast = &module.ast;
             Self::is_sqlalchemy_model(ast, &class_name)
         } else {
             false
@@ -2936,87 +4569,94 @@ impl CallGraphBuilder {
     }
 
     /// Extracts fields from a SQLAlchemy model
+    ///
+    /// Columns become scalar fields; `relationship()` attributes become fields holding the
+    /// related model, one (`Mapped["Parent"]`) or a list (`Mapped[list["Child"]]`).
     fn extract_sqlalchemy_fields(
         &self,
         class_def: &ast::StmtClassDef,
     ) -> Vec<crate::models::SQLAlchemyField> {
         let mut fields = Vec::new();
+        // Relationships declared without a `Mapped[...]` annotation
+        let mut unannotated = Vec::new();
 
         for body_stmt in &class_def.body {
-            if let ast::Stmt::AnnAssign(ann_assign) = body_stmt {
-                if let ast::Expr::Name(name) = ann_assign.target.as_ref() {
+            match body_stmt {
+                ast::Stmt::AnnAssign(ann_assign) => {
+                    let ast::Expr::Name(name) = ann_assign.target.as_ref() else {
+                        continue;
+                    };
                     let field_name = name.id.to_string();
+                    // Skip special fields like __tablename__
+                    if field_name.starts_with("__") {
+                        continue;
+                    }
+                    if let Some(call) = relationship_call(ann_assign.value.as_deref()) {
+                        match mapped_relationship(&ann_assign.annotation) {
+                            Some((related, many, nullable)) => {
+                                let many = relationship_uselist(call).unwrap_or(many);
+                                fields
+                                    .push(relationship_field(field_name, related, many, nullable));
+                            }
+                            None => unannotated.push((field_name, call)),
+                        }
+                        continue;
+                    }
                     let annotation_str = self.parser.expr_to_string(ann_assign.annotation.as_ref());
 
                     // Check if annotation contains Column or Mapped
                     if annotation_str.contains("Column") || annotation_str.contains("Mapped") {
-                        // Skip special fields like __tablename__
-                        if field_name.starts_with("__") {
-                            continue;
-                        }
                         let (type_name, nullable) = self.extract_column_type(&annotation_str);
                         fields.push(crate::models::SQLAlchemyField {
                             name: field_name,
                             type_name,
                             nullable,
+                            related_model: None,
+                            many: false,
                         });
                     }
                 }
+                ast::Stmt::Assign(assign) => {
+                    let [ast::Expr::Name(name)] = assign.targets.as_slice() else {
+                        continue;
+                    };
+                    if let Some(call) = relationship_call(Some(assign.value.as_ref())) {
+                        unannotated.push((name.id.to_string(), call));
+                    }
+                }
+                _ => {}
             }
         }
 
+        // Without an annotation, the cardinality comes from `uselist=`, or else from a
+        // `<name>_id` foreign key column, which makes the relationship many-to-one
+        for (field_name, call) in unannotated {
+            let Some(related) = call.args.first().and_then(relationship_target) else {
+                continue;
+            };
+            let foreign_key = format!("{}_id", field_name);
+            let many = relationship_uselist(call).unwrap_or_else(|| {
+                !class_def.body.iter().any(|stmt| {
+                    let target = match stmt {
+                        ast::Stmt::AnnAssign(ann_assign) => ann_assign.target.as_ref(),
+                        ast::Stmt::Assign(assign) => match assign.targets.as_slice() {
+                            [target] => target,
+                            _ => return false,
+                        },
+                        _ => return false,
+                    };
+                    matches!(target, ast::Expr::Name(name) if name.id.as_str() == foreign_key)
+                })
+            });
+            fields.push(relationship_field(field_name, related, many, !many));
+        }
+
         fields
     }
 
     /// Checks if SQLAlchemy type is compatible with Pydantic type
     fn types_compatible(&self, sql_type: &str, pydantic_type: &str) -> bool {
-        // Normalize types to lowercase for comparison
-        let sql_lower = sql_type.to_lowercase();
-        let pydantic_lower = pydantic_type.to_lowercase();
-
-        // Direct matches
-        if sql_lower == pydantic_lower {
-            return true;
-        }
-
-        // Type mappings
-        match (sql_lower.as_str(), pydantic_lower.as_str()) {
-            // Integer types
-            ("integer", "int") | ("int", "integer") => true,
-
-            // String types
-            ("string", "str") | ("str", "string") => true,
-            ("text", "str") | ("str", "text") => true,
-
-            // Boolean types
-            ("boolean", "bool") | ("bool", "boolean") => true,
-
-            // Numeric types
-            ("float", "float") => true,
-            ("numeric", "float") | ("float", "numeric") => true,
-            ("decimal", "float") | ("float", "decimal") => true,
-
-            // UUID types
-            (a, b) if a.contains("uuid") && b.contains("uuid") => true,
-
-            // Date/Time types
-            (a, b)
-                if (a.contains("date") || a.contains("time"))
-                    && (b.contains("date") || b.contains("time")) =>
-            {
-                true
-            }
-
-            // JSON types
-            (a, b)
-                if (a.contains("json") || a == "dict")
-                    && (b.contains("json") || b == "dict" || b == "object") =>
-            {
-                true
-            }
-
-            _ => false,
-        }
+        self.type_compatibility.compatible(sql_type, pydantic_type)
     }
 
     /// Matches Pydantic fields with SQLAlchemy fields and returns match percentage
@@ -3037,6 +4677,16 @@ impl CallGraphBuilder {
                 .iter()
                 .find(|sa_field| sa_field.name == pydantic_field.name)
             {
+                // Relationships are serialized by their own models: only the cardinality
+                // has to agree
+                if sql_field.related_model.is_some() {
+                    let pydantic_many = pydantic_field.type_name == "array"
+                        || collection_item(&pydantic_field.type_name).is_some();
+                    if pydantic_many == sql_field.many {
+                        matches += 1;
+                    }
+                    continue;
+                }
                 // Check type compatibility
                 if self.types_compatible(&sql_field.type_name, &pydantic_field.type_name) {
                     // Also check optionality compatibility
@@ -3065,3 +4715,201 @@ impl CallGraphBuilder {
         })
     }
 }
+
+/// `relationship(...)` call assigned to a SQLAlchemy model attribute (`relationship`,
+/// `orm.relationship`, `db.relationship`)
+fn relationship_call(value: Option<&ast::Expr>) -> Option<&ast::ExprCall> {
+    let ast::Expr::Call(call) = value? else {
+        return None;
+    };
+    let is_relationship = match call.func.as_ref() {
+        ast::Expr::Name(name) => name.id.as_str() == "relationship",
+        ast::Expr::Attribute(attr) => attr.attr.as_str() == "relationship",
+        _ => false,
+    };
+    is_relationship.then_some(call)
+}
+
+/// Explicit `uselist=True/False` of a `relationship()` call
+fn relationship_uselist(call: &ast::ExprCall) -> Option<bool> {
+    call.keywords.iter().find_map(|keyword| {
+        if keyword.arg.as_deref() != Some("uselist") {
+            return None;
+        }
+        match &keyword.value {
+            ast::Expr::Constant(constant) => match constant.value {
+                ast::Constant::Bool(uselist) => Some(uselist),
+                _ => None,
+            },
+            _ => None,
+        }
+    })
+}
+
+/// Model name a relationship refers to: `"Child"`, `Child` or `"models.Child"`
+fn relationship_target(expr: &ast::Expr) -> Option<String> {
+    let name = match expr {
+        ast::Expr::Constant(constant) => match &constant.value {
+            ast::Constant::Str(name) => name.trim().to_string(),
+            _ => return None,
+        },
+        ast::Expr::Name(name) => name.id.to_string(),
+        ast::Expr::Attribute(attr) => attr.attr.to_string(),
+        _ => return None,
+    };
+    let name = name.rsplit('.').next().unwrap_or(&name).to_string();
+    is_dotted_identifier(&name).then_some(name)
+}
+
+/// Related model, cardinality and nullability of a relationship annotation:
+/// `Mapped[list["Child"]]` holds many, `Mapped[Optional["Parent"]]` (or
+/// `Mapped["Parent | None"]`) one, possibly missing
+fn mapped_relationship(annotation: &ast::Expr) -> Option<(String, bool, bool)> {
+    let ast::Expr::Subscript(mapped) = annotation else {
+        return None;
+    };
+    let is_mapped = match mapped.value.as_ref() {
+        ast::Expr::Name(name) => name.id.as_str() == "Mapped",
+        ast::Expr::Attribute(attr) => attr.attr.as_str() == "Mapped",
+        _ => false,
+    };
+    if !is_mapped {
+        return None;
+    }
+    // The whole annotation may be a string: `Mapped["list[Child]"]`
+    let inner = match mapped.slice.as_ref() {
+        ast::Expr::Constant(constant) => match &constant.value {
+            ast::Constant::Str(text) => text.clone(),
+            _ => return None,
+        },
+        other => annotation_text(other)?,
+    };
+    let (inner, nullable) = strip_optional(&inner);
+    match collection_item(inner) {
+        Some(item) => Some((relationship_model(item)?, true, nullable)),
+        None => Some((relationship_model(inner)?, false, nullable)),
+    }
+}
+
+/// Annotation as text with string forward references unquoted (`list[Child]` for
+/// `list["Child"]`); None for shapes other than names, subscripts and `X | None` unions
+fn annotation_text(expr: &ast::Expr) -> Option<String> {
+    match expr {
+        ast::Expr::Name(name) => Some(name.id.to_string()),
+        ast::Expr::Attribute(attr) => {
+            Some(format!("{}.{}", annotation_text(&attr.value)?, attr.attr))
+        }
+        ast::Expr::Constant(constant) => match &constant.value {
+            ast::Constant::Str(text) => Some(text.clone()),
+            ast::Constant::None => Some("None".to_string()),
+            _ => None,
+        },
+        ast::Expr::Subscript(sub) => Some(format!(
+            "{}[{}]",
+            annotation_text(&sub.value)?,
+            annotation_text(&sub.slice)?
+        )),
+        ast::Expr::BinOp(bin_op) if matches!(bin_op.op, ast::Operator::BitOr) => Some(format!(
+            "{} | {}",
+            annotation_text(&bin_op.left)?,
+            annotation_text(&bin_op.right)?
+        )),
+        _ => None,
+    }
+}
+
+/// Strips `Optional[...]` and `| None` from an annotation, telling whether it was optional
+fn strip_optional(annotation: &str) -> (&str, bool) {
+    let annotation = annotation.trim();
+    for prefix in ["Optional[", "typing.Optional["] {
+        if let Some(inner) = annotation
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.strip_suffix(']'))
+        {
+            return (inner.trim(), true);
+        }
+    }
+    if let Some(inner) = annotation.strip_suffix("| None") {
+        return (inner.trim(), true);
+    }
+    if let Some(inner) = annotation.strip_prefix("None |") {
+        return (inner.trim(), true);
+    }
+    (annotation, false)
+}
+
+/// Item type of a collection annotation (`list[Child]`, `List[Child]`, `set[Child]`,
+/// `Sequence[Child]`, optionally wrapped in `Optional`); None for other annotations
+fn collection_item(annotation: &str) -> Option<&str> {
+    let (annotation, _) = strip_optional(annotation);
+    let (container, rest) = annotation.split_once('[')?;
+    let container = container.rsplit('.').next().unwrap_or(container);
+    let is_collection = matches!(
+        container,
+        "list" | "List" | "set" | "Set" | "frozenset" | "FrozenSet" | "Sequence"
+    );
+    is_collection
+        .then(|| rest.strip_suffix(']'))
+        .flatten()
+        .map(str::trim)
+}
+
+/// Model name of a relationship annotation item, unquoted and without its module
+fn relationship_model(item: &str) -> Option<String> {
+    let name = item.trim().trim_matches(|c| c == '"' || c == '\'');
+    let name = name.rsplit('.').next().unwrap_or(name);
+    is_dotted_identifier(name).then(|| name.to_string())
+}
+
+/// Field of a relationship to `related`; lists are typed `List[Model]`
+fn relationship_field(
+    name: String,
+    related: String,
+    many: bool,
+    nullable: bool,
+) -> crate::models::SQLAlchemyField {
+    crate::models::SQLAlchemyField {
+        name,
+        type_name: if many {
+            format!("List[{}]", related)
+        } else {
+            related.clone()
+        },
+        nullable,
+        related_model: Some(related),
+        many,
+    }
+}
+
+/// Whether `text` is a (dotted) Python name such as `USERS` or `Routes.USERS`
+fn is_dotted_identifier(text: &str) -> bool {
+    text.split('.').all(|part| {
+        part.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+/// Call deferred by `background_tasks.add_task(send_email, to, subject=...)`: `send_email`
+/// called with the remaining arguments
+fn background_task_call(call: &Call) -> Option<Call> {
+    let (_, method) = call.name.rsplit_once('.')?;
+    if method != "add_task" {
+        return None;
+    }
+    let (task, arguments) = call.arguments.split_first()?;
+    if task.parameter_name.is_some() || !is_dotted_identifier(&task.value) {
+        return None;
+    }
+    Some(Call {
+        name: task.value.clone(),
+        arguments: arguments.to_vec(),
+        ..call.clone()
+    })
+}
+
+/// Conversion-shaped method names (`to_response`, `from_row`, `as_dict`)
+fn is_conversion_shaped(method: &str) -> bool {
+    ["to_", "from_", "as_"]
+        .iter()
+        .any(|prefix| method.len() > prefix.len() && method.starts_with(prefix))
+}
@@ -1,3 +1,4 @@
+use crate::call_graph::HttpMethod;
 use crate::models::Location;
 use std::collections::HashMap;
 
@@ -45,3 +46,50 @@ impl Decorator {
         }
     }
 }
+
+/// Shape of a project-specific route decorator: `@<object>.<member>(path, ...)`
+///
+/// Registered through `CallGraphBuilder::with_route_patterns` in addition to the built-in
+/// FastAPI patterns, for routing DSLs the name heuristics don't know
+/// (`@endpoints.fetch("/users")` declaring a GET route).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoutePattern {
+    /// Variables the decorators are accessed on (`endpoints`, `api.v1`)
+    pub objects: Vec<String>,
+    /// Decorator member -> HTTP method of the route it declares
+    pub methods: HashMap<String, HttpMethod>,
+}
+
+impl RoutePattern {
+    /// Pattern for `objects` with the standard members (`get`, `post`, ... `options`)
+    pub fn new(objects: Vec<String>) -> Self {
+        let methods = [
+            HttpMethod::Get,
+            HttpMethod::Post,
+            HttpMethod::Put,
+            HttpMethod::Patch,
+            HttpMethod::Delete,
+            HttpMethod::Head,
+            HttpMethod::Options,
+        ]
+        .into_iter()
+        .map(|method| (format!("{:?}", method).to_lowercase(), method))
+        .collect();
+        Self { objects, methods }
+    }
+
+    /// Adds (or remaps) a decorator member declaring routes of `method`
+    pub fn with_method(mut self, member: impl Into<String>, method: HttpMethod) -> Self {
+        self.methods.insert(member.into(), method);
+        self
+    }
+
+    /// HTTP method of the route a decorator declares, if the decorator matches the pattern
+    pub fn http_method(&self, decorator_name: &str) -> Option<HttpMethod> {
+        let (object, member) = decorator_name.rsplit_once('.')?;
+        if !self.objects.iter().any(|candidate| candidate == object) {
+            return None;
+        }
+        self.methods.get(member).copied()
+    }
+}
@@ -0,0 +1,211 @@
+use crate::call_graph::{CallEdge, CallGraph, CallNode};
+
+/// Renders the call graph in Graphviz DOT format
+///
+/// Each node kind gets its own shape and fill color; edges are labeled by kind, a
+/// `DataFlow` edge with its schemas and transformation.
+pub fn to_dot(graph: &CallGraph) -> String {
+    let mut dot = String::from("digraph call_graph {\n");
+    dot.push_str("  rankdir=LR;\n");
+    dot.push_str("  node [style=filled];\n\n");
+    for idx in graph.node_indices() {
+        let (shape, color) = node_style(&graph[idx]);
+        dot.push_str(&format!(
+            "  n{} [label=\"{}\", shape={}, fillcolor={}];\n",
+            idx.index(),
+            escape_dot(&node_label(&graph[idx])),
+            shape,
+            color
+        ));
+    }
+    dot.push('\n');
+    for edge in graph.edge_indices() {
+        let Some((source, target)) = graph.edge_endpoints(edge) else {
+            continue;
+        };
+        let weight = &graph[edge];
+        dot.push_str(&format!(
+            "  n{} -> n{} [label=\"{}\"{}];\n",
+            source.index(),
+            target.index(),
+            escape_dot(&edge_label(weight)),
+            edge_style(weight)
+        ));
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+fn node_style(node: &CallNode) -> (&'static str, &'static str) {
+    match node {
+        CallNode::Module { .. } => ("folder", "lightgrey"),
+        CallNode::Class { .. } => ("component", "lightyellow"),
+        CallNode::Function { .. } => ("box", "lightblue"),
+        CallNode::Method { .. } => ("box", "lightcyan"),
+        CallNode::Route { .. } => ("hexagon", "palegreen"),
+        CallNode::Schema { .. } => ("note", "khaki"),
+    }
+}
+
+fn node_label(node: &CallNode) -> String {
+    match node {
+        CallNode::Module { path } => format!(
+            "Module: {}",
+            path.file_name().unwrap_or_default().to_string_lossy()
+        ),
+        CallNode::Function { name, line, .. } => format!("Function: {}\n(line {})", name, line),
+        CallNode::Class { name, .. } => format!("Class: {}", name),
+        CallNode::Method { name, .. } => format!("Method: {}", name),
+        CallNode::Route { path, method, .. } => {
+            format!("Route: {} {}", format!("{:?}", method).to_uppercase(), path)
+        }
+        CallNode::Schema { schema } => {
+            format!("Schema: {} ({:?})", schema.name, schema.schema_type)
+        }
+    }
+}
+
+fn edge_label(edge: &CallEdge) -> String {
+    match edge {
+        CallEdge::Import { import_path, .. } => format!("import: {}", import_path),
+        CallEdge::Call {
+            argument_mapping, ..
+        } => {
+            if argument_mapping.is_empty() {
+                "calls".to_string()
+            } else {
+                format!("calls ({} args)", argument_mapping.len())
+            }
+        }
+        CallEdge::Return { return_value, .. } => format!("returns: {}", return_value),
+        CallEdge::DataFlow {
+            from_schema,
+            to_schema,
+            transformation,
+            ..
+        } => {
+            let kind = transformation
+                .as_ref()
+                .map(|transformation| format!("{:?}", transformation))
+                .unwrap_or_else(|| "DataFlow".to_string());
+            format!("{} → {} [{}]", from_schema.name, to_schema.name, kind)
+        }
+        CallEdge::Relationship { field, kind, .. } => format!("{}: {:?}", field, kind),
+    }
+}
+
+fn edge_style(edge: &CallEdge) -> &'static str {
+    match edge {
+        CallEdge::Import { .. } => ", style=dotted, color=gray40",
+        CallEdge::Call { .. } => "",
+        CallEdge::Return { .. } => ", color=gray40",
+        CallEdge::DataFlow { .. } => ", style=dashed, color=purple",
+        CallEdge::Relationship { .. } => ", style=bold, color=darkorange",
+    }
+}
+
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::call_graph::HttpMethod;
+    use crate::models::{Location, NodeId, SchemaReference, SchemaType, TransformationType};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn schema(name: &str, schema_type: SchemaType) -> SchemaReference {
+        SchemaReference {
+            name: name.to_string(),
+            schema_type,
+            location: Location {
+                file: "app.py".to_string(),
+                line: 1,
+                column: None,
+            },
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn nodes_and_edges_are_rendered_by_kind() {
+        let mut graph = CallGraph::new();
+        let module = NodeId::from(graph.add_node(CallNode::Module {
+            path: PathBuf::from("src/app.py"),
+        }));
+        let handler = NodeId::from(graph.add_node(CallNode::Function {
+            name: "create_item".to_string(),
+            file: PathBuf::from("src/app.py"),
+            line: 7,
+            parameters: Vec::new(),
+            return_type: None,
+        }));
+        let route = NodeId::from(graph.add_node(CallNode::Route {
+            path: "/items".to_string(),
+            method: HttpMethod::Post,
+            handler,
+            location: schema("Item", SchemaType::Pydantic).location,
+            request_schema: None,
+            response_schema: None,
+            metadata: HashMap::new(),
+        }));
+        graph.add_node(CallNode::Schema {
+            schema: schema("Item", SchemaType::Pydantic),
+        });
+        graph.add_edge(
+            *module,
+            *handler,
+            CallEdge::Import {
+                from: module,
+                to: handler,
+                import_path: "app".to_string(),
+                file: PathBuf::from("src/app.py"),
+            },
+        );
+        graph.add_edge(
+            *route,
+            *handler,
+            CallEdge::Call {
+                caller: route,
+                callee: handler,
+                argument_mapping: Vec::new(),
+                location: schema("Item", SchemaType::Pydantic).location,
+            },
+        );
+        graph.add_edge(
+            *handler,
+            *handler,
+            CallEdge::DataFlow {
+                from: handler,
+                to: handler,
+                from_schema: schema("ItemModel", SchemaType::OrmModel),
+                to_schema: Box::new(schema("Item", SchemaType::Pydantic)),
+                location: schema("Item", SchemaType::Pydantic).location,
+                transformation: Some(TransformationType::OrmToPydantic),
+            },
+        );
+
+        let dot = to_dot(&graph);
+
+        assert!(dot.starts_with("digraph call_graph {\n"));
+        assert!(dot.contains("  n0 [label=\"Module: app.py\", shape=folder, fillcolor=lightgrey];"));
+        assert!(dot.contains(
+            "  n1 [label=\"Function: create_item\\n(line 7)\", shape=box, fillcolor=lightblue];"
+        ));
+        assert!(dot
+            .contains("  n2 [label=\"Route: POST /items\", shape=hexagon, fillcolor=palegreen];"));
+        assert!(
+            dot.contains("  n3 [label=\"Schema: Item (Pydantic)\", shape=note, fillcolor=khaki];")
+        );
+        assert!(dot.contains("  n0 -> n1 [label=\"import: app\", style=dotted, color=gray40];"));
+        assert!(dot.contains("  n2 -> n1 [label=\"calls\"];"));
+        assert!(dot.contains(
+            "  n1 -> n1 [label=\"ItemModel → Item [OrmToPydantic]\", style=dashed, color=purple];"
+        ));
+        assert!(dot.ends_with("}\n"));
+    }
+}
@@ -37,6 +37,12 @@ pub enum CallEdge {
         return_value: String,
     },
     /// Data flow between schemas (e.g., Pydantic ↔ ORM)
+    ///
+    /// A transformation happening inside a function (`Item.model_validate(data)`,
+    /// `item.model_dump()`) is recorded as a self-loop on the calling node: `from` and `to`
+    /// are both the caller, and the schemas tell what was converted into what. Such loops
+    /// carry no control flow; traversals skip them (see
+    /// [`CallEdge::is_transformation_self_loop`]).
     DataFlow {
         /// Source node (e.g., Pydantic model)
         from: NodeId,
@@ -51,4 +57,31 @@ pub enum CallEdge {
         /// Type of transformation (e.g., FromDict, ToJson, OrmToPydantic, etc.)
         transformation: Option<TransformationType>,
     },
+    /// ORM relationship between two model classes (`orders = relationship("Order")`)
+    Relationship {
+        /// Model declaring the relationship
+        from: NodeId,
+        /// Related model
+        to: NodeId,
+        /// Attribute holding the related model(s) (e.g., "orders")
+        field: String,
+        /// Cardinality, inferred from `Mapped[list[...]]` or `uselist=`
+        kind: RelationshipKind,
+    },
+}
+
+/// Cardinality of an ORM relationship, seen from the declaring model
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RelationshipKind {
+    /// The model holds a collection of related models (`Mapped[list["Order"]]`)
+    OneToMany,
+    /// The model holds a single related model (`Mapped["User"]`)
+    ManyToOne,
+}
+
+impl CallEdge {
+    /// Whether the edge is a transformation recorded as a `DataFlow` self-loop on its caller
+    pub fn is_transformation_self_loop(&self) -> bool {
+        matches!(self, CallEdge::DataFlow { from, to, .. } if from == to)
+    }
 }
@@ -0,0 +1,188 @@
+use crate::call_graph::{CallGraph, CallNode, HttpMethod};
+use crate::models::{Diagnostic, Location, SchemaReference};
+use crate::parsers::Call;
+use rustpython_parser::ast;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+
+/// Routes, schemas and calls of a single file, extracted without following its imports
+/// (see [`CallGraphBuilder::analyze_file`](crate::call_graph::CallGraphBuilder::analyze_file))
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FileAnalysis {
+    /// Routes the file declares
+    pub routes: Vec<FileRoute>,
+    /// Models the file declares (Pydantic and ORM)
+    pub schemas: Vec<SchemaReference>,
+    /// Function calls in the file
+    pub calls: Vec<FileCall>,
+    /// Types the routes use that are imported from other modules, so their fields are unknown
+    pub unresolved_types: BTreeSet<String>,
+    /// Problems found in the file (syntax errors, ...)
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Route declared in the analyzed file
+#[derive(Debug, Clone, Serialize)]
+pub struct FileRoute {
+    /// HTTP method
+    pub method: HttpMethod,
+    /// Route path
+    pub path: String,
+    /// Name of the handler function
+    pub handler: String,
+    /// Where the route is declared
+    pub location: Location,
+    /// Request body schema (if any)
+    pub request_schema: Option<SchemaReference>,
+    /// Response schema (if any)
+    pub response_schema: Option<SchemaReference>,
+}
+
+/// Function call in the analyzed file
+#[derive(Debug, Clone, Serialize)]
+pub struct FileCall {
+    /// Called function name (`db.add`, `create_user`, ...)
+    pub name: String,
+    /// Function containing the call (None at module level)
+    pub caller: Option<String>,
+    /// Where the call is
+    pub location: Location,
+}
+
+impl FileAnalysis {
+    /// Collects the analysis of a file from its graph
+    ///
+    /// `imported` maps the names the file imports to their module: those the route handlers
+    /// of `module` use in annotations or `response_model=` are listed as unresolved, unless
+    /// the file declares a model of that name itself.
+    pub(crate) fn new(
+        graph: &CallGraph,
+        module: Option<&ast::Mod>,
+        schemas: Vec<SchemaReference>,
+        calls: Vec<Call>,
+        imported: Option<&HashMap<String, String>>,
+        diagnostics: Vec<Diagnostic>,
+    ) -> Self {
+        let routes: Vec<FileRoute> = graph
+            .node_weights()
+            .filter_map(|node| match node {
+                CallNode::Route {
+                    path,
+                    method,
+                    handler,
+                    location,
+                    request_schema,
+                    response_schema,
+                    ..
+                } => Some(FileRoute {
+                    method: *method,
+                    path: path.clone(),
+                    handler: match graph.node_weight(handler.0) {
+                        Some(CallNode::Function { name, .. } | CallNode::Method { name, .. }) => {
+                            name.clone()
+                        }
+                        _ => String::new(),
+                    },
+                    location: location.clone(),
+                    request_schema: request_schema.clone(),
+                    response_schema: response_schema.clone(),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        let mut referenced = BTreeSet::new();
+        if let Some(ast::Mod::Module(module)) = module {
+            for stmt in &module.body {
+                let (name, args, returns, decorators) = match stmt {
+                    ast::Stmt::FunctionDef(def) => {
+                        (&def.name, &def.args, &def.returns, &def.decorator_list)
+                    }
+                    ast::Stmt::AsyncFunctionDef(def) => {
+                        (&def.name, &def.args, &def.returns, &def.decorator_list)
+                    }
+                    _ => continue,
+                };
+                if !routes.iter().any(|route| route.handler == name.as_str()) {
+                    continue;
+                }
+                let annotations = args
+                    .posonlyargs
+                    .iter()
+                    .chain(&args.args)
+                    .chain(&args.kwonlyargs)
+                    .filter_map(|arg| arg.def.annotation.as_deref())
+                    .chain(returns.as_deref());
+                let response_models = decorators.iter().filter_map(|decorator| match decorator {
+                    ast::Expr::Call(call) => call
+                        .keywords
+                        .iter()
+                        .find(|keyword| keyword.arg.as_deref() == Some("response_model"))
+                        .map(|keyword| &keyword.value),
+                    _ => None,
+                });
+                for expr in annotations.chain(response_models) {
+                    collect_type_names(expr, &mut referenced);
+                }
+            }
+        }
+        let unresolved_types = referenced
+            .into_iter()
+            .filter(|name| imported.is_some_and(|imported| imported.contains_key(name)))
+            .filter(|name| !schemas.iter().any(|schema| &schema.name == name))
+            .collect();
+
+        Self {
+            routes,
+            schemas,
+            calls: calls
+                .into_iter()
+                .map(|call| FileCall {
+                    name: call.name,
+                    caller: call.caller,
+                    location: call.location,
+                })
+                .collect(),
+            unresolved_types,
+            diagnostics,
+        }
+    }
+}
+
+/// Names of the types an annotation mentions: `Page[Item] | None` mentions `Page` and `Item`,
+/// forward references (`"Item"`) included
+fn collect_type_names(expr: &ast::Expr, names: &mut BTreeSet<String>) {
+    match expr {
+        ast::Expr::Name(name) => {
+            names.insert(name.id.to_string());
+        }
+        ast::Expr::Attribute(attr) => {
+            names.insert(attr.attr.to_string());
+        }
+        ast::Expr::Subscript(sub) => {
+            collect_type_names(&sub.value, names);
+            collect_type_names(&sub.slice, names);
+        }
+        ast::Expr::Tuple(tuple) => {
+            for elt in &tuple.elts {
+                collect_type_names(elt, names);
+            }
+        }
+        ast::Expr::BinOp(bin_op) => {
+            collect_type_names(&bin_op.left, names);
+            collect_type_names(&bin_op.right, names);
+        }
+        ast::Expr::Constant(constant) => {
+            if let ast::Constant::Str(text) = &constant.value {
+                if let Ok(ast::Mod::Expression(expression)) = rustpython_parser::parse(
+                    text,
+                    rustpython_parser::Mode::Expression,
+                    "<annotation>",
+                ) {
+                    collect_type_names(&expression.body, names);
+                }
+            }
+        }
+        _ => {}
+    }
+}
@@ -1,10 +1,127 @@
 use crate::call_graph::{CallEdge, CallNode};
-use crate::models::NodeId;
-use petgraph::{Directed, Graph};
+use crate::models::{Location, NodeId, SchemaReference};
+use petgraph::graph::EdgeReference;
+use petgraph::visit::EdgeRef;
+use petgraph::{Directed, Direction, Graph};
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::path::{Path, PathBuf};
 
 /// Call graph - main structure for representing relationships between code nodes
 pub type CallGraph = Graph<CallNode, CallEdge, Directed, u32>;
 
+/// Merges `other` into `graph`, returning the node of `graph` each node of `other` became
+///
+/// Modules are deduplicated by canonical path, and classes by name and canonical file: such
+/// a node already in `graph` is reused, a class gaining the methods `other` knows it by.
+/// Every other node is added, with the node ids it holds (class methods, method owner,
+/// route handler) remapped. Edges are remapped too; an edge between two reused nodes is
+/// skipped when `graph` already joins them with an edge of the same kind.
+pub fn merge_graphs(graph: &mut CallGraph, other: &CallGraph) -> HashMap<NodeId, NodeId> {
+    let mut known: HashMap<MergeKey, NodeId> = graph
+        .node_indices()
+        .filter_map(|idx| Some((merge_key(&graph[idx])?, NodeId::from(idx))))
+        .collect();
+
+    let mut mapping = HashMap::new();
+    let mut reused = BTreeSet::new();
+    for idx in other.node_indices() {
+        let key = merge_key(&other[idx]);
+        let existing = key.as_ref().and_then(|key| known.get(key)).copied();
+        let new_id = match existing {
+            Some(existing) => {
+                reused.insert(existing.0);
+                existing
+            }
+            None => {
+                let new_id = NodeId::from(graph.add_node(other[idx].clone()));
+                if let Some(key) = key {
+                    known.insert(key, new_id);
+                }
+                new_id
+            }
+        };
+        mapping.insert(NodeId::from(idx), new_id);
+    }
+
+    let remap = |id: NodeId| mapping.get(&id).copied().unwrap_or(id);
+    for idx in other.node_indices() {
+        let target = mapping[&NodeId::from(idx)];
+        if reused.contains(&target.0) {
+            // Reused classes also list the methods `other` found for them
+            if let (
+                CallNode::Class { methods, .. },
+                CallNode::Class {
+                    methods: other_methods,
+                    ..
+                },
+            ) = (&mut graph[*target], &other[idx])
+            {
+                for method in other_methods.iter().map(|&method| remap(method)) {
+                    if !methods.contains(&method) {
+                        methods.push(method);
+                    }
+                }
+            }
+            continue;
+        }
+        match &mut graph[*target] {
+            CallNode::Class { methods, .. } => methods
+                .iter_mut()
+                .for_each(|method| *method = remap(*method)),
+            CallNode::Method { class, .. } => *class = remap(*class),
+            CallNode::Route { handler, .. } => *handler = remap(*handler),
+            CallNode::Module { .. } | CallNode::Function { .. } | CallNode::Schema { .. } => {}
+        }
+    }
+
+    for edge in other.edge_references() {
+        let source = remap(NodeId::from(edge.source()));
+        let target = remap(NodeId::from(edge.target()));
+        let mut weight = edge.weight().clone();
+        if reused.contains(&source.0) && reused.contains(&target.0) {
+            let kind = std::mem::discriminant(&weight);
+            let joined = graph
+                .edges_connecting(*source, *target)
+                .any(|existing| std::mem::discriminant(existing.weight()) == kind);
+            if joined {
+                continue;
+            }
+        }
+        match &mut weight {
+            CallEdge::Import { from, to, .. }
+            | CallEdge::Return { from, to, .. }
+            | CallEdge::DataFlow { from, to, .. }
+            | CallEdge::Relationship { from, to, .. } => {
+                *from = remap(*from);
+                *to = remap(*to);
+            }
+            CallEdge::Call { caller, callee, .. } => {
+                *caller = remap(*caller);
+                *callee = remap(*callee);
+            }
+        }
+        graph.add_edge(*source, *target, weight);
+    }
+
+    mapping
+}
+
+/// Identity under which `merge_graphs` treats nodes of two graphs as the same node
+#[derive(PartialEq, Eq, Hash)]
+enum MergeKey {
+    Module(PathBuf),
+    Class(String, PathBuf),
+}
+
+fn merge_key(node: &CallNode) -> Option<MergeKey> {
+    let canonical = |path: &Path| path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    match node {
+        CallNode::Module { path } => Some(MergeKey::Module(canonical(path))),
+        CallNode::Class { name, file, .. } => Some(MergeKey::Class(name.clone(), canonical(file))),
+        _ => None,
+    }
+}
+
 /// Finds all nodes of a specific type
 pub fn find_nodes<F>(graph: &CallGraph, predicate: F) -> Vec<NodeId>
 where
@@ -40,18 +157,150 @@ pub fn find_node_by_name(graph: &CallGraph, name: &str) -> Option<NodeId> {
         .map(NodeId::from)
 }
 
-/// Gets all incoming nodes (who calls this node)
-pub fn incoming_nodes(graph: &CallGraph, node: NodeId) -> Vec<NodeId> {
+/// Source location of a node
+///
+/// Modules point at their first line. Classes and methods don't record a line, so they have
+/// no location.
+pub fn node_location(graph: &CallGraph, node: NodeId) -> Option<Location> {
+    match graph.node_weight(*node)? {
+        CallNode::Module { path } => Some(Location {
+            file: path.to_string_lossy().to_string(),
+            line: 1,
+            column: None,
+        }),
+        CallNode::Function { file, line, .. } => Some(Location {
+            file: file.to_string_lossy().to_string(),
+            line: *line,
+            column: None,
+        }),
+        CallNode::Route { location, .. } => Some(location.clone()),
+        CallNode::Schema { schema } => Some(schema.location.clone()),
+        CallNode::Class { .. } | CallNode::Method { .. } => None,
+    }
+}
+
+/// Edges of `node` in `direction` that traversals follow
+///
+/// Transformation self-loops (`DataFlow` edges from a caller to itself, see
+/// [`CallEdge::is_transformation_self_loop`]) are left out: they describe data converted
+/// inside the node, and following them would make every transforming function look like
+/// a cycle.
+pub fn traversal_edges(
+    graph: &CallGraph,
+    node: NodeId,
+    direction: Direction,
+) -> impl Iterator<Item = EdgeReference<'_, CallEdge, u32>> {
     graph
-        .neighbors_directed(*node, petgraph::Direction::Incoming)
-        .map(NodeId::from)
+        .edges_directed(*node, direction)
+        .filter(|edge| !edge.weight().is_transformation_self_loop())
+}
+
+/// Gets all incoming nodes (who calls this node), ignoring transformation self-loops
+pub fn incoming_nodes(graph: &CallGraph, node: NodeId) -> Vec<NodeId> {
+    traversal_edges(graph, node, Direction::Incoming)
+        .map(|edge| NodeId::from(edge.source()))
         .collect()
 }
 
-/// Gets all outgoing nodes (whom this node calls)
+/// Gets all outgoing nodes (whom this node calls), ignoring transformation self-loops
 pub fn outgoing_nodes(graph: &CallGraph, node: NodeId) -> Vec<NodeId> {
-    graph
-        .neighbors_directed(*node, petgraph::Direction::Outgoing)
-        .map(NodeId::from)
+    traversal_edges(graph, node, Direction::Outgoing)
+        .map(|edge| NodeId::from(edge.target()))
         .collect()
 }
+
+/// Nodes reachable from `node` through outgoing edges, sorted by index
+///
+/// `node` itself is included only when a real cycle leads back to it; transformation
+/// self-loops don't count.
+pub fn reachable_from(graph: &CallGraph, node: NodeId) -> Vec<NodeId> {
+    let mut reached = BTreeSet::new();
+    let mut queue = VecDeque::from([node]);
+    while let Some(current) = queue.pop_front() {
+        for next in outgoing_nodes(graph, current) {
+            if reached.insert(next.0) {
+                queue.push_back(next);
+            }
+        }
+    }
+    reached.into_iter().map(NodeId::from).collect()
+}
+
+/// Collects every schema referenced by the graph
+///
+/// Looks at schema nodes, route request/response schemas, parameter and return types
+/// of functions and methods, and both ends of `DataFlow` edges. A schema seen several
+/// times (same type, name and location) is returned once, with the metadata of all
+/// occurrences merged (e.g. the ORM link added to a Pydantic model after linking).
+/// References known only by name (a parameter typed with a schema declared elsewhere,
+/// no location) are skipped.
+pub fn collect_schemas(graph: &CallGraph) -> Vec<SchemaReference> {
+    let mut schemas: Vec<SchemaReference> = Vec::new();
+    let mut add = |schema: &SchemaReference| {
+        if schema.location.file.is_empty() {
+            return;
+        }
+        let existing = schemas.iter_mut().find(|known| {
+            known.schema_type == schema.schema_type
+                && known.name == schema.name
+                && known.location == schema.location
+        });
+        match existing {
+            Some(known) => {
+                for (key, value) in &schema.metadata {
+                    known
+                        .metadata
+                        .entry(key.clone())
+                        .or_insert_with(|| value.clone());
+                }
+            }
+            None => schemas.push(schema.clone()),
+        }
+    };
+
+    for node in graph.node_weights() {
+        match node {
+            CallNode::Schema { schema } => add(schema),
+            CallNode::Route {
+                request_schema,
+                response_schema,
+                ..
+            } => {
+                request_schema.iter().for_each(&mut add);
+                response_schema.iter().for_each(&mut add);
+            }
+            CallNode::Function {
+                parameters,
+                return_type,
+                ..
+            }
+            | CallNode::Method {
+                parameters,
+                return_type,
+                ..
+            } => {
+                let types = parameters
+                    .iter()
+                    .map(|param| &param.type_info)
+                    .chain(return_type.iter());
+                for type_info in types {
+                    type_info.schema_ref.iter().for_each(&mut add);
+                }
+            }
+            CallNode::Module { .. } | CallNode::Class { .. } => {}
+        }
+    }
+    for edge in graph.edge_weights() {
+        if let CallEdge::DataFlow {
+            from_schema,
+            to_schema,
+            ..
+        } = edge
+        {
+            add(from_schema);
+            add(to_schema);
+        }
+    }
+
+    schemas
+}
@@ -1,15 +1,21 @@
 pub mod builder;
 pub mod decorator;
+pub mod dot;
 pub mod edge;
 pub mod extractor;
+pub mod file_analysis;
 pub mod graph;
 pub mod node;
 pub mod router_generator;
+pub mod snapshot;
 
 pub use builder::*;
 pub use decorator::*;
+pub use dot::*;
 pub use edge::*;
 pub use extractor::*;
+pub use file_analysis::*;
 pub use graph::*;
 pub use node::*;
 pub use router_generator::*;
+pub use snapshot::*;
@@ -1,7 +1,68 @@
 use crate::models::{Location, NodeId, SchemaReference, TypeInfo};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
+/// Route metadata key marking routes declared with `include_in_schema=False` (value `"false"`)
+pub const INCLUDE_IN_SCHEMA_METADATA_KEY: &str = "include_in_schema";
+
+/// Route metadata key marking routes declared with `deprecated=True` (value `"true"`)
+pub const DEPRECATED_METADATA_KEY: &str = "deprecated";
+
+/// Route metadata key holding the explicit OpenAPI `operation_id` of a route
+pub const OPERATION_ID_METADATA_KEY: &str = "operation_id";
+
+/// Route metadata key listing the models an unannotated handler returns in different
+/// `return` statements (comma-separated, set when there are several; the first one
+/// becomes the route's response schema)
+pub const RETURNED_SCHEMAS_METADATA_KEY: &str = "returned_schemas";
+
+/// Route metadata key listing the HTTP headers a backend route requires (comma-separated,
+/// lower-cased)
+pub const REQUIRED_HEADERS_METADATA_KEY: &str = "required_headers";
+
+/// Route metadata key listing the HTTP headers a frontend call sets (comma-separated,
+/// lower-cased); absent when the headers can't be determined statically
+pub const SENT_HEADERS_METADATA_KEY: &str = "sent_headers";
+
+/// Route metadata key holding the media type a backend route reads its request body in
+/// (`application/json`, `application/x-www-form-urlencoded`, `multipart/form-data`)
+pub const REQUEST_MEDIA_TYPE_METADATA_KEY: &str = "request_media_type";
+
+/// Route metadata key holding the media type of the body a frontend call sends; absent when
+/// it can't be determined statically
+pub const SENT_MEDIA_TYPE_METADATA_KEY: &str = "sent_media_type";
+
+/// Route metadata key listing the `int`/`float`/`bool` query parameters a backend route
+/// reads (JSON list of [`QueryParameter`](crate::models::QueryParameter))
+pub const QUERY_PARAMETERS_METADATA_KEY: &str = "query_parameters";
+
+/// Route metadata key holding the query parameters a frontend call sends through a literal
+/// `params` object (JSON object of name to literal value, `null` when computed)
+pub const SENT_QUERY_METADATA_KEY: &str = "sent_query";
+
+/// Route metadata key holding the status code a backend route responds with
+/// (`status_code=201` or `status_code=status.HTTP_201_CREATED`); absent when the route
+/// declares none or a constant with an unknown value
+pub const STATUS_CODE_METADATA_KEY: &str = "status_code";
+
+/// Route metadata key holding the name of the constant a backend route's `status_code` is
+/// given with (`status.HTTP_201_CREATED`), also when its value is unknown (a custom constant)
+pub const STATUS_CONSTANT_METADATA_KEY: &str = "status_constant";
+
+/// Route metadata key holding the media type of a backend route whose `response_class` sends
+/// something other than JSON (`text/html` for `HTMLResponse`); such routes have no response
+/// schema
+pub const RESPONSE_MEDIA_TYPE_METADATA_KEY: &str = "response_media_type";
+
+/// Route metadata key set to `true` on backend routes streaming their response
+/// (`response_class=StreamingResponse`)
+pub const STREAMING_RESPONSE_METADATA_KEY: &str = "streaming_response";
+
+/// Route metadata key listing the fields of the generic model a backend route wraps its
+/// response in (`response_model=Page[Item]` gives the fields of `Page`, comma-separated)
+pub const RESPONSE_ENVELOPE_METADATA_KEY: &str = "response_envelope";
+
 /// Node in call graph - represents function, class, method or route
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CallNode {
@@ -57,6 +118,9 @@ pub enum CallNode {
         request_schema: Option<SchemaReference>,
         /// Response schema (if any)
         response_schema: Option<SchemaReference>,
+        /// Additional route facts (e.g. `handled_statuses` of a frontend API call)
+        #[serde(default)]
+        metadata: HashMap<String, String>,
     },
     /// Schema (Pydantic, Zod, TypeScript, OpenAPI, etc.)
     Schema {
@@ -65,6 +129,27 @@ pub enum CallNode {
     },
 }
 
+impl CallNode {
+    /// Whether this is a route intentionally left out of the OpenAPI schema
+    /// (`include_in_schema=False`, e.g. internal health checks)
+    pub fn is_excluded_from_schema(&self) -> bool {
+        matches!(
+            self,
+            CallNode::Route { metadata, .. }
+                if metadata.get(INCLUDE_IN_SCHEMA_METADATA_KEY).map(String::as_str) == Some("false")
+        )
+    }
+
+    /// Whether this is a route declared as deprecated (`deprecated=True`)
+    pub fn is_deprecated(&self) -> bool {
+        matches!(
+            self,
+            CallNode::Route { metadata, .. }
+                if metadata.get(DEPRECATED_METADATA_KEY).map(String::as_str) == Some("true")
+        )
+    }
+}
+
 /// Function/method parameter
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Parameter {
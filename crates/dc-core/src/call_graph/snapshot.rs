@@ -0,0 +1,97 @@
+use crate::call_graph::{CallEdge, CallGraph, CallNode};
+use crate::models::NodeId;
+use anyhow::{bail, Context, Result};
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+
+/// Version of the format written by [`to_json`]; [`from_json`] rejects other versions
+pub const GRAPH_FORMAT_VERSION: u32 = 1;
+
+/// Serialized call graph: nodes in index order, edges by node position
+#[derive(Serialize, Deserialize)]
+struct GraphSnapshot {
+    version: u32,
+    nodes: Vec<CallNode>,
+    edges: Vec<EdgeSnapshot>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EdgeSnapshot {
+    source: usize,
+    target: usize,
+    edge: CallEdge,
+}
+
+/// Serializes every node and edge of the graph to JSON
+///
+/// Nodes are written in index order, so the `NodeId`s held by nodes (class methods, route
+/// handlers) and edges keep pointing at the same nodes once read back by [`from_json`].
+pub fn to_json(graph: &CallGraph) -> Result<String> {
+    let snapshot = GraphSnapshot {
+        version: GRAPH_FORMAT_VERSION,
+        nodes: graph.node_weights().cloned().collect(),
+        edges: graph
+            .edge_indices()
+            .filter_map(|idx| {
+                let (source, target) = graph.edge_endpoints(idx)?;
+                Some(EdgeSnapshot {
+                    source: source.index(),
+                    target: target.index(),
+                    edge: graph[idx].clone(),
+                })
+            })
+            .collect(),
+    };
+    serde_json::to_string(&snapshot).context("Failed to serialize call graph")
+}
+
+/// Reads a graph written by [`to_json`], with the node indices it had when written
+pub fn from_json(json: &str) -> Result<CallGraph> {
+    let snapshot: GraphSnapshot =
+        serde_json::from_str(json).context("Failed to parse serialized call graph")?;
+    if snapshot.version != GRAPH_FORMAT_VERSION {
+        bail!(
+            "Unsupported call graph format version {} (expected {})",
+            snapshot.version,
+            GRAPH_FORMAT_VERSION
+        );
+    }
+
+    let node_count = snapshot.nodes.len();
+    let check = |id: usize| {
+        if id >= node_count {
+            bail!(
+                "Serialized call graph references node {} but has {} node(s)",
+                id,
+                node_count
+            );
+        }
+        Ok(NodeIndex::new(id))
+    };
+    let mut graph = CallGraph::with_capacity(node_count, snapshot.edges.len());
+    for node in snapshot.nodes {
+        graph.add_node(node);
+    }
+    for EdgeSnapshot {
+        source,
+        target,
+        edge,
+    } in snapshot.edges
+    {
+        for id in edge_node_ids(&edge) {
+            check(id.index())?;
+        }
+        graph.add_edge(check(source)?, check(target)?, edge);
+    }
+    Ok(graph)
+}
+
+fn edge_node_ids(edge: &CallEdge) -> [NodeId; 2] {
+    match edge {
+        CallEdge::Import { from, to, .. }
+        | CallEdge::Return { from, to, .. }
+        | CallEdge::DataFlow { from, to, .. }
+        | CallEdge::Relationship { from, to, .. } => [*from, *to],
+        CallEdge::Call { caller, callee, .. } => [*caller, *callee],
+    }
+}
@@ -0,0 +1,110 @@
+use ignore::WalkBuilder;
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+/// Files under `root` with one of the given extensions, in path order
+///
+/// With `respect_gitignore`, files and directories ignored by `.gitignore` files (and
+/// `.git/info/exclude` or the global excludes file) are skipped, also outside a git
+/// repository. A `root` that is a file is returned even when it is ignored: naming a file
+/// explicitly beats ignoring it.
+pub fn find_source_files(
+    root: &Path,
+    extensions: &[&str],
+    respect_gitignore: bool,
+) -> Vec<PathBuf> {
+    let walker = WalkBuilder::new(root)
+        .standard_filters(false)
+        .git_ignore(respect_gitignore)
+        .git_exclude(respect_gitignore)
+        .git_global(respect_gitignore)
+        .parents(respect_gitignore)
+        .require_git(false)
+        .sort_by_file_name(|a, b| a.cmp(b))
+        .build();
+
+    let mut files = Vec::new();
+    for entry in walker {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err) => {
+                debug!(root = ?root, error = %err, "Skipping unreadable path during discovery");
+                continue;
+            }
+        };
+        let path = entry.path();
+        let has_extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| extensions.contains(&ext));
+        if has_extension && entry.file_type().is_some_and(|kind| kind.is_file()) {
+            files.push(entry.into_path());
+        }
+    }
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let full_path = root.join(path);
+        fs::create_dir_all(full_path.parent().unwrap()).unwrap();
+        fs::write(full_path, content).unwrap();
+    }
+
+    fn relative(root: &Path, files: Vec<PathBuf>) -> Vec<String> {
+        files
+            .iter()
+            .map(|file| {
+                file.strip_prefix(root)
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_gitignored_files_are_skipped() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        write(root, ".gitignore", "generated/\n*.gen.ts\n");
+        write(root, "src/api.ts", "");
+        write(root, "src/api.gen.ts", "");
+        write(root, "src/view.tsx", "");
+        write(root, "src/notes.md", "");
+        write(root, "generated/client.ts", "");
+        write(root, "src/vendor/.gitignore", "*\n");
+        write(root, "src/vendor/lib.ts", "");
+
+        let files = find_source_files(root, &["ts", "tsx"], true);
+        assert_eq!(relative(root, files), ["src/api.ts", "src/view.tsx"]);
+
+        let files = find_source_files(root, &["ts", "tsx"], false);
+        assert_eq!(
+            relative(root, files),
+            [
+                "generated/client.ts",
+                "src/api.gen.ts",
+                "src/api.ts",
+                "src/vendor/lib.ts",
+                "src/view.tsx"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_explicit_files_beat_gitignore() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        write(root, ".gitignore", "generated/\n");
+        write(root, "generated/client.ts", "");
+
+        let files = find_source_files(&root.join("generated/client.ts"), &["ts"], true);
+        assert_eq!(relative(root, files), ["generated/client.ts"]);
+    }
+}
@@ -0,0 +1,257 @@
+use crate::call_graph::{collect_schemas, CallGraph, CallNode};
+use crate::models::{Location, SchemaType};
+use crate::parsers::{LocationConverter, PythonParser};
+use anyhow::{Context, Result};
+use rustpython_parser::ast::{self, Ranged};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashSet};
+use std::fs;
+use std::path::Path;
+use tracing::warn;
+
+/// Fix adding `response_model=` to route decorators of handlers annotated with a model
+pub const MISSING_RESPONSE_MODEL_FIX: &str = "missing-response-model";
+
+/// Text inserted into a source file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceEdit {
+    /// Fix producing the edit (e.g. [`MISSING_RESPONSE_MODEL_FIX`])
+    pub fix_id: String,
+    /// Position of the fixed code (the decorator)
+    pub location: Location,
+    /// Byte offset the text is inserted at
+    pub offset: usize,
+    /// Inserted text
+    pub insert: String,
+    /// Human-readable summary of the edit
+    pub description: String,
+}
+
+/// Adds the missing `response_model=` to the routes of every Python module of the graph
+///
+/// Handlers qualify when they are annotated with a Pydantic model of the graph (`-> User`,
+/// `-> list[User]`) and their route decorator has no `response_model` argument.
+/// A fixed file must parse again, otherwise it is left untouched; handlers that already
+/// declare `response_model` are skipped, so running the fix twice changes nothing.
+/// Returns the edits written to disk.
+pub fn fix_missing_response_models(
+    graph: &CallGraph,
+    parser: &PythonParser,
+) -> Result<Vec<SourceEdit>> {
+    let models: HashSet<String> = collect_schemas(graph)
+        .into_iter()
+        .filter(|schema| schema.schema_type == SchemaType::Pydantic)
+        .map(|schema| schema.name)
+        .collect();
+    let files: BTreeSet<&Path> = graph
+        .node_weights()
+        .filter_map(|node| match node {
+            CallNode::Module { path } if path.extension().is_some_and(|ext| ext == "py") => {
+                Some(path.as_path())
+            }
+            _ => None,
+        })
+        .collect();
+
+    let mut applied = Vec::new();
+    for file in files {
+        applied.extend(fix_file(file, |source, file_path| {
+            missing_response_model_edits(parser, source, file_path, &models)
+        })?);
+    }
+    Ok(applied)
+}
+
+/// Computes the `response_model=` insertions for one Python source
+pub fn missing_response_model_edits(
+    parser: &PythonParser,
+    source: &str,
+    file_path: &str,
+    models: &HashSet<String>,
+) -> Result<Vec<SourceEdit>> {
+    let module = rustpython_parser::parse(source, rustpython_parser::Mode::Module, file_path)
+        .with_context(|| format!("Failed to parse {}", file_path))?;
+    let ast::Mod::Module(module) = module else {
+        return Ok(Vec::new());
+    };
+    let converter = LocationConverter::new(source);
+
+    let mut edits = Vec::new();
+    let mut pending: Vec<&ast::Stmt> = module.body.iter().collect();
+    while let Some(stmt) = pending.pop() {
+        let (decorators, returns) = match stmt {
+            ast::Stmt::FunctionDef(func_def) => (&func_def.decorator_list, &func_def.returns),
+            ast::Stmt::AsyncFunctionDef(func_def) => (&func_def.decorator_list, &func_def.returns),
+            ast::Stmt::ClassDef(class_def) => {
+                pending.extend(&class_def.body);
+                continue;
+            }
+            _ => continue,
+        };
+        let Some(returns) = returns.as_deref() else {
+            continue;
+        };
+        if !annotates_model(returns, models) {
+            continue;
+        }
+        let annotation = &source[returns.range()];
+
+        for decorator in decorators {
+            let ast::Expr::Call(call) = decorator else {
+                continue;
+            };
+            let Some(name) = parser.get_decorator_name(decorator) else {
+                continue;
+            };
+            // Routes with a non-JSON `response_class` have no response model to declare
+            if !parser.is_route_decorator(&name)
+                || call.keywords.iter().any(|kw| match kw.arg.as_deref() {
+                    Some("response_model") => true,
+                    Some("response_class") => {
+                        PythonParser::non_json_response_media_type(&source[kw.value.range()])
+                            .is_some()
+                    }
+                    _ => false,
+                })
+            {
+                continue;
+            }
+
+            // After the last argument (keeps trailing commas and comments valid),
+            // or right before `)` of an empty argument list
+            let last_argument_end = call
+                .args
+                .iter()
+                .map(|arg| arg.range().end())
+                .chain(call.keywords.iter().map(|kw| kw.range().end()))
+                .max();
+            let (offset, insert) = match last_argument_end {
+                Some(end) => (usize::from(end), format!(", response_model={}", annotation)),
+                None => (
+                    usize::from(call.range().end()) - 1,
+                    format!("response_model={}", annotation),
+                ),
+            };
+            let (line, column) =
+                converter.byte_offset_to_location(decorator.range().start().into());
+            edits.push(SourceEdit {
+                fix_id: MISSING_RESPONSE_MODEL_FIX.to_string(),
+                location: Location {
+                    file: file_path.to_string(),
+                    line,
+                    column: Some(column),
+                },
+                offset,
+                insert,
+                description: format!("Added response_model={} to @{}", annotation, name),
+            });
+        }
+    }
+
+    edits.sort_by_key(|edit| edit.offset);
+    Ok(edits)
+}
+
+/// Applies insertions to a source (edits may come in any order)
+pub fn apply_edits(source: &str, edits: &[SourceEdit]) -> String {
+    let mut ordered: Vec<&SourceEdit> = edits.iter().collect();
+    ordered.sort_by_key(|edit| std::cmp::Reverse(edit.offset));
+    let mut fixed = source.to_string();
+    for edit in ordered {
+        fixed.insert_str(edit.offset, &edit.insert);
+    }
+    fixed
+}
+
+/// Computes and writes the edits of one file, skipping it when the result doesn't parse
+fn fix_file<F>(file: &Path, compute_edits: F) -> Result<Vec<SourceEdit>>
+where
+    F: FnOnce(&str, &str) -> Result<Vec<SourceEdit>>,
+{
+    let source =
+        fs::read_to_string(file).with_context(|| format!("Failed to read {}", file.display()))?;
+    let file_path = file.to_string_lossy();
+    let edits = match compute_edits(&source, &file_path) {
+        Ok(edits) if !edits.is_empty() => edits,
+        Ok(_) => return Ok(Vec::new()),
+        Err(err) => {
+            warn!(file_path = %file_path, error = %err, "Skipping file that can't be fixed");
+            return Ok(Vec::new());
+        }
+    };
+
+    let fixed = apply_edits(&source, &edits);
+    if rustpython_parser::parse(&fixed, rustpython_parser::Mode::Module, &file_path).is_err() {
+        warn!(file_path = %file_path, "Fixed file doesn't parse, leaving it untouched");
+        return Ok(Vec::new());
+    }
+    fs::write(file, fixed).with_context(|| format!("Failed to write {}", file.display()))?;
+    Ok(edits)
+}
+
+/// `-> User`, `-> schemas.User` or `-> list[User]` where `User` is a known model
+fn annotates_model(annotation: &ast::Expr, models: &HashSet<String>) -> bool {
+    match annotation {
+        ast::Expr::Name(name) => models.contains(name.id.as_str()),
+        ast::Expr::Attribute(attr) => models.contains(attr.attr.as_str()),
+        ast::Expr::Subscript(sub) => {
+            let container = match sub.value.as_ref() {
+                ast::Expr::Name(name) => name.id.as_str(),
+                ast::Expr::Attribute(attr) => attr.attr.as_str(),
+                _ => return false,
+            };
+            matches!(container, "list" | "List" | "Sequence") && annotates_model(&sub.slice, models)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edits(source: &str) -> Vec<SourceEdit> {
+        let models = HashSet::from(["User".to_string()]);
+        missing_response_model_edits(&PythonParser::new(), source, "main.py", &models).unwrap()
+    }
+
+    #[test]
+    fn test_missing_response_model_is_inserted_after_last_argument() {
+        let source = r#"@app.get("/users/{user_id}",)
+def get_user(user_id: int) -> User:
+    ...
+
+@app.get("/users", status_code=200)
+def list_users() -> list[User]:
+    ...
+
+@app.post()
+async def create_user() -> User:
+    ...
+
+@app.get("/me", response_model=User)
+def me() -> User:
+    ...
+
+@app.get("/health")
+def health() -> dict:
+    ...
+
+@app.get("/profile", response_class=HTMLResponse)
+def profile() -> User:
+    ...
+"#;
+        let found = edits(source);
+        let fixed = apply_edits(source, &found);
+
+        assert_eq!(found.len(), 3);
+        assert_eq!(found[0].location.line, 1);
+        assert!(fixed.contains(r#"@app.get("/users/{user_id}", response_model=User,)"#));
+        assert!(fixed.contains(r#"@app.get("/users", status_code=200, response_model=list[User])"#));
+        assert!(fixed.contains("@app.post(response_model=User)"));
+        assert!(fixed.contains(r#"@app.get("/health")"#));
+        assert!(fixed.contains(r#"@app.get("/profile", response_class=HTMLResponse)"#));
+        // Fixing again changes nothing
+        assert!(edits(&fixed).is_empty());
+    }
+}
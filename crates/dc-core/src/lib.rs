@@ -2,12 +2,15 @@ pub mod analyzers;
 pub mod cache;
 pub mod call_graph;
 pub mod data_flow;
+pub mod discovery;
 pub mod entry_point;
 pub mod error;
+pub mod fixes;
 pub mod logging;
 pub mod models;
 pub mod openapi;
 pub mod parsers;
+pub mod snippet;
 
 pub use error::{ConfigError, DcError, GraphError, ParseError, ValidationError};
 pub use logging::{init, init_default, init_from_args};
@@ -12,6 +12,9 @@ pub struct LoggingConfig {
     pub console: bool,
     /// Log format (text or json)
     pub format: LogFormat,
+    /// Show only errors on the console, regardless of `level` and `RUST_LOG`
+    /// (file logging keeps the configured level)
+    pub quiet: bool,
 }
 
 impl Default for LoggingConfig {
@@ -21,6 +24,7 @@ impl Default for LoggingConfig {
             file: std::env::var("DCV_LOG_FILE").ok().map(PathBuf::from),
             console: true,
             format: LogFormat::Text,
+            quiet: false,
         }
     }
 }
@@ -33,6 +37,13 @@ impl LoggingConfig {
             file,
             console,
             format,
+            quiet: false,
         }
     }
+
+    /// Restricts console output to errors
+    pub fn with_quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        self
+    }
 }
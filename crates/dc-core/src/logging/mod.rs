@@ -9,12 +9,20 @@ use std::path::PathBuf;
 /// Initialize logging system with the given configuration
 pub fn init(config: LoggingConfig) -> Result<()> {
     use tracing_subscriber::{
-        fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Registry,
+        filter::LevelFilter, fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer,
+        Registry,
     };
 
     let env_filter =
         EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(&config.level));
 
+    // Quiet mode keeps the console clean for the report; the log file is not affected
+    let console_level = if config.quiet {
+        LevelFilter::ERROR
+    } else {
+        LevelFilter::TRACE
+    };
+
     match (config.console, &config.file) {
         (true, Some(log_file)) => {
             // Both console and file
@@ -28,7 +36,8 @@ pub fn init(config: LoggingConfig) -> Result<()> {
                         .with_line_number(true)
                         .with_thread_ids(false)
                         .with_thread_names(false)
-                        .with_ansi(true),
+                        .with_ansi(true)
+                        .with_filter(console_level),
                 )
                 .with(
                     fmt::layer()
@@ -54,7 +63,8 @@ pub fn init(config: LoggingConfig) -> Result<()> {
                         .with_line_number(true)
                         .with_thread_ids(false)
                         .with_thread_names(false)
-                        .with_ansi(true),
+                        .with_ansi(true)
+                        .with_filter(console_level),
                 )
                 .init();
         }
@@ -95,6 +105,7 @@ pub fn init_from_args(
     log_level: Option<String>,
     log_file: Option<PathBuf>,
     verbose: bool,
+    quiet: bool,
 ) -> Result<()> {
     let level = if verbose {
         "debug".to_string()
@@ -110,6 +121,7 @@ pub fn init_from_args(
         file,
         console: true,
         format: formatter::LogFormat::Text,
+        quiet,
     };
 
     init(config)
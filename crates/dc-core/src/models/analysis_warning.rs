@@ -0,0 +1,93 @@
+use crate::models::Location;
+use serde::{Deserialize, Serialize};
+
+/// What the analysis couldn't do
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum AnalysisWarningKind {
+    /// Import of a project module that doesn't exist
+    UnresolvedImport,
+    /// Import of a declared dependency that isn't installed
+    ExternalDependency,
+    /// Imported module whose graph could not be built
+    ModuleSkipped,
+    /// Schemas that could not be extracted or enriched (OpenAPI, Pydantic JSON schema)
+    SchemaEnrichment,
+    /// Analysis step cut short because the analysis timeout was reached
+    TimedOut,
+}
+
+impl AnalysisWarningKind {
+    /// Identifier of the kind, as serialized (e.g. "unresolved-import")
+    pub fn id(&self) -> &'static str {
+        match self {
+            AnalysisWarningKind::UnresolvedImport => "unresolved-import",
+            AnalysisWarningKind::ExternalDependency => "external-dependency",
+            AnalysisWarningKind::ModuleSkipped => "module-skipped",
+            AnalysisWarningKind::SchemaEnrichment => "schema-enrichment",
+            AnalysisWarningKind::TimedOut => "timed-out",
+        }
+    }
+
+    /// What `count` warnings of this kind mean for the analysis (e.g. "37 imports unresolved")
+    pub fn describe(&self, count: usize) -> String {
+        match self {
+            AnalysisWarningKind::UnresolvedImport => format!("{} import(s) unresolved", count),
+            AnalysisWarningKind::ExternalDependency => {
+                format!("{} external dependency import(s) not installed", count)
+            }
+            AnalysisWarningKind::ModuleSkipped => format!("{} module(s) not analyzed", count),
+            AnalysisWarningKind::SchemaEnrichment => {
+                format!("{} schema enrichment(s) failed", count)
+            }
+            AnalysisWarningKind::TimedOut => {
+                format!("{} analysis step(s) cut short by the timeout", count)
+            }
+        }
+    }
+}
+
+/// Limitation of the analysis itself: code that could not be followed, so contracts
+/// through it were not checked
+///
+/// Unlike [`Diagnostic`](crate::models::Diagnostic)s these are not findings about the
+/// project, only about how complete the results are.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AnalysisWarning {
+    /// What the analysis couldn't do
+    pub kind: AnalysisWarningKind,
+    /// Where it happened (the import statement, the route, ...)
+    pub location: Location,
+    /// Human-readable explanation
+    pub message: String,
+}
+
+impl AnalysisWarning {
+    /// Creates a warning
+    pub fn new(kind: AnalysisWarningKind, location: Location, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            location,
+            message: message.into(),
+        }
+    }
+
+    /// Number of warnings of each kind, by kind
+    pub fn counts(warnings: &[AnalysisWarning]) -> Vec<(AnalysisWarningKind, usize)> {
+        let mut counts = std::collections::BTreeMap::new();
+        for warning in warnings {
+            *counts.entry(warning.kind).or_insert(0) += 1;
+        }
+        counts.into_iter().collect()
+    }
+
+    /// One-line overview of `warnings` (e.g. "37 import(s) unresolved, 2 module(s) not
+    /// analyzed"), None when there are none
+    pub fn summarize(warnings: &[AnalysisWarning]) -> Option<String> {
+        let parts: Vec<String> = Self::counts(warnings)
+            .into_iter()
+            .map(|(kind, count)| kind.describe(count))
+            .collect();
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+}
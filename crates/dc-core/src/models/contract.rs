@@ -18,6 +18,27 @@ pub struct Contract {
     pub severity: Severity,
 }
 
+impl Contract {
+    /// Severity of a contract with these mismatches: critical with a type mismatch, a
+    /// warning with any mismatch above `SeverityLevel::Low`, info otherwise
+    pub fn severity_of(mismatches: &[Mismatch]) -> Severity {
+        if mismatches
+            .iter()
+            .any(|m| m.mismatch_type == MismatchType::TypeMismatch)
+        {
+            Severity::Critical
+        } else if mismatches
+            .iter()
+            .any(|m| m.severity_level > SeverityLevel::Low)
+        {
+            // Low mismatches (e.g. a field an `extra="ignore"` model drops) are informational
+            Severity::Warning
+        } else {
+            Severity::Info
+        }
+    }
+}
+
 /// Detected mismatch at junction
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Mismatch {
@@ -36,6 +57,32 @@ pub struct Mismatch {
     /// Detailed severity level for more precise classification
     #[serde(default)]
     pub severity_level: SeverityLevel,
+    /// How many times this diagnostic was reported before deduplication
+    #[serde(default = "default_occurrences")]
+    pub occurrences: usize,
+}
+
+fn default_occurrences() -> usize {
+    1
+}
+
+impl Mismatch {
+    /// Stable rule identifier of this diagnostic
+    pub fn rule_id(&self) -> &'static str {
+        self.mismatch_type.rule_id()
+    }
+
+    /// Deduplication key: identical rule, location, field path and message
+    pub fn dedup_key(&self) -> (&'static str, &str, usize, Option<usize>, &str, &str) {
+        (
+            self.rule_id(),
+            self.location.file.as_str(),
+            self.location.line,
+            self.location.column,
+            self.path.as_str(),
+            self.message.as_str(),
+        )
+    }
 }
 
 /// Mismatch type
@@ -53,6 +100,26 @@ pub enum MismatchType {
     UnnormalizedData,
     /// Missing schema validation (dict[str, Any] or any)
     MissingSchema,
+    /// Both sides default a field to different values
+    DefaultMismatch,
+    /// Field the server generates is sent by the client
+    ResponseOnlyField,
+}
+
+impl MismatchType {
+    /// Rule identifier used in reports (matches the `ContractRule` names)
+    pub fn rule_id(&self) -> &'static str {
+        match self {
+            MismatchType::TypeMismatch => "type_mismatch",
+            MismatchType::MissingField => "missing_field",
+            MismatchType::ExtraField => "extra_field",
+            MismatchType::ValidationMismatch => "validation_mismatch",
+            MismatchType::UnnormalizedData => "unnormalized_data",
+            MismatchType::MissingSchema => "missing_schema",
+            MismatchType::DefaultMismatch => "default_mismatch",
+            MismatchType::ResponseOnlyField => "response_only_field",
+        }
+    }
 }
 
 /// Problem severity
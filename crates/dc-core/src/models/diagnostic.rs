@@ -0,0 +1,209 @@
+use crate::models::{Location, Severity};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Rule of diagnostics for files that could not be parsed
+pub const PARSE_ERROR_RULE: &str = "parse-error";
+
+/// Rule of diagnostics for frontend code still using a deprecated route or field
+pub const DEPRECATED_USAGE_RULE: &str = "deprecated-usage";
+
+/// Rule of diagnostics for documented examples that don't fit their field any more
+pub const STALE_EXAMPLE_RULE: &str = "stale-example";
+
+/// Rule of diagnostics for frontend types that could not be resolved to a schema
+pub const UNRESOLVED_TYPE_RULE: &str = "unresolved-type";
+
+/// Rule of diagnostics for a schema shared by frontend and backend that one side diverges from
+pub const SHARED_SCHEMA_RULE: &str = "shared-schema";
+
+/// Rule of diagnostics for OpenAPI operation IDs that are duplicated or don't map to a route
+pub const OPERATION_ID_RULE: &str = "operation-id";
+
+/// Rule of diagnostics for frontend calls that don't send a header their backend route requires
+pub const MISSING_HEADER_RULE: &str = "missing-header";
+
+/// Rule of diagnostics for frontend calls that reach their route only through a trailing-slash
+/// redirect
+pub const TRAILING_SLASH_RULE: &str = "trailing-slash";
+
+/// Rule of diagnostics for frontend calls sending a body in a media type their backend route
+/// doesn't read
+pub const CONTENT_TYPE_RULE: &str = "content-type";
+
+/// Rule of diagnostics for model names declared by several modules that a reference can't be
+/// resolved to one of
+pub const AMBIGUOUS_MODEL_RULE: &str = "ambiguous-model";
+
+/// Rule of diagnostics for paginated responses whose envelope differs from the other
+/// paginated routes, or from what the frontend reads
+pub const PAGINATION_SHAPE_RULE: &str = "pagination-shape";
+
+/// Rule of diagnostics for query parameter values the backend can't coerce to the
+/// parameter's type
+pub const QUERY_COERCION_RULE: &str = "query-coercion";
+
+/// Problem found while analyzing the code itself (not a contract between two links)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Rule identifier (e.g. `parse-error`)
+    pub rule_id: String,
+    /// Severity of the problem
+    pub severity: Severity,
+    /// Location in code
+    pub location: Location,
+    /// Error message
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Diagnostic for a file skipped because it could not be parsed
+    ///
+    /// `location` points at the syntax error.
+    pub fn parse_error(location: Location, error: impl Display) -> Self {
+        Self {
+            rule_id: PARSE_ERROR_RULE.to_string(),
+            severity: Severity::Warning,
+            message: format!(
+                "Failed to parse {} (line {}): {}; file skipped",
+                location.file, location.line, error
+            ),
+            location,
+        }
+    }
+
+    /// Informational diagnostic for a deprecated route or field the frontend still uses
+    ///
+    /// `location` points at the frontend usage.
+    pub fn deprecated_usage(location: Location, message: impl Into<String>) -> Self {
+        Self {
+            rule_id: DEPRECATED_USAGE_RULE.to_string(),
+            severity: Severity::Info,
+            location,
+            message: message.into(),
+        }
+    }
+
+    /// Informational diagnostic for a documented field example that doesn't validate
+    ///
+    /// `location` points at the schema declaring the example.
+    pub fn stale_example(location: Location, message: impl Into<String>) -> Self {
+        Self {
+            rule_id: STALE_EXAMPLE_RULE.to_string(),
+            severity: Severity::Info,
+            location,
+            message: message.into(),
+        }
+    }
+
+    /// Warning diagnostic for a frontend type whose contract could not be checked
+    ///
+    /// `location` points at the unresolved type.
+    pub fn unresolved_type(location: Location, message: impl Into<String>) -> Self {
+        Self {
+            rule_id: UNRESOLVED_TYPE_RULE.to_string(),
+            severity: Severity::Warning,
+            location,
+            message: message.into(),
+        }
+    }
+
+    /// Warning diagnostic for a frontend call and its backend route that don't agree on a
+    /// shared schema
+    ///
+    /// `location` points at the frontend call.
+    pub fn shared_schema(location: Location, message: impl Into<String>) -> Self {
+        Self {
+            rule_id: SHARED_SCHEMA_RULE.to_string(),
+            severity: Severity::Warning,
+            location,
+            message: message.into(),
+        }
+    }
+
+    /// Warning diagnostic for a duplicated, missing or orphaned OpenAPI operation ID
+    ///
+    /// `location` points at the route, or at the OpenAPI schema for operations no route serves.
+    pub fn operation_id(location: Location, message: impl Into<String>) -> Self {
+        Self {
+            rule_id: OPERATION_ID_RULE.to_string(),
+            severity: Severity::Warning,
+            location,
+            message: message.into(),
+        }
+    }
+
+    /// Warning diagnostic for a header a backend route requires and a frontend call doesn't send
+    ///
+    /// `location` points at the frontend call.
+    pub fn missing_header(location: Location, message: impl Into<String>) -> Self {
+        Self {
+            rule_id: MISSING_HEADER_RULE.to_string(),
+            severity: Severity::Warning,
+            location,
+            message: message.into(),
+        }
+    }
+
+    /// Warning diagnostic for a frontend call relying on a trailing-slash redirect
+    ///
+    /// `location` points at the frontend call.
+    pub fn trailing_slash(location: Location, message: impl Into<String>) -> Self {
+        Self {
+            rule_id: TRAILING_SLASH_RULE.to_string(),
+            severity: Severity::Warning,
+            location,
+            message: message.into(),
+        }
+    }
+
+    /// Warning diagnostic for a reference to a model name several modules declare with
+    /// different fields
+    ///
+    /// `location` points at the referencing file.
+    pub fn ambiguous_model(location: Location, message: impl Into<String>) -> Self {
+        Self {
+            rule_id: AMBIGUOUS_MODEL_RULE.to_string(),
+            severity: Severity::Warning,
+            location,
+            message: message.into(),
+        }
+    }
+
+    /// Warning diagnostic for a frontend call sending a body in a media type its backend
+    /// route doesn't read
+    ///
+    /// `location` points at the frontend call.
+    pub fn content_type(location: Location, message: impl Into<String>) -> Self {
+        Self {
+            rule_id: CONTENT_TYPE_RULE.to_string(),
+            severity: Severity::Warning,
+            location,
+            message: message.into(),
+        }
+    }
+
+    /// Warning diagnostic for a paginated response whose envelope differs from the other
+    /// paginated routes, or lacks fields the frontend reads
+    ///
+    /// `location` points at the route, or at the frontend call.
+    pub fn pagination_shape(location: Location, message: impl Into<String>) -> Self {
+        Self {
+            rule_id: PAGINATION_SHAPE_RULE.to_string(),
+            severity: Severity::Warning,
+            location,
+            message: message.into(),
+        }
+    }
+
+    /// Warning diagnostic for a frontend call sending a query parameter value its backend
+    /// route rejects (not a number for an `int`, a strict parameter given a string)
+    pub fn query_coercion(location: Location, message: impl Into<String>) -> Self {
+        Self {
+            rule_id: QUERY_COERCION_RULE.to_string(),
+            severity: Severity::Warning,
+            location,
+            message: message.into(),
+        }
+    }
+}
@@ -1,11 +1,25 @@
+pub mod analysis_warning;
 pub mod chain;
 pub mod contract;
+pub mod diagnostic;
 pub mod errors;
 pub mod location;
+pub mod query;
+pub mod rule_info;
 pub mod schema;
+pub mod status;
+pub mod type_compatibility;
+pub mod union;
 
+pub use analysis_warning::*;
 pub use chain::*;
 pub use contract::*;
+pub use diagnostic::*;
 pub use errors::*;
 pub use location::*;
+pub use query::*;
+pub use rule_info::*;
 pub use schema::*;
+pub use status::*;
+pub use type_compatibility::*;
+pub use union::*;
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+/// Scalar type a backend validates a query parameter as
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum QueryParamType {
+    /// `int` (`StrictInt`, ...)
+    Integer,
+    /// `float`
+    Number,
+    /// `bool`
+    Boolean,
+}
+
+impl QueryParamType {
+    /// Strings Pydantic accepts as booleans, compared lower-cased
+    const BOOLEAN_STRINGS: [&'static str; 12] = [
+        "0", "off", "f", "false", "n", "no", "1", "on", "t", "true", "y", "yes",
+    ];
+
+    /// Python name of the type (`int`, `float`, `bool`)
+    pub fn python_name(&self) -> &'static str {
+        match self {
+            QueryParamType::Integer => "int",
+            QueryParamType::Number => "float",
+            QueryParamType::Boolean => "bool",
+        }
+    }
+
+    /// Whether Pydantic's lax mode coerces a query string value to this type
+    ///
+    /// Numbers may use `_` separators, and integers a zero fraction (`2.0`); booleans are
+    /// `true`/`false`, `1`/`0`, `yes`/`no`, `on`/`off`, `t`/`f` or `y`/`n` in any case.
+    pub fn accepts(&self, value: &str) -> bool {
+        let value = value.trim();
+        let number = || value.replace('_', "").parse::<f64>().ok();
+        match self {
+            QueryParamType::Integer => {
+                number().is_some_and(|number| number.is_finite() && number.fract() == 0.0)
+            }
+            QueryParamType::Number => number().is_some(),
+            QueryParamType::Boolean => {
+                Self::BOOLEAN_STRINGS.contains(&value.to_lowercase().as_str())
+            }
+        }
+    }
+}
+
+/// Typed scalar query parameter of a backend route (`limit: int = 20`,
+/// `active: Annotated[bool, Query(strict=True)]`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QueryParameter {
+    /// Name in the query string (the `alias` when given)
+    pub name: String,
+    /// Type the value is validated as
+    pub param_type: QueryParamType,
+    /// Validated in strict mode (`StrictInt`, `Query(strict=True)`, `Strict()`), which
+    /// rejects the strings query values always are
+    pub strict: bool,
+}
@@ -0,0 +1,345 @@
+use crate::models::{
+    MismatchType, Severity, AMBIGUOUS_MODEL_RULE, CONTENT_TYPE_RULE, DEPRECATED_USAGE_RULE,
+    MISSING_HEADER_RULE, OPERATION_ID_RULE, PAGINATION_SHAPE_RULE, PARSE_ERROR_RULE,
+    QUERY_COERCION_RULE, SHARED_SCHEMA_RULE, STALE_EXAMPLE_RULE, TRAILING_SLASH_RULE,
+    UNRESOLVED_TYPE_RULE,
+};
+
+/// Page documenting every rule; a rule's section is `{RULES_DOCS_URL}#<rule id>`
+pub const RULES_DOCS_URL: &str = "https://github.com/Sett11/dc-verifier/blob/main/docs/rules.md";
+
+/// Human-readable description of a rule, shown by `explain` and linked from reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleInfo {
+    /// Rule identifier as it appears in reports (`type_mismatch`, `parse-error`)
+    pub id: &'static str,
+    /// One-line summary
+    pub title: &'static str,
+    /// Severity the rule's findings are reported with by default
+    pub default_severity: Severity,
+    /// What the rule checks
+    pub description: &'static str,
+    /// Why a violation matters
+    pub rationale: &'static str,
+    /// How a typical violation is fixed
+    pub example_fix: &'static str,
+}
+
+/// Metadata of all rules reported by the checker, keyed by `id`
+pub const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        id: "type_mismatch",
+        title: "Field types differ between two links",
+        default_severity: Severity::Critical,
+        description: "A field present on both sides of a contract has incompatible types \
+            (e.g. `number` on the frontend, `str` in the Pydantic model), or a tagged union \
+            is discriminated by a different field or accepts different tags.",
+        rationale: "The receiving side rejects or silently coerces the value, which surfaces \
+            as validation errors (422) or corrupted data at runtime.",
+        example_fix: "Align the declarations: `price: z.number()` on the frontend and \
+            `price: float` in the model, or add a custom equivalence to \
+            `[type_compatibility]` when the types are known to be compatible.",
+    },
+    RuleInfo {
+        id: "missing_field",
+        title: "Required field is not provided",
+        default_severity: Severity::Warning,
+        description: "The receiving schema requires a field the sending schema doesn't \
+            declare (or declares as optional).",
+        rationale: "Requests without the field fail validation; responses without it break \
+            consumers that rely on it.",
+        example_fix: "Add the field to the sender (`title: z.string()`), or give it a default \
+            in the receiver (`title: str = \"\"`).",
+    },
+    RuleInfo {
+        id: "extra_field",
+        title: "Field is sent but not accepted",
+        default_severity: Severity::Warning,
+        description: "The sending schema declares a field the receiving schema doesn't know. \
+            Unknown fields are checked against Pydantic models configuring `extra`: high severity with \
+            `forbid`, low with `allow` or `ignore`. A request field named after the Python \
+            attribute of an aliased Pydantic field (`Field(alias=...)`) is reported too.",
+        rationale: "The value is dropped or rejected (`extra=\"forbid\"`), so the sender's \
+            data never reaches its destination.",
+        example_fix: "Remove the field from the request type, or declare it in the receiving \
+            model.",
+    },
+    RuleInfo {
+        id: "validation_mismatch",
+        title: "Validation constraints differ",
+        default_severity: Severity::Warning,
+        description: "Both sides validate a field with different constraints (min/max \
+            length, bounds, patterns, array sizes).",
+        rationale: "Values accepted by the frontend are rejected by the backend, producing \
+            errors the UI doesn't anticipate.",
+        example_fix: "Use the same bounds on both sides: `z.string().max(50)` and \
+            `Field(max_length=50)`.",
+    },
+    RuleInfo {
+        id: "unnormalized_data",
+        title: "Data is not normalized before storage",
+        default_severity: Severity::Warning,
+        description: "Data flows into the database without the normalization its format \
+            needs (e.g. emails not lower-cased).",
+        rationale: "Unnormalized values break uniqueness checks and lookups.",
+        example_fix: "Normalize in a validator: `@field_validator(\"email\")` returning \
+            `value.lower()`.",
+    },
+    RuleInfo {
+        id: "missing_schema",
+        title: "Data is not validated by a schema",
+        default_severity: Severity::Warning,
+        description: "A link passes untyped data (`dict[str, Any]`, `any`) where a schema is \
+            expected.",
+        rationale: "Without a schema nothing checks the shape of the data, so contract \
+            issues go unnoticed.",
+        example_fix: "Replace `payload: dict` with a Pydantic model, or type the frontend \
+            value with an interface or Zod schema.",
+    },
+    RuleInfo {
+        id: "default_mismatch",
+        title: "Field defaults differ between two links",
+        default_severity: Severity::Warning,
+        description: "Both sides give a field a literal default (`Field(default=10)` in the \
+            model, `z.number().default(10)` on the frontend, `default` in JSON Schema) and the \
+            values differ. Computed defaults and fields defaulted on one side only are not \
+            compared.",
+        rationale: "An omitted value means something different to each side, so the UI shows \
+            one value while the backend stores another.",
+        example_fix: "Use the same default on both sides: `limit: int = Field(default=20)` and \
+            `limit: z.number().default(20)`.",
+    },
+    RuleInfo {
+        id: "response_only_field",
+        title: "Response-only field is sent by the client",
+        default_severity: Severity::Warning,
+        description: "A frontend request schema (Zod or TypeScript) sends a field the backend \
+            generates itself: `readOnly` in OpenAPI, `Field(frozen=True)`, or a field only the \
+            read half of a model split declares (`ItemCreate`/`ItemRead`, `ItemIn`/`ItemOut`).",
+        rationale: "The backend ignores or rejects the value, so the client believes it sets \
+            data (an `id`, a `created_at`) that the server overwrites.",
+        example_fix: "Drop the field from the request type, e.g. type the form as \
+            `Omit<Item, \"id\" | \"created_at\">`.",
+    },
+    RuleInfo {
+        id: PARSE_ERROR_RULE,
+        title: "File could not be parsed",
+        default_severity: Severity::Warning,
+        description: "A source file has a syntax error and was skipped.",
+        rationale: "Routes and schemas declared in the file are missing from the analysis, \
+            which may hide contract issues.",
+        example_fix: "Fix the syntax error at the reported line, or run with \
+            `--strict-parse` to fail fast.",
+    },
+    RuleInfo {
+        id: DEPRECATED_USAGE_RULE,
+        title: "Deprecated route or field is still used",
+        default_severity: Severity::Info,
+        description: "The frontend calls a route or reads a field the backend marks as \
+            deprecated.",
+        rationale: "Deprecated APIs are scheduled for removal; usages break when they go.",
+        example_fix: "Migrate the call to the replacement route or field named in the \
+            deprecation notice.",
+    },
+    RuleInfo {
+        id: UNRESOLVED_TYPE_RULE,
+        title: "Frontend type could not be resolved",
+        default_severity: Severity::Warning,
+        description: "Reported with `check --strict-types` only: a frontend type used in a \
+            contract could not be resolved to a schema with fields, so the contract comparison \
+            skipped it.",
+        rationale: "Contracts involving the type are never checked, which hides gaps in the \
+            frontend analysis.",
+        example_fix: "Declare the type as an interface or Zod schema inside the configured \
+            `src_paths`, or annotate the call with it (`api.post<UserCreate>(...)`).",
+    },
+    RuleInfo {
+        id: STALE_EXAMPLE_RULE,
+        title: "Documented example no longer fits its field",
+        default_severity: Severity::Info,
+        description: "An example documented for a field (`Field(examples=[...])`, OpenAPI \
+            `example`/`examples`) doesn't validate against the field's current type, bounds or \
+            enum values. Only literal examples are checked.",
+        rationale: "Examples are part of the documented contract; a stale one misleads API \
+            consumers and generated clients or mocks built from it.",
+        example_fix: "Update the example after the type change: \
+            `quantity: int = Field(examples=[3])` instead of `examples=[\"three\"]`.",
+    },
+    RuleInfo {
+        id: SHARED_SCHEMA_RULE,
+        title: "Frontend and backend disagree on a shared schema",
+        default_severity: Severity::Warning,
+        description: "A frontend call and the backend route it reaches don't use the same \
+            declaration of a schema: the route takes another schema than the one the frontend \
+            imports from a module shared with the backend, or each side declares its own copy \
+            of the schema and the copies' fields differ. Schemas both sides import from one \
+            declaration are trusted and not compared.",
+        rationale: "Shared schema modules are the single source of truth of the contract; a \
+            side that bypasses or copies them drifts without any type error.",
+        example_fix: "Import the schema from the shared module on both sides \
+            (`import { CreateUser } from \"@shared/user\"`) and delete the local copy.",
+    },
+    RuleInfo {
+        id: OPERATION_ID_RULE,
+        title: "OpenAPI operation ID is duplicated or doesn't map to a route",
+        default_severity: Severity::Warning,
+        description: "Operation IDs declared on FastAPI routes (`operation_id=\"listUsers\"`) \
+            or in the committed OpenAPI schema are used more than once, a route's operation ID \
+            is missing from the schema, or a schema operation ID belongs to no route. Routes \
+            without an explicit operation ID are matched to the schema by method and path.",
+        rationale: "Generated clients name their methods after operation IDs: duplicates \
+            collide, and IDs out of sync with the code produce clients calling removed or \
+            renamed handlers.",
+        example_fix: "Give each route a unique `operation_id` and regenerate the committed \
+            schema after renaming one.",
+    },
+    RuleInfo {
+        id: MISSING_HEADER_RULE,
+        title: "Required header is not sent",
+        default_severity: Severity::Warning,
+        description: "A backend route requires a header (a FastAPI `Header(...)` parameter \
+            without a default) and the frontend call reaching it doesn't set it in a literal \
+            `headers` object. Calls whose headers are held in a variable, spread from another \
+            object or may be added by an API client (`api.get(...)`, `axios.get(...)` without \
+            `headers`) are not checked.",
+        rationale: "Requests without the header are rejected with a 422 (or 401 for \
+            authentication headers) that only shows up at runtime.",
+        example_fix: "Send the header: `fetch(\"/orders\", { headers: { \"X-Api-Key\": key } })`, \
+            or give the parameter a default (`x_api_key: str | None = Header(None)`).",
+    },
+    RuleInfo {
+        id: TRAILING_SLASH_RULE,
+        title: "Call relies on a trailing-slash redirect",
+        default_severity: Severity::Warning,
+        description: "Reported with `strict_trailing_slash = true` only: a frontend call \
+            spells its URL with a trailing slash the route doesn't declare (`/users/` for \
+            `@app.get(\"/users\")`) or without one the route declares, so it only reaches the \
+            route through FastAPI's redirect.",
+        rationale: "The redirect costs a round trip, and some clients drop the request body \
+            or credentials when following it, or fail on a redirect to another origin.",
+        example_fix: "Spell the URL exactly as the route: `fetch(\"/users\")` for \
+            `@app.get(\"/users\")`.",
+    },
+    RuleInfo {
+        id: CONTENT_TYPE_RULE,
+        title: "Request body sent in a media type the route doesn't read",
+        default_severity: Severity::Warning,
+        description: "A frontend call sends its body as JSON, a form or multipart data, and \
+            the backend route it reaches reads another encoding: `Form(...)` parameters read \
+            `application/x-www-form-urlencoded` (or multipart), `File(...)`/`UploadFile` \
+            parameters `multipart/form-data` and body models `application/json`.",
+        rationale: "The backend can't decode the body and rejects the request (415 or 422), \
+            which no field-level check reveals.",
+        example_fix: "Send the encoding the route reads: `body: new FormData(form)` for a \
+            `Form(...)` route, or accept a JSON model on the backend.",
+    },
+    RuleInfo {
+        id: AMBIGUOUS_MODEL_RULE,
+        title: "Model name is declared by several modules",
+        default_severity: Severity::Warning,
+        description: "Several modules declare a Pydantic model of the same name with \
+            different fields, and a file references the name without declaring or importing \
+            one of them, so the checker can't tell which model is meant.",
+        rationale: "The contract may be checked against the wrong model, hiding real \
+            mismatches or reporting ones that don't exist.",
+        example_fix: "Import the model explicitly (`from app.admin.schemas import User`) or \
+            give the models distinct names (`AdminUser`).",
+    },
+    RuleInfo {
+        id: PAGINATION_SHAPE_RULE,
+        title: "Paginated responses disagree on their envelope",
+        default_severity: Severity::Warning,
+        description: "Paginated routes (a `response_model` wrapped in a generic model such as \
+            `Page[Item]`, or a response model with a list field and `total`, `page`, `size`, \
+            `limit`, `next`, `cursor`, ...) don't all return the same envelope fields: routes \
+            returning another envelope than most paginated routes are reported, and so are \
+            frontend calls reading pagination fields their route's envelope doesn't return.",
+        rationale: "Clients share pagination code across endpoints; an endpoint with another \
+            envelope breaks it (an undefined `total`, a pager that never stops) without any \
+            type error.",
+        example_fix: "Return the common wrapper (`response_model=Page[User]`) instead of a \
+            hand-written `UserList` with `results` and `count`.",
+    },
+    RuleInfo {
+        id: QUERY_COERCION_RULE,
+        title: "Query parameter value the backend can't coerce",
+        default_severity: Severity::Warning,
+        description: "A frontend call sends a query parameter (in its URL, or in a literal \
+            `params` object) that the backend route reads as `int`, `float` or `bool` with a \
+            value Pydantic can't coerce to that type (`?page=first`, `active: \"enabled\"`), \
+            or sends a parameter the route validates in strict mode (`StrictInt`, \
+            `Query(strict=True)`), which rejects the strings query values always are. Values \
+            computed at runtime are only checked against strict parameters.",
+        rationale: "Query strings carry text only: FastAPI answers a value it can't coerce \
+            with 422, for every request the call makes.",
+        example_fix: "Send a value of the parameter's type (`?page=1`, `active: true`), or \
+            drop strict mode on query parameters (`active: bool = Query(False)`).",
+    },
+];
+
+impl RuleInfo {
+    /// Looks a rule up by id; `-` and `_` are interchangeable (`type-mismatch`)
+    pub fn find(id: &str) -> Option<&'static RuleInfo> {
+        let normalize = |id: &str| id.trim().to_lowercase().replace('-', "_");
+        let wanted = normalize(id);
+        RULES.iter().find(|rule| normalize(rule.id) == wanted)
+    }
+
+    /// Rule of a contract mismatch type
+    pub fn for_mismatch(mismatch_type: MismatchType) -> &'static RuleInfo {
+        Self::find(mismatch_type.rule_id()).expect("every mismatch type has a registered rule")
+    }
+
+    /// Link to the rule's documentation
+    pub fn docs_url(&self) -> String {
+        format!("{}#{}", RULES_DOCS_URL, self.id)
+    }
+}
+
+/// Documentation link of a rule id, if the rule is registered
+pub fn rule_docs_url(rule_id: &str) -> Option<String> {
+    RuleInfo::find(rule_id).map(RuleInfo::docs_url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_accepts_both_separators() {
+        assert_eq!(RuleInfo::find("type-mismatch").unwrap().id, "type_mismatch");
+        assert_eq!(RuleInfo::find("Parse_Error").unwrap().id, "parse-error");
+        assert!(RuleInfo::find("no-such-rule").is_none());
+    }
+
+    #[test]
+    fn test_every_mismatch_type_is_registered() {
+        for mismatch_type in [
+            MismatchType::TypeMismatch,
+            MismatchType::MissingField,
+            MismatchType::ExtraField,
+            MismatchType::ValidationMismatch,
+            MismatchType::UnnormalizedData,
+            MismatchType::MissingSchema,
+            MismatchType::DefaultMismatch,
+            MismatchType::ResponseOnlyField,
+        ] {
+            assert_eq!(
+                RuleInfo::for_mismatch(mismatch_type).id,
+                mismatch_type.rule_id()
+            );
+        }
+    }
+
+    #[test]
+    fn test_rules_are_documented() {
+        let docs = include_str!("../../../../docs/rules.md");
+        for rule in RULES {
+            assert!(
+                docs.contains(&format!("### {}\n", rule.id)),
+                "docs/rules.md has no section for {}",
+                rule.id
+            );
+        }
+    }
+}
@@ -15,6 +15,37 @@ pub struct SchemaReference {
     pub metadata: HashMap<String, String>,
 }
 
+impl SchemaReference {
+    /// Stable hash of what the schema declares, independent of where it's declared
+    ///
+    /// Covers the schema type and name, then each field sorted by name with its type,
+    /// optionality and constraints, as [`SchemaParser`](crate::analyzers::SchemaParser)
+    /// reads them. Moving a schema to another file or line keeps its hash, any change to
+    /// its fields gives another. Hex-encoded BLAKE3 digest.
+    pub fn content_hash(&self) -> String {
+        let mut content = format!("{:?}\n{}\n", self.schema_type, self.name);
+        if let Ok(schema) = crate::analyzers::SchemaParser::parse(self) {
+            let mut fields: Vec<_> = schema.properties.iter().collect();
+            fields.sort_by(|a, b| a.0.cmp(b.0));
+            for (name, field) in fields {
+                let constraints: Vec<String> = field
+                    .constraints
+                    .iter()
+                    .map(|constraint| serde_json::to_string(constraint).unwrap_or_default())
+                    .collect();
+                content.push_str(&format!(
+                    "{}:{}:{}:[{}]\n",
+                    name,
+                    field.field_type,
+                    field.optional,
+                    constraints.join(",")
+                ));
+            }
+        }
+        blake3::hash(content.as_bytes()).to_hex().to_string()
+    }
+}
+
 /// Schema type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SchemaType {
@@ -30,6 +61,8 @@ pub enum SchemaType {
     JsonSchema,
     /// ORM model (SQLAlchemy, etc.)
     OrmModel,
+    /// Enum class (Python `enum.Enum`, `IntEnum`, ...)
+    Enum,
 }
 
 /// Type information
@@ -66,6 +99,10 @@ pub enum Constraint {
     Min(ConstraintValue),
     /// Maximum length (for strings) or value (for numbers)
     Max(ConstraintValue),
+    /// Exclusive minimum value (`gt=`, JSON Schema `exclusiveMinimum`)
+    ExclusiveMin(ConstraintValue),
+    /// Exclusive maximum value (`lt=`, JSON Schema `exclusiveMaximum`)
+    ExclusiveMax(ConstraintValue),
     /// Regular expression (for strings)
     Pattern(String),
     /// Email validation
@@ -89,9 +126,67 @@ pub struct PydanticFieldInfo {
     pub name: String,
     pub type_name: String,
     pub inner_type: Option<String>, // For list[T], dict[K, V]
+    /// Annotation is `Optional[...]` or `... | None`
     pub optional: bool,
     pub constraints: Vec<FieldConstraint>,
     pub default_value: Option<String>,
+    /// Field is produced by the server and only appears in responses
+    /// (`Field(frozen=True)` or present only in the `Read` half of a Create/Read model split)
+    #[serde(default)]
+    pub response_only: bool,
+    /// Field declared with `Field(deprecated=...)`
+    #[serde(default)]
+    pub deprecated: bool,
+    /// Members and tag field of a `Field(discriminator=...)` union
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discriminated_union: Option<crate::models::DiscriminatedUnion>,
+    /// Wire name set with `Field(alias="...")`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    /// Name read from requests, set with `Field(validation_alias="...")` (Pydantic v2)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_alias: Option<String>,
+    /// Name written to responses, set with `Field(serialization_alias="...")` (Pydantic v2)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serialization_alias: Option<String>,
+    /// Literal values of `Field(examples=[...])` (documentation only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<serde_json::Value>,
+    /// `default_value` as a JSON value when it is a literal (`Field(default=10)` -> `10`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_literal: Option<serde_json::Value>,
+    /// The annotation accepts `None` (`Optional[T]`, `T | None`), unlike a field that may
+    /// only be omitted because it has a default
+    #[serde(default)]
+    pub nullable: bool,
+    /// Values the items of a list field are restricted to (`list[Literal["a", "b"]]`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item_values: Option<Vec<String>>,
+}
+
+impl PydanticFieldInfo {
+    /// Whether clients must provide the field: it is neither optional nor has a default
+    pub fn is_required(&self) -> bool {
+        !self.optional && self.default_value.is_none()
+    }
+
+    /// Name the field is read from in requests: `validation_alias`, then `alias`, then the
+    /// attribute name
+    pub fn input_name(&self) -> &str {
+        self.validation_alias
+            .as_deref()
+            .or(self.alias.as_deref())
+            .unwrap_or(&self.name)
+    }
+
+    /// Name the field is written under in responses: `serialization_alias`, then `alias`,
+    /// then the attribute name
+    pub fn output_name(&self) -> &str {
+        self.serialization_alias
+            .as_deref()
+            .or(self.alias.as_deref())
+            .unwrap_or(&self.name)
+    }
 }
 
 /// Constraint for a field
@@ -101,6 +196,10 @@ pub enum FieldConstraint {
     MaxLength(usize),
     MinValue(f64),
     MaxValue(f64),
+    /// `gt=`
+    ExclusiveMinValue(f64),
+    /// `lt=`
+    ExclusiveMaxValue(f64),
     Pattern(String),
     Email,
     Url,
@@ -112,6 +211,13 @@ pub struct SQLAlchemyField {
     pub name: String,
     pub type_name: String,
     pub nullable: bool,
+    /// Model a `relationship()` field refers to (None for columns)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_model: Option<String>,
+    /// Whether the relationship holds a collection of related models (one-to-many,
+    /// many-to-many) rather than one
+    #[serde(default)]
+    pub many: bool,
 }
 
 /// Information about a field in a Zod schema
@@ -121,6 +227,22 @@ pub struct ZodField {
     pub type_name: String,
     pub optional: bool,
     pub nullable: bool,
+    /// Schema referenced via `z.lazy(() => Schema)` (recursive structures),
+    /// kept by name so self-referential schemas are not expanded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_ref: Option<String>,
+    /// Minimum item count of `z.array(...)` (`.min(n)`, `.length(n)`, `.nonempty()`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_items: Option<usize>,
+    /// Maximum item count of `z.array(...)` (`.max(n)`, `.length(n)`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_items: Option<usize>,
+    /// Literal passed to `.default(...)` (`z.number().default(10)` -> `10`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<serde_json::Value>,
+    /// Values the items of `z.array(z.enum([...]))` are restricted to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item_values: Option<Vec<String>>,
 }
 
 /// Information about Zod schema usage
@@ -174,4 +296,6 @@ pub enum TransformationType {
     OrmToPydantic,
     /// Pydantic model → ORM model (bidirectional ORM bridge)
     PydanticToOrm,
+    /// Project-specific conversion method (e.g. `to_response()`), keeps the method name
+    Custom(String),
 }
@@ -0,0 +1,123 @@
+use std::collections::BTreeSet;
+
+/// Built-in equivalences between type names used on different layers
+/// (SQLAlchemy column types ↔ Python annotations)
+const DEFAULT_EQUIVALENCES: &[(&str, &str)] = &[
+    ("integer", "int"),
+    ("string", "str"),
+    ("text", "str"),
+    ("boolean", "bool"),
+    ("numeric", "float"),
+    ("decimal", "float"),
+];
+
+/// Table of type names considered compatible across layers
+///
+/// Starts from the built-in defaults; projects add their own equivalences
+/// (e.g. a custom `Money` type ↔ `string`). Names are compared case-insensitively and
+/// every equivalence works in both directions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeCompatibility {
+    /// Normalized pairs, stored in both orders
+    pairs: BTreeSet<(String, String)>,
+}
+
+impl TypeCompatibility {
+    /// Creates the default matrix
+    pub fn new() -> Self {
+        let mut compatibility = Self {
+            pairs: BTreeSet::new(),
+        };
+        for (a, b) in DEFAULT_EQUIVALENCES {
+            compatibility.insert(a, b);
+        }
+        compatibility
+    }
+
+    /// Declares `a` and `b` equivalent
+    pub fn with_equivalence(mut self, a: &str, b: &str) -> Self {
+        self.insert(a, b);
+        self
+    }
+
+    /// Declares every type name of the table equivalent to each of its listed types
+    /// (`Money = ["string", "str"]`)
+    pub fn with_equivalences<I, V>(mut self, table: I) -> Self
+    where
+        I: IntoIterator<Item = (String, V)>,
+        V: IntoIterator<Item = String>,
+    {
+        for (name, equivalents) in table {
+            for equivalent in equivalents {
+                self.insert(&name, &equivalent);
+            }
+        }
+        self
+    }
+
+    /// Whether the two names are equal or declared equivalent (defaults or custom)
+    pub fn declared_equivalent(&self, a: &str, b: &str) -> bool {
+        let (a, b) = (normalize(a), normalize(b));
+        a == b || self.pairs.contains(&(a, b))
+    }
+
+    /// Whether the two types are compatible: declared equivalent, or in the same family
+    /// of UUID, date/time or JSON types
+    pub fn compatible(&self, a: &str, b: &str) -> bool {
+        if self.declared_equivalent(a, b) {
+            return true;
+        }
+        let (a, b) = (normalize(a), normalize(b));
+        let uuid = |t: &str| t.contains("uuid");
+        let temporal = |t: &str| t.contains("date") || t.contains("time");
+        let json = |t: &str| t.contains("json") || t == "dict" || t == "object";
+        (uuid(&a) && uuid(&b)) || (temporal(&a) && temporal(&b)) || (json(&a) && json(&b))
+    }
+
+    fn insert(&mut self, a: &str, b: &str) {
+        let (a, b) = (normalize(a), normalize(b));
+        self.pairs.insert((b.clone(), a.clone()));
+        self.pairs.insert((a, b));
+    }
+}
+
+impl Default for TypeCompatibility {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn normalize(type_name: &str) -> String {
+    type_name.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_matrix() {
+        let compatibility = TypeCompatibility::new();
+
+        assert!(compatibility.compatible("Integer", "int"));
+        assert!(compatibility.compatible("str", "Text"));
+        assert!(compatibility.compatible("DateTime", "datetime"));
+        assert!(compatibility.compatible("JSON", "dict"));
+        assert!(!compatibility.compatible("Integer", "str"));
+        assert!(!compatibility.declared_equivalent("DateTime", "date"));
+    }
+
+    #[test]
+    fn test_custom_equivalences_are_symmetric() {
+        let compatibility = TypeCompatibility::new().with_equivalences([(
+            "Money".to_string(),
+            vec!["string".to_string(), "str".to_string()],
+        )]);
+
+        assert!(compatibility.declared_equivalent("Money", "string"));
+        assert!(compatibility.declared_equivalent("str", "money"));
+        assert!(!compatibility.declared_equivalent("Money", "number"));
+        // Defaults are kept
+        assert!(compatibility.declared_equivalent("Boolean", "bool"));
+    }
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+/// Tagged union whose members are told apart by a literal discriminator field
+///
+/// Pydantic: `Union[Cat, Dog] = Field(discriminator="pet_type")`,
+/// TypeScript: `type Pet = Cat | Dog` where every member declares `pet_type: "cat"`-like literals.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DiscriminatedUnion {
+    /// Name of the tag field (e.g. "pet_type")
+    pub discriminator: String,
+    /// Wire name of the tag field when it differs from `discriminator`
+    /// (Pydantic `pet_type: Literal["cat"] = Field(alias="petType")`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discriminator_alias: Option<String>,
+    /// Union members in declaration order
+    pub members: Vec<UnionMember>,
+}
+
+/// Single member of a discriminated union
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UnionMember {
+    /// Schema name of the member (e.g. "Cat")
+    pub schema: String,
+    /// Literal value of the discriminator field, None if it could not be resolved
+    pub tag: Option<String>,
+    /// Member that is itself a discriminated union (nested unions)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nested: Option<Box<DiscriminatedUnion>>,
+}
+
+impl DiscriminatedUnion {
+    /// Name of the tag field as sent over the wire
+    pub fn wire_discriminator(&self) -> &str {
+        self.discriminator_alias
+            .as_deref()
+            .unwrap_or(&self.discriminator)
+    }
+
+    /// Finds the member matching another union's member: by tag when both are known,
+    /// by schema name otherwise
+    pub fn counterpart(&self, member: &UnionMember) -> Option<&UnionMember> {
+        match &member.tag {
+            Some(tag) => self
+                .members
+                .iter()
+                .find(|m| m.tag.as_deref() == Some(tag.as_str()))
+                .or_else(|| {
+                    self.members
+                        .iter()
+                        .find(|m| m.tag.is_none() && m.schema == member.schema)
+                }),
+            None => self.members.iter().find(|m| m.schema == member.schema),
+        }
+    }
+}
@@ -128,7 +128,11 @@ impl OpenAPILinker {
 
         // Check each Pydantic field against OpenAPI properties
         for pydantic_field in pydantic_fields {
-            if let Some(openapi_type) = openapi_map.get(pydantic_field.name.as_str()) {
+            // OpenAPI documents fields by their aliases
+            let openapi_type = openapi_map
+                .get(pydantic_field.input_name())
+                .or_else(|| openapi_map.get(pydantic_field.output_name()));
+            if let Some(openapi_type) = openapi_type {
                 // Field name matches, check type compatibility
                 if Self::types_compatible_openapi(&pydantic_field.type_name, openapi_type) {
                     matches += 1;
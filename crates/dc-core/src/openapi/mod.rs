@@ -1,5 +1,7 @@
 pub mod linker;
 pub mod parser;
+#[cfg(feature = "remote-openapi")]
+mod remote;
 pub mod schema;
 
 pub use linker::*;
@@ -4,11 +4,67 @@ use serde_json;
 use serde_yaml;
 use std::collections::HashMap;
 use std::path::Path;
+use std::time::Duration;
+
+/// Default timeout for fetching a spec from a URL
+pub const DEFAULT_FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How a spec given as an `http(s)://` URL is fetched
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FetchOptions {
+    /// Timeout of the whole request
+    pub timeout: Duration,
+    /// Accept invalid (e.g. self-signed) TLS certificates
+    pub insecure: bool,
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        Self {
+            timeout: DEFAULT_FETCH_TIMEOUT,
+            insecure: false,
+        }
+    }
+}
 
 /// Parser for OpenAPI schema files
 pub struct OpenAPIParser;
 
 impl OpenAPIParser {
+    /// Whether an OpenAPI source is an `http://` or `https://` URL rather than a file path
+    pub fn is_url(source: &str) -> bool {
+        let lower = source.trim().to_ascii_lowercase();
+        lower.starts_with("http://") || lower.starts_with("https://")
+    }
+
+    /// Parses an OpenAPI spec from a file path or an `http(s)://` URL
+    ///
+    /// URLs are fetched only when built with the `remote-openapi` feature; timeouts and
+    /// non-200 responses are reported as errors.
+    pub fn parse_source(source: &str, options: &FetchOptions) -> Result<OpenAPISchema> {
+        if Self::is_url(source) {
+            let content = Self::fetch(source.trim(), options)?;
+            Self::parse_str(&content)
+                .with_context(|| format!("Failed to parse OpenAPI spec fetched from {}", source))
+        } else {
+            Self::parse_file(Path::new(source))
+        }
+    }
+
+    #[cfg(feature = "remote-openapi")]
+    fn fetch(url: &str, options: &FetchOptions) -> Result<String> {
+        crate::openapi::remote::fetch(url, options)
+    }
+
+    #[cfg(not(feature = "remote-openapi"))]
+    fn fetch(url: &str, _options: &FetchOptions) -> Result<String> {
+        anyhow::bail!(
+            "Cannot fetch OpenAPI spec from {}: built without the `remote-openapi` feature \
+             (rebuild with `--features remote-openapi`)",
+            url
+        )
+    }
+
     /// Parses an OpenAPI file (JSON or YAML)
     pub fn parse_file(openapi_path: &Path) -> Result<OpenAPISchema> {
         let content = std::fs::read_to_string(openapi_path)
@@ -454,3 +510,44 @@ impl OpenAPIParser {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_url() {
+        assert!(OpenAPIParser::is_url(
+            "https://api.example.com/openapi.json"
+        ));
+        assert!(OpenAPIParser::is_url("HTTP://localhost:8000/openapi.json"));
+        assert!(!OpenAPIParser::is_url("openapi.json"));
+        assert!(!OpenAPIParser::is_url("/srv/http/openapi.yaml"));
+    }
+
+    #[test]
+    fn test_parse_source_reads_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("openapi.json");
+        std::fs::write(
+            &path,
+            r#"{"openapi": "3.0.0", "info": {"title": "t", "version": "1"}, "paths": {}}"#,
+        )
+        .unwrap();
+
+        let schema = OpenAPIParser::parse_source(&path.to_string_lossy(), &FetchOptions::default());
+        assert!(schema.is_ok(), "{:?}", schema.err());
+    }
+
+    #[cfg(not(feature = "remote-openapi"))]
+    #[test]
+    fn test_url_requires_remote_feature() {
+        let err = OpenAPIParser::parse_source(
+            "https://api.example.com/openapi.json",
+            &FetchOptions::default(),
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("remote-openapi"), "{}", err);
+    }
+}
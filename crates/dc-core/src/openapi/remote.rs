@@ -0,0 +1,132 @@
+use crate::openapi::FetchOptions;
+use anyhow::{bail, Context, Result};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{ring, CryptoProvider};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{DigitallySignedStruct, SignatureScheme};
+use std::io::Read;
+use std::sync::Arc;
+
+/// Downloads the spec served at `url`
+pub(crate) fn fetch(url: &str, options: &FetchOptions) -> Result<String> {
+    let mut agent = ureq::AgentBuilder::new().timeout(options.timeout);
+    if options.insecure {
+        agent = agent.tls_config(Arc::new(insecure_tls_config()?));
+    }
+
+    let response = match agent.build().get(url).call() {
+        Ok(response) => response,
+        Err(ureq::Error::Status(code, response)) => bail!(
+            "Failed to fetch OpenAPI spec from {}: HTTP {} {}",
+            url,
+            code,
+            response.status_text()
+        ),
+        Err(ureq::Error::Transport(transport)) if is_timeout(&transport) => bail!(
+            "Timed out after {}s fetching OpenAPI spec from {}",
+            options.timeout.as_secs(),
+            url
+        ),
+        Err(ureq::Error::Transport(transport)) => {
+            let hint = if transport.kind() == ureq::ErrorKind::ConnectionFailed
+                && url.starts_with("https://")
+                && !options.insecure
+            {
+                " (self-signed certificate? set `openapi_insecure = true` or pass --insecure)"
+            } else {
+                ""
+            };
+            bail!(
+                "Failed to fetch OpenAPI spec from {}: {}{}",
+                url,
+                transport,
+                hint
+            )
+        }
+    };
+
+    let mut body = String::new();
+    response
+        .into_reader()
+        .read_to_string(&mut body)
+        .with_context(|| format!("Failed to read OpenAPI spec from {}", url))?;
+    Ok(body)
+}
+
+fn is_timeout(transport: &ureq::Transport) -> bool {
+    let mut source = std::error::Error::source(transport);
+    while let Some(err) = source {
+        if let Some(io_err) = err.downcast_ref::<std::io::Error>() {
+            if matches!(
+                io_err.kind(),
+                std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock
+            ) {
+                return true;
+            }
+        }
+        source = err.source();
+    }
+    false
+}
+
+/// TLS configuration accepting any server certificate (internal self-signed deployments)
+fn insecure_tls_config() -> Result<rustls::ClientConfig> {
+    let provider = Arc::new(ring::default_provider());
+    Ok(
+        rustls::ClientConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()
+            .context("Failed to configure TLS")?
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(AcceptAnyCertificate(provider)))
+            .with_no_client_auth(),
+    )
+}
+
+/// Skips certificate validation; handshake signatures are still checked
+#[derive(Debug)]
+struct AcceptAnyCertificate(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for AcceptAnyCertificate {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
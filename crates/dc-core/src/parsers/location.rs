@@ -1,5 +1,13 @@
 /// Converter from byte offsets to line and column numbers
-/// Used for accurate conversion of TextSize from rustpython-parser to Location
+/// Used for accurate conversion of TextSize from rustpython-parser and swc spans to Location
+///
+/// Line start offsets are computed once in `new` (O(n) in the source length); each lookup is a
+/// binary search over them plus a scan of the target line, O(log lines + line length).
+/// Build one converter per file and reuse it for every node of that file.
+///
+/// Lines are split on `\n`, so CRLF sources work unchanged (the `\r` is the last character of
+/// its line). Columns count characters, not bytes, so multi-byte UTF-8 text before an offset
+/// does not shift the reported column.
 pub struct LocationConverter {
     source: String,
     line_starts: Vec<usize>,
@@ -7,7 +15,8 @@ pub struct LocationConverter {
 
 impl LocationConverter {
     /// Creates a new LocationConverter from source code
-    pub fn new(source: String) -> Self {
+    pub fn new(source: impl Into<String>) -> Self {
+        let source = source.into();
         let line_starts = Self::calculate_line_starts(&source);
         Self {
             source,
@@ -15,18 +24,22 @@ impl LocationConverter {
         }
     }
 
+    /// Source code the offsets refer to
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Number of lines in the source (an empty source has one empty line)
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
     /// Converts byte offset to line and column number (1-based)
+    ///
+    /// Offsets past the end of the source resolve to the end of the last line; offsets inside
+    /// a multi-byte character resolve to that character.
     pub fn byte_offset_to_location(&self, offset: usize) -> (usize, usize) {
-        if offset > self.source.len() {
-            // If offset is out of bounds, return the last line
-            let last_line = self.line_starts.len().max(1);
-            let last_col = self
-                .source
-                .len()
-                .saturating_sub(*self.line_starts.last().unwrap_or(&0))
-                .max(1);
-            return (last_line, last_col);
-        }
+        let offset = offset.min(self.source.len());
 
         // Binary search for the line containing offset
         let (line, line_start_pos) = match self.line_starts.binary_search(&offset) {
@@ -47,8 +60,18 @@ impl LocationConverter {
             }
         };
 
-        let column = offset.saturating_sub(line_start_pos) + 1;
-        (line, column)
+        let bytes = self.source.as_bytes();
+        let is_continuation = |byte: u8| byte & 0xC0 == 0x80;
+        let mut chars_before = bytes[line_start_pos..offset]
+            .iter()
+            .filter(|byte| !is_continuation(**byte))
+            .count();
+        if bytes.get(offset).copied().is_some_and(is_continuation) {
+            // Offset points into the middle of a character started before it
+            chars_before -= 1;
+        }
+
+        (line, chars_before + 1)
     }
 
     /// Calculates the start positions of each line (in bytes)
@@ -106,4 +129,30 @@ mod tests {
         assert!(line >= 1);
         assert!(col >= 1);
     }
+
+    #[test]
+    fn test_crlf_line_endings() {
+        let converter = LocationConverter::new("a = 1\r\nb = 2\r\n");
+
+        assert_eq!(converter.line_count(), 3);
+        // `\r` stays on the first line
+        assert_eq!(converter.byte_offset_to_location(5), (1, 6));
+        // `b` is right after `\r\n`
+        assert_eq!(converter.byte_offset_to_location(7), (2, 1));
+        assert_eq!(converter.byte_offset_to_location(11), (2, 5));
+    }
+
+    #[test]
+    fn test_multi_byte_utf8_columns() {
+        // "é" is 2 bytes, "😀" is 4 bytes
+        let source = "x = \"é😀\"\ny = 1";
+        let converter = LocationConverter::new(source);
+
+        // Closing quote: byte 11, 8th character
+        assert_eq!(converter.byte_offset_to_location(11), (1, 8));
+        // Offset inside the emoji resolves to the emoji itself
+        assert_eq!(converter.byte_offset_to_location(8), (1, 7));
+        let y = source.find('y').unwrap();
+        assert_eq!(converter.byte_offset_to_location(y), (2, 1));
+    }
 }
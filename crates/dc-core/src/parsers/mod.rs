@@ -43,6 +43,62 @@ pub struct Call {
     pub uses_optional_chaining: bool,
 }
 
+/// Comparison of a response status with a literal code (`res.status === 404`)
+#[derive(Debug, Clone)]
+pub struct StatusCheck {
+    /// Handled status code or range
+    pub status: crate::models::HandledStatus,
+    /// Name of function/method containing the comparison (same convention as [`Call::caller`])
+    pub caller: Option<String>,
+    /// Location in code
+    pub location: crate::models::Location,
+}
+
+/// Request body an HTTP call serializes (`body: JSON.stringify(payload)`)
+#[derive(Debug, Clone)]
+pub struct SerializedBody {
+    /// Frontend schema of the serialized value
+    pub schema: crate::models::SchemaReference,
+    /// Location of the HTTP call (same as its [`Call::location`])
+    pub location: crate::models::Location,
+}
+
+/// Route registered on an Express app or router (`router.post("/users", createUser)`)
+#[derive(Debug, Clone)]
+pub struct ExpressRoute {
+    /// HTTP method as registered (`get`, `post`, ...)
+    pub method: String,
+    /// Route path (`/users/:id`)
+    pub path: String,
+    /// Name of the handler when it is a named function (`createUser`)
+    pub handler: Option<String>,
+    /// Type the handler reads `req.body` as
+    pub request_type: Option<String>,
+    /// Type of the value the handler responds with
+    pub response_type: Option<String>,
+    /// Location of the registration
+    pub location: crate::models::Location,
+}
+
+/// HTTP request made by frontend code (`axios.post("/users", payload)`, `fetch("/items")`)
+#[derive(Debug, Clone)]
+pub struct HttpCall {
+    /// HTTP method (`GET` when `fetch`/`axios(config)` sets none)
+    pub method: crate::call_graph::HttpMethod,
+    /// Literal URL, or the static prefix of a template literal (`/users/` for
+    /// `` `/users/${id}` ``); `None` when the URL is not a literal or starts with a substitution
+    pub url_literal: Option<String>,
+    /// Whether `url_literal` is only the static prefix of a template literal
+    pub url_is_prefix: bool,
+    /// Type of the request body: a typed variable or parameter (`payload: CreateUser`), an
+    /// `as` cast, or the request type argument of `axios.post<Res, AxiosResponse<Res>, Req>`
+    pub request_type: Option<String>,
+    /// First type argument of the call (`User[]` for `axios.get<User[]>(...)`)
+    pub response_generic: Option<String>,
+    /// Location of the call
+    pub location: crate::models::Location,
+}
+
 /// Function call argument
 #[derive(Debug, Clone)]
 pub struct CallArgument {
@@ -5,8 +5,8 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use tracing::warn;
 
-use crate::call_graph::CallNode;
-use crate::models::{ImportError, Location};
+use crate::call_graph::{CallNode, HttpMethod, RoutePattern};
+use crate::models::{ImportError, Location, QueryParamType, QueryParameter};
 
 /// Determines if the given module name represents an external dependency
 /// by inspecting requirements.txt and pyproject.toml in the project root.
@@ -123,10 +123,61 @@ fn find_module_in_venv(module_name: &str, venv_path: &Path) -> Option<PathBuf> {
 }
 use crate::parsers::{Call, CallArgument, Import, LocationConverter};
 
+/// Model metadata key listing the names a model references through string annotations
+/// (comma-separated, as written: `B,models.C`)
+pub const FORWARD_REFS_METADATA_KEY: &str = "forward_refs";
+
+/// Model metadata key set to `true` when a model transforms its raw input before field
+/// validation (`@model_validator(mode="before")`, `@root_validator(pre=True)`), so the wire
+/// shape it accepts may differ from its fields
+pub const PRE_VALIDATOR_METADATA_KEY: &str = "pre_validator";
+
+/// Enum metadata key holding the members of an enum class as a JSON array of
+/// `{"name": "RED", "value": "red"}` objects (`value` is null when it isn't a literal,
+/// as with `auto()`)
+pub const ENUM_VARIANTS_METADATA_KEY: &str = "variants";
+
+/// Base classes making a class an enum
+const ENUM_BASES: &[&str] = &["Enum", "IntEnum", "StrEnum", "Flag", "IntFlag"];
+
+/// Capitalized typing names that are never forward references to models
+const TYPING_NAMES: &[&str] = &[
+    "Optional",
+    "Union",
+    "List",
+    "Dict",
+    "Set",
+    "FrozenSet",
+    "Tuple",
+    "Sequence",
+    "Mapping",
+    "Any",
+    "None",
+    "Literal",
+    "Annotated",
+    "Callable",
+    "Type",
+];
+
+/// Response classes serializing their `content` as JSON
+const RESPONSE_CLASSES: &[&str] = &["JSONResponse", "ORJSONResponse", "UJSONResponse"];
+
+/// Starlette/FastAPI response classes whose body isn't JSON, with the media type they send
+const NON_JSON_RESPONSE_CLASSES: &[(&str, &str)] = &[
+    ("HTMLResponse", "text/html"),
+    ("PlainTextResponse", "text/plain"),
+    ("FileResponse", "application/octet-stream"),
+    ("StreamingResponse", "application/octet-stream"),
+];
+
 /// Python code parser with call analysis
 pub struct PythonParser {
     /// Cache for resolved imports: (module, normalized project_root) -> resolved path or None
     import_cache: HashMap<(String, Option<String>), Option<PathBuf>>,
+    /// App/router variables whose decorators declare routes (empty = name heuristics)
+    route_objects: Vec<String>,
+    /// Project-specific route decorators recognized in addition to the built-in ones
+    route_patterns: Vec<RoutePattern>,
 }
 
 impl PythonParser {
@@ -134,9 +185,31 @@ impl PythonParser {
     pub fn new() -> Self {
         Self {
             import_cache: HashMap::new(),
+            route_objects: Vec::new(),
+            route_patterns: Vec::new(),
         }
     }
 
+    /// Restricts route decorators to the given app/router variables
+    /// (e.g. `application` for `application = FastAPI()`)
+    ///
+    /// With an empty list, common names (`app`, `router`, `api_router`, ...) are recognized.
+    pub fn set_route_objects(&mut self, route_objects: Vec<String>) {
+        self.route_objects = route_objects;
+    }
+
+    /// Recognizes route decorators of the given shapes in addition to the built-in ones
+    pub fn set_route_patterns(&mut self, route_patterns: Vec<RoutePattern>) {
+        self.route_patterns = route_patterns;
+    }
+
+    /// HTTP method declared by a decorator matching one of the registered route patterns
+    pub fn route_pattern_method(&self, name: &str) -> Option<HttpMethod> {
+        self.route_patterns
+            .iter()
+            .find_map(|pattern| pattern.http_method(name))
+    }
+
     /// Parses a file and extracts call nodes
     /// Note: This method is not currently used directly. CallGraphBuilder works directly with AST.
     pub fn parse_file(&self, _path: &Path) -> Result<Vec<CallNode>> {
@@ -257,6 +330,17 @@ impl PythonParser {
                     }
                 }
             }
+            // `if TYPE_CHECKING:` imports exist only for annotations (usually forward refs)
+            ast::Stmt::If(if_stmt)
+                if matches!(
+                    self.expr_to_string(&if_stmt.test).as_str(),
+                    "TYPE_CHECKING" | "typing.TYPE_CHECKING"
+                ) =>
+            {
+                for body_stmt in &if_stmt.body {
+                    self.extract_imports_from_stmt(body_stmt, imports, file_path, converter);
+                }
+            }
             _ => {}
         }
     }
@@ -308,13 +392,14 @@ impl PythonParser {
         if let ast::Mod::Module(module) = ast {
             for stmt in &module.body {
                 if let ast::Stmt::ClassDef(class_def) = stmt {
-                    // Check if class inherits from BaseModel
-                    if self.is_pydantic_base_model(&class_def.bases) {
+                    // Check if class inherits from BaseModel, possibly through models of the file
+                    if self.is_pydantic_model_in(class_def, ast, &mut Vec::new()) {
                         let mut metadata = std::collections::HashMap::new();
 
                         // Extract field information using new structured format
                         let mut fields = Vec::new();
-                        let mut has_from_attributes = false;
+                        let mut forward_refs: Vec<String> = Vec::new();
+                        let mut has_pre_validator = false;
 
                         // Extract fields from current class
                         for body_stmt in &class_def.body {
@@ -323,43 +408,34 @@ impl PythonParser {
                                     if let Ok(field_info) = self.extract_field_info(ann_assign) {
                                         fields.push(field_info);
                                     }
+                                    for name in self.forward_refs(&ann_assign.annotation) {
+                                        if !forward_refs.contains(&name) {
+                                            forward_refs.push(name);
+                                        }
+                                    }
                                 }
-                                ast::Stmt::Assign(assign_stmt) => {
-                                    // Check for model_config = {"from_attributes": True, ...}
-                                    if let Some(ast::Expr::Name(name)) = assign_stmt.targets.first()
+                                ast::Stmt::FunctionDef(func_def) => {
+                                    if let Some(field_info) = self.extract_computed_field(func_def)
                                     {
-                                        if name.id.as_str() == "model_config" {
-                                            if let Some(config_dict) =
-                                                self.extract_model_config(&assign_stmt.value)
-                                            {
-                                                // Store all model_config keys in metadata
-                                                for (key, value) in &config_dict {
-                                                    metadata.insert(
-                                                        format!("model_config.{}", key),
-                                                        value.clone(),
-                                                    );
-                                                }
-
-                                                // Check the value for from_attributes (for backward compatibility)
-                                                if let Some(value) =
-                                                    config_dict.get("from_attributes")
-                                                {
-                                                    // Accept True, "True", or true
-                                                    if value == "True"
-                                                        || value == "true"
-                                                        || value == &true.to_string()
-                                                    {
-                                                        has_from_attributes = true;
-                                                    }
-                                                }
-                                            }
-                                        }
+                                        fields.push(field_info);
+                                    }
+                                    if self.is_pre_validator(&func_def.decorator_list) {
+                                        has_pre_validator = true;
                                     }
                                 }
                                 _ => {}
                             }
                         }
 
+                        // model_config settings, the class's own and those of its base models
+                        let config = self.model_config(class_def, ast, &mut Vec::new());
+                        let has_from_attributes = config
+                            .get("from_attributes")
+                            .is_some_and(|value| value == "True" || value == "true");
+                        for (key, value) in config {
+                            metadata.insert(format!("model_config.{}", key), value);
+                        }
+
                         // Extract fields from base classes (recursively, within current file)
                         for base in &class_def.bases {
                             let base_name = self.extract_class_name_from_expr(base);
@@ -405,6 +481,19 @@ impl PythonParser {
                             metadata.insert("from_attributes".to_string(), "true".to_string());
                         }
 
+                        if has_pre_validator {
+                            metadata
+                                .insert(PRE_VALIDATOR_METADATA_KEY.to_string(), "true".to_string());
+                        }
+
+                        // Resolved against all known models once the whole project is read
+                        if !forward_refs.is_empty() {
+                            metadata.insert(
+                                FORWARD_REFS_METADATA_KEY.to_string(),
+                                forward_refs.join(","),
+                            );
+                        }
+
                         let range = class_def.range();
                         let (line, column) =
                             converter.byte_offset_to_location(range.start().into());
@@ -423,9 +512,432 @@ impl PythonParser {
             }
         }
 
+        Self::mark_response_only_fields(&mut models);
+        self.resolve_discriminated_unions(ast, &mut models);
+
         models
     }
 
+    /// Route registered by a call instead of a decorator, as the decorator it is equivalent
+    /// to: Starlette's `Route("/users", endpoint=list_users, methods=["GET"])`,
+    /// `app.add_route("/users", list_users)` or FastAPI's `app.add_api_route(...)`
+    ///
+    /// The endpoint (`endpoint=`, `route=` or the second argument) becomes the target function.
+    pub fn route_registration(&self, call: &Call) -> Option<crate::call_graph::Decorator> {
+        let member = call.name.rsplit('.').next().unwrap_or(&call.name);
+        if !matches!(member, "Route" | "add_route" | "add_api_route") {
+            return None;
+        }
+        let positional: Vec<&str> = call
+            .arguments
+            .iter()
+            .filter(|argument| argument.parameter_name.is_none())
+            .map(|argument| argument.value.as_str())
+            .collect();
+        let keyword_arguments: HashMap<String, String> = call
+            .arguments
+            .iter()
+            .filter_map(|argument| {
+                let name = argument.parameter_name.clone()?;
+                Some((name, argument.value.clone()))
+            })
+            .collect();
+        let path = positional
+            .first()
+            .copied()
+            .or(keyword_arguments.get("path").map(String::as_str))?
+            .to_string();
+        let endpoint = keyword_arguments
+            .get("endpoint")
+            .or(keyword_arguments.get("route"))
+            .map(String::as_str)
+            .or(positional.get(1).copied())?
+            .to_string();
+
+        Some(crate::call_graph::Decorator {
+            name: call.name.clone(),
+            arguments: vec![path],
+            keyword_arguments,
+            location: call.location.clone(),
+            target_function: Some(endpoint),
+        })
+    }
+
+    /// Path prefixes `Mount("/api", routes=[...])` gives the calls declared inside it,
+    /// keyed by the (line, column) of each call; mounts nest
+    ///
+    /// Only module-level assignments and expressions are searched, where Starlette route
+    /// tables are declared.
+    pub fn mount_prefixes(
+        &self,
+        ast: &ast::Mod,
+        converter: &LocationConverter,
+    ) -> HashMap<(usize, usize), String> {
+        let mut prefixes = HashMap::new();
+        let ast::Mod::Module(module) = ast else {
+            return prefixes;
+        };
+        for stmt in &module.body {
+            let value = match stmt {
+                ast::Stmt::Assign(assign) => Some(assign.value.as_ref()),
+                ast::Stmt::AnnAssign(ann_assign) => ann_assign.value.as_deref(),
+                ast::Stmt::Expr(expr) => Some(expr.value.as_ref()),
+                _ => None,
+            };
+            if let Some(value) = value {
+                self.collect_mount_prefixes(value, "", converter, &mut prefixes);
+            }
+        }
+        prefixes
+    }
+
+    fn collect_mount_prefixes(
+        &self,
+        expr: &ast::Expr,
+        prefix: &str,
+        converter: &LocationConverter,
+        prefixes: &mut HashMap<(usize, usize), String>,
+    ) {
+        match expr {
+            ast::Expr::Call(call) => {
+                let is_mount = self
+                    .call_name(&call.func)
+                    .is_some_and(|name| name.rsplit('.').next() == Some("Mount"));
+                let nested_prefix = if is_mount {
+                    let path = call
+                        .args
+                        .first()
+                        .or_else(|| {
+                            call.keywords
+                                .iter()
+                                .find(|kw| kw.arg.as_deref() == Some("path"))
+                                .map(|kw| &kw.value)
+                        })
+                        .map(|path| self.expr_to_string(path))
+                        .unwrap_or_default();
+                    format!("{}{}", prefix, path.trim_end_matches('/'))
+                } else {
+                    if !prefix.is_empty() {
+                        let location =
+                            converter.byte_offset_to_location(call.range().start().into());
+                        prefixes.insert(location, prefix.to_string());
+                    }
+                    prefix.to_string()
+                };
+                for arg in call
+                    .args
+                    .iter()
+                    .chain(call.keywords.iter().map(|kw| &kw.value))
+                {
+                    self.collect_mount_prefixes(arg, &nested_prefix, converter, prefixes);
+                }
+            }
+            ast::Expr::List(list) => {
+                for elt in &list.elts {
+                    self.collect_mount_prefixes(elt, prefix, converter, prefixes);
+                }
+            }
+            ast::Expr::Tuple(tuple) => {
+                for elt in &tuple.elts {
+                    self.collect_mount_prefixes(elt, prefix, converter, prefixes);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Whether a class is an enum: one of its bases is `Enum`, `IntEnum`, `StrEnum`, `Flag`
+    /// or `IntFlag`, mixins included (`class Color(str, Enum)`)
+    pub fn is_enum_class(&self, class_def: &ast::StmtClassDef) -> bool {
+        class_def.bases.iter().any(|base| {
+            let name = self.expr_to_string(base);
+            ENUM_BASES.contains(&name.rsplit('.').next().unwrap_or(&name))
+        })
+    }
+
+    /// Extracts enum classes from AST
+    pub fn extract_enums(
+        &self,
+        ast: &ast::Mod,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) -> Vec<crate::models::SchemaReference> {
+        let ast::Mod::Module(module) = ast else {
+            return Vec::new();
+        };
+        module
+            .body
+            .iter()
+            .filter_map(|stmt| match stmt {
+                ast::Stmt::ClassDef(class_def) if self.is_enum_class(class_def) => {
+                    Some(self.enum_schema(class_def, file_path, converter))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Schema of an enum class, with its members in `variants` metadata
+    ///
+    /// Members are the class-level assignments to public names (`RED = "red"`).
+    pub fn enum_schema(
+        &self,
+        class_def: &ast::StmtClassDef,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) -> crate::models::SchemaReference {
+        let variants: Vec<serde_json::Value> = class_def
+            .body
+            .iter()
+            .filter_map(|stmt| {
+                let ast::Stmt::Assign(assign) = stmt else {
+                    return None;
+                };
+                let [ast::Expr::Name(target)] = assign.targets.as_slice() else {
+                    return None;
+                };
+                if target.id.starts_with('_') {
+                    return None;
+                }
+                Some(serde_json::json!({
+                    "name": target.id.to_string(),
+                    "value": self.literal_value(&assign.value),
+                }))
+            })
+            .collect();
+
+        let mut metadata = HashMap::new();
+        metadata.insert(
+            ENUM_VARIANTS_METADATA_KEY.to_string(),
+            serde_json::Value::Array(variants).to_string(),
+        );
+        let (line, column) = converter.byte_offset_to_location(class_def.range().start().into());
+        crate::models::SchemaReference {
+            name: class_def.name.to_string(),
+            schema_type: crate::models::SchemaType::Enum,
+            location: crate::models::Location {
+                file: file_path.to_string(),
+                line,
+                column: Some(column),
+            },
+            metadata,
+        }
+    }
+
+    /// Resolves member tags of discriminated unions from the members' `Literal[...]` fields
+    ///
+    /// Module-level aliases (`Cat = Annotated[Union[Black, White], Field(discriminator="color")]`)
+    /// become nested unions when used as a member, and the union of fields annotated with them.
+    fn resolve_discriminated_unions(
+        &self,
+        ast: &ast::Mod,
+        models: &mut [crate::models::SchemaReference],
+    ) {
+        let ast::Mod::Module(module) = ast else {
+            return;
+        };
+
+        let aliases: HashMap<String, crate::models::DiscriminatedUnion> = module
+            .body
+            .iter()
+            .filter_map(|stmt| {
+                let ast::Stmt::Assign(assign) = stmt else {
+                    return None;
+                };
+                let [ast::Expr::Name(target)] = assign.targets.as_slice() else {
+                    return None;
+                };
+                let union = self.extract_discriminated_union(&assign.value, None)?;
+                Some((target.id.to_string(), union))
+            })
+            .collect();
+
+        let parse_fields = |model: &crate::models::SchemaReference| {
+            model
+                .metadata
+                .get("fields")
+                .and_then(|json| {
+                    serde_json::from_str::<Vec<crate::models::PydanticFieldInfo>>(json).ok()
+                })
+                .unwrap_or_default()
+        };
+        let model_fields: HashMap<String, Vec<crate::models::PydanticFieldInfo>> = models
+            .iter()
+            .map(|model| (model.name.clone(), parse_fields(model)))
+            .collect();
+
+        for model in models.iter_mut() {
+            let mut fields = parse_fields(model);
+            let mut changed = false;
+            for field in &mut fields {
+                let Some(union) = field
+                    .discriminated_union
+                    .clone()
+                    .or_else(|| aliases.get(&field.type_name).cloned())
+                else {
+                    continue;
+                };
+                let resolved =
+                    Self::resolve_union_members(union, &aliases, &model_fields, &mut Vec::new());
+                if field.discriminated_union.as_ref() != Some(&resolved) {
+                    field.discriminated_union = Some(resolved);
+                    changed = true;
+                }
+            }
+            if changed {
+                if let Ok(fields_json) = serde_json::to_string(&fields) {
+                    model.metadata.insert("fields".to_string(), fields_json);
+                }
+            }
+        }
+    }
+
+    /// Fills in member tags, expanding members that are themselves union aliases
+    fn resolve_union_members(
+        mut union: crate::models::DiscriminatedUnion,
+        aliases: &HashMap<String, crate::models::DiscriminatedUnion>,
+        model_fields: &HashMap<String, Vec<crate::models::PydanticFieldInfo>>,
+        visiting: &mut Vec<String>,
+    ) -> crate::models::DiscriminatedUnion {
+        for member in &mut union.members {
+            match aliases.get(&member.schema) {
+                Some(alias) if !visiting.contains(&member.schema) => {
+                    visiting.push(member.schema.clone());
+                    let nested =
+                        Self::resolve_union_members(alias.clone(), aliases, model_fields, visiting);
+                    visiting.pop();
+                    // A nested union is tagged by the value all of its members share
+                    member.tag =
+                        Self::shared_union_tag(&nested, &union.discriminator, model_fields);
+                    member.nested = Some(Box::new(nested));
+                }
+                Some(_) => {}
+                None => {
+                    let fields = model_fields.get(&member.schema);
+                    member.tag = Self::literal_tag(fields, &union.discriminator);
+                    if union.discriminator_alias.is_none() {
+                        union.discriminator_alias = fields
+                            .into_iter()
+                            .flatten()
+                            .find(|field| field.name == union.discriminator)
+                            .and_then(|field| field.alias.clone());
+                    }
+                }
+            }
+        }
+        union
+    }
+
+    /// Tag value for `discriminator` shared by every (leaf) member of a union
+    fn shared_union_tag(
+        union: &crate::models::DiscriminatedUnion,
+        discriminator: &str,
+        model_fields: &HashMap<String, Vec<crate::models::PydanticFieldInfo>>,
+    ) -> Option<String> {
+        let mut tags = union.members.iter().map(|member| match &member.nested {
+            Some(nested) => Self::shared_union_tag(nested, discriminator, model_fields),
+            None => Self::literal_tag(model_fields.get(&member.schema), discriminator),
+        });
+        let first = tags.next()??;
+        tags.all(|tag| tag.as_deref() == Some(first.as_str()))
+            .then_some(first)
+    }
+
+    /// Returns `cat` for a `pet_type: Literal["cat"]` field
+    fn literal_tag(
+        fields: Option<&Vec<crate::models::PydanticFieldInfo>>,
+        discriminator: &str,
+    ) -> Option<String> {
+        fields?
+            .iter()
+            .find(|field| field.name == discriminator && field.type_name == "Literal")
+            .and_then(|field| field.inner_type.clone())
+    }
+
+    /// Derives response-only fields from the Create/Read model split
+    ///
+    /// For `ItemCreate` + `ItemRead` (also `Update` for the write model, `Response`/`Public`
+    /// for the read one) and `ItemIn` + `ItemOut`, fields that exist only in the read model
+    /// are generated by the server and are marked `response_only`. Models are paired only
+    /// within one family and by a non-empty shared stem; `SignIn`/`SignOut`, `LogIn`/`LogOut`
+    /// and the like name actions rather than the two halves of a model.
+    fn mark_response_only_fields(models: &mut [crate::models::SchemaReference]) {
+        /// (write suffixes, read suffixes) of each model split
+        const FAMILIES: &[(&[&str], &[&str])] = &[
+            (&["Create", "Update"], &["Read", "Response", "Public"]),
+            (&["In"], &["Out"]),
+        ];
+        /// Verbs forming phrasal verbs with `In`/`Out` (`SignIn`, `CheckOut`)
+        const PHRASAL_VERBS: &[&str] = &["Sign", "Log", "Check", "Opt", "Clock", "Plug"];
+
+        fn stem<'a>(name: &'a str, suffixes: &[&str]) -> Option<&'a str> {
+            let stem = suffixes
+                .iter()
+                .find_map(|suffix| name.strip_suffix(suffix))
+                .filter(|stem| !stem.is_empty())?;
+            let last_word = stem
+                .rfind(|c: char| c.is_ascii_uppercase())
+                .map_or(stem, |start| &stem[start..]);
+            let names_action = suffixes.iter().any(|suffix| ["In", "Out"].contains(suffix))
+                && PHRASAL_VERBS.contains(&last_word);
+            (!names_action).then_some(stem)
+        }
+
+        let parse_fields = |model: &crate::models::SchemaReference| {
+            model
+                .metadata
+                .get("fields")
+                .and_then(|json| {
+                    serde_json::from_str::<Vec<crate::models::PydanticFieldInfo>>(json).ok()
+                })
+                .unwrap_or_default()
+        };
+
+        // Field names of each write model, by family and stem
+        let write_fields: std::collections::HashMap<(usize, String), Vec<String>> = models
+            .iter()
+            .flat_map(|model| {
+                FAMILIES
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(family, (write_suffixes, _))| {
+                        let stem = stem(&model.name, write_suffixes)?;
+                        let names = parse_fields(model).into_iter().map(|f| f.name).collect();
+                        Some(((family, stem.to_string()), names))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        for model in models.iter_mut() {
+            let Some(write_names) =
+                FAMILIES
+                    .iter()
+                    .enumerate()
+                    .find_map(|(family, (_, read_suffixes))| {
+                        let stem = stem(&model.name, read_suffixes)?;
+                        write_fields.get(&(family, stem.to_string()))
+                    })
+            else {
+                continue;
+            };
+
+            let mut fields = parse_fields(model);
+            let mut changed = false;
+            for field in &mut fields {
+                if !field.response_only && !write_names.contains(&field.name) {
+                    field.response_only = true;
+                    changed = true;
+                }
+            }
+            if changed {
+                if let Ok(fields_json) = serde_json::to_string(&fields) {
+                    model.metadata.insert("fields".to_string(), fields_json);
+                }
+            }
+        }
+    }
+
     /// Checks if the base class is Pydantic BaseModel
     fn is_pydantic_base_model(&self, bases: &[ast::Expr]) -> bool {
         for base in bases {
@@ -451,6 +963,492 @@ impl PythonParser {
         }
         false
     }
+
+    /// Class names a function body returns instances of, in source order without duplicates
+    ///
+    /// Recognizes `return User(...)`, `return User(...).model_dump()` and
+    /// `return user.model_dump()` after `user = User(...)`. Other returns (dict literals,
+    /// function calls) and nested functions/classes are ignored; whether a name is a
+    /// model is left to the caller.
+    pub fn returned_model_names(&self, body: &[ast::Stmt]) -> Vec<String> {
+        let mut assigned = HashMap::new();
+        let mut names = Vec::new();
+        Self::collect_returned_models(body, &mut assigned, &mut names);
+        names
+    }
+
+    fn collect_returned_models(
+        stmts: &[ast::Stmt],
+        assigned: &mut HashMap<String, String>,
+        names: &mut Vec<String>,
+    ) {
+        for stmt in stmts {
+            match stmt {
+                ast::Stmt::Assign(assign) => {
+                    if let [ast::Expr::Name(target)] = assign.targets.as_slice() {
+                        Self::record_assignment(target, Some(&assign.value), assigned);
+                    }
+                }
+                ast::Stmt::AnnAssign(assign) => {
+                    if let ast::Expr::Name(target) = assign.target.as_ref() {
+                        Self::record_assignment(target, assign.value.as_deref(), assigned);
+                    }
+                }
+                ast::Stmt::Return(ret_stmt) => {
+                    let returned = ret_stmt
+                        .value
+                        .as_deref()
+                        .and_then(|value| Self::returned_model(value, assigned));
+                    if let Some(name) = returned {
+                        if !names.contains(&name) {
+                            names.push(name);
+                        }
+                    }
+                }
+                ast::Stmt::If(if_stmt) => {
+                    Self::collect_returned_models(&if_stmt.body, assigned, names);
+                    Self::collect_returned_models(&if_stmt.orelse, assigned, names);
+                }
+                ast::Stmt::For(for_stmt) => {
+                    Self::collect_returned_models(&for_stmt.body, assigned, names);
+                    Self::collect_returned_models(&for_stmt.orelse, assigned, names);
+                }
+                ast::Stmt::AsyncFor(for_stmt) => {
+                    Self::collect_returned_models(&for_stmt.body, assigned, names);
+                    Self::collect_returned_models(&for_stmt.orelse, assigned, names);
+                }
+                ast::Stmt::While(while_stmt) => {
+                    Self::collect_returned_models(&while_stmt.body, assigned, names);
+                    Self::collect_returned_models(&while_stmt.orelse, assigned, names);
+                }
+                ast::Stmt::With(with_stmt) => {
+                    Self::collect_returned_models(&with_stmt.body, assigned, names);
+                }
+                ast::Stmt::AsyncWith(with_stmt) => {
+                    Self::collect_returned_models(&with_stmt.body, assigned, names);
+                }
+                ast::Stmt::Try(try_stmt) => {
+                    Self::collect_returned_models(&try_stmt.body, assigned, names);
+                    for handler in &try_stmt.handlers {
+                        let ast::ExceptHandler::ExceptHandler(except_handler) = handler;
+                        Self::collect_returned_models(&except_handler.body, assigned, names);
+                    }
+                    Self::collect_returned_models(&try_stmt.orelse, assigned, names);
+                    Self::collect_returned_models(&try_stmt.finalbody, assigned, names);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Remembers which class a variable was constructed from (forgets it on reassignment)
+    fn record_assignment(
+        target: &ast::ExprName,
+        value: Option<&ast::Expr>,
+        assigned: &mut HashMap<String, String>,
+    ) {
+        match value.and_then(Self::constructed_class) {
+            Some(class_name) => {
+                assigned.insert(target.id.to_string(), class_name);
+            }
+            None => {
+                assigned.remove(target.id.as_str());
+            }
+        }
+    }
+
+    /// Whether a return annotation is a Starlette/FastAPI response class (`-> JSONResponse`),
+    /// whose handler's body tells what it serializes
+    pub fn is_response_class_annotation(&self, annotation: &ast::Expr) -> bool {
+        let name = match annotation {
+            ast::Expr::Name(name) => name.id.as_str(),
+            ast::Expr::Attribute(attr) => attr.attr.as_str(),
+            _ => return false,
+        };
+        name == "Response" || RESPONSE_CLASSES.contains(&name)
+    }
+
+    /// Media type of a route's `response_class=` when it isn't JSON (`HTMLResponse` sends
+    /// `text/html`)
+    ///
+    /// JSON response classes and unknown (custom) classes give `None`: their body is treated
+    /// as JSON.
+    pub fn non_json_response_media_type(response_class: &str) -> Option<&'static str> {
+        let name = response_class.trim().rsplit('.').next()?;
+        NON_JSON_RESPONSE_CLASSES
+            .iter()
+            .find(|(class, _)| *class == name)
+            .map(|(_, media_type)| *media_type)
+    }
+
+    /// HTTP names of the headers a handler requires, lower-cased, in declaration order
+    ///
+    /// A parameter declares a header through its default (`x_token: str = Header()`) or its
+    /// annotation (`Annotated[str, Header(alias="X-Token")]`). It is required unless it has a
+    /// default (`Header(None)`, `Header(default="en")`, or a plain default next to an
+    /// `Annotated` declaration). The name is the `alias`, or the parameter name with
+    /// underscores converted to hyphens unless `convert_underscores=False`.
+    pub fn required_header_names(&self, args: &ast::Arguments) -> Vec<String> {
+        let mut names = Vec::new();
+        for arg in args
+            .posonlyargs
+            .iter()
+            .chain(&args.args)
+            .chain(&args.kwonlyargs)
+        {
+            let default = arg.default.as_deref();
+            let (header, has_default) = match default.and_then(Self::header_call) {
+                Some(header) => (header, Self::header_has_default(header)),
+                None => {
+                    let annotated = arg.def.annotation.as_deref().and_then(|annotation| {
+                        let ast::Expr::Subscript(sub) = annotation else {
+                            return None;
+                        };
+                        if !matches!(
+                            self.expr_to_string(&sub.value).as_str(),
+                            "Annotated" | "typing.Annotated"
+                        ) {
+                            return None;
+                        }
+                        let ast::Expr::Tuple(tuple) = sub.slice.as_ref() else {
+                            return None;
+                        };
+                        tuple.elts.iter().skip(1).find_map(Self::header_call)
+                    });
+                    let Some(header) = annotated else {
+                        continue;
+                    };
+                    (
+                        header,
+                        default.is_some() || Self::header_has_default(header),
+                    )
+                }
+            };
+            if has_default {
+                continue;
+            }
+
+            let keyword = |name: &str| {
+                header
+                    .keywords
+                    .iter()
+                    .find(|keyword| keyword.arg.as_ref().is_some_and(|arg| arg.as_str() == name))
+                    .map(|keyword| &keyword.value)
+            };
+            let alias = keyword("alias").and_then(|value| match value {
+                ast::Expr::Constant(constant) => match &constant.value {
+                    ast::Constant::Str(alias) => Some(alias.clone()),
+                    _ => None,
+                },
+                _ => None,
+            });
+            let name = alias.unwrap_or_else(|| {
+                let keep_underscores = keyword("convert_underscores").is_some_and(|value| {
+                    matches!(value, ast::Expr::Constant(constant)
+                        if matches!(constant.value, ast::Constant::Bool(false)))
+                });
+                if keep_underscores {
+                    arg.def.arg.to_string()
+                } else {
+                    arg.def.arg.replace('_', "-")
+                }
+            });
+            let name = name.to_lowercase();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    /// Media type a handler's form parameters expect the request body in
+    ///
+    /// `File(...)` and `UploadFile` parameters need `multipart/form-data`; `Form(...)`
+    /// parameters (as default or `Annotated` metadata) read
+    /// `application/x-www-form-urlencoded`, multipart being accepted too. None when no
+    /// parameter reads a form.
+    pub fn request_media_type(&self, args: &ast::Arguments) -> Option<&'static str> {
+        let call_name = |expr: &ast::Expr| {
+            let ast::Expr::Call(call) = expr else {
+                return None;
+            };
+            match call.func.as_ref() {
+                ast::Expr::Name(name) => Some(name.id.to_string()),
+                ast::Expr::Attribute(attr) => Some(attr.attr.to_string()),
+                _ => None,
+            }
+        };
+        let mut media_type = None;
+        for arg in args
+            .posonlyargs
+            .iter()
+            .chain(&args.args)
+            .chain(&args.kwonlyargs)
+        {
+            let mut markers: Vec<String> = arg
+                .default
+                .as_deref()
+                .and_then(call_name)
+                .into_iter()
+                .collect();
+            let annotation = arg
+                .def
+                .annotation
+                .as_deref()
+                .map(|annotation| self.expr_to_string(annotation))
+                .unwrap_or_default();
+            if let Some(ast::Expr::Subscript(sub)) = arg.def.annotation.as_deref() {
+                if let ast::Expr::Tuple(tuple) = sub.slice.as_ref() {
+                    if matches!(
+                        self.expr_to_string(&sub.value).as_str(),
+                        "Annotated" | "typing.Annotated"
+                    ) {
+                        markers.extend(tuple.elts.iter().skip(1).filter_map(call_name));
+                    }
+                }
+            }
+            if markers.iter().any(|marker| marker == "File") || annotation.contains("UploadFile") {
+                return Some("multipart/form-data");
+            }
+            if markers.iter().any(|marker| marker == "Form") {
+                media_type = Some("application/x-www-form-urlencoded");
+            }
+        }
+        media_type
+    }
+
+    /// Query parameters of a handler typed `int`, `float` or `bool`, in declaration order
+    ///
+    /// Parameters declared with another source (`Path`, `Header`, `Cookie`, `Body`, `Form`,
+    /// `File`, `Depends`) are skipped; path parameters without a marker are not, the caller
+    /// knows the route path. `Optional[int]` and `int | None` count as `int`. A parameter is
+    /// strict when typed `StrictInt`/`StrictFloat`/`StrictBool`, annotated with `Strict()`
+    /// or declared with `Query(strict=True)`.
+    pub fn typed_query_parameters(&self, args: &ast::Arguments) -> Vec<QueryParameter> {
+        const OTHER_SOURCES: [&str; 8] = [
+            "Path", "Header", "Cookie", "Body", "Form", "File", "Depends", "Security",
+        ];
+        fn call_of(expr: &ast::Expr) -> Option<(String, &ast::ExprCall)> {
+            let ast::Expr::Call(call) = expr else {
+                return None;
+            };
+            let name = match call.func.as_ref() {
+                ast::Expr::Name(name) => name.id.to_string(),
+                ast::Expr::Attribute(attr) => attr.attr.to_string(),
+                _ => return None,
+            };
+            Some((name, call))
+        }
+        let is_true = |expr: &ast::Expr| {
+            matches!(expr, ast::Expr::Constant(constant)
+                if matches!(constant.value, ast::Constant::Bool(true)))
+        };
+
+        let mut parameters = Vec::new();
+        for arg in args
+            .posonlyargs
+            .iter()
+            .chain(&args.args)
+            .chain(&args.kwonlyargs)
+        {
+            let Some(mut annotation) = arg.def.annotation.as_deref() else {
+                continue;
+            };
+            let mut markers: Vec<(String, &ast::ExprCall)> = arg
+                .default
+                .as_deref()
+                .and_then(call_of)
+                .into_iter()
+                .collect();
+            if let ast::Expr::Subscript(sub) = annotation {
+                if matches!(
+                    self.expr_to_string(&sub.value).as_str(),
+                    "Annotated" | "typing.Annotated"
+                ) {
+                    if let ast::Expr::Tuple(tuple) = sub.slice.as_ref() {
+                        markers.extend(tuple.elts.iter().skip(1).filter_map(call_of));
+                        if let Some(inner) = tuple.elts.first() {
+                            annotation = inner;
+                        }
+                    }
+                }
+            }
+            if markers
+                .iter()
+                .any(|(name, _)| OTHER_SOURCES.contains(&name.as_str()))
+            {
+                continue;
+            }
+
+            let type_name = self.non_optional_type_name(annotation);
+            let type_name = type_name.rsplit('.').next().unwrap_or(&type_name);
+            let param_type = match type_name {
+                "int" | "StrictInt" => QueryParamType::Integer,
+                "float" | "StrictFloat" => QueryParamType::Number,
+                "bool" | "StrictBool" => QueryParamType::Boolean,
+                _ => continue,
+            };
+            let keyword = |call: &'_ ast::ExprCall, name: &str| {
+                call.keywords
+                    .iter()
+                    .find(|keyword| keyword.arg.as_ref().is_some_and(|arg| arg.as_str() == name))
+                    .map(|keyword| keyword.value.clone())
+            };
+            let query = markers.iter().find(|(name, _)| name == "Query");
+            let strict = type_name.starts_with("Strict")
+                || markers
+                    .iter()
+                    .any(|(name, call)| name == "Strict" && call.args.first().is_none_or(is_true))
+                || query.is_some_and(|(_, call)| {
+                    keyword(call, "strict").is_some_and(|value| is_true(&value))
+                });
+            let alias = query
+                .and_then(|(_, call)| keyword(call, "alias"))
+                .and_then(|value| match value {
+                    ast::Expr::Constant(constant) => match constant.value {
+                        ast::Constant::Str(alias) => Some(alias),
+                        _ => None,
+                    },
+                    _ => None,
+                });
+            parameters.push(QueryParameter {
+                name: alias.unwrap_or_else(|| arg.def.arg.to_string()),
+                param_type,
+                strict,
+            });
+        }
+        parameters
+    }
+
+    /// Type name of an annotation with `Optional[...]`/`Union[..., None]`/`... | None`
+    /// unwrapped (`Optional[int]` -> `int`); other annotations are rendered as is
+    fn non_optional_type_name(&self, annotation: &ast::Expr) -> String {
+        let is_none = |expr: &ast::Expr| {
+            matches!(expr, ast::Expr::Constant(constant)
+                if matches!(constant.value, ast::Constant::None))
+        };
+        match annotation {
+            ast::Expr::BinOp(bin_op) if matches!(bin_op.op, ast::Operator::BitOr) => {
+                match (is_none(&bin_op.left), is_none(&bin_op.right)) {
+                    (false, true) => self.non_optional_type_name(&bin_op.left),
+                    (true, false) => self.non_optional_type_name(&bin_op.right),
+                    _ => self.expr_to_string(annotation),
+                }
+            }
+            ast::Expr::Subscript(sub) => {
+                let base = self.expr_to_string(&sub.value);
+                let base = base.rsplit('.').next().unwrap_or(&base);
+                let members: Vec<&ast::Expr> = match sub.slice.as_ref() {
+                    ast::Expr::Tuple(tuple) => tuple.elts.iter().collect(),
+                    single => vec![single],
+                };
+                let not_none: Vec<&ast::Expr> = members
+                    .into_iter()
+                    .filter(|member| !is_none(member))
+                    .collect();
+                match (base, not_none.as_slice()) {
+                    ("Optional" | "Union", [inner]) => self.non_optional_type_name(inner),
+                    _ => self.expr_to_string(annotation),
+                }
+            }
+            _ => self.expr_to_string(annotation),
+        }
+    }
+
+    /// `Header(...)` / `fastapi.Header(...)` call
+    fn header_call(expr: &ast::Expr) -> Option<&ast::ExprCall> {
+        let ast::Expr::Call(call) = expr else {
+            return None;
+        };
+        let name = match call.func.as_ref() {
+            ast::Expr::Name(name) => name.id.as_str(),
+            ast::Expr::Attribute(attr) => attr.attr.as_str(),
+            _ => return None,
+        };
+        (name == "Header").then_some(call)
+    }
+
+    /// Whether a `Header(...)` call gives a default: a first argument or `default=` other
+    /// than `...`, or a `default_factory`
+    fn header_has_default(call: &ast::ExprCall) -> bool {
+        let is_ellipsis = |expr: &ast::Expr| {
+            matches!(expr, ast::Expr::Constant(constant)
+                if matches!(constant.value, ast::Constant::Ellipsis))
+        };
+        call.args.first().is_some_and(|arg| !is_ellipsis(arg))
+            || call.keywords.iter().any(|keyword| {
+                match keyword.arg.as_ref().map(|arg| arg.as_str()) {
+                    Some("default") => !is_ellipsis(&keyword.value),
+                    Some("default_factory") => true,
+                    _ => false,
+                }
+            })
+    }
+
+    /// Model returned by a `return` value: a constructor call, `.model_dump()`/`.dict()` on
+    /// one, or either of them as the `content` of a JSON response class
+    /// (`JSONResponse(content=user.model_dump())`)
+    fn returned_model(value: &ast::Expr, assigned: &HashMap<String, String>) -> Option<String> {
+        if let Some(content) = Self::response_content(value) {
+            return Self::returned_model(content, assigned);
+        }
+        if let Some(class_name) = Self::constructed_class(value) {
+            return Some(class_name);
+        }
+        let ast::Expr::Call(call) = value else {
+            return None;
+        };
+        let ast::Expr::Attribute(attr) = call.func.as_ref() else {
+            return None;
+        };
+        if !matches!(attr.attr.as_str(), "model_dump" | "dict") {
+            return None;
+        }
+        match attr.value.as_ref() {
+            ast::Expr::Name(name) => assigned.get(name.id.as_str()).cloned(),
+            receiver => Self::constructed_class(receiver),
+        }
+    }
+
+    /// `content` of a JSON response construction (`content=` keyword or first argument)
+    fn response_content(expr: &ast::Expr) -> Option<&ast::Expr> {
+        let ast::Expr::Call(call) = expr else {
+            return None;
+        };
+        let name = match call.func.as_ref() {
+            ast::Expr::Name(name) => name.id.as_str(),
+            ast::Expr::Attribute(attr) => attr.attr.as_str(),
+            _ => return None,
+        };
+        if !RESPONSE_CLASSES.contains(&name) {
+            return None;
+        }
+        call.keywords
+            .iter()
+            .find(|keyword| {
+                keyword
+                    .arg
+                    .as_ref()
+                    .is_some_and(|arg| arg.as_str() == "content")
+            })
+            .map(|keyword| &keyword.value)
+            .or_else(|| call.args.first())
+    }
+
+    /// Class of a constructor call (`User(...)`, `schemas.User(...)`); calls of
+    /// lowercase names are treated as function calls
+    fn constructed_class(expr: &ast::Expr) -> Option<String> {
+        let ast::Expr::Call(call) = expr else {
+            return None;
+        };
+        let name = match call.func.as_ref() {
+            ast::Expr::Name(name) => name.id.as_str(),
+            ast::Expr::Attribute(attr) => attr.attr.as_str(),
+            _ => return None,
+        };
+        name.starts_with(|c: char| c.is_ascii_uppercase())
+            .then(|| name.to_string())
+    }
 }
 
 impl Default for PythonParser {
@@ -770,26 +1768,259 @@ impl PythonParser {
                     "call(...)".to_string()
                 }
             }
+            // f"{PREFIX}/users/{user_id}" -> "{PREFIX}/users/{user_id}"
+            ast::Expr::JoinedStr(joined) => joined
+                .values
+                .iter()
+                .map(|value| match value {
+                    ast::Expr::FormattedValue(formatted) => {
+                        format!("{{{}}}", self.expr_to_string(&formatted.value))
+                    }
+                    other => self.expr_to_string(other),
+                })
+                .collect(),
+            // ["GET", "POST"] -> "[GET, POST]"
+            ast::Expr::List(list) => format!(
+                "[{}]",
+                list.elts
+                    .iter()
+                    .map(|elt| self.expr_to_string(elt))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            // PREFIX + "/users" -> "{PREFIX}/users"
+            ast::Expr::BinOp(bin_op) if matches!(bin_op.op, ast::Operator::Add) => {
+                [bin_op.left.as_ref(), bin_op.right.as_ref()]
+                    .into_iter()
+                    .map(|operand| match operand {
+                        ast::Expr::Name(_) | ast::Expr::Attribute(_) => {
+                            format!("{{{}}}", self.expr_to_string(operand))
+                        }
+                        other => self.expr_to_string(other),
+                    })
+                    .collect()
+            }
             _ => format!("{:?}", expr),
         }
     }
 
-    #[allow(clippy::only_used_in_recursion)]
-    fn collect_decorators(
-        &self,
-        stmt: &ast::Stmt,
-        class_context: Option<String>,
-        decorators: &mut Vec<crate::call_graph::Decorator>,
-        file_path: &str,
-        converter: &LocationConverter,
-    ) {
-        match stmt {
-            ast::Stmt::FunctionDef(func_def) => {
-                let target_name = class_context
-                    .as_ref()
-                    .map(|class| format!("{}.{}", class, func_def.name))
-                    .unwrap_or_else(|| func_def.name.to_string());
-
+    /// Extracts module-level type aliases: `UserId = NewType("UserId", int)`,
+    /// `Vector: TypeAlias = list[float]` and `type Vector = list[float]`
+    ///
+    /// Each alias maps to the expression of its underlying type.
+    pub fn extract_type_aliases(&self, ast: &ast::Mod) -> HashMap<String, ast::Expr> {
+        let mut aliases = HashMap::new();
+        let ast::Mod::Module(module) = ast else {
+            return aliases;
+        };
+        for stmt in &module.body {
+            let (target, underlying) = match stmt {
+                ast::Stmt::TypeAlias(alias) => (alias.name.as_ref(), alias.value.as_ref()),
+                ast::Stmt::AnnAssign(assign) => {
+                    let annotation = self.expr_to_string(&assign.annotation);
+                    match &assign.value {
+                        Some(value)
+                            if matches!(annotation.as_str(), "TypeAlias" | "typing.TypeAlias") =>
+                        {
+                            (assign.target.as_ref(), value.as_ref())
+                        }
+                        _ => continue,
+                    }
+                }
+                ast::Stmt::Assign(assign) if assign.targets.len() == 1 => {
+                    let ast::Expr::Call(call) = assign.value.as_ref() else {
+                        continue;
+                    };
+                    let is_new_type = self.call_name(&call.func).is_some_and(|name| {
+                        matches!(
+                            name.as_str(),
+                            "NewType" | "typing.NewType" | "typing_extensions.NewType"
+                        )
+                    });
+                    match call.args.get(1) {
+                        Some(underlying) if is_new_type => (&assign.targets[0], underlying),
+                        _ => continue,
+                    }
+                }
+                _ => continue,
+            };
+            if let ast::Expr::Name(name) = target {
+                aliases.insert(name.id.to_string(), underlying.clone());
+            }
+        }
+        aliases
+    }
+
+    /// Names a star import (`from schemas import *`) brings in from a module
+    ///
+    /// The module's `__all__` when it is a literal list or tuple of strings, otherwise its
+    /// public top-level classes, functions and variables.
+    pub fn star_import_names(&self, ast: &ast::Mod) -> Vec<String> {
+        let ast::Mod::Module(module) = ast else {
+            return Vec::new();
+        };
+        let mut names = Vec::new();
+        for stmt in &module.body {
+            let (target, value) = match stmt {
+                ast::Stmt::Assign(assign) if assign.targets.len() == 1 => {
+                    (&assign.targets[0], Some(assign.value.as_ref()))
+                }
+                ast::Stmt::AnnAssign(assign) => (assign.target.as_ref(), assign.value.as_deref()),
+                ast::Stmt::ClassDef(class_def) => {
+                    names.push(class_def.name.to_string());
+                    continue;
+                }
+                ast::Stmt::FunctionDef(function_def) => {
+                    names.push(function_def.name.to_string());
+                    continue;
+                }
+                ast::Stmt::AsyncFunctionDef(function_def) => {
+                    names.push(function_def.name.to_string());
+                    continue;
+                }
+                _ => continue,
+            };
+            let ast::Expr::Name(name) = target else {
+                continue;
+            };
+            if name.id.as_str() == "__all__" {
+                let elements = match value {
+                    Some(ast::Expr::List(list)) => &list.elts,
+                    Some(ast::Expr::Tuple(tuple)) => &tuple.elts,
+                    _ => continue,
+                };
+                let exported: Option<Vec<String>> = elements
+                    .iter()
+                    .map(|element| match element {
+                        ast::Expr::Constant(constant) => match &constant.value {
+                            ast::Constant::Str(name) => Some(name.clone()),
+                            _ => None,
+                        },
+                        _ => None,
+                    })
+                    .collect();
+                if let Some(exported) = exported {
+                    return exported;
+                }
+                continue;
+            }
+            names.push(name.id.to_string());
+        }
+        names.retain(|name| !name.starts_with('_'));
+        names
+    }
+
+    /// Extracts module-level string constants (`USERS = "/users"`)
+    ///
+    /// Class attributes are keyed by their qualified name (`Routes.USERS`). Values may be
+    /// literals, f-strings and `+` concatenations of earlier constants; f-string parts that
+    /// aren't constants are kept as `{name}` placeholders (`f"{PREFIX}/users/{user_id}"`).
+    pub fn extract_string_constants(&self, ast: &ast::Mod) -> HashMap<String, String> {
+        let mut constants = HashMap::new();
+        if let ast::Mod::Module(module) = ast {
+            self.collect_string_constants(&module.body, None, &mut constants);
+        }
+        constants
+    }
+
+    fn collect_string_constants(
+        &self,
+        body: &[ast::Stmt],
+        class_name: Option<&str>,
+        constants: &mut HashMap<String, String>,
+    ) {
+        for stmt in body {
+            let (target, value) = match stmt {
+                ast::Stmt::Assign(assign) if assign.targets.len() == 1 => {
+                    (&assign.targets[0], assign.value.as_ref())
+                }
+                ast::Stmt::AnnAssign(assign) => match &assign.value {
+                    Some(value) => (assign.target.as_ref(), value.as_ref()),
+                    None => continue,
+                },
+                ast::Stmt::ClassDef(class_def) => {
+                    let qualified = match class_name {
+                        Some(outer) => format!("{}.{}", outer, class_def.name),
+                        None => class_def.name.to_string(),
+                    };
+                    self.collect_string_constants(&class_def.body, Some(&qualified), constants);
+                    continue;
+                }
+                _ => continue,
+            };
+            let ast::Expr::Name(name) = target else {
+                continue;
+            };
+            let Some(value) = self.string_value(value, class_name, constants) else {
+                continue;
+            };
+            let key = match class_name {
+                Some(class_name) => format!("{}.{}", class_name, name.id),
+                None => name.id.to_string(),
+            };
+            constants.insert(key, value);
+        }
+    }
+
+    /// Value of a string expression over the constants collected so far
+    fn string_value(
+        &self,
+        expr: &ast::Expr,
+        class_name: Option<&str>,
+        constants: &HashMap<String, String>,
+    ) -> Option<String> {
+        let lookup = |name: String| {
+            class_name
+                .and_then(|class_name| constants.get(&format!("{}.{}", class_name, name)))
+                .or_else(|| constants.get(&name))
+                .cloned()
+        };
+        match expr {
+            ast::Expr::Constant(constant) => match &constant.value {
+                ast::Constant::Str(value) => Some(value.clone()),
+                _ => None,
+            },
+            ast::Expr::Name(_) | ast::Expr::Attribute(_) => lookup(self.expr_to_string(expr)),
+            ast::Expr::JoinedStr(joined) => Some(
+                joined
+                    .values
+                    .iter()
+                    .map(|value| match value {
+                        ast::Expr::FormattedValue(formatted) => {
+                            let inner = self.expr_to_string(&formatted.value);
+                            lookup(inner.clone()).unwrap_or_else(|| format!("{{{}}}", inner))
+                        }
+                        other => self
+                            .string_value(other, class_name, constants)
+                            .unwrap_or_default(),
+                    })
+                    .collect(),
+            ),
+            ast::Expr::BinOp(binop) if matches!(binop.op, ast::Operator::Add) => {
+                let left = self.string_value(&binop.left, class_name, constants)?;
+                let right = self.string_value(&binop.right, class_name, constants)?;
+                Some(format!("{}{}", left, right))
+            }
+            _ => None,
+        }
+    }
+
+    #[allow(clippy::only_used_in_recursion)]
+    fn collect_decorators(
+        &self,
+        stmt: &ast::Stmt,
+        class_context: Option<String>,
+        decorators: &mut Vec<crate::call_graph::Decorator>,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) {
+        match stmt {
+            ast::Stmt::FunctionDef(func_def) => {
+                let target_name = class_context
+                    .as_ref()
+                    .map(|class| format!("{}.{}", class, func_def.name))
+                    .unwrap_or_else(|| func_def.name.to_string());
+
                 self.process_function_decorators(
                     &target_name,
                     &func_def.decorator_list,
@@ -871,8 +2102,9 @@ impl PythonParser {
         }
     }
 
+    /// Dotted name of a decorator (`app.get` for `@app.get("/")`)
     #[allow(clippy::only_used_in_recursion)]
-    fn get_decorator_name(&self, decorator: &ast::Expr) -> Option<String> {
+    pub fn get_decorator_name(&self, decorator: &ast::Expr) -> Option<String> {
         match decorator {
             ast::Expr::Attribute(attr) => self
                 .get_decorator_name(&attr.value)
@@ -909,11 +2141,40 @@ impl PythonParser {
         }
     }
 
-    fn is_route_decorator(&self, name: &str) -> bool {
+    pub fn is_route_decorator(&self, name: &str) -> bool {
+        if self.route_pattern_method(name).is_some() {
+            return true;
+        }
+
+        // Configured app/router variables: `<object>.<http method>` or `<object>.api_route` only
+        if !self.route_objects.is_empty() {
+            let Some((object, member)) = name.rsplit_once('.') else {
+                return false;
+            };
+            let route_members = [
+                "get",
+                "post",
+                "put",
+                "patch",
+                "delete",
+                "head",
+                "options",
+                "api_route",
+                "route",
+            ];
+            return self
+                .route_objects
+                .iter()
+                .any(|route_object| route_object == object)
+                && route_members.contains(&member);
+        }
+
         // Check for common FastAPI route patterns
         // 1. Direct app/router access: app.get, router.post, etc.
+        //    (but not app-level hooks such as `@app.middleware("http")`)
         if name.starts_with("app.") || name.starts_with("router.") {
-            return true;
+            let member = name.rsplit('.').next().unwrap_or_default();
+            return !["middleware", "exception_handler", "on_event"].contains(&member);
         }
 
         // 2. Contains .route: api_router.route, main_router.route
@@ -934,6 +2195,10 @@ impl PythonParser {
             "r",
             "rt",
             "router_instance",
+            // Flask blueprints (`@bp.get("/")` since Flask 2.0)
+            "bp",
+            "blueprint",
+            "api_bp",
         ];
 
         for router_name in &router_names {
@@ -1075,12 +2340,84 @@ impl PythonParser {
         trimmed.to_string()
     }
 
-    /// Extracts model_config dictionary
+    /// `model_config` settings of a Pydantic model
+    ///
+    /// Reads `model_config = ConfigDict(...)` (or a dict literal) and the Pydantic v1
+    /// `class Config`, then the settings of base models declared in the same file, which
+    /// apply unless the class overrides them. `visiting` guards against inheritance cycles.
+    fn model_config(
+        &self,
+        class_def: &ast::StmtClassDef,
+        ast: &ast::Mod,
+        visiting: &mut Vec<String>,
+    ) -> std::collections::HashMap<String, String> {
+        let mut config = std::collections::HashMap::new();
+        if visiting.iter().any(|name| name == class_def.name.as_str()) {
+            return config;
+        }
+        visiting.push(class_def.name.to_string());
+
+        for body_stmt in &class_def.body {
+            match body_stmt {
+                ast::Stmt::Assign(assign_stmt) => {
+                    // model_config = {"from_attributes": True, ...}
+                    // or model_config = ConfigDict(extra="forbid", ...)
+                    if let Some(ast::Expr::Name(name)) = assign_stmt.targets.first() {
+                        if name.id.as_str() == "model_config" {
+                            if let Some(config_dict) = self.extract_model_config(&assign_stmt.value)
+                            {
+                                config.extend(config_dict);
+                            }
+                        }
+                    }
+                }
+                // Pydantic v1: class Config: extra = Extra.forbid
+                ast::Stmt::ClassDef(config_class) if config_class.name.as_str() == "Config" => {
+                    for config_stmt in &config_class.body {
+                        if let ast::Stmt::Assign(assign) = config_stmt {
+                            if let [ast::Expr::Name(key)] = assign.targets.as_slice() {
+                                config
+                                    .insert(key.id.to_string(), self.expr_to_string(&assign.value));
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for base in &class_def.bases {
+            let base_name = self.extract_class_name_from_expr(base);
+            if let Some(base_model) = self.find_pydantic_model_by_name(&base_name, ast) {
+                for (key, value) in self.model_config(base_model, ast, visiting) {
+                    config.entry(key).or_insert(value);
+                }
+            }
+        }
+        config
+    }
+
+    /// Extracts model_config dictionary (a dict literal or a `ConfigDict(...)` call)
     /// Returns HashMap with config keys and string values
     fn extract_model_config(
         &self,
         expr: &ast::Expr,
     ) -> Option<std::collections::HashMap<String, String>> {
+        if let ast::Expr::Call(call) = expr {
+            let name = self.call_name(&call.func)?;
+            if name.rsplit('.').next() != Some("ConfigDict") {
+                return None;
+            }
+            let config = call
+                .keywords
+                .iter()
+                .filter_map(|keyword| {
+                    let key = keyword.arg.as_ref()?;
+                    Some((key.to_string(), self.expr_to_string(&keyword.value)))
+                })
+                .collect();
+            return Some(config);
+        }
         if let ast::Expr::Dict(dict) = expr {
             let mut config = std::collections::HashMap::new();
             for (key_expr, value_expr) in dict.keys.iter().zip(dict.values.iter()) {
@@ -1191,6 +2528,80 @@ impl PythonParser {
         }
     }
 
+    /// Members of a `|` union other than `None` (`list[T]` for `list[T] | None`)
+    fn union_members<'a>(&self, expr: &'a ast::Expr) -> Vec<&'a ast::Expr> {
+        self.union_operands(expr)
+            .into_iter()
+            .filter(|member| self.expr_to_string(member) != "None")
+            .collect()
+    }
+
+    /// All operands of a PEP 604 `|` union, `None` included (`[A, B, None]` for `A | B | None`)
+    ///
+    /// Any other expression is a union of itself.
+    pub fn union_operands<'a>(&self, expr: &'a ast::Expr) -> Vec<&'a ast::Expr> {
+        match expr {
+            ast::Expr::BinOp(bin_op) if matches!(bin_op.op, ast::Operator::BitOr) => {
+                let mut operands = self.union_operands(&bin_op.left);
+                operands.extend(self.union_operands(&bin_op.right));
+                operands
+            }
+            _ => vec![expr],
+        }
+    }
+
+    /// Values the items of a list annotation are restricted to
+    ///
+    /// `list[Literal["a", "b"]]`, also under `Optional[...]`, `| None`, `Annotated[...]`
+    /// or as `conlist(Literal[...])`. None for other annotations.
+    fn list_item_values(&self, annotation: &ast::Expr) -> Option<Vec<String>> {
+        match annotation {
+            ast::Expr::Subscript(sub) => match self.expr_to_string(&sub.value).as_str() {
+                "Optional" | "typing.Optional" => self.list_item_values(&sub.slice),
+                "Annotated" | "typing.Annotated" => match sub.slice.as_ref() {
+                    ast::Expr::Tuple(tuple) => self.list_item_values(tuple.elts.first()?),
+                    _ => None,
+                },
+                "list" | "List" | "typing.List" | "set" | "Set" | "Sequence" => {
+                    self.literal_values(&sub.slice)
+                }
+                _ => None,
+            },
+            ast::Expr::BinOp(_) => match self.union_members(annotation).as_slice() {
+                [member] => self.list_item_values(member),
+                _ => None,
+            },
+            ast::Expr::Call(call) if self.is_conlist_call(call) => {
+                self.literal_values(call.args.first()?)
+            }
+            _ => None,
+        }
+    }
+
+    /// Values of a `Literal["a", "b"]` annotation, as strings
+    fn literal_values(&self, annotation: &ast::Expr) -> Option<Vec<String>> {
+        let ast::Expr::Subscript(sub) = annotation else {
+            return None;
+        };
+        if !matches!(
+            self.expr_to_string(&sub.value).as_str(),
+            "Literal" | "typing.Literal"
+        ) {
+            return None;
+        }
+        let values = match sub.slice.as_ref() {
+            ast::Expr::Tuple(tuple) => tuple.elts.iter().collect(),
+            value => vec![value],
+        };
+        values
+            .into_iter()
+            .map(|value| match self.literal_value(value)? {
+                serde_json::Value::String(value) => Some(value),
+                value => Some(value.to_string()),
+            })
+            .collect()
+    }
+
     /// Extracts the value type from dict[K, V] -> returns V
     fn extract_dict_value_type(&self, slice: &ast::Expr) -> anyhow::Result<String> {
         if let ast::Expr::Tuple(tuple) = slice {
@@ -1216,11 +2627,20 @@ impl PythonParser {
         match expr {
             ast::Expr::Subscript(sub) => {
                 let base = self.expr_to_string(sub.value.as_ref());
+                if base == "Annotated" || base == "typing.Annotated" {
+                    // Annotated[T, StringConstraints(...)] -> the type of T
+                    if let ast::Expr::Tuple(tuple) = sub.slice.as_ref() {
+                        if let Some(first) = tuple.elts.first() {
+                            return self.extract_type_with_generics(first);
+                        }
+                    }
+                }
                 let slice_str = self.expr_to_string(sub.slice.as_ref());
 
                 if base == "Optional" {
-                    // Optional[T] -> (optional=true, T, None)
-                    Ok((true, slice_str, None))
+                    // Optional[T] -> (optional=true, T's base and inner types)
+                    let (_, base_type, inner) = self.extract_type_with_generics(&sub.slice)?;
+                    Ok((true, base_type, inner))
                 } else if base == "list" || base == "List" {
                     // list[T] -> (optional=false, "array", Some(T))
                     Ok((false, "array".to_string(), Some(slice_str)))
@@ -1238,6 +2658,11 @@ impl PythonParser {
                 if matches!(bin_op.op, Operator::BitOr) {
                     // Python 3.10+ union syntax: str | int | None
                     let (has_none, types) = self.collect_union_types(expr);
+                    // `list[T] | None` is read like `Optional[list[T]]`
+                    if let [member] = self.union_members(expr).as_slice() {
+                        let (_, base_type, inner) = self.extract_type_with_generics(member)?;
+                        return Ok((has_none, base_type, inner));
+                    }
                     let combined = if types.len() == 1 {
                         types[0].clone()
                     } else {
@@ -1248,6 +2673,16 @@ impl PythonParser {
                     Ok((false, self.expr_to_string(expr), None))
                 }
             }
+            ast::Expr::Call(call) if self.is_conlist_call(call) => {
+                // conlist(T, min_length=1) -> (optional=false, "array", Some(T))
+                let inner = call.args.first().map(|arg| self.expr_to_string(arg));
+                Ok((false, "array".to_string(), inner))
+            }
+            ast::Expr::Call(call) => match self.constrained_type(call) {
+                // constr(max_length=10) -> (optional=false, "str", None)
+                Some(base) => Ok((false, base.to_string(), None)),
+                None => Ok((false, self.expr_to_string(expr), None)),
+            },
             _ => {
                 // Check for Optional/Union using existing method
                 let (is_optional, base_type) = self.extract_optional_or_union_type(expr);
@@ -1256,6 +2691,183 @@ impl PythonParser {
         }
     }
 
+    /// Parses a string annotation (`"list[B]"`) into the expression it spells
+    fn parse_string_annotation(&self, expr: &ast::Expr) -> Option<ast::Expr> {
+        let ast::Expr::Constant(constant) = expr else {
+            return None;
+        };
+        let ast::Constant::Str(source) = &constant.value else {
+            return None;
+        };
+        match rustpython_parser::parse(
+            source.trim(),
+            rustpython_parser::Mode::Expression,
+            "<annotation>",
+        ) {
+            Ok(ast::Mod::Expression(expression)) => Some(*expression.body),
+            _ => None,
+        }
+    }
+
+    /// Names of the models an annotation references through strings (`"B"`, `list["B"]`,
+    /// `"Optional[models.B]"`)
+    ///
+    /// These forward references may point to models declared later in the file or in other
+    /// modules; typing names and builtins are skipped.
+    pub fn forward_refs(&self, annotation: &ast::Expr) -> Vec<String> {
+        let mut names = Vec::new();
+        self.collect_forward_refs(annotation, false, &mut names);
+        names
+    }
+
+    fn collect_forward_refs(&self, expr: &ast::Expr, quoted: bool, names: &mut Vec<String>) {
+        match expr {
+            ast::Expr::Constant(_) => {
+                if let Some(parsed) = self.parse_string_annotation(expr) {
+                    self.collect_forward_refs(&parsed, true, names);
+                }
+            }
+            ast::Expr::Name(_) | ast::Expr::Attribute(_) if quoted => {
+                let name = self.expr_to_string(expr);
+                let simple_name = name.rsplit('.').next().unwrap_or(&name);
+                let is_typing_name = simple_name.starts_with(|c: char| !c.is_ascii_uppercase())
+                    || TYPING_NAMES.contains(&simple_name);
+                if !is_typing_name && !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+            ast::Expr::Subscript(sub) => {
+                match self.expr_to_string(sub.value.as_ref()).as_str() {
+                    // Literal["a"] holds values, not types
+                    "Literal" | "typing.Literal" => {}
+                    // Annotated[T, Field(...)]: only T is a type
+                    "Annotated" | "typing.Annotated" => match sub.slice.as_ref() {
+                        ast::Expr::Tuple(tuple) => {
+                            if let Some(first) = tuple.elts.first() {
+                                self.collect_forward_refs(first, quoted, names);
+                            }
+                        }
+                        slice => self.collect_forward_refs(slice, quoted, names),
+                    },
+                    _ => self.collect_forward_refs(sub.slice.as_ref(), quoted, names),
+                }
+            }
+            ast::Expr::Tuple(tuple) => {
+                for elt in &tuple.elts {
+                    self.collect_forward_refs(elt, quoted, names);
+                }
+            }
+            ast::Expr::BinOp(bin_op) => {
+                self.collect_forward_refs(&bin_op.left, quoted, names);
+                self.collect_forward_refs(&bin_op.right, quoted, names);
+            }
+            _ => {}
+        }
+    }
+
+    /// Checks if a call is `conlist(...)` (constrained list type)
+    fn is_conlist_call(&self, call: &ast::ExprCall) -> bool {
+        self.call_name(&call.func)
+            .is_some_and(|name| name == "conlist" || name.ends_with(".conlist"))
+    }
+
+    /// Base type of a constrained type helper (`constr(...)` -> `str`, `conint(...)` -> `int`,
+    /// `confloat(...)` -> `float`)
+    fn constrained_type(&self, call: &ast::ExprCall) -> Option<&'static str> {
+        let name = self.call_name(&call.func)?;
+        match name.rsplit('.').next().unwrap_or(&name) {
+            "constr" => Some("str"),
+            "conint" => Some("int"),
+            "confloat" => Some("float"),
+            _ => None,
+        }
+    }
+
+    /// Constraints a field's annotation declares through Pydantic's constraint helpers
+    ///
+    /// Covers `constr`/`conint`/`confloat`/`conlist(T, min_length=..)` and the metadata of
+    /// `Annotated[str, StringConstraints(min_length=3)]` or `Annotated[int, Field(ge=0)]`.
+    /// Pydantic v1 spells list bounds `min_items`/`max_items`; they are stored as
+    /// `MinLength`/`MaxLength`, the same as `Field(min_length=..)` on a list field.
+    fn extract_annotation_constraints(
+        &self,
+        expr: &ast::Expr,
+    ) -> Vec<crate::models::FieldConstraint> {
+        match expr {
+            ast::Expr::Call(call)
+                if self.is_conlist_call(call) || self.constrained_type(call).is_some() =>
+            {
+                call.keywords
+                    .iter()
+                    .filter_map(|kw| self.keyword_constraint(kw))
+                    .collect()
+            }
+            ast::Expr::Subscript(sub)
+                if matches!(
+                    self.expr_to_string(sub.value.as_ref()).as_str(),
+                    "Annotated" | "typing.Annotated"
+                ) =>
+            {
+                let ast::Expr::Tuple(tuple) = sub.slice.as_ref() else {
+                    return Vec::new();
+                };
+                let mut constraints = Vec::new();
+                if let Some(first) = tuple.elts.first() {
+                    constraints.extend(self.extract_annotation_constraints(first));
+                }
+                for metadata in tuple.elts.iter().skip(1) {
+                    let ast::Expr::Call(call) = metadata else {
+                        continue;
+                    };
+                    let is_constraint_metadata = self.call_name(&call.func).is_some_and(|name| {
+                        matches!(
+                            name.rsplit('.').next().unwrap_or(&name),
+                            "Field" | "StringConstraints"
+                        )
+                    });
+                    if is_constraint_metadata {
+                        constraints.extend(
+                            call.keywords
+                                .iter()
+                                .filter_map(|kw| self.keyword_constraint(kw)),
+                        );
+                    } else {
+                        constraints.extend(self.extract_annotation_constraints(metadata));
+                    }
+                }
+                constraints
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Constraint a `Field(...)` or constraint helper keyword declares (`min_length=3`,
+    /// `gt=0`, `pattern="^a"`)
+    ///
+    /// Values other than literals (`max_length=MAX_NAME`) are skipped.
+    fn keyword_constraint(&self, kw: &ast::Keyword) -> Option<crate::models::FieldConstraint> {
+        use crate::models::FieldConstraint;
+
+        let value = self.literal_value(&kw.value)?;
+        match kw.arg.as_ref()?.as_str() {
+            // `min_items`/`max_items`: Pydantic v1 list bounds
+            "min_length" | "min_items" => value
+                .as_u64()
+                .map(|min| FieldConstraint::MinLength(min as usize)),
+            "max_length" | "max_items" => value
+                .as_u64()
+                .map(|max| FieldConstraint::MaxLength(max as usize)),
+            "min" | "ge" => value.as_f64().map(FieldConstraint::MinValue),
+            "max" | "le" => value.as_f64().map(FieldConstraint::MaxValue),
+            "gt" => value.as_f64().map(FieldConstraint::ExclusiveMinValue),
+            "lt" => value.as_f64().map(FieldConstraint::ExclusiveMaxValue),
+            "regex" | "pattern" => value
+                .as_str()
+                .map(|pattern| FieldConstraint::Pattern(pattern.to_string())),
+            _ => None,
+        }
+    }
+
     /// Extracts structured field constraints from Field() call
     fn extract_field_constraints_structured(
         &self,
@@ -1267,43 +2879,12 @@ impl PythonParser {
             // Check if it's Field() call
             if let Some(call_name) = self.call_name(&call_expr.func) {
                 if call_name == "Field" || call_name.ends_with(".Field") {
-                    for kw in &call_expr.keywords {
-                        if let Some(arg_name) = &kw.arg {
-                            let value_str = self.expr_to_string(&kw.value);
-
-                            match arg_name.as_str() {
-                                "min_length" => {
-                                    if let Ok(value) = value_str.parse::<usize>() {
-                                        constraints
-                                            .push(crate::models::FieldConstraint::MinLength(value));
-                                    }
-                                }
-                                "max_length" => {
-                                    if let Ok(value) = value_str.parse::<usize>() {
-                                        constraints
-                                            .push(crate::models::FieldConstraint::MaxLength(value));
-                                    }
-                                }
-                                "min" => {
-                                    if let Ok(value) = value_str.parse::<f64>() {
-                                        constraints
-                                            .push(crate::models::FieldConstraint::MinValue(value));
-                                    }
-                                }
-                                "max" => {
-                                    if let Ok(value) = value_str.parse::<f64>() {
-                                        constraints
-                                            .push(crate::models::FieldConstraint::MaxValue(value));
-                                    }
-                                }
-                                "regex" | "pattern" => {
-                                    constraints
-                                        .push(crate::models::FieldConstraint::Pattern(value_str));
-                                }
-                                _ => {}
-                            }
-                        }
-                    }
+                    constraints.extend(
+                        call_expr
+                            .keywords
+                            .iter()
+                            .filter_map(|kw| self.keyword_constraint(kw)),
+                    );
                 }
             }
         }
@@ -1322,42 +2903,455 @@ impl PythonParser {
             return Err(anyhow::anyhow!("Invalid field target"));
         };
 
-        let field_type_expr = ann_assign.annotation.as_ref();
+        // `b: "B | None"` is read like `b: B | None`
+        let parsed_annotation = self.parse_string_annotation(ann_assign.annotation.as_ref());
+        let field_type_expr = parsed_annotation
+            .as_ref()
+            .unwrap_or(ann_assign.annotation.as_ref());
 
         // Extract base type, inner type, and optionality
         let (is_optional, base_type, inner_type) =
             self.extract_type_with_generics(field_type_expr)?;
 
         // Extract constraints from Field() if present
-        let constraints = if let Some(value) = &ann_assign.value {
+        let mut constraints = if let Some(value) = &ann_assign.value {
             self.extract_field_constraints_structured(value)?
         } else {
             Vec::new()
         };
+        constraints.extend(self.extract_annotation_constraints(field_type_expr));
 
         // Extract default value
         let default_value = ann_assign.value.as_ref().and_then(|v| {
-            // Skip if it's Field() call
+            // Field() carries its default in `default=`, `default_factory=` or the first argument
             if let ast::Expr::Call(call) = v.as_ref() {
                 if let Some(call_name) = self.call_name(&call.func) {
                     if call_name == "Field" || call_name.ends_with(".Field") {
-                        return None;
+                        return self.extract_field_default(call);
                     }
                 }
             }
             Some(self.expr_to_string(v))
         });
 
+        // The same default as a JSON value, when it is a literal (`Field(default=10)`, `= "a"`)
+        let default_literal = ann_assign
+            .value
+            .as_deref()
+            .and_then(|v| self.field_default_expr(v))
+            .and_then(|expr| self.literal_value(expr));
+
+        // `Optional[...]` / `| None` only: a default is kept apart in `default_value`
+        let optional = is_optional;
+
+        // Field(frozen=True) marks server-managed values (ids, timestamps)
+        let response_only = ann_assign.value.as_ref().is_some_and(|v| {
+            if let ast::Expr::Call(call) = v.as_ref() {
+                if let Some(call_name) = self.call_name(&call.func) {
+                    if call_name == "Field" || call_name.ends_with(".Field") {
+                        return call.keywords.iter().any(|kw| {
+                            kw.arg.as_ref().map(|arg| arg.as_str()) == Some("frozen")
+                                && matches!(
+                                    &kw.value,
+                                    ast::Expr::Constant(c) if matches!(c.value, ast::Constant::Bool(true))
+                                )
+                        });
+                    }
+                }
+            }
+            false
+        });
+
+        // Field(deprecated=True) or Field(deprecated="Use `tags` instead")
+        let deprecated = ann_assign.value.as_ref().is_some_and(|v| {
+            if let ast::Expr::Call(call) = v.as_ref() {
+                if let Some(call_name) = self.call_name(&call.func) {
+                    if call_name == "Field" || call_name.ends_with(".Field") {
+                        return call.keywords.iter().any(|kw| {
+                            kw.arg.as_ref().map(|arg| arg.as_str()) == Some("deprecated")
+                                && !matches!(
+                                    &kw.value,
+                                    ast::Expr::Constant(c)
+                                        if matches!(c.value, ast::Constant::Bool(false) | ast::Constant::None)
+                                )
+                        });
+                    }
+                }
+            }
+            false
+        });
+
+        let discriminated_union =
+            self.extract_discriminated_union(field_type_expr, ann_assign.value.as_deref());
+
+        // Field(alias="petType"), Field(validation_alias="fullName", serialization_alias=...),
+        // as the default or in `Annotated[...]` metadata
+        let annotated_metadata: &[ast::Expr] = match field_type_expr {
+            ast::Expr::Subscript(sub)
+                if matches!(
+                    self.expr_to_string(&sub.value).as_str(),
+                    "Annotated" | "typing.Annotated"
+                ) =>
+            {
+                match sub.slice.as_ref() {
+                    ast::Expr::Tuple(tuple) => tuple.elts.get(1..).unwrap_or_default(),
+                    _ => &[],
+                }
+            }
+            _ => &[],
+        };
+        let field_alias = |keyword: &str| {
+            ann_assign
+                .value
+                .as_deref()
+                .into_iter()
+                .chain(annotated_metadata)
+                .find_map(|v| self.field_alias(v, keyword))
+        };
+        let alias = field_alias("alias");
+        let validation_alias = field_alias("validation_alias");
+        let serialization_alias = field_alias("serialization_alias");
+
+        // Field(examples=[...]) or the Pydantic v1 Field(example=...); literals only
+        let examples = ann_assign
+            .value
+            .as_ref()
+            .map(|v| self.extract_field_examples(v))
+            .unwrap_or_default();
+
         Ok(crate::models::PydanticFieldInfo {
             name: field_name,
             type_name: base_type,
             inner_type,
-            optional: is_optional,
+            optional,
             constraints,
             default_value,
+            response_only,
+            deprecated,
+            discriminated_union,
+            alias,
+            validation_alias,
+            serialization_alias,
+            examples,
+            default_literal,
+            nullable: is_optional,
+            item_values: self.list_item_values(field_type_expr),
+        })
+    }
+
+    /// String literal passed as `keyword` to a `Field(...)` call (`alias`,
+    /// `validation_alias`, `serialization_alias`), or the first choice of an `AliasChoices`;
+    /// `AliasPath` is skipped
+    fn field_alias(&self, value: &ast::Expr, keyword: &str) -> Option<String> {
+        let ast::Expr::Call(call) = value else {
+            return None;
+        };
+        let call_name = self.call_name(&call.func)?;
+        if call_name != "Field" && !call_name.ends_with(".Field") {
+            return None;
+        }
+        call.keywords.iter().find_map(|kw| {
+            if kw.arg.as_ref().map(|arg| arg.as_str()) != Some(keyword) {
+                return None;
+            }
+            match &kw.value {
+                ast::Expr::Constant(c) => match &c.value {
+                    ast::Constant::Str(alias) => Some(alias.clone()),
+                    _ => None,
+                },
+                // validation_alias=AliasChoices("userName", "user_name"): the first choice
+                ast::Expr::Call(choices)
+                    if self
+                        .call_name(&choices.func)
+                        .is_some_and(|name| name.rsplit('.').next() == Some("AliasChoices")) =>
+                {
+                    choices.args.iter().find_map(|choice| match choice {
+                        ast::Expr::Constant(c) => match &c.value {
+                            ast::Constant::Str(alias) => Some(alias.clone()),
+                            _ => None,
+                        },
+                        _ => None,
+                    })
+                }
+                _ => None,
+            }
+        })
+    }
+
+    /// Literal example values of a `Field(...)` call
+    fn extract_field_examples(&self, value: &ast::Expr) -> Vec<serde_json::Value> {
+        let ast::Expr::Call(call) = value else {
+            return Vec::new();
+        };
+        let is_field = self
+            .call_name(&call.func)
+            .is_some_and(|name| name == "Field" || name.ends_with(".Field"));
+        if !is_field {
+            return Vec::new();
+        }
+        let mut examples = Vec::new();
+        for kw in &call.keywords {
+            match (kw.arg.as_ref().map(|arg| arg.as_str()), &kw.value) {
+                (Some("examples"), ast::Expr::List(list)) => {
+                    examples.extend(list.elts.iter().filter_map(|elt| self.literal_value(elt)));
+                }
+                (Some("example"), value) => examples.extend(self.literal_value(value)),
+                _ => {}
+            }
+        }
+        examples
+    }
+
+    /// JSON value of a literal expression (`"a"`, `-1`, `2.5`, `None`, `[1, 2]`, `{"k": True}`)
+    fn literal_value(&self, expr: &ast::Expr) -> Option<serde_json::Value> {
+        use serde_json::Value;
+        match expr {
+            ast::Expr::Constant(constant) => match &constant.value {
+                ast::Constant::Str(value) => Some(Value::String(value.clone())),
+                ast::Constant::Bool(value) => Some(Value::Bool(*value)),
+                ast::Constant::None => Some(Value::Null),
+                ast::Constant::Int(value) => value.to_string().parse::<i64>().ok().map(Value::from),
+                ast::Constant::Float(value) => {
+                    serde_json::Number::from_f64(*value).map(Value::Number)
+                }
+                _ => None,
+            },
+            ast::Expr::UnaryOp(unary) if matches!(unary.op, ast::UnaryOp::USub) => {
+                match self.literal_value(&unary.operand)? {
+                    Value::Number(number) => match number.as_i64() {
+                        Some(value) => Some(Value::from(-value)),
+                        None => serde_json::Number::from_f64(-number.as_f64()?).map(Value::Number),
+                    },
+                    _ => None,
+                }
+            }
+            ast::Expr::List(list) => list
+                .elts
+                .iter()
+                .map(|elt| self.literal_value(elt))
+                .collect::<Option<Vec<_>>>()
+                .map(Value::Array),
+            ast::Expr::Dict(dict) => dict
+                .keys
+                .iter()
+                .zip(&dict.values)
+                .map(|(key, value)| match self.literal_value(key.as_ref()?)? {
+                    Value::String(key) => Some((key, self.literal_value(value)?)),
+                    _ => None,
+                })
+                .collect::<Option<serde_json::Map<_, _>>>()
+                .map(Value::Object),
+            _ => None,
+        }
+    }
+
+    /// Extracts a `@computed_field` property as an output field
+    ///
+    /// Computed fields are serialized like regular fields but can't be sent by clients, so they
+    /// are marked response-only. Works with `@property` and `@cached_property`; the type comes
+    /// from the return annotation or `computed_field(return_type=...)`.
+    /// Whether the decorators make a method a validator run on the raw input:
+    /// `@model_validator(mode="before")` (v2) or `@root_validator(pre=True)` (v1)
+    fn is_pre_validator(&self, decorators: &[ast::Expr]) -> bool {
+        decorators.iter().any(|decorator| {
+            let ast::Expr::Call(call) = decorator else {
+                return false;
+            };
+            let Some(name) = self.call_name(&call.func) else {
+                return false;
+            };
+            let (keyword, expected) = match name.rsplit('.').next() {
+                Some("model_validator") => ("mode", "before"),
+                Some("root_validator") => ("pre", "true"),
+                _ => return false,
+            };
+            call.keywords.iter().any(|kw| {
+                kw.arg.as_ref().map(|arg| arg.as_str()) == Some(keyword)
+                    && self.expr_to_string(&kw.value) == expected
+            })
         })
     }
 
+    fn extract_computed_field(
+        &self,
+        func_def: &ast::StmtFunctionDef,
+    ) -> Option<crate::models::PydanticFieldInfo> {
+        let decorator = func_def.decorator_list.iter().find(|decorator| {
+            let func = match decorator {
+                ast::Expr::Call(call) => call.func.as_ref(),
+                other => other,
+            };
+            self.call_name(func)
+                .is_some_and(|name| name == "computed_field" || name.ends_with(".computed_field"))
+        })?;
+
+        let return_type = match decorator {
+            ast::Expr::Call(call) => call
+                .keywords
+                .iter()
+                .find(|kw| kw.arg.as_ref().map(|arg| arg.as_str()) == Some("return_type"))
+                .map(|kw| &kw.value),
+            _ => None,
+        };
+        let (optional, type_name, inner_type) = match return_type.or(func_def.returns.as_deref()) {
+            Some(annotation) => self.extract_type_with_generics(annotation).ok()?,
+            None => (false, "Any".to_string(), None),
+        };
+
+        Some(crate::models::PydanticFieldInfo {
+            name: func_def.name.to_string(),
+            type_name,
+            inner_type,
+            optional,
+            constraints: Vec::new(),
+            default_value: None,
+            response_only: true,
+            deprecated: false,
+            discriminated_union: None,
+            alias: None,
+            validation_alias: None,
+            serialization_alias: None,
+            examples: Vec::new(),
+            default_literal: None,
+            nullable: optional,
+            item_values: None,
+        })
+    }
+
+    /// Extracts the discriminator and member names of a tagged union
+    ///
+    /// Handles `Union[Cat, Dog] = Field(discriminator="pet_type")` and
+    /// `Annotated[Cat | Dog, Field(discriminator="pet_type")]`. Member tags are
+    /// resolved later from the member models (see `resolve_discriminated_unions`).
+    fn extract_discriminated_union(
+        &self,
+        annotation: &ast::Expr,
+        value: Option<&ast::Expr>,
+    ) -> Option<crate::models::DiscriminatedUnion> {
+        let (union_expr, field_call) = match annotation {
+            ast::Expr::Subscript(sub) if self.expr_to_string(&sub.value) == "Annotated" => {
+                let ast::Expr::Tuple(tuple) = sub.slice.as_ref() else {
+                    return None;
+                };
+                let (first, rest) = tuple.elts.split_first()?;
+                let discriminator = rest.iter().find_map(|e| self.field_discriminator(e));
+                (first, discriminator)
+            }
+            _ => (annotation, value.and_then(|v| self.field_discriminator(v))),
+        };
+        let discriminator = field_call?;
+
+        let members = match union_expr {
+            ast::Expr::Subscript(sub) if self.expr_to_string(&sub.value) == "Union" => {
+                match sub.slice.as_ref() {
+                    ast::Expr::Tuple(tuple) => tuple
+                        .elts
+                        .iter()
+                        .map(|e| self.expr_to_string(e))
+                        .filter(|name| name != "None")
+                        .collect(),
+                    other => vec![self.expr_to_string(other)],
+                }
+            }
+            ast::Expr::BinOp(_) => self.collect_union_types(union_expr).1,
+            _ => Vec::new(),
+        };
+        if members.is_empty() {
+            return None;
+        }
+
+        Some(crate::models::DiscriminatedUnion {
+            discriminator,
+            discriminator_alias: None,
+            members: members
+                .into_iter()
+                .map(|schema| crate::models::UnionMember {
+                    schema,
+                    tag: None,
+                    nested: None,
+                })
+                .collect(),
+        })
+    }
+
+    /// Returns `x` for a `Field(discriminator="x")` call
+    fn field_discriminator(&self, expr: &ast::Expr) -> Option<String> {
+        let ast::Expr::Call(call) = expr else {
+            return None;
+        };
+        let call_name = self.call_name(&call.func)?;
+        if call_name != "Field" && !call_name.ends_with(".Field") {
+            return None;
+        }
+        call.keywords.iter().find_map(|kw| {
+            if kw.arg.as_ref().map(|arg| arg.as_str()) != Some("discriminator") {
+                return None;
+            }
+            match &kw.value {
+                ast::Expr::Constant(c) => match &c.value {
+                    ast::Constant::Str(name) => Some(name.clone()),
+                    _ => None,
+                },
+                _ => None,
+            }
+        })
+    }
+
+    /// Extracts the default of a `Field(...)` call
+    ///
+    /// `Field(default=0)` and `Field(0)` yield `0`, `Field(default_factory=list)` yields
+    /// `list()`. `Field(...)` (Ellipsis) and `Field()` without a default yield None.
+    fn extract_field_default(&self, call: &ast::ExprCall) -> Option<String> {
+        for kw in &call.keywords {
+            match kw.arg.as_ref().map(|arg| arg.as_str()) {
+                Some("default") => return self.non_ellipsis_to_string(&kw.value),
+                Some("default_factory") => {
+                    return Some(format!("{}()", self.expr_to_string(&kw.value)))
+                }
+                _ => {}
+            }
+        }
+
+        call.args
+            .first()
+            .and_then(|arg| self.non_ellipsis_to_string(arg))
+    }
+
+    /// Expression of a field's default value: `default=` or the first argument of `Field(...)`,
+    /// or the assigned value itself; `default_factory` and `...` have none
+    fn field_default_expr<'a>(&self, value: &'a ast::Expr) -> Option<&'a ast::Expr> {
+        let default = match value {
+            ast::Expr::Call(call)
+                if self
+                    .call_name(&call.func)
+                    .is_some_and(|name| name == "Field" || name.ends_with(".Field")) =>
+            {
+                call.keywords
+                    .iter()
+                    .find(|kw| kw.arg.as_ref().map(|arg| arg.as_str()) == Some("default"))
+                    .map(|kw| &kw.value)
+                    .or_else(|| call.args.first())?
+            }
+            other => other,
+        };
+        match default {
+            ast::Expr::Constant(constant) if matches!(constant.value, ast::Constant::Ellipsis) => {
+                None
+            }
+            _ => Some(default),
+        }
+    }
+
+    /// Converts an expression to string unless it is the `...` required marker
+    fn non_ellipsis_to_string(&self, expr: &ast::Expr) -> Option<String> {
+        match expr {
+            ast::Expr::Constant(constant) if matches!(constant.value, ast::Constant::Ellipsis) => {
+                None
+            }
+            _ => Some(self.expr_to_string(expr)),
+        }
+    }
+
     /// Extracts class name from expression (handles dotted names)
     fn extract_class_name_from_expr(&self, expr: &ast::Expr) -> String {
         let expr_str = self.expr_to_string(expr);
@@ -1369,6 +3363,37 @@ impl PythonParser {
             .to_string()
     }
 
+    /// Whether a class is a Pydantic model: it inherits from `BaseModel`, directly or through
+    /// other classes of the same file
+    ///
+    /// `visiting` guards against inheritance cycles.
+    fn is_pydantic_model_in(
+        &self,
+        class_def: &ast::StmtClassDef,
+        ast: &ast::Mod,
+        visiting: &mut Vec<String>,
+    ) -> bool {
+        if self.is_pydantic_base_model(&class_def.bases) {
+            return true;
+        }
+        if visiting.iter().any(|name| name == class_def.name.as_str()) {
+            return false;
+        }
+        visiting.push(class_def.name.to_string());
+        let ast::Mod::Module(module) = ast else {
+            return false;
+        };
+        class_def.bases.iter().any(|base| {
+            let base_name = self.extract_class_name_from_expr(base);
+            module.body.iter().any(|stmt| match stmt {
+                ast::Stmt::ClassDef(base_def) if base_def.name.as_str() == base_name => {
+                    self.is_pydantic_model_in(base_def, ast, visiting)
+                }
+                _ => false,
+            })
+        })
+    }
+
     /// Finds a Pydantic model by name in the AST
     fn find_pydantic_model_by_name<'a>(
         &self,
@@ -1380,7 +3405,7 @@ impl PythonParser {
                 if let ast::Stmt::ClassDef(class_def) = stmt {
                     if class_def.name.as_str() == class_name {
                         // Check if it's a Pydantic model
-                        if self.is_pydantic_base_model(&class_def.bases) {
+                        if self.is_pydantic_model_in(class_def, ast, &mut Vec::new()) {
                             return Some(class_def);
                         }
                     }
@@ -1399,10 +3424,18 @@ impl PythonParser {
 
         // Extract fields from current class
         for body_stmt in &class_def.body {
-            if let ast::Stmt::AnnAssign(ann_assign) = body_stmt {
-                if let Ok(field_info) = self.extract_field_info(ann_assign) {
-                    fields.push(field_info);
+            match body_stmt {
+                ast::Stmt::AnnAssign(ann_assign) => {
+                    if let Ok(field_info) = self.extract_field_info(ann_assign) {
+                        fields.push(field_info);
+                    }
+                }
+                ast::Stmt::FunctionDef(func_def) => {
+                    if let Some(field_info) = self.extract_computed_field(func_def) {
+                        fields.push(field_info);
+                    }
                 }
+                _ => {}
             }
         }
 
@@ -1,22 +1,55 @@
 use crate::models::{Location, SchemaReference, SchemaType, TypeInfo};
-use crate::parsers::{Call, CallArgument, FunctionInfo, Import, LocationConverter};
+use crate::parsers::{
+    Call, CallArgument, FunctionInfo, Import, LocationConverter, ENUM_VARIANTS_METADATA_KEY,
+};
 use anyhow::Result;
 use std::path::Path;
-use swc_common::{sync::Lrc, FileName, SourceMap};
+use swc_common::{sync::Lrc, FileName, SourceMap, Spanned};
 use swc_ecma_ast::*;
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsSyntax};
 
+/// Global types a parameter may be annotated with that are never user schemas
+const BUILTIN_TYPE_NAMES: &[&str] = &[
+    "Date",
+    "Object",
+    "String",
+    "Number",
+    "Boolean",
+    "Function",
+    "RegExp",
+    "Error",
+    "Blob",
+    "File",
+    "FormData",
+    "Headers",
+    "Request",
+    "Response",
+    "URL",
+    "URLSearchParams",
+];
+
+/// Type alias metadata key naming the utility type the alias applies
+/// (`Partial`, `Required`, `Readonly`, `Pick` or `Omit`)
+pub const UTILITY_TYPE_METADATA_KEY: &str = "utility_type";
+
+/// Type alias metadata key naming the type a utility type is applied to (`User` in
+/// `Partial<User>`); while the alias has no `fields`, resolution is pending
+pub const UTILITY_BASE_METADATA_KEY: &str = "utility_base";
+
+/// Type alias metadata key listing the keys a `Pick`/`Omit` alias selects, comma-separated
+pub const UTILITY_KEYS_METADATA_KEY: &str = "utility_keys";
+
+/// Schema metadata key holding validation constraints of fields, as a JSON map of field
+/// name to `Constraint`s (e.g. from the class-validator decorators of a NestJS DTO)
+pub const FIELD_CONSTRAINTS_METADATA_KEY: &str = "field_constraints";
+
 /// TypeScript code parser with call analysis (via swc)
-pub struct TypeScriptParser {
-    source_map: SourceMap,
-}
+pub struct TypeScriptParser;
 
 impl TypeScriptParser {
     /// Creates a new parser
     pub fn new() -> Self {
-        Self {
-            source_map: SourceMap::default(),
-        }
+        Self
     }
 
     /// Parses a file via swc
@@ -29,14 +62,17 @@ impl TypeScriptParser {
 
     /// Parses source code
     fn parse_source(&self, source: &str, path: &Path) -> Result<Module> {
+        // A fresh SourceMap per file keeps span offsets relative to this file's source,
+        // which is what LocationConverter expects (a shared map keeps growing offsets)
+        let source_map = SourceMap::default();
         let file_name: Lrc<FileName> = FileName::Real(path.to_path_buf()).into();
-        let fm = self
-            .source_map
-            .new_source_file(file_name, source.to_string());
+        let fm = source_map.new_source_file(file_name, source.to_string());
 
         let is_tsx = path.extension().and_then(|e| e.to_str()) == Some("tsx");
+        // Decorators are needed for NestJS controllers and class-validator DTOs
         let syntax = Syntax::Typescript(TsSyntax {
             tsx: is_tsx,
+            decorators: true,
             ..Default::default()
         });
 
@@ -218,6 +254,29 @@ impl TypeScriptParser {
             Stmt::Block(block_stmt) => {
                 self.walk_block_stmt(block_stmt, context, calls, file_path, converter, source);
             }
+            Stmt::Try(try_stmt) => {
+                self.walk_block_stmt(
+                    &try_stmt.block,
+                    context,
+                    calls,
+                    file_path,
+                    converter,
+                    source,
+                );
+                if let Some(handler) = &try_stmt.handler {
+                    self.walk_block_stmt(
+                        &handler.body,
+                        context,
+                        calls,
+                        file_path,
+                        converter,
+                        source,
+                    );
+                }
+                if let Some(finalizer) = &try_stmt.finalizer {
+                    self.walk_block_stmt(finalizer, context, calls, file_path, converter, source);
+                }
+            }
             Stmt::Decl(Decl::Fn(fn_decl)) => {
                 context.push(fn_decl.ident.sym.as_ref().to_string());
                 if let Some(body) = &fn_decl.function.body {
@@ -398,10 +457,459 @@ impl TypeScriptParser {
                     source,
                 );
             }
+            // `await fetch(...)`, `(fetch(...))`
+            Expr::Await(await_expr) => {
+                self.walk_expr(
+                    await_expr.arg.as_ref(),
+                    context,
+                    calls,
+                    file_path,
+                    converter,
+                    source,
+                );
+            }
+            Expr::Paren(paren_expr) => {
+                self.walk_expr(
+                    paren_expr.expr.as_ref(),
+                    context,
+                    calls,
+                    file_path,
+                    converter,
+                    source,
+                );
+            }
+            // Callbacks (`.then(res => ...)`) keep the enclosing function as caller
+            Expr::Arrow(arrow) => match arrow.body.as_ref() {
+                BlockStmtOrExpr::BlockStmt(block) => {
+                    self.walk_block_stmt(block, context, calls, file_path, converter, source);
+                }
+                BlockStmtOrExpr::Expr(body) => {
+                    self.walk_expr(body, context, calls, file_path, converter, source);
+                }
+            },
+            _ => {}
+        }
+    }
+
+    /// Extracts comparisons of a response `.status` with numeric literals
+    ///
+    /// Handles `res.status === 404`, `404 == res.status`, ranges (`err.response?.status >= 500`,
+    /// `res.status >= 400 && res.status < 500`) and `switch (res.status) { case 401: ... }`.
+    /// Callers follow the same convention as [`Self::extract_calls`], so checks can be matched
+    /// to the API calls made in the same function.
+    pub fn extract_status_checks(
+        &self,
+        module: &Module,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) -> Vec<crate::parsers::StatusCheck> {
+        let mut checks = Vec::new();
+        let mut context = Vec::new();
+
+        for item in &module.body {
+            match item {
+                ModuleItem::Stmt(stmt) => {
+                    self.walk_stmt_for_status(
+                        stmt,
+                        &mut context,
+                        &mut checks,
+                        file_path,
+                        converter,
+                    );
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                    self.walk_decl_for_status(
+                        &export_decl.decl,
+                        &mut context,
+                        &mut checks,
+                        file_path,
+                        converter,
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        checks
+    }
+
+    /// Extracts request bodies serialized with `JSON.stringify(...)` in HTTP call arguments
+    ///
+    /// Handles `fetch(url, { body: JSON.stringify(x) })`, `axios({ data: JSON.stringify(x) })`
+    /// and `axios.post(url, JSON.stringify(x))`. `x` may be a typed parameter or variable
+    /// (`payload: CreateUser`), a variable initialized with an object literal, or an inline
+    /// object literal whose field types are inferred from literals and typed identifiers.
+    /// Spreads are merged when their fields are known; otherwise the body is skipped.
+    pub fn extract_request_bodies(
+        &self,
+        module: &Module,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) -> Vec<crate::parsers::SerializedBody> {
+        let local_schemas = self
+            .extract_typescript_schemas(module, file_path, converter)
+            .into_iter()
+            .map(|schema| (schema.name.clone(), schema))
+            .collect();
+        let mut collector = RequestBodyCollector {
+            parser: self,
+            file_path,
+            converter,
+            local_schemas,
+            scope: Vec::new(),
+            bodies: Vec::new(),
+        };
+
+        for item in &module.body {
+            match item {
+                ModuleItem::Stmt(stmt) => collector.walk_stmt(stmt),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                    collector.walk_decl(&export_decl.decl);
+                }
+                _ => {}
+            }
+        }
+
+        collector.bodies
+    }
+
+    /// Extracts routes registered on Express apps and routers
+    ///
+    /// Handles `app.get("/users", handler)` and `router.post("/users", auth, handler)` on
+    /// `app`/`router` and on variables initialized with `express()`, `express.Router()` or
+    /// `Router()`; the last argument is the handler. Body and response types are read from the
+    /// handler when it is an inline function or a function declared in the module: the
+    /// `ResBody`/`ReqBody` arguments of `Request<...>` and `Response<...>` annotations,
+    /// `req.body` assigned to a typed variable or cast with `as`, and typed values passed to
+    /// `res.json(...)`/`res.send(...)`.
+    pub fn extract_express_routes(
+        &self,
+        module: &Module,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) -> Vec<crate::parsers::ExpressRoute> {
+        let mut collector = ExpressRouteCollector {
+            parser: self,
+            file_path,
+            converter,
+            receivers: ["app", "router"].into_iter().map(String::from).collect(),
+            handlers: std::collections::HashMap::new(),
+            routes: Vec::new(),
+        };
+
+        // Apps, routers and named handlers declared at module level
+        for item in &module.body {
+            let decl = match item {
+                ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => &export_decl.decl,
+                _ => continue,
+            };
+            collector.declare(decl);
+        }
+        for item in &module.body {
+            match item {
+                ModuleItem::Stmt(stmt) => collector.walk_stmt(stmt),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                    if let Decl::Fn(fn_decl) = &export_decl.decl {
+                        collector.walk_function(&fn_decl.function);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        collector.routes
+    }
+
+    /// Extracts HTTP requests made with `fetch` and axios-style clients
+    ///
+    /// Handles `fetch(url, { method, body })`, `axios(config)` / `client.request(config)`, and
+    /// `client.get(url)` / `client.post(url, body)` on `axios`, on variables and class
+    /// properties initialized with `axios.create()`, and on bindings explicitly typed as an
+    /// HTTP client (`api: AxiosInstance`, `constructor(private http: HttpClient)`). Other
+    /// receivers (`cache.get(...)`, an untyped `client`) are not HTTP clients.
+    pub fn extract_http_calls(
+        &self,
+        module: &Module,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) -> Vec<crate::parsers::HttpCall> {
+        let mut collector = HttpCallCollector {
+            parser: self,
+            file_path,
+            converter,
+            clients: std::iter::once("axios".to_string()).collect(),
+            class_clients: Vec::new(),
+            scope: Vec::new(),
+            calls: Vec::new(),
+        };
+
+        for item in &module.body {
+            match item {
+                ModuleItem::Stmt(stmt) => collector.walk_stmt(stmt),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                    collector.walk_decl(&export_decl.decl);
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export_default)) => {
+                    match &export_default.decl {
+                        DefaultDecl::Fn(fn_expr) => collector.walk_function(&fn_expr.function),
+                        DefaultDecl::Class(class_expr) => collector.walk_class(&class_expr.class),
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        collector.calls
+    }
+
+    /// Traverses Statement and collects status checks
+    fn walk_stmt_for_status(
+        &self,
+        stmt: &Stmt,
+        context: &mut Vec<String>,
+        checks: &mut Vec<crate::parsers::StatusCheck>,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) {
+        let mut walk_block = |block: &BlockStmt, context: &mut Vec<String>| {
+            for stmt in &block.stmts {
+                self.walk_stmt_for_status(stmt, context, checks, file_path, converter);
+            }
+        };
+
+        match stmt {
+            Stmt::Expr(expr_stmt) => {
+                self.walk_expr_for_status(&expr_stmt.expr, context, checks, file_path, converter);
+            }
+            Stmt::Return(ret_stmt) => {
+                if let Some(arg) = &ret_stmt.arg {
+                    self.walk_expr_for_status(arg, context, checks, file_path, converter);
+                }
+            }
+            Stmt::If(if_stmt) => {
+                self.walk_expr_for_status(&if_stmt.test, context, checks, file_path, converter);
+                self.walk_stmt_for_status(&if_stmt.cons, context, checks, file_path, converter);
+                if let Some(alt) = &if_stmt.alt {
+                    self.walk_stmt_for_status(alt, context, checks, file_path, converter);
+                }
+            }
+            Stmt::Switch(switch_stmt) => {
+                let on_status = Self::is_status_member(&switch_stmt.discriminant);
+                for case in &switch_stmt.cases {
+                    if let Some(code) = case.test.as_deref().and_then(Self::status_code_literal) {
+                        if on_status {
+                            let (line, column) =
+                                converter.byte_offset_to_location(case.span.lo.0 as usize);
+                            checks.push(crate::parsers::StatusCheck {
+                                status: crate::models::HandledStatus::Exact(code),
+                                caller: (!context.is_empty()).then(|| context.join(".")),
+                                location: Location {
+                                    file: file_path.to_string(),
+                                    line,
+                                    column: Some(column),
+                                },
+                            });
+                        }
+                    }
+                    for stmt in &case.cons {
+                        self.walk_stmt_for_status(stmt, context, checks, file_path, converter);
+                    }
+                }
+            }
+            Stmt::Block(block) => walk_block(block, context),
+            Stmt::Try(try_stmt) => {
+                walk_block(&try_stmt.block, context);
+                if let Some(handler) = &try_stmt.handler {
+                    walk_block(&handler.body, context);
+                }
+                if let Some(finalizer) = &try_stmt.finalizer {
+                    walk_block(finalizer, context);
+                }
+            }
+            Stmt::While(while_stmt) => {
+                self.walk_stmt_for_status(&while_stmt.body, context, checks, file_path, converter);
+            }
+            Stmt::For(for_stmt) => {
+                self.walk_stmt_for_status(&for_stmt.body, context, checks, file_path, converter);
+            }
+            Stmt::Decl(decl) => {
+                self.walk_decl_for_status(decl, context, checks, file_path, converter);
+            }
+            _ => {}
+        }
+    }
+
+    /// Traverses Declaration (functions push their name as caller context)
+    fn walk_decl_for_status(
+        &self,
+        decl: &Decl,
+        context: &mut Vec<String>,
+        checks: &mut Vec<crate::parsers::StatusCheck>,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) {
+        match decl {
+            Decl::Fn(fn_decl) => {
+                context.push(fn_decl.ident.sym.as_ref().to_string());
+                if let Some(body) = &fn_decl.function.body {
+                    for stmt in &body.stmts {
+                        self.walk_stmt_for_status(stmt, context, checks, file_path, converter);
+                    }
+                }
+                context.pop();
+            }
+            Decl::Var(var_decl) => {
+                for decl in &var_decl.decls {
+                    if let Some(init) = &decl.init {
+                        self.walk_expr_for_status(init, context, checks, file_path, converter);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Traverses Expression and collects status checks
+    fn walk_expr_for_status(
+        &self,
+        expr: &Expr,
+        context: &mut Vec<String>,
+        checks: &mut Vec<crate::parsers::StatusCheck>,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) {
+        match expr {
+            Expr::Bin(bin_expr) => {
+                if let Some(status) = self.status_condition(bin_expr) {
+                    let (line, column) =
+                        converter.byte_offset_to_location(bin_expr.span.lo.0 as usize);
+                    checks.push(crate::parsers::StatusCheck {
+                        status,
+                        caller: (!context.is_empty()).then(|| context.join(".")),
+                        location: Location {
+                            file: file_path.to_string(),
+                            line,
+                            column: Some(column),
+                        },
+                    });
+                    return;
+                }
+                self.walk_expr_for_status(&bin_expr.left, context, checks, file_path, converter);
+                self.walk_expr_for_status(&bin_expr.right, context, checks, file_path, converter);
+            }
+            Expr::Paren(paren) => {
+                self.walk_expr_for_status(&paren.expr, context, checks, file_path, converter);
+            }
+            Expr::Unary(unary) => {
+                self.walk_expr_for_status(&unary.arg, context, checks, file_path, converter);
+            }
+            Expr::Await(await_expr) => {
+                self.walk_expr_for_status(&await_expr.arg, context, checks, file_path, converter);
+            }
+            Expr::Cond(cond) => {
+                self.walk_expr_for_status(&cond.test, context, checks, file_path, converter);
+                self.walk_expr_for_status(&cond.cons, context, checks, file_path, converter);
+                self.walk_expr_for_status(&cond.alt, context, checks, file_path, converter);
+            }
+            Expr::Assign(assign) => {
+                self.walk_expr_for_status(&assign.right, context, checks, file_path, converter);
+            }
+            Expr::Call(call) => {
+                if let Callee::Expr(callee) = &call.callee {
+                    self.walk_expr_for_status(callee, context, checks, file_path, converter);
+                }
+                for arg in &call.args {
+                    self.walk_expr_for_status(&arg.expr, context, checks, file_path, converter);
+                }
+            }
+            Expr::Member(member) => {
+                self.walk_expr_for_status(&member.obj, context, checks, file_path, converter);
+            }
+            Expr::Arrow(arrow) => match arrow.body.as_ref() {
+                BlockStmtOrExpr::BlockStmt(block) => {
+                    for stmt in &block.stmts {
+                        self.walk_stmt_for_status(stmt, context, checks, file_path, converter);
+                    }
+                }
+                BlockStmtOrExpr::Expr(body) => {
+                    self.walk_expr_for_status(body, context, checks, file_path, converter);
+                }
+            },
             _ => {}
         }
     }
 
+    /// Converts a status comparison (or `&&` of two comparisons) into the handled status
+    fn status_condition(&self, bin_expr: &BinExpr) -> Option<crate::models::HandledStatus> {
+        use crate::models::HandledStatus;
+
+        if bin_expr.op == BinaryOp::LogicalAnd {
+            let (Expr::Bin(left), Expr::Bin(right)) =
+                (bin_expr.left.as_ref(), bin_expr.right.as_ref())
+            else {
+                return None;
+            };
+            return self
+                .status_condition(left)?
+                .intersect(&self.status_condition(right)?);
+        }
+
+        // Normalize to `<status> <op> <code>`
+        let (op, code) = if Self::is_status_member(&bin_expr.left) {
+            (bin_expr.op, Self::status_code_literal(&bin_expr.right)?)
+        } else if Self::is_status_member(&bin_expr.right) {
+            let flipped = match bin_expr.op {
+                BinaryOp::Gt => BinaryOp::Lt,
+                BinaryOp::GtEq => BinaryOp::LtEq,
+                BinaryOp::Lt => BinaryOp::Gt,
+                BinaryOp::LtEq => BinaryOp::GtEq,
+                op => op,
+            };
+            (flipped, Self::status_code_literal(&bin_expr.left)?)
+        } else {
+            return None;
+        };
+
+        let range = |min: u16, max: u16| (min <= max).then_some(HandledStatus::Range { min, max });
+        match op {
+            BinaryOp::EqEqEq | BinaryOp::EqEq => Some(HandledStatus::Exact(code)),
+            BinaryOp::Gt => range(code + 1, HandledStatus::MAX_CODE),
+            BinaryOp::GtEq => range(code, HandledStatus::MAX_CODE),
+            BinaryOp::Lt => range(HandledStatus::MIN_CODE, code - 1),
+            BinaryOp::LtEq => range(HandledStatus::MIN_CODE, code),
+            _ => None,
+        }
+    }
+
+    /// Checks if expression reads a response status: `res.status`, `err.response?.status`
+    fn is_status_member(expr: &Expr) -> bool {
+        let member = match expr {
+            Expr::Member(member) => member,
+            Expr::OptChain(opt_chain) => match opt_chain.base.as_ref() {
+                OptChainBase::Member(member) => member,
+                _ => return false,
+            },
+            Expr::Paren(paren) => return Self::is_status_member(&paren.expr),
+            _ => return false,
+        };
+        matches!(&member.prop, MemberProp::Ident(prop) if prop.sym.as_ref() == "status")
+    }
+
+    /// Returns an integer literal in the HTTP status code range
+    fn status_code_literal(expr: &Expr) -> Option<u16> {
+        let Expr::Lit(Lit::Num(num)) = expr else {
+            return None;
+        };
+        let code = num.value;
+        let valid = code.fract() == 0.0
+            && (f64::from(crate::models::HandledStatus::MIN_CODE)
+                ..=f64::from(crate::models::HandledStatus::MAX_CODE))
+                .contains(&code);
+        valid.then_some(code as u16)
+    }
+
     /// Traverses ExprOrSpread
     fn walk_expr_or_spread(
         &self,
@@ -783,6 +1291,42 @@ impl TypeScriptParser {
                     "call(...)".to_string()
                 }
             }
+            // `/users/${id}` keeps its static parts so URLs built from templates stay readable
+            Expr::Tpl(tpl) => {
+                let mut rendered = String::from("`");
+                for (idx, quasi) in tpl.quasis.iter().enumerate() {
+                    rendered.push_str(&quasi.raw);
+                    if let Some(expr) = tpl.exprs.get(idx) {
+                        rendered.push_str(&format!("${{{}}}", self.expr_to_string(expr)));
+                    }
+                }
+                rendered.push('`');
+                rendered
+            }
+            // `{ method: "POST", body: ... }` so fetch/axios options can be inspected
+            Expr::Object(object) => {
+                let props: Vec<String> = object
+                    .props
+                    .iter()
+                    .map(|prop| match prop {
+                        PropOrSpread::Spread(spread) => {
+                            format!("...{}", self.expr_to_string(&spread.expr))
+                        }
+                        PropOrSpread::Prop(prop) => match prop.as_ref() {
+                            Prop::KeyValue(key_value) => format!(
+                                "{}: {}",
+                                prop_name(&key_value.key).unwrap_or_else(|| "[...]".to_string()),
+                                self.expr_to_string(&key_value.value)
+                            ),
+                            Prop::Shorthand(ident) => ident.sym.to_string(),
+                            _ => "...".to_string(),
+                        },
+                    })
+                    .collect();
+                format!("{{ {} }}", props.join(", "))
+            }
+            // `new FormData(form)`, so request bodies can be told apart
+            Expr::New(new_expr) => format!("new {}(...)", self.expr_to_string(&new_expr.callee)),
             _ => "expr".to_string(),
         }
     }
@@ -807,75 +1351,367 @@ impl TypeScriptParser {
             self.walk_for_zod(item, &mut schemas, file_path, converter, &ts_schema_map);
         }
 
+        Self::resolve_lazy_schema_fields(&mut schemas);
+        self.attach_zod_discriminated_unions(module, &mut schemas);
+
         schemas
     }
 
-    /// Traverses AST to find Zod schemas
-    fn walk_for_zod(
-        &self,
-        item: &ModuleItem,
-        schemas: &mut Vec<SchemaReference>,
-        file_path: &str,
-        converter: &LocationConverter,
-        ts_schema_map: &std::collections::HashMap<String, SchemaReference>,
-    ) {
-        match item {
-            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
-                for decl in &var_decl.decls {
-                    if let Some(init) = &decl.init {
-                        if let Expr::Call(call_expr) = init.as_ref() {
-                            if let Callee::Expr(callee_expr) = &call_expr.callee {
-                                if self.is_zod_call(callee_expr.as_ref()) {
-                                    let span = call_expr.span;
-                                    let (line, column) =
-                                        converter.byte_offset_to_location(span.lo.0 as usize);
-
-                                    let schema_name = match &decl.name {
-                                        Pat::Ident(ident) => ident.id.sym.as_ref().to_string(),
-                                        _ => "ZodSchema".to_string(),
-                                    };
-
-                                    let mut metadata = std::collections::HashMap::new();
+    /// Attaches tagged unions declared with Zod
+    ///
+    /// `z.discriminatedUnion("kind", [Cat, Dog])` uses the given tag field, `z.union([Cat, Dog])`
+    /// infers it like TypeScript unions. Member tags come from the members' `z.literal(...)`
+    /// fields. Union schemas get `discriminated_union` metadata; `z.object` fields holding a
+    /// union (by name or inline) are listed in `discriminated_unions` (field name -> union).
+    fn attach_zod_discriminated_unions(&self, module: &Module, schemas: &mut [SchemaReference]) {
+        let mut literal_props = std::collections::HashMap::new();
+        let mut union_sources = std::collections::HashMap::new();
+        let mut explicit_tags = std::collections::HashMap::new();
+        let mut objects = Vec::new();
 
-                                    // Try to find associated TypeScript type
-                                    if let Some(ts_schema) = ts_schema_map.get(&schema_name) {
-                                        // Link Zod schema with TypeScript type
-                                        metadata.insert(
-                                            "typescript_type".to_string(),
-                                            ts_schema.name.clone(),
-                                        );
-                                        // Copy fields from TypeScript schema if present
-                                        if let Some(fields) = ts_schema.metadata.get("fields") {
-                                            metadata.insert("fields".to_string(), fields.clone());
-                                        }
-                                    }
+        for item in &module.body {
+            let var_decl = match item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                    match &export_decl.decl {
+                        Decl::Var(var_decl) => var_decl,
+                        _ => continue,
+                    }
+                }
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => var_decl,
+                _ => continue,
+            };
+            for decl in &var_decl.decls {
+                let (Pat::Ident(ident), Some(init)) = (&decl.name, &decl.init) else {
+                    continue;
+                };
+                let Some(call) = self.zod_root_call(init) else {
+                    continue;
+                };
+                let name = ident.id.sym.as_ref().to_string();
+                if let Some((explicit, sources)) = self.zod_union_sources(call) {
+                    if let Some(discriminator) = explicit {
+                        explicit_tags.insert(name.clone(), discriminator);
+                    }
+                    union_sources.insert(name, sources);
+                } else if matches!(&call.callee, Callee::Expr(callee) if self.is_zod_object_call(callee))
+                {
+                    literal_props.insert(name.clone(), self.zod_literal_props(call));
+                    objects.push((name, call));
+                }
+            }
+        }
 
-                                    // Check if this is z.object() and extract fields
-                                    if self.is_zod_object_call(callee_expr.as_ref()) {
-                                        let fields = self.extract_zod_object_fields(call_expr);
-                                        if !fields.is_empty() {
-                                            // Store fields as JSON
-                                            if let Ok(fields_json) = serde_json::to_string(&fields)
-                                            {
-                                                metadata.insert("fields".to_string(), fields_json);
-                                            }
-                                        }
-                                    }
+        let build =
+            |sources: &[UnionMemberSource], explicit: Option<&String>, root: Option<&str>| {
+                let mut visiting: Vec<String> = root.map(str::to_string).into_iter().collect();
+                Self::build_zod_union(
+                    sources,
+                    explicit.map(String::as_str),
+                    &literal_props,
+                    &union_sources,
+                    &mut visiting,
+                )
+            };
 
-                                    schemas.push(SchemaReference {
-                                        name: schema_name,
-                                        schema_type: SchemaType::Zod,
-                                        location: Location {
-                                            file: file_path.to_string(),
-                                            line,
-                                            column: Some(column),
-                                        },
-                                        metadata,
-                                    });
-                                }
-                            }
+        let mut field_unions = std::collections::HashMap::new();
+        for (name, call) in &objects {
+            let mut unions = std::collections::HashMap::new();
+            for (field, value) in self.zod_object_props(call) {
+                let union = match self.zod_root_ident(value) {
+                    Some(union_name) => union_sources.get(&union_name).and_then(|sources| {
+                        build(sources, explicit_tags.get(&union_name), Some(&union_name))
+                    }),
+                    None => self
+                        .zod_root_call(value)
+                        .and_then(|inner| self.zod_union_sources(inner))
+                        .and_then(|(explicit, sources)| build(&sources, explicit.as_ref(), None)),
+                };
+                if let Some(union) = union {
+                    unions.insert(field, union);
+                }
+            }
+            if !unions.is_empty() {
+                field_unions.insert(name.clone(), unions);
+            }
+        }
+
+        for schema in schemas
+            .iter_mut()
+            .filter(|schema| schema.schema_type == SchemaType::Zod)
+        {
+            if let Some(union) = union_sources.get(&schema.name).and_then(|sources| {
+                build(sources, explicit_tags.get(&schema.name), Some(&schema.name))
+            }) {
+                if let Ok(union_json) = serde_json::to_string(&union) {
+                    schema
+                        .metadata
+                        .insert("discriminated_union".to_string(), union_json);
+                }
+            }
+            if let Some(unions) = field_unions.get(&schema.name) {
+                if let Ok(unions_json) = serde_json::to_string(unions) {
+                    schema
+                        .metadata
+                        .insert("discriminated_unions".to_string(), unions_json);
+                }
+            }
+        }
+    }
+
+    /// Builds a Zod union; with an explicit discriminator (`z.discriminatedUnion`) members are
+    /// tagged by that field, otherwise the tag field is inferred
+    fn build_zod_union(
+        sources: &[UnionMemberSource],
+        explicit: Option<&str>,
+        literal_props: &std::collections::HashMap<String, Vec<(String, String)>>,
+        union_sources: &std::collections::HashMap<String, Vec<UnionMemberSource>>,
+        visiting: &mut Vec<String>,
+    ) -> Option<crate::models::DiscriminatedUnion> {
+        let Some(discriminator) = explicit else {
+            return Self::build_ts_union(sources, literal_props, union_sources, visiting)
+                .map(|(union, _)| union);
+        };
+
+        let tag_of = |props: &[(String, String)]| {
+            props
+                .iter()
+                .find(|(prop, _)| prop == discriminator)
+                .map(|(_, value)| value.clone())
+        };
+        let members = sources
+            .iter()
+            .map(|source| match source {
+                UnionMemberSource::Named(name) => match union_sources.get(name) {
+                    // A nested union is tagged by the value all of its members share
+                    Some(nested_sources) if !visiting.contains(name) => {
+                        visiting.push(name.clone());
+                        let nested = Self::build_ts_union(
+                            nested_sources,
+                            literal_props,
+                            union_sources,
+                            visiting,
+                        );
+                        visiting.pop();
+                        let (nested, shared) = nested.unzip();
+                        crate::models::UnionMember {
+                            schema: name.clone(),
+                            tag: shared.and_then(|shared| tag_of(&shared)),
+                            nested: nested.map(Box::new),
                         }
                     }
+                    _ => crate::models::UnionMember {
+                        schema: name.clone(),
+                        tag: literal_props.get(name).and_then(|props| tag_of(props)),
+                        nested: None,
+                    },
+                },
+                UnionMemberSource::Inline(props) => {
+                    let tag = tag_of(props);
+                    crate::models::UnionMember {
+                        schema: format!(
+                            "{{ {}: \"{}\" }}",
+                            discriminator,
+                            tag.as_deref().unwrap_or_default()
+                        ),
+                        tag,
+                        nested: None,
+                    }
+                }
+            })
+            .collect();
+
+        Some(crate::models::DiscriminatedUnion {
+            discriminator: discriminator.to_string(),
+            discriminator_alias: None,
+            members,
+        })
+    }
+
+    /// Members of `z.union([...])` / `z.discriminatedUnion("tag", [...])`, with the explicit
+    /// tag field of the latter
+    fn zod_union_sources(
+        &self,
+        call: &CallExpr,
+    ) -> Option<(Option<String>, Vec<UnionMemberSource>)> {
+        let Callee::Expr(callee) = &call.callee else {
+            return None;
+        };
+        let (explicit, options) = if self.is_zod_method(callee, "discriminatedUnion") {
+            let Expr::Lit(Lit::Str(tag)) = call.args.first()?.expr.as_ref() else {
+                return None;
+            };
+            (
+                Some(tag.value.as_str().unwrap_or("").to_string()),
+                call.args.get(1)?,
+            )
+        } else if self.is_zod_method(callee, "union") {
+            (None, call.args.first()?)
+        } else {
+            return None;
+        };
+        let Expr::Array(array) = options.expr.as_ref() else {
+            return None;
+        };
+
+        let sources = array
+            .elems
+            .iter()
+            .flatten()
+            .filter_map(|element| match self.zod_root_ident(&element.expr) {
+                Some(name) => Some(UnionMemberSource::Named(name)),
+                None => {
+                    let inner = self.zod_root_call(&element.expr)?;
+                    let Callee::Expr(inner_callee) = &inner.callee else {
+                        return None;
+                    };
+                    self.is_zod_object_call(inner_callee)
+                        .then(|| UnionMemberSource::Inline(self.zod_literal_props(inner)))
+                }
+            })
+            .collect();
+        Some((explicit, sources))
+    }
+
+    /// Key/value properties of a `z.object({...})` call
+    fn zod_object_props<'a>(&self, call: &'a CallExpr) -> Vec<(String, &'a Expr)> {
+        let Some(Expr::Object(obj_lit)) = call.args.first().map(|arg| arg.expr.as_ref()) else {
+            return Vec::new();
+        };
+        obj_lit
+            .props
+            .iter()
+            .filter_map(|prop| {
+                let PropOrSpread::Prop(prop) = prop else {
+                    return None;
+                };
+                let Prop::KeyValue(key_value) = prop.as_ref() else {
+                    return None;
+                };
+                let name = match &key_value.key {
+                    PropName::Ident(ident) => ident.sym.as_ref().to_string(),
+                    PropName::Str(str_lit) => str_lit.value.as_str().unwrap_or("").to_string(),
+                    _ => return None,
+                };
+                Some((name, key_value.value.as_ref()))
+            })
+            .collect()
+    }
+
+    /// `z.literal(...)` fields of a `z.object({...})` call (`kind: z.literal("cat")`)
+    fn zod_literal_props(&self, call: &CallExpr) -> Vec<(String, String)> {
+        self.zod_object_props(call)
+            .into_iter()
+            .filter_map(|(name, value)| {
+                let literal = self.zod_root_call(value)?;
+                let Callee::Expr(callee) = &literal.callee else {
+                    return None;
+                };
+                if !self.is_zod_method(callee, "literal") {
+                    return None;
+                }
+                let value = match literal.args.first()?.expr.as_ref() {
+                    Expr::Lit(Lit::Str(str_lit)) => {
+                        str_lit.value.as_str().unwrap_or("").to_string()
+                    }
+                    Expr::Lit(Lit::Num(number)) => number.value.to_string(),
+                    Expr::Lit(Lit::Bool(bool_lit)) => bool_lit.value.to_string(),
+                    _ => return None,
+                };
+                Some((name, value))
+            })
+            .collect()
+    }
+
+    /// The `z.<method>(...)` call a chained Zod expression starts from
+    /// (`z.literal("cat")` for `z.literal("cat").describe("tag")`)
+    fn zod_root_call<'a>(&self, expr: &'a Expr) -> Option<&'a CallExpr> {
+        let Expr::Call(call) = expr else {
+            return None;
+        };
+        let Callee::Expr(callee) = &call.callee else {
+            return None;
+        };
+        let Expr::Member(member) = callee.as_ref() else {
+            return None;
+        };
+        match member.obj.as_ref() {
+            Expr::Ident(ident) if ident.sym.as_ref() == "z" => Some(call),
+            obj => self.zod_root_call(obj),
+        }
+    }
+
+    /// The schema variable a chained expression starts from (`Pet` for `Pet.optional()`)
+    fn zod_root_ident(&self, expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Ident(ident) if ident.sym.as_ref() != "z" => Some(ident.sym.as_ref().to_string()),
+            Expr::Call(call) => {
+                let Callee::Expr(callee) = &call.callee else {
+                    return None;
+                };
+                let Expr::Member(member) = callee.as_ref() else {
+                    return None;
+                };
+                self.zod_root_ident(member.obj.as_ref())
+            }
+            _ => None,
+        }
+    }
+
+    /// Copies fields into `const A = z.lazy(() => B)` schemas from the schema they point to.
+    /// Follows chains of lazy aliases within the file and stops on cycles (`A -> B -> A`)
+    fn resolve_lazy_schema_fields(schemas: &mut [SchemaReference]) {
+        let by_name: std::collections::HashMap<String, usize> = schemas
+            .iter()
+            .enumerate()
+            .map(|(idx, schema)| (schema.name.clone(), idx))
+            .collect();
+
+        for idx in 0..schemas.len() {
+            if schemas[idx].metadata.contains_key("fields") {
+                continue;
+            }
+
+            let mut visited = std::collections::HashSet::new();
+            visited.insert(schemas[idx].name.clone());
+            let mut current = schemas[idx].metadata.get("lazy_schema_ref").cloned();
+            let mut resolved = None;
+
+            while let Some(target) = current {
+                if !visited.insert(target.clone()) {
+                    break;
+                }
+                let Some(&target_idx) = by_name.get(&target) else {
+                    break;
+                };
+                if let Some(fields) = schemas[target_idx].metadata.get("fields") {
+                    resolved = Some(fields.clone());
+                    break;
+                }
+                current = schemas[target_idx].metadata.get("lazy_schema_ref").cloned();
+            }
+
+            if let Some(fields) = resolved {
+                schemas[idx].metadata.insert("fields".to_string(), fields);
+            }
+        }
+    }
+
+    /// Traverses AST to find Zod schemas
+    fn walk_for_zod(
+        &self,
+        item: &ModuleItem,
+        schemas: &mut Vec<SchemaReference>,
+        file_path: &str,
+        converter: &LocationConverter,
+        ts_schema_map: &std::collections::HashMap<String, SchemaReference>,
+    ) {
+        match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
+                self.walk_zod_var_decl(var_decl, schemas, file_path, converter, ts_schema_map);
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                if let Decl::Var(var_decl) = &export_decl.decl {
+                    self.walk_zod_var_decl(var_decl, schemas, file_path, converter, ts_schema_map);
                 }
             }
             ModuleItem::Stmt(Stmt::Expr(expr_stmt)) => {
@@ -917,6 +1753,100 @@ impl TypeScriptParser {
         }
     }
 
+    /// Extracts Zod schemas declared by a (possibly exported) variable declaration
+    fn walk_zod_var_decl(
+        &self,
+        var_decl: &VarDecl,
+        schemas: &mut Vec<SchemaReference>,
+        file_path: &str,
+        converter: &LocationConverter,
+        ts_schema_map: &std::collections::HashMap<String, SchemaReference>,
+    ) {
+        for decl in &var_decl.decls {
+            if let Some(init) = &decl.init {
+                if let Expr::Call(call_expr) = init.as_ref() {
+                    if let Callee::Expr(callee_expr) = &call_expr.callee {
+                        if self.is_zod_call(callee_expr.as_ref()) {
+                            let span = call_expr.span;
+                            let (line, column) =
+                                converter.byte_offset_to_location(span.lo.0 as usize);
+
+                            let schema_name = match &decl.name {
+                                Pat::Ident(ident) => ident.id.sym.as_ref().to_string(),
+                                _ => "ZodSchema".to_string(),
+                            };
+
+                            let mut metadata = std::collections::HashMap::new();
+
+                            // Try to find associated TypeScript type
+                            if let Some(ts_schema) = ts_schema_map.get(&schema_name) {
+                                // Link Zod schema with TypeScript type
+                                metadata
+                                    .insert("typescript_type".to_string(), ts_schema.name.clone());
+                                // Copy fields from TypeScript schema if present
+                                if let Some(fields) = ts_schema.metadata.get("fields") {
+                                    metadata.insert("fields".to_string(), fields.clone());
+                                }
+                            }
+
+                            // Check if this is z.object() and extract fields
+                            if self.is_zod_object_call(callee_expr.as_ref()) {
+                                self.insert_zod_object_fields(call_expr, &mut metadata);
+                            } else if self.is_zod_lazy_call(callee_expr.as_ref()) {
+                                // Recursive schemas: z.lazy(() => z.object({...})) or z.lazy(() => Other)
+                                match self.zod_lazy_body(call_expr) {
+                                    Some(Expr::Ident(ident)) => {
+                                        metadata.insert(
+                                            "lazy_schema_ref".to_string(),
+                                            ident.sym.as_ref().to_string(),
+                                        );
+                                    }
+                                    Some(Expr::Call(inner_call)) => {
+                                        if let Callee::Expr(inner_callee) = &inner_call.callee {
+                                            if self.is_zod_object_call(inner_callee.as_ref()) {
+                                                self.insert_zod_object_fields(
+                                                    inner_call,
+                                                    &mut metadata,
+                                                );
+                                            }
+                                        }
+                                    }
+                                    _ => {}
+                                }
+                            }
+
+                            schemas.push(SchemaReference {
+                                name: schema_name,
+                                schema_type: SchemaType::Zod,
+                                location: Location {
+                                    file: file_path.to_string(),
+                                    line,
+                                    column: Some(column),
+                                },
+                                metadata,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Stores fields of a z.object({...}) call as JSON in schema metadata
+    fn insert_zod_object_fields(
+        &self,
+        call_expr: &CallExpr,
+        metadata: &mut std::collections::HashMap<String, String>,
+    ) {
+        let fields = self.extract_zod_object_fields(call_expr);
+        if !fields.is_empty() {
+            // Store fields as JSON
+            if let Ok(fields_json) = serde_json::to_string(&fields) {
+                metadata.insert("fields".to_string(), fields_json);
+            }
+        }
+    }
+
     /// Checks if expression is a Zod call
     fn is_zod_call(&self, expr: &Expr) -> bool {
         if let Expr::Member(member_expr) = expr {
@@ -928,7 +1858,10 @@ impl TypeScriptParser {
                             || method == "string"
                             || method == "number"
                             || method == "boolean"
-                            || method == "array";
+                            || method == "array"
+                            || method == "lazy"
+                            || method == "union"
+                            || method == "discriminatedUnion";
                     }
                 }
             }
@@ -938,11 +1871,21 @@ impl TypeScriptParser {
 
     /// Checks if expression is specifically z.object() call
     fn is_zod_object_call(&self, expr: &Expr) -> bool {
+        self.is_zod_method(expr, "object")
+    }
+
+    /// Checks if expression is specifically z.lazy() call
+    fn is_zod_lazy_call(&self, expr: &Expr) -> bool {
+        self.is_zod_method(expr, "lazy")
+    }
+
+    /// Checks if expression is `z.<method>`
+    fn is_zod_method(&self, expr: &Expr, method: &str) -> bool {
         if let Expr::Member(member_expr) = expr {
             if let Expr::Ident(ident) = member_expr.obj.as_ref() {
                 if ident.sym.as_ref() == "z" {
                     if let MemberProp::Ident(prop) = &member_expr.prop {
-                        return prop.sym.as_ref() == "object";
+                        return prop.sym.as_ref() == method;
                     }
                 }
             }
@@ -950,6 +1893,61 @@ impl TypeScriptParser {
         false
     }
 
+    /// Returns the expression produced by the getter of z.lazy(() => ...)
+    /// Handles both expression bodies and `{ return ...; }` blocks
+    fn zod_lazy_body<'a>(&self, call_expr: &'a CallExpr) -> Option<&'a Expr> {
+        let getter = call_expr.args.first()?;
+        let mut body = match getter.expr.as_ref() {
+            Expr::Arrow(arrow) => match arrow.body.as_ref() {
+                BlockStmtOrExpr::Expr(expr) => Some(expr.as_ref()),
+                BlockStmtOrExpr::BlockStmt(block) => Self::returned_expr(block),
+            },
+            Expr::Fn(fn_expr) => fn_expr.function.body.as_ref().and_then(Self::returned_expr),
+            _ => None,
+        }?;
+
+        while let Expr::Paren(paren) = body {
+            body = paren.expr.as_ref();
+        }
+        Some(body)
+    }
+
+    /// Returns the argument of the first `return` statement of a block
+    fn returned_expr(block: &BlockStmt) -> Option<&Expr> {
+        block.stmts.iter().find_map(|stmt| match stmt {
+            Stmt::Return(ret) => ret.arg.as_deref(),
+            _ => None,
+        })
+    }
+
+    /// Returns the schema referenced through z.lazy(() => Schema) in a field definition
+    /// Looks through chained methods and z.array(...), e.g. z.array(z.lazy(() => Node)).optional()
+    fn extract_zod_schema_ref(&self, expr: &Expr) -> Option<String> {
+        let Expr::Call(call) = expr else {
+            return None;
+        };
+        let Callee::Expr(callee) = &call.callee else {
+            return None;
+        };
+
+        if self.is_zod_lazy_call(callee.as_ref()) {
+            return match self.zod_lazy_body(call)? {
+                Expr::Ident(ident) => Some(ident.sym.as_ref().to_string()),
+                body => self.extract_zod_schema_ref(body),
+            };
+        }
+        if self.is_zod_method(callee.as_ref(), "array") {
+            return call
+                .args
+                .first()
+                .and_then(|arg| self.extract_zod_schema_ref(arg.expr.as_ref()));
+        }
+        if let Expr::Member(member) = callee.as_ref() {
+            return self.extract_zod_schema_ref(member.obj.as_ref());
+        }
+        None
+    }
+
     /// Extracts Zod type from an expression
     /// Handles: z.string(), z.number(), z.boolean(), z.array(), z.object(), etc.
     /// Also handles chained methods: z.string().email(), z.number().min(1), etc.
@@ -964,6 +1962,15 @@ impl TypeScriptParser {
                         // Check if this is a Zod call (z.string, z.number, etc.)
                         if let Expr::Ident(ident) = member.obj.as_ref() {
                             if ident.sym.as_ref() == "z" {
+                                // z.lazy(() => Schema) refers to another (usually object) schema
+                                if method == "lazy" {
+                                    return match self.zod_lazy_body(call) {
+                                        Some(Expr::Ident(_)) => "object".to_string(),
+                                        Some(body) => self.extract_zod_type(body),
+                                        None => "unknown".to_string(),
+                                    };
+                                }
+
                                 // Base type: string, number, boolean, array, object
                                 return method.to_string();
                             }
@@ -1011,6 +2018,163 @@ impl TypeScriptParser {
         (false, false)
     }
 
+    /// Extracts item count bounds from a `z.array(...)` method chain
+    /// Example: z.array(z.string()).min(1).max(10), z.array(Item).nonempty()
+    /// The outermost call wins when a bound is set twice.
+    fn zod_array_bounds(expr: &Expr) -> (Option<usize>, Option<usize>) {
+        let (mut min_items, mut max_items) = (None, None);
+        let mut current = expr;
+
+        while let Expr::Call(call) = current {
+            let Callee::Expr(callee) = &call.callee else {
+                break;
+            };
+            let Expr::Member(member) = callee.as_ref() else {
+                break;
+            };
+            let MemberProp::Ident(prop) = &member.prop else {
+                break;
+            };
+            let count = call.args.first().and_then(|arg| match arg.expr.as_ref() {
+                Expr::Lit(Lit::Num(num)) if num.value >= 0.0 && num.value.fract() == 0.0 => {
+                    Some(num.value as usize)
+                }
+                _ => None,
+            });
+
+            match prop.sym.as_ref() {
+                "min" => min_items = min_items.or(count),
+                "max" => max_items = max_items.or(count),
+                "length" => {
+                    min_items = min_items.or(count);
+                    max_items = max_items.or(count);
+                }
+                "nonempty" => min_items = min_items.or(Some(1)),
+                _ => {}
+            }
+            current = member.obj.as_ref();
+        }
+
+        (min_items, max_items)
+    }
+
+    /// Values the items of a `z.array(z.enum([...]))` (or `z.enum([...]).array()`) chain
+    /// are restricted to; None for other element schemas
+    fn zod_array_item_values(expr: &Expr) -> Option<Vec<String>> {
+        let mut current = expr;
+        let element = loop {
+            let Expr::Call(call) = current else {
+                return None;
+            };
+            let Callee::Expr(callee) = &call.callee else {
+                return None;
+            };
+            let Expr::Member(member) = callee.as_ref() else {
+                return None;
+            };
+            let is_array =
+                matches!(&member.prop, MemberProp::Ident(prop) if prop.sym.as_ref() == "array");
+            match member.obj.as_ref() {
+                Expr::Ident(ident) if ident.sym.as_ref() == "z" => {
+                    if !is_array {
+                        return None;
+                    }
+                    break call.args.first()?.expr.as_ref();
+                }
+                obj if is_array => break obj,
+                obj => current = obj,
+            }
+        };
+
+        let Expr::Call(call) = element else {
+            return None;
+        };
+        let Callee::Expr(callee) = &call.callee else {
+            return None;
+        };
+        let Expr::Member(member) = callee.as_ref() else {
+            return None;
+        };
+        let is_zod_enum = matches!(member.obj.as_ref(), Expr::Ident(ident) if ident.sym.as_ref() == "z")
+            && matches!(&member.prop, MemberProp::Ident(prop) if prop.sym.as_ref() == "enum");
+        if !is_zod_enum {
+            return None;
+        }
+        let Expr::Array(values) = call.args.first()?.expr.as_ref() else {
+            return None;
+        };
+        values
+            .elems
+            .iter()
+            .map(|elem| match elem.as_ref()?.expr.as_ref() {
+                Expr::Lit(Lit::Str(value)) => value.value.as_str().map(str::to_string),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Literal default of a Zod method chain (`10` for `z.number().int().default(10)`)
+    ///
+    /// Only literals (strings, numbers, booleans, `null` and arrays of them) are returned;
+    /// computed defaults (`.default(() => new Date())`) have none.
+    pub fn zod_default_value(expr: &Expr) -> Option<serde_json::Value> {
+        let mut current = expr;
+        while let Expr::Call(call) = current {
+            let Callee::Expr(callee) = &call.callee else {
+                return None;
+            };
+            let Expr::Member(member) = callee.as_ref() else {
+                return None;
+            };
+            if matches!(&member.prop, MemberProp::Ident(prop) if prop.sym.as_ref() == "default") {
+                return call
+                    .args
+                    .first()
+                    .and_then(|arg| Self::literal_json(arg.expr.as_ref()));
+            }
+            current = member.obj.as_ref();
+        }
+        None
+    }
+
+    /// JSON value of a literal expression (`"a"`, `-1`, `true`, `null`, `[1, 2]`)
+    fn literal_json(expr: &Expr) -> Option<serde_json::Value> {
+        use serde_json::Value;
+        match expr {
+            Expr::Lit(Lit::Str(s)) => s.value.as_str().map(|s| Value::String(s.to_string())),
+            Expr::Lit(Lit::Bool(b)) => Some(Value::Bool(b.value)),
+            Expr::Lit(Lit::Null(_)) => Some(Value::Null),
+            Expr::Lit(Lit::Num(n)) => Self::number_json(n.value),
+            Expr::Unary(unary) if unary.op == swc_ecma_ast::UnaryOp::Minus => {
+                match unary.arg.as_ref() {
+                    Expr::Lit(Lit::Num(n)) => Self::number_json(-n.value),
+                    _ => None,
+                }
+            }
+            Expr::Array(array) => array
+                .elems
+                .iter()
+                .map(|elem| {
+                    elem.as_ref()
+                        .filter(|elem| elem.spread.is_none())
+                        .and_then(|elem| Self::literal_json(elem.expr.as_ref()))
+                })
+                .collect::<Option<Vec<_>>>()
+                .map(Value::Array),
+            Expr::Paren(paren) => Self::literal_json(paren.expr.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Integral numbers become JSON integers, like Python `int` literals
+    fn number_json(value: f64) -> Option<serde_json::Value> {
+        if value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+            Some(serde_json::Value::from(value as i64))
+        } else {
+            serde_json::Number::from_f64(value).map(serde_json::Value::Number)
+        }
+    }
+
     /// Extracts fields from z.object({...}) call
     /// Example: z.object({ name: z.string(), age: z.number().optional() })
     fn extract_zod_object_fields(&self, call_expr: &CallExpr) -> Vec<crate::models::ZodField> {
@@ -1036,11 +2200,23 @@ impl TypeScriptParser {
                             let (is_optional, is_nullable) =
                                 self.is_zod_optional(key_value.value.as_ref());
 
-                            fields.push(crate::models::ZodField {
+                            let schema_ref = self.extract_zod_schema_ref(key_value.value.as_ref());
+                            let (min_items, max_items) = if field_type == "array" {
+                                Self::zod_array_bounds(key_value.value.as_ref())
+                            } else {
+                                (None, None)
+                            };
+
+                            fields.push(crate::models::ZodField {
                                 name: field_name,
                                 type_name: field_type,
                                 optional: is_optional,
                                 nullable: is_nullable,
+                                schema_ref,
+                                min_items,
+                                max_items,
+                                default_value: Self::zod_default_value(key_value.value.as_ref()),
+                                item_values: Self::zod_array_item_values(key_value.value.as_ref()),
                             });
                         }
                     }
@@ -1095,6 +2271,76 @@ impl TypeScriptParser {
         types
     }
 
+    /// Extracts classes with typed properties (NestJS DTOs: `class CreateUserDto { name: string }`)
+    ///
+    /// Static properties are skipped; `name?: string` is optional. Fields use the same
+    /// `name:type:required` format as interfaces.
+    pub fn extract_class_schemas(
+        &self,
+        module: &Module,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) -> Vec<SchemaReference> {
+        let mut schemas = Vec::new();
+
+        for item in &module.body {
+            let class_decl = match item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                    match &export_decl.decl {
+                        Decl::Class(class_decl) => class_decl,
+                        _ => continue,
+                    }
+                }
+                ModuleItem::Stmt(Stmt::Decl(Decl::Class(class_decl))) => class_decl,
+                _ => continue,
+            };
+
+            let fields: Vec<String> = class_decl
+                .class
+                .body
+                .iter()
+                .filter_map(|member| match member {
+                    swc_ecma_ast::ClassMember::ClassProp(prop) if !prop.is_static => {
+                        let name = prop_name(&prop.key)?;
+                        let type_ann = prop.type_ann.as_ref()?;
+                        Some(format!(
+                            "{}:{}:{}",
+                            name,
+                            self.ts_type_ann_to_string(type_ann),
+                            if prop.is_optional {
+                                "optional"
+                            } else {
+                                "required"
+                            }
+                        ))
+                    }
+                    _ => None,
+                })
+                .collect();
+            if fields.is_empty() {
+                continue;
+            }
+
+            let (line, column) =
+                converter.byte_offset_to_location(class_decl.class.span.lo.0 as usize);
+            schemas.push(SchemaReference {
+                name: class_decl.ident.sym.as_ref().to_string(),
+                schema_type: SchemaType::TypeScript,
+                location: Location {
+                    file: file_path.to_string(),
+                    line,
+                    column: Some(column),
+                },
+                metadata: std::collections::HashMap::from([(
+                    "fields".to_string(),
+                    fields.join(","),
+                )]),
+            });
+        }
+
+        schemas
+    }
+
     /// Extracts TypeScript schemas (interfaces and type aliases) from module
     pub fn extract_typescript_schemas(
         &self,
@@ -1108,9 +2354,396 @@ impl TypeScriptParser {
             self.walk_for_typescript_schemas(item, &mut schemas, file_path, converter);
         }
 
+        self.attach_discriminated_unions(module, &mut schemas);
+        Self::resolve_utility_types(&mut schemas);
+
         schemas
     }
 
+    /// Computes the fields of utility type aliases (`type Patch = Partial<User>`) whose base
+    /// is among `schemas`
+    ///
+    /// `Partial` makes every field optional, `Required` every field required, `Readonly`
+    /// keeps them, `Pick` keeps the listed keys and `Omit` the others. Aliases over other
+    /// aliases are resolved once their base is. Aliases whose base isn't in `schemas`
+    /// (declared in another module) keep their pending marker, so a later call with more
+    /// schemas can resolve them.
+    pub fn resolve_utility_types(schemas: &mut [SchemaReference]) {
+        loop {
+            let mut resolved_any = false;
+            for idx in 0..schemas.len() {
+                let schema = &schemas[idx];
+                if schema.metadata.contains_key("fields") {
+                    continue;
+                }
+                let Some(base) = schema.metadata.get(UTILITY_BASE_METADATA_KEY) else {
+                    continue;
+                };
+                let Some(base_fields) = schemas
+                    .iter()
+                    .find(|candidate| &candidate.name == base)
+                    .and_then(|base| base.metadata.get("fields"))
+                else {
+                    continue;
+                };
+                let utility = schema
+                    .metadata
+                    .get(UTILITY_TYPE_METADATA_KEY)
+                    .map(String::as_str)
+                    .unwrap_or_default();
+                let keys: Vec<&str> = schema
+                    .metadata
+                    .get(UTILITY_KEYS_METADATA_KEY)
+                    .map(|keys| keys.split(',').collect())
+                    .unwrap_or_default();
+                let fields = Self::apply_utility_type(utility, &keys, base_fields);
+                schemas[idx]
+                    .metadata
+                    .insert("fields".to_string(), fields.join(","));
+                resolved_any = true;
+            }
+            if !resolved_any {
+                return;
+            }
+        }
+    }
+
+    /// Fields (`name:type:required|optional`) of `utility<base, keys>` given the base's fields
+    fn apply_utility_type(utility: &str, keys: &[&str], base_fields: &str) -> Vec<String> {
+        base_fields
+            .split(',')
+            .filter(|field| !field.is_empty())
+            .filter_map(|field| {
+                let (name, rest) = field.split_once(':')?;
+                let (field_type, optionality) = rest.rsplit_once(':').unwrap_or((rest, "required"));
+                let optionality = match utility {
+                    "Partial" => "optional",
+                    "Required" => "required",
+                    "Pick" if !keys.contains(&name) => return None,
+                    "Omit" if keys.contains(&name) => return None,
+                    _ => optionality,
+                };
+                Some(format!("{}:{}:{}", name, field_type, optionality))
+            })
+            .collect()
+    }
+
+    /// Records the utility type a type alias applies to a named type (`Pick<User, "id">`)
+    /// as pending metadata, resolved by [`Self::resolve_utility_types`]
+    fn utility_type_metadata(
+        &self,
+        ts_type: &TsType,
+        metadata: &mut std::collections::HashMap<String, String>,
+    ) {
+        let TsType::TsTypeRef(type_ref) = ts_type else {
+            return;
+        };
+        let TsEntityName::Ident(utility) = &type_ref.type_name else {
+            return;
+        };
+        let utility = utility.sym.as_ref();
+        let with_keys = matches!(utility, "Pick" | "Omit");
+        if !with_keys && !matches!(utility, "Partial" | "Required" | "Readonly") {
+            return;
+        }
+        let Some(params) = type_ref.type_params.as_ref().map(|params| &params.params) else {
+            return;
+        };
+        let Some(TsType::TsTypeRef(base)) = params.first().map(|param| param.as_ref()) else {
+            return;
+        };
+        let keys = if with_keys {
+            let Some(keys) = params.get(1).and_then(|keys| Self::literal_keys(keys)) else {
+                return;
+            };
+            Some(keys.join(","))
+        } else {
+            None
+        };
+
+        metadata.insert(UTILITY_TYPE_METADATA_KEY.to_string(), utility.to_string());
+        metadata.insert(
+            UTILITY_BASE_METADATA_KEY.to_string(),
+            self.ts_entity_name_to_string(&base.type_name),
+        );
+        if let Some(keys) = keys {
+            metadata.insert(UTILITY_KEYS_METADATA_KEY.to_string(), keys);
+        }
+    }
+
+    /// String literal keys of `"id"` or `"id" | "name"`
+    fn literal_keys(ts_type: &TsType) -> Option<Vec<String>> {
+        match ts_type {
+            TsType::TsLitType(lit) => match &lit.lit {
+                TsLit::Str(key) => Some(vec![key.value.as_str()?.to_string()]),
+                _ => None,
+            },
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(union)) => {
+                union.types.iter().try_fold(Vec::new(), |mut keys, member| {
+                    keys.extend(Self::literal_keys(member)?);
+                    Some(keys)
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Attaches tagged unions (`type Pet = Cat | Dog` where members declare literal tags)
+    ///
+    /// Union aliases get `discriminated_union` metadata; fields typed with such an alias or
+    /// with an inline `Cat | Dog` are listed in `discriminated_unions` (field name -> union).
+    fn attach_discriminated_unions(&self, module: &Module, schemas: &mut [SchemaReference]) {
+        let mut literal_props = std::collections::HashMap::new();
+        let mut union_aliases = std::collections::HashMap::new();
+
+        for item in &module.body {
+            let decl = match item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => &export_decl.decl,
+                ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+                _ => continue,
+            };
+            match decl {
+                Decl::TsInterface(ts_interface) => {
+                    literal_props.insert(
+                        ts_interface.id.sym.as_ref().to_string(),
+                        self.ts_literal_props(&ts_interface.body.body),
+                    );
+                }
+                Decl::TsTypeAlias(ts_type_alias) => {
+                    let name = ts_type_alias.id.sym.as_ref().to_string();
+                    match ts_type_alias.type_ann.as_ref() {
+                        TsType::TsTypeLit(type_lit) => {
+                            literal_props.insert(name, self.ts_literal_props(&type_lit.members));
+                        }
+                        TsType::TsUnionOrIntersectionType(
+                            TsUnionOrIntersectionType::TsUnionType(union_type),
+                        ) => {
+                            let members: Vec<UnionMemberSource> = union_type
+                                .types
+                                .iter()
+                                .filter_map(|t| self.union_member_source(t))
+                                .collect();
+                            union_aliases.insert(name, members);
+                        }
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if literal_props.is_empty() && union_aliases.is_empty() {
+            return;
+        }
+
+        for schema in schemas.iter_mut() {
+            if let Some((union, _)) = union_aliases.get(&schema.name).and_then(|members| {
+                Self::build_ts_union(
+                    members,
+                    &literal_props,
+                    &union_aliases,
+                    &mut vec![schema.name.clone()],
+                )
+            }) {
+                if let Ok(union_json) = serde_json::to_string(&union) {
+                    schema
+                        .metadata
+                        .insert("discriminated_union".to_string(), union_json);
+                }
+            }
+
+            let mut field_unions = std::collections::HashMap::new();
+            for entry in schema
+                .metadata
+                .get("fields")
+                .map(|fields| fields.split(',').collect::<Vec<_>>())
+                .unwrap_or_default()
+            {
+                let mut parts = entry.split(':');
+                let (Some(name), Some(field_type)) = (parts.next(), parts.next()) else {
+                    continue;
+                };
+                let field_type = field_type.trim();
+                let union = match union_aliases.get(field_type) {
+                    Some(members) => Self::build_ts_union(
+                        members,
+                        &literal_props,
+                        &union_aliases,
+                        &mut vec![field_type.to_string()],
+                    ),
+                    None if field_type.contains('|') => {
+                        let members: Vec<UnionMemberSource> = field_type
+                            .split('|')
+                            .map(|member| UnionMemberSource::Named(member.trim().to_string()))
+                            .collect();
+                        Self::build_ts_union(
+                            &members,
+                            &literal_props,
+                            &union_aliases,
+                            &mut Vec::new(),
+                        )
+                    }
+                    None => None,
+                };
+                if let Some((union, _)) = union {
+                    field_unions.insert(name.trim().to_string(), union);
+                }
+            }
+            if !field_unions.is_empty() {
+                if let Ok(unions_json) = serde_json::to_string(&field_unions) {
+                    schema
+                        .metadata
+                        .insert("discriminated_unions".to_string(), unions_json);
+                }
+            }
+        }
+    }
+
+    /// Builds a discriminated union from its members, inferring the tag field
+    ///
+    /// The discriminator is a literal field declared by every member with a distinct value.
+    /// Returns the union and the literal fields all its members share (the tag of a nested union).
+    fn build_ts_union(
+        members: &[UnionMemberSource],
+        literal_props: &std::collections::HashMap<String, Vec<(String, String)>>,
+        union_aliases: &std::collections::HashMap<String, Vec<UnionMemberSource>>,
+        visiting: &mut Vec<String>,
+    ) -> Option<(crate::models::DiscriminatedUnion, Vec<(String, String)>)> {
+        let mut candidates = Vec::new();
+        for source in members {
+            let (schema, props, nested) = match source {
+                UnionMemberSource::Named(name) if name == "null" || name == "undefined" => continue,
+                UnionMemberSource::Named(name) => match union_aliases.get(name) {
+                    Some(nested_members) if !visiting.contains(name) => {
+                        visiting.push(name.clone());
+                        let nested = Self::build_ts_union(
+                            nested_members,
+                            literal_props,
+                            union_aliases,
+                            visiting,
+                        );
+                        visiting.pop();
+                        match nested {
+                            Some((union, shared)) => (name.clone(), shared, Some(Box::new(union))),
+                            None => (name.clone(), Vec::new(), None),
+                        }
+                    }
+                    _ => (
+                        name.clone(),
+                        literal_props.get(name).cloned().unwrap_or_default(),
+                        None,
+                    ),
+                },
+                UnionMemberSource::Inline(props) => (String::new(), props.clone(), None),
+            };
+            candidates.push((schema, props, nested));
+        }
+        if candidates.len() < 2 {
+            return None;
+        }
+
+        let value_of = |props: &[(String, String)], name: &str| {
+            props
+                .iter()
+                .find(|(prop, _)| prop == name)
+                .map(|(_, value)| value.clone())
+        };
+        let discriminator = candidates[0]
+            .1
+            .iter()
+            .map(|(name, _)| name.clone())
+            .find(|name| {
+                let mut values: Vec<String> = candidates
+                    .iter()
+                    .filter_map(|(_, props, _)| value_of(props, name))
+                    .collect();
+                values.sort();
+                values.dedup();
+                values.len() == candidates.len()
+            })?;
+
+        let shared: Vec<(String, String)> = candidates[0]
+            .1
+            .iter()
+            .filter(|(name, value)| {
+                candidates
+                    .iter()
+                    .all(|(_, props, _)| value_of(props, name).as_ref() == Some(value))
+            })
+            .cloned()
+            .collect();
+
+        let members = candidates
+            .into_iter()
+            .map(|(schema, props, nested)| {
+                let tag = value_of(&props, &discriminator);
+                let schema = if schema.is_empty() {
+                    format!(
+                        "{{ {}: \"{}\" }}",
+                        discriminator,
+                        tag.as_deref().unwrap_or_default()
+                    )
+                } else {
+                    schema
+                };
+                crate::models::UnionMember {
+                    schema,
+                    tag,
+                    nested,
+                }
+            })
+            .collect();
+
+        Some((
+            crate::models::DiscriminatedUnion {
+                discriminator,
+                discriminator_alias: None,
+                members,
+            },
+            shared,
+        ))
+    }
+
+    /// Describes a union member for tag inference; `null` / `undefined` are skipped
+    fn union_member_source(&self, ts_type: &TsType) -> Option<UnionMemberSource> {
+        match ts_type {
+            TsType::TsKeywordType(keyword)
+                if matches!(
+                    keyword.kind,
+                    TsKeywordTypeKind::TsNullKeyword | TsKeywordTypeKind::TsUndefinedKeyword
+                ) =>
+            {
+                None
+            }
+            TsType::TsTypeLit(type_lit) => Some(UnionMemberSource::Inline(
+                self.ts_literal_props(&type_lit.members),
+            )),
+            _ => Some(UnionMemberSource::Named(self.ts_type_to_string(ts_type))),
+        }
+    }
+
+    /// Collects properties typed with a literal (`kind: "cat"`, `version: 2`)
+    fn ts_literal_props(&self, members: &[TsTypeElement]) -> Vec<(String, String)> {
+        members
+            .iter()
+            .filter_map(|member| {
+                let TsTypeElement::TsPropertySignature(prop) = member else {
+                    return None;
+                };
+                let TsType::TsLitType(lit_type) = prop.type_ann.as_ref()?.type_ann.as_ref() else {
+                    return None;
+                };
+                let value = match &lit_type.lit {
+                    TsLit::Str(str_lit) => str_lit.value.as_str().unwrap_or("").to_string(),
+                    TsLit::Number(number) => number.value.to_string(),
+                    TsLit::Bool(bool_lit) => bool_lit.value.to_string(),
+                    _ => return None,
+                };
+                Some((self.ts_property_key_to_string(&prop.key), value))
+            })
+            .collect()
+    }
+
     /// Traverses AST to find TypeScript types
     fn walk_for_types(
         &self,
@@ -1315,27 +2948,16 @@ impl TypeScriptParser {
 
                         let name = ts_interface.id.sym.as_ref().to_string();
                         let mut metadata = std::collections::HashMap::new();
-                        let mut fields = Vec::new();
-
-                        for member in &ts_interface.body.body {
-                            if let swc_ecma_ast::TsTypeElement::TsPropertySignature(prop) = member {
-                                let field_name = self.ts_property_key_to_string(&prop.key);
-                                if let Some(type_ann) = &prop.type_ann {
-                                    let field_type = self.ts_type_ann_to_string(type_ann);
-                                    let optional = prop.optional;
-                                    fields.push(format!(
-                                        "{}:{}:{}",
-                                        field_name,
-                                        field_type,
-                                        if optional { "optional" } else { "required" }
-                                    ));
-                                }
-                            }
-                        }
+                        let fields = self.ts_type_elements_to_fields(&ts_interface.body.body);
 
                         if !fields.is_empty() {
                             metadata.insert("fields".to_string(), fields.join(","));
                         }
+                        let deprecated =
+                            self.ts_deprecated_members(&ts_interface.body.body, converter);
+                        if !deprecated.is_empty() {
+                            metadata.insert("deprecated_fields".to_string(), deprecated.join(","));
+                        }
 
                         // Mark schemas coming from generated files
                         if Self::is_generated_types_file(file_path) {
@@ -1366,6 +2988,23 @@ impl TypeScriptParser {
 
                         let mut metadata = std::collections::HashMap::new();
                         metadata.insert("type".to_string(), type_str);
+                        self.utility_type_metadata(ts_type_alias.type_ann.as_ref(), &mut metadata);
+
+                        // Object literal aliases (`type X = { a: string }`) carry fields too
+                        if let swc_ecma_ast::TsType::TsTypeLit(type_lit) =
+                            ts_type_alias.type_ann.as_ref()
+                        {
+                            let fields = self.ts_type_elements_to_fields(&type_lit.members);
+                            if !fields.is_empty() {
+                                metadata.insert("fields".to_string(), fields.join(","));
+                            }
+                            let deprecated =
+                                self.ts_deprecated_members(&type_lit.members, converter);
+                            if !deprecated.is_empty() {
+                                metadata
+                                    .insert("deprecated_fields".to_string(), deprecated.join(","));
+                            }
+                        }
 
                         // Mark schemas coming from generated files
                         if Self::is_generated_types_file(file_path) {
@@ -1387,36 +3026,30 @@ impl TypeScriptParser {
                             metadata,
                         });
                     }
+                    Decl::TsEnum(ts_enum) => {
+                        schemas.push(self.ts_enum_schema(ts_enum, file_path, converter));
+                    }
                     _ => {}
                 }
             }
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsEnum(ts_enum))) => {
+                schemas.push(self.ts_enum_schema(ts_enum, file_path, converter));
+            }
             ModuleItem::Stmt(Stmt::Decl(Decl::TsInterface(ts_interface))) => {
                 let span = ts_interface.span;
                 let (line, column) = converter.byte_offset_to_location(span.lo.0 as usize);
 
                 let name = ts_interface.id.sym.as_ref().to_string();
                 let mut metadata = std::collections::HashMap::new();
-                let mut fields = Vec::new();
-
-                for member in &ts_interface.body.body {
-                    if let swc_ecma_ast::TsTypeElement::TsPropertySignature(prop) = member {
-                        let field_name = self.ts_property_key_to_string(&prop.key);
-                        if let Some(type_ann) = &prop.type_ann {
-                            let field_type = self.ts_type_ann_to_string(type_ann);
-                            let optional = prop.optional;
-                            fields.push(format!(
-                                "{}:{}:{}",
-                                field_name,
-                                field_type,
-                                if optional { "optional" } else { "required" }
-                            ));
-                        }
-                    }
-                }
+                let fields = self.ts_type_elements_to_fields(&ts_interface.body.body);
 
                 if !fields.is_empty() {
                     metadata.insert("fields".to_string(), fields.join(","));
                 }
+                let deprecated = self.ts_deprecated_members(&ts_interface.body.body, converter);
+                if !deprecated.is_empty() {
+                    metadata.insert("deprecated_fields".to_string(), deprecated.join(","));
+                }
 
                 // Mark schemas coming from generated files
                 if Self::is_generated_types_file(file_path) {
@@ -1444,6 +3077,19 @@ impl TypeScriptParser {
 
                 let mut metadata = std::collections::HashMap::new();
                 metadata.insert("type".to_string(), type_str);
+                self.utility_type_metadata(ts_type_alias.type_ann.as_ref(), &mut metadata);
+
+                // Object literal aliases (`type X = { a: string }`) carry fields too
+                if let swc_ecma_ast::TsType::TsTypeLit(type_lit) = ts_type_alias.type_ann.as_ref() {
+                    let fields = self.ts_type_elements_to_fields(&type_lit.members);
+                    if !fields.is_empty() {
+                        metadata.insert("fields".to_string(), fields.join(","));
+                    }
+                    let deprecated = self.ts_deprecated_members(&type_lit.members, converter);
+                    if !deprecated.is_empty() {
+                        metadata.insert("deprecated_fields".to_string(), deprecated.join(","));
+                    }
+                }
 
                 // Mark schemas coming from generated files
                 if Self::is_generated_types_file(file_path) {
@@ -1466,6 +3112,161 @@ impl TypeScriptParser {
         }
     }
 
+    /// Schema of an `enum` declaration, with its member values in `enum_values` (JSON array)
+    /// and its members in `variants`, as for Python enums (see [`ENUM_VARIANTS_METADATA_KEY`])
+    ///
+    /// Members without an initializer continue the numbering of the previous numeric member
+    /// (from 0), as in TypeScript. Members initialized with anything other than a string or
+    /// numeric literal (`Mask = 1 << 2`, `Size = "abc".length`) have no statically known
+    /// value: they are listed in `enum_computed_members`, and so are uninitialized members
+    /// following them. `const enum` declarations are marked with `const_enum`.
+    fn ts_enum_schema(
+        &self,
+        ts_enum: &TsEnumDecl,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) -> SchemaReference {
+        let (line, column) = converter.byte_offset_to_location(ts_enum.span.lo.0 as usize);
+
+        let mut values = Vec::new();
+        let mut variants = Vec::new();
+        let mut computed = Vec::new();
+        // Value of the next uninitialized member; None after a string or computed member
+        let mut next_number = Some(0.0);
+        for member in &ts_enum.members {
+            let value = match member.init.as_deref() {
+                None => next_number.map(Self::enum_number),
+                Some(Expr::Lit(Lit::Str(str))) => {
+                    Some(serde_json::Value::from(str.value.as_str().unwrap_or("")))
+                }
+                Some(init) => Self::numeric_literal(init).map(Self::enum_number),
+            };
+            next_number = value
+                .as_ref()
+                .and_then(|value| value.as_f64())
+                .map(|n| n + 1.0);
+            let name = match &member.id {
+                TsEnumMemberId::Ident(ident) => ident.sym.to_string(),
+                TsEnumMemberId::Str(str) => str.value.as_str().unwrap_or("").to_string(),
+            };
+            variants.push(serde_json::json!({
+                "name": name,
+                "value": value.clone().unwrap_or(serde_json::Value::Null),
+            }));
+            match value {
+                Some(value) => values.push(value),
+                None => computed.push(name),
+            }
+        }
+
+        let mut metadata = std::collections::HashMap::new();
+        metadata.insert(
+            "enum_values".to_string(),
+            serde_json::Value::Array(values).to_string(),
+        );
+        metadata.insert(
+            ENUM_VARIANTS_METADATA_KEY.to_string(),
+            serde_json::Value::Array(variants).to_string(),
+        );
+        if !computed.is_empty() {
+            metadata.insert("enum_computed_members".to_string(), computed.join(","));
+        }
+        if ts_enum.is_const {
+            metadata.insert("const_enum".to_string(), "true".to_string());
+        }
+
+        SchemaReference {
+            name: ts_enum.id.sym.to_string(),
+            schema_type: SchemaType::TypeScript,
+            location: Location {
+                file: file_path.to_string(),
+                line,
+                column: Some(column),
+            },
+            metadata,
+        }
+    }
+
+    /// Numeric enum value as JSON; integral values have no fractional part (`1`, not `1.0`)
+    fn enum_number(number: f64) -> serde_json::Value {
+        if number.fract() == 0.0 && number.abs() < 1e15 {
+            serde_json::Value::from(number as i64)
+        } else {
+            serde_json::Value::from(number)
+        }
+    }
+
+    /// Value of a numeric literal, possibly negated (`3`, `-1.5`)
+    fn numeric_literal(expr: &Expr) -> Option<f64> {
+        match expr {
+            Expr::Lit(Lit::Num(num)) => Some(num.value),
+            Expr::Paren(paren) => Self::numeric_literal(&paren.expr),
+            Expr::Unary(unary) if unary.op == UnaryOp::Minus => {
+                Self::numeric_literal(&unary.arg).map(|value| -value)
+            }
+            _ => None,
+        }
+    }
+
+    /// Names of interface / type literal members documented with a `@deprecated` JSDoc tag
+    fn ts_deprecated_members(
+        &self,
+        members: &[swc_ecma_ast::TsTypeElement],
+        converter: &LocationConverter,
+    ) -> Vec<String> {
+        members
+            .iter()
+            .filter_map(|member| match member {
+                swc_ecma_ast::TsTypeElement::TsPropertySignature(prop)
+                    if Self::has_deprecated_jsdoc(converter.source(), prop.span.lo.0 as usize) =>
+                {
+                    Some(self.ts_property_key_to_string(&prop.key))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Checks whether the code at span offset `lo` is preceded by a `/** ... @deprecated ... */`
+    fn has_deprecated_jsdoc(source: &str, lo: usize) -> bool {
+        // Span offsets of a fresh SourceMap start at 1
+        let Some(before) = source.get(..lo.saturating_sub(1)) else {
+            return false;
+        };
+        let Some(before) = before.trim_end().strip_suffix("*/") else {
+            return false;
+        };
+        before
+            .rfind("/**")
+            .is_some_and(|start| before[start..].contains("@deprecated"))
+    }
+
+    /// Converts interface / type literal members to `name:type:optional|required` entries
+    fn ts_type_elements_to_fields(&self, members: &[swc_ecma_ast::TsTypeElement]) -> Vec<String> {
+        let mut fields = Vec::new();
+
+        for member in members {
+            if let swc_ecma_ast::TsTypeElement::TsPropertySignature(prop) = member {
+                let field_name = self.ts_property_key_to_string(&prop.key);
+                if let Some(type_ann) = &prop.type_ann {
+                    let field_type = self.ts_type_ann_to_string(type_ann);
+                    fields.push(format!(
+                        "{}:{}:{}",
+                        field_name,
+                        field_type,
+                        if prop.optional {
+                            "optional"
+                        } else {
+                            "required"
+                        }
+                    ));
+                }
+            }
+        }
+
+        fields
+    }
+
     /// Converts TypeScript type annotation to string
     fn ts_type_ann_to_string(&self, ts_type_ann: &swc_ecma_ast::TsTypeAnn) -> String {
         self.ts_type_to_string(&ts_type_ann.type_ann)
@@ -1732,29 +3533,38 @@ impl TypeScriptParser {
     ) {
         match item {
             // 1. Regular function declarations
-            ModuleItem::Stmt(Stmt::Decl(Decl::Fn(fn_decl))) => {
-                if fn_decl.ident.sym.as_ref() == function_name {
-                    let span = fn_decl.ident.span;
-                    let (line, column) = converter.byte_offset_to_location(span.lo.0 as usize);
-                    let parameters = self.extract_function_parameters(&fn_decl.function);
-                    let return_type = self.extract_return_type(&fn_decl.function);
-
-                    *result = Some(FunctionInfo {
-                        name: function_name.to_string(),
-                        parameters,
-                        return_type,
-                        is_async: fn_decl.function.is_async,
-                        location: crate::models::Location {
-                            file: file_path.to_string(),
-                            line,
-                            column: Some(column),
-                        },
-                    });
-                }
+            ModuleItem::Stmt(Stmt::Decl(Decl::Fn(fn_decl)))
+                if fn_decl.ident.sym.as_ref() == function_name =>
+            {
+                let span = fn_decl.ident.span;
+                let (line, column) = converter.byte_offset_to_location(span.lo.0 as usize);
+                let parameters = self.extract_function_parameters(&fn_decl.function);
+                let return_type = self.extract_return_type(&fn_decl.function);
+
+                *result = Some(FunctionInfo {
+                    name: function_name.to_string(),
+                    parameters,
+                    return_type,
+                    is_async: fn_decl.function.is_async,
+                    location: crate::models::Location {
+                        file: file_path.to_string(),
+                        line,
+                        column: Some(column),
+                    },
+                });
             }
 
-            // 2. Export functions
+            // 2. Export functions (including `export const fn = () => {}` used by generated clients)
             ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                if let Decl::Var(var_decl) = &export_decl.decl {
+                    self.find_arrow_function_in_var_decl(
+                        var_decl,
+                        function_name,
+                        result,
+                        file_path,
+                        converter,
+                    );
+                }
                 if let Decl::Fn(fn_decl) = &export_decl.decl {
                     if fn_decl.ident.sym.as_ref() == function_name {
                         let span = fn_decl.ident.span;
@@ -1779,36 +3589,15 @@ impl TypeScriptParser {
 
             // 3. Arrow functions (const/let)
             ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
-                for decl in &var_decl.decls {
-                    if let Pat::Ident(ident) = &decl.name {
-                        if ident.id.sym.as_ref() == function_name {
-                            if let Some(init) = &decl.init {
-                                if let Expr::Arrow(arrow_fn) = init.as_ref() {
-                                    let span = arrow_fn.span;
-                                    let (line, column) =
-                                        converter.byte_offset_to_location(span.lo.0 as usize);
-                                    let parameters =
-                                        self.extract_arrow_function_parameters(arrow_fn);
-                                    let return_type = self.extract_arrow_return_type(arrow_fn);
-
-                                    *result = Some(FunctionInfo {
-                                        name: function_name.to_string(),
-                                        parameters,
-                                        return_type,
-                                        is_async: arrow_fn.is_async,
-                                        location: crate::models::Location {
-                                            file: file_path.to_string(),
-                                            line,
-                                            column: Some(column),
-                                        },
-                                    });
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-
+                self.find_arrow_function_in_var_decl(
+                    var_decl,
+                    function_name,
+                    result,
+                    file_path,
+                    converter,
+                );
+            }
+
             // 4. IIFE - check in expressions
             ModuleItem::Stmt(Stmt::Expr(expr_stmt)) => {
                 self.walk_expr_for_iife(expr_stmt, function_name, result, file_path, converter);
@@ -1823,6 +3612,44 @@ impl TypeScriptParser {
         }
     }
 
+    /// Finds `const name = () => {}` in a variable declaration
+    fn find_arrow_function_in_var_decl(
+        &self,
+        var_decl: &swc_ecma_ast::VarDecl,
+        function_name: &str,
+        result: &mut Option<FunctionInfo>,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) {
+        for decl in &var_decl.decls {
+            if let Pat::Ident(ident) = &decl.name {
+                if ident.id.sym.as_ref() == function_name {
+                    if let Some(init) = &decl.init {
+                        if let Expr::Arrow(arrow_fn) = init.as_ref() {
+                            let span = arrow_fn.span;
+                            let (line, column) =
+                                converter.byte_offset_to_location(span.lo.0 as usize);
+                            let parameters = self.extract_arrow_function_parameters(arrow_fn);
+                            let return_type = self.extract_arrow_return_type(arrow_fn);
+
+                            *result = Some(FunctionInfo {
+                                name: function_name.to_string(),
+                                parameters,
+                                return_type,
+                                is_async: arrow_fn.is_async,
+                                location: crate::models::Location {
+                                    file: file_path.to_string(),
+                                    line,
+                                    column: Some(column),
+                                },
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     /// Handles IIFE (Immediately Invoked Function Expression) in expressions
     /// IIFE can be: (function name() { ... })() or (() => { ... })()
     fn walk_expr_for_iife(
@@ -1990,12 +3817,41 @@ impl TypeScriptParser {
         let base_type = self.ts_type_to_base_type(ts_type);
         TypeInfo {
             base_type,
-            schema_ref: None,
+            schema_ref: self.named_type_schema_ref(ts_type),
             constraints: Vec::new(),
             optional: false,
         }
     }
 
+    /// Reference to a declared type used by name (`user: CreateUser`)
+    ///
+    /// Only the name is known here; the location is left empty for the caller to resolve.
+    /// Generic and built-in types (`Date`, `Record<K, V>`) are not schemas.
+    fn named_type_schema_ref(&self, ts_type: &swc_ecma_ast::TsType) -> Option<SchemaReference> {
+        let swc_ecma_ast::TsType::TsTypeRef(type_ref) = ts_type else {
+            return None;
+        };
+        if type_ref.type_params.is_some() {
+            return None;
+        }
+        let type_name = self.ts_entity_name_to_string(&type_ref.type_name);
+        if !type_name.starts_with(|c: char| c.is_ascii_uppercase())
+            || BUILTIN_TYPE_NAMES.contains(&type_name.as_str())
+        {
+            return None;
+        }
+        Some(SchemaReference {
+            name: type_name,
+            schema_type: SchemaType::TypeScript,
+            location: Location {
+                file: String::new(),
+                line: 0,
+                column: None,
+            },
+            metadata: std::collections::HashMap::new(),
+        })
+    }
+
     /// Checks if type is Promise or PromiseLike
     fn is_promise_type(&self, ts_type: &swc_ecma_ast::TsType) -> bool {
         if let swc_ecma_ast::TsType::TsTypeRef(type_ref) = ts_type {
@@ -2261,7 +4117,7 @@ impl TypeScriptParser {
         decorators
     }
 
-    /// Extracts decorators from class methods
+    /// Extracts decorators from class methods, their parameters and class properties
     fn extract_method_decorators_from_class(
         &self,
         class: &swc_ecma_ast::Class,
@@ -2273,6 +4129,33 @@ impl TypeScriptParser {
         let mut decorators = Vec::new();
 
         for member in &class.body {
+            if let swc_ecma_ast::ClassMember::ClassProp(prop) = member {
+                let Some(property) = prop_name(&prop.key) else {
+                    continue;
+                };
+                for decorator in &prop.decorators {
+                    if let Some(name) = self.get_decorator_name_from_expr(decorator) {
+                        let (args, kwargs) = self.extract_decorator_arguments_from_expr(decorator);
+                        let (line, column) =
+                            converter.byte_offset_to_location(decorator.span.lo.0 as usize);
+
+                        decorators.push(TypeScriptDecorator {
+                            name,
+                            arguments: args,
+                            keyword_arguments: kwargs,
+                            location: Location {
+                                file: file_path.to_string(),
+                                line,
+                                column: Some(column),
+                            },
+                            target: DecoratorTarget::Property {
+                                class: class_name.to_string(),
+                                property: property.clone(),
+                            },
+                        });
+                    }
+                }
+            }
             if let swc_ecma_ast::ClassMember::Method(method) = member {
                 let method_name = match &method.key {
                     swc_ecma_ast::PropName::Ident(ident) => ident.sym.as_ref().to_string(),
@@ -2323,45 +4206,66 @@ impl TypeScriptParser {
 
     /// Extracts parameter decorators
     ///
-    /// В SWC AST декораторы параметров могут быть не доступны напрямую.
-    /// Парсим из исходного кода, используя позиции параметров.
+    /// Reads the decorators SWC attaches to each parameter (`@Body() dto: CreateUserDto`).
+    /// When the function has none in the AST, falls back to scanning the source text
+    /// before each parameter.
     fn extract_parameter_decorators(
         &self,
         function: &swc_ecma_ast::Function,
         class_name: Option<&str>,
         method_name: Option<&str>,
         source: &str,
-        _file_path: &str,
+        file_path: &str,
         converter: &LocationConverter,
     ) -> Vec<TypeScriptDecorator> {
         let mut decorators = Vec::new();
+        let (Some(class), Some(method)) = (class_name, method_name) else {
+            return decorators;
+        };
+        let from_ast = function
+            .params
+            .iter()
+            .any(|param| !param.decorators.is_empty());
 
-        // Парсим декораторы параметров из исходного кода
-        // Ищем паттерн: @Decorator() param: Type
         for (param_idx, param) in function.params.iter().enumerate() {
             let param_name = match &param.pat {
                 Pat::Ident(ident) => ident.id.sym.as_ref().to_string(),
                 _ => format!("param{}", param_idx),
             };
+            let target = DecoratorTarget::Parameter {
+                class: class.to_string(),
+                method: method.to_string(),
+                parameter: param_name.clone(),
+            };
 
-            // Пытаемся найти декораторы перед параметром в исходном коде
-            // Это приблизительный подход - ищем @Body(), @Query(), @Param() перед именем параметра
-            if let Some(decorator_info) =
-                self.find_parameter_decorator_in_source(source, &param_name, param.span, converter)
-            {
-                if let (Some(class), Some(method)) = (class_name, method_name) {
+            if from_ast {
+                for decorator in &param.decorators {
+                    let Some(name) = self.get_decorator_name_from_expr(decorator) else {
+                        continue;
+                    };
+                    let (line, column) =
+                        converter.byte_offset_to_location(decorator.span.lo.0 as usize);
                     decorators.push(TypeScriptDecorator {
-                        name: decorator_info.name,
-                        arguments: decorator_info.arguments,
-                        keyword_arguments: decorator_info.keyword_arguments,
-                        location: decorator_info.location,
-                        target: DecoratorTarget::Parameter {
-                            class: class.to_string(),
-                            method: method.to_string(),
-                            parameter: param_name,
+                        name,
+                        arguments: parameter_decorator_arguments(decorator, source),
+                        keyword_arguments: std::collections::HashMap::new(),
+                        location: Location {
+                            file: file_path.to_string(),
+                            line,
+                            column: Some(column),
                         },
+                        target: target.clone(),
                     });
                 }
+            } else if let Some(decorator_info) =
+                self.find_parameter_decorator_in_source(source, &param_name, param.span, converter)
+            {
+                // Fallback: approximate search for @Body(), @Query(), @Param() before the
+                // parameter name
+                decorators.push(TypeScriptDecorator {
+                    target,
+                    ..decorator_info
+                });
             }
         }
 
@@ -2389,42 +4293,45 @@ impl TypeScriptParser {
         // Ищем паттерны декораторов NestJS
         let decorator_prefixes = ["Body", "Query", "Param", "Headers", "Req", "Res"];
 
-        for name in &decorator_prefixes {
-            let pattern = format!("@{}(", name);
-            if let Some(pos) = search_text.rfind(&pattern) {
-                // Найдено, создаем декоратор
-                let decorator_pos = search_start + pos;
-                let (line, column) = converter.byte_offset_to_location(decorator_pos);
-
-                // Извлекаем аргументы из скобок
-                let mut args = Vec::new();
-                let after_at_and_name = &search_text[pos + pattern.len()..];
-                if let Some(paren_end) = after_at_and_name.find(')') {
-                    let arg_text = &after_at_and_name[..paren_end];
-                    if !arg_text.trim().is_empty() {
-                        let arg = arg_text.trim().trim_matches('\'').trim_matches('"');
-                        if !arg.is_empty() {
-                            args.push(arg.to_string());
-                        }
-                    }
-                }
-
-                return Some(TypeScriptDecorator {
-                    name: name.to_string(),
-                    arguments: args,
-                    keyword_arguments: std::collections::HashMap::new(),
-                    location: Location {
-                        file: String::new(), // Будет заполнено вызывающим кодом
-                        line,
-                        column: Some(column),
-                    },
-                    target: DecoratorTarget::Parameter {
-                        class: String::new(),
-                        method: String::new(),
-                        parameter: param_name.to_string(),
-                    },
-                });
-            }
+        // The closest decorator belongs to this parameter; earlier ones decorate the
+        // previous parameters
+        let closest = decorator_prefixes
+            .iter()
+            .filter_map(|name| {
+                let pattern = format!("@{}(", name);
+                search_text.rfind(&pattern).map(|pos| (pos, name, pattern))
+            })
+            .max_by_key(|(pos, _, _)| *pos);
+
+        if let Some((pos, name, pattern)) = closest {
+            // Найдено, создаем декоратор
+            let decorator_pos = search_start + pos;
+            let (line, column) = converter.byte_offset_to_location(decorator_pos);
+
+            // Arguments up to the matching parenthesis: `@Param('id', new ParseUUIDPipe())`
+            // gives `id` and the pipe expression
+            let after_at_and_name = &search_text[pos + pattern.len()..];
+            let args = split_decorator_arguments(after_at_and_name)
+                .into_iter()
+                .map(|arg| arg.trim_matches('\'').trim_matches('"').to_string())
+                .filter(|arg| !arg.is_empty())
+                .collect();
+
+            return Some(TypeScriptDecorator {
+                name: name.to_string(),
+                arguments: args,
+                keyword_arguments: std::collections::HashMap::new(),
+                location: Location {
+                    file: String::new(), // Будет заполнено вызывающим кодом
+                    line,
+                    column: Some(column),
+                },
+                target: DecoratorTarget::Parameter {
+                    class: String::new(),
+                    method: String::new(),
+                    parameter: param_name.to_string(),
+                },
+            });
         }
 
         None
@@ -2487,6 +4394,7 @@ impl TypeScriptParser {
                 Lit::Num(n) => n.value.to_string(),
                 Lit::Bool(b) => b.value.to_string(),
                 Lit::Null(_) => "null".to_string(),
+                Lit::Regex(regex) => regex.exp.to_string(),
                 _ => format!("{:?}", lit),
             },
             Expr::Ident(ident) => ident.sym.as_ref().to_string(),
@@ -2578,132 +4486,1579 @@ pub enum DecoratorTarget {
         method: String,
         parameter: String,
     },
+    /// Decorator on a class property (e.g. class-validator `@IsEmail()` on a DTO field)
+    Property { class: String, property: String },
 }
 
-impl Default for TypeScriptParser {
-    fn default() -> Self {
-        Self::new()
-    }
+/// Member of a TypeScript union, as seen by discriminator inference
+enum UnionMemberSource {
+    /// Named interface / type alias (`Cat`)
+    Named(String),
+    /// Inline object type with its literal properties (`{ kind: "cat"; lives: number }`)
+    Inline(Vec<(String, String)>),
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use tempfile::TempDir;
-
-    #[test]
-    fn test_extract_imports() {
-        let parser = TypeScriptParser::new();
-        let source = r#"
-import { Component } from './Component';
-import express from 'express';
-"#;
-        let temp_dir = TempDir::new().unwrap();
-        let test_file = temp_dir.path().join("test.ts");
-        std::fs::write(&test_file, source).unwrap();
+/// What is known about a binding that may be serialized as a request body
+enum BodyBinding {
+    /// Declared type (`payload: CreateUser`)
+    Typed(String),
+    /// Fields of the object literal it was initialized with (`name:type:required` entries)
+    Object(Vec<String>),
+}
 
-        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
-        let imports = parser.extract_imports(&module, test_file.to_str().unwrap(), &converter);
+/// Walks a module collecting `JSON.stringify(...)` request bodies of HTTP calls
+struct RequestBodyCollector<'a> {
+    parser: &'a TypeScriptParser,
+    file_path: &'a str,
+    converter: &'a LocationConverter,
+    /// Interfaces and type aliases declared in the module
+    local_schemas: std::collections::HashMap<String, SchemaReference>,
+    /// Visible bindings in declaration order; truncated when a function or block ends
+    scope: Vec<(String, BodyBinding)>,
+    bodies: Vec<crate::parsers::SerializedBody>,
+}
 
-        assert_eq!(imports.len(), 2);
-        assert_eq!(imports[0].path, "./Component");
-        assert_eq!(imports[1].path, "express");
+impl RequestBodyCollector<'_> {
+    fn walk_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Expr(expr_stmt) => self.walk_expr(&expr_stmt.expr),
+            Stmt::Return(ret_stmt) => {
+                if let Some(arg) = &ret_stmt.arg {
+                    self.walk_expr(arg);
+                }
+            }
+            Stmt::If(if_stmt) => {
+                self.walk_expr(&if_stmt.test);
+                self.walk_stmt(&if_stmt.cons);
+                if let Some(alt) = &if_stmt.alt {
+                    self.walk_stmt(alt);
+                }
+            }
+            Stmt::Switch(switch_stmt) => {
+                for case in &switch_stmt.cases {
+                    self.walk_stmts(&case.cons);
+                }
+            }
+            Stmt::Block(block) => self.walk_stmts(&block.stmts),
+            Stmt::Try(try_stmt) => {
+                self.walk_stmts(&try_stmt.block.stmts);
+                if let Some(handler) = &try_stmt.handler {
+                    self.walk_stmts(&handler.body.stmts);
+                }
+                if let Some(finalizer) = &try_stmt.finalizer {
+                    self.walk_stmts(&finalizer.stmts);
+                }
+            }
+            Stmt::While(while_stmt) => self.walk_stmt(&while_stmt.body),
+            Stmt::For(for_stmt) => self.walk_stmt(&for_stmt.body),
+            Stmt::ForOf(for_of) => self.walk_stmt(&for_of.body),
+            Stmt::ForIn(for_in) => self.walk_stmt(&for_in.body),
+            Stmt::Decl(decl) => self.walk_decl(decl),
+            _ => {}
+        }
     }
 
-    #[test]
-    fn test_extract_calls() {
-        let parser = TypeScriptParser::new();
-        let source = r#"
-function test() {
-    doSomething();
-    anotherFunction(arg1, arg2);
-}
-"#;
-        let temp_dir = TempDir::new().unwrap();
-        let test_file = temp_dir.path().join("test.ts");
-        std::fs::write(&test_file, source).unwrap();
+    /// Walks a block; its bindings go out of scope at the end
+    fn walk_stmts(&mut self, stmts: &[Stmt]) {
+        let scope_len = self.scope.len();
+        for stmt in stmts {
+            self.walk_stmt(stmt);
+        }
+        self.scope.truncate(scope_len);
+    }
 
-        let (module, source, converter) = parser.parse_file(&test_file).unwrap();
-        let calls = parser.extract_calls(&module, test_file.to_str().unwrap(), &converter, &source);
+    fn walk_decl(&mut self, decl: &Decl) {
+        match decl {
+            Decl::Fn(fn_decl) => self.walk_function(&fn_decl.function),
+            Decl::Var(var_decl) => {
+                for decl in &var_decl.decls {
+                    if let Some(init) = &decl.init {
+                        self.walk_expr(init);
+                    }
+                    let Pat::Ident(ident) = &decl.name else {
+                        continue;
+                    };
+                    let binding = match (&ident.type_ann, decl.init.as_deref()) {
+                        (Some(type_ann), _) => Some(BodyBinding::Typed(
+                            self.parser.ts_type_ann_to_string(type_ann),
+                        )),
+                        (None, Some(Expr::TsAs(as_expr))) => Some(BodyBinding::Typed(
+                            self.parser.ts_type_to_string(&as_expr.type_ann),
+                        )),
+                        (None, Some(Expr::Object(object))) => {
+                            self.object_fields(object).map(BodyBinding::Object)
+                        }
+                        _ => None,
+                    };
+                    if let Some(binding) = binding {
+                        self.scope.push((ident.id.sym.to_string(), binding));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
 
-        assert!(calls.len() >= 2);
-        assert!(calls.iter().any(|c| c.name == "doSomething"));
-        assert!(calls.iter().any(|c| c.name == "anotherFunction"));
+    fn walk_function(&mut self, function: &Function) {
+        let scope_len = self.scope.len();
+        for param in &function.params {
+            self.bind_param(&param.pat);
+        }
+        if let Some(body) = &function.body {
+            self.walk_stmts(&body.stmts);
+        }
+        self.scope.truncate(scope_len);
     }
 
-    #[test]
-    fn test_extract_typescript_schemas_interface() {
-        let parser = TypeScriptParser::new();
-        let source = r#"
-interface User {
-    name: string;
-    age: number;
-    email?: string;
-}
-"#;
-        let temp_dir = TempDir::new().unwrap();
-        let test_file = temp_dir.path().join("test.ts");
-        std::fs::write(&test_file, source).unwrap();
+    /// Brings a typed parameter (`payload: CreateUser`, `payload: CreateUser = ...`) into scope
+    fn bind_param(&mut self, pat: &Pat) {
+        let ident = match pat {
+            Pat::Ident(ident) => ident,
+            Pat::Assign(assign) => match assign.left.as_ref() {
+                Pat::Ident(ident) => ident,
+                _ => return,
+            },
+            _ => return,
+        };
+        if let Some(type_ann) = &ident.type_ann {
+            let type_name = self.parser.ts_type_ann_to_string(type_ann);
+            self.scope
+                .push((ident.id.sym.to_string(), BodyBinding::Typed(type_name)));
+        }
+    }
 
-        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
-        let schemas =
-            parser.extract_typescript_schemas(&module, test_file.to_str().unwrap(), &converter);
+    fn walk_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Call(call) => {
+                if let Some(body) = self.request_body(call) {
+                    self.bodies.push(body);
+                }
+                if let Callee::Expr(callee) = &call.callee {
+                    self.walk_expr(callee);
+                }
+                for arg in &call.args {
+                    self.walk_expr(&arg.expr);
+                }
+            }
+            Expr::Await(await_expr) => self.walk_expr(&await_expr.arg),
+            Expr::Paren(paren) => self.walk_expr(&paren.expr),
+            Expr::Member(member) => self.walk_expr(&member.obj),
+            Expr::Assign(assign) => self.walk_expr(&assign.right),
+            Expr::Cond(cond) => {
+                self.walk_expr(&cond.cons);
+                self.walk_expr(&cond.alt);
+            }
+            Expr::Arrow(arrow) => {
+                let scope_len = self.scope.len();
+                for param in &arrow.params {
+                    self.bind_param(param);
+                }
+                match arrow.body.as_ref() {
+                    BlockStmtOrExpr::BlockStmt(block) => self.walk_stmts(&block.stmts),
+                    BlockStmtOrExpr::Expr(body) => self.walk_expr(body),
+                }
+                self.scope.truncate(scope_len);
+            }
+            Expr::Fn(fn_expr) => self.walk_function(&fn_expr.function),
+            _ => {}
+        }
+    }
 
-        assert_eq!(schemas.len(), 1);
-        assert_eq!(schemas[0].name, "User");
-        assert_eq!(schemas[0].schema_type, SchemaType::TypeScript);
-        assert!(schemas[0].metadata.contains_key("fields"));
+    /// Finds `JSON.stringify(x)` passed directly or as `body`/`data` of an options object
+    fn request_body(&self, call: &CallExpr) -> Option<crate::parsers::SerializedBody> {
+        let value = call.args.iter().find_map(|arg| match arg.expr.as_ref() {
+            Expr::Object(options) => options.props.iter().find_map(|prop| {
+                let PropOrSpread::Prop(prop) = prop else {
+                    return None;
+                };
+                match prop.as_ref() {
+                    Prop::KeyValue(key_value)
+                        if matches!(
+                            prop_name(&key_value.key).as_deref(),
+                            Some("body" | "data")
+                        ) =>
+                    {
+                        self.stringified(&key_value.value)
+                    }
+                    _ => None,
+                }
+            }),
+            other => self.stringified(other),
+        })?;
+
+        let (line, column) = self
+            .converter
+            .byte_offset_to_location(call.span.lo.0 as usize);
+        let location = Location {
+            file: self.file_path.to_string(),
+            line,
+            column: Some(column),
+        };
+        let schema = self.body_schema(value, &location)?;
+        Some(crate::parsers::SerializedBody { schema, location })
     }
 
-    #[test]
-    fn test_extract_typescript_schemas_generated_file_metadata() {
-        let parser = TypeScriptParser::new();
-        let source = r#"
-interface Item {
-    id: string;
-}
-"#;
-        let temp_dir = TempDir::new().unwrap();
-        // Imitate common OpenAPI-generated types file
-        let test_file = temp_dir.path().join("types.gen.ts");
-        std::fs::write(&test_file, source).unwrap();
+    /// Returns the argument of `JSON.stringify(...)`
+    fn stringified<'e>(&self, expr: &'e Expr) -> Option<&'e Expr> {
+        let Expr::Call(call) = expr else {
+            return None;
+        };
+        if self.parser.call_name(&call.callee).as_deref() != Some("JSON.stringify") {
+            return None;
+        }
+        call.args.first().map(|arg| arg.expr.as_ref())
+    }
 
-        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
-        let schemas =
-            parser.extract_typescript_schemas(&module, test_file.to_str().unwrap(), &converter);
+    /// Resolves the schema of a serialized value
+    fn body_schema(&self, value: &Expr, location: &Location) -> Option<SchemaReference> {
+        let (name, fields) = match value {
+            Expr::Ident(ident) => match self.lookup(&ident.sym)? {
+                BodyBinding::Typed(type_name) => return self.typed_schema(type_name, location),
+                BodyBinding::Object(fields) => (ident.sym.to_string(), fields.clone()),
+            },
+            Expr::Object(object) => ("body".to_string(), self.object_fields(object)?),
+            Expr::Paren(paren) => return self.body_schema(&paren.expr, location),
+            _ => return None,
+        };
 
-        assert_eq!(schemas.len(), 1);
-        let schema = &schemas[0];
-        assert_eq!(schema.name, "Item");
-        assert_eq!(schema.schema_type, SchemaType::TypeScript);
-        // New metadata flags for generated files
-        assert_eq!(
-            schema.metadata.get("openapi_generated").map(String::as_str),
-            Some("true")
-        );
-        assert!(schema.metadata.contains_key("openapi_generated_from"));
+        Some(SchemaReference {
+            name,
+            schema_type: SchemaType::TypeScript,
+            location: location.clone(),
+            metadata: std::collections::HashMap::from([("fields".to_string(), fields.join(","))]),
+        })
     }
 
-    #[test]
-    fn test_extract_typescript_schemas_type_alias() {
-        let parser = TypeScriptParser::new();
-        let source = r#"
-type UserId = string;
-type UserRole = 'admin' | 'user';
-"#;
-        let temp_dir = TempDir::new().unwrap();
-        let test_file = temp_dir.path().join("test.ts");
-        std::fs::write(&test_file, source).unwrap();
+    /// Schema of a declared type; primitives and arrays are not object bodies
+    fn typed_schema(&self, type_name: &str, location: &Location) -> Option<SchemaReference> {
+        if !type_name.starts_with(|c: char| c.is_ascii_uppercase()) || type_name.ends_with("[]") {
+            return None;
+        }
+        Some(
+            self.local_schemas
+                .get(type_name)
+                .cloned()
+                .unwrap_or_else(|| SchemaReference {
+                    name: type_name.to_string(),
+                    schema_type: SchemaType::TypeScript,
+                    location: location.clone(),
+                    metadata: std::collections::HashMap::new(),
+                }),
+        )
+    }
 
-        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
-        let schemas =
-            parser.extract_typescript_schemas(&module, test_file.to_str().unwrap(), &converter);
+    /// Fields of an object literal, or `None` if a spread cannot be resolved
+    fn object_fields(&self, object: &ObjectLit) -> Option<Vec<String>> {
+        let mut fields: Vec<String> = Vec::new();
+        let mut push = |field: String| {
+            // Later properties override earlier ones (`{ ...defaults, name }`)
+            let name = field.split(':').next().unwrap_or_default().to_string();
+            fields.retain(|existing| existing.split(':').next() != Some(name.as_str()));
+            fields.push(field);
+        };
+
+        for prop in &object.props {
+            match prop {
+                PropOrSpread::Spread(spread) => {
+                    for field in self.spread_fields(&spread.expr)? {
+                        push(field);
+                    }
+                }
+                PropOrSpread::Prop(prop) => match prop.as_ref() {
+                    Prop::KeyValue(key_value) => {
+                        let name = prop_name(&key_value.key)?;
+                        push(format!(
+                            "{}:{}:required",
+                            name,
+                            self.value_type(&key_value.value)
+                        ));
+                    }
+                    Prop::Shorthand(ident) => {
+                        let type_name = match self.lookup(&ident.sym) {
+                            Some(BodyBinding::Typed(type_name)) => type_name.clone(),
+                            Some(BodyBinding::Object(_)) => "object".to_string(),
+                            None => "any".to_string(),
+                        };
+                        push(format!("{}:{}:required", ident.sym, type_name));
+                    }
+                    // Methods and accessors are not serialized as data
+                    _ => {}
+                },
+            }
+        }
+
+        Some(fields)
+    }
+
+    /// Fields contributed by `...expr`
+    fn spread_fields(&self, expr: &Expr) -> Option<Vec<String>> {
+        match expr {
+            Expr::Ident(ident) => match self.lookup(&ident.sym)? {
+                BodyBinding::Object(fields) => Some(fields.clone()),
+                BodyBinding::Typed(type_name) => {
+                    let fields = self.local_schemas.get(type_name)?.metadata.get("fields")?;
+                    Some(
+                        fields
+                            .split(',')
+                            .filter(|field| !field.is_empty())
+                            .map(str::to_string)
+                            .collect(),
+                    )
+                }
+            },
+            Expr::Object(object) => self.object_fields(object),
+            Expr::Paren(paren) => self.spread_fields(&paren.expr),
+            _ => None,
+        }
+    }
+
+    /// Infers the TypeScript type of a property value
+    fn value_type(&self, expr: &Expr) -> String {
+        match expr {
+            Expr::Lit(Lit::Str(_)) | Expr::Tpl(_) => "string".to_string(),
+            Expr::Lit(Lit::Num(_)) => "number".to_string(),
+            Expr::Lit(Lit::Bool(_)) => "boolean".to_string(),
+            Expr::Lit(Lit::Null(_)) => "null".to_string(),
+            Expr::Object(_) => "object".to_string(),
+            Expr::Array(_) => "any[]".to_string(),
+            Expr::TsAs(as_expr) => self.parser.ts_type_to_string(&as_expr.type_ann),
+            Expr::Paren(paren) => self.value_type(&paren.expr),
+            Expr::Ident(ident) => match self.lookup(&ident.sym) {
+                Some(BodyBinding::Typed(type_name)) => type_name.clone(),
+                Some(BodyBinding::Object(_)) => "object".to_string(),
+                None => "any".to_string(),
+            },
+            _ => "any".to_string(),
+        }
+    }
+
+    /// Innermost binding with the given name
+    fn lookup(&self, name: &str) -> Option<&BodyBinding> {
+        self.scope
+            .iter()
+            .rev()
+            .find(|(binding, _)| binding == name)
+            .map(|(_, binding)| binding)
+    }
+}
+
+/// Function registered as an Express route handler
+#[derive(Clone, Copy)]
+enum ExpressHandler<'a> {
+    Function(&'a Function),
+    Arrow(&'a ArrowExpr),
+}
+
+/// Walks a module collecting Express route registrations
+struct ExpressRouteCollector<'a> {
+    parser: &'a TypeScriptParser,
+    file_path: &'a str,
+    converter: &'a LocationConverter,
+    /// Names of apps and routers routes are registered on
+    receivers: std::collections::HashSet<String>,
+    /// Functions declared at module level, by name
+    handlers: std::collections::HashMap<String, ExpressHandler<'a>>,
+    routes: Vec<crate::parsers::ExpressRoute>,
+}
+
+impl<'a> ExpressRouteCollector<'a> {
+    /// Records an app/router variable or a named function declared at module level
+    fn declare(&mut self, decl: &'a Decl) {
+        match decl {
+            Decl::Fn(fn_decl) => {
+                self.handlers.insert(
+                    fn_decl.ident.sym.to_string(),
+                    ExpressHandler::Function(&fn_decl.function),
+                );
+            }
+            Decl::Var(var_decl) => {
+                for decl in &var_decl.decls {
+                    let (Pat::Ident(ident), Some(init)) = (&decl.name, decl.init.as_deref()) else {
+                        continue;
+                    };
+                    let name = ident.id.sym.to_string();
+                    match init {
+                        Expr::Call(call)
+                            if matches!(
+                                self.parser.call_name(&call.callee).as_deref(),
+                                Some("express" | "express.Router" | "Router")
+                            ) =>
+                        {
+                            self.receivers.insert(name);
+                        }
+                        Expr::Arrow(arrow) => {
+                            self.handlers.insert(name, ExpressHandler::Arrow(arrow));
+                        }
+                        Expr::Fn(fn_expr) => {
+                            self.handlers
+                                .insert(name, ExpressHandler::Function(&fn_expr.function));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn walk_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Expr(expr_stmt) => self.walk_expr(&expr_stmt.expr),
+            Stmt::Block(block) => self.walk_stmts(&block.stmts),
+            Stmt::If(if_stmt) => {
+                self.walk_stmt(&if_stmt.cons);
+                if let Some(alt) = &if_stmt.alt {
+                    self.walk_stmt(alt);
+                }
+            }
+            Stmt::Decl(Decl::Fn(fn_decl)) => self.walk_function(&fn_decl.function),
+            _ => {}
+        }
+    }
+
+    fn walk_stmts(&mut self, stmts: &[Stmt]) {
+        for stmt in stmts {
+            self.walk_stmt(stmt);
+        }
+    }
+
+    /// Walks a function registering routes (`function registerRoutes(app: Express) { ... }`)
+    fn walk_function(&mut self, function: &Function) {
+        if let Some(body) = &function.body {
+            self.walk_stmts(&body.stmts);
+        }
+    }
+
+    fn walk_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Call(call) => {
+                if let Some(route) = self.route(call) {
+                    self.routes.push(route);
+                }
+            }
+            Expr::Arrow(arrow) => {
+                if let BlockStmtOrExpr::BlockStmt(block) = arrow.body.as_ref() {
+                    self.walk_stmts(&block.stmts);
+                }
+            }
+            Expr::Paren(paren) => self.walk_expr(&paren.expr),
+            _ => {}
+        }
+    }
+
+    /// Route registered by `receiver.method("/path", ...handlers)`
+    fn route(&self, call: &CallExpr) -> Option<crate::parsers::ExpressRoute> {
+        let Callee::Expr(callee) = &call.callee else {
+            return None;
+        };
+        let Expr::Member(member) = callee.as_ref() else {
+            return None;
+        };
+        let Expr::Ident(receiver) = member.obj.as_ref() else {
+            return None;
+        };
+        let MemberProp::Ident(method) = &member.prop else {
+            return None;
+        };
+        let method = method.sym.to_string();
+        if !self.receivers.contains(receiver.sym.as_ref())
+            || !matches!(
+                method.as_str(),
+                "get" | "post" | "put" | "patch" | "delete" | "options" | "head"
+            )
+            || call.args.len() < 2
+        {
+            return None;
+        }
+        let path = match call.args.first()?.expr.as_ref() {
+            Expr::Lit(Lit::Str(path)) => path.value.as_str()?.to_string(),
+            Expr::Tpl(tpl) if tpl.exprs.is_empty() => tpl.quasis.first()?.raw.to_string(),
+            _ => return None,
+        };
+
+        let (handler, function) = match call.args.last()?.expr.as_ref() {
+            Expr::Ident(ident) => (
+                Some(ident.sym.to_string()),
+                self.handlers.get(ident.sym.as_ref()).copied(),
+            ),
+            Expr::Arrow(arrow) => (None, Some(ExpressHandler::Arrow(arrow))),
+            Expr::Fn(fn_expr) => (None, Some(ExpressHandler::Function(&fn_expr.function))),
+            _ => (None, None),
+        };
+        let types = function.map(|function| self.handler_types(function));
+        let (request_type, response_type) = types.unwrap_or_default();
+
+        let (line, column) = self
+            .converter
+            .byte_offset_to_location(call.span.lo.0 as usize);
+        Some(crate::parsers::ExpressRoute {
+            method,
+            path,
+            handler,
+            request_type,
+            response_type,
+            location: Location {
+                file: self.file_path.to_string(),
+                line,
+                column: Some(column),
+            },
+        })
+    }
+
+    /// Body and response types of a handler
+    fn handler_types(&self, handler: ExpressHandler) -> (Option<String>, Option<String>) {
+        let params: Vec<&Pat> = match handler {
+            ExpressHandler::Function(function) => {
+                function.params.iter().map(|param| &param.pat).collect()
+            }
+            ExpressHandler::Arrow(arrow) => arrow.params.iter().collect(),
+        };
+        let param = |idx: usize| match params.get(idx) {
+            Some(Pat::Ident(ident)) => Some(ident),
+            _ => None,
+        };
+
+        let mut types = HandlerTypeCollector {
+            parser: self.parser,
+            req: param(0).map_or("req".to_string(), |ident| ident.id.sym.to_string()),
+            res: param(1).map_or("res".to_string(), |ident| ident.id.sym.to_string()),
+            bindings: std::collections::HashMap::new(),
+            request_type: None,
+            response_type: None,
+        };
+        // `req: Request<Params, ResBody, ReqBody>`, `res: Response<ResBody>`
+        let type_arg = |idx: usize, expected: &str, arg: usize| {
+            let type_ann = param(idx)?.type_ann.as_ref()?;
+            let TsType::TsTypeRef(type_ref) = type_ann.type_ann.as_ref() else {
+                return None;
+            };
+            let name = self.parser.ts_entity_name_to_string(&type_ref.type_name);
+            if name.rsplit('.').next() != Some(expected) {
+                return None;
+            }
+            let type_arg = type_ref.type_params.as_ref()?.params.get(arg)?;
+            schema_type_name(self.parser.ts_type_to_string(type_arg))
+        };
+        types.request_type = type_arg(0, "Request", 2);
+        types.response_type = type_arg(1, "Response", 0).or_else(|| type_arg(0, "Request", 1));
+
+        match handler {
+            ExpressHandler::Function(function) => {
+                if let Some(body) = &function.body {
+                    types.walk_stmts(&body.stmts);
+                }
+            }
+            ExpressHandler::Arrow(arrow) => match arrow.body.as_ref() {
+                BlockStmtOrExpr::BlockStmt(block) => types.walk_stmts(&block.stmts),
+                BlockStmtOrExpr::Expr(body) => types.walk_expr(body),
+            },
+        }
+        (types.request_type, types.response_type)
+    }
+}
+
+/// Walks an Express handler collecting the types of `req.body` and of the response
+struct HandlerTypeCollector<'a> {
+    parser: &'a TypeScriptParser,
+    /// Name of the request parameter
+    req: String,
+    /// Name of the response parameter
+    res: String,
+    /// Declared types of the handler's variables
+    bindings: std::collections::HashMap<String, String>,
+    request_type: Option<String>,
+    response_type: Option<String>,
+}
+
+impl HandlerTypeCollector<'_> {
+    fn walk_stmts(&mut self, stmts: &[Stmt]) {
+        for stmt in stmts {
+            self.walk_stmt(stmt);
+        }
+    }
+
+    fn walk_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Expr(expr_stmt) => self.walk_expr(&expr_stmt.expr),
+            Stmt::Return(ret_stmt) => {
+                if let Some(arg) = &ret_stmt.arg {
+                    self.walk_expr(arg);
+                }
+            }
+            Stmt::If(if_stmt) => {
+                self.walk_stmt(&if_stmt.cons);
+                if let Some(alt) = &if_stmt.alt {
+                    self.walk_stmt(alt);
+                }
+            }
+            Stmt::Block(block) => self.walk_stmts(&block.stmts),
+            Stmt::Try(try_stmt) => {
+                self.walk_stmts(&try_stmt.block.stmts);
+                if let Some(handler) = &try_stmt.handler {
+                    self.walk_stmts(&handler.body.stmts);
+                }
+            }
+            Stmt::Decl(Decl::Var(var_decl)) => {
+                for decl in &var_decl.decls {
+                    if let Some(init) = &decl.init {
+                        self.walk_expr(init);
+                    }
+                    let Pat::Ident(ident) = &decl.name else {
+                        continue;
+                    };
+                    let type_name = match (&ident.type_ann, decl.init.as_deref()) {
+                        (Some(type_ann), _) => self.parser.ts_type_ann_to_string(type_ann),
+                        (None, Some(Expr::TsAs(as_expr))) => {
+                            self.parser.ts_type_to_string(&as_expr.type_ann)
+                        }
+                        _ => continue,
+                    };
+                    // `const payload: CreateUser = req.body`
+                    if decl
+                        .init
+                        .as_deref()
+                        .is_some_and(|init| self.is_req_body(init))
+                    {
+                        self.request_type = self
+                            .request_type
+                            .take()
+                            .or_else(|| schema_type_name(type_name.clone()));
+                    }
+                    self.bindings.insert(ident.id.sym.to_string(), type_name);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn walk_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Call(call) => {
+                if self.is_response_send(&call.callee) {
+                    if let Some(arg) = call.args.first() {
+                        self.response_type = self
+                            .response_type
+                            .take()
+                            .or_else(|| self.value_type(&arg.expr));
+                    }
+                }
+                if let Callee::Expr(callee) = &call.callee {
+                    self.walk_expr(callee);
+                }
+                for arg in &call.args {
+                    self.walk_expr(&arg.expr);
+                }
+            }
+            // `req.body as CreateUser`
+            Expr::TsAs(as_expr) => {
+                if self.is_req_body(&as_expr.expr) {
+                    self.request_type = self.request_type.take().or_else(|| {
+                        schema_type_name(self.parser.ts_type_to_string(&as_expr.type_ann))
+                    });
+                }
+                self.walk_expr(&as_expr.expr);
+            }
+            Expr::Await(await_expr) => self.walk_expr(&await_expr.arg),
+            Expr::Paren(paren) => self.walk_expr(&paren.expr),
+            Expr::Member(member) => self.walk_expr(&member.obj),
+            Expr::Assign(assign) => self.walk_expr(&assign.right),
+            Expr::Cond(cond) => {
+                self.walk_expr(&cond.cons);
+                self.walk_expr(&cond.alt);
+            }
+            _ => {}
+        }
+    }
+
+    /// `req.body`
+    fn is_req_body(&self, expr: &Expr) -> bool {
+        let Expr::Member(member) = expr else {
+            return false;
+        };
+        matches!(member.obj.as_ref(), Expr::Ident(obj) if *obj.sym == *self.req)
+            && matches!(&member.prop, MemberProp::Ident(prop) if &*prop.sym == "body")
+    }
+
+    /// `res.json`, `res.send`, `res.status(201).json`
+    fn is_response_send(&self, callee: &Callee) -> bool {
+        let Callee::Expr(callee) = callee else {
+            return false;
+        };
+        let Expr::Member(member) = callee.as_ref() else {
+            return false;
+        };
+        if !matches!(&member.prop, MemberProp::Ident(prop) if matches!(&*prop.sym, "json" | "send"))
+        {
+            return false;
+        }
+        let mut obj = member.obj.as_ref();
+        loop {
+            match obj {
+                Expr::Ident(ident) => return *ident.sym == *self.res,
+                Expr::Call(call) => match &call.callee {
+                    Callee::Expr(callee) => match callee.as_ref() {
+                        Expr::Member(inner) => obj = inner.obj.as_ref(),
+                        _ => return false,
+                    },
+                    _ => return false,
+                },
+                _ => return false,
+            }
+        }
+    }
+
+    /// Schema type of a value sent as the response
+    fn value_type(&self, expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Ident(ident) => schema_type_name(self.bindings.get(ident.sym.as_ref())?.clone()),
+            Expr::TsAs(as_expr) => {
+                schema_type_name(self.parser.ts_type_to_string(&as_expr.type_ann))
+            }
+            Expr::Paren(paren) => self.value_type(&paren.expr),
+            Expr::Await(await_expr) => self.value_type(&await_expr.arg),
+            _ => None,
+        }
+    }
+}
+
+/// Type name when it names an object schema (`CreateUser`), not a primitive, array or
+/// built-in type
+fn schema_type_name(type_name: String) -> Option<String> {
+    (type_name.starts_with(|c: char| c.is_ascii_uppercase())
+        && type_name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+        && !BUILTIN_TYPE_NAMES.contains(&type_name.as_str()))
+    .then_some(type_name)
+}
+
+/// Types of HTTP clients: `.get()`/`.post()`/... on bindings of these types are requests
+const HTTP_CLIENT_TYPES: &[&str] = &["AxiosInstance", "AxiosStatic", "HttpClient"];
+
+/// Whether `type_name` (possibly qualified, `axios.AxiosInstance`) is an HTTP client type
+fn is_http_client_type(type_name: &str) -> bool {
+    let name = type_name.rsplit('.').next().unwrap_or(type_name);
+    HTTP_CLIENT_TYPES.contains(&name)
+}
+
+/// Walks a module collecting HTTP requests
+struct HttpCallCollector<'a> {
+    parser: &'a TypeScriptParser,
+    file_path: &'a str,
+    converter: &'a LocationConverter,
+    /// Names of HTTP clients (`axios`, variables initialized with `axios.create()`)
+    clients: std::collections::HashSet<String>,
+    /// `this.<name>` HTTP clients of the class being walked
+    class_clients: Vec<String>,
+    /// Typed bindings visible in declaration order; truncated when a function or block ends
+    scope: Vec<(String, String)>,
+    calls: Vec<crate::parsers::HttpCall>,
+}
+
+impl HttpCallCollector<'_> {
+    fn walk_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Expr(expr_stmt) => self.walk_expr(&expr_stmt.expr),
+            Stmt::Return(ret_stmt) => {
+                if let Some(arg) = &ret_stmt.arg {
+                    self.walk_expr(arg);
+                }
+            }
+            Stmt::If(if_stmt) => {
+                self.walk_expr(&if_stmt.test);
+                self.walk_stmt(&if_stmt.cons);
+                if let Some(alt) = &if_stmt.alt {
+                    self.walk_stmt(alt);
+                }
+            }
+            Stmt::Switch(switch_stmt) => {
+                for case in &switch_stmt.cases {
+                    self.walk_stmts(&case.cons);
+                }
+            }
+            Stmt::Block(block) => self.walk_stmts(&block.stmts),
+            Stmt::Try(try_stmt) => {
+                self.walk_stmts(&try_stmt.block.stmts);
+                if let Some(handler) = &try_stmt.handler {
+                    self.walk_stmts(&handler.body.stmts);
+                }
+                if let Some(finalizer) = &try_stmt.finalizer {
+                    self.walk_stmts(&finalizer.stmts);
+                }
+            }
+            Stmt::While(while_stmt) => self.walk_stmt(&while_stmt.body),
+            Stmt::For(for_stmt) => self.walk_stmt(&for_stmt.body),
+            Stmt::ForOf(for_of) => self.walk_stmt(&for_of.body),
+            Stmt::ForIn(for_in) => self.walk_stmt(&for_in.body),
+            Stmt::Decl(decl) => self.walk_decl(decl),
+            _ => {}
+        }
+    }
+
+    /// Walks a block; its bindings go out of scope at the end
+    fn walk_stmts(&mut self, stmts: &[Stmt]) {
+        let scope_len = self.scope.len();
+        for stmt in stmts {
+            self.walk_stmt(stmt);
+        }
+        self.scope.truncate(scope_len);
+    }
+
+    fn walk_decl(&mut self, decl: &Decl) {
+        match decl {
+            Decl::Fn(fn_decl) => self.walk_function(&fn_decl.function),
+            Decl::Class(class_decl) => self.walk_class(&class_decl.class),
+            Decl::Var(var_decl) => {
+                for decl in &var_decl.decls {
+                    if let Pat::Ident(ident) = &decl.name {
+                        let name = ident.id.sym.to_string();
+                        if let Some(type_ann) = &ident.type_ann {
+                            let type_name = self.parser.ts_type_ann_to_string(type_ann);
+                            self.scope.push((name.clone(), type_name));
+                        }
+                        if let Some(Expr::Call(call)) = decl.init.as_deref() {
+                            if self.parser.call_name(&call.callee).as_deref()
+                                == Some("axios.create")
+                            {
+                                self.clients.insert(name);
+                            }
+                        }
+                    }
+                    if let Some(init) = &decl.init {
+                        self.walk_expr(init);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Walks class methods (`this.http.get(...)` in a service class)
+    fn walk_class(&mut self, class: &Class) {
+        let class_clients = class
+            .body
+            .iter()
+            .flat_map(|member| self.class_clients(member))
+            .collect();
+        let outer = std::mem::replace(&mut self.class_clients, class_clients);
+        for member in &class.body {
+            match member {
+                ClassMember::Method(method) => self.walk_function(&method.function),
+                ClassMember::Constructor(constructor) => {
+                    if let Some(body) = &constructor.body {
+                        self.walk_stmts(&body.stmts);
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.class_clients = outer;
+    }
+
+    /// Names of the HTTP client properties a class member declares: typed properties and
+    /// constructor parameter properties, and properties initialized with `axios.create()`
+    fn class_clients(&self, member: &ClassMember) -> Vec<String> {
+        let is_client_ident = |ident: &BindingIdent| {
+            ident.type_ann.as_ref().is_some_and(|type_ann| {
+                is_http_client_type(&self.parser.ts_type_ann_to_string(type_ann))
+            })
+        };
+        match member {
+            ClassMember::ClassProp(prop) => {
+                let PropName::Ident(key) = &prop.key else {
+                    return Vec::new();
+                };
+                let typed = prop.type_ann.as_ref().is_some_and(|type_ann| {
+                    is_http_client_type(&self.parser.ts_type_ann_to_string(type_ann))
+                });
+                let created = matches!(
+                    prop.value.as_deref(),
+                    Some(Expr::Call(call))
+                        if self.parser.call_name(&call.callee).as_deref() == Some("axios.create")
+                );
+                if typed || created {
+                    vec![key.sym.to_string()]
+                } else {
+                    Vec::new()
+                }
+            }
+            ClassMember::Constructor(constructor) => constructor
+                .params
+                .iter()
+                .filter_map(|param| match param {
+                    ParamOrTsParamProp::TsParamProp(prop) => match &prop.param {
+                        TsParamPropParam::Ident(ident) if is_client_ident(ident) => {
+                            Some(ident.id.sym.to_string())
+                        }
+                        _ => None,
+                    },
+                    ParamOrTsParamProp::Param(_) => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn walk_function(&mut self, function: &Function) {
+        let scope_len = self.scope.len();
+        for param in &function.params {
+            self.bind_param(&param.pat);
+        }
+        if let Some(body) = &function.body {
+            self.walk_stmts(&body.stmts);
+        }
+        self.scope.truncate(scope_len);
+    }
+
+    /// Brings a typed parameter (`payload: CreateUser`, `payload: CreateUser = ...`) into scope
+    fn bind_param(&mut self, pat: &Pat) {
+        let ident = match pat {
+            Pat::Ident(ident) => ident,
+            Pat::Assign(assign) => match assign.left.as_ref() {
+                Pat::Ident(ident) => ident,
+                _ => return,
+            },
+            _ => return,
+        };
+        if let Some(type_ann) = &ident.type_ann {
+            let type_name = self.parser.ts_type_ann_to_string(type_ann);
+            self.scope.push((ident.id.sym.to_string(), type_name));
+        }
+    }
+
+    fn walk_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Call(call) => {
+                if let Some(http_call) = self.http_call(call) {
+                    self.calls.push(http_call);
+                }
+                if let Callee::Expr(callee) = &call.callee {
+                    self.walk_expr(callee);
+                }
+                for arg in &call.args {
+                    self.walk_expr(&arg.expr);
+                }
+            }
+            Expr::Await(await_expr) => self.walk_expr(&await_expr.arg),
+            Expr::Paren(paren) => self.walk_expr(&paren.expr),
+            Expr::Member(member) => self.walk_expr(&member.obj),
+            Expr::Assign(assign) => self.walk_expr(&assign.right),
+            Expr::TsAs(as_expr) => self.walk_expr(&as_expr.expr),
+            Expr::Cond(cond) => {
+                self.walk_expr(&cond.test);
+                self.walk_expr(&cond.cons);
+                self.walk_expr(&cond.alt);
+            }
+            Expr::Arrow(arrow) => {
+                let scope_len = self.scope.len();
+                for param in &arrow.params {
+                    self.bind_param(param);
+                }
+                match arrow.body.as_ref() {
+                    BlockStmtOrExpr::BlockStmt(block) => self.walk_stmts(&block.stmts),
+                    BlockStmtOrExpr::Expr(body) => self.walk_expr(body),
+                }
+                self.scope.truncate(scope_len);
+            }
+            Expr::Fn(fn_expr) => self.walk_function(&fn_expr.function),
+            // `Promise.all([api.get("/a"), api.get("/b")])`
+            Expr::Array(array) => {
+                for elem in array.elems.iter().flatten() {
+                    self.walk_expr(&elem.expr);
+                }
+            }
+            // `{ data: await api.get("/a") }`
+            Expr::Object(object) => {
+                for prop in &object.props {
+                    match prop {
+                        PropOrSpread::Spread(spread) => self.walk_expr(&spread.expr),
+                        PropOrSpread::Prop(prop) => {
+                            if let Prop::KeyValue(key_value) = prop.as_ref() {
+                                self.walk_expr(&key_value.value);
+                            }
+                        }
+                    }
+                }
+            }
+            Expr::Tpl(tpl) => {
+                for expr in &tpl.exprs {
+                    self.walk_expr(expr);
+                }
+            }
+            Expr::Bin(bin) => {
+                self.walk_expr(&bin.left);
+                self.walk_expr(&bin.right);
+            }
+            Expr::New(new_expr) => {
+                self.walk_expr(&new_expr.callee);
+                for arg in new_expr.args.iter().flatten() {
+                    self.walk_expr(&arg.expr);
+                }
+            }
+            Expr::Seq(seq) => {
+                for expr in &seq.exprs {
+                    self.walk_expr(expr);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// HTTP request made by `call`, if it is one
+    fn http_call(&self, call: &CallExpr) -> Option<crate::parsers::HttpCall> {
+        use crate::call_graph::HttpMethod;
+
+        let Callee::Expr(callee) = &call.callee else {
+            return None;
+        };
+        let arg = |idx: usize| call.args.get(idx).map(|arg| arg.expr.as_ref());
+        let (method, url, body) = match callee.as_ref() {
+            Expr::Ident(ident) if ident.sym.as_ref() == "fetch" => {
+                let options = arg(1).and_then(object_lit);
+                let method = options
+                    .and_then(|options| object_prop(options, "method"))
+                    .and_then(str_lit)
+                    .and_then(HttpMethod::from_str_opt)
+                    .unwrap_or(HttpMethod::Get);
+                let body = options.and_then(|options| object_prop(options, "body"));
+                (method, arg(0), body)
+            }
+            Expr::Ident(ident) if ident.sym.as_ref() == "axios" => request_config(arg(0)?)?,
+            Expr::Member(member) => {
+                let MemberProp::Ident(prop) = &member.prop else {
+                    return None;
+                };
+                if !self.is_client(&member.obj) {
+                    return None;
+                }
+                if prop.sym.as_ref() == "request" {
+                    request_config(arg(0)?)?
+                } else {
+                    let method = HttpMethod::from_str_opt(prop.sym.as_ref())?;
+                    let body = match method {
+                        HttpMethod::Post | HttpMethod::Put | HttpMethod::Patch => arg(1),
+                        _ => None,
+                    };
+                    (method, arg(0), body)
+                }
+            }
+            _ => return None,
+        };
+
+        let (url_literal, url_is_prefix) = url.map(url_literal).unwrap_or((None, false));
+        let type_args = call.type_args.as_ref().map(|args| &args.params[..]);
+        let type_arg = |idx: usize| {
+            type_args
+                .and_then(|params| params.get(idx))
+                .map(|ts_type| self.parser.ts_type_to_string(ts_type))
+        };
+        let request_type = type_arg(2).or_else(|| body.and_then(|body| self.value_type(body)));
+        let (line, column) = self
+            .converter
+            .byte_offset_to_location(call.span.lo.0 as usize);
+        Some(crate::parsers::HttpCall {
+            method,
+            url_literal,
+            url_is_prefix,
+            request_type,
+            response_generic: type_arg(0),
+            location: Location {
+                file: self.file_path.to_string(),
+                line,
+                column: Some(column),
+            },
+        })
+    }
+
+    /// Whether `expr` names an HTTP client (`axios`, `api` from `axios.create()`, a binding
+    /// typed `AxiosInstance`, `this.http` typed `HttpClient`)
+    fn is_client(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Ident(ident) => {
+                let name = ident.sym.as_ref();
+                match self.scope.iter().rev().find(|(bound, _)| bound == name) {
+                    Some((_, type_name)) => is_http_client_type(type_name),
+                    None => self.clients.contains(name),
+                }
+            }
+            Expr::Member(member) => match (member.obj.as_ref(), &member.prop) {
+                (Expr::This(_), MemberProp::Ident(prop)) => self
+                    .class_clients
+                    .iter()
+                    .any(|name| name == prop.sym.as_ref()),
+                _ => false,
+            },
+            Expr::Paren(paren) => self.is_client(&paren.expr),
+            _ => false,
+        }
+    }
+
+    /// Type of a request body: `JSON.stringify(x)` is typed as `x`
+    fn value_type(&self, expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Call(call)
+                if self.parser.call_name(&call.callee).as_deref() == Some("JSON.stringify") =>
+            {
+                self.value_type(call.args.first()?.expr.as_ref())
+            }
+            Expr::TsAs(as_expr) => Some(self.parser.ts_type_to_string(&as_expr.type_ann)),
+            Expr::TsSatisfies(satisfies) => {
+                Some(self.parser.ts_type_to_string(&satisfies.type_ann))
+            }
+            Expr::Paren(paren) => self.value_type(&paren.expr),
+            Expr::Ident(ident) => self
+                .scope
+                .iter()
+                .rev()
+                .find(|(name, _)| name == ident.sym.as_ref())
+                .map(|(_, type_name)| type_name.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Method, URL and body of an axios request config (`{ method: "post", url, data }`)
+fn request_config(
+    config: &Expr,
+) -> Option<(crate::call_graph::HttpMethod, Option<&Expr>, Option<&Expr>)> {
+    let config = object_lit(config)?;
+    let method = object_prop(config, "method")
+        .and_then(str_lit)
+        .and_then(crate::call_graph::HttpMethod::from_str_opt)
+        .unwrap_or(crate::call_graph::HttpMethod::Get);
+    Some((
+        method,
+        object_prop(config, "url"),
+        object_prop(config, "data"),
+    ))
+}
+
+/// URL of a string or template literal, and whether it is only the template's static prefix
+fn url_literal(expr: &Expr) -> (Option<String>, bool) {
+    match expr {
+        Expr::Lit(Lit::Str(url)) => (url.value.as_str().map(str::to_string), false),
+        Expr::Tpl(tpl) => {
+            let prefix = tpl
+                .quasis
+                .first()
+                .map(|quasi| quasi.raw.to_string())
+                .unwrap_or_default();
+            if tpl.exprs.is_empty() {
+                (Some(prefix), false)
+            } else if prefix.is_empty() {
+                (None, false)
+            } else {
+                (Some(prefix), true)
+            }
+        }
+        Expr::Paren(paren) => url_literal(&paren.expr),
+        _ => (None, false),
+    }
+}
+
+fn object_lit(expr: &Expr) -> Option<&ObjectLit> {
+    match expr {
+        Expr::Object(object) => Some(object),
+        _ => None,
+    }
+}
+
+/// Value of a `key: value` property of an object literal
+fn object_prop<'e>(object: &'e ObjectLit, key: &str) -> Option<&'e Expr> {
+    object.props.iter().find_map(|prop| {
+        let PropOrSpread::Prop(prop) = prop else {
+            return None;
+        };
+        match prop.as_ref() {
+            Prop::KeyValue(key_value) if prop_name(&key_value.key).as_deref() == Some(key) => {
+                Some(key_value.value.as_ref())
+            }
+            _ => None,
+        }
+    })
+}
+
+fn str_lit(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Lit(Lit::Str(str)) => str.value.as_str(),
+        _ => None,
+    }
+}
+
+/// Static name of an object property key
+fn prop_name(key: &PropName) -> Option<String> {
+    match key {
+        PropName::Ident(ident) => Some(ident.sym.to_string()),
+        PropName::Str(str) => str.value.as_str().map(str::to_string),
+        _ => None,
+    }
+}
+
+impl Default for TypeScriptParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Source text of the arguments of a parameter decorator call (`'id'`,
+/// `new ParseUUIDPipe({ version: '4' })`), string quotes trimmed like the source scan does
+fn parameter_decorator_arguments(decorator: &Decorator, source: &str) -> Vec<String> {
+    let Expr::Call(call_expr) = decorator.expr.as_ref() else {
+        return Vec::new();
+    };
+    call_expr
+        .args
+        .iter()
+        .filter_map(|arg| {
+            // Byte positions of a file start at 1
+            let span = arg.expr.span();
+            source.get((span.lo.0 as usize).checked_sub(1)?..(span.hi.0 as usize).checked_sub(1)?)
+        })
+        .map(|text| text.trim().trim_matches('\'').trim_matches('"').to_string())
+        .filter(|arg| !arg.is_empty())
+        .collect()
+}
+
+/// Top-level, comma-separated arguments of a decorator call, given the text after its `(`
+///
+/// Stops at the parenthesis closing the call; commas nested in calls, objects or arrays
+/// don't split. Arguments are trimmed.
+fn split_decorator_arguments(text: &str) -> Vec<&str> {
+    let mut args = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (idx, c) in text.char_indices() {
+        match c {
+            '(' | '{' | '[' => depth += 1,
+            ')' if depth == 0 => {
+                args.push(text[start..idx].trim());
+                break;
+            }
+            ')' | '}' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                args.push(text[start..idx].trim());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    args.retain(|arg| !arg.is_empty());
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_extract_imports() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+import { Component } from './Component';
+import express from 'express';
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("test.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let imports = parser.extract_imports(&module, test_file.to_str().unwrap(), &converter);
+
+        assert_eq!(imports.len(), 2);
+        assert_eq!(imports[0].path, "./Component");
+        assert_eq!(imports[1].path, "express");
+    }
+
+    #[test]
+    fn test_extract_calls() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+function test() {
+    doSomething();
+    anotherFunction(arg1, arg2);
+}
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("test.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, source, converter) = parser.parse_file(&test_file).unwrap();
+        let calls = parser.extract_calls(&module, test_file.to_str().unwrap(), &converter, &source);
+
+        assert!(calls.len() >= 2);
+        assert!(calls.iter().any(|c| c.name == "doSomething"));
+        assert!(calls.iter().any(|c| c.name == "anotherFunction"));
+    }
+
+    #[test]
+    fn test_extract_typescript_schemas_interface() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+interface User {
+    name: string;
+    age: number;
+    email?: string;
+}
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("test.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas =
+            parser.extract_typescript_schemas(&module, test_file.to_str().unwrap(), &converter);
+
+        assert_eq!(schemas.len(), 1);
+        assert_eq!(schemas[0].name, "User");
+        assert_eq!(schemas[0].schema_type, SchemaType::TypeScript);
+        assert!(schemas[0].metadata.contains_key("fields"));
+    }
+
+    #[test]
+    fn test_extract_typescript_enums() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+export enum Color { Red, Green = 5, Blue }
+enum Status { Active = "active", "On Hold" = "on_hold" }
+export const enum Level { Low = -1, High = 1 }
+enum Flags { None = 0, Read = 1 << 0, Write }
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("enums.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas =
+            parser.extract_typescript_schemas(&module, test_file.to_str().unwrap(), &converter);
+        let metadata = |name: &str, key: &str| {
+            schemas
+                .iter()
+                .find(|schema| schema.name == name)
+                .unwrap_or_else(|| panic!("enum {} not extracted", name))
+                .metadata
+                .get(key)
+                .cloned()
+        };
+
+        assert_eq!(schemas.len(), 4);
+        assert_eq!(metadata("Color", "enum_values").as_deref(), Some("[0,5,6]"));
+        assert_eq!(
+            metadata("Status", "enum_values").as_deref(),
+            Some(r#"["active","on_hold"]"#)
+        );
+        assert_eq!(metadata("Level", "enum_values").as_deref(), Some("[-1,1]"));
+        assert_eq!(metadata("Level", "const_enum").as_deref(), Some("true"));
+        assert_eq!(metadata("Color", "const_enum"), None);
+        // Computed members and the members numbered after them have no known value
+        assert_eq!(metadata("Flags", "enum_values").as_deref(), Some("[0]"));
+        assert_eq!(
+            metadata("Flags", "enum_computed_members").as_deref(),
+            Some("Read,Write")
+        );
+
+        let variants = |name: &str| -> serde_json::Value {
+            serde_json::from_str(&metadata(name, ENUM_VARIANTS_METADATA_KEY).unwrap()).unwrap()
+        };
+        assert_eq!(
+            variants("Color"),
+            serde_json::json!([
+                {"name": "Red", "value": 0},
+                {"name": "Green", "value": 5},
+                {"name": "Blue", "value": 6},
+            ])
+        );
+        assert_eq!(
+            variants("Status"),
+            serde_json::json!([
+                {"name": "Active", "value": "active"},
+                {"name": "On Hold", "value": "on_hold"},
+            ])
+        );
+        assert_eq!(
+            variants("Level"),
+            serde_json::json!([{"name": "Low", "value": -1}, {"name": "High", "value": 1}])
+        );
+        assert_eq!(
+            variants("Flags"),
+            serde_json::json!([
+                {"name": "None", "value": 0},
+                {"name": "Read", "value": null},
+                {"name": "Write", "value": null},
+            ])
+        );
+    }
+
+    #[test]
+    fn test_extract_typescript_schemas_generated_file_metadata() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+interface Item {
+    id: string;
+}
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        // Imitate common OpenAPI-generated types file
+        let test_file = temp_dir.path().join("types.gen.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas =
+            parser.extract_typescript_schemas(&module, test_file.to_str().unwrap(), &converter);
+
+        assert_eq!(schemas.len(), 1);
+        let schema = &schemas[0];
+        assert_eq!(schema.name, "Item");
+        assert_eq!(schema.schema_type, SchemaType::TypeScript);
+        // New metadata flags for generated files
+        assert_eq!(
+            schema.metadata.get("openapi_generated").map(String::as_str),
+            Some("true")
+        );
+        assert!(schema.metadata.contains_key("openapi_generated_from"));
+    }
+
+    #[test]
+    fn test_extract_typescript_schemas_type_alias() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+type UserId = string;
+type UserRole = 'admin' | 'user';
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("test.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas =
+            parser.extract_typescript_schemas(&module, test_file.to_str().unwrap(), &converter);
 
         assert_eq!(schemas.len(), 2);
         assert!(schemas.iter().any(|s| s.name == "UserId"));
         assert!(schemas.iter().any(|s| s.name == "UserRole"));
     }
 
+    #[test]
+    fn test_extract_typescript_utility_types() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+import { Account } from "./accounts";
+
+export interface User {
+    id: number;
+    name: string;
+    email?: string;
+}
+
+export type UserPatch = Partial<User>;
+export type FullUser = Required<User>;
+type UserRef = Pick<User, "id" | "name">;
+type NewUser = Omit<User, "id">;
+type NewUserPatch = Partial<NewUser>;
+type AccountPatch = Partial<Account>;
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("users.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas =
+            parser.extract_typescript_schemas(&module, test_file.to_str().unwrap(), &converter);
+        let metadata = |name: &str, key: &str| {
+            schemas
+                .iter()
+                .find(|schema| schema.name == name)
+                .unwrap_or_else(|| panic!("schema {} not extracted", name))
+                .metadata
+                .get(key)
+                .cloned()
+        };
+
+        assert_eq!(
+            metadata("UserPatch", "fields").as_deref(),
+            Some("id:number:optional,name:string:optional,email:string:optional")
+        );
+        assert_eq!(
+            metadata("FullUser", "fields").as_deref(),
+            Some("id:number:required,name:string:required,email:string:required")
+        );
+        assert_eq!(
+            metadata("UserRef", "fields").as_deref(),
+            Some("id:number:required,name:string:required")
+        );
+        assert_eq!(
+            metadata("UserRef", UTILITY_KEYS_METADATA_KEY).as_deref(),
+            Some("id,name")
+        );
+        assert_eq!(
+            metadata("NewUser", "fields").as_deref(),
+            Some("name:string:required,email:string:optional")
+        );
+        // Aliases over aliases resolve once their base does
+        assert_eq!(
+            metadata("NewUserPatch", "fields").as_deref(),
+            Some("name:string:optional,email:string:optional")
+        );
+        // The base is declared in another module: resolution stays pending
+        assert_eq!(metadata("AccountPatch", "fields"), None);
+        assert_eq!(
+            metadata("AccountPatch", UTILITY_BASE_METADATA_KEY).as_deref(),
+            Some("Account")
+        );
+        assert_eq!(
+            metadata("AccountPatch", UTILITY_TYPE_METADATA_KEY).as_deref(),
+            Some("Partial")
+        );
+    }
+
+    #[test]
+    fn test_extract_typescript_discriminated_unions() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+interface BlackCat { pet_type: "cat"; color: "black"; }
+interface WhiteCat { pet_type: "cat"; color: "white"; }
+type Cat = BlackCat | WhiteCat;
+export type Pet = Cat | { pet_type: "dog"; barks: boolean } | null;
+export interface Owner {
+    pet: Pet;
+    favorite: BlackCat | WhiteCat;
+    name: string;
+}
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("pets.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas =
+            parser.extract_typescript_schemas(&module, test_file.to_str().unwrap(), &converter);
+        let schema = |name: &str| schemas.iter().find(|s| s.name == name).unwrap();
+
+        let pet: crate::models::DiscriminatedUnion =
+            serde_json::from_str(schema("Pet").metadata.get("discriminated_union").unwrap())
+                .unwrap();
+        assert_eq!(pet.discriminator, "pet_type");
+        let tags: Vec<_> = pet.members.iter().map(|m| m.tag.as_deref()).collect();
+        assert_eq!(tags, vec![Some("cat"), Some("dog")]);
+        assert_eq!(pet.members[1].schema, r#"{ pet_type: "dog" }"#);
+
+        // Nested union: `Cat` is told apart by `color`
+        let nested = pet.members[0].nested.as_ref().unwrap();
+        assert_eq!(nested.discriminator, "color");
+
+        let owner_unions: std::collections::HashMap<String, crate::models::DiscriminatedUnion> =
+            serde_json::from_str(
+                schema("Owner")
+                    .metadata
+                    .get("discriminated_unions")
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(owner_unions.len(), 2);
+        assert_eq!(owner_unions["pet"], pet);
+        assert_eq!(owner_unions["favorite"].discriminator, "color");
+    }
+
     #[test]
     fn test_extract_zod_schemas() {
         let parser = TypeScriptParser::new();
@@ -2725,6 +6080,507 @@ const userSchema = z.object({
         assert_eq!(schemas[0].schema_type, SchemaType::Zod);
     }
 
+    #[test]
+    fn test_extract_zod_discriminated_unions() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+const Cat = z.object({ kind: z.literal("cat"), lives: z.number() });
+const Dog = z.object({ kind: z.literal("dog") });
+export const Pet = z.discriminatedUnion("kind", [Cat, Dog]);
+const Shape = z.union([
+    z.object({ type: z.literal("circle"), radius: z.number() }),
+    z.object({ type: z.literal("square"), side: z.number() }),
+]);
+const Owner = z.object({
+    pet: Pet.optional(),
+    shape: Shape,
+    toy: z.discriminatedUnion("kind", [Cat, z.object({ kind: z.literal("bird") })]),
+});
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("schemas.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas = parser.extract_zod_schemas(&module, test_file.to_str().unwrap(), &converter);
+        let schema = |name: &str| schemas.iter().find(|s| s.name == name).unwrap();
+        let tags = |union: &crate::models::DiscriminatedUnion| {
+            union
+                .members
+                .iter()
+                .map(|m| m.tag.clone().unwrap_or_default())
+                .collect::<Vec<_>>()
+        };
+
+        let pet: crate::models::DiscriminatedUnion =
+            serde_json::from_str(schema("Pet").metadata.get("discriminated_union").unwrap())
+                .unwrap();
+        assert_eq!(pet.discriminator, "kind");
+        assert_eq!(tags(&pet), vec!["cat", "dog"]);
+        assert_eq!(pet.members[0].schema, "Cat");
+
+        let shape: crate::models::DiscriminatedUnion =
+            serde_json::from_str(schema("Shape").metadata.get("discriminated_union").unwrap())
+                .unwrap();
+        assert_eq!(shape.discriminator, "type");
+        assert_eq!(tags(&shape), vec!["circle", "square"]);
+
+        let owner_unions: std::collections::HashMap<String, crate::models::DiscriminatedUnion> =
+            serde_json::from_str(
+                schema("Owner")
+                    .metadata
+                    .get("discriminated_unions")
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(owner_unions["pet"], pet);
+        assert_eq!(owner_unions["shape"], shape);
+        assert_eq!(tags(&owner_unions["toy"]), vec!["cat", "bird"]);
+    }
+
+    fn zod_fields(schema: &SchemaReference) -> Vec<crate::models::ZodField> {
+        serde_json::from_str(
+            schema
+                .metadata
+                .get("fields")
+                .expect("fields metadata missing"),
+        )
+        .expect("fields metadata should be JSON")
+    }
+
+    #[test]
+    fn test_extract_zod_lazy_recursive_schemas() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+export const Category: z.ZodType<Category> = z.lazy(() =>
+    z.object({
+        name: z.string(),
+        subcategories: z.array(z.lazy(() => Category)),
+        parent: z.lazy(() => Category).optional(),
+    })
+);
+const CategoryAlias = z.lazy(() => Category);
+const Loop = z.lazy(() => LoopBack);
+const LoopBack = z.lazy(() => Loop);
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("category.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas = parser.extract_zod_schemas(&module, test_file.to_str().unwrap(), &converter);
+        let schema = |name: &str| schemas.iter().find(|s| s.name == name).unwrap();
+
+        let fields = zod_fields(schema("Category"));
+        let field = |name: &str| fields.iter().find(|f| f.name == name).unwrap();
+        assert_eq!(field("name").schema_ref, None);
+        assert_eq!(field("subcategories").type_name, "array");
+        assert_eq!(
+            field("subcategories").schema_ref.as_deref(),
+            Some("Category")
+        );
+        assert_eq!(field("parent").type_name, "object");
+        assert_eq!(field("parent").schema_ref.as_deref(), Some("Category"));
+        assert!(field("parent").optional);
+
+        // Aliases resolve to the target's fields; alias cycles stay unresolved
+        assert_eq!(zod_fields(schema("CategoryAlias")).len(), 3);
+        assert!(!schema("Loop").metadata.contains_key("fields"));
+        assert!(!schema("LoopBack").metadata.contains_key("fields"));
+    }
+
+    #[test]
+    fn test_extract_zod_array_length_bounds() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+export const Order = z.object({
+    items: z.array(z.string()).min(1).max(10),
+    tags: z.array(z.string()).nonempty().optional(),
+    pair: z.array(z.number()).length(2),
+    notes: z.array(z.string()),
+    code: z.string().min(3),
+});
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("order.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas = parser.extract_zod_schemas(&module, test_file.to_str().unwrap(), &converter);
+        let fields = zod_fields(schemas.iter().find(|s| s.name == "Order").unwrap());
+        let bounds = |name: &str| {
+            let field = fields.iter().find(|f| f.name == name).unwrap();
+            (field.min_items, field.max_items)
+        };
+
+        assert_eq!(bounds("items"), (Some(1), Some(10)));
+        assert_eq!(bounds("tags"), (Some(1), None));
+        assert_eq!(bounds("pair"), (Some(2), Some(2)));
+        assert_eq!(bounds("notes"), (None, None));
+        // String length is not an item count
+        assert_eq!(bounds("code"), (None, None));
+    }
+
+    #[test]
+    fn test_extract_zod_default_values() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+export const Filters = z.object({
+    limit: z.number().int().default(10),
+    sort: z.enum(["asc", "desc"]).default("asc").optional(),
+    archived: z.boolean().default(false),
+    since: z.string().default(() => new Date().toISOString()),
+    query: z.string().optional(),
+});
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("filters.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas = parser.extract_zod_schemas(&module, test_file.to_str().unwrap(), &converter);
+        let fields = zod_fields(schemas.iter().find(|s| s.name == "Filters").unwrap());
+        let default = |name: &str| {
+            fields
+                .iter()
+                .find(|f| f.name == name)
+                .unwrap()
+                .default_value
+                .clone()
+        };
+
+        assert_eq!(default("limit"), Some(serde_json::json!(10)));
+        assert_eq!(default("sort"), Some(serde_json::json!("asc")));
+        assert_eq!(default("archived"), Some(serde_json::json!(false)));
+        // Only literal defaults are extracted
+        assert_eq!(default("since"), None);
+        assert_eq!(default("query"), None);
+    }
+
+    #[test]
+    fn test_extract_deprecated_fields_from_jsdoc() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+export interface User {
+    id: number;
+    /** @deprecated use fullName */
+    name: string;
+    /** Display name */
+    fullName: string;
+    // @deprecated in a line comment is not JSDoc
+    email: string;
+}
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("user.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas =
+            parser.extract_typescript_schemas(&module, test_file.to_str().unwrap(), &converter);
+        let user = schemas.iter().find(|s| s.name == "User").unwrap();
+
+        assert_eq!(
+            user.metadata.get("deprecated_fields").map(String::as_str),
+            Some("name")
+        );
+    }
+
+    #[test]
+    fn test_extract_zod_lazy_mutual_recursion_across_files() {
+        let parser = TypeScriptParser::new();
+        let temp_dir = TempDir::new().unwrap();
+        let author_file = temp_dir.path().join("author.ts");
+        let book_file = temp_dir.path().join("book.ts");
+        std::fs::write(
+            &author_file,
+            r#"
+import { Book } from './book';
+export const Author = z.object({
+    name: z.string(),
+    books: z.array(z.lazy(() => Book)),
+});
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            &book_file,
+            r#"
+import { Author } from './author';
+export const Book = z.object({
+    title: z.string(),
+    author: z.lazy(() => { return Author; }),
+});
+"#,
+        )
+        .unwrap();
+
+        let extract = |path: &std::path::Path| {
+            let (module, _, converter) = parser.parse_file(path).unwrap();
+            parser.extract_zod_schemas(&module, path.to_str().unwrap(), &converter)
+        };
+        let author = extract(&author_file);
+        let book = extract(&book_file);
+
+        let books = zod_fields(&author[0]);
+        assert_eq!(books[1].schema_ref.as_deref(), Some("Book"));
+        let author_field = zod_fields(&book[0]);
+        assert_eq!(author_field[1].type_name, "object");
+        assert_eq!(author_field[1].schema_ref.as_deref(), Some("Author"));
+    }
+
+    #[test]
+    fn test_extract_status_checks() {
+        use crate::models::HandledStatus;
+
+        let parser = TypeScriptParser::new();
+        let source = r#"
+function handle(res: Response) {
+    switch (res.status) {
+        case 401:
+            return "login";
+        case 403:
+            return "forbidden";
+    }
+    if (400 <= res.status) {
+        return "client";
+    }
+    if (res.status !== 204 && count > 3) {
+        return "other";
+    }
+}
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("handle.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let checks = parser.extract_status_checks(&module, test_file.to_str().unwrap(), &converter);
+
+        let statuses: Vec<HandledStatus> = checks.iter().map(|c| c.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                HandledStatus::Exact(401),
+                HandledStatus::Exact(403),
+                HandledStatus::Range { min: 400, max: 599 },
+            ]
+        );
+        assert!(checks.iter().all(|c| c.caller.as_deref() == Some("handle")));
+    }
+
+    #[test]
+    fn test_extract_request_bodies() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+interface CreateUser {
+    name: string;
+    email?: string;
+}
+
+export async function createUser(payload: CreateUser) {
+    return fetch("/users", { method: "POST", body: JSON.stringify(payload) });
+}
+
+export async function rename(id: number, name: string) {
+    const base = { id, active: true };
+    return axios.post(`/users/${id}`, JSON.stringify({ ...base, name, note: `by ${name}` }));
+}
+
+export async function forward(extra) {
+    return fetch("/users", { method: "PUT", body: JSON.stringify({ ...extra, id: 1 }) });
+}
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("users.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let bodies =
+            parser.extract_request_bodies(&module, test_file.to_str().unwrap(), &converter);
+
+        // The body spreading an untyped parameter cannot be resolved
+        assert_eq!(bodies.len(), 2);
+        assert_eq!(bodies[0].schema.name, "CreateUser");
+        assert_eq!(bodies[0].location.line, 8);
+        assert_eq!(
+            bodies[0].schema.metadata.get("fields").map(String::as_str),
+            Some("name:string:required,email:string:optional")
+        );
+        assert_eq!(bodies[1].location.line, 13);
+        assert_eq!(
+            bodies[1].schema.metadata.get("fields").map(String::as_str),
+            Some("id:number:required,active:boolean:required,name:string:required,note:string:required")
+        );
+    }
+
+    #[test]
+    fn test_extract_http_calls() {
+        use crate::call_graph::HttpMethod;
+
+        let parser = TypeScriptParser::new();
+        let source = r#"
+const api = axios.create({ baseURL: "/api" });
+
+export async function listUsers() {
+    return axios.get<User[]>("/users");
+}
+
+export async function createUser(payload: CreateUser) {
+    await fetch("/api/items", { method: "POST", body: JSON.stringify(payload) });
+    return api.post<User>(`/users/${payload.team}/members`, payload);
+}
+
+export function update(id: number, data: unknown) {
+    return axios({ method: "patch", url: `/users/${id}`, data: data as UpdateUser });
+}
+
+class UserService {
+    constructor(private http: HttpClient) {}
+
+    remove(id: number) {
+        return this.http.delete(`${BASE}/users/${id}`);
+    }
+}
+
+const cache = new Map();
+cache.get("/users");
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("users.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let calls = parser.extract_http_calls(&module, test_file.to_str().unwrap(), &converter);
+
+        let summary: Vec<_> = calls
+            .iter()
+            .map(|call| {
+                (
+                    call.method,
+                    call.url_literal.as_deref(),
+                    call.url_is_prefix,
+                    call.request_type.as_deref(),
+                    call.response_generic.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (HttpMethod::Get, Some("/users"), false, None, Some("User[]")),
+                (
+                    HttpMethod::Post,
+                    Some("/api/items"),
+                    false,
+                    Some("CreateUser"),
+                    None
+                ),
+                (
+                    HttpMethod::Post,
+                    Some("/users/"),
+                    true,
+                    Some("CreateUser"),
+                    Some("User")
+                ),
+                (
+                    HttpMethod::Patch,
+                    Some("/users/"),
+                    true,
+                    Some("UpdateUser"),
+                    None
+                ),
+                (HttpMethod::Delete, None, false, None, None),
+            ]
+        );
+        assert_eq!(calls[1].location.line, 9);
+    }
+
+    fn http_call_urls(source: &str) -> Vec<String> {
+        let parser = TypeScriptParser::new();
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("api.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        parser
+            .extract_http_calls(&module, test_file.to_str().unwrap(), &converter)
+            .into_iter()
+            .filter_map(|call| call.url_literal)
+            .collect()
+    }
+
+    #[test]
+    fn test_http_clients_are_axios_instances_or_typed_clients() {
+        let urls = http_call_urls(
+            r#"
+const client = new Map<string, string>();
+client.get("/cache-key");
+const request = { get: (key: string) => key };
+request.get("/not-a-request");
+const api = axios.create({ baseURL: "/api" });
+api.get("/created");
+
+export function load(http: AxiosInstance, store: Storage) {
+    http.get("/typed-param");
+    store.get("/storage-key");
+}
+
+class Orders {
+    private client = axios.create();
+    private cache = new Map<string, string>();
+
+    constructor(private readonly http: HttpClient, private api: OrdersApi) {}
+
+    list() {
+        this.client.get("/class-created");
+        this.http.get("/class-typed");
+        this.api.get("/untyped-wrapper");
+        this.cache.get("/class-cache");
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            urls,
+            ["/created", "/typed-param", "/class-created", "/class-typed"]
+        );
+    }
+
+    #[test]
+    fn test_http_calls_nested_in_expressions_are_found() {
+        let urls = http_call_urls(
+            r#"
+const api = axios.create();
+
+export async function dashboard() {
+    const [users, teams] = await Promise.all([api.get("/array-a"), api.get("/array-b")]);
+    const page = { data: await api.get("/object"), ...(await api.get("/spread")) };
+    const label = `${await api.get("/template")}`;
+    const merged = (await api.get("/bin-left")) || (await api.get("/bin-right"));
+    const wrapped = new Wrapper(await api.get("/new"));
+    return (log("loading"), api.get("/seq"));
+}
+"#,
+        );
+
+        assert_eq!(
+            urls,
+            [
+                "/array-a",
+                "/array-b",
+                "/object",
+                "/spread",
+                "/template",
+                "/bin-left",
+                "/bin-right",
+                "/new",
+                "/seq"
+            ]
+        );
+    }
+
     #[test]
     fn test_extract_functions_and_classes() {
         let parser = TypeScriptParser::new();
@@ -0,0 +1,256 @@
+use crate::call_graph::{node_location, CallGraph};
+use crate::models::{Location, NodeId};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use unicode_width::UnicodeWidthChar;
+
+/// Columns a tab advances to the next multiple of, unless configured
+pub const DEFAULT_TAB_WIDTH: usize = 4;
+
+/// Source line of a location with the pointed-at code underlined, rendered like rustc:
+///
+/// ```text
+///  --> app/models.py:12:5
+///    |
+/// 12 |     price: str
+///    |     ^^^^^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snippet {
+    /// Location the snippet was resolved for
+    pub location: Location,
+    /// Source line, with tabs expanded to spaces
+    pub line: String,
+    /// Display column (0-based, in terminal cells) where the underline starts
+    pub underline_start: usize,
+    /// Width of the underline in terminal cells (at least 1)
+    pub underline_width: usize,
+}
+
+impl Snippet {
+    /// Line of `^` aligned under the pointed-at code
+    pub fn caret_line(&self) -> String {
+        format!(
+            "{}{}",
+            " ".repeat(self.underline_start),
+            "^".repeat(self.underline_width)
+        )
+    }
+}
+
+impl fmt::Display for Snippet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.location.line.to_string();
+        let gutter = " ".repeat(number.len());
+        match self.location.column {
+            Some(column) => writeln!(
+                f,
+                "{} --> {}:{}:{}",
+                gutter, self.location.file, self.location.line, column
+            )?,
+            None => writeln!(
+                f,
+                "{} --> {}:{}",
+                gutter, self.location.file, self.location.line
+            )?,
+        }
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", number, self.line)?;
+        writeln!(f, "{} | {}", gutter, self.caret_line())
+    }
+}
+
+/// Resolves locations (or graph nodes) to source snippets
+///
+/// Each file is read once per resolver; files that can't be read are remembered as such, so
+/// create one resolver per run and reuse it for every diagnostic.
+pub struct SnippetResolver {
+    /// Lines of every file read so far (`None` when the file couldn't be read)
+    files: HashMap<String, Option<Vec<String>>>,
+    tab_width: usize,
+    /// Directory relative file paths are read from (default: the current directory)
+    base_dir: Option<PathBuf>,
+}
+
+impl SnippetResolver {
+    /// Creates a resolver with an empty file cache
+    pub fn new() -> Self {
+        Self {
+            files: HashMap::new(),
+            tab_width: DEFAULT_TAB_WIDTH,
+            base_dir: None,
+        }
+    }
+
+    /// Sets the tab stop width used to align the underline
+    pub fn with_tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width.max(1);
+        self
+    }
+
+    /// Reads relative file paths from `base_dir`, for locations rewritten relative to a
+    /// project root
+    pub fn with_base_dir(mut self, base_dir: impl Into<PathBuf>) -> Self {
+        self.base_dir = Some(base_dir.into());
+        self
+    }
+
+    /// Snippet of a location
+    ///
+    /// The column (1-based, in characters) selects the underlined code: the identifier or
+    /// string literal starting there, or a single character. Without a column the whole line
+    /// is underlined, leading indentation excluded. Returns `None` when the file can't be read
+    /// or has no such line.
+    pub fn resolve(&mut self, location: &Location) -> Option<Snippet> {
+        let tab_width = self.tab_width;
+        let base_dir = self.base_dir.as_deref();
+        let lines = self
+            .files
+            .entry(location.file.clone())
+            .or_insert_with(|| {
+                let path = match base_dir {
+                    Some(base_dir) => base_dir.join(&location.file),
+                    None => PathBuf::from(&location.file),
+                };
+                fs::read_to_string(path)
+                    .ok()
+                    .map(|source| source.lines().map(str::to_string).collect())
+            })
+            .as_ref()?;
+        let source_line = lines.get(location.line.checked_sub(1)?)?;
+        let chars: Vec<char> = source_line.chars().collect();
+
+        let (start, end) = match location.column {
+            Some(column) if column >= 1 && column <= chars.len() => {
+                let start = column - 1;
+                (start, start + token_len(&chars[start..]))
+            }
+            _ => {
+                let start = chars.iter().take_while(|c| c.is_whitespace()).count();
+                let end =
+                    chars.len() - chars.iter().rev().take_while(|c| c.is_whitespace()).count();
+                (start, end.max(start))
+            }
+        };
+
+        // Expand tabs and measure every character in terminal cells
+        let mut line = String::new();
+        let mut width = 0;
+        let (mut underline_start, mut underline_end) = (0, 0);
+        for (index, &c) in chars.iter().enumerate() {
+            if index == start {
+                underline_start = width;
+            }
+            if c == '\t' {
+                let advance = tab_width - width % tab_width;
+                line.push_str(&" ".repeat(advance));
+                width += advance;
+            } else {
+                line.push(c);
+                width += c.width().unwrap_or(0);
+            }
+            if index + 1 == end {
+                underline_end = width;
+            }
+        }
+        if start >= chars.len() {
+            underline_start = width;
+        }
+
+        Some(Snippet {
+            location: location.clone(),
+            line,
+            underline_start,
+            underline_width: underline_end.saturating_sub(underline_start).max(1),
+        })
+    }
+
+    /// Snippet of the location of a graph node (see [`node_location`])
+    pub fn resolve_node(&mut self, graph: &CallGraph, node: NodeId) -> Option<Snippet> {
+        self.resolve(&node_location(graph, node)?)
+    }
+}
+
+impl Default for SnippetResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Length in characters of the token at the start of `chars`
+fn token_len(chars: &[char]) -> usize {
+    let is_word = |c: &char| c.is_alphanumeric() || matches!(c, '_' | '$' | '.');
+    match chars.first() {
+        Some(c) if is_word(c) => chars.iter().take_while(|c| is_word(c)).count(),
+        Some(&quote @ ('"' | '\'' | '`')) => chars[1..]
+            .iter()
+            .position(|&c| c == quote)
+            .map_or(chars.len(), |closing| closing + 2),
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location(file: &std::path::Path, line: usize, column: Option<usize>) -> Location {
+        Location {
+            file: file.to_string_lossy().to_string(),
+            line,
+            column,
+        }
+    }
+
+    #[test]
+    fn test_underlines_token_at_column_with_tabs_and_wide_chars() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("api.ts");
+        fs::write(
+            &file,
+            "const a = 1;\n\tconst 名前 = fetchUser(\"/users\");\n",
+        )
+        .unwrap();
+        let mut resolver = SnippetResolver::new();
+
+        let snippet = resolver.resolve(&location(&file, 2, Some(13))).unwrap();
+        assert_eq!(snippet.line, "    const 名前 = fetchUser(\"/users\");");
+        assert_eq!(
+            snippet.caret_line(),
+            "                 ^^^^^^^^^",
+            "caret must account for the tab and the double-width name"
+        );
+
+        let snippet = resolver.resolve(&location(&file, 2, Some(23))).unwrap();
+        assert_eq!(snippet.underline_width, 8);
+
+        let snippet = resolver.resolve(&location(&file, 2, None)).unwrap();
+        assert_eq!(snippet.underline_start, 4);
+        assert_eq!(
+            snippet.to_string(),
+            format!(
+                "  --> {}:2\n  |\n2 |     const 名前 = fetchUser(\"/users\");\n  |     {}\n",
+                file.display(),
+                "^".repeat(33)
+            )
+        );
+    }
+
+    #[test]
+    fn test_files_are_read_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("main.py");
+        fs::write(&file, "x = 1\n").unwrap();
+        let mut resolver = SnippetResolver::new();
+
+        assert!(resolver.resolve(&location(&file, 1, Some(1))).is_some());
+        fs::remove_file(&file).unwrap();
+        assert!(resolver.resolve(&location(&file, 1, Some(1))).is_some());
+        assert!(resolver.resolve(&location(&file, 2, None)).is_none());
+        assert!(resolver
+            .resolve(&location(&dir.path().join("missing.py"), 1, None))
+            .is_none());
+    }
+}
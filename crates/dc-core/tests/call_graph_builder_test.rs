@@ -0,0 +1,1918 @@
+use std::fs;
+use std::path::Path;
+
+use dc_core::analyzers::DocumentedRoute;
+use dc_core::call_graph::{
+    collect_schemas, find_node_by_name, from_json, incoming_nodes, outgoing_nodes, reachable_from,
+    to_json, CallEdge, CallGraph, CallGraphBuilder, CallNode, HttpMethod, Parameter,
+    RelationshipKind, RoutePattern, GRAPH_FORMAT_VERSION, QUERY_PARAMETERS_METADATA_KEY,
+    REQUEST_MEDIA_TYPE_METADATA_KEY, REQUIRED_HEADERS_METADATA_KEY,
+    RESOLVED_FORWARD_REFS_METADATA_KEY, RESPONSE_MEDIA_TYPE_METADATA_KEY,
+    RETURNED_SCHEMAS_METADATA_KEY, STATUS_CODE_METADATA_KEY, STATUS_CONSTANT_METADATA_KEY,
+    STREAMING_RESPONSE_METADATA_KEY, UNION_ALTERNATIVES_METADATA_KEY,
+};
+use dc_core::models::{
+    BaseType, Constraint, ConstraintValue, NodeId, QueryParamType, QueryParameter, SchemaType,
+    TransformationType,
+};
+use petgraph::visit::EdgeRef;
+
+fn create_temp_project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full_path = tmp_dir.path().join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        fs::write(&full_path, content).expect("failed to write file");
+    }
+    tmp_dir
+}
+
+fn build_graph(project: &Path, entry: &str) -> CallGraph {
+    let mut builder = CallGraphBuilder::new();
+    builder
+        .build_from_entry(&project.join(entry))
+        .expect("failed to build call graph");
+    builder.into_graph()
+}
+
+fn function_parameters(graph: &CallGraph, function_name: &str) -> Vec<Parameter> {
+    graph
+        .node_weights()
+        .find_map(|node| match node {
+            CallNode::Function {
+                name, parameters, ..
+            } if name == function_name => Some(parameters.clone()),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("function {} not found in graph", function_name))
+}
+
+#[test]
+fn query_and_path_kwargs_become_parameter_constraints() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from typing import Annotated
+from fastapi import Body, FastAPI, Path, Query
+from pydantic import Field
+
+app = FastAPI()
+
+@app.get("/items/{item_id}")
+def read_item(
+    item_id: int = Path(..., ge=1, le=1000),
+    q: str = Query(..., min_length=3, max_length=50, regex="^[a-z]+$"),
+    tag: Annotated[str, Query(pattern="^t")] = "t1",
+    page: int = Query(1, gt=-1),
+    limit: Annotated[int, Field(gt=0, lt=MAX_LIMIT)] = 10,
+    ratio: float = Body(0.5, ge=0, lt=1.5),
+):
+    return {"item_id": item_id}
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let params = function_parameters(&graph, "read_item");
+    let param = |name: &str| params.iter().find(|p| p.name == name).unwrap();
+
+    let item_id = param("item_id");
+    assert!(!item_id.optional, "Path(...) must stay required");
+    assert_eq!(
+        item_id.type_info.constraints,
+        vec![
+            Constraint::Min(ConstraintValue::Integer(1)),
+            Constraint::Max(ConstraintValue::Integer(1000)),
+        ]
+    );
+
+    let q = param("q");
+    assert!(!q.optional, "Query(...) must stay required");
+    assert_eq!(
+        q.type_info.constraints,
+        vec![
+            Constraint::Min(ConstraintValue::Integer(3)),
+            Constraint::Max(ConstraintValue::Integer(50)),
+            Constraint::Pattern("^[a-z]+$".to_string()),
+        ]
+    );
+
+    let tag = param("tag");
+    assert!(tag.optional);
+    assert_eq!(
+        tag.type_info.constraints,
+        vec![Constraint::Pattern("^t".to_string())]
+    );
+
+    let page = param("page");
+    assert!(page.optional);
+    assert_eq!(
+        page.type_info.constraints,
+        vec![Constraint::ExclusiveMin(ConstraintValue::Integer(-1))]
+    );
+
+    // `lt=MAX_LIMIT` isn't a literal and is skipped
+    assert_eq!(
+        param("limit").type_info.constraints,
+        vec![Constraint::ExclusiveMin(ConstraintValue::Integer(0))]
+    );
+    assert_eq!(
+        param("ratio").type_info.constraints,
+        vec![
+            Constraint::Min(ConstraintValue::Integer(0)),
+            Constraint::ExclusiveMax(ConstraintValue::Float(1.5)),
+        ]
+    );
+}
+
+#[test]
+fn pep_604_unions_resolve_like_optional_and_record_alternatives() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class A:
+    pass
+
+class B(BaseModel):
+    name: str
+
+class C(BaseModel):
+    title: str
+
+@app.post("/items")
+def create_item(body: A | B | C, note: str | None = None, tag: None | str = None):
+    return {}
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let params = function_parameters(&graph, "create_item");
+    let param = |name: &str| params.iter().find(|p| p.name == name).unwrap();
+
+    for name in ["note", "tag"] {
+        let type_info = &param(name).type_info;
+        assert_eq!(type_info.base_type, BaseType::String, "{}", name);
+        assert!(type_info.optional, "{} must be optional", name);
+        assert!(type_info.schema_ref.is_none());
+    }
+
+    let body = &param("body").type_info;
+    assert!(!body.optional);
+    assert_eq!(body.base_type, BaseType::Object);
+    let schema = body.schema_ref.as_ref().expect("union resolves to a model");
+    assert_eq!(schema.name, "B");
+    assert_eq!(
+        schema
+            .metadata
+            .get(UNION_ALTERNATIVES_METADATA_KEY)
+            .map(String::as_str),
+        Some("A,B,C")
+    );
+}
+
+#[test]
+fn enum_parameters_resolve_to_enum_schemas() {
+    let project = create_temp_project(&[
+        (
+            "priorities.py",
+            r#"
+from enum import IntEnum
+
+class Priority(IntEnum):
+    LOW = 1
+    HIGH = 2
+"#,
+        ),
+        (
+            "main.py",
+            r#"
+from enum import Enum
+from fastapi import FastAPI
+from priorities import Priority
+
+app = FastAPI()
+
+class Color(str, Enum):
+    RED = "red"
+    GREEN = "green"
+
+@app.get("/items")
+def list_items(color: Color, priority: Priority | None = None):
+    return []
+"#,
+        ),
+    ]);
+
+    let mut builder = CallGraphBuilder::new();
+    builder
+        .build_from_files(["priorities.py", "main.py"].map(|file| project.path().join(file)))
+        .expect("failed to build call graph");
+    let graph = builder.into_graph();
+    let params = function_parameters(&graph, "list_items");
+    let param = |name: &str| params.iter().find(|p| p.name == name).unwrap();
+
+    let color = &param("color").type_info;
+    assert_eq!(color.base_type, BaseType::String);
+    assert_eq!(
+        color
+            .schema_ref
+            .as_ref()
+            .map(|s| (s.name.as_str(), s.schema_type)),
+        Some(("Color", SchemaType::Enum))
+    );
+    assert_eq!(
+        color.constraints,
+        vec![Constraint::Enum(vec![
+            "red".to_string(),
+            "green".to_string()
+        ])]
+    );
+
+    let priority = &param("priority").type_info;
+    assert!(priority.optional);
+    assert_eq!(priority.base_type, BaseType::Number);
+    assert_eq!(
+        priority.constraints,
+        vec![Constraint::Enum(vec!["1".to_string(), "2".to_string()])]
+    );
+}
+
+#[test]
+fn custom_transformation_methods_become_data_flow_edges() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class UserOut(BaseModel):
+    id: int
+
+    @classmethod
+    def from_row(cls, row):
+        return cls(id=row.id)
+
+@app.get("/users/{user_id}")
+def read_user(user_id: int):
+    user = UserOut.from_row(load(user_id))
+    payload = user.to_response()
+    path.to_string()
+    return payload
+"#,
+    )]);
+
+    let mut builder =
+        CallGraphBuilder::new().with_transformation_methods(vec!["to_response".to_string()]);
+    builder
+        .build_from_entry(&project.path().join("main.py"))
+        .expect("failed to build call graph");
+    let graph = builder.into_graph();
+
+    let mut flows: Vec<(String, String, String)> = graph
+        .edge_weights()
+        .filter_map(|edge| match edge {
+            CallEdge::DataFlow {
+                from_schema,
+                to_schema,
+                transformation: Some(TransformationType::Custom(method)),
+                ..
+            } => Some((
+                method.clone(),
+                from_schema.name.clone(),
+                to_schema.name.clone(),
+            )),
+            _ => None,
+        })
+        .collect();
+    flows.sort();
+
+    assert_eq!(
+        flows,
+        vec![
+            (
+                "from_row".to_string(),
+                "Data".to_string(),
+                "UserOut".to_string()
+            ),
+            (
+                "to_response".to_string(),
+                "user".to_string(),
+                "Data".to_string()
+            ),
+        ],
+        "unknown receivers are only recorded for configured method names"
+    );
+}
+
+#[test]
+fn unparseable_files_are_skipped_with_a_diagnostic() {
+    let project =
+        create_temp_project(&[("broken.py", "import os\n\ndef helper(:\n    return []\n")]);
+    let entry = project.path().join("broken.py");
+
+    let mut builder = CallGraphBuilder::new();
+    builder
+        .build_from_entry(&entry)
+        .expect("a file with a syntax error should be skipped, not abort the build");
+
+    let diagnostics = builder.diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].rule_id, "parse-error");
+    assert!(diagnostics[0].location.file.ends_with("broken.py"));
+    assert_eq!(diagnostics[0].location.line, 3);
+
+    // Other modules importing the file don't parse it again
+    builder.build_from_entry(&entry).unwrap();
+    assert_eq!(builder.diagnostics().len(), 1);
+
+    let mut strict = CallGraphBuilder::new().with_strict_parse(true);
+    assert!(strict.build_from_entry(&entry).is_err());
+}
+
+#[test]
+fn configured_route_objects_replace_name_heuristics() {
+    let project = create_temp_project(&[(
+        "server.py",
+        r#"
+from fastapi import FastAPI
+from cachetools import api
+
+application = FastAPI()
+
+@application.get("/items")
+def list_items():
+    return []
+
+@api.get("cache-key")
+def cached():
+    return None
+"#,
+    )]);
+    let route_paths = |graph: &CallGraph| {
+        let mut paths: Vec<String> = graph
+            .node_weights()
+            .filter_map(|node| match node {
+                CallNode::Route { path, .. } => Some(path.clone()),
+                _ => None,
+            })
+            .collect();
+        paths.sort();
+        paths
+    };
+
+    // Heuristics miss `application` and mistake `api.get` for a route
+    let heuristic = build_graph(project.path(), "server.py");
+    assert_eq!(route_paths(&heuristic), vec!["cache-key"]);
+
+    let mut builder = CallGraphBuilder::new().with_route_objects(vec!["application".to_string()]);
+    builder
+        .build_from_entry(&project.path().join("server.py"))
+        .expect("failed to build call graph");
+    assert_eq!(route_paths(&builder.into_graph()), vec!["/items"]);
+}
+
+#[test]
+fn custom_route_patterns_declare_routes() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from fastapi import FastAPI
+from myframework import Endpoints
+
+app = FastAPI()
+endpoints = Endpoints()
+
+@endpoints.fetch("/users")
+def list_users():
+    return []
+
+@endpoints.post("/users")
+def create_user():
+    return {}
+
+@app.delete("/users/{user_id}")
+def delete_user(user_id: int):
+    return None
+
+@endpoints.cache("cache-key")
+def cached():
+    return None
+"#,
+    )]);
+
+    let pattern =
+        RoutePattern::new(vec!["endpoints".to_string()]).with_method("fetch", HttpMethod::Get);
+    let mut builder = CallGraphBuilder::new().with_route_patterns(vec![pattern]);
+    builder
+        .build_from_entry(&project.path().join("main.py"))
+        .expect("failed to build call graph");
+    let mut routes: Vec<(HttpMethod, String)> = builder
+        .into_graph()
+        .node_weights()
+        .filter_map(|node| match node {
+            CallNode::Route { path, method, .. } => Some((*method, path.clone())),
+            _ => None,
+        })
+        .collect();
+    routes.sort_by(|a, b| {
+        a.1.cmp(&b.1)
+            .then(format!("{:?}", a.0).cmp(&format!("{:?}", b.0)))
+    });
+
+    assert_eq!(
+        routes,
+        vec![
+            (HttpMethod::Get, "/users".to_string()),
+            (HttpMethod::Post, "/users".to_string()),
+            (HttpMethod::Delete, "/users/{user_id}".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn flask_routes_declare_one_route_per_method() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from flask import Blueprint, Flask
+
+app = Flask(__name__)
+bp = Blueprint("users", __name__)
+
+@app.route("/health")
+def health():
+    return "ok"
+
+@bp.route("/users", methods=["GET", "POST"])
+def users():
+    return []
+
+@bp.delete("/users/<int:user_id>")
+def delete_user(user_id):
+    return None
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let mut routes: Vec<(HttpMethod, String)> = graph
+        .node_weights()
+        .filter_map(|node| match node {
+            CallNode::Route { path, method, .. } => Some((*method, path.clone())),
+            _ => None,
+        })
+        .collect();
+    routes.sort_by(|a, b| {
+        a.1.cmp(&b.1)
+            .then(format!("{:?}", a.0).cmp(&format!("{:?}", b.0)))
+    });
+
+    assert_eq!(
+        routes,
+        vec![
+            (HttpMethod::Get, "/health".to_string()),
+            (HttpMethod::Get, "/users".to_string()),
+            (HttpMethod::Post, "/users".to_string()),
+            (HttpMethod::Delete, "/users/<int:user_id>".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn route_registration_calls_declare_routes() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from starlette.applications import Starlette
+from starlette.routing import Mount, Route
+
+USERS = "/users"
+
+async def list_users(request):
+    return []
+
+async def create_user(request):
+    return {}
+
+async def health(request):
+    return "ok"
+
+async def version(request):
+    return "1"
+
+routes = [
+    Route(USERS, endpoint=list_users),
+    Mount("/api/v1", routes=[
+        Route("/users", create_user, methods=["POST", "PUT"]),
+    ]),
+]
+
+app = Starlette(routes=routes)
+app.add_route("/health", health, methods=["GET", "HEAD"])
+app.add_route("/version", route=version)
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let mut routes: Vec<(HttpMethod, String, String)> = graph
+        .node_weights()
+        .filter_map(|node| match node {
+            CallNode::Route {
+                path,
+                method,
+                handler,
+                ..
+            } => {
+                let handler = match graph.node_weight(handler.0) {
+                    Some(CallNode::Function { name, .. }) => name.clone(),
+                    other => panic!("route handler is not a function: {:?}", other),
+                };
+                Some((*method, path.clone(), handler))
+            }
+            _ => None,
+        })
+        .collect();
+    routes.sort_by(|a, b| {
+        a.1.cmp(&b.1)
+            .then(format!("{:?}", a.0).cmp(&format!("{:?}", b.0)))
+    });
+
+    let route = |method: HttpMethod, path: &str, handler: &str| {
+        (method, path.to_string(), handler.to_string())
+    };
+    assert_eq!(
+        routes,
+        vec![
+            route(HttpMethod::Post, "/api/v1/users", "create_user"),
+            route(HttpMethod::Put, "/api/v1/users", "create_user"),
+            route(HttpMethod::Get, "/health", "health"),
+            route(HttpMethod::Head, "/health", "health"),
+            route(HttpMethod::Get, "/users", "list_users"),
+            route(HttpMethod::Get, "/version", "version"),
+        ]
+    );
+}
+
+#[test]
+fn deprecated_routes_are_tagged() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from fastapi import FastAPI
+
+app = FastAPI()
+
+@app.get("/v1/users", deprecated=True)
+def list_users_v1():
+    return []
+
+@app.get("/v2/users")
+def list_users_v2():
+    return []
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let deprecated: Vec<(String, bool)> = graph
+        .node_weights()
+        .filter_map(|node| match node {
+            CallNode::Route { path, .. } => Some((path.clone(), node.is_deprecated())),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(deprecated.len(), 2);
+    for (path, is_deprecated) in deprecated {
+        assert_eq!(is_deprecated, path.contains("/v1/"), "{}", path);
+    }
+}
+
+#[test]
+fn explicit_operation_ids_are_recorded() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from fastapi import FastAPI
+
+app = FastAPI()
+
+@app.get("/users", operation_id="listUsers")
+def list_users():
+    return []
+
+@app.get("/health", include_in_schema=False, operation_id="health")
+def health():
+    return {}
+
+@app.post("/users")
+def create_user():
+    return {}
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let mut routes: Vec<(String, Option<String>)> = DocumentedRoute::in_graph(&graph)
+        .into_iter()
+        .map(|route| (route.path, route.operation_id))
+        .collect();
+    routes.sort();
+
+    assert_eq!(
+        routes,
+        vec![
+            ("/users".to_string(), None),
+            ("/users".to_string(), Some("listUsers".to_string())),
+        ]
+    );
+}
+
+#[test]
+fn required_header_parameters_are_recorded() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from typing import Annotated, Optional
+from fastapi import FastAPI, Header
+
+app = FastAPI()
+
+@app.get("/orders")
+def list_orders(
+    tenant: Annotated[str, Header(alias="X-Tenant-ID")],
+    x_api_key: str = Header(...),
+    trace_id: Optional[str] = Header(None),
+    user_agent: Annotated[str, Header()] = "unknown",
+    legacy_token: str = Header(convert_underscores=False),
+):
+    return []
+
+@app.get("/health")
+def health():
+    return {}
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let required_headers = |wanted: &str| {
+        graph
+            .node_weights()
+            .find_map(|node| match node {
+                CallNode::Route { path, metadata, .. } if path == wanted => {
+                    Some(metadata.get(REQUIRED_HEADERS_METADATA_KEY).cloned())
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("route {} not found", wanted))
+    };
+
+    assert_eq!(
+        required_headers("/orders").as_deref(),
+        Some("x-tenant-id,x-api-key,legacy_token")
+    );
+    assert_eq!(required_headers("/health"), None);
+}
+
+#[test]
+fn request_media_types_follow_body_parameters() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from typing import Annotated
+from fastapi import FastAPI, File, Form, UploadFile
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class Item(BaseModel):
+    name: str
+
+@app.post("/items")
+def create_item(item: Item):
+    return item
+
+@app.post("/login")
+def login(username: Annotated[str, Form()], password: str = Form(...)):
+    return {}
+
+@app.post("/avatar")
+def upload_avatar(user_id: int = Form(...), avatar: UploadFile = File(...)):
+    return {}
+
+@app.get("/health")
+def health():
+    return {}
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let media_type = |wanted: &str| {
+        graph
+            .node_weights()
+            .find_map(|node| match node {
+                CallNode::Route { path, metadata, .. } if path == wanted => {
+                    Some(metadata.get(REQUEST_MEDIA_TYPE_METADATA_KEY).cloned())
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("route {} not found", wanted))
+    };
+
+    assert_eq!(media_type("/items").as_deref(), Some("application/json"));
+    assert_eq!(
+        media_type("/login").as_deref(),
+        Some("application/x-www-form-urlencoded")
+    );
+    assert_eq!(
+        media_type("/avatar").as_deref(),
+        Some("multipart/form-data")
+    );
+    assert_eq!(media_type("/health"), None);
+}
+
+#[test]
+fn status_code_constants_resolve_to_their_codes() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from fastapi import FastAPI, status
+from starlette import status as http_status
+
+app = FastAPI()
+
+ACCEPTED_LATER = 299
+
+@app.post("/items", status_code=status.HTTP_201_CREATED)
+def create_item():
+    return {}
+
+@app.delete("/items/{item_id}", status_code=http_status.HTTP_204_NO_CONTENT)
+def delete_item(item_id: int):
+    return None
+
+@app.post("/jobs", status_code=202)
+def create_job():
+    return {}
+
+@app.post("/imports", status_code=ACCEPTED_LATER)
+def create_import():
+    return {}
+
+@app.get("/health")
+def health():
+    return {}
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let status = |wanted: &str| {
+        graph
+            .node_weights()
+            .find_map(|node| match node {
+                CallNode::Route { path, metadata, .. } if path == wanted => Some((
+                    metadata.get(STATUS_CODE_METADATA_KEY).cloned(),
+                    metadata.get(STATUS_CONSTANT_METADATA_KEY).cloned(),
+                )),
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("route {} not found", wanted))
+    };
+
+    assert_eq!(
+        status("/items"),
+        (
+            Some("201".to_string()),
+            Some("status.HTTP_201_CREATED".to_string())
+        )
+    );
+    assert_eq!(
+        status("/items/{item_id}"),
+        (
+            Some("204".to_string()),
+            Some("http_status.HTTP_204_NO_CONTENT".to_string())
+        )
+    );
+    assert_eq!(status("/jobs"), (Some("202".to_string()), None));
+    assert_eq!(
+        status("/imports"),
+        (None, Some("ACCEPTED_LATER".to_string()))
+    );
+    assert_eq!(status("/health"), (None, None));
+}
+
+#[test]
+fn non_json_response_classes_have_no_response_schema() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from fastapi import FastAPI
+from fastapi.responses import HTMLResponse, JSONResponse, StreamingResponse
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class User(BaseModel):
+    id: int
+
+@app.get("/profile", response_class=HTMLResponse)
+def profile() -> User:
+    return User(id=1)
+
+@app.get("/export", response_class=StreamingResponse)
+def export():
+    return StreamingResponse(iter([]))
+
+@app.get("/users/me", response_class=JSONResponse)
+def me() -> User:
+    return User(id=1)
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let route = |wanted: &str| {
+        graph
+            .node_weights()
+            .find_map(|node| match node {
+                CallNode::Route {
+                    path,
+                    response_schema,
+                    metadata,
+                    ..
+                } if path == wanted => Some((
+                    response_schema.as_ref().map(|schema| schema.name.clone()),
+                    metadata.get(RESPONSE_MEDIA_TYPE_METADATA_KEY).cloned(),
+                    metadata.contains_key(STREAMING_RESPONSE_METADATA_KEY),
+                )),
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("route {} not found", wanted))
+    };
+
+    assert_eq!(
+        route("/profile"),
+        (None, Some("text/html".to_string()), false)
+    );
+    assert_eq!(
+        route("/export"),
+        (None, Some("application/octet-stream".to_string()), true)
+    );
+    assert_eq!(route("/users/me"), (Some("User".to_string()), None, false));
+}
+
+#[test]
+fn typed_query_parameters_are_recorded_on_routes() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from typing import Annotated, Optional
+
+from fastapi import FastAPI, Header, Query
+from pydantic import StrictBool
+
+app = FastAPI()
+
+@app.get("/users/{user_id}")
+def get_user(
+    user_id: int,
+    page: int = 1,
+    ratio: Optional[float] = None,
+    active: StrictBool = False,
+    archived: Annotated[bool, Query(alias="is-archived", strict=True)] = False,
+    x_limit: int = Header(10),
+    q: str = "",
+):
+    return {}
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let parameters = graph
+        .node_weights()
+        .find_map(|node| match node {
+            CallNode::Route { metadata, .. } => metadata.get(QUERY_PARAMETERS_METADATA_KEY),
+            _ => None,
+        })
+        .expect("query parameters should be recorded");
+    let parameters: Vec<QueryParameter> = serde_json::from_str(parameters).unwrap();
+    let summary: Vec<(&str, QueryParamType, bool)> = parameters
+        .iter()
+        .map(|parameter| {
+            (
+                parameter.name.as_str(),
+                parameter.param_type,
+                parameter.strict,
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        summary,
+        vec![
+            ("page", QueryParamType::Integer, false),
+            ("ratio", QueryParamType::Number, false),
+            ("active", QueryParamType::Boolean, true),
+            ("is-archived", QueryParamType::Boolean, true),
+        ]
+    );
+}
+
+#[test]
+fn unannotated_handlers_infer_response_schemas_from_returns() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class User(BaseModel):
+    id: int
+    name: str
+
+class ErrorOut(BaseModel):
+    detail: str
+
+def load(user_id):
+    return None
+
+@app.get("/users/{user_id}")
+def get_user(user_id: int):
+    row = load(user_id)
+    if row is None:
+        return {"error": "not found"}
+    user = User(id=user_id, name="a")
+    return user.model_dump()
+
+@app.get("/users/{user_id}/profile")
+async def get_profile(user_id: int):
+    try:
+        return User(id=user_id, name="a")
+    except KeyError:
+        return ErrorOut(detail="missing").model_dump()
+
+@app.get("/health")
+def health():
+    return {"status": "ok"}
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let route = |route_path: &str| {
+        graph
+            .node_weights()
+            .find_map(|node| match node {
+                CallNode::Route {
+                    path,
+                    response_schema,
+                    metadata,
+                    ..
+                } if path.trim_matches('"') == route_path => {
+                    Some((response_schema.clone(), metadata.clone()))
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("route {} not found", route_path))
+    };
+
+    let (schema, metadata) = route("/users/{user_id}");
+    assert_eq!(schema.map(|s| s.name).as_deref(), Some("User"));
+    assert!(!metadata.contains_key(RETURNED_SCHEMAS_METADATA_KEY));
+
+    let (schema, metadata) = route("/users/{user_id}/profile");
+    assert_eq!(schema.map(|s| s.name).as_deref(), Some("User"));
+    assert_eq!(
+        metadata
+            .get(RETURNED_SCHEMAS_METADATA_KEY)
+            .map(String::as_str),
+        Some("User,ErrorOut")
+    );
+
+    let (schema, _) = route("/health");
+    assert!(schema.is_none());
+}
+
+#[test]
+fn json_response_content_infers_response_schemas() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from fastapi import FastAPI
+from fastapi.responses import JSONResponse, ORJSONResponse
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class User(BaseModel):
+    id: int
+
+class Item(BaseModel):
+    name: str
+
+@app.get("/users/{user_id}")
+def get_user(user_id: int) -> JSONResponse:
+    user = User(id=user_id)
+    return JSONResponse(content=user.model_dump(), status_code=200)
+
+@app.get("/items")
+async def get_item():
+    return ORJSONResponse(Item(name="a").dict())
+
+@app.get("/raw")
+def raw() -> JSONResponse:
+    return JSONResponse(content={"ok": True})
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let mut schemas: Vec<(String, Option<String>)> = graph
+        .node_weights()
+        .filter_map(|node| match node {
+            CallNode::Route {
+                path,
+                response_schema,
+                ..
+            } => Some((
+                path.clone(),
+                response_schema.as_ref().map(|schema| schema.name.clone()),
+            )),
+            _ => None,
+        })
+        .collect();
+    schemas.sort();
+
+    assert_eq!(
+        schemas,
+        vec![
+            ("/items".to_string(), Some("Item".to_string())),
+            ("/raw".to_string(), None),
+            ("/users/{user_id}".to_string(), Some("User".to_string())),
+        ]
+    );
+}
+
+#[test]
+fn forward_refs_resolve_against_models_of_other_modules() {
+    let project = create_temp_project(&[
+        (
+            "main.py",
+            r#"
+from fastapi import FastAPI
+from orders import Order
+
+app = FastAPI()
+
+@app.post("/orders", response_model=Order)
+def create_order(order: Order):
+    return order
+"#,
+        ),
+        (
+            "orders.py",
+            r#"
+from typing import TYPE_CHECKING
+from pydantic import BaseModel
+
+if TYPE_CHECKING:
+    from customers import Customer
+
+class Order(BaseModel):
+    id: int
+    customer: "Customer | None" = None
+    replaces: list["Order"] = []
+    note: "Memo"
+
+Order.model_rebuild()
+"#,
+        ),
+        (
+            "customers.py",
+            r#"
+from pydantic import BaseModel
+
+class Customer(BaseModel):
+    name: str
+"#,
+        ),
+    ]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let order = collect_schemas(&graph)
+        .into_iter()
+        .find(|schema| schema.name == "Order")
+        .expect("Order schema not found");
+    let resolved = order
+        .metadata
+        .get(RESOLVED_FORWARD_REFS_METADATA_KEY)
+        .expect("forward refs were not resolved");
+    let resolved: Vec<&str> = resolved.split(',').collect();
+
+    assert_eq!(resolved.len(), 2, "{:?}", resolved);
+    assert!(resolved[0].starts_with("Customer=") && resolved[0].ends_with("customers.py:4"));
+    assert!(resolved[1].starts_with("Order=") && resolved[1].ends_with("orders.py:8"));
+}
+
+#[test]
+fn models_declared_in_several_modules_resolve_through_imports() {
+    let project = create_temp_project(&[
+        (
+            "main.py",
+            r#"
+from fastapi import FastAPI
+from public import User
+
+app = FastAPI()
+
+@app.post("/users")
+def create_user(user: User):
+    return user
+"#,
+        ),
+        (
+            "public.py",
+            r#"
+from pydantic import BaseModel
+
+class User(BaseModel):
+    name: str
+"#,
+        ),
+        (
+            "admin.py",
+            r#"
+from pydantic import BaseModel
+
+class User(BaseModel):
+    name: str
+    permissions: list[str]
+"#,
+        ),
+        (
+            "legacy.py",
+            r#"
+def rename(user: User) -> None:
+    pass
+"#,
+        ),
+    ]);
+
+    let mut builder = CallGraphBuilder::new();
+    builder
+        .build_from_files(
+            ["public.py", "admin.py", "legacy.py", "main.py"].map(|file| project.path().join(file)),
+        )
+        .expect("failed to build call graph");
+
+    let diagnostics = builder.diagnostics();
+    assert_eq!(diagnostics.len(), 1, "{:?}", diagnostics);
+    assert_eq!(diagnostics[0].rule_id, "ambiguous-model");
+    assert!(diagnostics[0].location.file.ends_with("legacy.py"));
+    assert!(diagnostics[0].message.contains("admin.py"));
+
+    let graph = builder.into_graph();
+    let user = function_parameters(&graph, "create_user")[0]
+        .type_info
+        .schema_ref
+        .clone()
+        .expect("User schema not resolved");
+    assert!(user.location.file.ends_with("public.py"), "{:?}", user);
+}
+
+#[test]
+fn star_imported_models_resolve_annotations() {
+    let project = create_temp_project(&[
+        (
+            "main.py",
+            r#"
+from fastapi import FastAPI
+from schemas import *
+
+app = FastAPI()
+
+@app.get("/users/me")
+def read_me() -> User:
+    return User(name="me")
+"#,
+        ),
+        (
+            "schemas.py",
+            r#"
+from pydantic import BaseModel
+
+__all__ = ["User"]
+
+class User(BaseModel):
+    name: str
+
+class AuditEntry(BaseModel):
+    action: str
+"#,
+        ),
+    ]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let return_type = graph
+        .node_weights()
+        .find_map(|node| match node {
+            CallNode::Function {
+                name, return_type, ..
+            } if name == "read_me" => return_type.clone(),
+            _ => None,
+        })
+        .expect("read_me has no return type");
+    let schema = return_type.schema_ref.expect("User was not resolved");
+    assert_eq!(schema.name, "User");
+    assert!(schema.location.file.ends_with("schemas.py"));
+}
+
+#[test]
+fn route_paths_given_as_constants_are_resolved() {
+    let project = create_temp_project(&[
+        (
+            "main.py",
+            r#"
+from fastapi import FastAPI
+from routes import ROUTES, API_PREFIX
+
+app = FastAPI()
+HEALTH = "/health"
+
+class Admin:
+    BASE = "/admin"
+
+@app.get(HEALTH)
+def health():
+    return None
+
+@app.get(ROUTES.USERS)
+def list_users():
+    return []
+
+@app.get(f"{API_PREFIX}/users/{user_id}")
+def get_user(user_id: int):
+    return None
+
+@app.get(Admin.BASE + "/stats")
+def stats():
+    return None
+
+@app.get(UNKNOWN_PATH)
+def unknown():
+    return None
+"#,
+        ),
+        (
+            "routes.py",
+            r#"
+API_PREFIX = "/api/v1"
+
+class ROUTES:
+    USERS = API_PREFIX + "/users"
+"#,
+        ),
+    ]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let mut paths: Vec<String> = graph
+        .node_weights()
+        .filter_map(|node| match node {
+            CallNode::Route { path, .. } => Some(path.clone()),
+            _ => None,
+        })
+        .collect();
+    paths.sort();
+
+    assert_eq!(
+        paths,
+        vec![
+            "/admin/stats",
+            "/api/v1/users",
+            "/api/v1/users/{user_id}",
+            "/health",
+            "UNKNOWN_PATH",
+        ]
+    );
+}
+
+#[test]
+fn annotated_return_types_are_unwrapped() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from typing import Annotated
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class User(BaseModel):
+    id: int
+
+@app.get("/users/{user_id}")
+def get_user(user_id: int) -> Annotated[User, "cached"]:
+    return User(id=user_id)
+
+@app.get("/users")
+async def list_users() -> Annotated[list[User], "paginated"]:
+    return []
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "main.py");
+    let response_schema = |route_path: &str| {
+        graph
+            .node_weights()
+            .find_map(|node| match node {
+                CallNode::Route {
+                    path,
+                    response_schema,
+                    ..
+                } if path == route_path => Some(response_schema.clone().map(|s| s.name)),
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("route {} not found", route_path))
+    };
+
+    assert_eq!(response_schema("/users/{user_id}").as_deref(), Some("User"));
+    assert_eq!(response_schema("/users").as_deref(), Some("User"));
+
+    let list_users_return = graph
+        .node_weights()
+        .find_map(|node| match node {
+            CallNode::Function {
+                name, return_type, ..
+            } if name == "list_users" => return_type.clone(),
+            _ => None,
+        })
+        .expect("list_users has a return type");
+    assert_eq!(list_users_return.base_type, BaseType::Array);
+}
+
+/// Nodes and edges (with their endpoints) in graph order, maps sorted by key
+fn serialized_graph(graph: &CallGraph) -> String {
+    let edges: Vec<_> = graph
+        .edge_indices()
+        .map(|edge| (graph.edge_endpoints(edge), &graph[edge]))
+        .map(|(endpoints, weight)| {
+            let (from, to) = endpoints.expect("edge has endpoints");
+            (from.index(), to.index(), weight)
+        })
+        .collect();
+    let nodes: Vec<&CallNode> = graph.node_weights().collect();
+    serde_json::to_value((nodes, edges))
+        .expect("graph serializes")
+        .to_string()
+}
+
+#[test]
+fn parallel_parsing_builds_the_same_graph_whatever_the_thread_count() {
+    let mut files = vec![(
+        "models.py".to_string(),
+        r#"
+from pydantic import BaseModel
+
+class Item(BaseModel):
+    id: int
+    name: str
+"#
+        .to_string(),
+    )];
+    for i in 0..8 {
+        files.push((
+            format!("routers/module_{i}.py"),
+            format!(
+                r#"
+from fastapi import APIRouter
+from models import Item
+
+router = APIRouter()
+
+def load_{i}(item_id: int) -> Item:
+    return Item(id=item_id, name="{i}")
+
+@router.get("/items_{i}/{{item_id}}", response_model=Item)
+def read_{i}(item_id: int) -> Item:
+    return load_{i}(item_id)
+"#
+            ),
+        ));
+    }
+    let project = create_temp_project(
+        &files
+            .iter()
+            .map(|(path, source)| (path.as_str(), source.as_str()))
+            .collect::<Vec<_>>(),
+    );
+    let paths: Vec<_> = (0..8)
+        .rev()
+        .map(|i| project.path().join(format!("routers/module_{i}.py")))
+        .collect();
+
+    let build = |threads: usize| {
+        let mut builder = CallGraphBuilder::new()
+            .with_project_root(project.path().to_path_buf())
+            .with_threads(threads);
+        builder
+            .build_from_files(&paths)
+            .expect("failed to build call graph");
+        assert_eq!(builder.parse_count(), 9);
+        serialized_graph(builder.graph())
+    };
+
+    let sequential = build(1);
+    assert_eq!(build(4), sequential);
+    assert_eq!(build(4), sequential);
+}
+
+#[test]
+fn streamed_files_are_parsed_once() {
+    let project = create_temp_project(&[
+        (
+            "main.py",
+            r#"
+from fastapi import FastAPI
+from models import Item
+
+app = FastAPI()
+
+@app.post("/items", response_model=Item)
+def create_item(item: Item) -> Item:
+    return item
+"#,
+        ),
+        (
+            "models.py",
+            r#"
+from pydantic import BaseModel
+
+class ItemBase(BaseModel):
+    name: str
+
+class Item(ItemBase):
+    id: int
+"#,
+        ),
+        (
+            "admin.py",
+            r#"
+from fastapi import APIRouter
+from models import Item
+
+router = APIRouter()
+
+@router.get("/admin/items", response_model=list[Item])
+def list_items():
+    return []
+"#,
+        ),
+    ]);
+
+    let mut builder = CallGraphBuilder::new();
+    builder
+        .build_from_files(["main.py", "admin.py", "main.py"].map(|file| project.path().join(file)))
+        .expect("failed to build call graph");
+
+    assert_eq!(builder.parse_count(), 3);
+    let routes = builder
+        .graph()
+        .node_weights()
+        .filter(|node| matches!(node, CallNode::Route { .. }))
+        .count();
+    assert_eq!(routes, 2);
+}
+
+#[test]
+fn unparseable_streamed_files_are_parsed_once() {
+    let project = create_temp_project(&[
+        ("broken.py", "import os\n\ndef helper(:\n    return []\n"),
+        ("helpers.py", "from broken import helper\n"),
+        ("main.py", "import helpers\n"),
+    ]);
+
+    let mut builder = CallGraphBuilder::new().with_threads(2);
+    builder
+        .build_from_files(
+            ["broken.py", "main.py", "helpers.py"].map(|file| project.path().join(file)),
+        )
+        .expect("failed to build call graph");
+
+    assert_eq!(builder.parse_count(), 3);
+    let diagnostics = builder.diagnostics();
+    assert_eq!(diagnostics.len(), 1, "{:?}", diagnostics);
+    assert_eq!(diagnostics[0].rule_id, "parse-error");
+    assert_eq!(diagnostics[0].location.line, 3);
+}
+
+#[test]
+fn single_file_is_analyzed_without_its_imports() {
+    let project = create_temp_project(&[
+        (
+            "main.py",
+            r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+from models import Item
+import admin
+
+app = FastAPI()
+
+class ItemQuery(BaseModel):
+    name: str
+
+@app.post("/items", response_model=Item)
+def create_item(query: ItemQuery) -> Item:
+    return admin.store(query)
+"#,
+        ),
+        (
+            "models.py",
+            r#"
+from pydantic import BaseModel
+
+class Item(BaseModel):
+    id: int
+"#,
+        ),
+        ("admin.py", "def store(query):\n    return query\n"),
+    ]);
+
+    let mut builder = CallGraphBuilder::new();
+    let analysis = builder
+        .analyze_file(&project.path().join("main.py"))
+        .expect("failed to analyze file");
+
+    assert_eq!(builder.parse_count(), 1);
+    assert_eq!(analysis.routes.len(), 1);
+    let route = &analysis.routes[0];
+    assert_eq!(
+        (route.method, route.path.as_str()),
+        (HttpMethod::Post, "/items")
+    );
+    assert_eq!(route.handler, "create_item");
+    assert_eq!(
+        route
+            .request_schema
+            .as_ref()
+            .map(|schema| schema.name.as_str()),
+        Some("ItemQuery")
+    );
+    let schemas: Vec<&str> = analysis
+        .schemas
+        .iter()
+        .map(|schema| schema.name.as_str())
+        .collect();
+    assert_eq!(schemas, vec!["ItemQuery"]);
+    assert_eq!(
+        analysis.unresolved_types.iter().collect::<Vec<_>>(),
+        vec!["Item"]
+    );
+    assert!(analysis
+        .calls
+        .iter()
+        .any(|call| call.name == "admin.store" && call.caller.as_deref() == Some("create_item")));
+}
+
+#[test]
+fn background_tasks_become_call_edges() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from fastapi import BackgroundTasks, FastAPI
+
+app = FastAPI()
+
+def send_email(to: str, subject: str):
+    pass
+
+def audit(action: str):
+    pass
+
+def reindex():
+    pass
+
+@app.post("/users")
+def create_user(email: str, background_tasks: BackgroundTasks, notify: bool = True):
+    if notify:
+        background_tasks.add_task(send_email, email, subject="Welcome")
+    background_tasks.add_task(audit, "create_user")
+    return {"email": email}
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "main.py");
+
+    let function_name = |id: &dc_core::models::NodeId| match graph.node_weight(**id) {
+        Some(CallNode::Function { name, .. }) => Some(name.clone()),
+        _ => None,
+    };
+    let mut tasks: Vec<(String, String, String)> = graph
+        .edge_weights()
+        .filter_map(|edge| match edge {
+            CallEdge::Call {
+                caller,
+                callee,
+                argument_mapping,
+                ..
+            } => {
+                let mut arguments: Vec<String> = argument_mapping
+                    .iter()
+                    .map(|(name, value)| format!("{}={}", name, value))
+                    .collect();
+                arguments.sort();
+                Some((
+                    function_name(caller)?,
+                    function_name(callee)?,
+                    arguments.join(", "),
+                ))
+            }
+            _ => None,
+        })
+        .collect();
+    tasks.sort();
+
+    // Conditionally added tasks are reachable as well; `reindex` is never scheduled
+    assert_eq!(
+        tasks,
+        vec![
+            (
+                "create_user".to_string(),
+                "audit".to_string(),
+                "arg0=create_user".to_string()
+            ),
+            (
+                "create_user".to_string(),
+                "send_email".to_string(),
+                "arg0=email, subject=Welcome".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn type_aliases_resolve_to_their_underlying_types() {
+    let project = create_temp_project(&[
+        (
+            "main.py",
+            r#"
+from typing import NewType, TypeAlias
+from fastapi import FastAPI
+from pydantic import BaseModel
+from ids import OrderId
+
+app = FastAPI()
+
+class Item(BaseModel):
+    name: str
+
+UserId = NewType("UserId", int)
+AdminId = NewType("AdminId", UserId)
+Tags: TypeAlias = list[str]
+type Draft = Item
+
+@app.post("/users/{user_id}/items")
+def create_item(user_id: AdminId, order_id: OrderId, tags: Tags, item: Draft):
+    return item
+"#,
+        ),
+        (
+            "ids.py",
+            r#"
+from typing import NewType
+
+OrderId = NewType("OrderId", str)
+"#,
+        ),
+    ]);
+
+    let graph = build_graph(project.path(), "main.py");
+
+    let parameters = function_parameters(&graph, "create_item");
+    let base_type = |name: &str| {
+        parameters
+            .iter()
+            .find(|parameter| parameter.name == name)
+            .unwrap()
+            .type_info
+            .base_type
+    };
+    // Aliases of aliases are followed
+    assert_eq!(base_type("user_id"), BaseType::Number);
+    // Imported from another module
+    assert_eq!(base_type("order_id"), BaseType::String);
+    assert_eq!(base_type("tags"), BaseType::Array);
+    let item = parameters.iter().find(|p| p.name == "item").unwrap();
+    assert_eq!(
+        item.type_info
+            .schema_ref
+            .as_ref()
+            .map(|schema| schema.name.as_str()),
+        Some("Item")
+    );
+}
+
+#[test]
+fn transformation_self_loops_are_not_traversed() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class Item(BaseModel):
+    name: str
+
+def save(item):
+    pass
+
+@app.post("/items")
+def create_item(payload: dict):
+    item = Item.model_validate(payload)
+    save(item)
+    return item.model_dump()
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "main.py");
+
+    let handler = find_node_by_name(&graph, "create_item").unwrap();
+    let save = find_node_by_name(&graph, "save").unwrap();
+    let self_loops = graph
+        .edge_weights()
+        .filter(|edge| edge.is_transformation_self_loop())
+        .count();
+    assert_eq!(self_loops, 2, "both transformations are recorded");
+
+    assert_eq!(outgoing_nodes(&graph, handler), vec![save]);
+    assert!(!incoming_nodes(&graph, handler).contains(&handler));
+    assert_eq!(reachable_from(&graph, handler), vec![save]);
+}
+
+#[test]
+fn files_are_skipped_once_the_deadline_has_passed() {
+    let project = create_temp_project(&[(
+        "main.py",
+        "from fastapi import FastAPI\n\napp = FastAPI()\n\ndef health():\n    return {}\n",
+    )]);
+
+    let mut builder = CallGraphBuilder::new().with_deadline(Some(std::time::Instant::now()));
+    builder
+        .build_from_entry(&project.path().join("main.py"))
+        .expect("a timed out build is not an error");
+    assert!(builder.timed_out());
+    assert_eq!(builder.into_graph().node_count(), 0);
+
+    let mut builder = CallGraphBuilder::new().with_deadline(Some(
+        std::time::Instant::now() + std::time::Duration::from_secs(600),
+    ));
+    builder
+        .build_from_entry(&project.path().join("main.py"))
+        .expect("failed to build call graph");
+    assert!(!builder.timed_out());
+    assert!(find_node_by_name(&builder.into_graph(), "health").is_some());
+}
+
+#[test]
+fn orm_relationships_link_model_classes() {
+    let project = create_temp_project(&[(
+        "models.py",
+        r#"from sqlalchemy import ForeignKey
+from sqlalchemy.orm import DeclarativeBase, Mapped, mapped_column, relationship
+
+
+class Base(DeclarativeBase):
+    pass
+
+
+class User(Base):
+    __tablename__ = "users"
+    id: Mapped[int] = mapped_column(primary_key=True)
+    orders: Mapped[list["Order"]] = relationship(back_populates="user")
+
+
+class Order(Base):
+    __tablename__ = "orders"
+    id: Mapped[int] = mapped_column(primary_key=True)
+    user_id: Mapped[int] = mapped_column(ForeignKey("users.id"))
+    user = relationship("User", back_populates="orders")
+"#,
+    )]);
+
+    let graph = build_graph(project.path(), "models.py");
+
+    let class_name = |id: &NodeId| match graph.node_weight(**id) {
+        Some(CallNode::Class { name, .. }) => name.clone(),
+        _ => String::new(),
+    };
+    let mut relationships: Vec<(String, String, String, RelationshipKind)> = graph
+        .edge_weights()
+        .filter_map(|edge| match edge {
+            CallEdge::Relationship {
+                from,
+                to,
+                field,
+                kind,
+            } => Some((class_name(from), class_name(to), field.clone(), *kind)),
+            _ => None,
+        })
+        .collect();
+    relationships.sort_by(|a, b| a.2.cmp(&b.2));
+    assert_eq!(
+        relationships,
+        vec![
+            (
+                "User".to_string(),
+                "Order".to_string(),
+                "orders".to_string(),
+                RelationshipKind::OneToMany
+            ),
+            (
+                "Order".to_string(),
+                "User".to_string(),
+                "user".to_string(),
+                RelationshipKind::ManyToOne
+            ),
+        ]
+    );
+}
+
+#[test]
+fn graphs_round_trip_through_json_with_stable_node_ids() {
+    let project = create_temp_project(&[(
+        "main.py",
+        r#"from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+
+class Item(BaseModel):
+    name: str
+
+
+class Repository:
+    def save(self, item: Item) -> Item:
+        return item
+
+
+def store(item: Item) -> Item:
+    return Repository().save(item)
+
+
+@app.post("/items")
+def create_item(item: Item) -> Item:
+    return store(item)
+"#,
+    )]);
+    let graph = build_graph(project.path(), "main.py");
+
+    let json = to_json(&graph).expect("failed to serialize graph");
+    let restored = from_json(&json).expect("failed to read serialized graph");
+
+    assert_eq!(restored.node_count(), graph.node_count());
+    assert_eq!(restored.edge_count(), graph.edge_count());
+    for idx in graph.node_indices() {
+        assert_eq!(
+            serde_json::to_value(&restored[idx]).unwrap(),
+            serde_json::to_value(&graph[idx]).unwrap()
+        );
+    }
+    for edge in graph.edge_references() {
+        let (source, target) = (NodeId::from(edge.source()), NodeId::from(edge.target()));
+        assert!(restored
+            .edges_connecting(*source, *target)
+            .any(|restored_edge| {
+                serde_json::to_value(restored_edge.weight()).unwrap()
+                    == serde_json::to_value(edge.weight()).unwrap()
+            }));
+    }
+    let handler = restored
+        .node_weights()
+        .find_map(|node| match node {
+            CallNode::Route { handler, .. } => Some(*handler),
+            _ => None,
+        })
+        .expect("route survives the round-trip");
+    assert!(matches!(
+        &restored[*handler],
+        CallNode::Function { name, .. } if name == "create_item"
+    ));
+    assert_eq!(
+        find_node_by_name(&restored, "store"),
+        find_node_by_name(&graph, "store")
+    );
+
+    let future = json.replacen(
+        &format!("\"version\":{}", GRAPH_FORMAT_VERSION),
+        "\"version\":99",
+        1,
+    );
+    assert!(from_json(&future).is_err());
+}
@@ -0,0 +1,152 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use dc_core::call_graph::{merge_graphs, CallEdge, CallGraph, CallNode, HttpMethod};
+use dc_core::models::{Location, NodeId};
+
+fn module(graph: &mut CallGraph, path: PathBuf) -> NodeId {
+    NodeId::from(graph.add_node(CallNode::Module { path }))
+}
+
+fn class(graph: &mut CallGraph, name: &str, file: &Path) -> NodeId {
+    NodeId::from(graph.add_node(CallNode::Class {
+        name: name.to_string(),
+        file: file.to_path_buf(),
+        methods: Vec::new(),
+    }))
+}
+
+fn method(graph: &mut CallGraph, name: &str, owner: NodeId) -> NodeId {
+    let id = NodeId::from(graph.add_node(CallNode::Method {
+        name: name.to_string(),
+        class: owner,
+        parameters: Vec::new(),
+        return_type: None,
+    }));
+    if let CallNode::Class { methods, .. } = &mut graph[*owner] {
+        methods.push(id);
+    }
+    id
+}
+
+fn import(graph: &mut CallGraph, from: NodeId, to: NodeId) {
+    graph.add_edge(
+        *from,
+        *to,
+        CallEdge::Import {
+            from,
+            to,
+            import_path: "models".to_string(),
+            file: PathBuf::from("main.py"),
+        },
+    );
+}
+
+fn count(graph: &CallGraph, predicate: impl Fn(&CallNode) -> bool) -> usize {
+    graph.node_weights().filter(|node| predicate(node)).count()
+}
+
+#[test]
+fn modules_and_classes_are_merged_once() {
+    let project = tempfile::tempdir().expect("failed to create temp dir");
+    let models = project.path().join("models.py");
+    fs::write(&models, "class UserService: ...\n").unwrap();
+    let main = project.path().join("main.py");
+    fs::write(&main, "").unwrap();
+
+    let mut graph = CallGraph::new();
+    let main_module = module(&mut graph, main.clone());
+    let models_module = module(&mut graph, models.clone());
+    let service = class(&mut graph, "UserService", &models);
+    let get = method(&mut graph, "get", service);
+    import(&mut graph, main_module, models_module);
+
+    // The other graph spells the same module differently and knows another method
+    let mut other = CallGraph::new();
+    let other_main = module(&mut other, main.clone());
+    let other_models = module(&mut other, project.path().join(".").join("models.py"));
+    let other_service = class(&mut other, "UserService", &models);
+    let other_create = method(&mut other, "create", other_service);
+    let admin = module(&mut other, project.path().join("admin.py"));
+    import(&mut other, other_main, other_models);
+    import(&mut other, admin, other_models);
+
+    let mapping = merge_graphs(&mut graph, &other);
+
+    assert_eq!(mapping[&other_main], main_module);
+    assert_eq!(mapping[&other_models], models_module);
+    assert_eq!(mapping[&other_service], service);
+    assert_eq!(
+        count(&graph, |node| matches!(node, CallNode::Module { .. })),
+        3
+    );
+    assert_eq!(
+        count(&graph, |node| matches!(node, CallNode::Class { .. })),
+        1
+    );
+
+    let create = mapping[&other_create];
+    match &graph[*service] {
+        CallNode::Class { methods, .. } => assert_eq!(methods, &vec![get, create]),
+        node => panic!("expected a class, got {:?}", node),
+    }
+    match &graph[*create] {
+        CallNode::Method { class, .. } => assert_eq!(*class, service),
+        node => panic!("expected a method, got {:?}", node),
+    }
+
+    // main -> models was already there; admin -> models is new
+    assert_eq!(graph.edge_count(), 2);
+    let admin_import = graph
+        .edges_connecting(*mapping[&admin], *models_module)
+        .next()
+        .expect("admin import should be merged");
+    match admin_import.weight() {
+        CallEdge::Import { from, to, .. } => {
+            assert_eq!((*from, *to), (mapping[&admin], models_module))
+        }
+        edge => panic!("expected an import, got {:?}", edge),
+    }
+}
+
+#[test]
+fn other_nodes_are_added_with_their_references_remapped() {
+    let mut graph = CallGraph::new();
+    module(&mut graph, PathBuf::from("app/main.py"));
+
+    let mut other = CallGraph::new();
+    let handler = NodeId::from(other.add_node(CallNode::Function {
+        name: "list_users".to_string(),
+        file: PathBuf::from("app/users.py"),
+        line: 4,
+        parameters: Vec::new(),
+        return_type: None,
+    }));
+    let route = NodeId::from(other.add_node(CallNode::Route {
+        path: "/users".to_string(),
+        method: HttpMethod::Get,
+        handler,
+        location: Location {
+            file: "app/users.py".to_string(),
+            line: 3,
+            column: None,
+        },
+        request_schema: None,
+        response_schema: None,
+        metadata: Default::default(),
+    }));
+
+    let mapping = merge_graphs(&mut graph, &other);
+
+    assert_eq!(graph.node_count(), 3);
+    match &graph[*mapping[&route]] {
+        CallNode::Route {
+            handler: merged, ..
+        } => assert_eq!(*merged, mapping[&handler]),
+        node => panic!("expected a route, got {:?}", node),
+    }
+    assert!(matches!(
+        &graph[*mapping[&handler]],
+        CallNode::Function { name, .. } if name == "list_users"
+    ));
+}
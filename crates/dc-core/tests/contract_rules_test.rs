@@ -0,0 +1,774 @@
+use std::collections::HashMap;
+
+use dc_core::analyzers::ContractChecker;
+use dc_core::models::{
+    Contract, Location, MismatchType, RuleInfo, SchemaReference, SchemaType, SeverityLevel,
+    TypeCompatibility,
+};
+
+fn schema(name: &str, schema_type: SchemaType, metadata: &[(&str, &str)]) -> SchemaReference {
+    SchemaReference {
+        name: name.to_string(),
+        schema_type,
+        location: Location {
+            file: "schemas".to_string(),
+            line: 1,
+            column: None,
+        },
+        metadata: metadata
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<HashMap<_, _>>(),
+    }
+}
+
+fn item_openapi_schema() -> SchemaReference {
+    schema(
+        "Item",
+        SchemaType::OpenAPI,
+        &[(
+            "json_schema",
+            r#"{
+                "type": "object",
+                "properties": {
+                    "id": {"type": "string", "readOnly": true},
+                    "title": {"type": "string"}
+                },
+                "required": ["id", "title"]
+            }"#,
+        )],
+    )
+}
+
+#[test]
+fn omitted_response_only_field_is_not_reported_missing() {
+    let request = schema(
+        "ItemForm",
+        SchemaType::TypeScript,
+        &[("fields", "title:string")],
+    );
+
+    let mismatches = ContractChecker::new().compare_schemas(&request, &item_openapi_schema());
+
+    assert!(
+        mismatches.is_empty(),
+        "response-only `id` must not be required from the client, got: {:?}",
+        mismatches
+    );
+}
+
+#[test]
+fn sending_response_only_field_is_reported() {
+    let request = schema(
+        "ItemForm",
+        SchemaType::TypeScript,
+        &[("fields", "id:string,title:string")],
+    );
+
+    let mismatches = ContractChecker::new().compare_schemas(&request, &item_openapi_schema());
+
+    assert_eq!(mismatches.len(), 1, "got: {:?}", mismatches);
+    assert_eq!(mismatches[0].mismatch_type, MismatchType::ResponseOnlyField);
+    assert_eq!(mismatches[0].path, "id");
+    assert_eq!(mismatches[0].rule_id(), "response_only_field");
+}
+
+fn owner_pydantic_schema() -> SchemaReference {
+    let source = r#"
+from typing import Literal, Union
+from pydantic import BaseModel, Field
+
+class Cat(BaseModel):
+    pet_type: Literal["cat"]
+
+class Dog(BaseModel):
+    pet_type: Literal["dog"]
+
+class Owner(BaseModel):
+    pet: Union[Cat, Dog] = Field(discriminator="pet_type")
+"#;
+    let ast = rustpython_parser::parse(source, rustpython_parser::Mode::Module, "models.py")
+        .expect("failed to parse source");
+    let converter = dc_core::parsers::LocationConverter::new(source.to_string());
+    dc_core::parsers::PythonParser::new()
+        .extract_pydantic_models(&ast, "models.py", &converter)
+        .into_iter()
+        .find(|model| model.name == "Owner")
+        .expect("Owner model")
+}
+
+#[test]
+fn discriminated_union_members_are_compared_by_tag() {
+    let request = schema(
+        "OwnerForm",
+        SchemaType::TypeScript,
+        &[
+            ("fields", "pet:Pet:required"),
+            (
+                "discriminated_unions",
+                r#"{"pet": {"discriminator": "pet_type", "members": [
+                    {"schema": "Cat", "tag": "cat"},
+                    {"schema": "Bird", "tag": "bird"}
+                ]}}"#,
+            ),
+        ],
+    );
+
+    let mismatches = ContractChecker::new().compare_schemas(&request, &owner_pydantic_schema());
+    let union_messages: Vec<_> = mismatches
+        .iter()
+        .filter(|m| m.message.starts_with("Union member"))
+        .collect();
+
+    assert_eq!(union_messages.len(), 2, "got: {:?}", mismatches);
+    assert!(union_messages
+        .iter()
+        .any(|m| m.mismatch_type == MismatchType::TypeMismatch && m.message.contains("'Bird'")));
+    assert!(union_messages
+        .iter()
+        .any(|m| m.mismatch_type == MismatchType::MissingField && m.message.contains("'Dog'")));
+}
+
+#[test]
+fn discriminated_union_with_different_tag_field_is_reported() {
+    let request = schema(
+        "OwnerForm",
+        SchemaType::TypeScript,
+        &[
+            ("fields", "pet:Pet:required"),
+            (
+                "discriminated_unions",
+                r#"{"pet": {"discriminator": "kind", "members": [
+                    {"schema": "Cat", "tag": "cat"},
+                    {"schema": "Dog", "tag": "dog"}
+                ]}}"#,
+            ),
+        ],
+    );
+
+    let mismatches = ContractChecker::new().compare_schemas(&request, &owner_pydantic_schema());
+    let union_mismatches: Vec<_> = mismatches
+        .iter()
+        .filter(|m| m.message.contains("discriminated by"))
+        .collect();
+
+    assert_eq!(union_mismatches.len(), 1, "got: {:?}", mismatches);
+    assert_eq!(union_mismatches[0].path, "pet");
+    assert_eq!(
+        union_mismatches[0].mismatch_type,
+        MismatchType::TypeMismatch
+    );
+}
+
+#[test]
+fn zod_union_matches_aliased_pydantic_tag_by_value() {
+    let source = r#"
+from typing import Literal, Union
+from pydantic import BaseModel, Field
+
+class Cat(BaseModel):
+    pet_type: Literal["cat"] = Field(alias="petType")
+
+class Dog(BaseModel):
+    pet_type: Literal["dog"] = Field(alias="petType")
+
+class Owner(BaseModel):
+    pet: Union[Cat, Dog] = Field(discriminator="pet_type")
+"#;
+    let ast = rustpython_parser::parse(source, rustpython_parser::Mode::Module, "models.py")
+        .expect("failed to parse source");
+    let converter = dc_core::parsers::LocationConverter::new(source.to_string());
+    let owner = dc_core::parsers::PythonParser::new()
+        .extract_pydantic_models(&ast, "models.py", &converter)
+        .into_iter()
+        .find(|model| model.name == "Owner")
+        .expect("Owner model");
+
+    // Members are named differently on the frontend; only the tags have to agree
+    let request = schema(
+        "OwnerSchema",
+        SchemaType::Zod,
+        &[
+            (
+                "fields",
+                r#"[{"name": "pet", "type_name": "unknown", "optional": false, "nullable": false}]"#,
+            ),
+            (
+                "discriminated_unions",
+                r#"{"pet": {"discriminator": "petType", "members": [
+                    {"schema": "CatSchema", "tag": "cat"},
+                    {"schema": "BirdSchema", "tag": "bird"}
+                ]}}"#,
+            ),
+        ],
+    );
+
+    let mismatches = ContractChecker::new().compare_schemas(&request, &owner);
+    let union_messages: Vec<_> = mismatches
+        .iter()
+        .filter(|m| m.message.starts_with("Union") || m.message.contains("discriminated by"))
+        .collect();
+
+    assert_eq!(union_messages.len(), 2, "got: {:?}", mismatches);
+    assert!(union_messages.iter().any(|m| {
+        m.mismatch_type == MismatchType::TypeMismatch
+            && m.message.contains("'BirdSchema' (petType = \"bird\")")
+    }));
+    assert!(union_messages
+        .iter()
+        .any(|m| { m.mismatch_type == MismatchType::MissingField && m.message.contains("'Dog'") }));
+}
+
+fn order_pydantic_schema() -> SchemaReference {
+    let source = r#"
+from pydantic import BaseModel, conlist
+
+class Order(BaseModel):
+    items: conlist(str, min_length=1)
+    tags: list[str]
+"#;
+    let ast = rustpython_parser::parse(source, rustpython_parser::Mode::Module, "models.py")
+        .expect("failed to parse source");
+    let converter = dc_core::parsers::LocationConverter::new(source.to_string());
+    dc_core::parsers::PythonParser::new()
+        .extract_pydantic_models(&ast, "models.py", &converter)
+        .into_iter()
+        .find(|model| model.name == "Order")
+        .expect("Order model")
+}
+
+#[test]
+fn array_length_bounds_are_compared_with_zod() {
+    let request = schema(
+        "OrderForm",
+        SchemaType::Zod,
+        &[(
+            "fields",
+            r#"[
+                {"name": "items", "type_name": "array", "optional": false, "nullable": false},
+                {"name": "tags", "type_name": "array", "optional": false, "nullable": false,
+                 "min_items": 1, "max_items": 5}
+            ]"#,
+        )],
+    );
+
+    let mismatches = ContractChecker::new().compare_schemas(&request, &order_pydantic_schema());
+    let array_mismatches: Vec<_> = mismatches
+        .iter()
+        .filter(|m| m.mismatch_type == MismatchType::ValidationMismatch)
+        .collect();
+
+    assert_eq!(array_mismatches.len(), 2, "got: {:?}", mismatches);
+    // Unbounded form vs `conlist(min_length=1)`: empty lists get rejected by the backend
+    assert_eq!(array_mismatches[0].path, "items");
+    assert_eq!(array_mismatches[0].severity_level, SeverityLevel::High);
+    assert_eq!(
+        array_mismatches[0].message,
+        "Array field 'items' allows any number of items in 'OrderForm' but at least 1 item(s) in 'Order'"
+    );
+    // A stricter form is only a divergence
+    assert_eq!(array_mismatches[1].path, "tags");
+    assert_eq!(array_mismatches[1].severity_level, SeverityLevel::Low);
+    assert_eq!(array_mismatches[1].rule_id(), "validation_mismatch");
+}
+
+#[test]
+fn array_length_bounds_are_read_from_openapi_items_keywords() {
+    let response = schema(
+        "Page",
+        SchemaType::OpenAPI,
+        &[(
+            "json_schema",
+            r#"{
+                "type": "object",
+                "properties": {
+                    "results": {"type": "array", "items": {"type": "string"}, "maxItems": 50}
+                },
+                "required": ["results"]
+            }"#,
+        )],
+    );
+    let client = schema(
+        "PageView",
+        SchemaType::OpenAPI,
+        &[(
+            "json_schema",
+            r#"{
+                "type": "object",
+                "properties": {
+                    "results": {"type": "array", "items": {"type": "string"}, "maxItems": 50}
+                },
+                "required": ["results"]
+            }"#,
+        )],
+    );
+
+    let mismatches = ContractChecker::new().compare_schemas(&response, &client);
+    assert!(mismatches.is_empty(), "got: {:?}", mismatches);
+
+    let unbounded = schema(
+        "PageView",
+        SchemaType::TypeScript,
+        &[("fields", "results:array:required")],
+    );
+    let mismatches = ContractChecker::new().compare_schemas(&unbounded, &response);
+    assert_eq!(mismatches.len(), 1, "got: {:?}", mismatches);
+    assert_eq!(mismatches[0].path, "results");
+    assert_eq!(mismatches[0].severity_level, SeverityLevel::High);
+}
+
+#[test]
+fn custom_type_equivalences_suppress_type_mismatches() {
+    let request = schema(
+        "OrderForm",
+        SchemaType::TypeScript,
+        &[("fields", "total:string:required")],
+    );
+    let order = schema(
+        "Order",
+        SchemaType::OpenAPI,
+        &[(
+            "json_schema",
+            r#"{"type": "object", "properties": {"total": {"type": "Money"}}, "required": ["total"]}"#,
+        )],
+    );
+    let is_type_mismatch = |m: &dc_core::models::Mismatch| {
+        m.mismatch_type == MismatchType::TypeMismatch && m.path == "total"
+    };
+
+    let default_mismatches = ContractChecker::new().compare_schemas(&request, &order);
+    assert!(default_mismatches.iter().any(is_type_mismatch));
+
+    let compatibility = TypeCompatibility::new().with_equivalence("Money", "string");
+    let mismatches =
+        ContractChecker::with_type_compatibility(compatibility).compare_schemas(&request, &order);
+    assert!(
+        !mismatches.iter().any(is_type_mismatch),
+        "Money ↔ string is declared equivalent, got: {:?}",
+        mismatches
+    );
+}
+
+fn user_pydantic_schemas() -> HashMap<String, SchemaReference> {
+    let source = r#"
+from pydantic import BaseModel, ConfigDict, Extra
+
+class StrictUser(BaseModel):
+    model_config = ConfigDict(extra="forbid")
+    name: str
+
+class LegacyUser(BaseModel):
+    name: str
+
+    class Config:
+        extra = Extra.ignore
+
+class LooseUser(BaseModel):
+    name: str
+"#;
+    let ast = rustpython_parser::parse(source, rustpython_parser::Mode::Module, "models.py")
+        .expect("failed to parse source");
+    let converter = dc_core::parsers::LocationConverter::new(source.to_string());
+    dc_core::parsers::PythonParser::new()
+        .extract_pydantic_models(&ast, "models.py", &converter)
+        .into_iter()
+        .map(|model| (model.name.clone(), model))
+        .collect()
+}
+
+#[test]
+fn unknown_fields_are_reported_by_extra_setting() {
+    let models = user_pydantic_schemas();
+    let request = schema(
+        "UserForm",
+        SchemaType::TypeScript,
+        &[("fields", "name:string,nickname:string")],
+    );
+    let checker = ContractChecker::new();
+
+    let forbidden = checker.compare_schemas(&request, &models["StrictUser"]);
+    assert_eq!(forbidden.len(), 1, "got: {:?}", forbidden);
+    assert_eq!(forbidden[0].mismatch_type, MismatchType::ExtraField);
+    assert_eq!(forbidden[0].path, "nickname");
+    assert_eq!(forbidden[0].severity_level, SeverityLevel::High);
+    assert_eq!(
+        forbidden[0].message,
+        "Field 'nickname' is not declared in 'StrictUser' (extra=\"forbid\"): requests with it \
+         are rejected"
+    );
+
+    let ignored = checker.compare_schemas(&request, &models["LegacyUser"]);
+    assert_eq!(ignored.len(), 1, "got: {:?}", ignored);
+    assert_eq!(ignored[0].severity_level, SeverityLevel::Low);
+
+    // Without an explicit `extra` setting the model isn't checked
+    assert!(checker
+        .compare_schemas(&request, &models["LooseUser"])
+        .is_empty());
+}
+
+#[test]
+fn differing_defaults_are_reported() {
+    let source = r#"
+from pydantic import BaseModel, Field
+
+class Pagination(BaseModel):
+    limit: int = Field(default=20, ge=1)
+    page: int = 1
+    query: str | None = None
+"#;
+    let ast = rustpython_parser::parse(source, rustpython_parser::Mode::Module, "models.py")
+        .expect("failed to parse source");
+    let converter = dc_core::parsers::LocationConverter::new(source.to_string());
+    let pagination = dc_core::parsers::PythonParser::new()
+        .extract_pydantic_models(&ast, "models.py", &converter)
+        .into_iter()
+        .find(|model| model.name == "Pagination")
+        .expect("Pagination model");
+    let request = schema(
+        "PaginationSchema",
+        SchemaType::Zod,
+        &[(
+            "fields",
+            r#"[
+                {"name": "limit", "type_name": "number", "optional": true, "nullable": false,
+                 "default_value": 10},
+                {"name": "page", "type_name": "number", "optional": true, "nullable": false,
+                 "default_value": 1.0},
+                {"name": "query", "type_name": "string", "optional": true, "nullable": false}
+            ]"#,
+        )],
+    );
+
+    let mismatches = ContractChecker::new().compare_schemas(&request, &pagination);
+    let defaults: Vec<_> = mismatches
+        .iter()
+        .filter(|m| m.mismatch_type == MismatchType::DefaultMismatch)
+        .collect();
+
+    assert_eq!(defaults.len(), 1, "got: {:?}", mismatches);
+    assert_eq!(defaults[0].path, "limit");
+    assert_eq!(defaults[0].severity_level, SeverityLevel::Medium);
+    assert_eq!(
+        defaults[0].message,
+        "Default of field 'limit' differs: 10 in 'PaginationSchema', 20 in 'Pagination'"
+    );
+}
+
+#[test]
+fn python_names_of_aliased_fields_are_reported() {
+    let source = r#"
+from pydantic import BaseModel, ConfigDict, Field
+
+class UserIn(BaseModel):
+    model_config = ConfigDict(extra="forbid")
+    full_name: str = Field(alias="fullName")
+    birth_date: str = Field(validation_alias="birthDate", serialization_alias="dob")
+
+class UserOut(BaseModel):
+    birth_date: str = Field(validation_alias="birthDate", serialization_alias="dob")
+"#;
+    let ast = rustpython_parser::parse(source, rustpython_parser::Mode::Module, "models.py")
+        .expect("failed to parse source");
+    let converter = dc_core::parsers::LocationConverter::new(source.to_string());
+    let models: HashMap<String, SchemaReference> = dc_core::parsers::PythonParser::new()
+        .extract_pydantic_models(&ast, "models.py", &converter)
+        .into_iter()
+        .map(|model| (model.name.clone(), model))
+        .collect();
+    let checker = ContractChecker::new();
+
+    // Requests go by the validation name: `birthDate` is read, `full_name` is not
+    let request = schema(
+        "UserForm",
+        SchemaType::TypeScript,
+        &[("fields", "full_name:string,birthDate:string")],
+    );
+    let mismatches = checker.compare_schemas(&request, &models["UserIn"]);
+    assert_eq!(mismatches.len(), 1, "got: {:?}", mismatches);
+    assert_eq!(mismatches[0].mismatch_type, MismatchType::ExtraField);
+    assert_eq!(mismatches[0].path, "full_name");
+    assert_eq!(
+        mismatches[0].message,
+        "Field 'full_name' is sent under its Python name; 'UserIn' reads it as 'fullName'"
+    );
+
+    let aliased = schema(
+        "UserForm",
+        SchemaType::TypeScript,
+        &[("fields", "fullName:string,birthDate:string")],
+    );
+    assert!(checker
+        .compare_schemas(&aliased, &models["UserIn"])
+        .is_empty());
+
+    // Responses go by the serialization name
+    let response = schema(
+        "User",
+        SchemaType::TypeScript,
+        &[("fields", "birth_date:string")],
+    );
+    let mismatches = checker.compare_schemas(&models["UserOut"], &response);
+    assert_eq!(mismatches.len(), 1, "got: {:?}", mismatches);
+    assert_eq!(mismatches[0].mismatch_type, MismatchType::MissingField);
+    assert_eq!(
+        mismatches[0].message,
+        "Field 'birth_date' is read under its Python name; 'UserOut' sends it as 'dob'"
+    );
+}
+
+#[test]
+fn models_populated_by_name_accept_python_names() {
+    let source = r#"
+from pydantic import BaseModel, ConfigDict, Field
+
+class Base(BaseModel):
+    model_config = ConfigDict(populate_by_name=True, extra="forbid")
+
+class UserIn(Base):
+    full_name: str = Field(alias="fullName")
+    birth_date: str = Field(validation_alias="birthDate")
+
+class LegacyUserIn(BaseModel):
+    full_name: str = Field(alias="fullName")
+
+    class Config:
+        allow_population_by_field_name = True
+"#;
+    let ast = rustpython_parser::parse(source, rustpython_parser::Mode::Module, "models.py")
+        .expect("failed to parse source");
+    let converter = dc_core::parsers::LocationConverter::new(source.to_string());
+    let models: HashMap<String, SchemaReference> = dc_core::parsers::PythonParser::new()
+        .extract_pydantic_models(&ast, "models.py", &converter)
+        .into_iter()
+        .map(|model| (model.name.clone(), model))
+        .collect();
+    let checker = ContractChecker::new();
+
+    // Either name is read, the setting being inherited from the base model
+    for fields in [
+        "full_name:string,birthDate:string",
+        "fullName:string,birth_date:string",
+    ] {
+        let request = schema("UserForm", SchemaType::TypeScript, &[("fields", fields)]);
+        let mismatches = checker.compare_schemas(&request, &models["UserIn"]);
+        assert!(mismatches.is_empty(), "{}: {:?}", fields, mismatches);
+    }
+
+    let legacy = schema(
+        "UserForm",
+        SchemaType::TypeScript,
+        &[("fields", "full_name:string")],
+    );
+    assert!(checker
+        .compare_schemas(&legacy, &models["LegacyUserIn"])
+        .is_empty());
+
+    // Fields the model doesn't declare under either name are still extra
+    let extra = schema(
+        "UserForm",
+        SchemaType::TypeScript,
+        &[(
+            "fields",
+            "full_name:string,birth_date:string,nickname:string",
+        )],
+    );
+    let mismatches = checker.compare_schemas(&extra, &models["UserIn"]);
+    assert_eq!(mismatches.len(), 1, "got: {:?}", mismatches);
+    assert_eq!(mismatches[0].mismatch_type, MismatchType::ExtraField);
+    assert_eq!(mismatches[0].path, "nickname");
+}
+
+#[test]
+fn pre_validated_models_downgrade_field_mismatches() {
+    let source = r#"
+from pydantic import BaseModel, ConfigDict, model_validator, root_validator
+
+class WrappedUser(BaseModel):
+    model_config = ConfigDict(extra="forbid")
+    name: str
+    email: str
+
+    @model_validator(mode="before")
+    @classmethod
+    def unwrap(cls, data):
+        return data["user"]
+
+class LegacyWrappedUser(BaseModel):
+    name: str
+
+    @root_validator(pre=True)
+    def unwrap(cls, values):
+        return values["user"]
+
+class CheckedUser(BaseModel):
+    name: str
+
+    @model_validator(mode="after")
+    def check(self):
+        return self
+"#;
+    let ast = rustpython_parser::parse(source, rustpython_parser::Mode::Module, "models.py")
+        .expect("failed to parse source");
+    let converter = dc_core::parsers::LocationConverter::new(source.to_string());
+    let models: HashMap<String, SchemaReference> = dc_core::parsers::PythonParser::new()
+        .extract_pydantic_models(&ast, "models.py", &converter)
+        .into_iter()
+        .map(|model| (model.name.clone(), model))
+        .collect();
+    let flagged = |name: &str| {
+        models[name]
+            .metadata
+            .get(dc_core::parsers::PRE_VALIDATOR_METADATA_KEY)
+            .cloned()
+    };
+    assert_eq!(flagged("WrappedUser").as_deref(), Some("true"));
+    assert_eq!(flagged("LegacyWrappedUser").as_deref(), Some("true"));
+    assert_eq!(flagged("CheckedUser"), None);
+
+    let request = schema(
+        "UserForm",
+        SchemaType::TypeScript,
+        &[("fields", "name:string,email:string,user:object")],
+    );
+    let mismatches = ContractChecker::new().compare_schemas(&request, &models["WrappedUser"]);
+    // `extra="forbid"` alone would make the unknown field high severity
+    assert_eq!(mismatches.len(), 1, "got: {:?}", mismatches);
+    assert_eq!(mismatches[0].mismatch_type, MismatchType::ExtraField);
+    assert_eq!(mismatches[0].path, "user");
+    assert_eq!(mismatches[0].severity_level, SeverityLevel::Low);
+    assert!(mismatches[0]
+        .message
+        .ends_with("('WrappedUser' transforms its input before validation)"));
+}
+
+#[test]
+fn array_of_enum_facets_are_reported_separately() {
+    let source = r#"
+from typing import Literal, Optional
+from pydantic import BaseModel
+
+class Filter(BaseModel):
+    statuses: list[Literal["active", "archived"]] | None = None
+    tags: list[Literal["a", "b"]] = []
+    kinds: Optional[list[str]] = None
+"#;
+    let ast = rustpython_parser::parse(source, rustpython_parser::Mode::Module, "models.py")
+        .expect("failed to parse source");
+    let converter = dc_core::parsers::LocationConverter::new(source.to_string());
+    let filter = dc_core::parsers::PythonParser::new()
+        .extract_pydantic_models(&ast, "models.py", &converter)
+        .into_iter()
+        .find(|model| model.name == "Filter")
+        .expect("Filter model");
+    let form = schema(
+        "FilterForm",
+        SchemaType::Zod,
+        &[(
+            "fields",
+            r#"[
+                {"name": "statuses", "type_name": "array", "optional": true, "nullable": true,
+                 "item_values": ["active", "deleted"]},
+                {"name": "tags", "type_name": "array", "optional": true, "nullable": true,
+                 "item_values": ["a"]},
+                {"name": "kinds", "type_name": "enum", "optional": true, "nullable": false}
+            ]"#,
+        )],
+    );
+
+    let mismatches = ContractChecker::new().compare_schemas(&form, &filter);
+    let messages: Vec<(&str, &str)> = mismatches
+        .iter()
+        .map(|m| (m.path.as_str(), m.message.as_str()))
+        .collect();
+    assert_eq!(mismatches.len(), 3, "got: {:?}", messages);
+    // Element values: `null` is fine, `deleted` is not
+    assert!(messages.contains(&(
+        "statuses",
+        "Items of array field 'statuses' may be 'deleted' in 'FilterForm', which 'Filter' doesn't accept (accepted: 'active', 'archived')"
+    )));
+    // Nullability: `[]` is the default, but `null` is rejected
+    assert!(messages.contains(&(
+        "tags",
+        "Array field 'tags' may be null in 'FilterForm' but 'Filter' only accepts an array (send [] instead of null)"
+    )));
+    // Array-ness is a type mismatch
+    assert!(mismatches
+        .iter()
+        .any(|m| m.path == "kinds" && m.mismatch_type == MismatchType::TypeMismatch));
+}
+
+#[test]
+fn nullable_arrays_are_read_from_json_schema_and_typescript() {
+    let response = schema(
+        "Filter",
+        SchemaType::OpenAPI,
+        &[(
+            "json_schema",
+            r#"{
+                "type": "object",
+                "properties": {
+                    "statuses": {
+                        "anyOf": [
+                            {"type": "array", "items": {"type": "string", "enum": ["active", "archived"]}},
+                            {"type": "null"}
+                        ],
+                        "default": null
+                    }
+                },
+                "required": ["statuses"]
+            }"#,
+        )],
+    );
+
+    let nullable_view = schema(
+        "FilterView",
+        SchemaType::TypeScript,
+        &[("fields", "statuses:Status[] | null")],
+    );
+    let mismatches = ContractChecker::new().compare_schemas(&response, &nullable_view);
+    assert!(mismatches.is_empty(), "got: {:?}", mismatches);
+
+    let view = schema(
+        "FilterView",
+        SchemaType::TypeScript,
+        &[("fields", "statuses:Status[]")],
+    );
+    let mismatches = ContractChecker::new().compare_schemas(&response, &view);
+    assert_eq!(mismatches.len(), 1, "got: {:?}", mismatches);
+    assert_eq!(mismatches[0].rule_id(), "validation_mismatch");
+    assert_eq!(
+        mismatches[0].message,
+        "Array field 'statuses' may be null in 'Filter' but 'FilterView' only accepts an array (send [] instead of null)"
+    );
+}
+
+#[test]
+fn type_mismatch_registry_severity_matches_checked_contracts() {
+    let request = schema(
+        "OrderForm",
+        SchemaType::TypeScript,
+        &[("fields", "total:string:required")],
+    );
+    let order = schema(
+        "Order",
+        SchemaType::TypeScript,
+        &[("fields", "total:number:required")],
+    );
+
+    let mismatches = ContractChecker::new().compare_schemas(&request, &order);
+    assert!(
+        mismatches
+            .iter()
+            .all(|m| m.mismatch_type == MismatchType::TypeMismatch),
+        "got: {:?}",
+        mismatches
+    );
+    assert!(!mismatches.is_empty());
+
+    // `check` assigns contract severities with `Contract::severity_of`
+    assert_eq!(
+        Contract::severity_of(&mismatches),
+        RuleInfo::for_mismatch(MismatchType::TypeMismatch).default_severity
+    );
+}
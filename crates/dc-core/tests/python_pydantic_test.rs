@@ -0,0 +1,579 @@
+use dc_core::models::{FieldConstraint, PydanticFieldInfo, SchemaReference, SchemaType};
+use dc_core::parsers::{LocationConverter, PythonParser, ENUM_VARIANTS_METADATA_KEY};
+use rustpython_parser::{parse, Mode};
+
+fn extract_models(source: &str) -> Vec<SchemaReference> {
+    let ast = parse(source, Mode::Module, "models.py").expect("failed to parse source");
+    let converter = LocationConverter::new(source.to_string());
+    PythonParser::new().extract_pydantic_models(&ast, "models.py", &converter)
+}
+
+fn model_fields(models: &[SchemaReference], model_name: &str) -> Vec<PydanticFieldInfo> {
+    let model = models
+        .iter()
+        .find(|m| m.name == model_name)
+        .unwrap_or_else(|| panic!("model {} not found", model_name));
+    serde_json::from_str(
+        model
+            .metadata
+            .get("fields")
+            .expect("fields metadata missing"),
+    )
+    .expect("fields metadata should be JSON")
+}
+
+#[test]
+fn field_defaults_make_fields_not_required_without_making_them_optional() {
+    let models = extract_models(
+        r#"
+from typing import Optional
+from pydantic import BaseModel, Field
+
+class Item(BaseModel):
+    name: str
+    tags: list[str] = Field(default_factory=list)
+    count: int = Field(default=0, ge=0)
+    label: str = Field("x", max_length=10)
+    code: str = Field(..., min_length=2)
+    note: str = "n/a"
+    parent: Optional[str]
+    summary: str | None = None
+"#,
+    );
+    let fields = model_fields(&models, "Item");
+    let field = |name: &str| fields.iter().find(|f| f.name == name).unwrap();
+
+    assert!(field("name").is_required());
+    assert_eq!(field("name").default_value, None);
+
+    // A default lets clients omit the field, but the annotation does not accept None
+    for (name, default) in [
+        ("tags", "list()"),
+        ("count", "0"),
+        ("label", "x"),
+        ("note", "n/a"),
+    ] {
+        assert!(!field(name).optional, "{} is not Optional", name);
+        assert!(!field(name).is_required(), "{} has a default", name);
+        assert_eq!(field(name).default_value.as_deref(), Some(default));
+    }
+
+    assert!(field("code").is_required(), "Field(...) must stay required");
+    assert_eq!(field("code").default_value, None);
+
+    assert!(field("parent").optional);
+    assert!(field("summary").optional);
+    assert!(!field("summary").is_required());
+}
+
+#[test]
+fn frozen_and_read_only_split_fields_are_response_only() {
+    let models = extract_models(
+        r#"
+from datetime import datetime
+from pydantic import BaseModel, Field
+
+class ItemCreate(BaseModel):
+    title: str
+
+class ItemRead(BaseModel):
+    id: int
+    title: str
+    created_at: datetime
+
+class Audit(BaseModel):
+    ref: str = Field(..., frozen=True)
+    note: str
+"#,
+    );
+
+    let read = model_fields(&models, "ItemRead");
+    let read_field = |name: &str| read.iter().find(|f| f.name == name).unwrap();
+    assert!(read_field("id").response_only);
+    assert!(read_field("created_at").response_only);
+    assert!(!read_field("title").response_only);
+
+    assert!(model_fields(&models, "ItemCreate")
+        .iter()
+        .all(|f| !f.response_only));
+
+    let audit = model_fields(&models, "Audit");
+    assert!(
+        audit
+            .iter()
+            .find(|f| f.name == "ref")
+            .unwrap()
+            .response_only
+    );
+    assert!(
+        !audit
+            .iter()
+            .find(|f| f.name == "note")
+            .unwrap()
+            .response_only
+    );
+}
+
+#[test]
+fn read_only_split_pairs_models_of_one_family_only() {
+    let models = extract_models(
+        r#"
+from pydantic import BaseModel
+
+class UserIn(BaseModel):
+    name: str
+
+class UserOut(BaseModel):
+    id: int
+    name: str
+
+class SignIn(BaseModel):
+    email: str
+
+class SignOut(BaseModel):
+    email: str
+    session_id: str
+
+class LogIn(BaseModel):
+    username: str
+
+class LogOut(BaseModel):
+    username: str
+    token: str
+
+class OrderIn(BaseModel):
+    total: float
+
+class OrderRead(BaseModel):
+    id: int
+    total: float
+
+class Create(BaseModel):
+    name: str
+
+class Read(BaseModel):
+    id: int
+    name: str
+"#,
+    );
+    let response_only = |model: &str| -> Vec<String> {
+        model_fields(&models, model)
+            .into_iter()
+            .filter(|f| f.response_only)
+            .map(|f| f.name)
+            .collect()
+    };
+
+    assert_eq!(response_only("UserOut"), ["id"]);
+    // Actions, not the two halves of a model
+    assert!(response_only("SignOut").is_empty());
+    assert!(response_only("LogOut").is_empty());
+    // `In` pairs with `Out` only, and a bare suffix has no stem to pair by
+    assert!(response_only("OrderRead").is_empty());
+    assert!(response_only("Read").is_empty());
+}
+
+#[test]
+fn discriminated_union_members_and_nested_unions_are_resolved() {
+    let models = extract_models(
+        r#"
+from typing import Annotated, Literal, Union
+from pydantic import BaseModel, Field
+
+class BlackCat(BaseModel):
+    pet_type: Literal["cat"]
+    color: Literal["black"]
+
+class WhiteCat(BaseModel):
+    pet_type: Literal["cat"]
+    color: Literal["white"]
+
+class Dog(BaseModel):
+    pet_type: Literal["dog"]
+
+Cat = Annotated[Union[BlackCat, WhiteCat], Field(discriminator="color")]
+
+class Owner(BaseModel):
+    pet: Union[Cat, Dog] = Field(discriminator="pet_type")
+    other: Annotated[BlackCat | Dog, Field(discriminator="pet_type")]
+"#,
+    );
+    let fields = model_fields(&models, "Owner");
+
+    let pet = fields.iter().find(|f| f.name == "pet").unwrap();
+    let union = pet
+        .discriminated_union
+        .as_ref()
+        .expect("pet is a tagged union");
+    assert_eq!(union.discriminator, "pet_type");
+    let tags: Vec<_> = union.members.iter().map(|m| m.tag.as_deref()).collect();
+    assert_eq!(tags, vec![Some("cat"), Some("dog")]);
+
+    let nested = union.members[0]
+        .nested
+        .as_ref()
+        .expect("Cat is a nested union");
+    assert_eq!(nested.discriminator, "color");
+    let nested_tags: Vec<_> = nested.members.iter().map(|m| m.tag.as_deref()).collect();
+    assert_eq!(nested_tags, vec![Some("black"), Some("white")]);
+
+    let other = fields.iter().find(|f| f.name == "other").unwrap();
+    let union = other.discriminated_union.as_ref().expect("Annotated union");
+    let schemas: Vec<_> = union.members.iter().map(|m| m.schema.as_str()).collect();
+    assert_eq!(schemas, vec!["BlackCat", "Dog"]);
+    assert!(union.members.iter().all(|m| m.tag.is_some()));
+}
+
+#[test]
+fn computed_fields_are_response_only_output_fields() {
+    let models = extract_models(
+        r#"
+from functools import cached_property
+from typing import Optional
+from pydantic import BaseModel, computed_field
+
+class User(BaseModel):
+    first_name: str
+    last_name: str
+
+    @computed_field
+    @property
+    def full_name(self) -> str:
+        return f"{self.first_name} {self.last_name}"
+
+    @computed_field(return_type=list[str])
+    @cached_property
+    def initials(self):
+        return [self.first_name[0], self.last_name[0]]
+
+    @computed_field
+    @property
+    def nickname(self) -> Optional[str]:
+        return None
+
+    @property
+    def not_serialized(self) -> int:
+        return 1
+
+    def helper(self) -> str:
+        return ""
+"#,
+    );
+    let fields = model_fields(&models, "User");
+    let field = |name: &str| fields.iter().find(|f| f.name == name);
+
+    let full_name = field("full_name").expect("computed property should be a field");
+    assert_eq!(full_name.type_name, "str");
+    assert!(full_name.response_only);
+    assert!(!full_name.optional);
+
+    let initials = field("initials").expect("cached computed property should be a field");
+    assert_eq!(initials.type_name, "array");
+    assert_eq!(initials.inner_type.as_deref(), Some("str"));
+    assert!(initials.response_only);
+
+    assert!(field("nickname").unwrap().optional);
+    assert!(field("not_serialized").is_none());
+    assert!(field("helper").is_none());
+    assert!(!field("first_name").unwrap().response_only);
+}
+
+#[test]
+fn conlist_and_list_field_bounds_become_length_constraints() {
+    let models = extract_models(
+        r#"
+from pydantic import BaseModel, Field, conlist
+
+class Order(BaseModel):
+    items: conlist(str, min_length=1, max_length=10)
+    legacy: conlist(int, min_items=2)
+    tags: list[str] = Field(..., max_items=5)
+    notes: list[str]
+"#,
+    );
+    let fields = model_fields(&models, "Order");
+    let field = |name: &str| fields.iter().find(|f| f.name == name).unwrap();
+
+    assert_eq!(field("items").type_name, "array");
+    assert_eq!(field("items").inner_type.as_deref(), Some("str"));
+    assert_eq!(
+        field("items").constraints,
+        vec![
+            FieldConstraint::MinLength(1),
+            FieldConstraint::MaxLength(10)
+        ]
+    );
+    assert_eq!(
+        field("legacy").constraints,
+        vec![FieldConstraint::MinLength(2)]
+    );
+    assert_eq!(
+        field("tags").constraints,
+        vec![FieldConstraint::MaxLength(5)]
+    );
+    assert!(field("notes").constraints.is_empty());
+}
+
+#[test]
+fn constraint_helpers_and_annotated_metadata_become_constraints() {
+    let models = extract_models(
+        r#"
+from typing import Annotated
+from pydantic import BaseModel, Field, StringConstraints, confloat, conint, conlist, constr
+
+class Signup(BaseModel):
+    username: Annotated[str, StringConstraints(min_length=3, max_length=20, pattern="^[a-z]+$")]
+    age: Annotated[int, Field(ge=18, le=130)]
+    code: constr(min_length=2, regex="^[A-Z]+$")
+    count: conint(ge=0, le=10)
+    ratio: confloat(gt=0, le=1.5)
+    tags: Annotated[conlist(str, max_length=5), "tags"]
+    score: float = Field(gt=-1, lt=100)
+    slug: str = Field(max_length=MAX_SLUG, pattern=SLUG_PATTERN)
+"#,
+    );
+    let fields = model_fields(&models, "Signup");
+    let field = |name: &str| fields.iter().find(|f| f.name == name).unwrap();
+
+    assert_eq!(field("username").type_name, "str");
+    assert_eq!(
+        field("username").constraints,
+        vec![
+            FieldConstraint::MinLength(3),
+            FieldConstraint::MaxLength(20),
+            FieldConstraint::Pattern("^[a-z]+$".to_string()),
+        ]
+    );
+    assert_eq!(field("age").type_name, "int");
+    assert_eq!(
+        field("age").constraints,
+        vec![
+            FieldConstraint::MinValue(18.0),
+            FieldConstraint::MaxValue(130.0)
+        ]
+    );
+    assert_eq!(field("code").type_name, "str");
+    assert_eq!(
+        field("code").constraints,
+        vec![
+            FieldConstraint::MinLength(2),
+            FieldConstraint::Pattern("^[A-Z]+$".to_string()),
+        ]
+    );
+    assert_eq!(field("count").type_name, "int");
+    assert_eq!(
+        field("count").constraints,
+        vec![
+            FieldConstraint::MinValue(0.0),
+            FieldConstraint::MaxValue(10.0)
+        ]
+    );
+    assert_eq!(field("ratio").type_name, "float");
+    assert_eq!(
+        field("ratio").constraints,
+        vec![
+            FieldConstraint::ExclusiveMinValue(0.0),
+            FieldConstraint::MaxValue(1.5)
+        ]
+    );
+    assert_eq!(
+        field("score").constraints,
+        vec![
+            FieldConstraint::ExclusiveMinValue(-1.0),
+            FieldConstraint::ExclusiveMaxValue(100.0)
+        ]
+    );
+    // Constants aren't resolved: their constraints are skipped
+    assert!(field("slug").constraints.is_empty());
+    assert_eq!(field("tags").type_name, "array");
+    assert_eq!(field("tags").inner_type.as_deref(), Some("str"));
+    assert_eq!(
+        field("tags").constraints,
+        vec![FieldConstraint::MaxLength(5)]
+    );
+}
+
+#[test]
+fn field_deprecated_flag_is_extracted() {
+    let models = extract_models(
+        r#"
+from pydantic import BaseModel, Field
+
+class User(BaseModel):
+    name: str = Field(..., deprecated=True)
+    nickname: str = Field("", deprecated="Use name instead")
+    email: str = Field(..., deprecated=False)
+"#,
+    );
+    let fields = model_fields(&models, "User");
+    let deprecated = |name: &str| fields.iter().find(|f| f.name == name).unwrap().deprecated;
+
+    assert!(deprecated("name"));
+    assert!(deprecated("nickname"));
+    assert!(!deprecated("email"));
+}
+
+#[test]
+fn string_annotations_are_parsed_and_recorded_as_forward_refs() {
+    let models = extract_models(
+        r#"
+from typing import Literal, Optional
+from pydantic import BaseModel
+
+class Node(BaseModel):
+    kind: Literal["Leaf"] = "Leaf"
+    parent: "Node | None" = None
+    children: list["Node"] = []
+    owner: "Optional[users.User]"
+    tags: "list[str]"
+"#,
+    );
+    let fields = model_fields(&models, "Node");
+    let field = |name: &str| fields.iter().find(|f| f.name == name).unwrap();
+
+    assert_eq!(field("parent").type_name, "Node");
+    assert!(field("parent").optional);
+    assert_eq!(field("children").type_name, "array");
+    assert_eq!(field("children").inner_type.as_deref(), Some("Node"));
+    assert_eq!(field("owner").type_name, "users.User");
+    assert!(field("owner").optional);
+    assert_eq!(field("tags").inner_type.as_deref(), Some("str"));
+
+    let node = models.iter().find(|m| m.name == "Node").unwrap();
+    assert_eq!(
+        node.metadata.get("forward_refs").map(String::as_str),
+        Some("Node,users.User")
+    );
+}
+
+#[test]
+fn field_examples_are_extracted_as_literals() {
+    let models = extract_models(
+        r#"
+from pydantic import BaseModel, Field
+
+class Item(BaseModel):
+    quantity: int = Field(examples=[3, -1, "three"])
+    price: float = Field(example=9.5)
+    tags: list[str] = Field(default_factory=list, examples=[["a", "b"], {"k": None}])
+    owner: str = Field(examples=[make_owner()])
+    name: str
+"#,
+    );
+    let fields = model_fields(&models, "Item");
+    let examples = |name: &str| {
+        fields
+            .iter()
+            .find(|f| f.name == name)
+            .unwrap()
+            .examples
+            .clone()
+    };
+
+    assert_eq!(
+        examples("quantity"),
+        [
+            serde_json::json!(3),
+            serde_json::json!(-1),
+            serde_json::json!("three")
+        ]
+    );
+    assert_eq!(examples("price"), [serde_json::json!(9.5)]);
+    assert_eq!(
+        examples("tags"),
+        [
+            serde_json::json!(["a", "b"]),
+            serde_json::json!({"k": null})
+        ]
+    );
+    // Non-literal examples are skipped
+    assert!(examples("owner").is_empty());
+    assert!(examples("name").is_empty());
+}
+
+#[test]
+fn enum_classes_become_enum_schemas_with_variants() {
+    let source = r#"
+import enum
+from enum import Enum, IntEnum, auto
+from pydantic import BaseModel
+
+class Color(str, Enum):
+    RED = "red"
+    GREEN = "green"
+    _ignore_ = ["shade"]
+
+    def label(self):
+        return self.value.title()
+
+class Priority(IntEnum):
+    LOW = 1
+    HIGH = auto()
+
+class Shape(enum.Enum):
+    CIRCLE = "circle"
+
+class Item(BaseModel):
+    color: Color
+"#;
+    let ast = parse(source, Mode::Module, "models.py").expect("failed to parse source");
+    let converter = LocationConverter::new(source.to_string());
+    let enums = PythonParser::new().extract_enums(&ast, "models.py", &converter);
+
+    let variants = |name: &str| -> serde_json::Value {
+        let schema = enums
+            .iter()
+            .find(|schema| schema.name == name)
+            .unwrap_or_else(|| panic!("enum {} not found", name));
+        assert_eq!(schema.schema_type, SchemaType::Enum);
+        serde_json::from_str(&schema.metadata[ENUM_VARIANTS_METADATA_KEY]).unwrap()
+    };
+    assert_eq!(enums.len(), 3);
+    assert_eq!(
+        variants("Color"),
+        serde_json::json!([
+            {"name": "RED", "value": "red"},
+            {"name": "GREEN", "value": "green"}
+        ])
+    );
+    assert_eq!(
+        variants("Priority"),
+        serde_json::json!([
+            {"name": "LOW", "value": 1},
+            {"name": "HIGH", "value": null}
+        ])
+    );
+    assert_eq!(
+        variants("Shape"),
+        serde_json::json!([{"name": "CIRCLE", "value": "circle"}])
+    );
+    // Enums aren't models
+    assert!(extract_models(source)
+        .iter()
+        .all(|model| model.name == "Item"));
+}
+
+#[test]
+fn aliases_are_read_from_annotated_metadata_and_alias_choices() {
+    let models = extract_models(
+        r#"
+from typing import Annotated
+from pydantic import AliasChoices, AliasPath, BaseModel, Field
+
+class Profile(BaseModel):
+    user_name: Annotated[str, Field(alias="userName", max_length=20)]
+    birth_date: str = Field(validation_alias=AliasChoices("birthDate", "dob"))
+    city: str = Field(validation_alias=AliasPath("address", "city"))
+"#,
+    );
+    let fields = model_fields(&models, "Profile");
+    let field = |name: &str| fields.iter().find(|f| f.name == name).unwrap();
+
+    assert_eq!(field("user_name").alias.as_deref(), Some("userName"));
+    assert_eq!(field("user_name").input_name(), "userName");
+    assert_eq!(field("user_name").output_name(), "userName");
+    assert_eq!(field("birth_date").input_name(), "birthDate");
+    assert_eq!(field("birth_date").output_name(), "birth_date");
+    // Nested paths have no single wire name
+    assert_eq!(field("city").validation_alias, None);
+}
@@ -78,6 +78,11 @@ fn test_build_zod_to_pydantic_chain_via_openapi() {
         type_name: "string".to_string(),
         optional: false,
         nullable: false,
+        schema_ref: None,
+        min_items: None,
+        max_items: None,
+        default_value: None,
+        item_values: None,
     }];
     let zod_fields_json =
         serde_json::to_string(&zod_fields).expect("Failed to serialize Zod fields");
@@ -144,6 +149,7 @@ fn test_build_zod_to_pydantic_chain_via_openapi() {
         location: api_location,
         request_schema: None,
         response_schema: None,
+        metadata: std::collections::HashMap::new(),
     });
 
     // Pydantic model that should be resolved from OpenAPI "ItemRequest"
@@ -154,6 +160,16 @@ fn test_build_zod_to_pydantic_chain_via_openapi() {
         optional: false,
         constraints: Vec::new(),
         default_value: None,
+        response_only: false,
+        deprecated: false,
+        discriminated_union: None,
+        alias: None,
+        validation_alias: None,
+        serialization_alias: None,
+        examples: Vec::new(),
+        default_literal: None,
+        nullable: false,
+        item_values: None,
     }];
     let pydantic_fields_json =
         serde_json::to_string(&pydantic_fields).expect("Failed to serialize Pydantic fields");
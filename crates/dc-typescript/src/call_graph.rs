@@ -1,11 +1,16 @@
 use crate::path_resolver;
 use anyhow::{Context, Result};
-use dc_core::call_graph::{CallEdge, CallGraph, CallNode, HttpMethod};
+use dc_core::call_graph::{
+    CallEdge, CallGraph, CallNode, HttpMethod, SENT_HEADERS_METADATA_KEY,
+    SENT_MEDIA_TYPE_METADATA_KEY, SENT_QUERY_METADATA_KEY,
+};
+use dc_core::discovery::find_source_files;
 use dc_core::models::{Location, NodeId};
-use dc_core::openapi::{OpenAPILinker, OpenAPIParser, OpenAPISchema};
-use dc_core::parsers::{Call, TypeScriptParser};
+use dc_core::openapi::{FetchOptions, OpenAPILinker, OpenAPIParser, OpenAPISchema};
+use dc_core::parsers::{Call, StatusCheck, TypeScriptParser, UTILITY_BASE_METADATA_KEY};
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::time::Instant;
 use swc_ecma_ast;
 use tracing::{debug, error, warn};
 
@@ -22,12 +27,17 @@ pub struct TypeScriptCallGraphBuilder {
     max_depth: Option<usize>,
     /// Current recursion depth
     current_depth: usize,
+    /// Time after which no more files are read (None = no limit)
+    deadline: Option<Instant>,
     /// Enable verbose debug output
     verbose: bool,
     /// Cache for SDK function analysis results (function_name -> ApiCallInfo)
     sdk_function_cache: HashMap<String, Option<ApiCallInfo>>,
     /// Map of imported functions to their source files (function_name -> source_file_path)
     imported_functions: HashMap<String, PathBuf>,
+    /// Type declarations of the generated files of each SDK directory, parsed once
+    /// (directory -> [(file, schemas)])
+    generated_schemas: HashMap<PathBuf, Vec<(PathBuf, Vec<dc_core::models::SchemaReference>)>>,
     /// TypeScript path resolver for handling path mappings
     path_resolver: path_resolver::TypeScriptPathResolver,
     /// OpenAPI schema for linking TypeScript types to Backend
@@ -38,6 +48,10 @@ pub struct TypeScriptCallGraphBuilder {
     openapi_linker: Option<OpenAPILinker>,
     /// Zod extractor for finding schema usages
     zod_extractor: crate::zod::ZodExtractor,
+    /// Create Route nodes for routes registered on Express apps and routers
+    express_routes: bool,
+    /// Skip files ignored by `.gitignore` when walking `src_paths`
+    respect_gitignore: bool,
 }
 
 impl TypeScriptCallGraphBuilder {
@@ -111,13 +125,17 @@ impl TypeScriptCallGraphBuilder {
             project_root: Some(project_root.clone()),
             max_depth: None,
             current_depth: 0,
+            deadline: None,
             verbose: false,
             sdk_function_cache: HashMap::new(),
             imported_functions: HashMap::new(),
+            generated_schemas: HashMap::new(),
             path_resolver: path_resolver::TypeScriptPathResolver::new(&project_root),
             openapi_schema: None,
             openapi_linker: None,
             zod_extractor: crate::zod::ZodExtractor::new(),
+            express_routes: false,
+            respect_gitignore: true,
         }
     }
 
@@ -127,42 +145,87 @@ impl TypeScriptCallGraphBuilder {
         self
     }
 
+    /// Stops reading files once `deadline` has passed, leaving the graph partial
+    pub fn with_deadline(mut self, deadline: Option<Instant>) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
     /// Sets the verbose flag for debug output
     pub fn with_verbose(mut self, verbose: bool) -> Self {
         self.verbose = verbose;
         self
     }
 
-    /// Sets the OpenAPI schema path
+    /// Creates Route nodes for Express route registrations (`router.post("/users", ...)`),
+    /// for graphs of Node backends
+    pub fn with_express_routes(mut self, express_routes: bool) -> Self {
+        self.express_routes = express_routes;
+        self
+    }
+
+    /// Sets whether files ignored by `.gitignore` are skipped when walking `src_paths`
+    /// (default: `true`); source paths naming a file are analyzed either way
+    pub fn with_respect_gitignore(mut self, respect_gitignore: bool) -> Self {
+        self.respect_gitignore = respect_gitignore;
+        self
+    }
+
+    /// Sets the OpenAPI schema path (a file or an `http(s)://` URL)
     /// If provided, the builder will use OpenAPI schema to link TypeScript API calls with Backend routes
-    pub fn with_openapi_schema(mut self, openapi_path: Option<PathBuf>) -> Self {
-        if let Some(path) = openapi_path {
-            if let Ok(schema) = OpenAPIParser::parse_file(&path) {
-                if self.verbose {
-                    debug!(
-                        openapi_path = ?path,
-                        "Loaded OpenAPI schema"
-                    );
+    pub fn with_openapi_schema(self, openapi_path: Option<PathBuf>) -> Self {
+        let verbose = self.verbose;
+        let schema = openapi_path.and_then(|path| {
+            match OpenAPIParser::parse_source(&path.to_string_lossy(), &FetchOptions::default()) {
+                Ok(schema) => {
+                    if verbose {
+                        debug!(
+                            openapi_path = ?path,
+                            "Loaded OpenAPI schema"
+                        );
+                    }
+                    Some(schema)
+                }
+                Err(_) => {
+                    if verbose {
+                        warn!(
+                            openapi_path = ?path,
+                            "Failed to parse OpenAPI schema"
+                        );
+                    }
+                    None
                 }
-                self.openapi_schema = Some(schema.clone());
-                self.openapi_linker = Some(OpenAPILinker::new(schema));
-            } else if self.verbose {
-                warn!(
-                    openapi_path = ?path,
-                    "Failed to parse OpenAPI schema"
-                );
             }
+        });
+        self.with_openapi_spec(schema)
+    }
+
+    /// Sets an already parsed OpenAPI schema (e.g. fetched once from a URL)
+    pub fn with_openapi_spec(mut self, schema: Option<OpenAPISchema>) -> Self {
+        if let Some(schema) = schema {
+            self.openapi_schema = Some(schema.clone());
+            self.openapi_linker = Some(OpenAPILinker::new(schema));
         }
         self
     }
 
-    /// Builds graph for TypeScript project
-    pub fn build_graph(mut self) -> Result<CallGraph> {
-        // 1. Find all .ts/.tsx files in src_paths
+    /// `.ts`/`.tsx` files under `src_paths` the graph is built from
+    pub fn source_files(&self) -> Vec<PathBuf> {
         let mut files = Vec::new();
         for src_path in &self.src_paths {
-            self.find_ts_files(src_path, &mut files)?;
+            files.extend(find_source_files(
+                src_path,
+                &["ts", "tsx"],
+                self.respect_gitignore,
+            ));
         }
+        files
+    }
+
+    /// Builds graph for TypeScript project
+    pub fn build_graph(mut self) -> Result<CallGraph> {
+        // 1. Find all .ts/.tsx files in src_paths
+        let files = self.source_files();
 
         // 2. Determine project root using helper function (only if not already set or if it differs)
         let discovered_root = Self::determine_project_root(&self.src_paths);
@@ -196,6 +259,13 @@ impl TypeScriptCallGraphBuilder {
         if self.processed_files.contains(&normalized) {
             return Ok(()); // Already processed
         }
+        // Files not reached before the deadline are skipped
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return Ok(());
+        }
 
         // Check recursion depth limit
         if let Some(max_depth) = self.max_depth {
@@ -273,17 +343,48 @@ impl TypeScriptCallGraphBuilder {
                 }
             }
 
+            // Status codes compared next to API calls (`res.status === 404`)
+            let status_checks =
+                self.parser
+                    .extract_status_checks(&module, &file_path_str, &converter);
+
+            // Request bodies serialized at the call site (`body: JSON.stringify(payload)`)
+            let request_bodies =
+                self.parser
+                    .extract_request_bodies(&module, &file_path_str, &converter);
+
             // Detect API calls and create Route nodes
             for call in calls {
-                if let Some(api_call) = self.detect_api_call(&call) {
-                    if let Err(err) =
-                        self.create_route_from_api_call(api_call, &normalized, &file_path_str)
-                    {
-                        debug!(
-                            call_name = %call.name,
-                            error = %err,
-                            "Failed to create route from API call"
-                        );
+                if let Some(mut api_call) = self.detect_api_call(&call) {
+                    if api_call.request_type.is_none() {
+                        api_call.request_type = request_bodies
+                            .iter()
+                            .find(|body| body.location == call.location)
+                            .map(|body| dc_core::models::TypeInfo {
+                                base_type: dc_core::models::BaseType::Object,
+                                schema_ref: Some(self.resolve_imported_schema(
+                                    &body.schema,
+                                    &imports,
+                                    &normalized,
+                                )),
+                                constraints: Vec::new(),
+                                optional: false,
+                            });
+                    }
+                    match self.create_route_from_api_call(api_call, &normalized, &file_path_str) {
+                        Ok(route_node) => {
+                            self.attach_handled_statuses(route_node, &call, &status_checks);
+                            self.attach_sent_headers(route_node, &call);
+                            self.attach_sent_query(route_node, &call);
+                            self.attach_sent_media_type(route_node, &call);
+                        }
+                        Err(err) => {
+                            debug!(
+                                call_name = %call.name,
+                                error = %err,
+                                "Failed to create route from API call"
+                            );
+                        }
                     }
                 }
             }
@@ -377,6 +478,21 @@ impl TypeScriptCallGraphBuilder {
                 }
             }
 
+            // Express routes, once the handlers declared in the file have nodes
+            if self.express_routes {
+                let routes =
+                    self.parser
+                        .extract_express_routes(&module, &file_path_str, &converter);
+                if !routes.is_empty() {
+                    let local_schemas =
+                        self.parser
+                            .extract_typescript_schemas(&module, &file_path_str, &converter);
+                    for route in routes {
+                        self.create_express_route(route, &local_schemas, &imports, &normalized);
+                    }
+                }
+            }
+
             // Extract Zod schemas and add them to graph
             let zod_schemas = self
                 .parser
@@ -621,6 +737,11 @@ impl TypeScriptCallGraphBuilder {
             }
         };
 
+        // Generated clients declare their contract explicitly: take request/response
+        // types from the generated declarations instead of inferring them at call sites.
+        let (request_type, response_type) =
+            self.extract_generated_contract_types(&module, function_name, source_file);
+
         // If we have an OpenAPI linker, try to resolve the API call purely by operationId
         // (function name) before doing any heuristic source scanning. This helps when
         // the URL or HTTP method are not easily recoverable from generated SDK code.
@@ -631,8 +752,9 @@ impl TypeScriptCallGraphBuilder {
                         path: endpoint.path.clone(),
                         method,
                         location: function_info.location.clone(),
-                        request_type: None,
-                        response_type: None,
+                        request_type,
+                        response_type,
+                        transport: None,
                     });
 
                     self.sdk_function_cache
@@ -645,7 +767,13 @@ impl TypeScriptCallGraphBuilder {
         // Analyze function body to find client.get/post/delete calls
         // We need to parse the source code to find the pattern:
         // return (options?.client ?? client).get/post/delete({ url: "...", ... })
-        let api_info = self.extract_api_info_from_sdk_function(&source, &function_info.location);
+        let api_info = self
+            .extract_api_info_from_sdk_function(&source, &function_info.location)
+            .map(|info| ApiCallInfo {
+                request_type,
+                response_type,
+                ..info
+            });
 
         // Cache the result
         self.sdk_function_cache
@@ -753,12 +881,347 @@ impl TypeScriptCallGraphBuilder {
                 location: function_location.clone(),
                 request_type: None,
                 response_type: None,
+                transport: None,
             })
         } else {
             None
         }
     }
 
+    /// Resolves request/response types of a generated SDK function
+    ///
+    /// Handles the common codegen shape:
+    /// `(options: Options<CreateItemData>) => client.post<CreateItemResponse, ...>({...})`.
+    /// The request type is the `body` of the `*Data` type when present, otherwise the
+    /// `*Data` type itself. Types are looked up in the generated files next to the SDK.
+    fn extract_generated_contract_types(
+        &mut self,
+        module: &swc_ecma_ast::Module,
+        function_name: &str,
+        sdk_file: &Path,
+    ) -> (
+        Option<dc_core::models::TypeInfo>,
+        Option<dc_core::models::TypeInfo>,
+    ) {
+        let (request_name, response_name) = Self::generated_type_names(module, function_name);
+
+        let request_type = request_name.and_then(|name| {
+            let data_schema = self.find_generated_schema(&name, sdk_file)?;
+            let body_type = data_schema.metadata.get("fields").and_then(|fields| {
+                fields.split(',').find_map(|field| {
+                    let mut parts = field.trim().splitn(3, ':');
+                    match (parts.next(), parts.next()) {
+                        (Some("body"), Some(body_type)) => Some(body_type.to_string()),
+                        _ => None,
+                    }
+                })
+            });
+            let schema = match body_type {
+                Some(body_type) => self
+                    .find_generated_schema(&body_type, sdk_file)
+                    .unwrap_or(data_schema),
+                None => data_schema,
+            };
+            Some(Self::generated_type_info(schema))
+        });
+
+        let response_type = response_name
+            .and_then(|name| self.find_generated_schema(&name, sdk_file))
+            .map(Self::generated_type_info);
+
+        (request_type, response_type)
+    }
+
+    /// Reads the `Options<RequestData>` parameter type and the `.method<Response>(...)` call
+    /// type argument of a generated SDK function, declared as a function or an arrow
+    fn generated_type_names(
+        module: &swc_ecma_ast::Module,
+        function_name: &str,
+    ) -> (Option<String>, Option<String>) {
+        use swc_ecma_ast::{BlockStmtOrExpr, Decl, Expr, ModuleDecl, ModuleItem, Pat, Stmt};
+
+        let from_function = |function: &swc_ecma_ast::Function| {
+            let params: Vec<&Pat> = function.params.iter().map(|param| &param.pat).collect();
+            (
+                Self::options_type_arg(&params),
+                function
+                    .body
+                    .as_ref()
+                    .and_then(|body| Self::response_type_arg_in_stmts(&body.stmts)),
+            )
+        };
+
+        for item in &module.body {
+            let decl = match item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => &export_decl.decl,
+                ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+                _ => continue,
+            };
+            match decl {
+                Decl::Fn(fn_decl) if fn_decl.ident.sym.as_ref() == function_name => {
+                    return from_function(&fn_decl.function);
+                }
+                Decl::Var(var_decl) => {
+                    let init =
+                        var_decl
+                            .decls
+                            .iter()
+                            .find_map(|declarator| match &declarator.name {
+                                Pat::Ident(ident) if ident.id.sym.as_ref() == function_name => {
+                                    declarator.init.as_deref()
+                                }
+                                _ => None,
+                            });
+                    match init {
+                        Some(Expr::Arrow(arrow)) => {
+                            let params: Vec<&Pat> = arrow.params.iter().collect();
+                            let response = match arrow.body.as_ref() {
+                                BlockStmtOrExpr::BlockStmt(block) => {
+                                    Self::response_type_arg_in_stmts(&block.stmts)
+                                }
+                                BlockStmtOrExpr::Expr(expr) => Self::response_type_arg(expr),
+                            };
+                            return (Self::options_type_arg(&params), response);
+                        }
+                        Some(Expr::Fn(fn_expr)) => return from_function(&fn_expr.function),
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+        (None, None)
+    }
+
+    /// `RequestData` of the first parameter typed `Options<RequestData>`
+    fn options_type_arg(params: &[&swc_ecma_ast::Pat]) -> Option<String> {
+        use swc_ecma_ast::{Pat, TsEntityName, TsType};
+
+        params.iter().find_map(|param| {
+            let type_ann = match param {
+                Pat::Ident(ident) => ident.type_ann.as_ref(),
+                Pat::Assign(assign) => match assign.left.as_ref() {
+                    Pat::Ident(ident) => ident.type_ann.as_ref(),
+                    _ => None,
+                },
+                Pat::Object(object) => object.type_ann.as_ref(),
+                _ => None,
+            }?;
+            let TsType::TsTypeRef(type_ref) = type_ann.type_ann.as_ref() else {
+                return None;
+            };
+            match &type_ref.type_name {
+                TsEntityName::Ident(name) if name.sym.as_ref() == "Options" => {
+                    Self::type_ref_name(type_ref.type_params.as_ref()?.params.first()?)
+                }
+                _ => None,
+            }
+        })
+    }
+
+    /// Response type argument of the first request call of a function body
+    fn response_type_arg_in_stmts(stmts: &[swc_ecma_ast::Stmt]) -> Option<String> {
+        use swc_ecma_ast::{Decl, Stmt};
+
+        stmts.iter().find_map(|stmt| match stmt {
+            Stmt::Return(return_stmt) => Self::response_type_arg(return_stmt.arg.as_deref()?),
+            Stmt::Expr(expr_stmt) => Self::response_type_arg(&expr_stmt.expr),
+            Stmt::Decl(Decl::Var(var_decl)) => var_decl
+                .decls
+                .iter()
+                .find_map(|declarator| Self::response_type_arg(declarator.init.as_deref()?)),
+            _ => None,
+        })
+    }
+
+    /// First type argument of a `.get<Response>(...)`/`.post<Response>(...)`/... call in
+    /// `expr`
+    fn response_type_arg(expr: &swc_ecma_ast::Expr) -> Option<String> {
+        use swc_ecma_ast::{Callee, Expr, MemberProp};
+
+        match expr {
+            Expr::Call(call) => {
+                let Callee::Expr(callee) = &call.callee else {
+                    return None;
+                };
+                if let (Expr::Member(member), Some(type_args)) =
+                    (callee.as_ref(), call.type_args.as_ref())
+                {
+                    if let MemberProp::Ident(prop) = &member.prop {
+                        if Self::http_method_from_str(prop.sym.as_ref()).is_some() {
+                            return Self::type_ref_name(type_args.params.first()?);
+                        }
+                    }
+                }
+                Self::response_type_arg(callee).or_else(|| {
+                    call.args
+                        .iter()
+                        .find_map(|arg| Self::response_type_arg(&arg.expr))
+                })
+            }
+            Expr::Await(await_expr) => Self::response_type_arg(&await_expr.arg),
+            Expr::Paren(paren) => Self::response_type_arg(&paren.expr),
+            Expr::Member(member) => Self::response_type_arg(&member.obj),
+            Expr::TsAs(as_expr) => Self::response_type_arg(&as_expr.expr),
+            _ => None,
+        }
+    }
+
+    /// Name of a plain type reference (`ItemPublic`)
+    fn type_ref_name(ts_type: &swc_ecma_ast::TsType) -> Option<String> {
+        match ts_type {
+            swc_ecma_ast::TsType::TsTypeRef(type_ref) => match &type_ref.type_name {
+                swc_ecma_ast::TsEntityName::Ident(name) => Some(name.sym.to_string()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Finds a type declaration in the generated files next to an SDK file
+    ///
+    /// The SDK file is searched first, then the other generated files of its directory.
+    /// Each directory's files are parsed once and their declarations cached.
+    fn find_generated_schema(
+        &mut self,
+        type_name: &str,
+        sdk_file: &Path,
+    ) -> Option<dc_core::models::SchemaReference> {
+        let dir = sdk_file.parent().unwrap_or(Path::new("")).to_path_buf();
+        if !self.generated_schemas.contains_key(&dir) {
+            let mut generated: Vec<PathBuf> = std::fs::read_dir(&dir)
+                .map(|entries| {
+                    entries
+                        .filter_map(|entry| entry.ok().map(|e| e.path()))
+                        .filter(|path| self.is_sdk_file(path))
+                        .collect()
+                })
+                .unwrap_or_default();
+            generated.sort();
+            let parsed = generated
+                .into_iter()
+                .map(|file| {
+                    let schemas = self.generated_file_schemas(&file);
+                    (file, schemas)
+                })
+                .collect();
+            self.generated_schemas.insert(dir.clone(), parsed);
+        }
+        if !self.generated_schemas[&dir]
+            .iter()
+            .any(|(file, _)| file == sdk_file)
+        {
+            let schemas = self.generated_file_schemas(sdk_file);
+            self.generated_schemas
+                .get_mut(&dir)?
+                .push((sdk_file.to_path_buf(), schemas));
+        }
+
+        let files = &self.generated_schemas[&dir];
+        files
+            .iter()
+            .filter(|(file, _)| file == sdk_file)
+            .chain(files.iter().filter(|(file, _)| file != sdk_file))
+            .flat_map(|(_, schemas)| schemas)
+            .find(|schema| schema.name == type_name)
+            .cloned()
+    }
+
+    /// Type declarations of a generated file; none when it can't be parsed
+    fn generated_file_schemas(&self, file: &Path) -> Vec<dc_core::models::SchemaReference> {
+        let Ok((module, _source, converter)) = self.parser.parse_file(file) else {
+            return Vec::new();
+        };
+        self.parser
+            .extract_typescript_schemas(&module, &file.to_string_lossy(), &converter)
+    }
+
+    /// Declaration of a body type imported from another module
+    ///
+    /// Bodies typed with an imported type (`payload: CreateUser`) only carry its name; the
+    /// module it is imported from is parsed for the interface or Zod schema. The reference
+    /// is returned unchanged when it already has fields or the import can't be resolved.
+    /// Utility type aliases over an imported type (`type Patch = Partial<User>`) get their
+    /// fields once that type is resolved.
+    fn resolve_imported_schema(
+        &self,
+        schema: &dc_core::models::SchemaReference,
+        imports: &[dc_core::parsers::Import],
+        current_file: &Path,
+    ) -> dc_core::models::SchemaReference {
+        if schema.metadata.contains_key(UTILITY_BASE_METADATA_KEY) {
+            return self.resolve_utility_base(schema.clone(), imports, current_file);
+        }
+        if !schema.metadata.is_empty() {
+            return schema.clone();
+        }
+        imports
+            .iter()
+            .find(|import| import.names.contains(&schema.name))
+            .and_then(|import| self.resolve_import_path(&import.path, current_file).ok())
+            .and_then(|path| {
+                let (module, _source, converter) = self.parser.parse_file(&path).ok()?;
+                let file_path_str = path.to_string_lossy().to_string();
+                let declared = self
+                    .parser
+                    .extract_typescript_schemas(&module, &file_path_str, &converter)
+                    .into_iter()
+                    .chain(
+                        self.parser
+                            .extract_zod_schemas(&module, &file_path_str, &converter),
+                    )
+                    .find(|declared| declared.name == schema.name)?;
+                let module_imports =
+                    self.parser
+                        .extract_imports(&module, &file_path_str, &converter);
+                Some(self.resolve_utility_base(declared, &module_imports, &path))
+            })
+            .unwrap_or_else(|| schema.clone())
+    }
+
+    /// Fields of a utility type alias still waiting for its base type (declared in another
+    /// module than the alias), resolved through the imports of the alias's module
+    fn resolve_utility_base(
+        &self,
+        schema: dc_core::models::SchemaReference,
+        imports: &[dc_core::parsers::Import],
+        file: &Path,
+    ) -> dc_core::models::SchemaReference {
+        if schema.metadata.contains_key("fields") {
+            return schema;
+        }
+        let Some(base) = schema.metadata.get(UTILITY_BASE_METADATA_KEY) else {
+            return schema;
+        };
+        let placeholder = dc_core::models::SchemaReference {
+            name: base.clone(),
+            schema_type: dc_core::models::SchemaType::TypeScript,
+            location: schema.location.clone(),
+            metadata: HashMap::new(),
+        };
+        let base = self.resolve_imported_schema(&placeholder, imports, file);
+        let mut schemas = [schema, base];
+        TypeScriptParser::resolve_utility_types(&mut schemas);
+        let [schema, _] = schemas;
+        schema
+    }
+
+    /// Wraps a generated schema into TypeInfo, marking it as a generated-client contract
+    fn generated_type_info(
+        mut schema: dc_core::models::SchemaReference,
+    ) -> dc_core::models::TypeInfo {
+        schema.metadata.insert(
+            "contract_source".to_string(),
+            "generated_client".to_string(),
+        );
+        dc_core::models::TypeInfo {
+            base_type: dc_core::models::BaseType::Object,
+            schema_ref: Some(schema),
+            constraints: Vec::new(),
+            optional: false,
+        }
+    }
+
     /// Extracts URL from call arguments
     /// Tries to extract from object with "url" property, falls back to first argument as string
     fn extract_url_from_call_arguments(&self, call: &Call) -> String {
@@ -807,6 +1270,7 @@ impl TypeScriptCallGraphBuilder {
                 location: call.location.clone(),
                 request_type: None,
                 response_type: None,
+                transport: None,
             });
         }
 
@@ -822,6 +1286,7 @@ impl TypeScriptCallGraphBuilder {
                         location: call.location.clone(),
                         request_type: None,
                         response_type: None,
+                        transport: None,
                     });
                 }
             }
@@ -843,6 +1308,7 @@ impl TypeScriptCallGraphBuilder {
                             location: call.location.clone(),
                             request_type: None,
                             response_type: None,
+                            transport: None,
                         });
                     }
                 }
@@ -874,6 +1340,7 @@ impl TypeScriptCallGraphBuilder {
                         location: call.location.clone(),
                         request_type: None,
                         response_type: None,
+                        transport: None,
                     });
                 }
             }
@@ -892,6 +1359,7 @@ impl TypeScriptCallGraphBuilder {
                         location: call.location.clone(),
                         request_type: None,
                         response_type: None,
+                        transport: None,
                     });
                 }
             }
@@ -915,6 +1383,7 @@ impl TypeScriptCallGraphBuilder {
                     location: call.location.clone(),
                     request_type,
                     response_type,
+                    transport: Some("graphql"),
                 });
             }
 
@@ -939,6 +1408,7 @@ impl TypeScriptCallGraphBuilder {
                     location: call.location.clone(),
                     request_type,
                     response_type,
+                    transport: None,
                 });
             }
         }
@@ -963,6 +1433,7 @@ impl TypeScriptCallGraphBuilder {
                     location: call.location.clone(),
                     request_type,
                     response_type,
+                    transport: None,
                 });
             }
         }
@@ -982,6 +1453,7 @@ impl TypeScriptCallGraphBuilder {
                         location: call.location.clone(),
                         request_type,
                         response_type,
+                        transport: None,
                     });
                 }
             }
@@ -1004,6 +1476,7 @@ impl TypeScriptCallGraphBuilder {
                 location: call.location.clone(),
                 request_type,
                 response_type,
+                transport: Some("trpc"),
             });
         }
 
@@ -1019,6 +1492,7 @@ impl TypeScriptCallGraphBuilder {
                 location: call.location.clone(),
                 request_type,
                 response_type,
+                transport: Some("server_action"),
             });
         }
 
@@ -1292,7 +1766,8 @@ impl TypeScriptCallGraphBuilder {
                 let after_method = &options_str[method_start..];
                 if let Some(colon) = after_method.find(':') {
                     let method_part = &after_method[colon + 1..];
-                    let method_clean = method_part
+                    let method_end = method_part.find([',', '}']).unwrap_or(method_part.len());
+                    let method_clean = method_part[..method_end]
                         .trim()
                         .trim_matches('"')
                         .trim_matches('\'')
@@ -1312,7 +1787,7 @@ impl TypeScriptCallGraphBuilder {
         api_call: ApiCallInfo,
         file_path: &Path,
         _file_path_str: &str,
-    ) -> Result<()> {
+    ) -> Result<NodeId> {
         // Priority order for type extraction:
         // 1. Try to find corresponding service file and extract types from function
         // 2. Use types from generic parameters of API call (useQuery/useMutation)
@@ -1334,12 +1809,19 @@ impl TypeScriptCallGraphBuilder {
                     }
                 }
 
+                // Types serialized at the call site fill in what the service does not declare
+                found_types.0 = found_types.0.or(api_call.request_type.clone());
                 found_types
             } else {
                 // No service file found, use types from API call generic params
                 (api_call.request_type, api_call.response_type)
             };
 
+        // The handler node is shared by all calls of the file: the route keeps this call's own
+        // schemas (OpenAPI schemas below take precedence)
+        let call_request_schema = request_type.as_ref().and_then(|t| t.schema_ref.clone());
+        let call_response_schema = response_type.as_ref().and_then(|t| t.schema_ref.clone());
+
         // Create handler with types if available
         let handler_node = match (request_type, response_type) {
             (Some(req_type), Some(resp_type)) => {
@@ -1369,6 +1851,22 @@ impl TypeScriptCallGraphBuilder {
                     false,
                 )
             }
+            (Some(req_type), None) => {
+                // Only request type available (e.g. a serialized body)
+                self.get_or_create_function_node_with_details(
+                    "api_handler",
+                    file_path,
+                    0,
+                    vec![dc_core::call_graph::Parameter {
+                        name: "request".to_string(),
+                        type_info: req_type,
+                        optional: false,
+                        default_value: None,
+                    }],
+                    None,
+                    false,
+                )
+            }
             _ => {
                 // Fallback to existing code
                 self.get_or_create_function_node("api_handler", file_path)
@@ -1377,14 +1875,22 @@ impl TypeScriptCallGraphBuilder {
         let location = api_call.location.clone();
 
         // Create initial Route node for this API call
-        let route_node = NodeId::from(self.graph.add_node(CallNode::Route {
-            path: api_call.path.clone(),
-            method: api_call.method,
-            handler: handler_node,
-            location: location.clone(),
-            request_schema: None,
-            response_schema: None,
-        }));
+        let route_node = NodeId::from(
+            self.graph.add_node(CallNode::Route {
+                path: api_call.path.clone(),
+                method: api_call.method,
+                handler: handler_node,
+                location: location.clone(),
+                request_schema: call_request_schema,
+                response_schema: call_response_schema,
+                metadata: api_call
+                    .transport
+                    .map(|transport| {
+                        HashMap::from([("transport".to_string(), transport.to_string())])
+                    })
+                    .unwrap_or_default(),
+            }),
+        );
 
         // If we have an OpenAPI linker, try to match this route to an OpenAPI endpoint
         // and enrich Route node with request/response schemas from OpenAPI.
@@ -1441,7 +1947,264 @@ impl TypeScriptCallGraphBuilder {
             },
         );
 
-        Ok(())
+        Ok(route_node)
+    }
+
+    /// Creates the Route node of an Express route, linked to its handler
+    ///
+    /// Body and response types name interfaces or Zod schemas declared in the file or
+    /// imported from another module.
+    fn create_express_route(
+        &mut self,
+        route: dc_core::parsers::ExpressRoute,
+        local_schemas: &[dc_core::models::SchemaReference],
+        imports: &[dc_core::parsers::Import],
+        file_path: &Path,
+    ) {
+        let Ok(method) = route.method.parse::<HttpMethod>() else {
+            return;
+        };
+        let handler = match &route.handler {
+            Some(name) => self
+                .find_function_node(name, file_path)
+                .unwrap_or_else(|| self.get_or_create_function_node(name, file_path)),
+            // Inline handlers are named after their route
+            None => self.get_or_create_function_node(
+                &format!("{} {}", route.method.to_uppercase(), route.path),
+                file_path,
+            ),
+        };
+        let schema = |type_name: &Option<String>| {
+            type_name.as_ref().map(|type_name| {
+                let declared = local_schemas
+                    .iter()
+                    .find(|schema| schema.name == *type_name)
+                    .cloned();
+                let placeholder = dc_core::models::SchemaReference {
+                    name: type_name.clone(),
+                    schema_type: dc_core::models::SchemaType::TypeScript,
+                    location: route.location.clone(),
+                    metadata: HashMap::new(),
+                };
+                declared.unwrap_or_else(|| {
+                    self.resolve_imported_schema(&placeholder, imports, file_path)
+                })
+            })
+        };
+        let request_schema = schema(&route.request_type);
+        let response_schema = schema(&route.response_type);
+
+        let route_node = NodeId::from(self.graph.add_node(CallNode::Route {
+            path: route.path.clone(),
+            method,
+            handler,
+            location: route.location.clone(),
+            request_schema,
+            response_schema,
+            metadata: HashMap::new(),
+        }));
+        self.graph.add_edge(
+            *route_node,
+            *handler,
+            CallEdge::Call {
+                caller: route_node,
+                callee: handler,
+                argument_mapping: Vec::new(),
+                location: route.location,
+            },
+        );
+    }
+
+    /// Stores status codes the frontend compares in the same function as the API call
+    /// in route metadata (`handled_statuses`, JSON list of [`HandledStatus`])
+    ///
+    /// [`HandledStatus`]: dc_core::models::HandledStatus
+    fn attach_handled_statuses(
+        &mut self,
+        route_node: NodeId,
+        call: &Call,
+        status_checks: &[StatusCheck],
+    ) {
+        let mut statuses = Vec::new();
+        for check in status_checks {
+            if check.caller == call.caller && !statuses.contains(&check.status) {
+                statuses.push(check.status);
+            }
+        }
+        if statuses.is_empty() {
+            return;
+        }
+
+        if let Some(CallNode::Route { metadata, .. }) = self.graph.node_weight_mut(route_node.0) {
+            if let Ok(json) = serde_json::to_string(&statuses) {
+                metadata.insert("handled_statuses".to_string(), json);
+            }
+        }
+    }
+
+    /// Stores the headers a call sets in route metadata (`sent_headers`), when they are known
+    ///
+    /// Headers are read from a literal `headers` object in the call's options
+    /// (`fetch(url, { headers: { "X-Api-Key": key } })`,
+    /// `axios.post(url, body, { headers: { ... } })`). A plain `fetch` without `headers` sends
+    /// none; other clients may add default headers, so nothing is recorded for them. A
+    /// `headers` variable, spread or computed key leaves the headers unknown.
+    fn attach_sent_headers(&mut self, route_node: NodeId, call: &Call) {
+        let options: Vec<&str> = call
+            .arguments
+            .iter()
+            .filter_map(|arg| object_properties(&arg.value))
+            .flatten()
+            .collect();
+        // `{ headers }` or `{ ...options }` may carry any header
+        if options
+            .iter()
+            .any(|prop| *prop == "headers" || prop.starts_with("..."))
+        {
+            return;
+        }
+        let headers_option = options
+            .iter()
+            .find_map(|prop| prop.strip_prefix("headers: "));
+        let sent = match headers_option {
+            Some(headers) => object_properties(headers).and_then(|props| {
+                props
+                    .iter()
+                    .map(|prop| {
+                        let (key, _) = prop.split_once(": ")?;
+                        (!key.starts_with("...") && key != "[...]").then(|| key.to_lowercase())
+                    })
+                    .collect::<Option<Vec<_>>>()
+            }),
+            None if call.name == "fetch" => Some(Vec::new()),
+            None => None,
+        };
+        let Some(sent) = sent else {
+            return;
+        };
+
+        if let Some(CallNode::Route { metadata, .. }) = self.graph.node_weight_mut(route_node.0) {
+            metadata.insert(SENT_HEADERS_METADATA_KEY.to_string(), sent.join(","));
+        }
+    }
+
+    /// Stores the query parameters a call sends through a literal `params` object in route
+    /// metadata (`sent_query`, JSON object of name to literal value)
+    ///
+    /// `axios.get(url, { params: { page: 2, active: "yes" } })` sends `page=2&active=yes`;
+    /// values that aren't string, number or boolean literals are recorded as `null`, and
+    /// spread entries are skipped. Parameters in the URL itself are read from its path.
+    fn attach_sent_query(&mut self, route_node: NodeId, call: &Call) {
+        let Some(params) = call
+            .arguments
+            .iter()
+            .filter_map(|arg| object_properties(&arg.value))
+            .flatten()
+            .find_map(|prop| prop.strip_prefix("params: "))
+            .and_then(object_properties)
+        else {
+            return;
+        };
+        let mut sent = serde_json::Map::new();
+        for param in params {
+            if param.starts_with("...") {
+                continue;
+            }
+            let (name, value) = match param.split_once(": ") {
+                Some((name, value)) => (name, literal_query_value(value)),
+                None => (param, None),
+            };
+            sent.insert(
+                name.to_string(),
+                value.map_or(serde_json::Value::Null, serde_json::Value::String),
+            );
+        }
+        if sent.is_empty() {
+            return;
+        }
+
+        if let Some(CallNode::Route { metadata, .. }) = self.graph.node_weight_mut(route_node.0) {
+            metadata.insert(
+                SENT_QUERY_METADATA_KEY.to_string(),
+                serde_json::Value::Object(sent).to_string(),
+            );
+        }
+    }
+
+    /// Stores the media type of the body a call sends in route metadata (`sent_media_type`),
+    /// when it is known
+    ///
+    /// A literal `Content-Type` header wins. Otherwise the body tells: `JSON.stringify(...)`
+    /// is JSON, `new FormData(...)` multipart and `new URLSearchParams(...)` a URL-encoded
+    /// form; API clients other than `fetch` also serialize an object literal passed as data
+    /// (`axios.post(url, { name })`) as JSON.
+    fn attach_sent_media_type(&mut self, route_node: NodeId, call: &Call) {
+        let Some(CallNode::Route { method, .. }) = self.graph.node_weight(route_node.0) else {
+            return;
+        };
+        let has_body = !matches!(
+            method,
+            HttpMethod::Get | HttpMethod::Head | HttpMethod::Delete | HttpMethod::Options
+        );
+        let is_fetch = call.name == "fetch";
+
+        let options: Vec<&str> = call
+            .arguments
+            .iter()
+            .filter_map(|arg| object_properties(&arg.value))
+            .flatten()
+            .collect();
+        let content_type = options
+            .iter()
+            .find_map(|prop| prop.strip_prefix("headers: "))
+            .and_then(object_properties)
+            .and_then(|headers| {
+                headers.into_iter().find_map(|header| {
+                    let (key, value) = header.split_once(": ")?;
+                    key.eq_ignore_ascii_case("content-type").then_some(value)
+                })
+            });
+        let media_type = match content_type {
+            Some(value) => value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .map(str::to_lowercase),
+            None => {
+                let data_argument = call
+                    .arguments
+                    .get(1)
+                    .filter(|_| !is_fetch && has_body)
+                    .map(|arg| arg.value.as_str());
+                options
+                    .iter()
+                    .filter_map(|prop| {
+                        prop.strip_prefix("body: ")
+                            .or_else(|| prop.strip_prefix("data: "))
+                    })
+                    .chain(data_argument)
+                    .find_map(|body| {
+                        if body.starts_with("JSON.stringify(") {
+                            Some("application/json")
+                        } else if body.starts_with("new FormData(") {
+                            Some("multipart/form-data")
+                        } else if body.starts_with("new URLSearchParams(") {
+                            Some("application/x-www-form-urlencoded")
+                        } else if body.starts_with('{') && !is_fetch {
+                            Some("application/json")
+                        } else {
+                            None
+                        }
+                    })
+                    .map(str::to_string)
+            }
+        };
+        let Some(media_type) = media_type else {
+            return;
+        };
+
+        if let Some(CallNode::Route { metadata, .. }) = self.graph.node_weight_mut(route_node.0) {
+            metadata.insert(SENT_MEDIA_TYPE_METADATA_KEY.to_string(), media_type);
+        }
     }
 
     /// Gets or creates a module node
@@ -1675,7 +2438,8 @@ impl TypeScriptCallGraphBuilder {
             }
         }
 
-        let remaining = import_path.trim_start_matches('.');
+        // "./client/sdk" -> "client/sdk": a leading separator would make join() absolute
+        let remaining = import_path.trim_start_matches('.').trim_start_matches('/');
         if !remaining.is_empty() {
             let replaced = remaining.replace('/', std::path::MAIN_SEPARATOR_STR);
             path = path.join(replaced);
@@ -2007,28 +2771,6 @@ impl TypeScriptCallGraphBuilder {
         (None, None)
     }
 
-    #[allow(clippy::only_used_in_recursion)]
-    fn find_ts_files(&self, dir: &PathBuf, files: &mut Vec<PathBuf>) -> Result<()> {
-        if dir.is_file() {
-            if let Some(ext) = dir.extension() {
-                if ext == "ts" || ext == "tsx" {
-                    files.push(dir.clone());
-                }
-            }
-            return Ok(());
-        }
-
-        if dir.is_dir() {
-            for entry in std::fs::read_dir(dir)? {
-                let entry = entry?;
-                let path = entry.path();
-                self.find_ts_files(&path, files)?;
-            }
-        }
-
-        Ok(())
-    }
-
     /// Maximum depth for re-export traversal to prevent infinite recursion
     const MAX_REEXPORT_DEPTH: usize = 10;
 
@@ -2270,6 +3012,50 @@ impl TypeScriptCallGraphBuilder {
     }
 }
 
+/// Top-level properties of an object literal rendered by the parser (`{ a: 1, b: { c: 2 } }`)
+///
+/// Returns None when `value` isn't an object literal. Commas nested in objects, arrays,
+/// calls or strings don't split properties.
+fn object_properties(value: &str) -> Option<Vec<&str>> {
+    let inner = value.trim().strip_prefix('{')?.strip_suffix('}')?.trim();
+    let mut props = Vec::new();
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    for (idx, ch) in inner.char_indices() {
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'' | '`') => quote = Some(ch),
+            (None, '{' | '[' | '(') => depth += 1,
+            (None, '}' | ']' | ')') => depth = depth.saturating_sub(1),
+            (None, ',') if depth == 0 => {
+                props.push(inner[start..idx].trim());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    let last = inner[start..].trim();
+    if !last.is_empty() {
+        props.push(last);
+    }
+    Some(props)
+}
+
+/// Text a literal serializes to in a query string (`"yes"` -> `yes`, `2`, `true`); None for
+/// other expressions
+fn literal_query_value(value: &str) -> Option<String> {
+    let value = value.trim();
+    if let Some(text) = value
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+    {
+        return Some(text.to_string());
+    }
+    (value == "true" || value == "false" || value.parse::<f64>().is_ok()).then(|| value.to_string())
+}
+
 /// Information about an API call
 #[derive(Clone)]
 struct ApiCallInfo {
@@ -2280,4 +3066,6 @@ struct ApiCallInfo {
     request_type: Option<dc_core::models::TypeInfo>,
     /// Optional response type (for future use with generic parameters)
     response_type: Option<dc_core::models::TypeInfo>,
+    /// Non-REST transport (`trpc`, `graphql`, `server_action`) whose path is synthesized
+    transport: Option<&'static str>,
 }
@@ -1,5 +1,5 @@
 use dc_core::models::{Location, SchemaReference, SchemaType};
-use dc_core::parsers::LocationConverter;
+use dc_core::parsers::{LocationConverter, TypeScriptParser};
 use serde_json;
 use swc_ecma_ast::{
     CallExpr, Callee, Expr, MemberProp, Module, ModuleItem, Prop, PropName, PropOrSpread, Stmt,
@@ -205,6 +205,13 @@ impl ZodExtractor {
                                 type_name: field_type,
                                 optional: is_optional,
                                 nullable: is_nullable,
+                                schema_ref: None,
+                                min_items: None,
+                                max_items: None,
+                                default_value: TypeScriptParser::zod_default_value(
+                                    key_value.value.as_ref(),
+                                ),
+                                item_values: None,
                             });
                         }
                     }
@@ -1,3 +1,7 @@
+use dc_core::call_graph::{
+    CallNode, HttpMethod, SENT_HEADERS_METADATA_KEY, SENT_MEDIA_TYPE_METADATA_KEY,
+};
+use dc_core::models::HandledStatus;
 use dc_typescript::TypeScriptCallGraphBuilder;
 use tempfile::TempDir;
 
@@ -174,3 +178,624 @@ export function getUser(id: UserId): User {
 
     assert!(!function_nodes.is_empty());
 }
+
+#[test]
+fn test_generated_client_call_uses_generated_contract_types() {
+    let temp_dir = TempDir::new().unwrap();
+    let client_dir = temp_dir.path().join("client");
+    std::fs::create_dir_all(&client_dir).unwrap();
+
+    std::fs::write(
+        client_dir.join("types.gen.ts"),
+        r#"
+export type ItemCreate = {
+    name: string;
+    price?: number;
+};
+
+export type ItemPublic = {
+    id: string;
+    name: string;
+};
+
+export type CreateItemData = {
+    body: ItemCreate;
+    url: '/items/';
+};
+
+export type CreateItemResponse = ItemPublic;
+"#,
+    )
+    .unwrap();
+
+    std::fs::write(
+        client_dir.join("sdk.gen.ts"),
+        r#"
+import type { CreateItemData, CreateItemResponse } from './types.gen';
+
+export const createItem = (options: Options<CreateItemData>) => {
+    return (options?.client ?? client).post<ItemPublic, unknown>({
+        url: '/items/',
+        ...options
+    });
+};
+"#,
+    )
+    .unwrap();
+
+    std::fs::write(
+        temp_dir.path().join("app.ts"),
+        r#"
+import { createItem } from './client/sdk.gen';
+
+export async function submit() {
+    return createItem({ body: { name: "x" } });
+}
+"#,
+    )
+    .unwrap();
+
+    let builder = TypeScriptCallGraphBuilder::new(vec![temp_dir.path().to_path_buf()]);
+    let graph = builder.build_graph().unwrap();
+
+    let (handler, path) = graph
+        .node_weights()
+        .find_map(|node| match node {
+            dc_core::call_graph::CallNode::Route { handler, path, .. } => {
+                Some((*handler, path.clone()))
+            }
+            _ => None,
+        })
+        .expect("route for generated client call should be created");
+    assert_eq!(path, "/items/");
+
+    let Some(dc_core::call_graph::CallNode::Function {
+        parameters,
+        return_type,
+        ..
+    }) = graph.node_weight(handler.0)
+    else {
+        panic!("route handler should be a function node");
+    };
+
+    let request_schema = parameters[0].type_info.schema_ref.as_ref().unwrap();
+    assert_eq!(request_schema.name, "ItemCreate");
+    assert_eq!(
+        request_schema
+            .metadata
+            .get("contract_source")
+            .map(String::as_str),
+        Some("generated_client")
+    );
+
+    let response_schema = return_type.as_ref().unwrap().schema_ref.as_ref().unwrap();
+    assert_eq!(response_schema.name, "ItemPublic");
+}
+
+#[test]
+fn test_generated_client_type_arguments_are_read_from_the_ast() {
+    let temp_dir = TempDir::new().unwrap();
+    let client_dir = temp_dir.path().join("client");
+    std::fs::create_dir_all(&client_dir).unwrap();
+
+    std::fs::write(
+        client_dir.join("types.gen.ts"),
+        r#"
+export type ItemCreate = {
+    name: string;
+};
+
+export type ItemPublic = {
+    id: string;
+    name: string;
+};
+
+export type CreateItemData = {
+    body: ItemCreate;
+    url: '/items/';
+};
+
+export type LegacyData = {
+    body: string;
+};
+"#,
+    )
+    .unwrap();
+
+    // Type arguments split over lines, and a comment naming another `Options<...>`
+    std::fs::write(
+        client_dir.join("sdk.gen.ts"),
+        r#"
+/**
+ * Creates an item (replaces the Options<LegacyData> variant)
+ */
+export const createItem = (
+    options: Options<CreateItemData>,
+) => {
+    return (options?.client ?? client).post<
+        ItemPublic,
+        unknown
+    >({
+        url: '/items/',
+        ...options
+    });
+};
+"#,
+    )
+    .unwrap();
+
+    std::fs::write(
+        temp_dir.path().join("app.ts"),
+        r#"
+import { createItem } from './client/sdk.gen';
+
+export async function submit() {
+    return createItem({ body: { name: "x" } });
+}
+"#,
+    )
+    .unwrap();
+
+    let builder = TypeScriptCallGraphBuilder::new(vec![temp_dir.path().to_path_buf()]);
+    let graph = builder.build_graph().unwrap();
+
+    let handler = graph
+        .node_weights()
+        .find_map(|node| match node {
+            dc_core::call_graph::CallNode::Route { handler, .. } => Some(*handler),
+            _ => None,
+        })
+        .expect("route for generated client call should be created");
+    let Some(dc_core::call_graph::CallNode::Function {
+        parameters,
+        return_type,
+        ..
+    }) = graph.node_weight(handler.0)
+    else {
+        panic!("route handler should be a function node");
+    };
+
+    let request_schema = parameters[0].type_info.schema_ref.as_ref().unwrap();
+    assert_eq!(request_schema.name, "ItemCreate");
+    let response_schema = return_type.as_ref().unwrap().schema_ref.as_ref().unwrap();
+    assert_eq!(response_schema.name, "ItemPublic");
+}
+
+#[test]
+fn test_route_records_status_codes_handled_around_call() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("api.ts"),
+        r#"
+export async function loadItem(id: string) {
+    try {
+        const res = await fetch(`/api/items/${id}`);
+        if (res.status === 404) {
+            return null;
+        }
+        if (res.status >= 500 && res.status < 600) {
+            throw new Error("server");
+        }
+        return res.json();
+    } catch (err) {
+        if (err.response?.status === 401) {
+            return null;
+        }
+        throw err;
+    }
+}
+
+export async function removeItem(id: string) {
+    const res = await axios.delete(`/api/items/${id}`);
+    return res.data;
+}
+"#,
+    )
+    .unwrap();
+
+    let builder = TypeScriptCallGraphBuilder::new(vec![temp_dir.path().to_path_buf()]);
+    let graph = builder.build_graph().unwrap();
+
+    let handled: Vec<(HttpMethod, Option<Vec<HandledStatus>>)> = graph
+        .node_weights()
+        .filter_map(|node| match node {
+            CallNode::Route {
+                method, metadata, ..
+            } => Some((
+                *method,
+                metadata
+                    .get("handled_statuses")
+                    .map(|json| serde_json::from_str(json).unwrap()),
+            )),
+            _ => None,
+        })
+        .collect();
+
+    let for_method = |wanted: HttpMethod| {
+        handled
+            .iter()
+            .find(|(method, _)| *method == wanted)
+            .unwrap_or_else(|| panic!("no {:?} route detected", wanted))
+            .1
+            .clone()
+    };
+
+    assert_eq!(
+        for_method(HttpMethod::Get),
+        Some(vec![
+            HandledStatus::Exact(404),
+            HandledStatus::Range { min: 500, max: 599 },
+            HandledStatus::Exact(401),
+        ])
+    );
+    assert_eq!(for_method(HttpMethod::Delete), None);
+}
+
+#[test]
+fn test_route_records_literal_headers_sent_by_call() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("api.ts"),
+        r#"
+export async function listOrders(key: string) {
+    return fetch("/api/orders", { headers: { "X-Api-Key": key, Accept: "application/json" } });
+}
+
+export async function ping() {
+    return fetch("/api/ping");
+}
+
+export async function deleteOrder(id: string, auth: Record<string, string>) {
+    return fetch(`/api/orders/${id}`, { method: "DELETE", headers: { ...auth } });
+}
+
+export async function createOrder(order: object) {
+    return axios.post("/api/orders", order);
+}
+"#,
+    )
+    .unwrap();
+
+    let builder = TypeScriptCallGraphBuilder::new(vec![temp_dir.path().to_path_buf()]);
+    let graph = builder.build_graph().unwrap();
+
+    let mut sent: Vec<(HttpMethod, String, Option<String>)> = graph
+        .node_weights()
+        .filter_map(|node| match node {
+            CallNode::Route {
+                method,
+                path,
+                metadata,
+                ..
+            } => Some((
+                *method,
+                path.clone(),
+                metadata.get(SENT_HEADERS_METADATA_KEY).cloned(),
+            )),
+            _ => None,
+        })
+        .collect();
+    sent.sort_by(|a, b| a.1.cmp(&b.1));
+
+    assert_eq!(
+        sent,
+        vec![
+            (
+                HttpMethod::Get,
+                "\"/api/orders\"".to_string(),
+                Some("x-api-key,accept".to_string())
+            ),
+            // Other clients may add default headers
+            (HttpMethod::Post, "\"/api/orders\"".to_string(), None),
+            (
+                HttpMethod::Get,
+                "\"/api/ping\"".to_string(),
+                Some(String::new())
+            ),
+            // Spread headers are unknown
+            (HttpMethod::Delete, "`/api/orders/${id}`".to_string(), None),
+        ]
+    );
+}
+
+#[test]
+fn test_route_records_media_type_of_sent_body() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("api.ts"),
+        r#"
+export async function login(form: HTMLFormElement) {
+    return fetch("/api/login", { method: "POST", body: new FormData(form) });
+}
+
+export async function createItem(name: string) {
+    return fetch("/api/items", { method: "POST", body: JSON.stringify({ name }) });
+}
+
+export async function renameItem(id: string, name: string) {
+    return axios.put(`/api/items/${id}`, { name });
+}
+
+export async function importItems(csv: string) {
+    return fetch("/api/import", {
+        method: "POST",
+        headers: { "Content-Type": "text/csv" },
+        body: csv,
+    });
+}
+
+export async function listItems() {
+    return axios.get("/api/items", { params: { page: 1 } });
+}
+"#,
+    )
+    .unwrap();
+
+    let builder = TypeScriptCallGraphBuilder::new(vec![temp_dir.path().to_path_buf()]);
+    let graph = builder.build_graph().unwrap();
+
+    let mut sent: Vec<(HttpMethod, String, Option<String>)> = graph
+        .node_weights()
+        .filter_map(|node| match node {
+            CallNode::Route {
+                method,
+                path,
+                metadata,
+                ..
+            } => Some((
+                *method,
+                path.clone(),
+                metadata.get(SENT_MEDIA_TYPE_METADATA_KEY).cloned(),
+            )),
+            _ => None,
+        })
+        .collect();
+    sent.sort_by(|a, b| (&a.1, format!("{:?}", a.0)).cmp(&(&b.1, format!("{:?}", b.0))));
+
+    assert_eq!(
+        sent,
+        vec![
+            (
+                HttpMethod::Post,
+                "\"/api/import\"".to_string(),
+                Some("text/csv".to_string())
+            ),
+            // GET requests carry no body
+            (HttpMethod::Get, "\"/api/items\"".to_string(), None),
+            (
+                HttpMethod::Post,
+                "\"/api/items\"".to_string(),
+                Some("application/json".to_string())
+            ),
+            (
+                HttpMethod::Post,
+                "\"/api/login\"".to_string(),
+                Some("multipart/form-data".to_string())
+            ),
+            (
+                HttpMethod::Put,
+                "`/api/items/${id}`".to_string(),
+                Some("application/json".to_string())
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_serialized_request_body_becomes_request_type() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("api.ts"),
+        r#"
+interface CreateUser {
+    name: string;
+    email?: string;
+}
+
+export async function createUser(payload: CreateUser) {
+    const res = await fetch("/api/users", {
+        method: "POST",
+        headers: { "Content-Type": "application/json" },
+        body: JSON.stringify(payload),
+    });
+    return res.json();
+}
+"#,
+    )
+    .unwrap();
+
+    let builder = TypeScriptCallGraphBuilder::new(vec![temp_dir.path().to_path_buf()]);
+    let graph = builder.build_graph().unwrap();
+
+    let handler = graph
+        .node_weights()
+        .find_map(|node| match node {
+            CallNode::Route {
+                method, handler, ..
+            } => Some((*method, *handler)),
+            _ => None,
+        })
+        .expect("fetch call should create a route");
+    assert_eq!(handler.0, HttpMethod::Post);
+
+    let request_schema = match graph.node_weight(handler.1 .0) {
+        Some(CallNode::Function { parameters, .. }) => parameters
+            .first()
+            .and_then(|param| param.type_info.schema_ref.clone()),
+        _ => None,
+    }
+    .expect("serialized body should be the handler's request type");
+    assert_eq!(request_schema.name, "CreateUser");
+}
+
+#[test]
+fn test_utility_type_bodies_resolve_imported_base_fields() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("models.ts"),
+        r#"
+export interface User {
+    id: number;
+    name: string;
+}
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        temp_dir.path().join("api.ts"),
+        r#"
+import { User } from "./models";
+
+type UserPatch = Partial<User>;
+
+export async function patchUser(payload: UserPatch) {
+    return fetch("/api/users/1", { method: "PATCH", body: JSON.stringify(payload) });
+}
+"#,
+    )
+    .unwrap();
+
+    let builder = TypeScriptCallGraphBuilder::new(vec![temp_dir.path().to_path_buf()]);
+    let graph = builder.build_graph().unwrap();
+
+    let handler = graph
+        .node_weights()
+        .find_map(|node| match node {
+            CallNode::Route { handler, .. } => Some(*handler),
+            _ => None,
+        })
+        .expect("fetch call should create a route");
+    let request_schema = match graph.node_weight(handler.0) {
+        Some(CallNode::Function { parameters, .. }) => parameters
+            .first()
+            .and_then(|param| param.type_info.schema_ref.clone()),
+        _ => None,
+    }
+    .expect("serialized body should be the handler's request type");
+    assert_eq!(request_schema.name, "UserPatch");
+    assert_eq!(
+        request_schema.metadata.get("fields").map(String::as_str),
+        Some("id:number:optional,name:string:optional")
+    );
+}
+
+#[test]
+fn test_express_routes_become_route_nodes() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("models.ts"),
+        r#"
+export interface User {
+    id: number;
+    name: string;
+}
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        temp_dir.path().join("server.ts"),
+        r#"
+import express, { Request, Response } from 'express';
+import { User } from './models';
+
+interface CreateUser {
+    name: string;
+}
+
+const app = express();
+const users = express.Router();
+
+async function createUser(req: Request<{}, User, CreateUser>, res: Response) {
+    const user = await save(req.body);
+    res.status(201).json(user);
+}
+
+users.post('/users', authenticate, createUser);
+
+users.get('/users/:id', async (req, res) => {
+    const user: User = await load(req.params.id);
+    res.json(user);
+});
+
+users.put('/users/:id', (req, res) => {
+    const update = req.body as CreateUser;
+    res.send(update);
+});
+
+app.get('/health', (req, res) => res.send('ok'));
+app.set('port', 3000);
+"#,
+    )
+    .unwrap();
+
+    // Registrations are only read for backends
+    let frontend = TypeScriptCallGraphBuilder::new(vec![temp_dir.path().to_path_buf()])
+        .build_graph()
+        .unwrap();
+    assert!(!frontend
+        .node_weights()
+        .any(|node| matches!(node, CallNode::Route { .. })));
+
+    let graph = TypeScriptCallGraphBuilder::new(vec![temp_dir.path().to_path_buf()])
+        .with_express_routes(true)
+        .build_graph()
+        .unwrap();
+    let name = |schema: &Option<dc_core::models::SchemaReference>| {
+        schema
+            .as_ref()
+            .map_or("-".to_string(), |schema| schema.name.clone())
+    };
+    let mut routes: Vec<String> = graph
+        .node_weights()
+        .filter_map(|node| match node {
+            CallNode::Route {
+                path,
+                method,
+                handler,
+                request_schema,
+                response_schema,
+                ..
+            } => {
+                let handler_name = match graph.node_weight(handler.0) {
+                    Some(CallNode::Function { name, .. }) => name.clone(),
+                    other => panic!("handler should be a function, got {:?}", other),
+                };
+                Some(format!(
+                    "{:?} {} {} -> {} ({})",
+                    method,
+                    path,
+                    name(request_schema),
+                    name(response_schema),
+                    handler_name
+                ))
+            }
+            _ => None,
+        })
+        .collect();
+    routes.sort();
+
+    assert_eq!(
+        routes,
+        vec![
+            "Get /health - -> - (GET /health)",
+            "Get /users/:id - -> User (GET /users/:id)",
+            "Post /users CreateUser -> User (createUser)",
+            "Put /users/:id CreateUser -> CreateUser (PUT /users/:id)",
+        ]
+    );
+
+    // Imported types are resolved to their declarations
+    let user_schema = graph
+        .node_weights()
+        .find_map(|node| match node {
+            CallNode::Route {
+                path,
+                response_schema: Some(schema),
+                ..
+            } if path == "/users/:id" => Some(schema.clone()),
+            _ => None,
+        })
+        .unwrap();
+    assert!(user_schema.location.file.ends_with("models.ts"));
+    assert!(user_schema.metadata.contains_key("fields"));
+}